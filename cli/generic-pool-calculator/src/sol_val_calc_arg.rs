@@ -5,36 +5,52 @@ use spl_calculator_lib::sanctum_spl_sol_val_calc_program;
 
 #[derive(Clone, Copy, Debug)]
 pub enum SolValCalcArg {
+    Everstake,
     Lido,
     Marinade,
+    SPool,
     SanctumSpl,
+    Socean,
     Spl,
+    Svsp,
     Wsol,
     Unknown(Pubkey),
 }
 
 impl SolValCalcArg {
     pub const HELP_STR: &str = "A SOL Value Calculator Program. Can either be a program ID pubkey or one of the following known programs:
+- everstake
 - lido
 - marinade
+- s-pool
 - sanctum-spl
+- socean
 - spl
+- svsp
 - wsol";
 
     pub fn parse_arg(arg: &str) -> Result<Self, Box<dyn Error + Send + Sync + 'static>> {
         Ok(match arg {
+            "everstake" => Self::Everstake,
             "lido" => Self::Lido,
             "marinade" => Self::Marinade,
+            "s-pool" => Self::SPool,
             "sanctum-spl" => Self::SanctumSpl,
+            "socean" => Self::Socean,
             "spl" => Self::Spl,
+            "svsp" => Self::Svsp,
             "wsol" => Self::Wsol,
             _ => {
                 let pk = Pubkey::from_str(arg)?;
                 match pk {
+                    everstake_calculator_lib::program::ID => Self::Everstake,
                     lido_calculator_lib::program::ID => Self::Lido,
                     marinade_calculator_lib::program::ID => Self::Marinade,
+                    s_pool_calculator_lib::program::ID => Self::SPool,
                     sanctum_spl_sol_val_calc_program::ID => Self::SanctumSpl,
+                    socean_calculator_lib::program::ID => Self::Socean,
                     spl_calculator_lib::program::ID => Self::Spl,
+                    svsp_calculator_lib::program::ID => Self::Svsp,
                     wsol_calculator_lib::program::ID => Self::Wsol,
                     _ => Self::Unknown(pk),
                 }
@@ -44,10 +60,14 @@ impl SolValCalcArg {
 
     pub fn program_id(&self) -> Pubkey {
         match self {
+            Self::Everstake => everstake_calculator_lib::program::ID,
             Self::Lido => lido_calculator_lib::program::ID,
             Self::Marinade => marinade_calculator_lib::program::ID,
+            Self::SPool => s_pool_calculator_lib::program::ID,
             Self::SanctumSpl => sanctum_spl_sol_val_calc_program::ID,
+            Self::Socean => socean_calculator_lib::program::ID,
             Self::Spl => spl_calculator_lib::program::ID,
+            Self::Svsp => svsp_calculator_lib::program::ID,
             Self::Wsol => wsol_calculator_lib::program::ID,
             Self::Unknown(pk) => *pk,
         }
@@ -2,10 +2,13 @@ use std::convert::Infallible;
 
 use borsh::BorshDeserialize;
 use data_encoding::BASE64;
+use everstake_calculator_lib::everstake_sol_val_calc_account_metas;
 use generic_pool_calculator_interface::CalculatorState;
 use lido_calculator_lib::lido_sol_val_calc_account_metas;
 use marinade_calculator_lib::marinade_sol_val_calc_account_metas;
+use s_pool_calculator_lib::SPoolLstSolCommonFreeArgsConst;
 use sanctum_token_ratio::U64ValueRange;
+use socean_calculator_lib::socean_sol_val_calc_account_metas;
 use solana_client::nonblocking::rpc_client::RpcClient;
 use solana_readonly_account::keyed::Keyed;
 use solana_rpc_client_api::response::RpcSimulateTransactionResult;
@@ -19,6 +22,7 @@ use solana_sdk::{
 };
 use solana_transaction_status::{UiReturnDataEncoding, UiTransactionReturnData};
 use spl_calculator_lib::SplLstSolCommonFreeArgsConst;
+use svsp_calculator_lib::svsp_sol_val_calc_account_metas;
 use wsol_calculator_lib::WSOL_LST_SOL_COMMON_METAS;
 
 use crate::sol_val_calc_arg::SolValCalcArg;
@@ -40,14 +44,30 @@ pub async fn lst_sol_common_account_metas(
     pool: Option<Pubkey>,
 ) -> Vec<AccountMeta> {
     let pool = match arg {
-        SolValCalcArg::SanctumSpl | SolValCalcArg::Spl => {
-            pool.expect("pool pubkey must be provided if spl or sanctum-spl")
+        SolValCalcArg::SPool
+        | SolValCalcArg::SanctumSpl
+        | SolValCalcArg::Spl
+        | SolValCalcArg::Svsp => {
+            pool.expect("pool pubkey must be provided if s-pool, spl, sanctum-spl or svsp")
         }
         _ => Pubkey::default(), // dont care
     };
     match arg {
+        SolValCalcArg::Everstake => everstake_sol_val_calc_account_metas().to_vec(),
         SolValCalcArg::Lido => lido_sol_val_calc_account_metas().to_vec(),
         SolValCalcArg::Marinade => marinade_sol_val_calc_account_metas().to_vec(),
+        SolValCalcArg::SPool => {
+            let pool_acc = rpc.get_account(&pool).await.unwrap();
+            SPoolLstSolCommonFreeArgsConst {
+                pool_state: Keyed {
+                    account: pool_acc,
+                    pubkey: pool,
+                },
+            }
+            .resolve_to_account_metas()
+            .unwrap()
+            .to_vec()
+        }
         SolValCalcArg::SanctumSpl => {
             let pool_acc = rpc.get_account(&pool).await.unwrap();
             SplLstSolCommonFreeArgsConst {
@@ -60,6 +80,7 @@ pub async fn lst_sol_common_account_metas(
             .unwrap()
             .to_vec()
         }
+        SolValCalcArg::Socean => socean_sol_val_calc_account_metas().to_vec(),
         SolValCalcArg::Spl => {
             let pool_acc = rpc.get_account(&pool).await.unwrap();
             SplLstSolCommonFreeArgsConst {
@@ -72,6 +93,7 @@ pub async fn lst_sol_common_account_metas(
             .unwrap()
             .to_vec()
         }
+        SolValCalcArg::Svsp => svsp_sol_val_calc_account_metas(pool).to_vec(),
         SolValCalcArg::Wsol => WSOL_LST_SOL_COMMON_METAS.to_vec(),
         SolValCalcArg::Unknown(_) => unreachable!(),
     }
@@ -25,7 +25,7 @@ pub struct LstToSolArgs {
     #[arg(
         long,
         short,
-        help = "The stake pool to run for. Must be provided for spl or sanctum-spl"
+        help = "The stake pool to run for. Must be provided for s-pool, spl, sanctum-spl or svsp"
     )]
     pub pool: Option<Pubkey>,
 }
@@ -4,7 +4,7 @@ use clap::{
     builder::{StringValueParser, TypedValueParser, ValueParser},
     Parser,
 };
-use s_cli_utils::{CONFIG_HELP, TX_SEND_MODE_HELP};
+use s_cli_utils::{NonceArgs, TxEncoding, CONFIG_HELP, EXPORT_HELP, TX_SEND_MODE_HELP};
 use sanctum_solana_cli_utils::{ConfigWrapper, TxSendMode};
 use solana_sdk::pubkey::Pubkey;
 use subcmd::Subcmd;
@@ -34,6 +34,12 @@ pub struct Args {
     )]
     pub send_mode: TxSendMode,
 
+    #[arg(long, global = true, help = EXPORT_HELP, value_enum)]
+    pub export: Option<TxEncoding>,
+
+    #[command(flatten)]
+    pub nonce: NonceArgs,
+
     #[arg(
         help = "program ID of the flat-fee pricing program",
         default_value_t = flat_fee_lib::program::ID,
@@ -7,13 +7,9 @@ use flat_fee_lib::{
     account_resolvers::AddLstFreeArgs, pda::ProgramStateFindPdaArgs, utils::try_program_state,
     ADD_LST_IX_COMPUTE_UNIT_CEIL,
 };
-use sanctum_solana_cli_utils::{parse_signer, TxSendingNonblockingRpcClient};
+use s_cli_utils::{handle_tx_or_export, parse_signer_or_pubkey};
 use solana_readonly_account::sdk::KeyedAccount;
-use solana_sdk::{
-    compute_budget::ComputeBudgetInstruction,
-    message::{v0::Message, VersionedMessage},
-    transaction::VersionedTransaction,
-};
+use solana_sdk::compute_budget::ComputeBudgetInstruction;
 
 use crate::lst_arg::LstArg;
 
@@ -57,7 +53,7 @@ impl AddLstArgs {
         let rpc = args.config.nonblocking_rpc_client();
         let program_id = args.program;
 
-        let manager_signer = manager.map(|s| parse_signer(&s).unwrap());
+        let manager_signer = manager.map(|s| parse_signer_or_pubkey(&s).unwrap());
         let manager = manager_signer.as_ref().unwrap_or(&payer);
 
         let state_pda = ProgramStateFindPdaArgs { program_id }
@@ -90,28 +86,22 @@ impl AddLstArgs {
         let mut signers = vec![payer.as_ref(), manager.as_ref()];
         signers.dedup();
 
-        let rbh = rpc.get_latest_blockhash().await.unwrap();
-        let tx = VersionedTransaction::try_new(
-            VersionedMessage::V0(
-                Message::try_compile(
-                    &payer.pubkey(),
-                    &[
-                        ComputeBudgetInstruction::set_compute_unit_limit(
-                            ADD_LST_IX_COMPUTE_UNIT_CEIL,
-                        ),
-                        // TODO: make compute unit price dynamic
-                        ComputeBudgetInstruction::set_compute_unit_price(33),
-                        ix,
-                    ],
-                    &[],
-                    rbh,
-                )
-                .unwrap(),
-            ),
-            &signers,
-        )
-        .unwrap();
+        let tx = args
+            .nonce
+            .build_tx(
+                &rpc,
+                &payer.pubkey(),
+                &[
+                    ComputeBudgetInstruction::set_compute_unit_limit(ADD_LST_IX_COMPUTE_UNIT_CEIL),
+                    // TODO: make compute unit price dynamic
+                    ComputeBudgetInstruction::set_compute_unit_price(33),
+                    ix,
+                ],
+                &[],
+                &signers,
+            )
+            .await;
 
-        rpc.handle_tx(&tx, args.send_mode).await;
+        handle_tx_or_export(&rpc, &tx, args.send_mode, args.export).await;
     }
 }
@@ -3,12 +3,8 @@ use flat_fee_interface::initialize_ix_with_program_id;
 use flat_fee_lib::{
     account_resolvers::InitializeFreeArgs, pda::ProgramStateFindPdaArgs, utils::try_program_state,
 };
-use sanctum_solana_cli_utils::TxSendingNonblockingRpcClient;
-use solana_sdk::{
-    commitment_config::CommitmentConfig,
-    message::{v0::Message, VersionedMessage},
-    transaction::VersionedTransaction,
-};
+use s_cli_utils::handle_tx_or_export;
+use solana_sdk::commitment_config::CommitmentConfig;
 
 #[derive(Args, Debug)]
 #[command(long_about = "Initializes the flat-fee pricing program's state")]
@@ -46,13 +42,11 @@ impl InitializeArgs {
         )
         .unwrap();
 
-        let rbh = rpc.get_latest_blockhash().await.unwrap();
-        let tx = VersionedTransaction::try_new(
-            VersionedMessage::V0(Message::try_compile(&signer.pubkey(), &[ix], &[], rbh).unwrap()),
-            &[signer.as_ref()],
-        )
-        .unwrap();
+        let tx = args
+            .nonce
+            .build_tx(&rpc, &signer.pubkey(), &[ix], &[], &[signer.as_ref()])
+            .await;
 
-        rpc.handle_tx(&tx, args.send_mode).await;
+        handle_tx_or_export(&rpc, &tx, args.send_mode, args.export).await;
     }
 }
@@ -1,4 +1,5 @@
 use clap::Subcommand;
+use s_cli_utils::{SendSignedArgs, SignArgs};
 
 mod add_lst;
 mod common;
@@ -8,9 +9,11 @@ mod price_exact_out;
 mod price_lp_tokens_to_mint;
 mod price_lp_tokens_to_redeem;
 mod remove_lst;
+mod send_signed;
 mod set_lp_withdrawal_fee;
 mod set_lst_fee;
 mod set_manager;
+mod sign;
 mod view;
 mod view_lst;
 
@@ -41,6 +44,8 @@ pub enum Subcmd {
     PriceExactOut(PriceExactOutArgs),
     PriceLpTokensToMint(PriceLpTokensToMintArgs),
     PriceLpTokensToRedeem(PriceLpTokensToRedeemArgs),
+    Sign(SignArgs),
+    SendSigned(SendSignedArgs),
 }
 
 impl Subcmd {
@@ -58,6 +63,8 @@ impl Subcmd {
             Self::PriceExactOut(_) => PriceExactOutArgs::run(args).await,
             Self::PriceLpTokensToMint(_) => PriceLpTokensToMintArgs::run(args).await,
             Self::PriceLpTokensToRedeem(_) => PriceLpTokensToRedeemArgs::run(args).await,
+            Self::Sign(_) => sign::run(args).await,
+            Self::SendSigned(_) => send_signed::run(args).await,
         }
     }
 }
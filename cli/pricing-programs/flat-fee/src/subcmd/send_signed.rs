@@ -0,0 +1,15 @@
+use s_cli_utils::handle_tx_or_export;
+
+use super::Subcmd;
+
+pub async fn run(args: crate::Args) {
+    let send_signed_args = match args.subcmd {
+        Subcmd::SendSigned(a) => a,
+        _ => unreachable!(),
+    };
+
+    let rpc = args.config.nonblocking_rpc_client();
+    let tx = send_signed_args.combined_tx();
+
+    handle_tx_or_export(&rpc, &tx, args.send_mode, args.export).await;
+}
@@ -4,13 +4,9 @@ use flat_fee_lib::{
     account_resolvers::SetLpWithdrawalFeeFreeArgs, pda::ProgramStateFindPdaArgs,
     utils::try_program_state, SET_LP_WITHDRAWAL_FEE_COMPUTE_UNIT_CEIL,
 };
-use sanctum_solana_cli_utils::{parse_signer, TxSendingNonblockingRpcClient};
+use s_cli_utils::{handle_tx_or_export, parse_signer_or_pubkey};
 use solana_readonly_account::sdk::KeyedAccount;
-use solana_sdk::{
-    compute_budget::ComputeBudgetInstruction,
-    message::{v0::Message, VersionedMessage},
-    transaction::VersionedTransaction,
-};
+use solana_sdk::compute_budget::ComputeBudgetInstruction;
 
 use super::{common::verify_manager, Subcmd};
 
@@ -41,7 +37,7 @@ impl SetLpWithdrawalFeeArgs {
         let rpc = args.config.nonblocking_rpc_client();
         let program_id = args.program;
 
-        let manager_signer = manager.map(|s| parse_signer(&s).unwrap());
+        let manager_signer = manager.map(|s| parse_signer_or_pubkey(&s).unwrap());
         let manager = manager_signer.as_ref().unwrap_or(&payer);
 
         let state_pda = ProgramStateFindPdaArgs { program_id }
@@ -70,27 +66,23 @@ impl SetLpWithdrawalFeeArgs {
         let mut signers = vec![payer.as_ref(), manager.as_ref()];
         signers.dedup();
 
-        let rbh = rpc.get_latest_blockhash().await.unwrap();
-        let tx = VersionedTransaction::try_new(
-            VersionedMessage::V0(
-                Message::try_compile(
-                    &payer.pubkey(),
-                    &[
-                        ComputeBudgetInstruction::set_compute_unit_limit(
-                            SET_LP_WITHDRAWAL_FEE_COMPUTE_UNIT_CEIL,
-                        ),
-                        ComputeBudgetInstruction::set_compute_unit_price(100),
-                        ix,
-                    ],
-                    &[],
-                    rbh,
-                )
-                .unwrap(),
-            ),
-            &signers,
-        )
-        .unwrap();
+        let tx = args
+            .nonce
+            .build_tx(
+                &rpc,
+                &payer.pubkey(),
+                &[
+                    ComputeBudgetInstruction::set_compute_unit_limit(
+                        SET_LP_WITHDRAWAL_FEE_COMPUTE_UNIT_CEIL,
+                    ),
+                    ComputeBudgetInstruction::set_compute_unit_price(100),
+                    ix,
+                ],
+                &[],
+                &signers,
+            )
+            .await;
 
-        rpc.handle_tx(&tx, args.send_mode).await;
+        handle_tx_or_export(&rpc, &tx, args.send_mode, args.export).await;
     }
 }
@@ -7,12 +7,8 @@ use flat_fee_lib::{
     account_resolvers::SetLstFeeByMintFreeArgs, pda::ProgramStateFindPdaArgs,
     utils::try_program_state,
 };
-use sanctum_solana_cli_utils::{parse_signer, TxSendingNonblockingRpcClient};
+use s_cli_utils::{handle_tx_or_export, parse_signer_or_pubkey};
 use solana_readonly_account::sdk::KeyedAccount;
-use solana_sdk::{
-    message::{v0::Message, VersionedMessage},
-    transaction::VersionedTransaction,
-};
 
 use crate::lst_arg::LstArg;
 
@@ -56,7 +52,7 @@ impl SetLstFeeArgs {
         let rpc = args.config.nonblocking_rpc_client();
         let program_id = args.program;
 
-        let manager_signer = manager.map(|s| parse_signer(&s).unwrap());
+        let manager_signer = manager.map(|s| parse_signer_or_pubkey(&s).unwrap());
         let manager = manager_signer.as_ref().unwrap_or(&payer);
 
         let state_pda = ProgramStateFindPdaArgs { program_id }
@@ -87,13 +83,11 @@ impl SetLstFeeArgs {
         let mut signers = vec![payer.as_ref(), manager.as_ref()];
         signers.dedup();
 
-        let rbh = rpc.get_latest_blockhash().await.unwrap();
-        let tx = VersionedTransaction::try_new(
-            VersionedMessage::V0(Message::try_compile(&payer.pubkey(), &[ix], &[], rbh).unwrap()),
-            &signers,
-        )
-        .unwrap();
+        let tx = args
+            .nonce
+            .build_tx(&rpc, &payer.pubkey(), &[ix], &[], &signers)
+            .await;
 
-        rpc.handle_tx(&tx, args.send_mode).await;
+        handle_tx_or_export(&rpc, &tx, args.send_mode, args.export).await;
     }
 }
@@ -1,11 +1,8 @@
 use clap::Args;
 use flat_fee_interface::{set_manager_ix_with_program_id, SetManagerKeys};
 use flat_fee_lib::{pda::ProgramStateFindPdaArgs, utils::try_program_state};
-use sanctum_solana_cli_utils::{parse_pubkey_src, parse_signer, TxSendingNonblockingRpcClient};
-use solana_sdk::{
-    message::{v0::Message, VersionedMessage},
-    transaction::VersionedTransaction,
-};
+use s_cli_utils::{handle_tx_or_export, parse_signer_or_pubkey};
+use sanctum_solana_cli_utils::parse_pubkey_src;
 
 use super::{common::verify_manager, Subcmd};
 
@@ -36,7 +33,7 @@ impl SetManagerArgs {
         let rpc = args.config.nonblocking_rpc_client();
         let program_id = args.program;
 
-        let curr_manager_signer = curr_manager.map(|s| parse_signer(&s).unwrap());
+        let curr_manager_signer = curr_manager.map(|s| parse_signer_or_pubkey(&s).unwrap());
         let curr_manager = curr_manager_signer.as_ref().unwrap_or(&payer);
 
         let new_manager = parse_pubkey_src(&new_manager).unwrap();
@@ -60,13 +57,11 @@ impl SetManagerArgs {
         let mut signers = vec![payer.as_ref(), curr_manager.as_ref()];
         signers.dedup();
 
-        let rbh = rpc.get_latest_blockhash().await.unwrap();
-        let tx = VersionedTransaction::try_new(
-            VersionedMessage::V0(Message::try_compile(&payer.pubkey(), &[ix], &[], rbh).unwrap()),
-            &signers,
-        )
-        .unwrap();
+        let tx = args
+            .nonce
+            .build_tx(&rpc, &payer.pubkey(), &[ix], &[], &signers)
+            .await;
 
-        rpc.handle_tx(&tx, args.send_mode).await;
+        handle_tx_or_export(&rpc, &tx, args.send_mode, args.export).await;
     }
 }
@@ -1,11 +1,14 @@
 use std::collections::HashMap;
 
 use clap::Args;
+use flat_fee_interface::{FeeAccount, ProgramState};
 use flat_fee_lib::{
     pda::{FeeAccountFindPdaArgs, ProgramStateFindPdaArgs},
     utils::{try_fee_account, try_program_state},
 };
+use s_cli_utils::{csv_record, OutputFormat, OUTPUT_FORMAT_HELP};
 use sanctum_lst_list::SanctumLst;
+use serde::Serialize;
 use solana_sdk::pubkey::Pubkey;
 
 use crate::lst_arg::SANCTUM_LST_LIST;
@@ -14,11 +17,107 @@ use super::Subcmd;
 
 #[derive(Args, Debug)]
 #[command(long_about = "Views flat-fee pricing program's program state and all fee accounts")]
-pub struct ViewArgs;
+pub struct ViewArgs {
+    #[arg(
+        long,
+        short,
+        help = OUTPUT_FORMAT_HELP,
+        default_value_t = OutputFormat::default(),
+        value_enum,
+    )]
+    pub output: OutputFormat,
+}
+
+/// Program info for machine-readable output. Pubkeys are base58 strings.
+#[derive(Clone, Debug, Serialize)]
+struct ProgramView {
+    program_id: String,
+    program_state: Option<ProgramStateView>,
+    fee_accounts: Vec<FeeAccountView>,
+}
+
+#[derive(Clone, Debug, Serialize)]
+struct ProgramStateView {
+    address: String,
+    manager: String,
+    lp_withdrawal_fee_bps: u16,
+}
+
+impl ProgramStateView {
+    fn new(
+        address: Pubkey,
+        ProgramState {
+            manager,
+            lp_withdrawal_fee_bps,
+        }: &ProgramState,
+    ) -> Self {
+        Self {
+            address: address.to_string(),
+            manager: manager.to_string(),
+            lp_withdrawal_fee_bps: *lp_withdrawal_fee_bps,
+        }
+    }
+}
+
+/// `lst_mint` and `symbol` are None if the LST is not on sanctum-lst-list
+#[derive(Clone, Debug, Serialize)]
+pub struct FeeAccountView {
+    address: String,
+    lst_mint: Option<String>,
+    symbol: Option<String>,
+    input_fee_bps: i16,
+    output_fee_bps: i16,
+}
+
+impl FeeAccountView {
+    pub const CSV_HEADER: [&'static str; 5] = [
+        "address",
+        "lst_mint",
+        "symbol",
+        "input_fee_bps",
+        "output_fee_bps",
+    ];
+
+    pub fn new(
+        address: Pubkey,
+        lst_mint: Option<Pubkey>,
+        symbol: Option<&str>,
+        FeeAccount {
+            input_fee_bps,
+            output_fee_bps,
+            ..
+        }: &FeeAccount,
+    ) -> Self {
+        Self {
+            address: address.to_string(),
+            lst_mint: lst_mint.map(|m| m.to_string()),
+            symbol: symbol.map(Into::into),
+            input_fee_bps: *input_fee_bps,
+            output_fee_bps: *output_fee_bps,
+        }
+    }
+
+    pub fn csv_record(&self) -> String {
+        let Self {
+            address,
+            lst_mint,
+            symbol,
+            input_fee_bps,
+            output_fee_bps,
+        } = self;
+        csv_record([
+            address.clone(),
+            lst_mint.clone().unwrap_or_default(),
+            symbol.clone().unwrap_or_default(),
+            input_fee_bps.to_string(),
+            output_fee_bps.to_string(),
+        ])
+    }
+}
 
 impl ViewArgs {
     pub async fn run(args: crate::Args) {
-        let Self = match args.subcmd {
+        let Self { output } = match args.subcmd {
             Subcmd::View(a) => a,
             _ => unreachable!(),
         };
@@ -47,25 +146,58 @@ impl ViewArgs {
             .collect();
 
         let mut program_accs = rpc.get_program_accounts(&program_id).await.unwrap();
-        program_accs.retain(|(pk, acc)| {
-            if *pk == state_pda {
-                let state = try_program_state(&acc.data).unwrap();
-                println!("{state:#?}");
-                println!();
-                false
-            } else {
-                true
+        let state_acc_idx = program_accs.iter().position(|(pk, _)| *pk == state_pda);
+        let state_acc = state_acc_idx.map(|i| program_accs.remove(i).1);
+        let state = state_acc
+            .as_ref()
+            .map(|acc| try_program_state(&acc.data).unwrap());
+        let fee_accounts = program_accs
+            .iter()
+            .map(|(pk, acc)| (*pk, pda_to_lst.get(pk), try_fee_account(&acc.data).unwrap()));
+
+        match output {
+            OutputFormat::Json => {
+                let view = ProgramView {
+                    program_id: program_id.to_string(),
+                    program_state: state.map(|s| ProgramStateView::new(state_pda, s)),
+                    fee_accounts: fee_accounts
+                        .map(|(pk, lst, fee)| {
+                            FeeAccountView::new(
+                                pk,
+                                lst.map(|l| l.mint),
+                                lst.map(|l| l.symbol.as_str()),
+                                fee,
+                            )
+                        })
+                        .collect(),
+                };
+                println!("{}", serde_json::to_string_pretty(&view).unwrap());
+            }
+            OutputFormat::Csv => {
+                println!("{}", csv_record(FeeAccountView::CSV_HEADER));
+                for (pk, lst, fee) in fee_accounts {
+                    let view = FeeAccountView::new(
+                        pk,
+                        lst.map(|l| l.mint),
+                        lst.map(|l| l.symbol.as_str()),
+                        fee,
+                    );
+                    println!("{}", view.csv_record());
+                }
+            }
+            OutputFormat::Text => {
+                if let Some(state) = state {
+                    println!("{state:#?}");
+                    println!();
+                }
+                for (_pk, lst, fee) in fee_accounts {
+                    let symbol =
+                        lst.map_or_else(|| "Unknown LST", |SanctumLst { symbol, .. }| symbol);
+                    println!("{symbol}:");
+                    println!("{fee:#?}");
+                    println!();
+                }
             }
-        });
-
-        for (pk, acc) in program_accs {
-            let symbol = pda_to_lst
-                .get(&pk)
-                .map_or_else(|| "Unknown LST", |SanctumLst { symbol, .. }| symbol);
-            println!("{symbol}:");
-            let fee = try_fee_account(&acc.data).unwrap();
-            println!("{fee:#?}");
-            println!();
         }
     }
 }
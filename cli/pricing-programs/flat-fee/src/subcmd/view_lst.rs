@@ -3,12 +3,25 @@ use clap::{
     Args,
 };
 use flat_fee_lib::{pda::FeeAccountFindPdaArgs, utils::try_fee_account};
+use s_cli_utils::{csv_record, OutputFormat, OUTPUT_FORMAT_HELP};
 
-use crate::{lst_arg::LstArg, subcmd::Subcmd};
+use crate::{
+    lst_arg::LstArg,
+    subcmd::{view::FeeAccountView, Subcmd},
+};
 
 #[derive(Args, Debug)]
 #[command(long_about = "View the current fees for a given LST")]
 pub struct ViewLstArgs {
+    #[arg(
+        long,
+        short,
+        help = OUTPUT_FORMAT_HELP,
+        default_value_t = OutputFormat::default(),
+        value_enum,
+    )]
+    pub output: OutputFormat,
+
     #[arg(
         help = "Mint of the LST to view fees for. Can either be a pubkey or case-insensitive symbol of a token on sanctum-lst-list. e.g. 'bsol'",
         value_parser = StringValueParser::new().try_map(|s| LstArg::parse_arg(&s)),
@@ -18,7 +31,7 @@ pub struct ViewLstArgs {
 
 impl ViewLstArgs {
     pub async fn run(args: crate::Args) {
-        let Self { lst_mint } = match args.subcmd {
+        let Self { output, lst_mint } = match args.subcmd {
             Subcmd::ViewLst(a) => a,
             _ => unreachable!(),
         };
@@ -35,6 +48,22 @@ impl ViewLstArgs {
         let fee_account_data = rpc.get_account_data(&fee_account_pda).await.unwrap();
         let fee_account = try_fee_account(&fee_account_data).unwrap();
 
-        println!("{fee_account:#?}");
+        let view = || {
+            let symbol = match lst_mint {
+                LstArg::SanctumLst(lst) => Some(lst.symbol.as_str()),
+                LstArg::Unknown(_) => None,
+            };
+            FeeAccountView::new(fee_account_pda, Some(lst_mint.mint()), symbol, fee_account)
+        };
+        match output {
+            OutputFormat::Json => {
+                println!("{}", serde_json::to_string_pretty(&view()).unwrap())
+            }
+            OutputFormat::Csv => {
+                println!("{}", csv_record(FeeAccountView::CSV_HEADER));
+                println!("{}", view().csv_record());
+            }
+            OutputFormat::Text => println!("{fee_account:#?}"),
+        }
     }
 }
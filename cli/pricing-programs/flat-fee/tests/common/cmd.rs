@@ -86,7 +86,7 @@ pub fn cargo_bin() -> Command {
     Command::cargo_bin("flt").unwrap()
 }
 
-fn base_cmd(cfg: &TempCliConfig) -> Command {
+pub fn base_cmd(cfg: &TempCliConfig) -> Command {
     let mut cmd = cargo_bin();
     cmd.with_send_mode_dump_msg().with_cfg_temp_cli(cfg);
     cmd
@@ -110,6 +110,10 @@ pub trait TestCmd {
     fn cmd_view(&mut self) -> &mut Self;
 
     fn cmd_view_lst(&mut self) -> &mut Self;
+
+    fn cmd_sign(&mut self) -> &mut Self;
+
+    fn cmd_send_signed(&mut self) -> &mut Self;
 }
 
 impl TestCmd for Command {
@@ -148,4 +152,12 @@ impl TestCmd for Command {
     fn cmd_view_lst(&mut self) -> &mut Self {
         self.arg("view-lst")
     }
+
+    fn cmd_sign(&mut self) -> &mut Self {
+        self.arg("sign")
+    }
+
+    fn cmd_send_signed(&mut self) -> &mut Self {
+        self.arg("send-signed")
+    }
 }
@@ -1,5 +1,6 @@
 mod add_lst;
 mod initialize;
+mod offline_tx;
 mod remove_lst;
 mod set_lp_withdrawal_fee;
 mod set_lst_fee;
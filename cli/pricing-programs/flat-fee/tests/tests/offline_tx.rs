@@ -0,0 +1,88 @@
+use std::process::Output;
+
+use cli_test_utils::{assert_all_txs_success_nonempty, TestCliCmd};
+use flat_fee_interface::ProgramState;
+use flat_fee_lib::{pda::FeeAccountFindPdaArgs, utils::try_fee_account};
+use flat_fee_test_utils::MockFeeAccountArgs;
+use sanctum_solana_test_utils::{test_fixtures_dir, ExtendedBanksClient};
+use solana_program::pubkey::Pubkey;
+use solana_program_test::ProgramTest;
+use solana_sdk::{
+    signature::{read_keypair_file, Keypair},
+    signer::Signer,
+};
+
+use crate::common::{base_cmd, setup, TestCmd};
+
+const MANAGER_KEY_FILE: &str = "s-controller-test-initial-authority-key.json";
+
+#[tokio::test(flavor = "multi_thread")]
+async fn export_then_sign_set_lst_fee() {
+    let lst_mint = Pubkey::new_unique();
+    let manager = read_keypair_file(test_fixtures_dir().join(MANAGER_KEY_FILE))
+        .unwrap()
+        .pubkey();
+    let program_state = ProgramState {
+        manager,
+        lp_withdrawal_fee_bps: Default::default(),
+    };
+
+    let (_cmd, cfg, mut bc, _payer, _rbh) = setup(
+        ProgramTest::default(),
+        Keypair::new(),
+        Some(program_state),
+        &[],
+        &[MockFeeAccountArgs {
+            input_fee_bps: Default::default(),
+            output_fee_bps: Default::default(),
+            lst_mint,
+        }],
+    )
+    .await;
+
+    let Output { stdout, status, .. } = base_cmd(&cfg)
+        .with_flat_fee_program()
+        .cmd_set_lst_fee()
+        .arg("--manager")
+        .arg(manager.to_string())
+        .arg(lst_mint.to_string())
+        .arg("1")
+        .arg("2")
+        .arg("--export")
+        .arg("base58")
+        .output()
+        .unwrap();
+    assert!(status.success());
+    let exported = String::from_utf8(stdout).unwrap();
+
+    let mut cmd = base_cmd(&cfg);
+    cmd.cmd_sign()
+        .arg("--encoding")
+        .arg("base58")
+        .arg("--signer")
+        .arg(test_fixtures_dir().join(MANAGER_KEY_FILE))
+        .arg(exported.trim());
+    let Output { stdout, status, .. } = cmd.output().unwrap();
+    assert!(status.success());
+    let signed = String::from_utf8(stdout).unwrap();
+
+    // dump-msg send mode outputs the signed tx as base64
+    let mut cmd = base_cmd(&cfg);
+    cmd.cmd_send_signed()
+        .arg("--encoding")
+        .arg("base58")
+        .arg(signed.trim());
+    let exec_res = cmd.exec_b64_txs(&mut bc).await;
+    assert_all_txs_success_nonempty(&exec_res);
+
+    let fee_account_addr = FeeAccountFindPdaArgs {
+        program_id: flat_fee_lib::program::ID,
+        lst_mint,
+    }
+    .get_fee_account_address_and_bump_seed()
+    .0;
+    let fee_account_data = bc.get_account_data(fee_account_addr).await;
+    let fee_account = try_fee_account(&fee_account_data).unwrap();
+    assert_eq!(fee_account.input_fee_bps, 1);
+    assert_eq!(fee_account.output_fee_bps, 2);
+}
@@ -1,6 +1,8 @@
 use std::process::Output;
 
 use flat_fee_interface::ProgramState;
+use flat_fee_test_utils::MockFeeAccountArgs;
+use serde_json::Value;
 use solana_program::pubkey::Pubkey;
 use solana_program_test::ProgramTest;
 use solana_sdk::signature::Keypair;
@@ -29,3 +31,91 @@ async fn view_success() {
     assert!(status.success());
     eprintln!("{}", std::str::from_utf8(&stdout).unwrap());
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn view_json_success() {
+    let manager = Pubkey::new_unique();
+    let program_state = ProgramState {
+        manager,
+        lp_withdrawal_fee_bps: 10,
+    };
+
+    let (mut cmd, _cfg, _bc, _payer, _rbh) = setup(
+        ProgramTest::default(),
+        Keypair::new(),
+        Some(program_state),
+        &[],
+        &[MockFeeAccountArgs {
+            input_fee_bps: 1,
+            output_fee_bps: -2,
+            lst_mint: Pubkey::new_unique(),
+        }],
+    )
+    .await;
+
+    cmd.with_flat_fee_program()
+        .cmd_view()
+        .arg("--output")
+        .arg("json");
+
+    let Output { status, stdout, .. } = cmd.output().unwrap();
+    assert!(status.success());
+    let view: Value = serde_json::from_slice(&stdout).unwrap();
+    assert_eq!(view["program_state"]["manager"], manager.to_string());
+    assert_eq!(view["program_state"]["lp_withdrawal_fee_bps"], 10);
+    let fee_accounts = view["fee_accounts"].as_array().unwrap();
+    assert_eq!(fee_accounts.len(), 1);
+    assert_eq!(fee_accounts[0]["lst_mint"], Value::Null);
+    assert_eq!(fee_accounts[0]["input_fee_bps"], 1);
+    assert_eq!(fee_accounts[0]["output_fee_bps"], -2);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn view_csv_success() {
+    let program_state = ProgramState {
+        manager: Pubkey::default(),
+        lp_withdrawal_fee_bps: Default::default(),
+    };
+
+    let (mut cmd, _cfg, _bc, _payer, _rbh) = setup(
+        ProgramTest::default(),
+        Keypair::new(),
+        Some(program_state),
+        &[],
+        &[
+            MockFeeAccountArgs {
+                input_fee_bps: 1,
+                output_fee_bps: 2,
+                lst_mint: Pubkey::new_unique(),
+            },
+            MockFeeAccountArgs {
+                input_fee_bps: 3,
+                output_fee_bps: 4,
+                lst_mint: Pubkey::new_unique(),
+            },
+        ],
+    )
+    .await;
+
+    cmd.with_flat_fee_program()
+        .cmd_view()
+        .arg("--output")
+        .arg("csv");
+
+    let Output { status, stdout, .. } = cmd.output().unwrap();
+    assert!(status.success());
+    let stdout = String::from_utf8(stdout).unwrap();
+    let mut lines = stdout.lines();
+    assert_eq!(
+        lines.next().unwrap(),
+        "address,lst_mint,symbol,input_fee_bps,output_fee_bps"
+    );
+    let mut fees: Vec<(&str, &str)> = lines
+        .map(|l| {
+            let row: Vec<&str> = l.split(',').collect();
+            (row[3], row[4])
+        })
+        .collect();
+    fees.sort();
+    assert_eq!(fees, vec![("1", "2"), ("3", "4")]);
+}
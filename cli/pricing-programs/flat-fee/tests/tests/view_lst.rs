@@ -2,6 +2,7 @@ use std::process::Output;
 
 use flat_fee_interface::ProgramState;
 use flat_fee_test_utils::MockFeeAccountArgs;
+use serde_json::Value;
 use solana_program::pubkey::Pubkey;
 use solana_program_test::ProgramTest;
 use solana_sdk::signature::Keypair;
@@ -38,3 +39,40 @@ async fn view_lst_success() {
     assert!(status.success());
     eprintln!("{}", std::str::from_utf8(&stdout).unwrap());
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn view_lst_json_success() {
+    let lst_mint: Pubkey = Pubkey::new_unique();
+
+    let program_state = ProgramState {
+        manager: Pubkey::default(),
+        lp_withdrawal_fee_bps: Default::default(),
+    };
+
+    let (mut cmd, _cfg, _bc, _payer, _rbh) = setup(
+        ProgramTest::default(),
+        Keypair::new(),
+        Some(program_state),
+        &[],
+        &[MockFeeAccountArgs {
+            input_fee_bps: 5,
+            output_fee_bps: 6,
+            lst_mint,
+        }],
+    )
+    .await;
+
+    cmd.with_flat_fee_program()
+        .cmd_view_lst()
+        .arg("--output")
+        .arg("json")
+        .arg(lst_mint.to_string());
+
+    let Output { status, stdout, .. } = cmd.output().unwrap();
+    assert!(status.success());
+    let fee_account: Value = serde_json::from_slice(&stdout).unwrap();
+    assert_eq!(fee_account["lst_mint"], lst_mint.to_string());
+    assert_eq!(fee_account["symbol"], Value::Null);
+    assert_eq!(fee_account["input_fee_bps"], 5);
+    assert_eq!(fee_account["output_fee_bps"], 6);
+}
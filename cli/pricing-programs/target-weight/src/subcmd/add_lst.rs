@@ -0,0 +1,110 @@
+use clap::{
+    builder::{StringValueParser, TypedValueParser},
+    Args,
+};
+use sanctum_solana_cli_utils::{parse_signer, TxSendingNonblockingRpcClient};
+use solana_readonly_account::sdk::KeyedAccount;
+use solana_sdk::{
+    compute_budget::ComputeBudgetInstruction,
+    message::{v0::Message, VersionedMessage},
+    transaction::VersionedTransaction,
+};
+use target_weight_interface::{add_lst_ix_with_program_id, AddLstIxArgs};
+use target_weight_lib::{
+    account_resolvers::AddLstFreeArgs, pda::ProgramStateFindPdaArgs, utils::try_program_state,
+    ADD_LST_IX_COMPUTE_UNIT_CEIL,
+};
+
+use crate::lst_arg::LstArg;
+
+use super::{common::verify_manager, Subcmd};
+
+#[derive(Args, Debug)]
+#[command(long_about = "Enable an LST to be supported by the target-weight pricing program")]
+pub struct AddLstArgs {
+    #[arg(
+        long,
+        short,
+        help = "The program's current manager signer. Defaults to config wallet if not set."
+    )]
+    pub manager: Option<String>,
+
+    #[arg(
+        help = "Mint of the new LST to add. Can either be a pubkey or case-insensitive symbol of a token on sanctum-lst-list. e.g. 'bsol'",
+        value_parser = StringValueParser::new().try_map(|s| LstArg::parse_arg(&s)),
+    )]
+    pub lst_mint: LstArg,
+
+    #[arg(help = "Target allocation weight of the LST in the pool, in bips")]
+    pub target_weight_bps: u16,
+}
+
+impl AddLstArgs {
+    pub async fn run(args: crate::Args) {
+        let Self {
+            manager,
+            lst_mint,
+            target_weight_bps,
+        } = match args.subcmd {
+            Subcmd::AddLst(a) => a,
+            _ => unreachable!(),
+        };
+        let payer = args.config.signer();
+        let rpc = args.config.nonblocking_rpc_client();
+        let program_id = args.program;
+
+        let manager_signer = manager.map(|s| parse_signer(&s).unwrap());
+        let manager = manager_signer.as_ref().unwrap_or(&payer);
+
+        let state_pda = ProgramStateFindPdaArgs { program_id }
+            .get_program_state_address_and_bump_seed()
+            .0;
+        let state_acc = rpc.get_account(&state_pda).await.unwrap();
+        let state = try_program_state(&state_acc.data).unwrap();
+        verify_manager(state, manager.pubkey()).unwrap();
+
+        let ix = add_lst_ix_with_program_id(
+            program_id,
+            AddLstFreeArgs {
+                payer: payer.pubkey(),
+                state_acc: KeyedAccount {
+                    pubkey: state_pda,
+                    account: state_acc,
+                },
+                lst_mint: lst_mint.mint(),
+            }
+            .resolve_for_prog(program_id)
+            .unwrap()
+            .0,
+            AddLstIxArgs { target_weight_bps },
+        )
+        .unwrap();
+
+        let mut signers = vec![payer.as_ref(), manager.as_ref()];
+        signers.dedup();
+
+        let rbh = rpc.get_latest_blockhash().await.unwrap();
+        let tx = VersionedTransaction::try_new(
+            VersionedMessage::V0(
+                Message::try_compile(
+                    &payer.pubkey(),
+                    &[
+                        ComputeBudgetInstruction::set_compute_unit_limit(
+                            ADD_LST_IX_COMPUTE_UNIT_CEIL,
+                        ),
+                        // TODO: make compute unit price dynamic
+                        ComputeBudgetInstruction::set_compute_unit_price(33),
+                        ix,
+                    ],
+                    &[],
+                    rbh,
+                )
+                .unwrap(),
+            ),
+            &signers,
+        )
+        .unwrap();
+
+        rpc.handle_tx(&tx, args.send_mode).await;
+    }
+}
@@ -0,0 +1,14 @@
+use solana_sdk::pubkey::Pubkey;
+use std::convert::Infallible;
+use target_weight_interface::ProgramState;
+
+pub fn verify_manager(state: &ProgramState, curr_manager: Pubkey) -> Result<(), Infallible> {
+    if state.manager != curr_manager {
+        eprintln!(
+            "Wrong manager. Expected: {}. Got: {}",
+            state.manager, curr_manager
+        );
+        std::process::exit(-1);
+    }
+    Ok(())
+}
@@ -0,0 +1,51 @@
+use clap::Subcommand;
+
+mod add_lst;
+mod common;
+mod initialize;
+mod remove_lst;
+mod set_fee_params;
+mod set_lst_weight;
+mod set_manager;
+mod set_pool;
+mod view;
+mod view_lst;
+
+use add_lst::AddLstArgs;
+use initialize::InitializeArgs;
+use remove_lst::RemoveLstArgs;
+use set_fee_params::SetFeeParamsArgs;
+use set_lst_weight::SetLstWeightArgs;
+use set_manager::SetManagerArgs;
+use set_pool::SetPoolArgs;
+
+use self::{view::ViewArgs, view_lst::ViewLstArgs};
+
+#[derive(Debug, Subcommand)]
+pub enum Subcmd {
+    Initialize,
+    SetManager(SetManagerArgs),
+    SetPool(SetPoolArgs),
+    AddLst(AddLstArgs),
+    RemoveLst(RemoveLstArgs),
+    SetLstWeight(SetLstWeightArgs),
+    SetFeeParams(SetFeeParamsArgs),
+    View(ViewArgs),
+    ViewLst(ViewLstArgs),
+}
+
+impl Subcmd {
+    pub async fn run(args: crate::Args) {
+        match &args.subcmd {
+            Self::Initialize => InitializeArgs::run(args).await,
+            Self::SetManager(_) => SetManagerArgs::run(args).await,
+            Self::SetPool(_) => SetPoolArgs::run(args).await,
+            Self::AddLst(_) => AddLstArgs::run(args).await,
+            Self::RemoveLst(_) => RemoveLstArgs::run(args).await,
+            Self::SetLstWeight(_) => SetLstWeightArgs::run(args).await,
+            Self::SetFeeParams(_) => SetFeeParamsArgs::run(args).await,
+            Self::View(_) => ViewArgs::run(args).await,
+            Self::ViewLst(_) => ViewLstArgs::run(args).await,
+        }
+    }
+}
@@ -0,0 +1,129 @@
+use clap::Args;
+use sanctum_solana_cli_utils::{parse_signer, TxSendingNonblockingRpcClient};
+use solana_readonly_account::sdk::KeyedAccount;
+use solana_sdk::{
+    compute_budget::ComputeBudgetInstruction,
+    message::{v0::Message, VersionedMessage},
+    transaction::VersionedTransaction,
+};
+use target_weight_interface::{set_fee_params_ix_with_program_id, SetFeeParamsIxArgs};
+use target_weight_lib::{
+    account_resolvers::SetFeeParamsFreeArgs, pda::ProgramStateFindPdaArgs,
+    utils::try_program_state, SET_FEE_PARAMS_COMPUTE_UNIT_CEIL,
+};
+
+use super::{common::verify_manager, Subcmd};
+
+#[derive(Args, Debug)]
+#[command(
+    long_about = "Update the swap fee params and the fees imposed for redeeming LP token for LST"
+)]
+pub struct SetFeeParamsArgs {
+    #[arg(
+        long,
+        short,
+        help = "The program's current manager signer. Defaults to config wallet if not set."
+    )]
+    pub manager: Option<String>,
+
+    #[arg(help = "Fee in bips to impose when redeeming LP token for LST")]
+    pub lp_withdrawal_fee_bps: u16,
+
+    #[arg(
+        help = "Swap fee in bips to impose when a swap leaves the pool's balance unchanged",
+        allow_negative_numbers = true
+    )]
+    pub base_fee_bps: i16,
+
+    #[arg(
+        help = "Lower bound of the swap fee in bips. Negative values allow rebates",
+        allow_negative_numbers = true
+    )]
+    pub min_fee_bps: i16,
+
+    #[arg(
+        help = "Upper bound of the swap fee in bips",
+        allow_negative_numbers = true
+    )]
+    pub max_fee_bps: i16,
+
+    #[arg(
+        help = "Swap fee in bips added per 100% of combined deviation from the input and output LSTs' target weights"
+    )]
+    pub imbalance_fee_bps: u16,
+}
+
+impl SetFeeParamsArgs {
+    pub async fn run(args: crate::Args) {
+        let Self {
+            manager,
+            lp_withdrawal_fee_bps,
+            base_fee_bps,
+            min_fee_bps,
+            max_fee_bps,
+            imbalance_fee_bps,
+        } = match args.subcmd {
+            Subcmd::SetFeeParams(a) => a,
+            _ => unreachable!(),
+        };
+        let payer = args.config.signer();
+        let rpc = args.config.nonblocking_rpc_client();
+        let program_id = args.program;
+
+        let manager_signer = manager.map(|s| parse_signer(&s).unwrap());
+        let manager = manager_signer.as_ref().unwrap_or(&payer);
+
+        let state_pda = ProgramStateFindPdaArgs { program_id }
+            .get_program_state_address_and_bump_seed()
+            .0;
+        let state_acc = rpc.get_account(&state_pda).await.unwrap();
+        let state = try_program_state(&state_acc.data).unwrap();
+        verify_manager(state, manager.pubkey()).unwrap();
+
+        let ix = set_fee_params_ix_with_program_id(
+            program_id,
+            SetFeeParamsFreeArgs {
+                state_acc: KeyedAccount {
+                    pubkey: state_pda,
+                    account: state_acc,
+                },
+            }
+            .resolve_for_prog(program_id)
+            .unwrap(),
+            SetFeeParamsIxArgs {
+                lp_withdrawal_fee_bps,
+                base_fee_bps,
+                min_fee_bps,
+                max_fee_bps,
+                imbalance_fee_bps,
+            },
+        )
+        .unwrap();
+
+        let mut signers = vec![payer.as_ref(), manager.as_ref()];
+        signers.dedup();
+
+        let rbh = rpc.get_latest_blockhash().await.unwrap();
+        let tx = VersionedTransaction::try_new(
+            VersionedMessage::V0(
+                Message::try_compile(
+                    &payer.pubkey(),
+                    &[
+                        ComputeBudgetInstruction::set_compute_unit_limit(
+                            SET_FEE_PARAMS_COMPUTE_UNIT_CEIL,
+                        ),
+                        ComputeBudgetInstruction::set_compute_unit_price(100),
+                        ix,
+                    ],
+                    &[],
+                    rbh,
+                )
+                .unwrap(),
+            ),
+            &signers,
+        )
+        .unwrap();
+
+        rpc.handle_tx(&tx, args.send_mode).await;
+    }
+}
@@ -0,0 +1,92 @@
+use clap::{
+    builder::{StringValueParser, TypedValueParser},
+    Args,
+};
+use sanctum_solana_cli_utils::{parse_signer, TxSendingNonblockingRpcClient};
+use solana_readonly_account::sdk::KeyedAccount;
+use solana_sdk::{
+    message::{v0::Message, VersionedMessage},
+    transaction::VersionedTransaction,
+};
+use target_weight_interface::{set_lst_weight_ix_with_program_id, SetLstWeightIxArgs};
+use target_weight_lib::{
+    account_resolvers::SetLstWeightByMintFreeArgs, pda::ProgramStateFindPdaArgs,
+    utils::try_program_state,
+};
+
+use crate::lst_arg::LstArg;
+
+use super::{common::verify_manager, Subcmd};
+
+#[derive(Args, Debug)]
+#[command(long_about = "Update the target allocation weight for given LST")]
+pub struct SetLstWeightArgs {
+    #[arg(
+        long,
+        short,
+        help = "The program's current manager signer. Defaults to config wallet if not set."
+    )]
+    pub manager: Option<String>,
+
+    #[arg(
+        help = "Mint of the LST to set the target weight for. Can either be a pubkey or case-insensitive symbol of a token on sanctum-lst-list. e.g. 'bsol'",
+        value_parser = StringValueParser::new().try_map(|s| LstArg::parse_arg(&s)),
+    )]
+    pub lst_mint: LstArg,
+
+    #[arg(help = "New target allocation weight of the LST in the pool, in bips")]
+    pub target_weight_bps: u16,
+}
+
+impl SetLstWeightArgs {
+    pub async fn run(args: crate::Args) {
+        let Self {
+            manager,
+            lst_mint,
+            target_weight_bps,
+        } = match args.subcmd {
+            Subcmd::SetLstWeight(a) => a,
+            _ => unreachable!(),
+        };
+        let payer = args.config.signer();
+        let rpc = args.config.nonblocking_rpc_client();
+        let program_id = args.program;
+
+        let manager_signer = manager.map(|s| parse_signer(&s).unwrap());
+        let manager = manager_signer.as_ref().unwrap_or(&payer);
+
+        let state_pda = ProgramStateFindPdaArgs { program_id }
+            .get_program_state_address_and_bump_seed()
+            .0;
+        let state_acc = rpc.get_account(&state_pda).await.unwrap();
+        let state = try_program_state(&state_acc.data).unwrap();
+        verify_manager(state, manager.pubkey()).unwrap();
+
+        let ix = set_lst_weight_ix_with_program_id(
+            program_id,
+            SetLstWeightByMintFreeArgs {
+                lst_mint: lst_mint.mint(),
+                state_acc: KeyedAccount {
+                    pubkey: state_pda,
+                    account: state_acc,
+                },
+            }
+            .resolve_for_prog(program_id)
+            .unwrap(),
+            SetLstWeightIxArgs { target_weight_bps },
+        )
+        .unwrap();
+
+        let mut signers = vec![payer.as_ref(), manager.as_ref()];
+        signers.dedup();
+
+        let rbh = rpc.get_latest_blockhash().await.unwrap();
+        let tx = VersionedTransaction::try_new(
+            VersionedMessage::V0(Message::try_compile(&payer.pubkey(), &[ix], &[], rbh).unwrap()),
+            &signers,
+        )
+        .unwrap();
+
+        rpc.handle_tx(&tx, args.send_mode).await;
+    }
+}
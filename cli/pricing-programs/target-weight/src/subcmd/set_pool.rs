@@ -0,0 +1,104 @@
+use std::str::FromStr;
+
+use clap::{
+    builder::{StringValueParser, TypedValueParser},
+    Args,
+};
+use s_controller_lib::{Pool, PoolId};
+use sanctum_solana_cli_utils::{parse_signer, TxSendingNonblockingRpcClient};
+use solana_readonly_account::sdk::KeyedAccount;
+use solana_sdk::{
+    message::{v0::Message, VersionedMessage},
+    pubkey::Pubkey,
+    transaction::VersionedTransaction,
+};
+use target_weight_interface::set_pool_ix_with_program_id;
+use target_weight_lib::{
+    account_resolvers::SetPoolFreeArgs, pda::ProgramStateFindPdaArgs, utils::try_program_state,
+};
+
+use super::{common::verify_manager, Subcmd};
+
+#[derive(Args, Debug)]
+#[command(
+    long_about = "Sets the S controller pool whose reserves the program prices swaps against"
+)]
+pub struct SetPoolArgs {
+    #[arg(
+        long,
+        short,
+        help = "The program's current manager signer. Defaults to config wallet if not set."
+    )]
+    pub manager: Option<String>,
+
+    #[arg(
+        long,
+        help = "Program ID of the S controller program",
+        default_value_t = s_controller_lib::program::ID,
+        value_parser = StringValueParser::new().try_map(|s| Pubkey::from_str(&s)),
+    )]
+    pub s_program: Pubkey,
+
+    #[arg(
+        long,
+        short,
+        help = "LP token mint of the pool to price for. Defaults to the S controller program's default pool if not set.",
+        value_parser = StringValueParser::new().try_map(|s| Pubkey::from_str(&s)),
+    )]
+    pub pool: Option<Pubkey>,
+}
+
+impl SetPoolArgs {
+    pub async fn run(args: crate::Args) {
+        let Self {
+            manager,
+            s_program,
+            pool,
+        } = match args.subcmd {
+            Subcmd::SetPool(a) => a,
+            _ => unreachable!(),
+        };
+        let payer = args.config.signer();
+        let rpc = args.config.nonblocking_rpc_client();
+        let program_id = args.program;
+
+        let manager_signer = manager.map(|s| parse_signer(&s).unwrap());
+        let manager = manager_signer.as_ref().unwrap_or(&payer);
+
+        let pool_id = pool.map_or(PoolId::Default, PoolId::Keyed);
+        let pool_state = Pool::find_for_prog(s_program, pool_id).pdas.pool_state;
+
+        let state_pda = ProgramStateFindPdaArgs { program_id }
+            .get_program_state_address_and_bump_seed()
+            .0;
+        let state_acc = rpc.get_account(&state_pda).await.unwrap();
+        let state = try_program_state(&state_acc.data).unwrap();
+        verify_manager(state, manager.pubkey()).unwrap();
+
+        let ix = set_pool_ix_with_program_id(
+            program_id,
+            SetPoolFreeArgs {
+                pool_state,
+                state_acc: KeyedAccount {
+                    pubkey: state_pda,
+                    account: state_acc,
+                },
+            }
+            .resolve_for_prog(program_id)
+            .unwrap(),
+        )
+        .unwrap();
+
+        let mut signers = vec![payer.as_ref(), manager.as_ref()];
+        signers.dedup();
+
+        let rbh = rpc.get_latest_blockhash().await.unwrap();
+        let tx = VersionedTransaction::try_new(
+            VersionedMessage::V0(Message::try_compile(&payer.pubkey(), &[ix], &[], rbh).unwrap()),
+            &signers,
+        )
+        .unwrap();
+
+        rpc.handle_tx(&tx, args.send_mode).await;
+    }
+}
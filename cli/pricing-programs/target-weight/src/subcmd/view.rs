@@ -0,0 +1,73 @@
+use std::collections::HashMap;
+
+use clap::Args;
+use sanctum_lst_list::SanctumLst;
+use solana_sdk::pubkey::Pubkey;
+use target_weight_lib::{
+    pda::{ProgramStateFindPdaArgs, WeightAccountFindPdaArgs},
+    utils::{try_program_state, try_weight_account},
+};
+
+use crate::lst_arg::SANCTUM_LST_LIST;
+
+use super::Subcmd;
+
+#[derive(Args, Debug)]
+#[command(
+    long_about = "Views target-weight pricing program's program state and all weight accounts"
+)]
+pub struct ViewArgs;
+
+impl ViewArgs {
+    pub async fn run(args: crate::Args) {
+        let Self = match args.subcmd {
+            Subcmd::View(a) => a,
+            _ => unreachable!(),
+        };
+
+        let rpc = args.config.nonblocking_rpc_client();
+        let program_id = args.program;
+
+        let state_pda = ProgramStateFindPdaArgs { program_id }
+            .get_program_state_address_and_bump_seed()
+            .0;
+
+        let pda_to_lst: HashMap<Pubkey, &'static SanctumLst> = SANCTUM_LST_LIST
+            .sanctum_lst_list
+            .iter()
+            .map(|lst| {
+                (
+                    WeightAccountFindPdaArgs {
+                        program_id,
+                        lst_mint: lst.mint,
+                    }
+                    .get_weight_account_address_and_bump_seed()
+                    .0,
+                    lst,
+                )
+            })
+            .collect();
+
+        let mut program_accs = rpc.get_program_accounts(&program_id).await.unwrap();
+        program_accs.retain(|(pk, acc)| {
+            if *pk == state_pda {
+                let state = try_program_state(&acc.data).unwrap();
+                println!("{state:#?}");
+                println!();
+                false
+            } else {
+                true
+            }
+        });
+
+        for (pk, acc) in program_accs {
+            let symbol = pda_to_lst
+                .get(&pk)
+                .map_or_else(|| "Unknown LST", |SanctumLst { symbol, .. }| symbol);
+            println!("{symbol}:");
+            let weight = try_weight_account(&acc.data).unwrap();
+            println!("{weight:#?}");
+            println!();
+        }
+    }
+}
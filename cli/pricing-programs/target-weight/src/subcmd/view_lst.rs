@@ -0,0 +1,40 @@
+use clap::{
+    builder::{StringValueParser, TypedValueParser},
+    Args,
+};
+use target_weight_lib::{pda::WeightAccountFindPdaArgs, utils::try_weight_account};
+
+use crate::{lst_arg::LstArg, subcmd::Subcmd};
+
+#[derive(Args, Debug)]
+#[command(long_about = "View the current target weight for a given LST")]
+pub struct ViewLstArgs {
+    #[arg(
+        help = "Mint of the LST to view the target weight for. Can either be a pubkey or case-insensitive symbol of a token on sanctum-lst-list. e.g. 'bsol'",
+        value_parser = StringValueParser::new().try_map(|s| LstArg::parse_arg(&s)),
+    )]
+    pub lst_mint: LstArg,
+}
+
+impl ViewLstArgs {
+    pub async fn run(args: crate::Args) {
+        let Self { lst_mint } = match args.subcmd {
+            Subcmd::ViewLst(a) => a,
+            _ => unreachable!(),
+        };
+
+        let rpc = args.config.nonblocking_rpc_client();
+        let program_id = args.program;
+
+        let weight_account_pda = WeightAccountFindPdaArgs {
+            program_id,
+            lst_mint: lst_mint.mint(),
+        }
+        .get_weight_account_address_and_bump_seed()
+        .0;
+        let weight_account_data = rpc.get_account_data(&weight_account_pda).await.unwrap();
+        let weight_account = try_weight_account(&weight_account_data).unwrap();
+
+        println!("{weight_account:#?}");
+    }
+}
@@ -0,0 +1,124 @@
+use assert_cmd::Command;
+use cli_test_utils::TestCliCmd;
+use sanctum_solana_test_utils::{
+    banks_rpc_server::BanksRpcServer,
+    cli::TempCliConfig,
+    token::{tokenkeg::TokenkegProgramTest, MockMintArgs},
+    ExtendedProgramTest, IntoAccount,
+};
+use solana_program::pubkey::Pubkey;
+use solana_program_test::{processor, BanksClient, ProgramTest};
+use solana_sdk::{hash::Hash, signature::Keypair, signer::Signer};
+use target_weight_interface::ProgramState;
+
+use super::{MockProgramState, MockWeightAccount, MockWeightAccountArgs};
+
+fn add_target_weight_program(mut pt: ProgramTest) -> ProgramTest {
+    pt.add_program(
+        "target_weight",
+        target_weight_lib::program::ID,
+        processor!(target_weight::entrypoint::process_instruction),
+    );
+    pt
+}
+
+fn add_mock_mint(pt: ProgramTest, mint: Pubkey) -> ProgramTest {
+    pt.add_tokenkeg_mint_from_args(
+        mint,
+        MockMintArgs {
+            mint_authority: None,
+            freeze_authority: None,
+            supply: 0,
+            decimals: 9,
+        },
+    )
+}
+
+// setup program test
+// - `program_state`         sets target weight program state up
+// - `mock_mints`            sets token mint up
+// - `mock_weight_accounts`  sets token mint up and set mock weight account
+// - funds given payer and set it as default cli keypair in temporary config
+pub async fn setup(
+    pt: ProgramTest,
+    payer: Keypair,
+    program_state: Option<ProgramState>,
+    mock_mints: &[Pubkey],
+    mock_weight_accounts: &[MockWeightAccountArgs],
+) -> (Command, TempCliConfig, BanksClient, Keypair, Hash) {
+    let mut pt = add_target_weight_program(pt).add_system_account(payer.pubkey(), 1_000_000_000);
+
+    if let Some(program_state) = program_state {
+        pt.add_account(
+            target_weight_lib::program::STATE_ID,
+            MockProgramState(program_state).into_account(),
+        );
+    }
+
+    for &mint in mock_mints {
+        pt = add_mock_mint(pt, mint);
+    }
+
+    for mwa in mock_weight_accounts {
+        pt = add_mock_mint(pt, mwa.lst_mint);
+        let (acc, addr) = mwa.to_weight_account_and_addr();
+        pt.add_account(addr, MockWeightAccount(acc).into_account())
+    }
+
+    let (bc, _rng_payer, rbh) = pt.start().await;
+
+    let (port, _jh) = BanksRpcServer::spawn_random_unused(bc.clone()).await;
+    let cfg = TempCliConfig::from_keypair_and_local_port(&payer, port);
+    let cmd = base_cmd(&cfg);
+    (cmd, cfg, bc, payer, rbh)
+}
+
+pub fn cargo_bin() -> Command {
+    Command::cargo_bin("tgw").unwrap()
+}
+
+fn base_cmd(cfg: &TempCliConfig) -> Command {
+    let mut cmd = cargo_bin();
+    cmd.with_send_mode_dump_msg().with_cfg_temp_cli(cfg);
+    cmd
+}
+
+pub trait TestCmd {
+    fn with_target_weight_program(&mut self) -> &mut Self;
+
+    fn cmd_initialize(&mut self) -> &mut Self;
+
+    fn cmd_set_pool(&mut self) -> &mut Self;
+
+    fn cmd_set_fee_params(&mut self) -> &mut Self;
+
+    fn cmd_add_lst(&mut self) -> &mut Self;
+
+    fn cmd_set_lst_weight(&mut self) -> &mut Self;
+}
+
+impl TestCmd for Command {
+    fn with_target_weight_program(&mut self) -> &mut Self {
+        self.arg(target_weight_lib::program::ID_STR)
+    }
+
+    fn cmd_initialize(&mut self) -> &mut Self {
+        self.arg("initialize")
+    }
+
+    fn cmd_set_pool(&mut self) -> &mut Self {
+        self.arg("set-pool")
+    }
+
+    fn cmd_set_fee_params(&mut self) -> &mut Self {
+        self.arg("set-fee-params")
+    }
+
+    fn cmd_add_lst(&mut self) -> &mut Self {
+        self.arg("add-lst")
+    }
+
+    fn cmd_set_lst_weight(&mut self) -> &mut Self {
+        self.arg("set-lst-weight")
+    }
+}
@@ -0,0 +1,86 @@
+use sanctum_solana_test_utils::{est_rent_exempt_lamports, IntoAccount};
+use solana_program::pubkey::Pubkey;
+use solana_sdk::account::Account;
+use target_weight_interface::{ProgramState, WeightAccount};
+use target_weight_lib::{
+    initial_constants::{
+        INITIAL_BASE_FEE_BPS, INITIAL_IMBALANCE_FEE_BPS, INITIAL_LP_WITHDRAWAL_FEE_BPS,
+        INITIAL_MAX_FEE_BPS, INITIAL_MIN_FEE_BPS,
+    },
+    pda::WeightAccountFindPdaArgs,
+    program::{STATE_SIZE, WEIGHT_ACCOUNT_SIZE},
+    utils::{try_program_state_mut, try_weight_account_mut},
+};
+
+/// Program state with the initial fee params managed by `manager`,
+/// pricing for the s-controller's default pool with no LSTs added
+pub fn program_state_with_manager(manager: Pubkey) -> ProgramState {
+    ProgramState {
+        manager,
+        pool_state: s_controller_lib::program::POOL_STATE_ID,
+        lst_state_list: s_controller_lib::program::LST_STATE_LIST_ID,
+        lp_withdrawal_fee_bps: INITIAL_LP_WITHDRAWAL_FEE_BPS,
+        base_fee_bps: INITIAL_BASE_FEE_BPS,
+        min_fee_bps: INITIAL_MIN_FEE_BPS,
+        max_fee_bps: INITIAL_MAX_FEE_BPS,
+        imbalance_fee_bps: INITIAL_IMBALANCE_FEE_BPS,
+        total_target_weight_bps: 0,
+    }
+}
+
+pub struct MockProgramState(pub ProgramState);
+
+impl IntoAccount for MockProgramState {
+    fn into_account(self) -> Account {
+        let mut data = vec![0u8; STATE_SIZE];
+        let dst = try_program_state_mut(&mut data).unwrap();
+        *dst = self.0;
+        Account {
+            lamports: est_rent_exempt_lamports(STATE_SIZE),
+            data,
+            owner: target_weight_lib::program::ID,
+            executable: false,
+            rent_epoch: u64::MAX,
+        }
+    }
+}
+
+pub struct MockWeightAccount(pub WeightAccount);
+
+impl IntoAccount for MockWeightAccount {
+    fn into_account(self) -> Account {
+        let mut data = vec![0u8; WEIGHT_ACCOUNT_SIZE];
+        let dst = try_weight_account_mut(&mut data).unwrap();
+        *dst = self.0;
+        Account {
+            lamports: est_rent_exempt_lamports(WEIGHT_ACCOUNT_SIZE),
+            data,
+            owner: target_weight_lib::program::ID,
+            executable: false,
+            rent_epoch: u64::MAX,
+        }
+    }
+}
+
+pub struct MockWeightAccountArgs {
+    pub target_weight_bps: u16,
+    pub lst_mint: Pubkey,
+}
+
+impl MockWeightAccountArgs {
+    pub fn to_weight_account_and_addr(&self) -> (WeightAccount, Pubkey) {
+        let (addr, bump) = WeightAccountFindPdaArgs {
+            lst_mint: self.lst_mint,
+            program_id: target_weight_lib::program::ID,
+        }
+        .get_weight_account_address_and_bump_seed();
+        (
+            WeightAccount {
+                bump,
+                padding: 0u8,
+                target_weight_bps: self.target_weight_bps,
+            },
+            addr,
+        )
+    }
+}
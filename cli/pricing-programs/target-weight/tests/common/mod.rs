@@ -0,0 +1,5 @@
+mod cmd;
+mod mock;
+
+pub use cmd::*;
+pub use mock::*;
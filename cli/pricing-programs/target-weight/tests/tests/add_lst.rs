@@ -0,0 +1,43 @@
+use cli_test_utils::{assert_all_txs_success_nonempty, TestCliCmd};
+use solana_program::pubkey::Pubkey;
+use solana_program_test::ProgramTest;
+use solana_sdk::{signature::Keypair, signer::Signer};
+use target_weight_lib::{pda::WeightAccountFindPdaArgs, utils::try_weight_account};
+
+use crate::common::{program_state_with_manager, setup, TestCmd};
+
+#[tokio::test(flavor = "multi_thread")]
+async fn add_lst_success() {
+    const TARGET_WEIGHT_BPS: u16 = 2_500;
+
+    let lst_mint = Pubkey::new_unique();
+    let payer = Keypair::new();
+
+    let program_state = program_state_with_manager(payer.pubkey());
+
+    let (mut cmd, _cfg, mut bc, _payer, _rbh) = setup(
+        ProgramTest::default(),
+        payer,
+        Some(program_state),
+        &[lst_mint],
+        &[],
+    )
+    .await;
+
+    cmd.with_target_weight_program()
+        .cmd_add_lst()
+        .arg(lst_mint.to_string())
+        .arg(TARGET_WEIGHT_BPS.to_string());
+
+    let exec_res = cmd.exec_b64_txs(&mut bc).await;
+    assert_all_txs_success_nonempty(&exec_res);
+
+    let (weight_acc_addr, _bump) = WeightAccountFindPdaArgs {
+        program_id: target_weight_lib::program::ID,
+        lst_mint,
+    }
+    .get_weight_account_address_and_bump_seed();
+    let weight_acc = bc.get_account(weight_acc_addr).await.unwrap().unwrap();
+    let weight_acc = try_weight_account(&weight_acc.data).unwrap();
+    assert_eq!(weight_acc.target_weight_bps, TARGET_WEIGHT_BPS);
+}
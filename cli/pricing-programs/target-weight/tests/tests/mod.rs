@@ -0,0 +1,4 @@
+mod add_lst;
+mod initialize;
+mod set_fee_params;
+mod set_lst_weight;
@@ -0,0 +1,45 @@
+use cli_test_utils::{assert_all_txs_success_nonempty, TestCliCmd};
+use solana_program_test::ProgramTest;
+use solana_sdk::{signature::Keypair, signer::Signer};
+use target_weight_lib::utils::try_program_state;
+
+use crate::common::{program_state_with_manager, setup, TestCmd};
+
+#[tokio::test(flavor = "multi_thread")]
+async fn set_fee_params_success() {
+    const LP_WITHDRAWAL_FEE_BPS: u16 = 10;
+    const BASE_FEE_BPS: i16 = 5;
+    const MIN_FEE_BPS: i16 = -5;
+    const MAX_FEE_BPS: i16 = 200;
+    const IMBALANCE_FEE_BPS: u16 = 300;
+
+    let payer = Keypair::new();
+
+    let program_state = program_state_with_manager(payer.pubkey());
+
+    let (mut cmd, _cfg, mut bc, _payer, _rbh) =
+        setup(ProgramTest::default(), payer, Some(program_state), &[], &[]).await;
+
+    cmd.with_target_weight_program()
+        .cmd_set_fee_params()
+        .arg(LP_WITHDRAWAL_FEE_BPS.to_string())
+        .arg(BASE_FEE_BPS.to_string())
+        .arg(MIN_FEE_BPS.to_string())
+        .arg(MAX_FEE_BPS.to_string())
+        .arg(IMBALANCE_FEE_BPS.to_string());
+
+    let exec_res = cmd.exec_b64_txs(&mut bc).await;
+    assert_all_txs_success_nonempty(&exec_res);
+
+    let state = bc
+        .get_account(target_weight_lib::program::STATE_ID)
+        .await
+        .unwrap()
+        .unwrap();
+    let state = try_program_state(&state.data).unwrap();
+    assert_eq!(state.lp_withdrawal_fee_bps, LP_WITHDRAWAL_FEE_BPS);
+    assert_eq!(state.base_fee_bps, BASE_FEE_BPS);
+    assert_eq!(state.min_fee_bps, MIN_FEE_BPS);
+    assert_eq!(state.max_fee_bps, MAX_FEE_BPS);
+    assert_eq!(state.imbalance_fee_bps, IMBALANCE_FEE_BPS);
+}
@@ -0,0 +1,56 @@
+use cli_test_utils::{assert_all_txs_success_nonempty, TestCliCmd};
+use solana_program::pubkey::Pubkey;
+use solana_program_test::ProgramTest;
+use solana_sdk::{signature::Keypair, signer::Signer};
+use target_weight_interface::ProgramState;
+use target_weight_lib::utils::{try_program_state, try_weight_account};
+
+use crate::common::{program_state_with_manager, setup, MockWeightAccountArgs, TestCmd};
+
+#[tokio::test(flavor = "multi_thread")]
+async fn set_lst_weight_success() {
+    const INITIAL_TARGET_WEIGHT_BPS: u16 = 2_500;
+    const NEW_TARGET_WEIGHT_BPS: u16 = 4_000;
+
+    let lst_mint = Pubkey::new_unique();
+    let payer = Keypair::new();
+
+    let program_state = ProgramState {
+        total_target_weight_bps: INITIAL_TARGET_WEIGHT_BPS,
+        ..program_state_with_manager(payer.pubkey())
+    };
+    let mock_weight_account = MockWeightAccountArgs {
+        target_weight_bps: INITIAL_TARGET_WEIGHT_BPS,
+        lst_mint,
+    };
+    let (weight_acc_addr, _) = mock_weight_account.to_weight_account_and_addr();
+
+    let (mut cmd, _cfg, mut bc, _payer, _rbh) = setup(
+        ProgramTest::default(),
+        payer,
+        Some(program_state),
+        &[],
+        &[mock_weight_account],
+    )
+    .await;
+
+    cmd.with_target_weight_program()
+        .cmd_set_lst_weight()
+        .arg(lst_mint.to_string())
+        .arg(NEW_TARGET_WEIGHT_BPS.to_string());
+
+    let exec_res = cmd.exec_b64_txs(&mut bc).await;
+    assert_all_txs_success_nonempty(&exec_res);
+
+    let weight_acc = bc.get_account(weight_acc_addr).await.unwrap().unwrap();
+    let weight_acc = try_weight_account(&weight_acc.data).unwrap();
+    assert_eq!(weight_acc.target_weight_bps, NEW_TARGET_WEIGHT_BPS);
+
+    let state = bc
+        .get_account(target_weight_lib::program::STATE_ID)
+        .await
+        .unwrap()
+        .unwrap();
+    let state = try_program_state(&state.data).unwrap();
+    assert_eq!(state.total_target_weight_bps, NEW_TARGET_WEIGHT_BPS);
+}
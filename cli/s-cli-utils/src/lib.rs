@@ -1,3 +1,13 @@
+use std::fmt::Display;
+
+use clap::ValueEnum;
+
+mod offline_tx;
+mod sanctum_lst;
+
+pub use offline_tx::*;
+pub use sanctum_lst::*;
+
 pub const CONFIG_HELP: &str =
     "Path to solana CLI config. Defaults to solana cli default if not provided";
 
@@ -6,3 +16,34 @@ pub const TX_SEND_MODE_HELP: &str = "Transaction send mode.
 - sim-only: simulates the tx against the cluster and outputs logs to stderr
 - dump-msg: dumps the base64 encoded tx to stdout. For use with inspectors and multisigs
 ";
+
+pub const OUTPUT_FORMAT_HELP: &str = "Output format.
+- text: human-readable text
+- json: a single JSON object
+- csv: a CSV table with a header row, one row per LST
+";
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    #[default]
+    Text,
+    Json,
+    Csv,
+}
+
+/// Formats `fields` as a single CSV record without the trailing newline,
+/// quoting fields that contain commas, quotes or newlines
+pub fn csv_record<T: Display>(fields: impl IntoIterator<Item = T>) -> String {
+    fields
+        .into_iter()
+        .map(|field| {
+            let field = field.to_string();
+            if field.contains([',', '"', '\n', '\r']) {
+                format!("\"{}\"", field.replace('"', "\"\""))
+            } else {
+                field
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(",")
+}
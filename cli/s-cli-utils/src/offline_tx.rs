@@ -0,0 +1,471 @@
+//! Exporting, signing and combining partially-signed transactions offline,
+//! for authorities whose keypairs are not available to the CLI, e.g. multisigs or cold wallets.
+
+use std::{error::Error, str::FromStr};
+
+use clap::{
+    builder::{StringValueParser, TypedValueParser},
+    Args, ValueEnum,
+};
+use data_encoding::BASE64;
+use sanctum_solana_cli_utils::{parse_signer, TxSendMode, TxSendingNonblockingRpcClient};
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_sdk::{
+    address_lookup_table_account::AddressLookupTableAccount,
+    bs58,
+    hash::Hash,
+    instruction::Instruction,
+    message::{v0::Message, VersionedMessage},
+    nonce::state::{State, Versions},
+    pubkey::Pubkey,
+    signature::Signature,
+    signer::{null_signer::NullSigner, Signer, SignerError},
+    system_instruction,
+    transaction::VersionedTransaction,
+};
+use thiserror::Error;
+
+pub const EXPORT_HELP: &str = "Instead of sending the transaction, export it to stdout in the given encoding.
+Signer args may be set to pubkeys instead of keypairs, their signatures are left empty in the exported transaction.
+Use the `sign` subcommand to add signatures and `send-signed` to combine them and send the transaction.
+Note that the transaction expires with its recent blockhash, ~1 minute after export, unless --nonce is set.";
+
+pub const NONCE_HELP: &str = "Durable nonce account to use as the transaction's blockhash instead of the latest blockhash, so that transactions exported with --export do not expire before they are signed and sent.
+An AdvanceNonceAccount instruction is prepended to each transaction. Since that advances the nonce, only one transaction built with the same nonce can land.";
+
+pub const NONCE_AUTHORITY_HELP: &str =
+    "Authority of the durable nonce account. Can be a pubkey or signer. Defaults to config wallet if not set.";
+
+pub const TX_ENCODING_HELP: &str = "Encoding of the serialized transaction";
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum)]
+pub enum TxEncoding {
+    Base58,
+    #[default]
+    Base64,
+}
+
+#[derive(Debug, Error)]
+pub enum OfflineTxError {
+    #[error("Transaction has invalid {0:?} encoding")]
+    InvalidEncoding(TxEncoding),
+
+    #[error("Transaction could not be serialized or deserialized: {0}")]
+    InvalidTransaction(#[from] bincode::Error),
+
+    #[error("{0} is not a required signer of the transaction")]
+    NotASigner(Pubkey),
+
+    #[error("Signing failed: {0}")]
+    Sign(#[from] SignerError),
+
+    #[error("Transactions to combine have different messages")]
+    MessageMismatch,
+
+    #[error("Transactions to combine have different signatures for {0}")]
+    SignatureMismatch(Pubkey),
+
+    #[error("No transactions to combine")]
+    NoTransactions,
+
+    #[error("{0} is not an initialized nonce account")]
+    InvalidNonceAccount(Pubkey),
+}
+
+impl TxEncoding {
+    pub fn encode_tx(self, tx: &VersionedTransaction) -> Result<String, OfflineTxError> {
+        let bytes = bincode::serialize(tx)?;
+        Ok(match self {
+            Self::Base58 => bs58::encode(bytes).into_string(),
+            Self::Base64 => BASE64.encode(&bytes),
+        })
+    }
+
+    pub fn decode_tx(self, s: &str) -> Result<VersionedTransaction, OfflineTxError> {
+        let bytes = match self {
+            Self::Base58 => bs58::decode(s.trim()).into_vec().ok(),
+            Self::Base64 => BASE64.decode(s.trim().as_bytes()).ok(),
+        }
+        .ok_or(OfflineTxError::InvalidEncoding(self))?;
+        Ok(bincode::deserialize(&bytes)?)
+    }
+}
+
+/// Like [`parse_signer`], but a pubkey arg is parsed into a [`NullSigner`]
+/// that leaves its signature empty, for exporting transactions
+pub fn parse_signer_or_pubkey(arg: &str) -> Result<Box<dyn Signer>, Box<dyn Error>> {
+    match Pubkey::from_str(arg) {
+        Ok(pubkey) => Ok(Box::new(NullSigner::new(&pubkey))),
+        Err(_) => parse_signer(arg),
+    }
+}
+
+/// Exports `tx` to stdout if `export` is set, else handles it according to `send_mode`.
+///
+/// Exits if `tx` is to be sent but is missing signatures.
+pub async fn handle_tx_or_export(
+    rpc: &RpcClient,
+    tx: &VersionedTransaction,
+    send_mode: TxSendMode,
+    export: Option<TxEncoding>,
+) {
+    if let Some(encoding) = export {
+        println!("{}", encoding.encode_tx(tx).unwrap_or_else(exit_with_err));
+        return;
+    }
+    let missing = missing_signers(tx);
+    if !missing.is_empty() {
+        eprintln!("Transaction is missing signatures from {missing:?}. Use --export to export it for signing instead.");
+        std::process::exit(-1);
+    }
+    rpc.handle_tx(tx, send_mode).await;
+}
+
+#[derive(Args, Clone, Debug, Default)]
+pub struct NonceArgs {
+    #[arg(
+        long,
+        global = true,
+        help = NONCE_HELP,
+        value_parser = StringValueParser::new().try_map(|s| Pubkey::from_str(&s)),
+    )]
+    pub nonce: Option<Pubkey>,
+
+    #[arg(long, global = true, help = NONCE_AUTHORITY_HELP, requires = "nonce")]
+    pub nonce_authority: Option<String>,
+}
+
+impl NonceArgs {
+    /// Compiles `ixs` into a v0 transaction with `payer` as fee payer, signed by `signers`.
+    ///
+    /// If `--nonce` is set, an AdvanceNonceAccount instruction signed by the nonce authority,
+    /// which defaults to `payer`, is prepended and the nonce is used as the transaction's blockhash.
+    /// Else the latest blockhash is used.
+    ///
+    /// Exits if the nonce account is not an initialized nonce account.
+    pub async fn build_tx(
+        &self,
+        rpc: &RpcClient,
+        payer: &Pubkey,
+        ixs: &[Instruction],
+        luts: &[AddressLookupTableAccount],
+        signers: &[&dyn Signer],
+    ) -> VersionedTransaction {
+        let nonce = match self.nonce {
+            Some(n) => n,
+            None => {
+                let rbh = rpc.get_latest_blockhash().await.unwrap();
+                return VersionedTransaction::try_new(
+                    VersionedMessage::V0(Message::try_compile(payer, ixs, luts, rbh).unwrap()),
+                    signers,
+                )
+                .unwrap();
+            }
+        };
+        let nonce_blockhash = fetch_nonce_blockhash(rpc, &nonce)
+            .await
+            .unwrap_or_else(exit_with_err);
+        let nonce_authority_signer = self
+            .nonce_authority
+            .as_ref()
+            .map(|s| parse_signer_or_pubkey(s).unwrap());
+        let nonce_authority = nonce_authority_signer
+            .as_ref()
+            .map_or(*payer, |s| s.pubkey());
+
+        let mut signers = signers.to_vec();
+        if let Some(s) = nonce_authority_signer.as_ref() {
+            if signers
+                .iter()
+                .all(|signer| signer.pubkey() != nonce_authority)
+            {
+                signers.push(s.as_ref());
+            }
+        }
+        let ixs: Vec<Instruction> = [system_instruction::advance_nonce_account(
+            &nonce,
+            &nonce_authority,
+        )]
+        .into_iter()
+        .chain(ixs.iter().cloned())
+        .collect();
+        VersionedTransaction::try_new(
+            VersionedMessage::V0(Message::try_compile(payer, &ixs, luts, nonce_blockhash).unwrap()),
+            &signers,
+        )
+        .unwrap()
+    }
+}
+
+/// Returns the durable nonce stored in nonce account `nonce`,
+/// to be used as the blockhash of a transaction that advances it
+pub async fn fetch_nonce_blockhash(
+    rpc: &RpcClient,
+    nonce: &Pubkey,
+) -> Result<Hash, OfflineTxError> {
+    let data = rpc.get_account_data(nonce).await.unwrap();
+    let versions: Versions =
+        bincode::deserialize(&data).map_err(|_| OfflineTxError::InvalidNonceAccount(*nonce))?;
+    match versions.state() {
+        State::Initialized(data) => Ok(data.blockhash()),
+        State::Uninitialized => Err(OfflineTxError::InvalidNonceAccount(*nonce)),
+    }
+}
+
+#[derive(Args, Debug)]
+#[command(
+    about = "Sign a transaction exported with --export",
+    long_about = "Sign a transaction exported with --export and output it to stdout with the added signatures, in the same encoding.
+
+Signers may either sign the transaction one after another, or each sign their own copy of the exported transaction and combine the copies with send-signed."
+)]
+pub struct SignArgs {
+    #[arg(
+        long,
+        short,
+        help = TX_ENCODING_HELP,
+        default_value_t = TxEncoding::default(),
+        value_enum,
+    )]
+    pub encoding: TxEncoding,
+
+    #[arg(
+        long,
+        help = "Signer to sign the transaction with. Can be specified multiple times. Defaults to config wallet if not set."
+    )]
+    pub signer: Vec<String>,
+
+    #[arg(help = "The serialized transaction")]
+    pub tx: String,
+}
+
+impl SignArgs {
+    /// Signs the transaction with `--signer`s, or `default_signer` if none are set,
+    /// and outputs it to stdout in the same encoding.
+    ///
+    /// Exits if the transaction cannot be decoded or signed.
+    pub fn run(self, default_signer: Box<dyn Signer>) {
+        let Self {
+            encoding,
+            signer,
+            tx,
+        } = self;
+
+        let signers = if signer.is_empty() {
+            vec![default_signer]
+        } else {
+            signer.iter().map(|s| parse_signer(s).unwrap()).collect()
+        };
+
+        let mut tx = encoding.decode_tx(&tx).unwrap_or_else(exit_with_err);
+        for signer in &signers {
+            sign_tx(&mut tx, signer.as_ref()).unwrap_or_else(exit_with_err);
+        }
+
+        let missing = missing_signers(&tx);
+        if !missing.is_empty() {
+            eprintln!("Transaction is still missing signatures from {missing:?}");
+        }
+        println!("{}", encoding.encode_tx(&tx).unwrap_or_else(exit_with_err));
+    }
+}
+
+#[derive(Args, Debug)]
+#[command(
+    about = "Combine the signatures of transactions signed with sign and send the result",
+    long_about = "Combine the signatures of one or more signed copies of the same transaction exported with --export, and handle the combined transaction according to --send-mode.
+
+Fails if the combined transaction is still missing signatures, unless --export is set, in which case the combined transaction is exported instead."
+)]
+pub struct SendSignedArgs {
+    #[arg(
+        long,
+        short,
+        help = TX_ENCODING_HELP,
+        default_value_t = TxEncoding::default(),
+        value_enum,
+    )]
+    pub encoding: TxEncoding,
+
+    #[arg(
+        required = true,
+        help = "The serialized signed copies of the transaction"
+    )]
+    pub txs: Vec<String>,
+}
+
+impl SendSignedArgs {
+    /// Decodes the signed copies of the transaction and combines their signatures.
+    ///
+    /// Exits if any copy cannot be decoded or the copies cannot be combined.
+    pub fn combined_tx(&self) -> VersionedTransaction {
+        self.txs
+            .iter()
+            .map(|tx| self.encoding.decode_tx(tx))
+            .collect::<Result<Vec<_>, _>>()
+            .and_then(combine_signed_txs)
+            .unwrap_or_else(exit_with_err)
+    }
+}
+
+fn exit_with_err<T>(e: OfflineTxError) -> T {
+    eprintln!("{e}");
+    std::process::exit(-1);
+}
+
+/// Returns the required signers of `tx` whose signatures are empty
+pub fn missing_signers(tx: &VersionedTransaction) -> Vec<Pubkey> {
+    tx.message
+        .static_account_keys()
+        .iter()
+        .zip(tx.signatures.iter())
+        .filter(|(_, sig)| **sig == Signature::default())
+        .map(|(pk, _)| *pk)
+        .collect()
+}
+
+/// Signs `tx` with `signer`, replacing any existing signature of `signer`
+pub fn sign_tx(tx: &mut VersionedTransaction, signer: &dyn Signer) -> Result<(), OfflineTxError> {
+    let pubkey = signer.try_pubkey()?;
+    let index = tx
+        .message
+        .static_account_keys()
+        .iter()
+        .take(tx.signatures.len())
+        .position(|pk| *pk == pubkey)
+        .ok_or(OfflineTxError::NotASigner(pubkey))?;
+    tx.signatures[index] = signer.try_sign_message(&tx.message.serialize())?;
+    Ok(())
+}
+
+/// Combines the signatures of multiple partially-signed copies of the same transaction
+pub fn combine_signed_txs(
+    txs: impl IntoIterator<Item = VersionedTransaction>,
+) -> Result<VersionedTransaction, OfflineTxError> {
+    let mut txs = txs.into_iter();
+    let mut res = txs.next().ok_or(OfflineTxError::NoTransactions)?;
+    for tx in txs {
+        if tx.message != res.message {
+            return Err(OfflineTxError::MessageMismatch);
+        }
+        for (i, sig) in tx.signatures.into_iter().enumerate() {
+            if sig == Signature::default() {
+                continue;
+            }
+            let existing = &mut res.signatures[i];
+            if *existing == Signature::default() {
+                *existing = sig;
+            } else if *existing != sig {
+                return Err(OfflineTxError::SignatureMismatch(
+                    res.message.static_account_keys()[i],
+                ));
+            }
+        }
+    }
+    Ok(res)
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_sdk::{
+        hash::Hash,
+        instruction::{AccountMeta, Instruction},
+        message::{v0::Message, VersionedMessage},
+        signature::Keypair,
+    };
+
+    use super::*;
+
+    fn two_signer_tx(payer: &Keypair, authority: &dyn Signer) -> VersionedTransaction {
+        let ix = Instruction::new_with_bytes(
+            Pubkey::new_unique(),
+            &[],
+            vec![AccountMeta::new_readonly(authority.pubkey(), true)],
+        );
+        VersionedTransaction::try_new(
+            VersionedMessage::V0(
+                Message::try_compile(&payer.pubkey(), &[ix], &[], Hash::new_unique()).unwrap(),
+            ),
+            &[payer as &dyn Signer, authority],
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn encoding_round_trip() {
+        let payer = Keypair::new();
+        let authority = Keypair::new();
+        let tx = two_signer_tx(&payer, &authority);
+        for encoding in [TxEncoding::Base58, TxEncoding::Base64] {
+            let decoded = encoding
+                .decode_tx(&encoding.encode_tx(&tx).unwrap())
+                .unwrap();
+            assert_eq!(decoded, tx);
+        }
+    }
+
+    #[test]
+    fn pubkey_arg_leaves_signature_empty() {
+        let payer = Keypair::new();
+        let authority = Keypair::new();
+        let null_signer = parse_signer_or_pubkey(&authority.pubkey().to_string()).unwrap();
+        let tx = two_signer_tx(&payer, null_signer.as_ref());
+        assert_eq!(missing_signers(&tx), vec![authority.pubkey()]);
+    }
+
+    #[test]
+    fn sign_then_combine() {
+        let payer = Keypair::new();
+        let authority_a = Keypair::new();
+        let authority_b = Keypair::new();
+        let ix = Instruction::new_with_bytes(
+            Pubkey::new_unique(),
+            &[],
+            vec![
+                AccountMeta::new_readonly(authority_a.pubkey(), true),
+                AccountMeta::new_readonly(authority_b.pubkey(), true),
+            ],
+        );
+        let unsigned = VersionedTransaction::try_new(
+            VersionedMessage::V0(
+                Message::try_compile(&payer.pubkey(), &[ix], &[], Hash::new_unique()).unwrap(),
+            ),
+            &[
+                &payer as &dyn Signer,
+                &NullSigner::new(&authority_a.pubkey()),
+                &NullSigner::new(&authority_b.pubkey()),
+            ],
+        )
+        .unwrap();
+        assert_eq!(
+            missing_signers(&unsigned),
+            vec![authority_a.pubkey(), authority_b.pubkey()]
+        );
+
+        let mut signed_a = unsigned.clone();
+        sign_tx(&mut signed_a, &authority_a).unwrap();
+        let mut signed_b = unsigned.clone();
+        sign_tx(&mut signed_b, &authority_b).unwrap();
+        assert!(matches!(
+            sign_tx(&mut signed_b, &Keypair::new()),
+            Err(OfflineTxError::NotASigner(_))
+        ));
+
+        let combined = combine_signed_txs([signed_a, signed_b]).unwrap();
+        assert!(missing_signers(&combined).is_empty());
+        assert!(combined.verify_with_results().into_iter().all(|ok| ok));
+    }
+
+    #[test]
+    fn combine_rejects_different_messages() {
+        let payer = Keypair::new();
+        let authority = Keypair::new();
+        assert!(matches!(
+            combine_signed_txs([
+                two_signer_tx(&payer, &authority),
+                two_signer_tx(&payer, &authority)
+            ]),
+            Err(OfflineTxError::MessageMismatch)
+        ));
+    }
+}
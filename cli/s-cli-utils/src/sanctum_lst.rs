@@ -0,0 +1,14 @@
+use lazy_static::lazy_static;
+use sanctum_lst_list::{SanctumLst, SanctumLstList};
+use solana_sdk::pubkey::Pubkey;
+
+lazy_static! {
+    pub static ref SANCTUM_LST_LIST: SanctumLstList = SanctumLstList::load();
+}
+
+pub fn find_sanctum_lst_by_mint(mint: Pubkey) -> Option<&'static SanctumLst> {
+    SANCTUM_LST_LIST
+        .sanctum_lst_list
+        .iter()
+        .find(|lst| lst.mint == mint)
+}
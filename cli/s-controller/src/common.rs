@@ -1,17 +1,26 @@
 use std::convert::Infallible;
 
 use generic_pool_calculator_lib::account_resolvers::LstSolCommonIntermediateKeys;
-use lazy_static::lazy_static;
 use lido_calculator_lib::lido_sol_val_calc_account_metas;
 use marinade_calculator_lib::marinade_sol_val_calc_account_metas;
 use s_controller_interface::PoolState;
-use sanctum_lst_list::{PoolInfo, SanctumLst, SanctumLstList, SplPoolAccounts};
+use s_controller_lib::{Pool, PoolId, PoolPdas};
+use s_pool_calculator_lib::s_pool_sol_val_calc_account_metas;
+use sanctum_lst_list::{PoolInfo, SanctumLst, SplPoolAccounts};
 use solana_sdk::{instruction::AccountMeta, pubkey::Pubkey};
 use spl_calculator_lib::{resolve_to_account_metas_for_calc, SanctumSplSolValCalc, SplSolValCalc};
 use wsol_calculator_lib::WSOL_LST_SOL_COMMON_METAS;
 
-lazy_static! {
-    pub static ref SANCTUM_LST_LIST: SanctumLstList = SanctumLstList::load();
+pub use s_cli_utils::{find_sanctum_lst_by_mint, SANCTUM_LST_LIST};
+
+/// The ID of the pool selected by the `--pool` arg, the default pool if not set
+pub fn pool_id_of(pool: Option<Pubkey>) -> PoolId {
+    pool.map_or(PoolId::Default, PoolId::Keyed)
+}
+
+/// Finds the PDAs of the pool selected by the `--pool` arg, the default pool if not set
+pub fn find_pool_pdas(program_id: Pubkey, pool: Option<Pubkey>) -> PoolPdas {
+    Pool::find_for_prog(program_id, pool_id_of(pool)).pdas
 }
 
 pub fn verify_admin(state: &PoolState, admin: Pubkey) -> Result<(), Infallible> {
@@ -55,7 +64,7 @@ pub fn sol_val_calc_of_sanctum_lst(sanctum_lst: &SanctumLst) -> Pubkey {
         PoolInfo::ReservePool => wsol_calculator_lib::program::ID,
         PoolInfo::SanctumSpl(_) => spl_calculator_lib::sanctum_spl_sol_val_calc_program::ID,
         PoolInfo::Spl(_) => spl_calculator_lib::program::ID,
-        PoolInfo::SPool(_) => todo!(),
+        PoolInfo::SPool(_) => s_pool_calculator_lib::program::ID,
     }
 }
 
@@ -86,13 +95,59 @@ pub fn sol_value_calculator_accounts_of_sanctum_lst(
             })
             .to_vec()
         }
-        PoolInfo::SPool(_) => todo!(),
+        // sanctum-lst-list only lists the LP token of the default S pool
+        PoolInfo::SPool(_) => {
+            s_pool_sol_val_calc_account_metas(s_controller_lib::program::POOL_STATE_ID, *mint)
+                .to_vec()
+        }
     }
 }
 
-pub fn find_sanctum_lst_by_mint(mint: Pubkey) -> Option<&'static SanctumLst> {
-    SANCTUM_LST_LIST
-        .sanctum_lst_list
-        .iter()
-        .find(|lst| lst.mint == mint)
+/// Returns the accounts suffix slice required to call PriceLpTokensToMint for `lst_mint`,
+/// excluding the pricing program ID.
+///
+/// Exits if the pool's pricing program is not supported
+pub fn price_lp_tokens_to_mint_accounts(
+    pricing_program: Pubkey,
+    lst_mint: Pubkey,
+) -> Vec<AccountMeta> {
+    match pricing_program {
+        flat_fee_lib::program::ID | target_weight_lib::program::ID => {
+            vec![AccountMeta::new_readonly(lst_mint, false)]
+        }
+        _ => {
+            eprintln!("Unsupported pricing program {pricing_program}");
+            std::process::exit(-1);
+        }
+    }
+}
+
+/// Returns the accounts suffix slice required to call PriceLpTokensToRedeem for `lst_mint`,
+/// excluding the pricing program ID.
+///
+/// Exits if the pool's pricing program is not supported
+pub fn price_lp_tokens_to_redeem_accounts(
+    pricing_program: Pubkey,
+    lst_mint: Pubkey,
+) -> Vec<AccountMeta> {
+    match pricing_program {
+        flat_fee_lib::program::ID => {
+            flat_fee_lib::account_resolvers::PriceLpTokensToRedeemFreeArgs {
+                output_lst_mint: lst_mint,
+            }
+            .resolve_to_account_metas()
+            .to_vec()
+        }
+        target_weight_lib::program::ID => {
+            target_weight_lib::account_resolvers::PriceLpTokensToRedeemFreeArgs {
+                output_lst_mint: lst_mint,
+            }
+            .resolve_to_account_metas()
+            .to_vec()
+        }
+        _ => {
+            eprintln!("Unsupported pricing program {pricing_program}");
+            std::process::exit(-1);
+        }
+    }
 }
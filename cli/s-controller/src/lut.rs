@@ -0,0 +1,367 @@
+//! Address lookup table of all the static accounts used by the pool
+
+use std::collections::HashSet;
+
+use s_controller_interface::{LstState, PoolState};
+use s_controller_lib::{
+    create_pool_reserves_address_with_pool_state_id,
+    create_protocol_fee_accumulator_address_with_protocol_fee_id, try_lst_state_list,
+    try_pool_state, PoolPdas,
+};
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_sdk::{
+    account::Account,
+    address_lookup_table::state::AddressLookupTable,
+    address_lookup_table_account::AddressLookupTableAccount,
+    hash::Hash,
+    instruction::Instruction,
+    message::{v0::Message, VersionedMessage},
+    packet::PACKET_DATA_SIZE,
+    pubkey::Pubkey,
+    system_program, sysvar,
+};
+
+use crate::{
+    common::{find_sanctum_lst_by_mint, sol_value_calculator_accounts_of_sanctum_lst},
+    rpc::get_multiple_accounts_chunked,
+};
+
+/// Max number of addresses to add per ExtendLookupTable instruction
+/// such that the transaction still fits within the packet size limit
+pub const MAX_ADDRESSES_PER_EXTEND: usize = 20;
+
+/// Max number of accounts, including those loaded from lookup tables, a tx can lock
+pub const MAX_TX_ACCOUNT_LOCKS: usize = 64;
+
+/// Fetches and deserializes the lookup table at `lut`, if any
+pub async fn fetch_luts(rpc: &RpcClient, lut: Option<Pubkey>) -> Vec<AddressLookupTableAccount> {
+    let lut = match lut {
+        Some(lut) => lut,
+        None => return vec![],
+    };
+    let lut_acc = fetch_lut_account(rpc, lut).await;
+    vec![AddressLookupTableAccount {
+        key: lut,
+        addresses: deserialize_lut(lut, &lut_acc.data).addresses.to_vec(),
+    }]
+}
+
+/// Fetches the account at `lut`. Exits if it cannot be fetched.
+pub async fn fetch_lut_account(rpc: &RpcClient, lut: Pubkey) -> Account {
+    rpc.get_account(&lut).await.unwrap_or_else(|e| {
+        eprintln!("Failed to fetch lookup table {lut}: {e}");
+        std::process::exit(-1);
+    })
+}
+
+/// Deserializes the data of the account at `lut`. Exits if it is not a lookup table.
+pub fn deserialize_lut(lut: Pubkey, data: &[u8]) -> AddressLookupTable<'_> {
+    AddressLookupTable::deserialize(data).unwrap_or_else(|e| {
+        eprintln!("{lut} is not an address lookup table: {e}");
+        std::process::exit(-1);
+    })
+}
+
+/// Fetches the pool's current state and returns [`pool_lut_addresses`] for it
+pub async fn fetch_pool_lut_addresses(
+    rpc: &RpcClient,
+    program_id: Pubkey,
+    pdas: PoolPdas,
+) -> Vec<Pubkey> {
+    let mut fetched_accs = rpc
+        .get_multiple_accounts(&[pdas.pool_state, pdas.lst_state_list])
+        .await
+        .unwrap_or_else(|e| {
+            eprintln!("Failed to fetch pool accounts: {e}");
+            std::process::exit(-1);
+        });
+    // lst_state_list does not exist until the first LST is added
+    let lst_state_list_acc = fetched_accs.pop().flatten();
+    let pool_state_acc = fetched_accs.pop().flatten().unwrap_or_else(|| {
+        eprintln!("Pool state {} does not exist", pdas.pool_state);
+        std::process::exit(-1);
+    });
+    let pool_state = try_pool_state(&pool_state_acc.data).unwrap_or_else(|e| {
+        eprintln!("Invalid pool state {}: {e}", pdas.pool_state);
+        std::process::exit(-1);
+    });
+    let lst_state_list = match lst_state_list_acc.as_ref() {
+        Some(acc) => try_lst_state_list(&acc.data).unwrap_or_else(|e| {
+            eprintln!("Invalid LST state list {}: {e}", pdas.lst_state_list);
+            std::process::exit(-1);
+        }),
+        None => &[],
+    };
+
+    let mints: Vec<Pubkey> = lst_state_list.iter().map(|lst| lst.mint).collect();
+    let mint_accs = get_multiple_accounts_chunked(rpc, &mints).await;
+    let lsts: Vec<PoolLutLst> = lst_state_list
+        .iter()
+        .zip(mint_accs)
+        .map(|(lst_state, mint_acc)| PoolLutLst {
+            lst_state,
+            token_program: mint_acc
+                .unwrap_or_else(|| {
+                    eprintln!("LST mint {} does not exist", lst_state.mint);
+                    std::process::exit(-1);
+                })
+                .owner,
+        })
+        .collect();
+
+    pool_lut_addresses(program_id, pdas, pool_state, &lsts)
+}
+
+/// A LST in the pool, along with its mint's owner
+pub struct PoolLutLst<'a> {
+    pub lst_state: &'a LstState,
+    pub token_program: Pubkey,
+}
+
+/// All the static accounts used by the pool's instructions, deduplicated.
+///
+/// Includes the accounts required to CPI the SOL value calculator program
+/// of LSTs on sanctum-lst-list and the pool's pricing program.
+pub fn pool_lut_addresses(
+    program_id: Pubkey,
+    pdas: PoolPdas,
+    pool_state: &PoolState,
+    lsts: &[PoolLutLst],
+) -> Vec<Pubkey> {
+    let mut res = vec![
+        program_id,
+        pdas.pool_state,
+        pdas.lst_state_list,
+        pdas.protocol_fee,
+        pool_state.lp_token_mint,
+        pool_state.pricing_program,
+        system_program::ID,
+        sysvar::instructions::ID,
+        spl_associated_token_account::ID,
+    ];
+    res.extend(pricing_program_state(pool_state.pricing_program));
+    for PoolLutLst {
+        lst_state,
+        token_program,
+    } in lsts
+    {
+        res.extend([
+            lst_state.mint,
+            *token_program,
+            lst_state.sol_value_calculator,
+            create_pool_reserves_address_with_pool_state_id(
+                pdas.pool_state,
+                lst_state,
+                *token_program,
+            )
+            .unwrap(),
+            create_protocol_fee_accumulator_address_with_protocol_fee_id(
+                pdas.protocol_fee,
+                lst_state,
+                *token_program,
+            )
+            .unwrap(),
+        ]);
+        if let Some(sanctum_lst) = find_sanctum_lst_by_mint(lst_state.mint) {
+            res.extend(
+                sol_value_calculator_accounts_of_sanctum_lst(sanctum_lst)
+                    .into_iter()
+                    .map(|meta| meta.pubkey),
+            );
+        }
+        res.extend(pricing_program_lst_account(
+            pool_state.pricing_program,
+            lst_state.mint,
+        ));
+    }
+    let mut seen = HashSet::new();
+    res.retain(|pk| seen.insert(*pk));
+    res
+}
+
+/// Whether `ixs` can be compiled with `luts` into a single transaction
+/// that is within the packet size and account lock limits
+pub fn ixs_fit_in_tx(
+    payer: Pubkey,
+    ixs: &[Instruction],
+    luts: &[AddressLookupTableAccount],
+) -> bool {
+    let msg = match Message::try_compile(&payer, ixs, luts, Hash::default()) {
+        Ok(msg) => msg,
+        Err(_) => return false,
+    };
+    let n_accounts = msg.account_keys.len()
+        + msg
+            .address_table_lookups
+            .iter()
+            .map(|l| l.writable_indexes.len() + l.readonly_indexes.len())
+            .sum::<usize>();
+    n_accounts <= MAX_TX_ACCOUNT_LOCKS
+        && serialized_tx_len(&VersionedMessage::V0(msg)) <= PACKET_DATA_SIZE
+}
+
+/// Length of the serialized transaction of `msg` once signed
+pub fn serialized_tx_len(msg: &VersionedMessage) -> usize {
+    let n_signatures = usize::from(msg.header().num_required_signatures);
+    // compact-u16 len prefix of the signatures array is 1 byte for < 128 signatures
+    1 + n_signatures * 64 + msg.serialize().len()
+}
+
+fn pricing_program_state(pricing_program: Pubkey) -> Option<Pubkey> {
+    match pricing_program {
+        flat_fee_lib::program::ID => Some(flat_fee_lib::program::STATE_ID),
+        target_weight_lib::program::ID => Some(target_weight_lib::program::STATE_ID),
+        _ => None,
+    }
+}
+
+fn pricing_program_lst_account(pricing_program: Pubkey, lst_mint: Pubkey) -> Option<Pubkey> {
+    match pricing_program {
+        flat_fee_lib::program::ID => Some(
+            flat_fee_lib::pda::FeeAccountFindPdaArgs {
+                program_id: pricing_program,
+                lst_mint,
+            }
+            .get_fee_account_address_and_bump_seed()
+            .0,
+        ),
+        target_weight_lib::program::ID => Some(
+            target_weight_lib::pda::WeightAccountFindPdaArgs {
+                program_id: pricing_program,
+                lst_mint,
+            }
+            .get_weight_account_address_and_bump_seed()
+            .0,
+        ),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bytemuck::Zeroable;
+    use s_controller_lib::{
+        find_pool_reserves_address, find_protocol_fee_accumulator_address, FindLstPdaAtaKeys,
+    };
+    use sanctum_lst_list::SanctumLst;
+
+    use crate::common::SANCTUM_LST_LIST;
+
+    use super::*;
+
+    #[test]
+    fn pool_lut_addresses_deduped() {
+        let mint = Pubkey::new_unique();
+        let token_program = Pubkey::new_unique();
+        let find_keys = FindLstPdaAtaKeys {
+            lst_mint: mint,
+            token_program,
+        };
+        let (pool_reserves, pool_reserves_bump) = find_pool_reserves_address(find_keys);
+        let (protocol_fee_accumulator, protocol_fee_accumulator_bump) =
+            find_protocol_fee_accumulator_address(find_keys);
+        let lst_state = LstState {
+            is_input_disabled: 0,
+            pool_reserves_bump,
+            protocol_fee_accumulator_bump,
+            padding: Default::default(),
+            max_share_bps: 0,
+            sol_value: 0,
+            mint,
+            sol_value_calculator: Pubkey::new_unique(),
+        };
+        let pool_state = PoolState {
+            total_sol_value: 0,
+            trading_protocol_fee_bps: 0,
+            lp_protocol_fee_bps: 0,
+            version: 0,
+            is_disabled: 0,
+            is_rebalancing: 0,
+            timelock_delay_hours: 0,
+            admin: Pubkey::new_unique(),
+            rebalance_authority: Pubkey::new_unique(),
+            protocol_fee_beneficiary: Pubkey::new_unique(),
+            pricing_program: flat_fee_lib::program::ID,
+            // duplicate of the LST's mint
+            lp_token_mint: mint,
+        };
+        let addresses = pool_lut_addresses(
+            s_controller_lib::program::ID,
+            PoolPdas::DEFAULT,
+            &pool_state,
+            &[PoolLutLst {
+                lst_state: &lst_state,
+                token_program,
+            }],
+        );
+        let unique: HashSet<_> = addresses.iter().collect();
+        assert_eq!(unique.len(), addresses.len());
+        for expected in [
+            mint,
+            token_program,
+            pool_reserves,
+            protocol_fee_accumulator,
+            flat_fee_lib::program::STATE_ID,
+        ] {
+            assert!(addresses.contains(&expected));
+        }
+    }
+
+    #[test]
+    fn pool_lut_addresses_sanctum_lsts() {
+        let sanctum_lsts: Vec<&SanctumLst> = SANCTUM_LST_LIST
+            .sanctum_lst_list
+            .iter()
+            .filter(|lst| lst.token_program == spl_token::ID)
+            .take(2)
+            .collect();
+        let lst_states: Vec<LstState> = sanctum_lsts
+            .iter()
+            .map(|lst| LstState {
+                mint: lst.mint,
+                sol_value_calculator: Pubkey::new_unique(),
+                ..Zeroable::zeroed()
+            })
+            .collect();
+        let pool_state = PoolState {
+            lp_token_mint: Pubkey::new_unique(),
+            pricing_program: Pubkey::new_unique(),
+            ..Zeroable::zeroed()
+        };
+        let pdas = PoolPdas::DEFAULT;
+        let addresses = pool_lut_addresses(
+            s_controller_lib::program::ID,
+            pdas,
+            &pool_state,
+            &lst_states
+                .iter()
+                .map(|lst_state| PoolLutLst {
+                    lst_state,
+                    token_program: spl_token::ID,
+                })
+                .collect::<Vec<_>>(),
+        );
+        let unique: HashSet<_> = addresses.iter().collect();
+        assert_eq!(unique.len(), addresses.len());
+        let mut expected = vec![
+            s_controller_lib::program::ID,
+            pdas.pool_state,
+            pdas.lst_state_list,
+            pdas.protocol_fee,
+            pool_state.lp_token_mint,
+            pool_state.pricing_program,
+            spl_token::ID,
+        ];
+        for (sanctum_lst, lst_state) in sanctum_lsts.iter().zip(lst_states.iter()) {
+            expected.extend([lst_state.mint, lst_state.sol_value_calculator]);
+            expected.extend(
+                sol_value_calculator_accounts_of_sanctum_lst(sanctum_lst)
+                    .into_iter()
+                    .map(|meta| meta.pubkey),
+            );
+        }
+        for pk in expected {
+            assert!(addresses.contains(&pk));
+        }
+    }
+}
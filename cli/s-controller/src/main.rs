@@ -4,17 +4,23 @@ use clap::{
     builder::{StringValueParser, TypedValueParser, ValueParser},
     Parser,
 };
-use s_cli_utils::{CONFIG_HELP, TX_SEND_MODE_HELP};
-use sanctum_solana_cli_utils::{ConfigWrapper, TxSendMode};
+use s_cli_utils::{NonceArgs, CONFIG_HELP};
+use sanctum_solana_cli_utils::ConfigWrapper;
 use solana_sdk::pubkey::Pubkey;
 use subcmd::Subcmd;
 use tokio::runtime::Runtime;
+use tx::TxArgs;
 
 mod common;
 mod lst_arg;
+mod lut;
 mod pricing_prog_arg;
 mod rpc;
+mod simulate;
+mod spl_stake_pool;
 mod subcmd;
+mod tx;
+mod user_swap;
 
 #[derive(Parser, Debug)]
 #[command(author, version, about = "S Controller Program CLI")]
@@ -28,14 +34,11 @@ pub struct Args {
     )]
     pub config: ConfigWrapper,
 
-    #[arg(
-        long,
-        short,
-        help = TX_SEND_MODE_HELP,
-        default_value_t = TxSendMode::default(),
-        value_enum,
-    )]
-    pub send_mode: TxSendMode,
+    #[command(flatten)]
+    pub tx: TxArgs,
+
+    #[command(flatten)]
+    pub nonce: NonceArgs,
 
     #[arg(
         long,
@@ -46,6 +49,21 @@ pub struct Args {
     )]
     pub program: Pubkey,
 
+    #[arg(
+        long,
+        help = "LP token mint of the pool to operate on. Defaults to the program's default pool if not set.",
+        value_parser = StringValueParser::new().try_map(|s| Pubkey::from_str(&s)),
+    )]
+    pub pool: Option<Pubkey>,
+
+    #[arg(
+        long,
+        global = true,
+        help = "Address lookup table to compile transactions with, to fit more accounts per transaction. Create one with create-lut.",
+        value_parser = StringValueParser::new().try_map(|s| Pubkey::from_str(&s)),
+    )]
+    pub lut: Option<Pubkey>,
+
     #[command(subcommand)]
     pub subcmd: Subcmd,
 }
@@ -3,25 +3,28 @@ use std::{error::Error, str::FromStr};
 use solana_sdk::pubkey::Pubkey;
 
 const FLAT_FEE_IDENT: &str = "flat-fee";
+const TARGET_WEIGHT_IDENT: &str = "target-weight";
 
 #[derive(Clone, Copy, Debug)]
 pub enum PricingProgArg {
     FlatFee,
+    TargetWeight,
     Unknown(Pubkey),
 }
 
 impl PricingProgArg {
     pub fn parse_arg(arg: &str) -> Result<Self, Box<dyn Error + Send + Sync + 'static>> {
-        Ok(if arg == FLAT_FEE_IDENT {
-            Self::FlatFee
-        } else {
-            Self::Unknown(Pubkey::from_str(arg)?)
+        Ok(match arg {
+            FLAT_FEE_IDENT => Self::FlatFee,
+            TARGET_WEIGHT_IDENT => Self::TargetWeight,
+            _ => Self::Unknown(Pubkey::from_str(arg)?),
         })
     }
 
     pub fn program_id(&self) -> Pubkey {
         match self {
             Self::FlatFee => flat_fee_lib::program::ID,
+            Self::TargetWeight => target_weight_lib::program::ID,
             Self::Unknown(pk) => *pk,
         }
     }
@@ -1,79 +1,99 @@
-use data_encoding::BASE64;
-use s_controller_lib::{find_disable_pool_authority_list_address, find_pool_state_address};
-use solana_account_decoder::{UiAccount, UiAccountData, UiAccountEncoding};
-use solana_client::{
-    nonblocking::rpc_client::RpcClient,
-    rpc_config::{RpcSimulateTransactionAccountsConfig, RpcSimulateTransactionConfig},
-    rpc_response::RpcSimulateTransactionResult,
-};
-use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountPubkey};
+use std::collections::HashMap;
+
+use s_controller_lib::PoolPdas;
+use s_jup_interface::{SPoolInitAccounts, SPoolInitKeys, SPoolJup};
+use solana_client::nonblocking::rpc_client::RpcClient;
 use solana_sdk::{account::Account, pubkey::Pubkey, transaction::VersionedTransaction};
 
-pub async fn fetch_pool_state(rpc: &RpcClient, program_id: Pubkey) -> Account {
-    rpc.get_account(&find_pool_state_address(program_id).0)
+use crate::{
+    common::{pool_id_of, SANCTUM_LST_LIST},
+    simulate::{is_pool_state_modified, simulate_tx, PoolAccountsData, TxPreview},
+};
+
+/// Max number of accounts a single getMultipleAccounts RPC call can fetch
+const MAX_MULTIPLE_ACCOUNTS: usize = 100;
+
+pub async fn fetch_pool_state(rpc: &RpcClient, pdas: PoolPdas) -> Account {
+    rpc.get_account(&pdas.pool_state).await.unwrap()
+}
+
+pub async fn fetch_disable_pool_authority_list(rpc: &RpcClient, pdas: PoolPdas) -> Account {
+    rpc.get_account(&pdas.disable_pool_authority_list)
         .await
         .unwrap()
 }
 
-pub async fn fetch_disable_pool_authority_list(rpc: &RpcClient, program_id: Pubkey) -> Account {
-    rpc.get_account(&find_disable_pool_authority_list_address(program_id).0)
+/// getMultipleAccounts for any number of accounts, split into multiple RPC calls if required.
+/// Results are in the same order as `pubkeys`
+pub async fn get_multiple_accounts_chunked(
+    rpc: &RpcClient,
+    pubkeys: &[Pubkey],
+) -> Vec<Option<Account>> {
+    let mut res = Vec::with_capacity(pubkeys.len());
+    for chunk in pubkeys.chunks(MAX_MULTIPLE_ACCOUNTS) {
+        res.extend(rpc.get_multiple_accounts(chunk).await.unwrap());
+    }
+    res
+}
+
+/// Fetches the accounts of the pool selected by the `--pool` arg
+/// and initializes a [`SPoolJup`] from them that is ready for quoting and swapping
+pub async fn fetch_s_pool(rpc: &RpcClient, program_id: Pubkey, pool: Option<Pubkey>) -> SPoolJup {
+    let pool_id = pool_id_of(pool);
+    let SPoolInitKeys {
+        lst_state_list,
+        pool_state,
+    } = SPoolJup::init_keys_for_pool(program_id, pool_id);
+    let mut fetched_accs = rpc
+        .get_multiple_accounts(&[lst_state_list, pool_state])
         .await
-        .unwrap()
+        .unwrap();
+    let pool_state = fetched_accs.pop().unwrap().unwrap();
+    let lst_state_list = fetched_accs.pop().unwrap().unwrap();
+    let mut s_pool = SPoolJup::from_init_accounts_for_pool(
+        program_id,
+        pool_id,
+        SPoolInitAccounts {
+            lst_state_list,
+            pool_state,
+        },
+        &SANCTUM_LST_LIST.sanctum_lst_list,
+    )
+    .unwrap();
+
+    let keys = s_pool.get_accounts_to_update_full();
+    let accounts: HashMap<Pubkey, Account> = keys
+        .iter()
+        .zip(get_multiple_accounts_chunked(rpc, &keys).await)
+        .filter_map(|(pubkey, acc)| acc.map(|acc| (*pubkey, acc)))
+        .collect();
+    // update_full() errors if any of the pool's LSTs could not be updated,
+    // e.g. unknown SOL value calculator program. This only matters if the
+    // LST is part of the swap, in which case quoting it fails with the error.
+    let _ = s_pool.update_full(&accounts);
+    s_pool
 }
 
-// NB: this fn is currently not tested because our current BanksRpcServer setup doesn't really
-// allow simulation with post tx accounts results:
-// https://github.com/igneous-labs/sanctum-solana-utils/issues/40#issuecomment-1932036297
-// All cmds that rely on this should provide some way to do without it so that they can be tested
-pub async fn does_tx_modify_pool_state<P: ReadonlyAccountData + ReadonlyAccountPubkey>(
+/// Simulates `tx` and returns whether it modifies the pool's PoolState, whose data before `tx` is `pool_state_before`.
+///
+/// Returns true if the RPC does not return accounts after simulation, e.g. the BanksClient-backed RPC used in tests,
+/// since there is no way to tell. Exits if the simulation fails.
+pub async fn does_tx_modify_pool_state(
     rpc: &RpcClient,
     tx: &VersionedTransaction,
-    pool_state_before: P,
+    pdas: PoolPdas,
+    pool_state_before: &[u8],
 ) -> bool {
-    let RpcSimulateTransactionResult {
-        err,
-        logs,
-        accounts,
-        ..
-    } = rpc
-        .simulate_transaction_with_config(
-            tx,
-            RpcSimulateTransactionConfig {
-                sig_verify: false,
-                replace_recent_blockhash: true, // must set to true or sim will error with blockhash not found
-                commitment: None,
-                encoding: None,
-                accounts: Some(RpcSimulateTransactionAccountsConfig {
-                    encoding: Some(UiAccountEncoding::Base64),
-                    addresses: vec![pool_state_before.pubkey().to_string()],
-                }),
-                min_context_slot: None,
-            },
-        )
-        .await
-        .unwrap()
-        .value;
-    if let Some(err) = err {
-        eprintln!("Simulation error: {err}");
-        match logs {
-            Some(logs) => {
-                eprintln!("Logs:");
-                eprintln!("{logs:#?}");
-            }
-            None => eprintln!("No logs available"),
+    let outcome = match simulate_tx(rpc, tx, pdas).await {
+        Ok(o) => o,
+        Err(e) => {
+            eprintln!("Failed to simulate transaction: {e}");
+            std::process::exit(-1);
         }
+    };
+    if outcome.err.is_some() {
+        eprintln!("{}", TxPreview::new(&PoolAccountsData::default(), &outcome));
         std::process::exit(-1);
     }
-    let UiAccount { data, .. } = accounts.as_ref().unwrap()[0].as_ref().unwrap();
-    let pool_state_data_after = match data {
-        UiAccountData::Binary(b64_str, encoding) => {
-            if *encoding != UiAccountEncoding::Base64 {
-                panic!("Unexpected ui account encoding {encoding:?}");
-            }
-            BASE64.decode(b64_str.as_bytes()).unwrap()
-        }
-        _ => panic!("Unexpected ui account data {data:?}"),
-    };
-    let d = pool_state_before.data();
-    *pool_state_data_after.as_slice() != **d
+    is_pool_state_modified(pool_state_before, &outcome)
 }
@@ -0,0 +1,573 @@
+//! `--simulate`: previews the effects of a subcommand's transactions on the pool without sending them
+
+use std::{collections::HashSet, fmt::Display};
+
+use bytemuck::Zeroable;
+use data_encoding::BASE64;
+use s_controller_interface::{LstState, PoolState, SControllerError};
+use s_controller_lib::{try_lst_state_list, try_pool_state, PoolPdas};
+use solana_account_decoder::{UiAccount, UiAccountData, UiAccountEncoding};
+use solana_client::{
+    client_error::ClientError,
+    nonblocking::rpc_client::RpcClient,
+    rpc_config::{RpcSimulateTransactionAccountsConfig, RpcSimulateTransactionConfig},
+    rpc_response::RpcSimulateTransactionResult,
+};
+use solana_sdk::{
+    pubkey::Pubkey,
+    transaction::{TransactionError, VersionedTransaction},
+};
+
+use crate::common::find_sanctum_lst_by_mint;
+
+/// Data of the pool's PoolState and LstStateList accounts.
+/// None if the account does not exist.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PoolAccountsData {
+    pub pool_state: Option<Vec<u8>>,
+    pub lst_state_list: Option<Vec<u8>>,
+}
+
+impl PoolAccountsData {
+    fn addresses(pdas: PoolPdas) -> [Pubkey; 2] {
+        [pdas.pool_state, pdas.lst_state_list]
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SimulationOutcome {
+    pub err: Option<TransactionError>,
+    pub logs: Option<Vec<String>>,
+    pub units_consumed: Option<u64>,
+
+    /// None if the RPC did not return the accounts after simulation
+    pub post: Option<PoolAccountsData>,
+}
+
+pub async fn fetch_pool_accounts_data(
+    rpc: &RpcClient,
+    pdas: PoolPdas,
+) -> Result<PoolAccountsData, ClientError> {
+    let mut accs = rpc
+        .get_multiple_accounts(&PoolAccountsData::addresses(pdas))
+        .await?
+        .into_iter()
+        .map(|acc| acc.map(|acc| acc.data));
+    Ok(PoolAccountsData {
+        pool_state: accs.next().flatten(),
+        lst_state_list: accs.next().flatten(),
+    })
+}
+
+/// Simulates `tx`, requesting the pool's PoolState and LstStateList accounts after simulation
+pub async fn simulate_tx(
+    rpc: &RpcClient,
+    tx: &VersionedTransaction,
+    pdas: PoolPdas,
+) -> Result<SimulationOutcome, ClientError> {
+    let RpcSimulateTransactionResult {
+        err,
+        logs,
+        accounts,
+        units_consumed,
+        ..
+    } = rpc
+        .simulate_transaction_with_config(
+            tx,
+            RpcSimulateTransactionConfig {
+                sig_verify: false,
+                replace_recent_blockhash: true, // must set to true or sim will error with blockhash not found
+                commitment: None,
+                encoding: None,
+                accounts: Some(RpcSimulateTransactionAccountsConfig {
+                    encoding: Some(UiAccountEncoding::Base64),
+                    addresses: PoolAccountsData::addresses(pdas)
+                        .iter()
+                        .map(|pk| pk.to_string())
+                        .collect(),
+                }),
+                min_context_slot: None,
+            },
+        )
+        .await?
+        .value;
+    let post = accounts.and_then(|accounts| {
+        let mut accs = accounts
+            .into_iter()
+            .map(|acc| acc.map(ui_account_data).transpose());
+        Some(PoolAccountsData {
+            pool_state: accs.next()?.ok()?,
+            lst_state_list: accs.next()?.ok()?,
+        })
+    });
+    Ok(SimulationOutcome {
+        err,
+        logs,
+        units_consumed,
+        post,
+    })
+}
+
+fn ui_account_data(UiAccount { data, .. }: UiAccount) -> Result<Vec<u8>, String> {
+    match data {
+        UiAccountData::Binary(b64_str, UiAccountEncoding::Base64) => {
+            BASE64.decode(b64_str.as_bytes()).map_err(|e| e.to_string())
+        }
+        _ => Err(format!("Unexpected ui account data {data:?}")),
+    }
+}
+
+/// Returns true if the simulated transaction changed the PoolState from `pool_state_before`.
+///
+/// Also returns true if the RPC did not return the PoolState after simulation,
+/// since there is no way to tell.
+pub fn is_pool_state_modified(pool_state_before: &[u8], outcome: &SimulationOutcome) -> bool {
+    match &outcome.post {
+        Some(post) => post.pool_state.as_deref() != Some(pool_state_before),
+        None => true,
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FieldChange {
+    pub field: &'static str,
+    pub before: String,
+    pub after: String,
+}
+
+/// Exhaustively destructures `$ty` so that this fails to compile if a field is added to it
+/// but not to `$field`s
+macro_rules! field_changes {
+    ($ty:ident, $before:expr, $after:expr, [$($field:ident),* $(,)?]) => {{
+        let $ty { $($field: _),* } = $before;
+        let mut changes = vec![];
+        $(
+            if $before.$field != $after.$field {
+                changes.push(FieldChange {
+                    field: stringify!($field),
+                    before: format!("{:?}", $before.$field),
+                    after: format!("{:?}", $after.$field),
+                });
+            }
+        )*
+        changes
+    }};
+}
+
+pub fn pool_state_changes(before: &PoolState, after: &PoolState) -> Vec<FieldChange> {
+    field_changes!(
+        PoolState,
+        before,
+        after,
+        [
+            total_sol_value,
+            trading_protocol_fee_bps,
+            lp_protocol_fee_bps,
+            version,
+            is_disabled,
+            is_rebalancing,
+            timelock_delay_hours,
+            admin,
+            rebalance_authority,
+            protocol_fee_beneficiary,
+            pricing_program,
+            lp_token_mint,
+        ]
+    )
+}
+
+pub fn lst_state_changes(before: &LstState, after: &LstState) -> Vec<FieldChange> {
+    field_changes!(
+        LstState,
+        before,
+        after,
+        [
+            is_input_disabled,
+            pool_reserves_bump,
+            protocol_fee_accumulator_bump,
+            padding,
+            max_share_bps,
+            sol_value,
+            mint,
+            sol_value_calculator,
+        ]
+    )
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum LstStateListChange {
+    Added(LstState),
+    Removed(LstState),
+    Modified {
+        mint: Pubkey,
+        changes: Vec<FieldChange>,
+    },
+}
+
+/// LstStates are matched by mint since removing an LST changes the indices of other LSTs
+pub fn lst_state_list_changes(before: &[LstState], after: &[LstState]) -> Vec<LstStateListChange> {
+    let mut res = vec![];
+    for b in before {
+        match after.iter().find(|a| a.mint == b.mint) {
+            None => res.push(LstStateListChange::Removed(*b)),
+            Some(a) => {
+                let changes = lst_state_changes(b, a);
+                if !changes.is_empty() {
+                    res.push(LstStateListChange::Modified {
+                        mint: b.mint,
+                        changes,
+                    });
+                }
+            }
+        }
+    }
+    let before_mints: HashSet<Pubkey> = before.iter().map(|b| b.mint).collect();
+    res.extend(
+        after
+            .iter()
+            .filter(|a| !before_mints.contains(&a.mint))
+            .map(|a| LstStateListChange::Added(*a)),
+    );
+    res
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PoolChanges {
+    pub pool_state: Vec<FieldChange>,
+    pub lst_state_list: Vec<LstStateListChange>,
+}
+
+impl PoolChanges {
+    /// Nonexistent accounts are treated as a zeroed PoolState and an empty LstStateList
+    pub fn new(
+        before: &PoolAccountsData,
+        after: &PoolAccountsData,
+    ) -> Result<Self, SControllerError> {
+        let zeroed = PoolState::zeroed();
+        let pool_state = |data: &Option<Vec<u8>>| match data {
+            Some(d) => try_pool_state(d).copied(),
+            None => Ok(zeroed),
+        };
+        let lst_state_list = |data: &Option<Vec<u8>>| match data {
+            Some(d) => try_lst_state_list(d).map(|l| l.to_vec()),
+            None => Ok(vec![]),
+        };
+        Ok(Self {
+            pool_state: pool_state_changes(
+                &pool_state(&before.pool_state)?,
+                &pool_state(&after.pool_state)?,
+            ),
+            lst_state_list: lst_state_list_changes(
+                &lst_state_list(&before.lst_state_list)?,
+                &lst_state_list(&after.lst_state_list)?,
+            ),
+        })
+    }
+}
+
+/// Human-readable summary of a simulated transaction
+pub struct TxPreview<'a> {
+    pub outcome: &'a SimulationOutcome,
+    pub changes: Option<Result<PoolChanges, SControllerError>>,
+}
+
+impl<'a> TxPreview<'a> {
+    pub fn new(pre: &PoolAccountsData, outcome: &'a SimulationOutcome) -> Self {
+        Self {
+            outcome,
+            changes: outcome
+                .post
+                .as_ref()
+                .map(|post| PoolChanges::new(pre, post)),
+        }
+    }
+}
+
+impl Display for TxPreview<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let SimulationOutcome {
+            err,
+            logs,
+            units_consumed,
+            ..
+        } = self.outcome;
+        match err {
+            Some(err) => writeln!(f, "Simulation failed: {err}")?,
+            None => writeln!(f, "Simulation succeeded")?,
+        }
+        match units_consumed {
+            Some(cus) => writeln!(f, "Compute units consumed: {cus}")?,
+            None => writeln!(f, "Compute units consumed: unknown")?,
+        }
+        match logs {
+            Some(logs) => {
+                writeln!(f, "Logs:")?;
+                for log in logs {
+                    writeln!(f, "  {log}")?;
+                }
+            }
+            None => writeln!(f, "No logs available")?,
+        }
+        if err.is_some() {
+            return Ok(());
+        }
+        let PoolChanges {
+            pool_state,
+            lst_state_list,
+        } = match &self.changes {
+            None => {
+                return writeln!(
+                    f,
+                    "Pool changes: RPC did not return accounts after simulation"
+                )
+            }
+            Some(Err(e)) => return writeln!(f, "Pool changes: failed to decode accounts: {e}"),
+            Some(Ok(changes)) => changes,
+        };
+        if pool_state.is_empty() {
+            writeln!(f, "PoolState: no changes")?;
+        } else {
+            writeln!(f, "PoolState:")?;
+            write_field_changes(f, "  ", pool_state)?;
+        }
+        if lst_state_list.is_empty() {
+            writeln!(f, "LstStateList: no changes")?;
+        } else {
+            writeln!(f, "LstStateList:")?;
+            for change in lst_state_list {
+                match change {
+                    LstStateListChange::Added(lst_state) => {
+                        writeln!(f, "  {} added: {lst_state:?}", lst_label(lst_state.mint))?
+                    }
+                    LstStateListChange::Removed(lst_state) => {
+                        writeln!(f, "  {} removed", lst_label(lst_state.mint))?
+                    }
+                    LstStateListChange::Modified { mint, changes } => {
+                        writeln!(f, "  {}:", lst_label(*mint))?;
+                        write_field_changes(f, "    ", changes)?;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+fn write_field_changes(
+    f: &mut std::fmt::Formatter<'_>,
+    indent: &str,
+    changes: &[FieldChange],
+) -> std::fmt::Result {
+    for FieldChange {
+        field,
+        before,
+        after,
+    } in changes
+    {
+        writeln!(f, "{indent}{field}: {before} -> {after}")?;
+    }
+    Ok(())
+}
+
+fn lst_label(mint: Pubkey) -> String {
+    find_sanctum_lst_by_mint(mint).map_or_else(|| mint.to_string(), |lst| lst.symbol.clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use s_controller_interface::{set_protocol_fee_ix, SetProtocolFeeIxArgs, SetProtocolFeeKeys};
+    use s_controller_lib::{initial_authority, program::POOL_STATE_ID};
+    use s_controller_test_utils::{PoolStateProgramTest, DEFAULT_POOL_STATE};
+    use sanctum_solana_test_utils::test_fixtures_dir;
+    use solana_program_test::{
+        processor, BanksClient, BanksTransactionResultWithSimulation, ProgramTest,
+    };
+    use solana_sdk::{
+        instruction::Instruction,
+        message::{v0::Message, VersionedMessage},
+        signature::{read_keypair_file, Keypair},
+        signer::Signer,
+    };
+
+    use super::*;
+
+    async fn fetch_pool_accounts_data_banks(
+        bc: &mut BanksClient,
+        pdas: PoolPdas,
+    ) -> PoolAccountsData {
+        let [pool_state, lst_state_list] = PoolAccountsData::addresses(pdas);
+        PoolAccountsData {
+            pool_state: bc.get_account(pool_state).await.unwrap().map(|a| a.data),
+            lst_state_list: bc
+                .get_account(lst_state_list)
+                .await
+                .unwrap()
+                .map(|a| a.data),
+        }
+    }
+
+    /// [`simulate_tx`] against a BanksClient.
+    ///
+    /// BanksClient simulation does not return accounts, so a successful `tx` is executed to obtain them.
+    async fn simulate_tx_banks(
+        bc: &mut BanksClient,
+        tx: &VersionedTransaction,
+        pdas: PoolPdas,
+    ) -> SimulationOutcome {
+        let BanksTransactionResultWithSimulation {
+            result,
+            simulation_details,
+        } = bc.simulate_transaction(tx.clone()).await.unwrap();
+        let err = result.unwrap().err();
+        let (logs, units_consumed) =
+            simulation_details.map_or((None, None), |d| (Some(d.logs), Some(d.units_consumed)));
+        let post = match err {
+            Some(_) => None,
+            None => {
+                bc.process_transaction(tx.clone()).await.unwrap();
+                Some(fetch_pool_accounts_data_banks(bc, pdas).await)
+            }
+        };
+        SimulationOutcome {
+            err,
+            logs,
+            units_consumed,
+            post,
+        }
+    }
+
+    async fn preview_banks(
+        ix: Instruction,
+        signer: &Keypair,
+    ) -> (PoolAccountsData, SimulationOutcome) {
+        let mut pt = ProgramTest::default().add_pool_state(DEFAULT_POOL_STATE);
+        pt.add_program(
+            "s_controller",
+            s_controller_lib::program::ID,
+            processor!(s_controller::entrypoint::process_instruction),
+        );
+        let (mut bc, payer, rbh) = pt.start().await;
+        let tx = VersionedTransaction::try_new(
+            VersionedMessage::V0(Message::try_compile(&payer.pubkey(), &[ix], &[], rbh).unwrap()),
+            &[&payer, signer],
+        )
+        .unwrap();
+        let pdas = PoolPdas::DEFAULT;
+        let pre = fetch_pool_accounts_data_banks(&mut bc, pdas).await;
+        let outcome = simulate_tx_banks(&mut bc, &tx, pdas).await;
+        (pre, outcome)
+    }
+
+    fn set_trading_protocol_fee_ix(admin: Pubkey, bps: u16) -> Instruction {
+        set_protocol_fee_ix(
+            SetProtocolFeeKeys {
+                admin,
+                pool_state: POOL_STATE_ID,
+            },
+            SetProtocolFeeIxArgs {
+                new_trading_protocol_fee_bps: Some(bps),
+                new_lp_protocol_fee_bps: None,
+            },
+        )
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn preview_shows_pool_state_changes() {
+        let mock_auth_kp = read_keypair_file(
+            test_fixtures_dir().join("s-controller-test-initial-authority-key.json"),
+        )
+        .unwrap();
+        let (pre, outcome) = preview_banks(
+            set_trading_protocol_fee_ix(initial_authority::ID, 5),
+            &mock_auth_kp,
+        )
+        .await;
+
+        assert!(outcome.err.is_none());
+        assert!(outcome.units_consumed.unwrap() > 0);
+        assert!(!outcome.logs.as_ref().unwrap().is_empty());
+        assert!(is_pool_state_modified(
+            pre.pool_state.as_ref().unwrap(),
+            &outcome
+        ));
+
+        let preview = TxPreview::new(&pre, &outcome);
+        assert_eq!(
+            preview.changes.as_ref().unwrap().as_ref().unwrap(),
+            &PoolChanges {
+                pool_state: vec![FieldChange {
+                    field: "trading_protocol_fee_bps",
+                    before: "0".into(),
+                    after: "5".into(),
+                }],
+                lst_state_list: vec![],
+            }
+        );
+        let s = preview.to_string();
+        assert!(s.contains("Simulation succeeded"));
+        assert!(s.contains("trading_protocol_fee_bps: 0 -> 5"));
+        assert!(s.contains("LstStateList: no changes"));
+    }
+
+    #[tokio::test]
+    async fn preview_shows_failed_simulation() {
+        let rando_kp = Keypair::new();
+        let (pre, outcome) =
+            preview_banks(set_trading_protocol_fee_ix(rando_kp.pubkey(), 5), &rando_kp).await;
+
+        assert!(outcome.err.is_some());
+        let s = TxPreview::new(&pre, &outcome).to_string();
+        assert!(s.contains("Simulation failed"));
+        assert!(!s.contains("PoolState"));
+    }
+
+    #[test]
+    fn pool_state_modified_if_no_post_accounts() {
+        assert!(is_pool_state_modified(
+            &[0u8; 8],
+            &SimulationOutcome::default()
+        ));
+    }
+
+    #[test]
+    fn lst_state_list_changes_matched_by_mint() {
+        let lst_state = |sol_value| LstState {
+            is_input_disabled: 0,
+            pool_reserves_bump: 0,
+            protocol_fee_accumulator_bump: 0,
+            padding: [0u8; 3],
+            max_share_bps: 0,
+            sol_value,
+            mint: Pubkey::new_unique(),
+            sol_value_calculator: Pubkey::new_unique(),
+        };
+        let removed = lst_state(1);
+        let unchanged = lst_state(2);
+        let modified_before = lst_state(3);
+        let modified_after = LstState {
+            sol_value: 4,
+            ..modified_before
+        };
+        let added = lst_state(5);
+
+        assert_eq!(
+            lst_state_list_changes(
+                &[removed, unchanged, modified_before],
+                &[unchanged, modified_after, added]
+            ),
+            vec![
+                LstStateListChange::Removed(removed),
+                LstStateListChange::Modified {
+                    mint: modified_before.mint,
+                    changes: vec![FieldChange {
+                        field: "sol_value",
+                        before: "3".into(),
+                        after: "4".into(),
+                    }],
+                },
+                LstStateListChange::Added(added),
+            ]
+        );
+    }
+}
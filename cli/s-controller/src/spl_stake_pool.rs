@@ -0,0 +1,102 @@
+//! Minimal SPL stake pool DepositSol and WithdrawSol instruction builders for rebalancing
+
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::{Pubkey, PubkeyError},
+    stake, system_program, sysvar,
+};
+use spl_calculator_interface::SplStakePool;
+
+const DEPOSIT_SOL_DISCM: u8 = 14;
+
+const WITHDRAW_SOL_DISCM: u8 = 16;
+
+/// A deserialized SPL stake pool along with its address and the stake pool program that owns it
+pub struct StakePoolAccounts<'a> {
+    pub program_id: Pubkey,
+    pub stake_pool_addr: Pubkey,
+    pub stake_pool: &'a SplStakePool,
+}
+
+impl<'a> StakePoolAccounts<'a> {
+    pub fn withdraw_authority(&self) -> Result<Pubkey, PubkeyError> {
+        Pubkey::create_program_address(
+            &[
+                self.stake_pool_addr.as_ref(),
+                b"withdraw",
+                &[self.stake_pool.stake_withdraw_bump_seed],
+            ],
+            &self.program_id,
+        )
+    }
+
+    /// Deposits `lamports` SOL from `lamports_from` for LST minted to `pool_tokens_to`.
+    ///
+    /// The referral fee, if any, is also paid to `pool_tokens_to`.
+    pub fn deposit_sol_ix(
+        &self,
+        lamports_from: Pubkey,
+        pool_tokens_to: Pubkey,
+        lamports: u64,
+    ) -> Result<Instruction, PubkeyError> {
+        let mut accounts = vec![
+            AccountMeta::new(self.stake_pool_addr, false),
+            AccountMeta::new_readonly(self.withdraw_authority()?, false),
+            AccountMeta::new(self.stake_pool.reserve_stake, false),
+            AccountMeta::new(lamports_from, true),
+            AccountMeta::new(pool_tokens_to, false),
+            AccountMeta::new(self.stake_pool.manager_fee_account, false),
+            AccountMeta::new(pool_tokens_to, false),
+            AccountMeta::new(self.stake_pool.pool_mint, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new_readonly(self.stake_pool.token_program_id, false),
+        ];
+        if let Some(sol_deposit_authority) = self.stake_pool.sol_deposit_authority {
+            accounts.push(AccountMeta::new_readonly(sol_deposit_authority, true));
+        }
+        Ok(Instruction {
+            program_id: self.program_id,
+            accounts,
+            data: ix_data(DEPOSIT_SOL_DISCM, lamports),
+        })
+    }
+
+    /// Burns `pool_tokens` LST from `pool_tokens_from` for SOL sent to `lamports_to`
+    pub fn withdraw_sol_ix(
+        &self,
+        user_transfer_authority: Pubkey,
+        pool_tokens_from: Pubkey,
+        lamports_to: Pubkey,
+        pool_tokens: u64,
+    ) -> Result<Instruction, PubkeyError> {
+        let mut accounts = vec![
+            AccountMeta::new(self.stake_pool_addr, false),
+            AccountMeta::new_readonly(self.withdraw_authority()?, false),
+            AccountMeta::new_readonly(user_transfer_authority, true),
+            AccountMeta::new(pool_tokens_from, false),
+            AccountMeta::new(self.stake_pool.reserve_stake, false),
+            AccountMeta::new(lamports_to, false),
+            AccountMeta::new(self.stake_pool.manager_fee_account, false),
+            AccountMeta::new(self.stake_pool.pool_mint, false),
+            AccountMeta::new_readonly(sysvar::clock::ID, false),
+            AccountMeta::new_readonly(sysvar::stake_history::ID, false),
+            AccountMeta::new_readonly(stake::program::ID, false),
+            AccountMeta::new_readonly(self.stake_pool.token_program_id, false),
+        ];
+        if let Some(sol_withdraw_authority) = self.stake_pool.sol_withdraw_authority {
+            accounts.push(AccountMeta::new_readonly(sol_withdraw_authority, true));
+        }
+        Ok(Instruction {
+            program_id: self.program_id,
+            accounts,
+            data: ix_data(WITHDRAW_SOL_DISCM, pool_tokens),
+        })
+    }
+}
+
+fn ix_data(discm: u8, amount: u64) -> Vec<u8> {
+    let mut data = Vec::with_capacity(9);
+    data.push(discm);
+    data.extend(amount.to_le_bytes());
+    data
+}
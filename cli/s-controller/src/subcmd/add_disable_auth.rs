@@ -4,17 +4,17 @@ use clap::{
     builder::{StringValueParser, TypedValueParser},
     Args,
 };
+use s_cli_utils::parse_signer_or_pubkey;
 use s_controller_interface::add_disable_pool_authority_ix_with_program_id;
-use s_controller_lib::{find_pool_state_address, try_pool_state, AddDisablePoolAuthorityFreeArgs};
-use sanctum_solana_cli_utils::{parse_signer, TxSendingNonblockingRpcClient};
+use s_controller_lib::{try_pool_state, AddDisablePoolAuthorityFreeArgs};
 use solana_readonly_account::sdk::KeyedAccount;
-use solana_sdk::{
-    message::{v0::Message, VersionedMessage},
-    pubkey::Pubkey,
-    transaction::VersionedTransaction,
-};
+use solana_sdk::pubkey::Pubkey;
 
-use crate::{common::verify_admin, rpc::fetch_pool_state};
+use crate::{
+    common::{find_pool_pdas, verify_admin},
+    rpc::fetch_pool_state,
+    tx::handle_tx,
+};
 
 use super::Subcmd;
 
@@ -50,11 +50,12 @@ impl AddDisableAuthArgs {
         let payer = args.config.signer();
         let rpc = args.config.nonblocking_rpc_client();
         let program_id = args.program;
+        let pdas = find_pool_pdas(program_id, args.pool);
 
-        let admin_signer = admin.map(|s| parse_signer(&s).unwrap());
+        let admin_signer = admin.map(|s| parse_signer_or_pubkey(&s).unwrap());
         let admin = admin_signer.as_ref().unwrap_or(&payer);
 
-        let pool_state_acc = fetch_pool_state(&rpc, program_id).await;
+        let pool_state_acc = fetch_pool_state(&rpc, pdas).await;
 
         let pool_state = try_pool_state(&pool_state_acc.data).unwrap();
         verify_admin(pool_state, admin.pubkey()).unwrap();
@@ -65,11 +66,11 @@ impl AddDisableAuthArgs {
                 payer: payer.pubkey(),
                 new_authority,
                 pool_state_acc: KeyedAccount {
-                    pubkey: find_pool_state_address(program_id).0,
+                    pubkey: pdas.pool_state,
                     account: pool_state_acc,
                 },
             }
-            .resolve_for_prog(program_id)
+            .resolve_for_pool(pdas)
             .unwrap(),
         )
         .unwrap();
@@ -77,13 +78,11 @@ impl AddDisableAuthArgs {
         let mut signers = vec![payer.as_ref(), admin.as_ref()];
         signers.dedup();
 
-        let rbh = rpc.get_latest_blockhash().await.unwrap();
-        let tx = VersionedTransaction::try_new(
-            VersionedMessage::V0(Message::try_compile(&payer.pubkey(), &[ix], &[], rbh).unwrap()),
-            &signers,
-        )
-        .unwrap();
+        let tx = args
+            .nonce
+            .build_tx(&rpc, &payer.pubkey(), &[ix], &[], &signers)
+            .await;
 
-        rpc.handle_tx(&tx, args.send_mode).await;
+        handle_tx(&rpc, &tx, args.tx, pdas).await;
     }
 }
@@ -0,0 +1,56 @@
+use clap::{
+    builder::{StringValueParser, TypedValueParser},
+    Args,
+};
+use jupiter_amm_interface::SwapMode;
+use solana_sdk::native_token::sol_to_lamports;
+
+use crate::{
+    lst_arg::LstArg,
+    user_swap::{SwapMint, UserSwap, UserSwapArgs},
+};
+
+use super::Subcmd;
+
+#[derive(Args, Debug)]
+#[command(
+    about = "Add liquidity to the pool in a single LST",
+    long_about = "Add an exact amount of a single LST to the pool for LP tokens, outputting the quote to stderr.
+The LST is transferred from the authority's associated token account. LP tokens are minted to the authority's associated token account, which is created if it does not exist."
+)]
+pub struct AddLiquidityArgs {
+    #[arg(
+        help = "Mint of the LST to add. Can either be a pubkey or case-insensitive symbol of a token on sanctum-lst-list. e.g. 'jitosol'",
+        value_parser = StringValueParser::new().try_map(|s| LstArg::parse_arg(&s)),
+    )]
+    pub lst_mint: LstArg,
+
+    #[arg(help = "Amount of LST to add")]
+    pub amount: f64,
+
+    #[command(flatten)]
+    pub user_swap: UserSwapArgs,
+}
+
+impl AddLiquidityArgs {
+    pub async fn run(args: crate::Args) {
+        let Self {
+            lst_mint,
+            amount,
+            user_swap,
+        } = match &args.subcmd {
+            Subcmd::AddLiquidity(a) => a,
+            _ => unreachable!(),
+        };
+
+        UserSwap {
+            input: SwapMint::Lst(lst_mint.mint()),
+            output: SwapMint::LpToken,
+            amount: sol_to_lamports(*amount), // assume all LSTs are 9 d.p.
+            swap_mode: SwapMode::ExactIn,
+            args: user_swap,
+        }
+        .run(&args)
+        .await;
+    }
+}
@@ -0,0 +1,177 @@
+use std::error::Error;
+
+use clap::{
+    builder::{StringValueParser, TypedValueParser},
+    Args,
+};
+use s_controller_lib::{
+    add_liquidity_multi_ix_by_mints_full_for_pool, try_pool_state,
+    AddLiquidityMultiByMintsFreeArgs, AddLiquidityMultiIxAmts, MultiLiquidityLst,
+};
+use sanctum_associated_token_lib::FindAtaAddressArgs;
+use sanctum_token_lib::MintWithTokenProgram;
+use solana_sdk::native_token::sol_to_lamports;
+use spl_associated_token_account::instruction::create_associated_token_account;
+
+use crate::{
+    common::{find_pool_pdas, price_lp_tokens_to_mint_accounts},
+    lst_arg::LstArg,
+    lut::fetch_luts,
+    tx::handle_tx,
+};
+
+use super::Subcmd;
+
+#[derive(Clone, Copy, Debug)]
+pub struct LstAmountArg {
+    pub lst: LstArg,
+    pub amount: f64,
+}
+
+impl LstAmountArg {
+    pub fn parse_arg(arg: &str) -> Result<Self, Box<dyn Error + Send + Sync + 'static>> {
+        let (lst, amount) = arg
+            .split_once('=')
+            .ok_or_else(|| format!("Expected <LST>=<AMOUNT>, got {arg}"))?;
+        Ok(Self {
+            lst: LstArg::parse_arg(lst)?,
+            amount: amount.parse()?,
+        })
+    }
+}
+
+#[derive(Args, Debug)]
+#[command(
+    about = "Add liquidity to the pool in multiple LSTs at once",
+    long_about = "Add liquidity to the pool in multiple LSTs at once, minting LP tokens once for their summed SOL value.
+LSTs are transferred from the payer's associated token accounts. All LSTs must be on sanctum-lst-list."
+)]
+pub struct AddLiquidityMultiArgs {
+    #[arg(
+        help = "LSTs and amounts to add, in the form <LST>=<AMOUNT>. LST can either be a pubkey or case-insensitive symbol of a token on sanctum-lst-list. e.g. 'bsol=1.5 jitosol=2'",
+        num_args = 1..,
+        value_parser = StringValueParser::new().try_map(|s| LstAmountArg::parse_arg(&s)),
+    )]
+    pub lst_amounts: Vec<LstAmountArg>,
+
+    #[arg(
+        long,
+        short,
+        help = "Minimum amount of LP tokens to receive",
+        default_value_t = 0.0
+    )]
+    pub min_lp_out: f64,
+}
+
+impl AddLiquidityMultiArgs {
+    pub async fn run(args: crate::Args) {
+        let Self {
+            lst_amounts,
+            min_lp_out,
+        } = match args.subcmd {
+            Subcmd::AddLiquidityMulti(a) => a,
+            _ => unreachable!(),
+        };
+
+        let payer = args.config.signer();
+        let rpc = args.config.nonblocking_rpc_client();
+        let program_id = args.program;
+        let pdas = find_pool_pdas(program_id, args.pool);
+
+        let mut fetched_accs = rpc
+            .get_multiple_accounts(&[pdas.lst_state_list, pdas.pool_state])
+            .await
+            .unwrap();
+        let pool_state_acc = fetched_accs.pop().unwrap().unwrap();
+        let lst_state_list_acc = fetched_accs.pop().unwrap().unwrap();
+        let pool_state = try_pool_state(&pool_state_acc.data).unwrap();
+        let lp_token_mint = pool_state.lp_token_mint;
+        let pricing_program = pool_state.pricing_program;
+
+        let lp_token_program = rpc.get_account(&lp_token_mint).await.unwrap().owner;
+        let dst_lp_acc = FindAtaAddressArgs {
+            wallet: payer.pubkey(),
+            mint: lp_token_mint,
+            token_program: lp_token_program,
+        }
+        .find_ata_address()
+        .0;
+
+        let mut lsts = Vec::with_capacity(lst_amounts.len());
+        let mut sol_val_calc_accounts = Vec::with_capacity(lst_amounts.len());
+        let mut pricing_program_accounts = Vec::with_capacity(lst_amounts.len());
+        let mut amounts = Vec::with_capacity(lst_amounts.len());
+        for LstAmountArg { lst, amount } in lst_amounts.iter() {
+            let (token_program, calc_accounts) =
+                match (lst.token_program(), lst.sol_value_calculator_accounts_of()) {
+                    (Some(tp), Some(accs)) => (tp, accs),
+                    _ => {
+                        eprintln!("{} not on sanctum-lst-list", lst.mint());
+                        std::process::exit(-1);
+                    }
+                };
+            lsts.push(MultiLiquidityLst {
+                lst_mint: MintWithTokenProgram {
+                    pubkey: lst.mint(),
+                    token_program,
+                },
+                lst_acc: FindAtaAddressArgs {
+                    wallet: payer.pubkey(),
+                    mint: lst.mint(),
+                    token_program,
+                }
+                .find_ata_address()
+                .0,
+            });
+            sol_val_calc_accounts.push(calc_accounts);
+            pricing_program_accounts.push(price_lp_tokens_to_mint_accounts(
+                pricing_program,
+                lst.mint(),
+            ));
+            amounts.push(sol_to_lamports(*amount)); // assume all LSTs are 9 d.p.
+        }
+
+        let mut ixs = if rpc.get_account(&dst_lp_acc).await.is_err() {
+            vec![create_associated_token_account(
+                &payer.pubkey(),
+                &payer.pubkey(),
+                &lp_token_mint,
+                &lp_token_program,
+            )]
+        } else {
+            vec![]
+        };
+        let sol_val_calc_account_suffixes: Vec<_> =
+            sol_val_calc_accounts.iter().map(Vec::as_slice).collect();
+        let pricing_program_account_suffixes: Vec<_> =
+            pricing_program_accounts.iter().map(Vec::as_slice).collect();
+        ixs.push(
+            add_liquidity_multi_ix_by_mints_full_for_pool(
+                program_id,
+                pdas.into(),
+                AddLiquidityMultiByMintsFreeArgs {
+                    signer: payer.pubkey(),
+                    dst_lp_acc,
+                    pool_state: &pool_state_acc,
+                    lst_state_list: &lst_state_list_acc,
+                    lsts: &lsts,
+                },
+                AddLiquidityMultiIxAmts {
+                    lst_amounts: &amounts,
+                    min_lp_out: sol_to_lamports(min_lp_out),
+                },
+                &sol_val_calc_account_suffixes,
+                &pricing_program_account_suffixes,
+            )
+            .unwrap(),
+        );
+
+        let luts = fetch_luts(&rpc, args.lut).await;
+        let tx = args
+            .nonce
+            .build_tx(&rpc, &payer.pubkey(), &ixs, &luts, &[payer.as_ref()])
+            .await;
+
+        handle_tx(&rpc, &tx, args.tx, pdas).await;
+    }
+}
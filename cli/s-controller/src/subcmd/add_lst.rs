@@ -2,21 +2,18 @@ use clap::{
     builder::{StringValueParser, TypedValueParser},
     Args,
 };
+use s_cli_utils::parse_signer_or_pubkey;
 use s_controller_interface::add_lst_ix_with_program_id;
-use s_controller_lib::{
-    find_pool_state_address, try_pool_state, AddLstFreeArgs, ADD_LST_IX_COMPUTE_UNIT_CEIL,
-};
-use sanctum_solana_cli_utils::{parse_signer, TxSendingNonblockingRpcClient};
+use s_controller_lib::{try_pool_state, AddLstFreeArgs, ADD_LST_IX_COMPUTE_UNIT_CEIL};
 use solana_readonly_account::{keyed::Keyed, ReadonlyAccountData};
-use solana_sdk::{
-    compute_budget::ComputeBudgetInstruction,
-    message::{v0::Message, VersionedMessage},
-    pubkey::Pubkey,
-    transaction::VersionedTransaction,
-};
+use solana_sdk::{compute_budget::ComputeBudgetInstruction, pubkey::Pubkey};
 use std::str::FromStr;
 
-use crate::{common::verify_admin, lst_arg::LstArg};
+use crate::{
+    common::{find_pool_pdas, verify_admin},
+    lst_arg::LstArg,
+    tx::handle_tx,
+};
 
 use super::Subcmd;
 
@@ -59,8 +56,9 @@ impl AddLstArgs {
         let payer = args.config.signer();
         let rpc = args.config.nonblocking_rpc_client();
         let program_id = args.program;
+        let pdas = find_pool_pdas(program_id, args.pool);
 
-        let admin_signer = admin.map(|s| parse_signer(&s).unwrap());
+        let admin_signer = admin.map(|s| parse_signer_or_pubkey(&s).unwrap());
         let admin = admin_signer.as_ref().unwrap_or(&payer);
 
         let sol_val_calc = sol_val_calc.unwrap_or_else(|| {
@@ -68,7 +66,7 @@ impl AddLstArgs {
                 .expect("LST not found on list, --sol-val-calc must be provided")
         });
 
-        let pool_state_addr = find_pool_state_address(program_id).0;
+        let pool_state_addr = pdas.pool_state;
         let mut fetched_accs = rpc
             .get_multiple_accounts(&[pool_state_addr, mint.mint()])
             .await
@@ -82,41 +80,38 @@ impl AddLstArgs {
         let (keys, _bumps) = AddLstFreeArgs {
             payer: payer.pubkey(),
             sol_value_calculator: sol_val_calc,
-            pool_state: pool_state_acc,
+            pool_state: Keyed {
+                pubkey: pool_state_addr,
+                account: pool_state_acc,
+            },
             lst_mint: Keyed {
                 pubkey: mint.mint(),
                 account: lst_mint_acc,
             },
         }
-        .resolve_for_prog(program_id)
+        .resolve_for_pool(pdas)
         .unwrap();
         let ix = add_lst_ix_with_program_id(program_id, keys).unwrap();
 
         let mut signers = vec![payer.as_ref(), admin.as_ref()];
         signers.dedup();
 
-        let rbh = rpc.get_latest_blockhash().await.unwrap();
-        let tx = VersionedTransaction::try_new(
-            VersionedMessage::V0(
-                Message::try_compile(
-                    &payer.pubkey(),
-                    &[
-                        ComputeBudgetInstruction::set_compute_unit_limit(
-                            ADD_LST_IX_COMPUTE_UNIT_CEIL,
-                        ),
-                        // TODO: make compute unit price dynamic
-                        ComputeBudgetInstruction::set_compute_unit_price(20),
-                        ix,
-                    ],
-                    &[],
-                    rbh,
-                )
-                .unwrap(),
-            ),
-            &signers,
-        )
-        .unwrap();
+        let tx = args
+            .nonce
+            .build_tx(
+                &rpc,
+                &payer.pubkey(),
+                &[
+                    ComputeBudgetInstruction::set_compute_unit_limit(ADD_LST_IX_COMPUTE_UNIT_CEIL),
+                    // TODO: make compute unit price dynamic
+                    ComputeBudgetInstruction::set_compute_unit_price(20),
+                    ix,
+                ],
+                &[],
+                &signers,
+            )
+            .await;
 
-        rpc.handle_tx(&tx, args.send_mode).await;
+        handle_tx(&rpc, &tx, args.tx, pdas).await;
     }
 }
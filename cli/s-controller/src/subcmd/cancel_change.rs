@@ -0,0 +1,106 @@
+use std::str::FromStr;
+
+use clap::{
+    builder::{StringValueParser, TypedValueParser},
+    Args,
+};
+use s_cli_utils::parse_signer_or_pubkey;
+use s_controller_interface::{cancel_pending_change_ix_with_program_id, CancelPendingChangeIxArgs};
+use s_controller_lib::{
+    index_to_u32, try_disable_pool_authority_list, try_pool_state, CancelPendingChangeFreeArgs,
+};
+use solana_sdk::pubkey::Pubkey;
+
+use crate::{
+    common::{find_pool_pdas, verify_disable_pool_authority},
+    tx::handle_tx,
+};
+
+use super::Subcmd;
+
+#[derive(Args, Debug)]
+#[command(
+    long_about = "Cancels a pending change queued in timelock mode. Can be done by the admin or any disable pool authority."
+)]
+pub struct CancelChangeArgs {
+    #[arg(
+        long,
+        short,
+        help = "The program's admin or a disable pool authority. Defaults to config wallet if not set."
+    )]
+    pub authority: Option<String>,
+
+    #[arg(
+        long,
+        short,
+        help = "The account to refund rent SOL to. Defaults to config wallet if not set.",
+        value_parser = StringValueParser::new().try_map(|s| Pubkey::from_str(&s)),
+    )]
+    pub refund_rent_to: Option<Pubkey>,
+
+    #[arg(help = "Index of the pending change on the pending change list")]
+    pub index: usize,
+}
+
+impl CancelChangeArgs {
+    pub async fn run(args: crate::Args) {
+        let Self {
+            authority,
+            refund_rent_to,
+            index,
+        } = match args.subcmd {
+            Subcmd::CancelChange(a) => a,
+            _ => unreachable!(),
+        };
+
+        let payer = args.config.signer();
+        let rpc = args.config.nonblocking_rpc_client();
+        let program_id = args.program;
+        let pdas = find_pool_pdas(program_id, args.pool);
+
+        let authority_signer = authority.map(|s| parse_signer_or_pubkey(&s).unwrap());
+        let authority = authority_signer.as_ref().unwrap_or(&payer);
+        let refund_rent_to = refund_rent_to.unwrap_or_else(|| payer.pubkey());
+
+        let mut fetched_accs = rpc
+            .get_multiple_accounts(&[pdas.pool_state, pdas.disable_pool_authority_list])
+            .await
+            .unwrap();
+        let disable_auth_list_acc = fetched_accs.pop().unwrap();
+        let pool_state_acc = fetched_accs.pop().unwrap().unwrap();
+        let pool_state = try_pool_state(&pool_state_acc.data).unwrap();
+
+        if pool_state.admin != authority.pubkey() {
+            let disable_auth_list = disable_auth_list_acc
+                .as_ref()
+                .map_or(Ok(&[][..]), |acc| {
+                    try_disable_pool_authority_list(&acc.data)
+                })
+                .unwrap();
+            verify_disable_pool_authority(disable_auth_list, authority.pubkey()).unwrap();
+        }
+
+        let ix = cancel_pending_change_ix_with_program_id(
+            program_id,
+            CancelPendingChangeFreeArgs {
+                signer: authority.pubkey(),
+                refund_rent_to,
+            }
+            .resolve_for_pool(pdas),
+            CancelPendingChangeIxArgs {
+                pending_change_index: index_to_u32(index).unwrap(),
+            },
+        )
+        .unwrap();
+
+        let mut signers = vec![payer.as_ref(), authority.as_ref()];
+        signers.dedup();
+
+        let tx = args
+            .nonce
+            .build_tx(&rpc, &payer.pubkey(), &[ix], &[], &signers)
+            .await;
+
+        handle_tx(&rpc, &tx, args.tx, pdas).await;
+    }
+}
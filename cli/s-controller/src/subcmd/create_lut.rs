@@ -0,0 +1,84 @@
+use clap::Args;
+use s_cli_utils::parse_signer_or_pubkey;
+use solana_sdk::{
+    address_lookup_table::{instruction::create_lookup_table, state::LOOKUP_TABLE_MAX_ADDRESSES},
+    commitment_config::CommitmentConfig,
+};
+
+use crate::{common::find_pool_pdas, lut::fetch_pool_lut_addresses, tx::handle_tx};
+
+use super::{extend_lut::extend_lut_ixs, Subcmd};
+
+#[derive(Args, Debug)]
+#[command(
+    about = "Create an address lookup table containing the pool's static accounts",
+    long_about = "Create an address lookup table containing the pool's static accounts: the pool's PDAs, LST mints, reserves, protocol fee accumulators, the SOL value calculator accounts of LSTs on sanctum-lst-list and the pricing program's accounts.
+Pass the created table to other commands with --lut to fit more LSTs per transaction. Run extend-lut after LSTs are added to the pool to keep it up to date."
+)]
+pub struct CreateLutArgs {
+    #[arg(
+        long,
+        short,
+        help = "The lookup table's authority. Defaults to config wallet if not set."
+    )]
+    pub authority: Option<String>,
+}
+
+impl CreateLutArgs {
+    pub async fn run(args: crate::Args) {
+        let Self { authority } = match args.subcmd {
+            Subcmd::CreateLut(a) => a,
+            _ => unreachable!(),
+        };
+
+        let payer = args.config.signer();
+        let rpc = args.config.nonblocking_rpc_client();
+        let program_id = args.program;
+        let pdas = find_pool_pdas(program_id, args.pool);
+
+        let authority_signer = authority.map(|s| parse_signer_or_pubkey(&s).unwrap());
+        let authority = authority_signer.as_ref().unwrap_or(&payer);
+
+        let addresses = fetch_pool_lut_addresses(&rpc, program_id, pdas).await;
+        if addresses.len() > LOOKUP_TABLE_MAX_ADDRESSES {
+            eprintln!(
+                "Pool has {} static accounts, only the first {LOOKUP_TABLE_MAX_ADDRESSES} will be added",
+                addresses.len()
+            );
+        }
+        let addresses = &addresses[..addresses.len().min(LOOKUP_TABLE_MAX_ADDRESSES)];
+
+        // recent_slot must be in the SlotHashes sysvar
+        let recent_slot = rpc
+            .get_slot_with_commitment(CommitmentConfig::finalized())
+            .await
+            .unwrap();
+        let (create_ix, lut) = create_lookup_table(authority.pubkey(), payer.pubkey(), recent_slot);
+
+        let mut signers = vec![payer.as_ref(), authority.as_ref()];
+        signers.dedup();
+
+        let mut extend_ixs =
+            extend_lut_ixs(lut, authority.pubkey(), payer.pubkey(), addresses).into_iter();
+        let mut ixs = vec![create_ix];
+        ixs.extend(extend_ixs.next());
+        loop {
+            // since we're doing it sequentially, fetch blockhash
+            // on every iter to make sure it wont expire
+            let tx = args
+                .nonce
+                .build_tx(&rpc, &payer.pubkey(), &ixs, &[], &signers)
+                .await;
+            handle_tx(&rpc, &tx, args.tx, pdas).await;
+            ixs = match extend_ixs.next() {
+                Some(ix) => vec![ix],
+                None => break,
+            };
+        }
+
+        eprintln!(
+            "Lookup table {lut} created with {} addresses. Pass --lut {lut} to use it.",
+            addresses.len()
+        );
+    }
+}
@@ -2,21 +2,17 @@ use clap::{
     builder::{StringValueParser, TypedValueParser},
     Args,
 };
+use s_cli_utils::parse_signer_or_pubkey;
 use s_controller_interface::{disable_lst_input_ix_with_program_id, DisableLstInputIxArgs};
-use s_controller_lib::{
-    find_lst_state_list_address, find_pool_state_address, try_pool_state,
-    DisableEnableLstInputByMintFreeArgs,
-};
-use sanctum_solana_cli_utils::{parse_signer, TxSendingNonblockingRpcClient};
+use s_controller_lib::{try_pool_state, DisableEnableLstInputByMintFreeArgs};
 use solana_readonly_account::ReadonlyAccountData;
-use solana_sdk::{
-    message::{v0::Message, VersionedMessage},
-    pubkey::Pubkey,
-    transaction::VersionedTransaction,
-};
+use solana_sdk::pubkey::Pubkey;
 use std::str::FromStr;
 
-use crate::common::verify_admin;
+use crate::{
+    common::{find_pool_pdas, verify_admin},
+    tx::handle_tx,
+};
 
 use super::Subcmd;
 
@@ -47,12 +43,13 @@ impl DisableLstInputArgs {
         let payer = args.config.signer();
         let rpc = args.config.nonblocking_rpc_client();
         let program_id = args.program;
+        let pdas = find_pool_pdas(program_id, args.pool);
 
-        let admin_signer = admin.map(|s| parse_signer(&s).unwrap());
+        let admin_signer = admin.map(|s| parse_signer_or_pubkey(&s).unwrap());
         let admin = admin_signer.as_ref().unwrap_or(&payer);
 
-        let pool_state_addr = find_pool_state_address(program_id).0;
-        let lst_state_list_addr = find_lst_state_list_address(program_id).0;
+        let pool_state_addr = pdas.pool_state;
+        let lst_state_list_addr = pdas.lst_state_list;
         let mut fetched_accs = rpc
             .get_multiple_accounts(&[pool_state_addr, lst_state_list_addr])
             .await
@@ -68,7 +65,7 @@ impl DisableLstInputArgs {
             pool_state: pool_state_acc,
             lst_state_list: lst_state_list_acc,
         }
-        .resolve_disable_for_prog(program_id)
+        .resolve_disable_for_pool(pdas)
         .unwrap();
         let ix = disable_lst_input_ix_with_program_id(
             program_id,
@@ -82,13 +79,11 @@ impl DisableLstInputArgs {
         let mut signers = vec![payer.as_ref(), admin.as_ref()];
         signers.dedup();
 
-        let rbh = rpc.get_latest_blockhash().await.unwrap();
-        let tx = VersionedTransaction::try_new(
-            VersionedMessage::V0(Message::try_compile(&payer.pubkey(), &[ix], &[], rbh).unwrap()),
-            &signers,
-        )
-        .unwrap();
+        let tx = args
+            .nonce
+            .build_tx(&rpc, &payer.pubkey(), &[ix], &[], &signers)
+            .await;
 
-        rpc.handle_tx(&tx, args.send_mode).await;
+        handle_tx(&rpc, &tx, args.tx, pdas).await;
     }
 }
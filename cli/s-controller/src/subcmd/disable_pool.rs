@@ -1,15 +1,12 @@
 use clap::Args;
+use s_cli_utils::parse_signer_or_pubkey;
 use s_controller_interface::disable_pool_ix_with_program_id;
 use s_controller_lib::{try_disable_pool_authority_list, try_pool_state, DisablePoolFreeArgs};
-use sanctum_solana_cli_utils::{parse_signer, TxSendingNonblockingRpcClient};
-use solana_sdk::{
-    message::{v0::Message, VersionedMessage},
-    transaction::VersionedTransaction,
-};
 
 use crate::{
-    common::verify_disable_pool_authority,
+    common::{find_pool_pdas, verify_disable_pool_authority},
     rpc::{fetch_disable_pool_authority_list, fetch_pool_state},
+    tx::handle_tx,
 };
 
 use super::Subcmd;
@@ -41,17 +38,18 @@ impl DisablePoolArgs {
         let payer = args.config.signer();
         let rpc = args.config.nonblocking_rpc_client();
         let program_id = args.program;
+        let pdas = find_pool_pdas(program_id, args.pool);
 
-        let authority_signer = authority.map(|s| parse_signer(&s).unwrap());
+        let authority_signer = authority.map(|s| parse_signer_or_pubkey(&s).unwrap());
         let authority = authority_signer.as_ref().unwrap_or(&payer);
 
-        let pool_state_acc = fetch_pool_state(&rpc, program_id).await;
+        let pool_state_acc = fetch_pool_state(&rpc, pdas).await;
         let pool_state = try_pool_state(&pool_state_acc.data).unwrap();
 
         // check if authority is either the admin or a disable pool authority
         if pool_state.admin != authority.pubkey() {
             let disable_pool_authority_list_acc =
-                fetch_disable_pool_authority_list(&rpc, program_id).await;
+                fetch_disable_pool_authority_list(&rpc, pdas).await;
             let disable_pool_authority_list =
                 try_disable_pool_authority_list(&disable_pool_authority_list_acc.data).unwrap();
 
@@ -63,20 +61,18 @@ impl DisablePoolArgs {
             DisablePoolFreeArgs {
                 signer: authority.pubkey(),
             }
-            .resolve_for_prog(program_id),
+            .resolve_for_pool(pdas),
         )
         .unwrap();
 
         let mut signers = vec![payer.as_ref(), authority.as_ref()];
         signers.dedup();
 
-        let rbh = rpc.get_latest_blockhash().await.unwrap();
-        let tx = VersionedTransaction::try_new(
-            VersionedMessage::V0(Message::try_compile(&payer.pubkey(), &[ix], &[], rbh).unwrap()),
-            &signers,
-        )
-        .unwrap();
+        let tx = args
+            .nonce
+            .build_tx(&rpc, &payer.pubkey(), &[ix], &[], &signers)
+            .await;
 
-        rpc.handle_tx(&tx, args.send_mode).await;
+        handle_tx(&rpc, &tx, args.tx, pdas).await;
     }
 }
@@ -2,18 +2,14 @@ use clap::{
     builder::{StringValueParser, TypedValueParser},
     Args,
 };
+use s_cli_utils::parse_signer_or_pubkey;
 use s_controller_interface::{enable_lst_input_ix_with_program_id, EnableLstInputIxArgs};
-use s_controller_lib::{
-    find_lst_state_list_address, find_pool_state_address, DisableEnableLstInputByMintFreeArgs,
-};
-use sanctum_solana_cli_utils::{parse_signer, TxSendingNonblockingRpcClient};
-use solana_sdk::{
-    message::{v0::Message, VersionedMessage},
-    pubkey::Pubkey,
-    transaction::VersionedTransaction,
-};
+use s_controller_lib::DisableEnableLstInputByMintFreeArgs;
+use solana_sdk::pubkey::Pubkey;
 use std::str::FromStr;
 
+use crate::{common::find_pool_pdas, tx::handle_tx};
+
 use super::Subcmd;
 
 #[derive(Args, Debug)]
@@ -43,12 +39,13 @@ impl EnableLstInputArgs {
         let payer = args.config.signer();
         let rpc = args.config.nonblocking_rpc_client();
         let program_id = args.program;
+        let pdas = find_pool_pdas(program_id, args.pool);
 
-        let admin_signer = admin.map(|s| parse_signer(&s).unwrap());
+        let admin_signer = admin.map(|s| parse_signer_or_pubkey(&s).unwrap());
         let admin = admin_signer.as_ref().unwrap_or(&payer);
 
-        let pool_state_addr = find_pool_state_address(program_id).0;
-        let lst_state_list_addr = find_lst_state_list_address(program_id).0;
+        let pool_state_addr = pdas.pool_state;
+        let lst_state_list_addr = pdas.lst_state_list;
         let mut fetched_accs = rpc
             .get_multiple_accounts(&[pool_state_addr, lst_state_list_addr])
             .await
@@ -61,7 +58,7 @@ impl EnableLstInputArgs {
             pool_state: pool_state_acc,
             lst_state_list: lst_state_list_acc,
         }
-        .resolve_enable_for_prog(program_id)
+        .resolve_enable_for_pool(pdas)
         .unwrap();
         let ix = enable_lst_input_ix_with_program_id(
             program_id,
@@ -75,13 +72,11 @@ impl EnableLstInputArgs {
         let mut signers = vec![payer.as_ref(), admin.as_ref()];
         signers.dedup();
 
-        let rbh = rpc.get_latest_blockhash().await.unwrap();
-        let tx = VersionedTransaction::try_new(
-            VersionedMessage::V0(Message::try_compile(&payer.pubkey(), &[ix], &[], rbh).unwrap()),
-            &signers,
-        )
-        .unwrap();
+        let tx = args
+            .nonce
+            .build_tx(&rpc, &payer.pubkey(), &[ix], &[], &signers)
+            .await;
 
-        rpc.handle_tx(&tx, args.send_mode).await;
+        handle_tx(&rpc, &tx, args.tx, pdas).await;
     }
 }
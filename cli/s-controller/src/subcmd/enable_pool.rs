@@ -1,13 +1,13 @@
 use clap::Args;
+use s_cli_utils::parse_signer_or_pubkey;
 use s_controller_interface::enable_pool_ix_with_program_id;
 use s_controller_lib::{try_pool_state, EnablePoolFreeArgs};
-use sanctum_solana_cli_utils::{parse_signer, TxSendingNonblockingRpcClient};
-use solana_sdk::{
-    message::{v0::Message, VersionedMessage},
-    transaction::VersionedTransaction,
-};
 
-use crate::{common::verify_admin, rpc::fetch_pool_state};
+use crate::{
+    common::{find_pool_pdas, verify_admin},
+    rpc::fetch_pool_state,
+    tx::handle_tx,
+};
 
 use super::Subcmd;
 
@@ -32,11 +32,12 @@ impl EnablePoolArgs {
         let payer = args.config.signer();
         let rpc = args.config.nonblocking_rpc_client();
         let program_id = args.program;
+        let pdas = find_pool_pdas(program_id, args.pool);
 
-        let admin_signer = admin.map(|s| parse_signer(&s).unwrap());
+        let admin_signer = admin.map(|s| parse_signer_or_pubkey(&s).unwrap());
         let admin = admin_signer.as_ref().unwrap_or(&payer);
 
-        let pool_state_acc = fetch_pool_state(&rpc, program_id).await;
+        let pool_state_acc = fetch_pool_state(&rpc, pdas).await;
         let pool_state = try_pool_state(&pool_state_acc.data).unwrap();
 
         verify_admin(pool_state, admin.pubkey()).unwrap();
@@ -44,7 +45,7 @@ impl EnablePoolArgs {
         let ix = enable_pool_ix_with_program_id(
             program_id,
             EnablePoolFreeArgs { pool_state_acc }
-                .resolve_for_prog(program_id)
+                .resolve_with_pool_state_id(pdas.pool_state)
                 .unwrap(),
         )
         .unwrap();
@@ -52,13 +53,11 @@ impl EnablePoolArgs {
         let mut signers = vec![payer.as_ref(), admin.as_ref()];
         signers.dedup();
 
-        let rbh = rpc.get_latest_blockhash().await.unwrap();
-        let tx = VersionedTransaction::try_new(
-            VersionedMessage::V0(Message::try_compile(&payer.pubkey(), &[ix], &[], rbh).unwrap()),
-            &signers,
-        )
-        .unwrap();
+        let tx = args
+            .nonce
+            .build_tx(&rpc, &payer.pubkey(), &[ix], &[], &signers)
+            .await;
 
-        rpc.handle_tx(&tx, args.send_mode).await;
+        handle_tx(&rpc, &tx, args.tx, pdas).await;
     }
 }
@@ -0,0 +1,370 @@
+use std::str::FromStr;
+
+use clap::{
+    builder::{StringValueParser, TypedValueParser},
+    Args,
+};
+use s_cli_utils::parse_signer_or_pubkey;
+use s_controller_interface::{
+    add_disable_pool_authority_ix_with_program_id, add_lst_ix_with_program_id,
+    remove_disable_pool_authority_ix_with_program_id, remove_lst_ix_with_program_id,
+    set_admin_ix_with_program_id, set_lst_cap_ix_with_program_id,
+    set_pricing_program_ix_with_program_id, set_protocol_fee_beneficiary_ix_with_program_id,
+    set_protocol_fee_ix_with_program_id, set_rebalance_authority_ix_with_program_id,
+    set_timelock_delay_ix_with_program_id, SetLstCapIxArgs, SetProtocolFeeIxArgs,
+    SetTimelockDelayIxArgs,
+};
+use s_controller_lib::{
+    execute_pending_change_ix_full_for_prog, set_sol_value_calculator_ix_by_mint_full_for_pool,
+    try_pending_change_list, try_pool_state, AddDisablePoolAuthorityFreeArgs, AddLstFreeArgs,
+    AdminChange, ExecutePendingChangeFreeArgs, KnownAuthoritySetRebalanceAuthorityFreeArgs,
+    RemoveDisablePoolAuthorityByPubkeyFreeArgs, RemoveLstByMintFreeArgs, SetAdminFreeArgs,
+    SetLstCapByMintFreeArgs, SetPricingProgramFreeArgs, SetProtocolFeeBeneficiaryFreeArgs,
+    SetProtocolFeeFreeArgs, SetSolValueCalculatorByMintFreeArgs, SetTimelockDelayFreeArgs,
+    ADD_LST_IX_COMPUTE_UNIT_CEIL,
+};
+use solana_readonly_account::sdk::KeyedAccount;
+use solana_sdk::{
+    compute_budget::ComputeBudgetInstruction, instruction::AccountMeta, pubkey::Pubkey,
+};
+
+use crate::{
+    common::{
+        find_pool_pdas, find_sanctum_lst_by_mint, sol_value_calculator_accounts_of_sanctum_lst,
+        verify_admin, verify_protocol_fee_beneficiary,
+    },
+    tx::handle_tx,
+};
+
+use super::Subcmd;
+
+#[derive(Args, Debug)]
+#[command(
+    about = "Executes a pending change whose timelock delay has elapsed.",
+    long_about = "Executes a pending change whose timelock delay has elapsed.
+
+The admin must sign, same as making the change directly, except for
+set-protocol-fee-beneficiary changes, which must be signed by the current beneficiary.
+Use view-pending-changes to find the index of the pending change."
+)]
+pub struct ExecuteChangeArgs {
+    #[arg(
+        long,
+        short,
+        help = "The program's admin authority signer. Defaults to config wallet if not set."
+    )]
+    pub admin: Option<String>,
+
+    #[arg(
+        long,
+        short,
+        help = "For set-protocol-fee-beneficiary changes, the pool's current protocol fee beneficiary signer. Defaults to config wallet if not set."
+    )]
+    pub beneficiary: Option<String>,
+
+    #[arg(
+        long,
+        short,
+        help = "The account to refund rent SOL to. Defaults to config wallet if not set.",
+        value_parser = StringValueParser::new().try_map(|s| Pubkey::from_str(&s)),
+    )]
+    pub refund_rent_to: Option<Pubkey>,
+
+    #[arg(
+        long = "account-suffix",
+        short = 'c',
+        help = "For set-sol-value-calculator changes, account suffix slice to call LstToSol for the LST, excluding the program ID and mint. Defaults to the accounts of the LST on sanctum-lst-list if not set.",
+        num_args(1..),
+    )]
+    pub account_suffix: Option<Vec<Pubkey>>,
+
+    #[arg(help = "Index of the pending change on the pending change list")]
+    pub index: usize,
+}
+
+impl ExecuteChangeArgs {
+    pub async fn run(args: crate::Args) {
+        let Self {
+            admin,
+            beneficiary,
+            refund_rent_to,
+            account_suffix,
+            index,
+        } = match args.subcmd {
+            Subcmd::ExecuteChange(a) => a,
+            _ => unreachable!(),
+        };
+
+        let payer = args.config.signer();
+        let rpc = args.config.nonblocking_rpc_client();
+        let program_id = args.program;
+        let pdas = find_pool_pdas(program_id, args.pool);
+
+        let admin_signer = admin.map(|s| parse_signer_or_pubkey(&s).unwrap());
+        let admin = admin_signer.as_ref().unwrap_or(&payer);
+        let beneficiary_signer = beneficiary.map(|s| parse_signer_or_pubkey(&s).unwrap());
+        let beneficiary = beneficiary_signer.as_ref().unwrap_or(&payer);
+        let refund_rent_to = refund_rent_to.unwrap_or_else(|| payer.pubkey());
+
+        let mut fetched = rpc
+            .get_multiple_accounts(&[
+                pdas.pool_state,
+                pdas.lst_state_list,
+                pdas.pending_change_list,
+            ])
+            .await
+            .unwrap();
+        let pending_change_list_acc = fetched.pop().unwrap().unwrap();
+        let lst_state_list_acc = fetched.pop().unwrap().unwrap();
+        let pool_state_acc = fetched.pop().unwrap().unwrap();
+
+        let pending_change_list = try_pending_change_list(&pending_change_list_acc.data).unwrap();
+        let pending_change = pending_change_list.get(index).unwrap_or_else(|| {
+            eprintln!("No pending change at index {index}");
+            std::process::exit(-1);
+        });
+        let change = AdminChange::try_from(pending_change).unwrap();
+
+        let pool_state = try_pool_state(&pool_state_acc.data).unwrap();
+        let authority = match change {
+            AdminChange::SetProtocolFeeBeneficiary { .. } => {
+                verify_protocol_fee_beneficiary(pool_state, beneficiary.pubkey()).unwrap();
+                beneficiary
+            }
+            _ => {
+                verify_admin(pool_state, admin.pubkey()).unwrap();
+                admin
+            }
+        };
+
+        let pool_state_keyed = KeyedAccount {
+            pubkey: pdas.pool_state,
+            account: pool_state_acc,
+        };
+        let mut compute_unit_limit = None;
+        let direct_ix = match change {
+            AdminChange::SetAdmin { new_admin } => set_admin_ix_with_program_id(
+                program_id,
+                SetAdminFreeArgs {
+                    new_admin,
+                    pool_state: pool_state_keyed,
+                }
+                .resolve_for_pool(pdas)
+                .unwrap(),
+            )
+            .unwrap(),
+            AdminChange::SetPricingProgram {
+                new_pricing_program,
+            } => set_pricing_program_ix_with_program_id(
+                program_id,
+                SetPricingProgramFreeArgs {
+                    new_pricing_program,
+                    pool_state_acc: pool_state_keyed,
+                }
+                .resolve_for_pool(pdas)
+                .unwrap(),
+            )
+            .unwrap(),
+            AdminChange::SetSolValueCalculator {
+                lst_mint,
+                new_sol_value_calculator,
+            } => {
+                let sol_value_calculator_accounts: Vec<AccountMeta> = match account_suffix {
+                    Some(account_suffix) => std::iter::once(lst_mint)
+                        .chain(account_suffix)
+                        .map(|pubkey| AccountMeta::new_readonly(pubkey, false))
+                        .collect(),
+                    None => {
+                        match find_sanctum_lst_by_mint(lst_mint) {
+                            Some(lst) => sol_value_calculator_accounts_of_sanctum_lst(lst),
+                            None => {
+                                eprintln!("LST {lst_mint} not on sanctum-lst-list, --account-suffix required");
+                                std::process::exit(-1);
+                            }
+                        }
+                    }
+                };
+                let lst_mint_acc = rpc.get_account(&lst_mint).await.unwrap();
+                set_sol_value_calculator_ix_by_mint_full_for_pool(
+                    program_id,
+                    pdas,
+                    &SetSolValueCalculatorByMintFreeArgs {
+                        pool_state: pool_state_keyed,
+                        lst_state_list: lst_state_list_acc,
+                        lst_mint: KeyedAccount {
+                            pubkey: lst_mint,
+                            account: lst_mint_acc,
+                        },
+                    },
+                    &sol_value_calculator_accounts,
+                    new_sol_value_calculator,
+                )
+                .unwrap()
+            }
+            AdminChange::SetProtocolFee {
+                new_trading_protocol_fee_bps,
+                new_lp_protocol_fee_bps,
+            } => set_protocol_fee_ix_with_program_id(
+                program_id,
+                SetProtocolFeeFreeArgs {
+                    pool_state: pool_state_keyed,
+                }
+                .resolve_for_pool(pdas)
+                .unwrap(),
+                SetProtocolFeeIxArgs {
+                    new_trading_protocol_fee_bps,
+                    new_lp_protocol_fee_bps,
+                },
+            )
+            .unwrap(),
+            AdminChange::RemoveLst { lst_mint } => {
+                let lst_mint_acc = rpc.get_account(&lst_mint).await.unwrap();
+                let (keys, ix_args) = RemoveLstByMintFreeArgs {
+                    refund_rent_to,
+                    pool_state: pool_state_keyed,
+                    lst_state_list: lst_state_list_acc,
+                    lst_mint: KeyedAccount {
+                        pubkey: lst_mint,
+                        account: lst_mint_acc,
+                    },
+                }
+                .resolve_with_pdas(pdas.into())
+                .unwrap();
+                remove_lst_ix_with_program_id(program_id, keys, ix_args).unwrap()
+            }
+            AdminChange::SetTimelockDelay {
+                new_timelock_delay_hours,
+            } => set_timelock_delay_ix_with_program_id(
+                program_id,
+                SetTimelockDelayFreeArgs {
+                    pool_state: pool_state_keyed,
+                }
+                .resolve_for_pool(pdas)
+                .unwrap(),
+                SetTimelockDelayIxArgs {
+                    timelock_delay_hours: new_timelock_delay_hours,
+                },
+            )
+            .unwrap(),
+            AdminChange::AddLst {
+                lst_mint,
+                sol_value_calculator,
+            } => {
+                let lst_mint_acc = rpc.get_account(&lst_mint).await.unwrap();
+                let (keys, _bumps) = AddLstFreeArgs {
+                    payer: payer.pubkey(),
+                    sol_value_calculator,
+                    pool_state: pool_state_keyed,
+                    lst_mint: KeyedAccount {
+                        pubkey: lst_mint,
+                        account: lst_mint_acc,
+                    },
+                }
+                .resolve_for_pool(pdas)
+                .unwrap();
+                compute_unit_limit = Some(ADD_LST_IX_COMPUTE_UNIT_CEIL);
+                add_lst_ix_with_program_id(program_id, keys).unwrap()
+            }
+            AdminChange::SetRebalanceAuthority {
+                new_rebalance_authority,
+            } => set_rebalance_authority_ix_with_program_id(
+                program_id,
+                KnownAuthoritySetRebalanceAuthorityFreeArgs {
+                    new_rebalance_authority,
+                    pool_state: pool_state_keyed,
+                }
+                .resolve_pool_admin_with_pool_state_id()
+                .unwrap(),
+            )
+            .unwrap(),
+            AdminChange::SetProtocolFeeBeneficiary { new_beneficiary } => {
+                set_protocol_fee_beneficiary_ix_with_program_id(
+                    program_id,
+                    SetProtocolFeeBeneficiaryFreeArgs {
+                        new_beneficiary,
+                        pool_state: pool_state_keyed.account,
+                    }
+                    .resolve_with_pool_state_id(pdas.pool_state)
+                    .unwrap(),
+                )
+                .unwrap()
+            }
+            AdminChange::AddDisablePoolAuthority { new_authority } => {
+                add_disable_pool_authority_ix_with_program_id(
+                    program_id,
+                    AddDisablePoolAuthorityFreeArgs {
+                        payer: payer.pubkey(),
+                        new_authority,
+                        pool_state_acc: pool_state_keyed,
+                    }
+                    .resolve_for_pool(pdas)
+                    .unwrap(),
+                )
+                .unwrap()
+            }
+            AdminChange::RemoveDisablePoolAuthority {
+                authority: removing,
+            } => {
+                let disable_auth_list_acc = rpc
+                    .get_account(&pdas.disable_pool_authority_list)
+                    .await
+                    .unwrap();
+                let (keys, ix_args) = RemoveDisablePoolAuthorityByPubkeyFreeArgs {
+                    refund_rent_to,
+                    signer: admin.pubkey(),
+                    authority: removing,
+                    pool_state_acc: pool_state_keyed.account,
+                    disable_pool_authority_list: disable_auth_list_acc,
+                }
+                .resolve_with_pdas(pdas.into())
+                .unwrap();
+                remove_disable_pool_authority_ix_with_program_id(program_id, keys, ix_args).unwrap()
+            }
+            AdminChange::SetLstCap {
+                lst_mint,
+                max_share_bps,
+            } => {
+                let (keys, index) = SetLstCapByMintFreeArgs {
+                    lst_mint,
+                    pool_state: pool_state_keyed.account,
+                    lst_state_list: lst_state_list_acc,
+                }
+                .resolve_for_pool(pdas)
+                .unwrap();
+                set_lst_cap_ix_with_program_id(
+                    program_id,
+                    keys,
+                    SetLstCapIxArgs {
+                        index: index.try_into().unwrap(),
+                        max_share_bps,
+                    },
+                )
+                .unwrap()
+            }
+        };
+
+        let ix = execute_pending_change_ix_full_for_prog(
+            program_id,
+            ExecutePendingChangeFreeArgs { refund_rent_to }.resolve_for_pool(pdas),
+            index,
+            direct_ix,
+        )
+        .unwrap();
+
+        let ixs: Vec<_> = compute_unit_limit
+            .map(ComputeBudgetInstruction::set_compute_unit_limit)
+            .into_iter()
+            .chain(std::iter::once(ix))
+            .collect();
+
+        let mut signers = vec![payer.as_ref(), authority.as_ref()];
+        signers.dedup();
+
+        let tx = args
+            .nonce
+            .build_tx(&rpc, &payer.pubkey(), &ixs, &[], &signers)
+            .await;
+
+        handle_tx(&rpc, &tx, args.tx, pdas).await;
+    }
+}
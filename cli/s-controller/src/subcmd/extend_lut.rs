@@ -0,0 +1,183 @@
+use std::str::FromStr;
+
+use clap::{
+    builder::{StringValueParser, TypedValueParser},
+    Args,
+};
+use s_cli_utils::parse_signer_or_pubkey;
+use solana_sdk::{
+    address_lookup_table::{instruction::extend_lookup_table, state::LOOKUP_TABLE_MAX_ADDRESSES},
+    instruction::Instruction,
+    pubkey::Pubkey,
+};
+
+use crate::{
+    common::find_pool_pdas,
+    lut::{deserialize_lut, fetch_lut_account, fetch_pool_lut_addresses, MAX_ADDRESSES_PER_EXTEND},
+    tx::handle_tx,
+};
+
+use super::Subcmd;
+
+#[derive(Args, Debug)]
+#[command(
+    about = "Add the pool's static accounts missing from an address lookup table to it",
+    long_about = "Add the pool's static accounts missing from an address lookup table to it.
+Run after LSTs are added to the pool or its pricing program changes to keep a table created with create-lut up to date. Does nothing if the table already contains all of them."
+)]
+pub struct ExtendLutArgs {
+    #[arg(
+        help = "The lookup table to extend",
+        value_parser = StringValueParser::new().try_map(|s| Pubkey::from_str(&s)),
+    )]
+    pub table: Pubkey,
+
+    #[arg(
+        long,
+        short,
+        help = "The lookup table's authority. Defaults to config wallet if not set."
+    )]
+    pub authority: Option<String>,
+}
+
+impl ExtendLutArgs {
+    pub async fn run(args: crate::Args) {
+        let Self {
+            table: lut,
+            authority,
+        } = match args.subcmd {
+            Subcmd::ExtendLut(a) => a,
+            _ => unreachable!(),
+        };
+
+        let payer = args.config.signer();
+        let rpc = args.config.nonblocking_rpc_client();
+        let program_id = args.program;
+        let pdas = find_pool_pdas(program_id, args.pool);
+
+        let authority_signer = authority.map(|s| parse_signer_or_pubkey(&s).unwrap());
+        let authority = authority_signer.as_ref().unwrap_or(&payer);
+
+        let lut_acc = fetch_lut_account(&rpc, lut).await;
+        let lut_state = deserialize_lut(lut, &lut_acc.data);
+        if lut_state.meta.authority != Some(authority.pubkey()) {
+            eprintln!(
+                "Wrong lookup table authority. Expected {:?}, got {}",
+                lut_state.meta.authority,
+                authority.pubkey()
+            );
+            std::process::exit(-1);
+        }
+
+        let mut missing = fetch_pool_lut_addresses(&rpc, program_id, pdas).await;
+        missing.retain(|pk| !lut_state.addresses.contains(pk));
+        if missing.is_empty() {
+            eprintln!("Lookup table {lut} already contains all of the pool's static accounts, nothing to add");
+            return;
+        }
+        let capacity = LOOKUP_TABLE_MAX_ADDRESSES - lut_state.addresses.len();
+        if missing.len() > capacity {
+            eprintln!(
+                "{} addresses missing from lookup table but it only has space for {capacity} more, only adding the first {capacity}",
+                missing.len()
+            );
+            missing.truncate(capacity);
+        }
+
+        let mut signers = vec![payer.as_ref(), authority.as_ref()];
+        signers.dedup();
+
+        // dyn Signer is not Sync, so just send the txes sequentially
+        for ix in extend_lut_ixs(lut, authority.pubkey(), payer.pubkey(), &missing) {
+            // since we're doing it sequentially, fetch blockhash
+            // on every iter to make sure it wont expire
+            let tx = args
+                .nonce
+                .build_tx(&rpc, &payer.pubkey(), &[ix], &[], &signers)
+                .await;
+            handle_tx(&rpc, &tx, args.tx, pdas).await;
+        }
+
+        eprintln!("Added {} addresses to lookup table {lut}", missing.len());
+    }
+}
+
+/// ExtendLookupTable instructions adding `addresses` to `lut`,
+/// each of which fits in a single tx
+pub fn extend_lut_ixs(
+    lut: Pubkey,
+    authority: Pubkey,
+    payer: Pubkey,
+    addresses: &[Pubkey],
+) -> Vec<Instruction> {
+    addresses
+        .chunks(MAX_ADDRESSES_PER_EXTEND)
+        .map(|chunk| extend_lookup_table(lut, authority, Some(payer), chunk.to_vec()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_sdk::{
+        address_lookup_table::instruction::{create_lookup_table, ProgramInstruction},
+        hash::Hash,
+        message::{v0::Message, VersionedMessage},
+        packet::PACKET_DATA_SIZE,
+        signature::Keypair,
+        signer::Signer,
+        transaction::VersionedTransaction,
+    };
+
+    use super::*;
+
+    #[test]
+    fn extend_lut_ixs_chunked() {
+        let addresses: Vec<Pubkey> = (0..2 * MAX_ADDRESSES_PER_EXTEND + 1)
+            .map(|_| Pubkey::new_unique())
+            .collect();
+        let ixs = extend_lut_ixs(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            &addresses,
+        );
+        assert_eq!(ixs.len(), 3);
+        let added: Vec<Pubkey> = ixs
+            .iter()
+            .flat_map(|ix| match bincode::deserialize(&ix.data).unwrap() {
+                ProgramInstruction::ExtendLookupTable { new_addresses } => {
+                    assert!(new_addresses.len() <= MAX_ADDRESSES_PER_EXTEND);
+                    new_addresses
+                }
+                _ => panic!("not an ExtendLookupTable instruction"),
+            })
+            .collect();
+        assert_eq!(added, addresses);
+    }
+
+    #[test]
+    fn create_and_extend_lut_fits_tx_size() {
+        let payer = Keypair::new();
+        let authority = Keypair::new();
+        let (create_ix, lut) = create_lookup_table(authority.pubkey(), payer.pubkey(), 0);
+        let addresses: Vec<Pubkey> = (0..MAX_ADDRESSES_PER_EXTEND)
+            .map(|_| Pubkey::new_unique())
+            .collect();
+        let mut ixs = vec![create_ix];
+        ixs.extend(extend_lut_ixs(
+            lut,
+            authority.pubkey(),
+            payer.pubkey(),
+            &addresses,
+        ));
+        assert_eq!(ixs.len(), 2);
+        let tx = VersionedTransaction::try_new(
+            VersionedMessage::V0(
+                Message::try_compile(&payer.pubkey(), &ixs, &[], Hash::default()).unwrap(),
+            ),
+            &[&payer, &authority],
+        )
+        .unwrap();
+        assert!(bincode::serialize(&tx).unwrap().len() <= PACKET_DATA_SIZE);
+    }
+}
@@ -1,11 +1,10 @@
 use clap::Args;
+use s_cli_utils::parse_signer_or_pubkey;
 use s_controller_interface::initialize_ix_with_program_id;
 use s_controller_lib::{InitializeFreeArgs, InitializeResolveForProg};
-use sanctum_solana_cli_utils::{parse_pubkey_src, parse_signer, TxSendingNonblockingRpcClient};
-use solana_sdk::{
-    message::{v0::Message, VersionedMessage},
-    transaction::VersionedTransaction,
-};
+use sanctum_solana_cli_utils::parse_pubkey_src;
+
+use crate::{common::find_pool_pdas, tx::handle_tx};
 
 use super::Subcmd;
 
@@ -47,8 +46,9 @@ impl InitArgs {
         let payer = args.config.signer();
         let rpc = args.config.nonblocking_rpc_client();
         let program_id = args.program;
+        let pdas = find_pool_pdas(program_id, None);
 
-        let init_auth_signer = init_auth.map(|s| parse_signer(&s).unwrap());
+        let init_auth_signer = init_auth.map(|s| parse_signer_or_pubkey(&s).unwrap());
         let init_auth = init_auth_signer.as_ref().unwrap_or(&payer);
 
         let lp_token_mint = parse_pubkey_src(&lp_token_mint).unwrap();
@@ -69,13 +69,11 @@ impl InitArgs {
         let mut signers = vec![payer.as_ref(), init_auth.as_ref()];
         signers.dedup();
 
-        let rbh = rpc.get_latest_blockhash().await.unwrap();
-        let tx = VersionedTransaction::try_new(
-            VersionedMessage::V0(Message::try_compile(&payer.pubkey(), &[ix], &[], rbh).unwrap()),
-            &signers,
-        )
-        .unwrap();
+        let tx = args
+            .nonce
+            .build_tx(&rpc, &payer.pubkey(), &[ix], &[], &signers)
+            .await;
 
-        rpc.handle_tx(&tx, args.send_mode).await;
+        handle_tx(&rpc, &tx, args.tx, pdas).await;
     }
 }
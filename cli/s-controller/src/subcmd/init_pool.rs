@@ -0,0 +1,89 @@
+use clap::Args;
+use s_cli_utils::parse_signer_or_pubkey;
+use s_controller_interface::initialize_pool_ix_with_program_id;
+use s_controller_lib::{try_pool_state, InitializePoolFreeArgs};
+use sanctum_solana_cli_utils::parse_pubkey_src;
+
+use crate::{
+    common::{find_pool_pdas, verify_admin},
+    rpc::fetch_pool_state,
+    tx::handle_tx,
+};
+
+use super::Subcmd;
+
+#[derive(Args, Debug)]
+#[command(
+    about = "Initializes an additional pool keyed by its LP token mint",
+    long_about = "Initializes an additional pool keyed by its LP token mint
+
+Prerequisites:
+- the program's default pool must already be initialized
+- lp_token_mint must be an initialized spl-token (not 2022) mint with 9 decimals and 0 supply and have mint authority set to the default pool's admin
+
+The procedure will:
+- Initialize the new pool's state only. Use add-lst with --pool <lp_token_mint> to initialize the lst list and add the first LST to the new pool.
+- Transfer the mint authority of lp_token_mint to the program. Make sure token metadata is already set up if required.
+- Set the new pool's admin, rebalance authority and protocol fee beneficiary to the default pool's admin"
+)]
+pub struct InitPoolArgs {
+    #[arg(
+        long,
+        short,
+        help = "The default pool's admin. Defaults to config wallet if not set."
+    )]
+    pub admin: Option<String>,
+
+    #[arg(help = "The initialized lp_token_mint. Can be a pubkey or signer.")]
+    pub lp_token_mint: String,
+}
+
+impl InitPoolArgs {
+    pub async fn run(args: crate::Args) {
+        let Self {
+            admin,
+            lp_token_mint,
+        } = match args.subcmd {
+            Subcmd::InitPool(a) => a,
+            _ => unreachable!(),
+        };
+
+        let payer = args.config.signer();
+        let rpc = args.config.nonblocking_rpc_client();
+        let program_id = args.program;
+        let default_pdas = find_pool_pdas(program_id, None);
+
+        let admin_signer = admin.map(|s| parse_signer_or_pubkey(&s).unwrap());
+        let admin = admin_signer.as_ref().unwrap_or(&payer);
+
+        let lp_token_mint = parse_pubkey_src(&lp_token_mint).unwrap();
+        let pdas = find_pool_pdas(program_id, Some(lp_token_mint.pubkey()));
+
+        let default_pool_state_acc = fetch_pool_state(&rpc, default_pdas).await;
+        verify_admin(
+            try_pool_state(&default_pool_state_acc.data).unwrap(),
+            admin.pubkey(),
+        )
+        .unwrap();
+
+        let (keys, _pool_state_bump) = InitializePoolFreeArgs {
+            payer: payer.pubkey(),
+            lp_token_mint: lp_token_mint.pubkey(),
+            default_pool_state: default_pool_state_acc,
+        }
+        .resolve_for_prog(program_id)
+        .unwrap();
+        eprintln!("Initializing pool with pool state {}", keys.pool_state);
+        let ix = initialize_pool_ix_with_program_id(program_id, keys).unwrap();
+
+        let mut signers = vec![payer.as_ref(), admin.as_ref()];
+        signers.dedup();
+
+        let tx = args
+            .nonce
+            .build_tx(&rpc, &payer.pubkey(), &[ix], &[], &signers)
+            .await;
+
+        handle_tx(&rpc, &tx, args.tx, pdas).await;
+    }
+}
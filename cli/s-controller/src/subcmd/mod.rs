@@ -1,40 +1,73 @@
 use clap::Subcommand;
+use s_cli_utils::{SendSignedArgs, SignArgs};
 
 use self::{
-    add_disable_auth::AddDisableAuthArgs, add_lst::AddLstArgs,
+    add_disable_auth::AddDisableAuthArgs, add_liquidity::AddLiquidityArgs,
+    add_liquidity_multi::AddLiquidityMultiArgs, add_lst::AddLstArgs,
+    cancel_change::CancelChangeArgs, create_lut::CreateLutArgs,
     disable_lst_input::DisableLstInputArgs, disable_pool::DisablePoolArgs,
-    enable_lst_input::EnableLstInputArgs, enable_pool::EnablePoolArgs, init::InitArgs,
-    remove_disable_auth::RemoveDisableAuthArgs, remove_lst::RemoveLstArgs, set_admin::SetAdminArgs,
-    set_pricing_prog::SetPricingProgArgs, set_protocol_fee::SetProtocolFeeArgs,
+    enable_lst_input::EnableLstInputArgs, enable_pool::EnablePoolArgs,
+    execute_change::ExecuteChangeArgs, extend_lut::ExtendLutArgs, init::InitArgs,
+    init_pool::InitPoolArgs, queue_change::QueueChangeArgs, rebalance::RebalanceArgs,
+    remove_disable_auth::RemoveDisableAuthArgs, remove_liquidity::RemoveLiquidityArgs,
+    remove_liquidity_pro_rata::RemoveLiquidityProRataArgs, remove_lst::RemoveLstArgs,
+    set_admin::SetAdminArgs, set_lst_cap::SetLstCapArgs, set_pricing_prog::SetPricingProgArgs,
+    set_protocol_fee::SetProtocolFeeArgs,
     set_protocol_fee_beneficiary::SetProtocolFeeBeneficiaryArgs,
-    set_rebalance_auth::SetRebalanceAuthArgs, set_sol_value_calculator::SetSolValueCalculatorArgs,
-    sync::SyncArgs, sync_all::SyncAllArgs, view::ViewArgs,
+    set_rebalance_auth::SetRebalanceAuthArgs, set_rebalance_loss_limit::SetRebalanceLossLimitArgs,
+    set_sol_value_calculator::SetSolValueCalculatorArgs, set_timelock_delay::SetTimelockDelayArgs,
+    swap::SwapArgs, swap_exact_out::SwapExactOutArgs, sync::SyncArgs, sync_all::SyncAllArgs,
+    view::ViewArgs, view_pending_changes::ViewPendingChangesArgs,
+    withdraw_all_protocol_fees::WithdrawAllProtocolFeesArgs,
     withdraw_protocol_fees::WithdrawProtocolFeesArgs,
+    withdraw_protocol_fees_swap::WithdrawProtocolFeesSwapArgs,
 };
 
 mod add_disable_auth;
+mod add_liquidity;
+mod add_liquidity_multi;
 mod add_lst;
+mod cancel_change;
+mod create_lut;
 mod disable_lst_input;
 mod disable_pool;
 mod enable_lst_input;
 mod enable_pool;
+mod execute_change;
+mod extend_lut;
 mod init;
+mod init_pool;
+mod queue_change;
+mod rebalance;
 mod remove_disable_auth;
+mod remove_liquidity;
+mod remove_liquidity_pro_rata;
 mod remove_lst;
+mod send_signed;
 mod set_admin;
+mod set_lst_cap;
 mod set_pricing_prog;
 mod set_protocol_fee;
 mod set_protocol_fee_beneficiary;
 mod set_rebalance_auth;
+mod set_rebalance_loss_limit;
 mod set_sol_value_calculator;
+mod set_timelock_delay;
+mod sign;
+mod swap;
+mod swap_exact_out;
 mod sync;
 mod sync_all;
 mod view;
+mod view_pending_changes;
+mod withdraw_all_protocol_fees;
 mod withdraw_protocol_fees;
+mod withdraw_protocol_fees_swap;
 
 #[derive(Debug, Subcommand)]
 pub enum Subcmd {
     Init(InitArgs),
+    InitPool(InitPoolArgs),
     AddDisableAuth(AddDisableAuthArgs),
     RemoveDisableAuth(RemoveDisableAuthArgs),
     SetAdmin(SetAdminArgs),
@@ -52,13 +85,34 @@ pub enum Subcmd {
     Sync(SyncArgs),
     SyncAll(SyncAllArgs),
     WithdrawProtocolFees(WithdrawProtocolFeesArgs),
+    WithdrawAllProtocolFees(WithdrawAllProtocolFeesArgs),
+    WithdrawProtocolFeesSwap(WithdrawProtocolFeesSwapArgs),
+    AddLiquidityMulti(AddLiquidityMultiArgs),
+    RemoveLiquidityProRata(RemoveLiquidityProRataArgs),
     View(ViewArgs),
+    SetTimelockDelay(SetTimelockDelayArgs),
+    QueueChange(QueueChangeArgs),
+    ViewPendingChanges(ViewPendingChangesArgs),
+    ExecuteChange(ExecuteChangeArgs),
+    CancelChange(CancelChangeArgs),
+    SetLstCap(SetLstCapArgs),
+    CreateLut(CreateLutArgs),
+    ExtendLut(ExtendLutArgs),
+    Rebalance(RebalanceArgs),
+    SetRebalanceLossLimit(SetRebalanceLossLimitArgs),
+    Sign(SignArgs),
+    SendSigned(SendSignedArgs),
+    Swap(SwapArgs),
+    SwapExactOut(SwapExactOutArgs),
+    AddLiquidity(AddLiquidityArgs),
+    RemoveLiquidity(RemoveLiquidityArgs),
 }
 
 impl Subcmd {
     pub async fn run(args: crate::Args) {
         match args.subcmd {
             Self::Init(_) => InitArgs::run(args).await,
+            Self::InitPool(_) => InitPoolArgs::run(args).await,
             Self::AddDisableAuth(_) => AddDisableAuthArgs::run(args).await,
             Self::RemoveDisableAuth(_) => RemoveDisableAuthArgs::run(args).await,
             Self::SetAdmin(_) => SetAdminArgs::run(args).await,
@@ -76,7 +130,27 @@ impl Subcmd {
             Self::Sync(_) => SyncArgs::run(args).await,
             Self::SyncAll(_) => SyncAllArgs::run(args).await,
             Self::WithdrawProtocolFees(_) => WithdrawProtocolFeesArgs::run(args).await,
+            Self::WithdrawAllProtocolFees(_) => WithdrawAllProtocolFeesArgs::run(args).await,
+            Self::WithdrawProtocolFeesSwap(_) => WithdrawProtocolFeesSwapArgs::run(args).await,
+            Self::AddLiquidityMulti(_) => AddLiquidityMultiArgs::run(args).await,
+            Self::RemoveLiquidityProRata(_) => RemoveLiquidityProRataArgs::run(args).await,
             Self::View(_) => ViewArgs::run(args).await,
+            Self::SetTimelockDelay(_) => SetTimelockDelayArgs::run(args).await,
+            Self::QueueChange(_) => QueueChangeArgs::run(args).await,
+            Self::ViewPendingChanges(_) => ViewPendingChangesArgs::run(args).await,
+            Self::ExecuteChange(_) => ExecuteChangeArgs::run(args).await,
+            Self::CancelChange(_) => CancelChangeArgs::run(args).await,
+            Self::SetLstCap(_) => SetLstCapArgs::run(args).await,
+            Self::CreateLut(_) => CreateLutArgs::run(args).await,
+            Self::ExtendLut(_) => ExtendLutArgs::run(args).await,
+            Self::Rebalance(_) => RebalanceArgs::run(args).await,
+            Self::SetRebalanceLossLimit(_) => SetRebalanceLossLimitArgs::run(args).await,
+            Self::Sign(_) => sign::run(args).await,
+            Self::SendSigned(_) => send_signed::run(args).await,
+            Self::Swap(_) => SwapArgs::run(args).await,
+            Self::SwapExactOut(_) => SwapExactOutArgs::run(args).await,
+            Self::AddLiquidity(_) => AddLiquidityArgs::run(args).await,
+            Self::RemoveLiquidity(_) => RemoveLiquidityArgs::run(args).await,
         }
     }
 }
@@ -0,0 +1,288 @@
+use std::str::FromStr;
+
+use clap::{
+    builder::{StringValueParser, TypedValueParser},
+    Args, Subcommand,
+};
+use s_cli_utils::parse_signer_or_pubkey;
+use s_controller_interface::queue_pending_change_ix_with_program_id;
+use s_controller_lib::{try_pool_state, AdminChange, QueuePendingChangeFreeArgs};
+use sanctum_solana_cli_utils::parse_pubkey_src;
+use solana_readonly_account::sdk::KeyedAccount;
+use solana_sdk::pubkey::Pubkey;
+
+use crate::{
+    common::{find_pool_pdas, verify_admin},
+    lst_arg::LstArg,
+    pricing_prog_arg::PricingProgArg,
+    rpc::fetch_pool_state,
+    tx::handle_tx,
+};
+
+use super::Subcmd;
+
+#[derive(Args, Debug)]
+#[command(
+    about = "Queues an admin change for a pool in timelock mode.",
+    long_about = "Queues an admin change for a pool in timelock mode.
+
+The change can be executed with execute-change once the pool's timelock delay has elapsed,
+or cancelled with cancel-change by the admin or a disable pool authority before that."
+)]
+pub struct QueueChangeArgs {
+    #[arg(
+        long,
+        short,
+        help = "The program's admin authority signer. Defaults to config wallet if not set."
+    )]
+    pub admin: Option<String>,
+
+    #[command(subcommand)]
+    pub change: QueueChangeSubcmd,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum QueueChangeSubcmd {
+    #[command(about = "Queues a set-admin change")]
+    SetAdmin {
+        #[arg(help = "The new program's admin authority to set. Can be a pubkey or signer.")]
+        new_admin: String,
+    },
+
+    #[command(about = "Queues a set-pricing-prog change")]
+    SetPricingProg {
+        #[arg(
+            help = "The new pricing program to set to. This can be a pubkey or the following known pricing program identifiers:
+- flat-fee
+- target-weight",
+            value_parser = StringValueParser::new().try_map(|s| PricingProgArg::parse_arg(&s)),
+        )]
+        pricing_prog: PricingProgArg,
+    },
+
+    #[command(about = "Queues a set-sol-value-calculator change")]
+    SetSolValueCalculator {
+        #[arg(
+            long,
+            short,
+            help = "The LST's SOL value calculator program to set to.",
+            value_parser = StringValueParser::new().try_map(|s| Pubkey::from_str(&s)),
+        )]
+        sol_val_calc: Pubkey,
+
+        #[arg(
+            long,
+            short,
+            help = "Mint of the LST to set SOL value calculator program for. Can either be a pubkey or case-insensitive symbol of a token on sanctum-lst-list. e.g. 'bsol'",
+            value_parser = StringValueParser::new().try_map(|s| LstArg::parse_arg(&s)),
+        )]
+        mint: LstArg,
+    },
+
+    #[command(about = "Queues a set-protocol-fee change")]
+    SetProtocolFee {
+        #[arg(
+            long,
+            short,
+            help = "The pool's new trading protocol fee in bips. No change if not set."
+        )]
+        trading_fee: Option<u16>,
+
+        #[arg(
+            long,
+            short,
+            help = "The pool's new lp protocol fee in bips. No change if not set."
+        )]
+        lp_fee: Option<u16>,
+    },
+
+    #[command(about = "Queues a remove-lst change")]
+    RemoveLst {
+        #[arg(
+            help = "Mint of the LST to remove",
+            value_parser = StringValueParser::new().try_map(|s| Pubkey::from_str(&s)),
+        )]
+        mint: Pubkey,
+    },
+
+    #[command(about = "Queues a change of the timelock delay. 0 disables timelock mode.")]
+    SetTimelockDelay {
+        #[arg(help = "The new delay in hours")]
+        hours: u8,
+    },
+
+    #[command(about = "Queues an add-lst change")]
+    AddLst {
+        #[arg(
+            long,
+            short,
+            help = "The LST's SOL value calculator program. Required if LST is not on sanctum-lst-list",
+            value_parser = StringValueParser::new().try_map(|s| Pubkey::from_str(&s)),
+        )]
+        sol_val_calc: Option<Pubkey>,
+
+        #[arg(
+            help = "Mint of the new LST to add. Can either be a pubkey or case-insensitive symbol of a token on sanctum-lst-list. e.g. 'bsol'",
+            value_parser = StringValueParser::new().try_map(|s| LstArg::parse_arg(&s)),
+        )]
+        mint: LstArg,
+    },
+
+    #[command(about = "Queues a set-rebalance-auth change")]
+    SetRebalanceAuth {
+        #[arg(help = "The new rebalance authority to set. Can be a pubkey or signer.")]
+        new_rebalance_auth: String,
+    },
+
+    #[command(about = "Queues a set-protocol-fee-beneficiary change")]
+    SetProtocolFeeBeneficiary {
+        #[arg(
+            help = "The new protocol fee beneficiary to set.",
+            value_parser = StringValueParser::new().try_map(|s| Pubkey::from_str(&s)),
+        )]
+        new_beneficiary: Pubkey,
+    },
+
+    #[command(about = "Queues an add-disable-auth change")]
+    AddDisableAuth {
+        #[arg(
+            help = "The new disable pool authority to add.",
+            value_parser = StringValueParser::new().try_map(|s| Pubkey::from_str(&s)),
+        )]
+        new_authority: Pubkey,
+    },
+
+    #[command(about = "Queues a remove-disable-auth change")]
+    RemoveDisableAuth {
+        #[arg(
+            help = "The disable pool authority to remove.",
+            value_parser = StringValueParser::new().try_map(|s| Pubkey::from_str(&s)),
+        )]
+        removing: Pubkey,
+    },
+
+    #[command(about = "Queues a set-lst-cap change")]
+    SetLstCap {
+        #[arg(
+            help = "Mint of the LST to set the cap for. Can either be a pubkey or case-insensitive symbol of a token on sanctum-lst-list. e.g. 'bsol'",
+            value_parser = StringValueParser::new().try_map(|s| LstArg::parse_arg(&s)),
+        )]
+        mint: LstArg,
+
+        #[arg(help = "Max share of the pool's total SOL value in bips. 0 removes the cap.")]
+        max_share_bps: u16,
+    },
+}
+
+impl QueueChangeSubcmd {
+    pub fn into_admin_change(self) -> AdminChange {
+        match self {
+            Self::SetAdmin { new_admin } => AdminChange::SetAdmin {
+                new_admin: parse_pubkey_src(&new_admin).unwrap().pubkey(),
+            },
+            Self::SetPricingProg { pricing_prog } => AdminChange::SetPricingProgram {
+                new_pricing_program: pricing_prog.program_id(),
+            },
+            Self::SetSolValueCalculator { sol_val_calc, mint } => {
+                AdminChange::SetSolValueCalculator {
+                    lst_mint: mint.mint(),
+                    new_sol_value_calculator: sol_val_calc,
+                }
+            }
+            Self::SetProtocolFee {
+                trading_fee,
+                lp_fee,
+            } => {
+                if trading_fee.is_none() && lp_fee.is_none() {
+                    eprintln!("At least one of --trading-fee or --lp-fee must be set");
+                    std::process::exit(-1);
+                }
+                AdminChange::SetProtocolFee {
+                    new_trading_protocol_fee_bps: trading_fee,
+                    new_lp_protocol_fee_bps: lp_fee,
+                }
+            }
+            Self::RemoveLst { mint } => AdminChange::RemoveLst { lst_mint: mint },
+            Self::SetTimelockDelay { hours } => AdminChange::SetTimelockDelay {
+                new_timelock_delay_hours: hours,
+            },
+            Self::AddLst { sol_val_calc, mint } => AdminChange::AddLst {
+                lst_mint: mint.mint(),
+                sol_value_calculator: sol_val_calc.unwrap_or_else(|| {
+                    mint.sol_val_calc_of()
+                        .expect("LST not found on list, --sol-val-calc must be provided")
+                }),
+            },
+            Self::SetRebalanceAuth { new_rebalance_auth } => AdminChange::SetRebalanceAuthority {
+                new_rebalance_authority: parse_pubkey_src(&new_rebalance_auth).unwrap().pubkey(),
+            },
+            Self::SetProtocolFeeBeneficiary { new_beneficiary } => {
+                AdminChange::SetProtocolFeeBeneficiary { new_beneficiary }
+            }
+            Self::AddDisableAuth { new_authority } => {
+                AdminChange::AddDisablePoolAuthority { new_authority }
+            }
+            Self::RemoveDisableAuth { removing } => AdminChange::RemoveDisablePoolAuthority {
+                authority: removing,
+            },
+            Self::SetLstCap {
+                mint,
+                max_share_bps,
+            } => AdminChange::SetLstCap {
+                lst_mint: mint.mint(),
+                max_share_bps,
+            },
+        }
+    }
+}
+
+impl QueueChangeArgs {
+    pub async fn run(args: crate::Args) {
+        let Self { admin, change } = match args.subcmd {
+            Subcmd::QueueChange(a) => a,
+            _ => unreachable!(),
+        };
+
+        let payer = args.config.signer();
+        let rpc = args.config.nonblocking_rpc_client();
+        let program_id = args.program;
+        let pdas = find_pool_pdas(program_id, args.pool);
+
+        let admin_signer = admin.map(|s| parse_signer_or_pubkey(&s).unwrap());
+        let admin = admin_signer.as_ref().unwrap_or(&payer);
+        let change = change.into_admin_change();
+
+        let pool_state_acc = fetch_pool_state(&rpc, pdas).await;
+        let pool_state = try_pool_state(&pool_state_acc.data).unwrap();
+        verify_admin(pool_state, admin.pubkey()).unwrap();
+        if pool_state.timelock_delay_hours == 0 {
+            eprintln!("Pool not in timelock mode, make the change directly instead");
+            std::process::exit(-1);
+        }
+
+        let ix = queue_pending_change_ix_with_program_id(
+            program_id,
+            QueuePendingChangeFreeArgs {
+                payer: payer.pubkey(),
+                pool_state: KeyedAccount {
+                    pubkey: pdas.pool_state,
+                    account: pool_state_acc,
+                },
+            }
+            .resolve_for_pool(pdas)
+            .unwrap(),
+            change.into(),
+        )
+        .unwrap();
+
+        let mut signers = vec![payer.as_ref(), admin.as_ref()];
+        signers.dedup();
+
+        let tx = args
+            .nonce
+            .build_tx(&rpc, &payer.pubkey(), &[ix], &[], &signers)
+            .await;
+
+        handle_tx(&rpc, &tx, args.tx, pdas).await;
+    }
+}
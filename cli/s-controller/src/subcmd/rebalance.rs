@@ -0,0 +1,511 @@
+use std::{path::PathBuf, str::FromStr};
+
+use clap::{
+    builder::{StringValueParser, TypedValueParser},
+    Args,
+};
+use data_encoding::BASE64;
+use generic_pool_calculator_lib::GenericPoolSolValCalc;
+use s_cli_utils::parse_signer_or_pubkey;
+use s_controller_interface::SControllerError;
+use s_controller_lib::{
+    rebalance_ixs, start_rebalance_ix_full_for_prog, try_pool_state, SrcDstLstIndexes,
+    SrcDstLstSolValueCalcAccountSuffixes, SrcDstLstSolValueCalcAccounts,
+    StartRebalanceByMintsFreeArgs, StartRebalanceIxFullArgs, StartRebalanceIxLstAmts,
+};
+use sanctum_associated_token_lib::FindAtaAddressArgs;
+use sanctum_lst_list::{PoolInfo, SplPoolAccounts};
+use serde::Deserialize;
+use solana_client::{
+    nonblocking::rpc_client::RpcClient, rpc_config::RpcSimulateTransactionConfig,
+    rpc_response::RpcSimulateTransactionResult,
+};
+use solana_readonly_account::keyed::Keyed;
+use solana_sdk::{
+    compute_budget::ComputeBudgetInstruction,
+    instruction::{AccountMeta, Instruction, InstructionError},
+    native_token::sol_to_lamports,
+    pubkey::Pubkey,
+    transaction::{TransactionError, VersionedTransaction},
+};
+use spl_associated_token_account::instruction::create_associated_token_account;
+use spl_calculator_lib::{deserialize_stake_pool_checked, SanctumSplSolValCalc, SplSolValCalc};
+use spl_token::native_mint;
+
+use crate::{
+    common::find_pool_pdas, lst_arg::LstArg, lut::fetch_luts, spl_stake_pool::StakePoolAccounts,
+    tx::handle_tx,
+};
+
+use super::Subcmd;
+
+const REBALANCE_COMPUTE_UNIT_LIMIT: u32 = 1_400_000;
+
+#[derive(Args, Debug)]
+#[command(
+    about = "Rebalance the pool from one LST into another",
+    long_about = "Rebalance the pool from one LST into another with a StartRebalance -> intermediate instructions -> EndRebalance transaction.
+StartRebalance withdraws src LST to the rebalance authority. The intermediate instructions must convert it into dst LST of at least equal SOL value and deposit it into the pool's dst LST reserves.
+
+If --ixs is not provided, the built-in route is used, which only supports rebalancing between wSOL and a SPL stake pool LST:
+- wSOL -> LST: unwraps the withdrawn wSOL and DepositSols it into the stake pool, minting the LST to the pool's reserves
+- LST -> wSOL: WithdrawSols the withdrawn LST from the stake pool directly into the pool's wSOL reserves
+
+The transaction is always simulated first, and is not sent if it would fail, e.g. because the pool would lose more SOL value than its rebalance loss limits allow.
+Both LSTs must be on sanctum-lst-list."
+)]
+pub struct RebalanceArgs {
+    #[arg(
+        long,
+        short,
+        help = "The pool's rebalance authority. Defaults to config wallet if not set."
+    )]
+    pub authority: Option<String>,
+
+    #[arg(
+        long,
+        help = "Path to a JSON file containing the intermediate instructions, e.g. a swap through an external program.
+Format is an array of {\"programId\": string, \"accounts\": [{\"pubkey\": string, \"isSigner\": bool, \"isWritable\": bool}], \"data\": base64 string}, same as jupiter's swap-instructions API.
+If not set, uses the built-in SPL stake pool route."
+    )]
+    pub ixs: Option<PathBuf>,
+
+    #[arg(
+        long,
+        short,
+        help = "The src LST token account to withdraw to. Defaults to associated token account of authority, which is created if it does not exist, if not set. Must not be set if --ixs is not provided.",
+        value_parser = StringValueParser::new().try_map(|s| Pubkey::from_str(&s)),
+    )]
+    pub withdraw_to: Option<Pubkey>,
+
+    #[arg(
+        long,
+        help = "Fail if the pool has less than this amount of src LST in its reserves before the rebalance",
+        default_value_t = 0.0
+    )]
+    pub min_starting_src: f64,
+
+    #[arg(
+        long,
+        help = "Fail if the pool has more than this amount of dst LST in its reserves before the rebalance. Defaults to no limit if not set."
+    )]
+    pub max_starting_dst: Option<f64>,
+
+    #[arg(
+        long,
+        help = "Fail if the pool's total SOL value after the rebalance is less than this. Raised to the minimum allowed by the pool's rebalance loss limits.",
+        default_value_t = 0.0
+    )]
+    pub min_ending_sol_value: f64,
+
+    #[arg(
+        help = "Mint of the LST to rebalance from. Can either be a pubkey or case-insensitive symbol of a token on sanctum-lst-list. e.g. 'bsol'",
+        value_parser = StringValueParser::new().try_map(|s| LstArg::parse_arg(&s)),
+    )]
+    pub src_mint: LstArg,
+
+    #[arg(
+        help = "Mint of the LST to rebalance to. Can either be a pubkey or case-insensitive symbol of a token on sanctum-lst-list. e.g. 'jitosol'",
+        value_parser = StringValueParser::new().try_map(|s| LstArg::parse_arg(&s)),
+    )]
+    pub dst_mint: LstArg,
+
+    #[arg(help = "Amount of src LST to rebalance")]
+    pub amount: f64,
+}
+
+/// An instruction in the format returned by jupiter's swap-instructions API
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct JsonInstruction {
+    program_id: String,
+    accounts: Vec<JsonAccountMeta>,
+    data: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct JsonAccountMeta {
+    pubkey: String,
+    is_signer: bool,
+    is_writable: bool,
+}
+
+impl TryFrom<JsonInstruction> for Instruction {
+    type Error = String;
+
+    fn try_from(
+        JsonInstruction {
+            program_id,
+            accounts,
+            data,
+        }: JsonInstruction,
+    ) -> Result<Self, Self::Error> {
+        let accounts = accounts
+            .into_iter()
+            .map(
+                |JsonAccountMeta {
+                     pubkey,
+                     is_signer,
+                     is_writable,
+                 }| {
+                    Ok(AccountMeta {
+                        pubkey: Pubkey::from_str(&pubkey).map_err(|e| e.to_string())?,
+                        is_signer,
+                        is_writable,
+                    })
+                },
+            )
+            .collect::<Result<_, String>>()?;
+        Ok(Self {
+            program_id: Pubkey::from_str(&program_id).map_err(|e| e.to_string())?,
+            accounts,
+            data: BASE64.decode(data.as_bytes()).map_err(|e| e.to_string())?,
+        })
+    }
+}
+
+impl RebalanceArgs {
+    pub async fn run(args: crate::Args) {
+        let Self {
+            authority,
+            ixs: ixs_path,
+            withdraw_to,
+            min_starting_src,
+            max_starting_dst,
+            min_ending_sol_value,
+            src_mint,
+            dst_mint,
+            amount,
+        } = match args.subcmd {
+            Subcmd::Rebalance(a) => a,
+            _ => unreachable!(),
+        };
+
+        let payer = args.config.signer();
+        let rpc = args.config.nonblocking_rpc_client();
+        let program_id = args.program;
+        let pdas = find_pool_pdas(program_id, args.pool);
+
+        let authority_signer = authority.map(|s| parse_signer_or_pubkey(&s).unwrap());
+        let authority = authority_signer.as_ref().unwrap_or(&payer);
+
+        let (src_lst_calculator_accounts, dst_lst_calculator_accounts) = match (
+            src_mint.sol_value_calculator_accounts_of(),
+            dst_mint.sol_value_calculator_accounts_of(),
+        ) {
+            (Some(s), Some(d)) => (s, d),
+            _ => {
+                eprintln!("Both src and dst LSTs must be on sanctum-lst-list");
+                std::process::exit(-1);
+            }
+        };
+        if ixs_path.is_none() && withdraw_to.is_some() {
+            eprintln!("--withdraw-to can only be set if --ixs is provided");
+            std::process::exit(-1);
+        }
+
+        let mut fetched_accs = rpc
+            .get_multiple_accounts(&[
+                pdas.pool_state,
+                pdas.lst_state_list,
+                src_mint.mint(),
+                dst_mint.mint(),
+            ])
+            .await
+            .unwrap();
+        let dst_mint_acc = fetched_accs.pop().unwrap().unwrap();
+        let src_mint_acc = fetched_accs.pop().unwrap().unwrap();
+        let lst_state_list_acc = fetched_accs.pop().unwrap().unwrap();
+        let pool_state_acc = fetched_accs.pop().unwrap().unwrap();
+
+        let pool_state = try_pool_state(&pool_state_acc.data).unwrap();
+        if pool_state.rebalance_authority != authority.pubkey() {
+            eprintln!(
+                "Wrong rebalance authority. Expected: {}. Got: {}",
+                pool_state.rebalance_authority,
+                authority.pubkey()
+            );
+            std::process::exit(-1);
+        }
+
+        let authority_src_ata = FindAtaAddressArgs {
+            wallet: authority.pubkey(),
+            mint: src_mint.mint(),
+            token_program: src_mint_acc.owner,
+        }
+        .find_ata_address()
+        .0;
+        let withdraw_to = withdraw_to.unwrap_or(authority_src_ata);
+
+        let (
+            start_rebalance_keys,
+            SrcDstLstIndexes {
+                src_lst_index,
+                dst_lst_index,
+            },
+            sol_val_calc_program_ids,
+        ) = StartRebalanceByMintsFreeArgs {
+            withdraw_to,
+            lst_state_list: Keyed {
+                pubkey: pdas.lst_state_list,
+                account: &lst_state_list_acc,
+            },
+            pool_state: Keyed {
+                pubkey: pdas.pool_state,
+                account: &pool_state_acc,
+            },
+            src_lst_mint: Keyed {
+                pubkey: src_mint.mint(),
+                account: &src_mint_acc,
+            },
+            dst_lst_mint: Keyed {
+                pubkey: dst_mint.mint(),
+                account: &dst_mint_acc,
+            },
+        }
+        .resolve_for_pool(pdas)
+        .unwrap();
+        let dst_pool_reserves = start_rebalance_keys.dst_pool_reserves;
+
+        let amount = sol_to_lamports(amount); // assume all LSTs are 9 d.p.
+        let start_rebalance_ix = start_rebalance_ix_full_for_prog(
+            program_id,
+            start_rebalance_keys,
+            StartRebalanceIxFullArgs {
+                src_lst_index,
+                dst_lst_index,
+                lst_amts: StartRebalanceIxLstAmts {
+                    amount,
+                    min_starting_src_lst: sol_to_lamports(min_starting_src),
+                    max_starting_dst_lst: max_starting_dst.map_or(u64::MAX, sol_to_lamports),
+                    min_ending_sol_value: sol_to_lamports(min_ending_sol_value),
+                },
+            },
+            SrcDstLstSolValueCalcAccounts::new(
+                sol_val_calc_program_ids,
+                SrcDstLstSolValueCalcAccountSuffixes {
+                    src_lst_calculator_accounts: &src_lst_calculator_accounts,
+                    dst_lst_calculator_accounts: &dst_lst_calculator_accounts,
+                },
+            ),
+        )
+        .unwrap();
+
+        let intermediate_ixs = match ixs_path {
+            Some(path) => {
+                let file = std::fs::File::open(path).unwrap();
+                let json_ixs: Vec<JsonInstruction> = serde_json::from_reader(file).unwrap();
+                json_ixs
+                    .into_iter()
+                    .map(|ix| Instruction::try_from(ix).unwrap())
+                    .collect()
+            }
+            None => {
+                builtin_spl_stake_pool_ixs(
+                    &rpc,
+                    BuiltinRouteArgs {
+                        authority: authority.pubkey(),
+                        src_mint,
+                        dst_mint,
+                        withdraw_to,
+                        dst_pool_reserves,
+                        amount,
+                    },
+                )
+                .await
+            }
+        };
+
+        let mut ixs = vec![ComputeBudgetInstruction::set_compute_unit_limit(
+            REBALANCE_COMPUTE_UNIT_LIMIT,
+        )];
+        let withdraw_to_opt = rpc
+            .get_multiple_accounts(&[withdraw_to])
+            .await
+            .unwrap()
+            .pop()
+            .unwrap();
+        if withdraw_to_opt.is_none() {
+            if withdraw_to != authority_src_ata {
+                eprintln!("Can only create withdraw_to token account if it's the associated token account of authority");
+                std::process::exit(-1);
+            }
+            ixs.push(create_associated_token_account(
+                &payer.pubkey(),
+                &authority.pubkey(),
+                &src_mint.mint(),
+                &src_mint_acc.owner,
+            ));
+        }
+        ixs.extend(rebalance_ixs(start_rebalance_ix, intermediate_ixs).unwrap());
+
+        let mut signers = vec![payer.as_ref(), authority.as_ref()];
+        signers.dedup();
+
+        let luts = fetch_luts(&rpc, args.lut).await;
+        let tx = args
+            .nonce
+            .build_tx(&rpc, &payer.pubkey(), &ixs, &luts, &signers)
+            .await;
+
+        simulate_rebalance(&rpc, &tx).await;
+
+        handle_tx(&rpc, &tx, args.tx, pdas).await;
+    }
+}
+
+struct BuiltinRouteArgs {
+    authority: Pubkey,
+    src_mint: LstArg,
+    dst_mint: LstArg,
+    withdraw_to: Pubkey,
+    dst_pool_reserves: Pubkey,
+    amount: u64,
+}
+
+/// Intermediate instructions for rebalancing between wSOL and a SPL stake pool LST
+/// by depositing into or withdrawing from the stake pool.
+///
+/// Exits if the LSTs are not supported
+async fn builtin_spl_stake_pool_ixs(
+    rpc: &RpcClient,
+    BuiltinRouteArgs {
+        authority,
+        src_mint,
+        dst_mint,
+        withdraw_to,
+        dst_pool_reserves,
+        amount,
+    }: BuiltinRouteArgs,
+) -> Vec<Instruction> {
+    let (stake_pool_lst, is_stake) = if src_mint.mint() == native_mint::ID {
+        (dst_mint, true)
+    } else if dst_mint.mint() == native_mint::ID {
+        (src_mint, false)
+    } else {
+        eprintln!("Built-in route only supports rebalancing between wSOL and a SPL stake pool LST, use --ixs instead");
+        std::process::exit(-1);
+    };
+    let (program_id, stake_pool_addr) = match stake_pool_lst {
+        LstArg::SanctumLst(lst) => match lst.pool {
+            PoolInfo::Spl(SplPoolAccounts { pool, .. }) => (SplSolValCalc::POOL_PROGRAM_ID, pool),
+            PoolInfo::SanctumSpl(SplPoolAccounts { pool, .. }) => {
+                (SanctumSplSolValCalc::POOL_PROGRAM_ID, pool)
+            }
+            _ => {
+                eprintln!("Built-in route only supports SPL stake pool LSTs, use --ixs instead");
+                std::process::exit(-1);
+            }
+        },
+        LstArg::Unknown(_) => unreachable!(), // checked to be on sanctum-lst-list above
+    };
+    let stake_pool_acc = rpc.get_account(&stake_pool_addr).await.unwrap();
+    let stake_pool = deserialize_stake_pool_checked(&stake_pool_acc).unwrap();
+    let stake_pool_accounts = StakePoolAccounts {
+        program_id,
+        stake_pool_addr,
+        stake_pool: &stake_pool,
+    };
+
+    if is_stake {
+        if stake_pool.sol_deposit_authority.is_some() {
+            eprintln!("Stake pool has a SOL deposit authority, use --ixs instead");
+            std::process::exit(-1);
+        }
+        vec![
+            // closing the wSOL account unwraps all its SOL, including the withdrawn amount.
+            // Any wSOL the authority already had in it is unwrapped too.
+            spl_token::instruction::close_account(
+                &spl_token::ID,
+                &withdraw_to,
+                &authority,
+                &authority,
+                &[],
+            )
+            .unwrap(),
+            stake_pool_accounts
+                .deposit_sol_ix(authority, dst_pool_reserves, amount)
+                .unwrap(),
+        ]
+    } else {
+        if stake_pool.sol_withdraw_authority.is_some() {
+            eprintln!("Stake pool has a SOL withdraw authority, use --ixs instead");
+            std::process::exit(-1);
+        }
+        vec![
+            stake_pool_accounts
+                .withdraw_sol_ix(authority, withdraw_to, dst_pool_reserves, amount)
+                .unwrap(),
+            // wrap the SOL sent to the pool's wSOL reserves
+            spl_token::instruction::sync_native(&spl_token::ID, &dst_pool_reserves).unwrap(),
+        ]
+    }
+}
+
+/// Simulates the rebalance transaction, exiting if it fails
+async fn simulate_rebalance(rpc: &RpcClient, tx: &VersionedTransaction) {
+    let RpcSimulateTransactionResult { err, logs, .. } = rpc
+        .simulate_transaction_with_config(
+            tx,
+            RpcSimulateTransactionConfig {
+                sig_verify: false,
+                replace_recent_blockhash: true,
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap()
+        .value;
+    let err = match err {
+        Some(e) => e,
+        None => return,
+    };
+    match &err {
+        TransactionError::InstructionError(_, InstructionError::Custom(code))
+            if *code == SControllerError::PoolWouldLoseSolValue as u32 =>
+        {
+            eprintln!("Rebalance would cause the pool to lose more SOL value than allowed")
+        }
+        _ => eprintln!("Rebalance simulation failed: {err}"),
+    }
+    match logs {
+        Some(logs) => {
+            eprintln!("Logs:");
+            eprintln!("{logs:#?}");
+        }
+        None => eprintln!("No logs available"),
+    }
+    std::process::exit(-1);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_json_instruction() {
+        let program_id = Pubkey::new_unique();
+        let account = Pubkey::new_unique();
+        let json = format!(
+            r#"[{{
+                "programId": "{program_id}",
+                "accounts": [{{ "pubkey": "{account}", "isSigner": false, "isWritable": true }}],
+                "data": "AQID"
+            }}]"#
+        );
+        let json_ixs: Vec<JsonInstruction> = serde_json::from_str(&json).unwrap();
+        let ixs: Vec<Instruction> = json_ixs
+            .into_iter()
+            .map(|ix| Instruction::try_from(ix).unwrap())
+            .collect();
+        assert_eq!(
+            ixs,
+            vec![Instruction {
+                program_id,
+                accounts: vec![AccountMeta::new(account, false)],
+                data: vec![1, 2, 3],
+            }]
+        );
+    }
+}
@@ -4,20 +4,18 @@ use clap::{
     builder::{StringValueParser, TypedValueParser},
     Args,
 };
+use s_cli_utils::parse_signer_or_pubkey;
 use s_controller_interface::remove_disable_pool_authority_ix_with_program_id;
 use s_controller_lib::{
-    find_disable_pool_authority_list_address, find_pool_state_address,
     try_disable_pool_authority_list, try_pool_state, RemoveDisablePoolAuthorityByPubkeyFreeArgs,
 };
-use sanctum_solana_cli_utils::{parse_signer, TxSendingNonblockingRpcClient};
 use solana_readonly_account::ReadonlyAccountData;
-use solana_sdk::{
-    message::{v0::Message, VersionedMessage},
-    pubkey::Pubkey,
-    transaction::VersionedTransaction,
-};
+use solana_sdk::pubkey::Pubkey;
 
-use crate::common::verify_disable_pool_authority;
+use crate::{
+    common::{find_pool_pdas, verify_disable_pool_authority},
+    tx::handle_tx,
+};
 
 use super::Subcmd;
 
@@ -62,13 +60,14 @@ impl RemoveDisableAuthArgs {
         let payer = args.config.signer();
         let rpc = args.config.nonblocking_rpc_client();
         let program_id = args.program;
+        let pdas = find_pool_pdas(program_id, args.pool);
 
-        let authority_signer = authority.map(|s| parse_signer(&s).unwrap());
+        let authority_signer = authority.map(|s| parse_signer_or_pubkey(&s).unwrap());
         let authority = authority_signer.as_ref().unwrap_or(&payer);
         let refund_rent_to = refund_rent_to.unwrap_or_else(|| payer.pubkey());
 
-        let pool_state_addr = find_pool_state_address(program_id).0;
-        let disable_auth_list_addr = find_disable_pool_authority_list_address(program_id).0;
+        let pool_state_addr = pdas.pool_state;
+        let disable_auth_list_addr = pdas.disable_pool_authority_list;
         let mut fetched_accs = rpc
             .get_multiple_accounts(&[pool_state_addr, disable_auth_list_addr])
             .await
@@ -96,7 +95,7 @@ impl RemoveDisableAuthArgs {
             pool_state_acc,
             disable_pool_authority_list: disable_auth_list_acc,
         }
-        .resolve_for_prog(program_id)
+        .resolve_with_pdas(pdas.into())
         .unwrap();
 
         let ix =
@@ -105,13 +104,11 @@ impl RemoveDisableAuthArgs {
         let mut signers = vec![payer.as_ref(), authority.as_ref()];
         signers.dedup();
 
-        let rbh = rpc.get_latest_blockhash().await.unwrap();
-        let tx = VersionedTransaction::try_new(
-            VersionedMessage::V0(Message::try_compile(&payer.pubkey(), &[ix], &[], rbh).unwrap()),
-            &signers,
-        )
-        .unwrap();
+        let tx = args
+            .nonce
+            .build_tx(&rpc, &payer.pubkey(), &[ix], &[], &signers)
+            .await;
 
-        rpc.handle_tx(&tx, args.send_mode).await;
+        handle_tx(&rpc, &tx, args.tx, pdas).await;
     }
 }
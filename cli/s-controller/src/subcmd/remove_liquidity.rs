@@ -0,0 +1,56 @@
+use clap::{
+    builder::{StringValueParser, TypedValueParser},
+    Args,
+};
+use jupiter_amm_interface::SwapMode;
+use solana_sdk::native_token::sol_to_lamports;
+
+use crate::{
+    lst_arg::LstArg,
+    user_swap::{SwapMint, UserSwap, UserSwapArgs},
+};
+
+use super::Subcmd;
+
+#[derive(Args, Debug)]
+#[command(
+    about = "Remove liquidity from the pool in a single LST",
+    long_about = "Burn an exact amount of LP tokens to remove liquidity from the pool in a single LST, outputting the quote to stderr.
+LP tokens are burnt from the authority's associated token account. The LST is transferred to the authority's associated token account, which is created if it does not exist."
+)]
+pub struct RemoveLiquidityArgs {
+    #[arg(
+        help = "Mint of the LST to receive. Can either be a pubkey or case-insensitive symbol of a token on sanctum-lst-list. e.g. 'jitosol'",
+        value_parser = StringValueParser::new().try_map(|s| LstArg::parse_arg(&s)),
+    )]
+    pub lst_mint: LstArg,
+
+    #[arg(help = "Amount of LP tokens to burn")]
+    pub lp_amount: f64,
+
+    #[command(flatten)]
+    pub user_swap: UserSwapArgs,
+}
+
+impl RemoveLiquidityArgs {
+    pub async fn run(args: crate::Args) {
+        let Self {
+            lst_mint,
+            lp_amount,
+            user_swap,
+        } = match &args.subcmd {
+            Subcmd::RemoveLiquidity(a) => a,
+            _ => unreachable!(),
+        };
+
+        UserSwap {
+            input: SwapMint::LpToken,
+            output: SwapMint::Lst(lst_mint.mint()),
+            amount: sol_to_lamports(*lp_amount), // LP token is 9 d.p.
+            swap_mode: SwapMode::ExactIn,
+            args: user_swap,
+        }
+        .run(&args)
+        .await;
+    }
+}
@@ -0,0 +1,162 @@
+use clap::Args;
+use s_controller_interface::LstState;
+use s_controller_lib::{
+    remove_liquidity_pro_rata_ix_by_mints_full_for_pool, try_lst_state_list, try_pool_state,
+    MultiLiquidityLst, RemoveLiquidityProRataByMintsFreeArgs, RemoveLiquidityProRataIxAmts,
+};
+use sanctum_associated_token_lib::FindAtaAddressArgs;
+use sanctum_token_lib::MintWithTokenProgram;
+use solana_sdk::native_token::sol_to_lamports;
+use spl_associated_token_account::instruction::create_associated_token_account;
+
+use crate::{
+    common::{
+        find_pool_pdas, find_sanctum_lst_by_mint, price_lp_tokens_to_redeem_accounts,
+        sol_value_calculator_accounts_of_sanctum_lst,
+    },
+    lut::fetch_luts,
+    tx::handle_tx,
+};
+
+use super::Subcmd;
+
+#[derive(Args, Debug)]
+#[command(
+    about = "Redeem LP tokens pro-rata across every LST in the pool",
+    long_about = "Redeem LP tokens pro-rata across every LST in the pool, in proportion to each LST's share of the pool's SOL value.
+LSTs are transferred to the payer's associated token accounts, which are created if they do not exist.
+All LSTs in the pool must be on sanctum-lst-list."
+)]
+pub struct RemoveLiquidityProRataArgs {
+    #[arg(help = "Amount of LP tokens to redeem")]
+    pub lp_amount: f64,
+
+    #[arg(
+        long,
+        short,
+        help = "Minimum amount of each LST to receive, in the same order as the pool's LST list. Defaults to 0 for every LST if not set.",
+        value_delimiter = ',',
+        num_args = 1..,
+    )]
+    pub min_lst_outs: Vec<f64>,
+}
+
+impl RemoveLiquidityProRataArgs {
+    pub async fn run(args: crate::Args) {
+        let Self {
+            lp_amount,
+            min_lst_outs,
+        } = match args.subcmd {
+            Subcmd::RemoveLiquidityProRata(a) => a,
+            _ => unreachable!(),
+        };
+
+        let payer = args.config.signer();
+        let rpc = args.config.nonblocking_rpc_client();
+        let program_id = args.program;
+        let pdas = find_pool_pdas(program_id, args.pool);
+
+        let mut fetched_accs = rpc
+            .get_multiple_accounts(&[pdas.lst_state_list, pdas.pool_state])
+            .await
+            .unwrap();
+        let pool_state_acc = fetched_accs.pop().unwrap().unwrap();
+        let lst_state_list_acc = fetched_accs.pop().unwrap().unwrap();
+        let pool_state = try_pool_state(&pool_state_acc.data).unwrap();
+        let lst_state_list = try_lst_state_list(&lst_state_list_acc.data).unwrap();
+        let lp_token_mint = pool_state.lp_token_mint;
+        let pricing_program = pool_state.pricing_program;
+
+        let min_lst_outs: Vec<u64> = if min_lst_outs.is_empty() {
+            vec![0; lst_state_list.len()]
+        } else if min_lst_outs.len() == lst_state_list.len() {
+            // assume all LSTs are 9 d.p.
+            min_lst_outs.into_iter().map(sol_to_lamports).collect()
+        } else {
+            eprintln!(
+                "Expected {} min LST outs, got {}",
+                lst_state_list.len(),
+                min_lst_outs.len()
+            );
+            std::process::exit(-1);
+        };
+
+        let lp_token_program = rpc.get_account(&lp_token_mint).await.unwrap().owner;
+        let src_lp_acc = FindAtaAddressArgs {
+            wallet: payer.pubkey(),
+            mint: lp_token_mint,
+            token_program: lp_token_program,
+        }
+        .find_ata_address()
+        .0;
+
+        let mut ixs = vec![];
+        let mut lsts = Vec::with_capacity(lst_state_list.len());
+        let mut sol_val_calc_accounts = Vec::with_capacity(lst_state_list.len());
+        let mut pricing_program_accounts = Vec::with_capacity(lst_state_list.len());
+        for LstState { mint, .. } in lst_state_list.iter() {
+            let sanctum_lst = find_sanctum_lst_by_mint(*mint).unwrap_or_else(|| {
+                eprintln!("{mint} not on sanctum-lst-list");
+                std::process::exit(-1);
+            });
+            let dst_lst_acc = FindAtaAddressArgs {
+                wallet: payer.pubkey(),
+                mint: *mint,
+                token_program: sanctum_lst.token_program,
+            }
+            .find_ata_address()
+            .0;
+            if rpc.get_account(&dst_lst_acc).await.is_err() {
+                ixs.push(create_associated_token_account(
+                    &payer.pubkey(),
+                    &payer.pubkey(),
+                    mint,
+                    &sanctum_lst.token_program,
+                ));
+            }
+            lsts.push(MultiLiquidityLst {
+                lst_mint: MintWithTokenProgram {
+                    pubkey: *mint,
+                    token_program: sanctum_lst.token_program,
+                },
+                lst_acc: dst_lst_acc,
+            });
+            sol_val_calc_accounts.push(sol_value_calculator_accounts_of_sanctum_lst(sanctum_lst));
+            pricing_program_accounts
+                .push(price_lp_tokens_to_redeem_accounts(pricing_program, *mint));
+        }
+
+        let sol_val_calc_account_suffixes: Vec<_> =
+            sol_val_calc_accounts.iter().map(Vec::as_slice).collect();
+        let pricing_program_account_suffixes: Vec<_> =
+            pricing_program_accounts.iter().map(Vec::as_slice).collect();
+        ixs.push(
+            remove_liquidity_pro_rata_ix_by_mints_full_for_pool(
+                program_id,
+                pdas.into(),
+                RemoveLiquidityProRataByMintsFreeArgs {
+                    signer: payer.pubkey(),
+                    src_lp_acc,
+                    pool_state: &pool_state_acc,
+                    lst_state_list: &lst_state_list_acc,
+                    lsts: &lsts,
+                },
+                RemoveLiquidityProRataIxAmts {
+                    lp_token_amount: sol_to_lamports(lp_amount),
+                    min_lst_outs: &min_lst_outs,
+                },
+                &sol_val_calc_account_suffixes,
+                &pricing_program_account_suffixes,
+            )
+            .unwrap(),
+        );
+
+        let luts = fetch_luts(&rpc, args.lut).await;
+        let tx = args
+            .nonce
+            .build_tx(&rpc, &payer.pubkey(), &ixs, &luts, &[payer.as_ref()])
+            .await;
+
+        handle_tx(&rpc, &tx, args.tx, pdas).await;
+    }
+}
@@ -0,0 +1,16 @@
+use crate::{common::find_pool_pdas, tx::handle_tx};
+
+use super::Subcmd;
+
+pub async fn run(args: crate::Args) {
+    let send_signed_args = match args.subcmd {
+        Subcmd::SendSigned(a) => a,
+        _ => unreachable!(),
+    };
+
+    let rpc = args.config.nonblocking_rpc_client();
+    let pdas = find_pool_pdas(args.program, args.pool);
+    let tx = send_signed_args.combined_tx();
+
+    handle_tx(&rpc, &tx, args.tx, pdas).await;
+}
@@ -1,14 +1,15 @@
 use clap::Args;
+use s_cli_utils::parse_signer_or_pubkey;
 use s_controller_interface::set_admin_ix_with_program_id;
-use s_controller_lib::{find_pool_state_address, try_pool_state, SetAdminFreeArgs};
-use sanctum_solana_cli_utils::{parse_pubkey_src, parse_signer, TxSendingNonblockingRpcClient};
+use s_controller_lib::{try_pool_state, SetAdminFreeArgs};
+use sanctum_solana_cli_utils::parse_pubkey_src;
 use solana_readonly_account::sdk::KeyedAccount;
-use solana_sdk::{
-    message::{v0::Message, VersionedMessage},
-    transaction::VersionedTransaction,
-};
 
-use crate::{common::verify_admin, rpc::fetch_pool_state};
+use crate::{
+    common::{find_pool_pdas, verify_admin},
+    rpc::fetch_pool_state,
+    tx::handle_tx,
+};
 
 use super::Subcmd;
 
@@ -45,12 +46,13 @@ impl SetAdminArgs {
         let payer = args.config.signer();
         let rpc = args.config.nonblocking_rpc_client();
         let program_id = args.program;
+        let pdas = find_pool_pdas(program_id, args.pool);
 
-        let curr_admin_signer = curr_admin.map(|s| parse_signer(&s).unwrap());
+        let curr_admin_signer = curr_admin.map(|s| parse_signer_or_pubkey(&s).unwrap());
         let curr_admin = curr_admin_signer.as_ref().unwrap_or(&payer);
         let new_admin = parse_pubkey_src(&new_admin).unwrap().pubkey();
 
-        let pool_state_acc = fetch_pool_state(&rpc, program_id).await;
+        let pool_state_acc = fetch_pool_state(&rpc, pdas).await;
         let pool_state = try_pool_state(&pool_state_acc.data).unwrap();
         verify_admin(pool_state, curr_admin.pubkey()).unwrap();
 
@@ -59,11 +61,11 @@ impl SetAdminArgs {
             SetAdminFreeArgs {
                 new_admin,
                 pool_state: KeyedAccount {
-                    pubkey: find_pool_state_address(program_id).0,
+                    pubkey: pdas.pool_state,
                     account: pool_state_acc,
                 },
             }
-            .resolve_for_prog(program_id)
+            .resolve_for_pool(pdas)
             .unwrap(),
         )
         .unwrap();
@@ -71,13 +73,11 @@ impl SetAdminArgs {
         let mut signers = vec![payer.as_ref(), curr_admin.as_ref()];
         signers.dedup();
 
-        let rbh = rpc.get_latest_blockhash().await.unwrap();
-        let tx = VersionedTransaction::try_new(
-            VersionedMessage::V0(Message::try_compile(&payer.pubkey(), &[ix], &[], rbh).unwrap()),
-            &signers,
-        )
-        .unwrap();
+        let tx = args
+            .nonce
+            .build_tx(&rpc, &payer.pubkey(), &[ix], &[], &signers)
+            .await;
 
-        rpc.handle_tx(&tx, args.send_mode).await;
+        handle_tx(&rpc, &tx, args.tx, pdas).await;
     }
 }
@@ -0,0 +1,102 @@
+use clap::{
+    builder::{StringValueParser, TypedValueParser},
+    Args,
+};
+use s_cli_utils::parse_signer_or_pubkey;
+use s_controller_interface::{set_lst_cap_ix_with_program_id, SetLstCapIxArgs};
+use s_controller_lib::{try_pool_state, SetLstCapByMintFreeArgs};
+use solana_readonly_account::ReadonlyAccountData;
+use solana_sdk::pubkey::Pubkey;
+use std::str::FromStr;
+
+use crate::{
+    common::{find_pool_pdas, verify_admin},
+    tx::handle_tx,
+};
+
+use super::Subcmd;
+
+#[derive(Args, Debug)]
+#[command(
+    long_about = "Sets the max share of the pool's total SOL value a LST's reserves may hold.
+
+Swaps and liquidity adds that would push the LST's reserves over this share are rejected.
+Set to 0 to remove the cap."
+)]
+pub struct SetLstCapArgs {
+    #[arg(
+        long,
+        short,
+        help = "The pool's admin. Defaults to config wallet if not set."
+    )]
+    pub admin: Option<String>,
+
+    #[arg(
+        help = "Mint of the LST to set the cap for",
+        value_parser = StringValueParser::new().try_map(|s| Pubkey::from_str(&s)),
+    )]
+    pub mint: Pubkey,
+
+    #[arg(help = "The LST's max share of the pool's total SOL value in bips. 0 means uncapped.")]
+    pub max_share_bps: u16,
+}
+
+impl SetLstCapArgs {
+    pub async fn run(args: crate::Args) {
+        let Self {
+            admin,
+            mint,
+            max_share_bps,
+        } = match args.subcmd {
+            Subcmd::SetLstCap(a) => a,
+            _ => unreachable!(),
+        };
+
+        let payer = args.config.signer();
+        let rpc = args.config.nonblocking_rpc_client();
+        let program_id = args.program;
+        let pdas = find_pool_pdas(program_id, args.pool);
+
+        let admin_signer = admin.map(|s| parse_signer_or_pubkey(&s).unwrap());
+        let admin = admin_signer.as_ref().unwrap_or(&payer);
+
+        let pool_state_addr = pdas.pool_state;
+        let lst_state_list_addr = pdas.lst_state_list;
+        let mut fetched_accs = rpc
+            .get_multiple_accounts(&[pool_state_addr, lst_state_list_addr])
+            .await
+            .unwrap();
+        let lst_state_list_acc = fetched_accs.pop().unwrap().unwrap();
+        let pool_state_acc = fetched_accs.pop().unwrap().unwrap();
+
+        let pool_state = try_pool_state(&pool_state_acc.data()).unwrap();
+        verify_admin(pool_state, admin.pubkey()).unwrap();
+
+        let (keys, index) = SetLstCapByMintFreeArgs {
+            lst_mint: mint,
+            pool_state: pool_state_acc,
+            lst_state_list: lst_state_list_acc,
+        }
+        .resolve_for_pool(pdas)
+        .unwrap();
+        let ix = set_lst_cap_ix_with_program_id(
+            program_id,
+            keys,
+            SetLstCapIxArgs {
+                index: index.try_into().unwrap(),
+                max_share_bps,
+            },
+        )
+        .unwrap();
+
+        let mut signers = vec![payer.as_ref(), admin.as_ref()];
+        signers.dedup();
+
+        let tx = args
+            .nonce
+            .build_tx(&rpc, &payer.pubkey(), &[ix], &[], &signers)
+            .await;
+
+        handle_tx(&rpc, &tx, args.tx, pdas).await;
+    }
+}
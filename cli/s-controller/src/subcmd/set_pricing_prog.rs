@@ -2,15 +2,16 @@ use clap::{
     builder::{StringValueParser, TypedValueParser},
     Args,
 };
+use s_cli_utils::parse_signer_or_pubkey;
 use s_controller_interface::set_pricing_program_ix_with_program_id;
 use s_controller_lib::{try_pool_state, SetPricingProgramFreeArgs};
-use sanctum_solana_cli_utils::{parse_signer, TxSendingNonblockingRpcClient};
-use solana_sdk::{
-    message::{v0::Message, VersionedMessage},
-    transaction::VersionedTransaction,
-};
 
-use crate::{common::verify_admin, pricing_prog_arg::PricingProgArg, rpc::fetch_pool_state};
+use crate::{
+    common::{find_pool_pdas, verify_admin},
+    pricing_prog_arg::PricingProgArg,
+    rpc::fetch_pool_state,
+    tx::handle_tx,
+};
 
 use super::Subcmd;
 
@@ -26,7 +27,8 @@ pub struct SetPricingProgArgs {
 
     #[arg(
         help = "The new pricing program to set to. This can be a pubkey or the following known pricing program identifiers:
-- flat-fee",
+- flat-fee
+- target-weight",
     value_parser = StringValueParser::new().try_map(|s| PricingProgArg::parse_arg(&s)))]
     pub pricing_prog: PricingProgArg,
 }
@@ -44,11 +46,12 @@ impl SetPricingProgArgs {
         let payer = args.config.signer();
         let rpc = args.config.nonblocking_rpc_client();
         let program_id = args.program;
+        let pdas = find_pool_pdas(program_id, args.pool);
 
-        let admin_signer = admin.map(|s| parse_signer(&s).unwrap());
+        let admin_signer = admin.map(|s| parse_signer_or_pubkey(&s).unwrap());
         let admin = admin_signer.as_ref().unwrap_or(&payer);
 
-        let pool_state_acc = fetch_pool_state(&rpc, program_id).await;
+        let pool_state_acc = fetch_pool_state(&rpc, pdas).await;
         let pool_state = try_pool_state(&pool_state_acc.data).unwrap();
         verify_admin(pool_state, admin.pubkey()).unwrap();
 
@@ -58,7 +61,7 @@ impl SetPricingProgArgs {
                 new_pricing_program: pricing_prog.program_id(),
                 pool_state_acc,
             }
-            .resolve_for_prog(program_id)
+            .resolve_with_pool_state_id(pdas.pool_state)
             .unwrap(),
         )
         .unwrap();
@@ -66,13 +69,11 @@ impl SetPricingProgArgs {
         let mut signers = vec![payer.as_ref(), admin.as_ref()];
         signers.dedup();
 
-        let rbh = rpc.get_latest_blockhash().await.unwrap();
-        let tx = VersionedTransaction::try_new(
-            VersionedMessage::V0(Message::try_compile(&payer.pubkey(), &[ix], &[], rbh).unwrap()),
-            &signers,
-        )
-        .unwrap();
+        let tx = args
+            .nonce
+            .build_tx(&rpc, &payer.pubkey(), &[ix], &[], &signers)
+            .await;
 
-        rpc.handle_tx(&tx, args.send_mode).await;
+        handle_tx(&rpc, &tx, args.tx, pdas).await;
     }
 }
@@ -4,16 +4,16 @@ use clap::{
     builder::{StringValueParser, TypedValueParser},
     Args,
 };
+use s_cli_utils::parse_signer_or_pubkey;
 use s_controller_interface::set_protocol_fee_beneficiary_ix_with_program_id;
 use s_controller_lib::{try_pool_state, SetProtocolFeeBeneficiaryFreeArgs};
-use sanctum_solana_cli_utils::{parse_signer, TxSendingNonblockingRpcClient};
-use solana_sdk::{
-    message::{v0::Message, VersionedMessage},
-    pubkey::Pubkey,
-    transaction::VersionedTransaction,
-};
+use solana_sdk::pubkey::Pubkey;
 
-use crate::{common::verify_protocol_fee_beneficiary, rpc::fetch_pool_state};
+use crate::{
+    common::{find_pool_pdas, verify_protocol_fee_beneficiary},
+    rpc::fetch_pool_state,
+    tx::handle_tx,
+};
 
 use super::Subcmd;
 
@@ -47,11 +47,12 @@ impl SetProtocolFeeBeneficiaryArgs {
         let payer = args.config.signer();
         let rpc = args.config.nonblocking_rpc_client();
         let program_id = args.program;
+        let pdas = find_pool_pdas(program_id, args.pool);
 
-        let curr_beneficiary_signer = curr_beneficiary.map(|s| parse_signer(&s).unwrap());
+        let curr_beneficiary_signer = curr_beneficiary.map(|s| parse_signer_or_pubkey(&s).unwrap());
         let curr_beneficiary = curr_beneficiary_signer.as_ref().unwrap_or(&payer);
 
-        let pool_state_acc = fetch_pool_state(&rpc, program_id).await;
+        let pool_state_acc = fetch_pool_state(&rpc, pdas).await;
         let pool_state = try_pool_state(&pool_state_acc.data).unwrap();
         verify_protocol_fee_beneficiary(pool_state, curr_beneficiary.pubkey()).unwrap();
 
@@ -61,7 +62,7 @@ impl SetProtocolFeeBeneficiaryArgs {
                 new_beneficiary,
                 pool_state: pool_state_acc,
             }
-            .resolve_for_prog(program_id)
+            .resolve_with_pool_state_id(pdas.pool_state)
             .unwrap(),
         )
         .unwrap();
@@ -69,13 +70,11 @@ impl SetProtocolFeeBeneficiaryArgs {
         let mut signers = vec![payer.as_ref(), curr_beneficiary.as_ref()];
         signers.dedup();
 
-        let rbh = rpc.get_latest_blockhash().await.unwrap();
-        let tx = VersionedTransaction::try_new(
-            VersionedMessage::V0(Message::try_compile(&payer.pubkey(), &[ix], &[], rbh).unwrap()),
-            &signers,
-        )
-        .unwrap();
+        let tx = args
+            .nonce
+            .build_tx(&rpc, &payer.pubkey(), &[ix], &[], &signers)
+            .await;
 
-        rpc.handle_tx(&tx, args.send_mode).await;
+        handle_tx(&rpc, &tx, args.tx, pdas).await;
     }
 }
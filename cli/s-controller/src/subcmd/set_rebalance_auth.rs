@@ -1,13 +1,12 @@
 use clap::Args;
+use s_cli_utils::parse_signer_or_pubkey;
 use s_controller_interface::set_rebalance_authority_ix_with_program_id;
 use s_controller_lib::{try_pool_state, KnownAuthoritySetRebalanceAuthorityFreeArgs};
-use sanctum_solana_cli_utils::{parse_pubkey_src, parse_signer, TxSendingNonblockingRpcClient};
-use solana_sdk::{
-    message::{v0::Message, VersionedMessage},
-    transaction::VersionedTransaction,
-};
+use sanctum_solana_cli_utils::parse_pubkey_src;
+use solana_readonly_account::sdk::KeyedAccount;
+use solana_sdk::transaction::VersionedTransaction;
 
-use crate::rpc::fetch_pool_state;
+use crate::{common::find_pool_pdas, rpc::fetch_pool_state, tx::handle_tx};
 
 use super::Subcmd;
 
@@ -38,26 +37,30 @@ impl SetRebalanceAuthArgs {
         let payer = args.config.signer();
         let rpc = args.config.nonblocking_rpc_client();
         let program_id = args.program;
+        let pdas = find_pool_pdas(program_id, args.pool);
 
-        let authority_signer = authority.map(|s| parse_signer(&s).unwrap());
+        let authority_signer = authority.map(|s| parse_signer_or_pubkey(&s).unwrap());
         let authority = authority_signer.as_ref().unwrap_or(&payer);
         let new_rebalance_authority = parse_pubkey_src(&new_rebalance_auth).unwrap().pubkey();
 
-        let pool_state_acc = fetch_pool_state(&rpc, program_id).await;
-        let pool_state = try_pool_state(&pool_state_acc.data).unwrap();
+        let pool_state_acc = KeyedAccount {
+            pubkey: pdas.pool_state,
+            account: fetch_pool_state(&rpc, pdas).await,
+        };
+        let pool_state = try_pool_state(&pool_state_acc.account.data).unwrap();
         let keys = if pool_state.admin == authority.pubkey() {
             KnownAuthoritySetRebalanceAuthorityFreeArgs {
                 new_rebalance_authority,
                 pool_state: pool_state_acc,
             }
-            .resolve_pool_admin_for_prog(program_id)
+            .resolve_pool_admin_with_pool_state_id()
             .unwrap()
         } else if pool_state.rebalance_authority == authority.pubkey() {
             KnownAuthoritySetRebalanceAuthorityFreeArgs {
                 new_rebalance_authority,
                 pool_state: pool_state_acc,
             }
-            .resolve_current_rebalance_authority_for_prog(program_id)
+            .resolve_current_rebalance_authority_with_pool_state_id()
             .unwrap()
         } else {
             eprintln!(
@@ -72,13 +75,11 @@ impl SetRebalanceAuthArgs {
         let mut signers = vec![payer.as_ref(), authority.as_ref()];
         signers.dedup();
 
-        let rbh = rpc.get_latest_blockhash().await.unwrap();
-        let tx = VersionedTransaction::try_new(
-            VersionedMessage::V0(Message::try_compile(&payer.pubkey(), &[ix], &[], rbh).unwrap()),
-            &signers,
-        )
-        .unwrap();
+        let tx = args
+            .nonce
+            .build_tx(&rpc, &payer.pubkey(), &[ix], &[], &signers)
+            .await;
 
-        rpc.handle_tx(&tx, args.send_mode).await;
+        handle_tx(&rpc, &tx, args.tx, pdas).await;
     }
 }
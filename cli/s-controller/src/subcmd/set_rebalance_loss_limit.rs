@@ -0,0 +1,94 @@
+use clap::Args;
+use s_cli_utils::parse_signer_or_pubkey;
+use s_controller_interface::{
+    set_rebalance_loss_limit_ix_with_program_id, SetRebalanceLossLimitIxArgs,
+};
+use s_controller_lib::{try_pool_state, SetRebalanceLossLimitFreeArgs};
+use solana_readonly_account::sdk::KeyedAccount;
+use solana_sdk::transaction::VersionedTransaction;
+
+use crate::{
+    common::{find_pool_pdas, verify_admin},
+    rpc::fetch_pool_state,
+    tx::handle_tx,
+};
+
+use super::Subcmd;
+
+#[derive(Args, Debug)]
+#[command(
+    about = "Sets the max SOL value the pool may lose to rebalances",
+    long_about = "Sets the max SOL value the pool may lose to rebalances, in bips of the pool's total SOL value before the rebalance.
+
+Rebalances may not lose any SOL value until this has been set.
+
+Prerequisites:
+- If the pool is in timelock mode, the limits can only be lowered."
+)]
+pub struct SetRebalanceLossLimitArgs {
+    #[arg(
+        long,
+        short,
+        help = "The program's admin authority signer. Defaults to config wallet if not set."
+    )]
+    pub admin: Option<String>,
+
+    #[arg(help = "Max SOL value a single rebalance may lose, in bips")]
+    pub per_rebalance_bps: u16,
+
+    #[arg(help = "Max cumulative SOL value all rebalances in an epoch may lose, in bips")]
+    pub per_epoch_bps: u16,
+}
+
+impl SetRebalanceLossLimitArgs {
+    pub async fn run(args: crate::Args) {
+        let Self {
+            admin,
+            per_rebalance_bps,
+            per_epoch_bps,
+        } = match args.subcmd {
+            Subcmd::SetRebalanceLossLimit(a) => a,
+            _ => unreachable!(),
+        };
+
+        let payer = args.config.signer();
+        let rpc = args.config.nonblocking_rpc_client();
+        let program_id = args.program;
+        let pdas = find_pool_pdas(program_id, args.pool);
+
+        let admin_signer = admin.map(|s| parse_signer_or_pubkey(&s).unwrap());
+        let admin = admin_signer.as_ref().unwrap_or(&payer);
+
+        let pool_state_acc = fetch_pool_state(&rpc, pdas).await;
+        let pool_state = try_pool_state(&pool_state_acc.data).unwrap();
+        verify_admin(pool_state, admin.pubkey()).unwrap();
+
+        let ix = set_rebalance_loss_limit_ix_with_program_id(
+            program_id,
+            SetRebalanceLossLimitFreeArgs {
+                payer: payer.pubkey(),
+                pool_state: KeyedAccount {
+                    pubkey: pdas.pool_state,
+                    account: pool_state_acc,
+                },
+            }
+            .resolve_for_pool(pdas)
+            .unwrap(),
+            SetRebalanceLossLimitIxArgs {
+                max_loss_bps_per_rebalance: per_rebalance_bps,
+                max_loss_bps_per_epoch: per_epoch_bps,
+            },
+        )
+        .unwrap();
+
+        let mut signers = vec![payer.as_ref(), admin.as_ref()];
+        signers.dedup();
+
+        let tx = args
+            .nonce
+            .build_tx(&rpc, &payer.pubkey(), &[ix], &[], &signers)
+            .await;
+
+        handle_tx(&rpc, &tx, args.tx, pdas).await;
+    }
+}
@@ -4,21 +4,19 @@ use clap::{
     builder::{StringValueParser, TypedValueParser},
     Args,
 };
+use s_cli_utils::parse_signer_or_pubkey;
 use s_controller_lib::{
-    find_lst_state_list_address, find_pool_state_address,
-    set_sol_value_calculator_ix_by_mint_full_with_program_id, try_pool_state,
+    set_sol_value_calculator_ix_by_mint_full_for_pool, try_pool_state,
     SetSolValueCalculatorByMintFreeArgs,
 };
-use sanctum_solana_cli_utils::{parse_signer, TxSendingNonblockingRpcClient};
 use solana_readonly_account::sdk::KeyedAccount;
-use solana_sdk::{
-    instruction::AccountMeta,
-    message::{v0::Message, VersionedMessage},
-    pubkey::Pubkey,
-    transaction::VersionedTransaction,
-};
+use solana_sdk::{instruction::AccountMeta, pubkey::Pubkey};
 
-use crate::{common::verify_admin, lst_arg::LstArg};
+use crate::{
+    common::{find_pool_pdas, verify_admin},
+    lst_arg::LstArg,
+    tx::handle_tx,
+};
 
 use super::Subcmd;
 
@@ -73,12 +71,13 @@ impl SetSolValueCalculatorArgs {
         let payer = args.config.signer();
         let rpc = args.config.nonblocking_rpc_client();
         let program_id = args.program;
+        let pdas = find_pool_pdas(program_id, args.pool);
 
-        let admin_signer = admin.map(|s| parse_signer(&s).unwrap());
+        let admin_signer = admin.map(|s| parse_signer_or_pubkey(&s).unwrap());
         let admin = admin_signer.as_ref().unwrap_or(&payer);
 
-        let pool_state_addr = find_pool_state_address(program_id).0;
-        let lst_state_list_addr = find_lst_state_list_address(program_id).0;
+        let pool_state_addr = pdas.pool_state;
+        let lst_state_list_addr = pdas.lst_state_list;
         let mut fetched = rpc
             .get_multiple_accounts(&[pool_state_addr, lst_state_list_addr, mint.mint()])
             .await
@@ -101,8 +100,9 @@ impl SetSolValueCalculatorArgs {
             is_writable: false,
         }))
         .collect();
-        let ix = set_sol_value_calculator_ix_by_mint_full_with_program_id(
+        let ix = set_sol_value_calculator_ix_by_mint_full_for_pool(
             program_id,
+            pdas,
             &SetSolValueCalculatorByMintFreeArgs {
                 pool_state: pool_state_acc,
                 lst_state_list: lst_state_list_acc,
@@ -119,13 +119,11 @@ impl SetSolValueCalculatorArgs {
         let mut signers = vec![payer.as_ref(), admin.as_ref()];
         signers.dedup();
 
-        let rbh = rpc.get_latest_blockhash().await.unwrap();
-        let tx = VersionedTransaction::try_new(
-            VersionedMessage::V0(Message::try_compile(&payer.pubkey(), &[ix], &[], rbh).unwrap()),
-            &signers,
-        )
-        .unwrap();
+        let tx = args
+            .nonce
+            .build_tx(&rpc, &payer.pubkey(), &[ix], &[], &signers)
+            .await;
 
-        rpc.handle_tx(&tx, args.send_mode).await;
+        handle_tx(&rpc, &tx, args.tx, pdas).await;
     }
 }
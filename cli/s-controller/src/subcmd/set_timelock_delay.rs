@@ -0,0 +1,91 @@
+use clap::Args;
+use s_cli_utils::parse_signer_or_pubkey;
+use s_controller_interface::{set_timelock_delay_ix_with_program_id, SetTimelockDelayIxArgs};
+use s_controller_lib::{try_pool_state, SetTimelockDelayFreeArgs};
+use solana_readonly_account::sdk::KeyedAccount;
+
+use crate::{
+    common::{find_pool_pdas, verify_admin},
+    rpc::fetch_pool_state,
+    tx::handle_tx,
+};
+
+use super::Subcmd;
+
+#[derive(Args, Debug)]
+#[command(
+    about = "Enables timelock mode for the pool.",
+    long_about = "Enables timelock mode for the pool.
+
+In timelock mode, set-admin, set-pricing-prog, set-sol-value-calculator, set-protocol-fee and remove-lst
+must be queued with queue-change and can only be executed with execute-change after the delay has elapsed.
+
+Prerequisites:
+- The pool must not already be in timelock mode. Use queue-change set-timelock-delay to change the delay
+  or disable timelock mode once enabled."
+)]
+pub struct SetTimelockDelayArgs {
+    #[arg(
+        long,
+        short,
+        help = "The program's admin authority signer. Defaults to config wallet if not set."
+    )]
+    pub admin: Option<String>,
+
+    #[arg(help = "The delay in hours between queueing and executing a pending change")]
+    pub hours: u8,
+}
+
+impl SetTimelockDelayArgs {
+    pub async fn run(args: crate::Args) {
+        let Self { admin, hours } = match args.subcmd {
+            Subcmd::SetTimelockDelay(a) => a,
+            _ => unreachable!(),
+        };
+
+        let payer = args.config.signer();
+        let rpc = args.config.nonblocking_rpc_client();
+        let program_id = args.program;
+        let pdas = find_pool_pdas(program_id, args.pool);
+
+        let admin_signer = admin.map(|s| parse_signer_or_pubkey(&s).unwrap());
+        let admin = admin_signer.as_ref().unwrap_or(&payer);
+
+        let pool_state_acc = fetch_pool_state(&rpc, pdas).await;
+        let pool_state = try_pool_state(&pool_state_acc.data).unwrap();
+        verify_admin(pool_state, admin.pubkey()).unwrap();
+        if pool_state.timelock_delay_hours != 0 {
+            eprintln!(
+                "Pool already in timelock mode with delay {} hours. Use queue-change set-timelock-delay instead",
+                pool_state.timelock_delay_hours
+            );
+            std::process::exit(-1);
+        }
+
+        let ix = set_timelock_delay_ix_with_program_id(
+            program_id,
+            SetTimelockDelayFreeArgs {
+                pool_state: KeyedAccount {
+                    pubkey: pdas.pool_state,
+                    account: pool_state_acc,
+                },
+            }
+            .resolve_for_pool(pdas)
+            .unwrap(),
+            SetTimelockDelayIxArgs {
+                timelock_delay_hours: hours,
+            },
+        )
+        .unwrap();
+
+        let mut signers = vec![payer.as_ref(), admin.as_ref()];
+        signers.dedup();
+
+        let tx = args
+            .nonce
+            .build_tx(&rpc, &payer.pubkey(), &[ix], &[], &signers)
+            .await;
+
+        handle_tx(&rpc, &tx, args.tx, pdas).await;
+    }
+}
@@ -0,0 +1,9 @@
+use super::Subcmd;
+
+pub async fn run(args: crate::Args) {
+    let sign_args = match args.subcmd {
+        Subcmd::Sign(a) => a,
+        _ => unreachable!(),
+    };
+    sign_args.run(args.config.signer());
+}
@@ -0,0 +1,63 @@
+use clap::{
+    builder::{StringValueParser, TypedValueParser},
+    Args,
+};
+use jupiter_amm_interface::SwapMode;
+use solana_sdk::native_token::sol_to_lamports;
+
+use crate::{
+    lst_arg::LstArg,
+    user_swap::{SwapMint, UserSwap, UserSwapArgs},
+};
+
+use super::Subcmd;
+
+#[derive(Args, Debug)]
+#[command(
+    about = "Swap an exact amount of one LST for another",
+    long_about = "Swap an exact amount of input LST for output LST, outputting the quote to stderr.
+LSTs are transferred from and to the authority's associated token accounts. The output LST's associated token account is created if it does not exist."
+)]
+pub struct SwapArgs {
+    #[arg(
+        help = "Mint of the LST to swap from. Can either be a pubkey or case-insensitive symbol of a token on sanctum-lst-list. e.g. 'jitosol'",
+        value_parser = StringValueParser::new().try_map(|s| LstArg::parse_arg(&s)),
+    )]
+    pub input_mint: LstArg,
+
+    #[arg(
+        help = "Mint of the LST to swap to. Can either be a pubkey or case-insensitive symbol of a token on sanctum-lst-list. e.g. 'msol'",
+        value_parser = StringValueParser::new().try_map(|s| LstArg::parse_arg(&s)),
+    )]
+    pub output_mint: LstArg,
+
+    #[arg(help = "Amount of input LST to swap")]
+    pub amount: f64,
+
+    #[command(flatten)]
+    pub user_swap: UserSwapArgs,
+}
+
+impl SwapArgs {
+    pub async fn run(args: crate::Args) {
+        let Self {
+            input_mint,
+            output_mint,
+            amount,
+            user_swap,
+        } = match &args.subcmd {
+            Subcmd::Swap(a) => a,
+            _ => unreachable!(),
+        };
+
+        UserSwap {
+            input: SwapMint::Lst(input_mint.mint()),
+            output: SwapMint::Lst(output_mint.mint()),
+            amount: sol_to_lamports(*amount), // assume all LSTs are 9 d.p.
+            swap_mode: SwapMode::ExactIn,
+            args: user_swap,
+        }
+        .run(&args)
+        .await;
+    }
+}
@@ -3,20 +3,15 @@ use clap::{
     Args,
 };
 use s_controller_lib::{
-    find_lst_state_list_address, find_pool_state_address, sync_sol_value_ix_full_for_prog,
-    SyncSolValueByMintFreeArgs, SyncSolValuePdas,
+    sync_sol_value_ix_full_for_prog, SyncSolValueByMintFreeArgs, SyncSolValuePdas,
 };
-use sanctum_solana_cli_utils::TxSendingNonblockingRpcClient;
 use solana_readonly_account::keyed::Keyed;
-use solana_sdk::{
-    instruction::AccountMeta,
-    message::{v0::Message, VersionedMessage},
-    pubkey::Pubkey,
-    transaction::VersionedTransaction,
-};
+use solana_sdk::{instruction::AccountMeta, pubkey::Pubkey};
 use std::str::FromStr;
 
-use crate::{lst_arg::LstArg, rpc::does_tx_modify_pool_state};
+use crate::{
+    common::find_pool_pdas, lst_arg::LstArg, rpc::does_tx_modify_pool_state, tx::handle_tx,
+};
 
 use super::Subcmd;
 
@@ -66,6 +61,7 @@ impl SyncArgs {
         let payer = args.config.signer();
         let rpc = args.config.nonblocking_rpc_client();
         let program_id = args.program;
+        let pdas = find_pool_pdas(program_id, args.pool);
 
         // accounts suffix slice including lst_mint as first account
         let suffix = mint.sol_value_calculator_accounts_of().unwrap_or_else(|| {
@@ -82,8 +78,8 @@ impl SyncArgs {
             .collect()
         });
 
-        let pool_state_addr = find_pool_state_address(program_id).0;
-        let lst_state_list_addr = find_lst_state_list_address(program_id).0;
+        let pool_state_addr = pdas.pool_state;
+        let lst_state_list_addr = pdas.lst_state_list;
         let mint_addr = mint.mint();
         let mut fetched_accs = rpc
             .get_multiple_accounts(&[mint_addr, lst_state_list_addr, pool_state_addr])
@@ -114,29 +110,19 @@ impl SyncArgs {
         )
         .unwrap();
 
-        let rbh = rpc.get_latest_blockhash().await.unwrap();
-        let tx = VersionedTransaction::try_new(
-            VersionedMessage::V0(Message::try_compile(&payer.pubkey(), &[ix], &[], rbh).unwrap()),
-            &[payer.as_ref()],
-        )
-        .unwrap();
+        let tx = args
+            .nonce
+            .build_tx(&rpc, &payer.pubkey(), &[ix], &[], &[payer.as_ref()])
+            .await;
 
         if !force {
-            let should_run = does_tx_modify_pool_state(
-                &rpc,
-                &tx,
-                Keyed {
-                    pubkey: pool_state_addr,
-                    account: &pool_state_acc,
-                },
-            )
-            .await;
+            let should_run = does_tx_modify_pool_state(&rpc, &tx, pdas, &pool_state_acc.data).await;
             if !should_run {
                 eprintln!("Sync not required, --force not provided. Exiting.");
                 return;
             }
         }
 
-        rpc.handle_tx(&tx, args.send_mode).await;
+        handle_tx(&rpc, &tx, args.tx, pdas).await;
     }
 }
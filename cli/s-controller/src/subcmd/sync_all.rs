@@ -1,34 +1,37 @@
 use clap::Args;
 use s_controller_interface::LstState;
 use s_controller_lib::{
-    find_lst_state_list_address, find_pool_state_address, sync_sol_value_ix_full_for_prog,
-    try_lst_state_list, SyncSolValueByMintFreeArgs, SyncSolValuePdas,
+    sync_sol_value_ix_full_for_prog, try_lst_state_list, SyncSolValueByMintFreeArgs,
+    SyncSolValuePdas,
 };
 use sanctum_lst_list::SanctumLst;
-use sanctum_solana_cli_utils::TxSendingNonblockingRpcClient;
 use sanctum_token_lib::MintWithTokenProgram;
-use solana_readonly_account::keyed::Keyed;
 use solana_sdk::{
-    instruction::Instruction,
-    message::{v0::Message, VersionedMessage},
-    transaction::VersionedTransaction,
+    address_lookup_table_account::AddressLookupTableAccount, instruction::Instruction,
+    pubkey::Pubkey,
 };
 
 use crate::{
-    common::{find_sanctum_lst_by_mint, sol_value_calculator_accounts_of_sanctum_lst},
+    common::{
+        find_pool_pdas, find_sanctum_lst_by_mint, sol_value_calculator_accounts_of_sanctum_lst,
+    },
+    lut::{fetch_luts, ixs_fit_in_tx},
     rpc::does_tx_modify_pool_state,
+    tx::handle_tx,
 };
 
 use super::Subcmd;
 
-// TODO: this can probably increase if we use a LUT
+/// Max syncs per tx if no lookup table is provided.
+/// With a lookup table, syncs are packed into each tx until it's full instead.
 const MAX_GENERIC_SOL_VAL_CALC_SYNCS_PER_TX: usize = 4;
 
 #[derive(Args, Debug)]
 #[command(
     about = "Sync SOL value for all sanctum-lst-list LSTs in the pool",
     long_about = "Sync SOL value for all sanctum-lst-list LSTs in the pool.
-To sync a single LST that might not be on sanctum-lst-list, use sync."
+To sync a single LST that might not be on sanctum-lst-list, use sync.
+Packs more syncs into each transaction if --lut is provided."
 )]
 pub struct SyncAllArgs {
     #[arg(
@@ -50,9 +53,10 @@ impl SyncAllArgs {
         let payer = args.config.signer();
         let rpc = args.config.nonblocking_rpc_client();
         let program_id = args.program;
+        let pdas = find_pool_pdas(program_id, args.pool);
 
-        let lst_state_list_addr = find_lst_state_list_address(program_id).0;
-        let pool_state_addr = find_pool_state_address(program_id).0;
+        let lst_state_list_addr = pdas.lst_state_list;
+        let pool_state_addr = pdas.pool_state;
 
         let mut fetched_accs = rpc
             .get_multiple_accounts(&[lst_state_list_addr, pool_state_addr])
@@ -74,56 +78,57 @@ impl SyncAllArgs {
             })
             .collect();
 
+        let all_ixs: Vec<Instruction> = sanctum_lsts
+            .iter()
+            .map(|sanctum_lst| {
+                let (keys, index, sol_value_calculator_program_id) = SyncSolValueByMintFreeArgs {
+                    lst_state_list: &lst_state_list_acc,
+                    lst_mint: MintWithTokenProgram {
+                        pubkey: sanctum_lst.mint,
+                        token_program: sanctum_lst.token_program,
+                    },
+                }
+                .resolve_with_pdas(SyncSolValuePdas {
+                    pool_state: pool_state_addr,
+                    lst_state_list: lst_state_list_addr,
+                })
+                .unwrap();
+                sync_sol_value_ix_full_for_prog(
+                    program_id,
+                    keys,
+                    index,
+                    &sol_value_calculator_accounts_of_sanctum_lst(sanctum_lst),
+                    sol_value_calculator_program_id,
+                )
+                .unwrap()
+            })
+            .collect();
+
+        let luts = fetch_luts(&rpc, args.lut).await;
+        let mut batches = vec![];
+        let mut start = 0;
+        for len in sync_batch_lens(payer.pubkey(), &all_ixs, &luts) {
+            let end = start + len;
+            batches.push((&sanctum_lsts[start..end], &all_ixs[start..end]));
+            start = end;
+        }
+
         // dyn Signer is not Sync, so just send the txes sequentially
-        let fut_iter = sanctum_lsts
-            .chunks(MAX_GENERIC_SOL_VAL_CALC_SYNCS_PER_TX)
-            .map(|chunk| async {
-                let ixs: Vec<Instruction> = chunk
-                    .iter()
-                    .map(|sanctum_lst| {
-                        let (keys, index, sol_value_calculator_program_id) =
-                            SyncSolValueByMintFreeArgs {
-                                lst_state_list: &lst_state_list_acc,
-                                lst_mint: MintWithTokenProgram {
-                                    pubkey: sanctum_lst.mint,
-                                    token_program: sanctum_lst.token_program,
-                                },
-                            }
-                            .resolve_with_pdas(SyncSolValuePdas {
-                                pool_state: pool_state_addr,
-                                lst_state_list: lst_state_list_addr,
-                            })
-                            .unwrap();
-                        sync_sol_value_ix_full_for_prog(
-                            program_id,
-                            keys,
-                            index,
-                            &sol_value_calculator_accounts_of_sanctum_lst(sanctum_lst),
-                            sol_value_calculator_program_id,
-                        )
-                        .unwrap()
-                    })
-                    .collect();
+        let fut_iter = batches.into_iter().map(|(chunk, ixs)| {
+            let luts = &luts;
+            let rpc = &rpc;
+            let payer = &payer;
+            let pool_state_acc = &pool_state_acc;
+            let nonce = &args.nonce;
+            async move {
                 // since we're doing it sequentially, fetch blockhash
                 // on every iter to make sure it wont expire
-                let rbh = rpc.get_latest_blockhash().await.unwrap();
-                let tx = VersionedTransaction::try_new(
-                    VersionedMessage::V0(
-                        Message::try_compile(&payer.pubkey(), &ixs, &[], rbh).unwrap(),
-                    ),
-                    &[payer.as_ref()],
-                )
-                .unwrap();
-                if !force {
-                    let should_run = does_tx_modify_pool_state(
-                        &rpc,
-                        &tx,
-                        Keyed {
-                            pubkey: pool_state_addr,
-                            account: &pool_state_acc,
-                        },
-                    )
+                let tx = nonce
+                    .build_tx(rpc, &payer.pubkey(), ixs, luts, &[payer.as_ref()])
                     .await;
+                if !force {
+                    let should_run =
+                        does_tx_modify_pool_state(rpc, &tx, pdas, &pool_state_acc.data).await;
                     if !should_run {
                         eprint!("Sync not required for ");
                         for sanctum_lst in chunk.iter() {
@@ -133,21 +138,55 @@ impl SyncAllArgs {
                         return;
                     }
                 }
-                rpc.handle_tx(&tx, args.send_mode).await;
-            });
+                handle_tx(rpc, &tx, args.tx, pdas).await;
+            }
+        });
         for fut in fut_iter {
             fut.await;
         }
     }
 }
 
+/// Lengths of the consecutive batches of sync `ixs` to send in each tx.
+///
+/// Without a lookup table, this is fixed-size chunks of [`MAX_GENERIC_SOL_VAL_CALC_SYNCS_PER_TX`].
+/// With one, each batch is greedily extended for as long as the tx still fits.
+fn sync_batch_lens(
+    payer: Pubkey,
+    ixs: &[Instruction],
+    luts: &[AddressLookupTableAccount],
+) -> Vec<usize> {
+    if luts.is_empty() {
+        return ixs
+            .chunks(MAX_GENERIC_SOL_VAL_CALC_SYNCS_PER_TX)
+            .map(|chunk| chunk.len())
+            .collect();
+    }
+    let mut res = vec![];
+    let mut start = 0;
+    while start < ixs.len() {
+        let mut end = start + 1;
+        while end < ixs.len() && ixs_fit_in_tx(payer, &ixs[start..=end], luts) {
+            end += 1;
+        }
+        res.push(end - start);
+        start = end;
+    }
+    res
+}
+
 #[cfg(test)]
 mod tests {
     use generic_pool_calculator_interface::SOL_TO_LST_IX_ACCOUNTS_LEN;
     use s_controller_interface::SyncSolValueKeys;
     use solana_sdk::{
-        hash::Hash, instruction::AccountMeta, packet::PACKET_DATA_SIZE, pubkey::Pubkey,
-        signature::Keypair, signer::Signer,
+        hash::Hash,
+        instruction::AccountMeta,
+        message::{v0::Message, VersionedMessage},
+        packet::PACKET_DATA_SIZE,
+        signature::Keypair,
+        signer::Signer,
+        transaction::VersionedTransaction,
     };
 
     use super::*;
@@ -194,4 +233,67 @@ mod tests {
         // println!("{serlen}");
         assert!(serlen <= PACKET_DATA_SIZE);
     }
+
+    #[test]
+    fn sync_batch_lens_lut_fits_more_per_tx() {
+        let program_id = Pubkey::new_unique();
+        let base_keys = SyncSolValueKeys {
+            lst_mint: Pubkey::default(),
+            pool_state: Pubkey::new_unique(),
+            lst_state_list: Pubkey::new_unique(),
+            pool_reserves: Pubkey::new_unique(),
+        };
+        let ixs: Vec<Instruction> = (0..20)
+            .map(|_| {
+                let mut accounts = base_keys;
+                accounts.lst_mint = Pubkey::new_unique();
+                accounts.pool_reserves = Pubkey::new_unique();
+                let sol_value_calculator_accounts: [AccountMeta; SOL_TO_LST_IX_ACCOUNTS_LEN] =
+                    [0; SOL_TO_LST_IX_ACCOUNTS_LEN].map(|_| AccountMeta {
+                        pubkey: Pubkey::new_unique(),
+                        is_signer: false,
+                        is_writable: false,
+                    });
+                sync_sol_value_ix_full_for_prog(
+                    program_id,
+                    accounts,
+                    0,
+                    &sol_value_calculator_accounts,
+                    Pubkey::new_unique(),
+                )
+                .unwrap()
+            })
+            .collect();
+        let luts = [AddressLookupTableAccount {
+            key: Pubkey::new_unique(),
+            addresses: ixs
+                .iter()
+                .flat_map(|ix| ix.accounts.iter().map(|meta| meta.pubkey))
+                .collect(),
+        }];
+        let payer = Keypair::new();
+
+        let no_lut_lens = sync_batch_lens(payer.pubkey(), &ixs, &[]);
+        assert!(no_lut_lens
+            .iter()
+            .all(|len| *len <= MAX_GENERIC_SOL_VAL_CALC_SYNCS_PER_TX));
+
+        let lut_lens = sync_batch_lens(payer.pubkey(), &ixs, &luts);
+        assert_eq!(lut_lens.iter().sum::<usize>(), ixs.len());
+        assert!(lut_lens.len() < no_lut_lens.len());
+        let mut start = 0;
+        for len in lut_lens {
+            let end = start + len;
+            let tx = VersionedTransaction::try_new(
+                VersionedMessage::V0(
+                    Message::try_compile(&payer.pubkey(), &ixs[start..end], &luts, Hash::default())
+                        .unwrap(),
+                ),
+                &[&payer],
+            )
+            .unwrap();
+            assert!(bincode::serialize(&tx).unwrap().len() <= PACKET_DATA_SIZE);
+            start = end;
+        }
+    }
 }
@@ -1,53 +1,328 @@
 use clap::Args;
-use s_controller_interface::LstState;
+use s_cli_utils::{csv_record, OutputFormat, OUTPUT_FORMAT_HELP};
+use s_controller_interface::{LstState, PoolState, RebalanceLossState};
 use s_controller_lib::{
     create_pool_reserves_address_with_pool_state_id,
-    create_protocol_fee_accumulator_address_with_protocol_fee_id, find_lst_state_list_address,
-    find_pool_state_address, find_protocol_fee_address, try_lst_state_list, try_pool_state, U8Bool,
+    create_protocol_fee_accumulator_address_with_protocol_fee_id, try_lst_state_list,
+    try_pool_state, try_rebalance_loss_state, U8Bool,
 };
 use sanctum_token_lib::{mint_supply, token_account_balance};
-use solana_sdk::native_token::lamports_to_sol;
+use serde::Serialize;
+use solana_sdk::{native_token::lamports_to_sol, pubkey::Pubkey};
 
-use crate::common::find_sanctum_lst_by_mint;
+use crate::common::{find_pool_pdas, find_sanctum_lst_by_mint};
 
 use super::Subcmd;
 
 #[derive(Args, Debug)]
 #[command(long_about = "View info about the pool.")]
 pub struct ViewArgs {
-    #[arg(long, short, help = "Display info as raw struct")]
+    #[arg(
+        long,
+        short,
+        help = "Display info as raw struct",
+        conflicts_with = "output"
+    )]
     pub raw: bool,
+
+    #[arg(
+        long,
+        short,
+        help = OUTPUT_FORMAT_HELP,
+        default_value_t = OutputFormat::default(),
+        value_enum,
+    )]
+    pub output: OutputFormat,
+}
+
+/// Pool info for machine-readable output.
+///
+/// Pubkeys are base58 strings and all token and SOL value amounts are in atomics.
+#[derive(Clone, Debug, Serialize)]
+struct PoolView {
+    program_id: String,
+    lp_token_supply: u64,
+    pool_state_address: String,
+    pool_state: PoolStateView,
+    rebalance_loss_state_address: String,
+    rebalance_loss_state: Option<RebalanceLossStateView>,
+    protocol_fee_address: String,
+    lst_state_list_address: String,
+    lst_state_list: Vec<LstView>,
+}
+
+#[derive(Clone, Debug, Serialize)]
+struct PoolStateView {
+    total_sol_value: u64,
+    trading_protocol_fee_bps: u16,
+    lp_protocol_fee_bps: u16,
+    version: u8,
+    is_disabled: bool,
+    is_rebalancing: bool,
+    timelock_delay_hours: u8,
+    admin: String,
+    rebalance_authority: String,
+    protocol_fee_beneficiary: String,
+    pricing_program: String,
+    lp_token_mint: String,
+}
+
+impl From<&PoolState> for PoolStateView {
+    fn from(
+        PoolState {
+            total_sol_value,
+            trading_protocol_fee_bps,
+            lp_protocol_fee_bps,
+            version,
+            is_disabled,
+            is_rebalancing,
+            timelock_delay_hours,
+            admin,
+            rebalance_authority,
+            protocol_fee_beneficiary,
+            pricing_program,
+            lp_token_mint,
+        }: &PoolState,
+    ) -> Self {
+        Self {
+            total_sol_value: *total_sol_value,
+            trading_protocol_fee_bps: *trading_protocol_fee_bps,
+            lp_protocol_fee_bps: *lp_protocol_fee_bps,
+            version: *version,
+            is_disabled: U8Bool(*is_disabled).is_true(),
+            is_rebalancing: U8Bool(*is_rebalancing).is_true(),
+            timelock_delay_hours: *timelock_delay_hours,
+            admin: admin.to_string(),
+            rebalance_authority: rebalance_authority.to_string(),
+            protocol_fee_beneficiary: protocol_fee_beneficiary.to_string(),
+            pricing_program: pricing_program.to_string(),
+            lp_token_mint: lp_token_mint.to_string(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
+struct RebalanceLossStateView {
+    max_loss_bps_per_rebalance: u16,
+    max_loss_bps_per_epoch: u16,
+    epoch: u64,
+    epoch_loss: u64,
+    epoch_start_total_sol_value: u64,
+}
+
+impl From<&RebalanceLossState> for RebalanceLossStateView {
+    fn from(
+        RebalanceLossState {
+            max_loss_bps_per_rebalance,
+            max_loss_bps_per_epoch,
+            epoch,
+            epoch_loss,
+            epoch_start_total_sol_value,
+            ..
+        }: &RebalanceLossState,
+    ) -> Self {
+        Self {
+            max_loss_bps_per_rebalance: *max_loss_bps_per_rebalance,
+            max_loss_bps_per_epoch: *max_loss_bps_per_epoch,
+            epoch: *epoch,
+            epoch_loss: *epoch_loss,
+            epoch_start_total_sol_value: *epoch_start_total_sol_value,
+        }
+    }
+}
+
+/// A [`LstState`] along with its pool reserves and protocol fee accumulator balances
+#[derive(Clone, Debug, Serialize)]
+struct LstView {
+    symbol: Option<String>,
+    mint: String,
+    is_input_disabled: bool,
+    sol_value: u64,
+    sol_value_calculator: String,
+    max_share_bps: u16,
+    reserves_address: String,
+    reserves_balance: u64,
+    protocol_fee_accumulator_address: String,
+    protocol_fee_accumulator_balance: u64,
+}
+
+impl LstView {
+    const CSV_HEADER: [&'static str; 10] = [
+        "symbol",
+        "mint",
+        "is_input_disabled",
+        "sol_value",
+        "sol_value_calculator",
+        "max_share_bps",
+        "reserves_address",
+        "reserves_balance",
+        "protocol_fee_accumulator_address",
+        "protocol_fee_accumulator_balance",
+    ];
+
+    fn csv_record(&self) -> String {
+        let Self {
+            symbol,
+            mint,
+            is_input_disabled,
+            sol_value,
+            sol_value_calculator,
+            max_share_bps,
+            reserves_address,
+            reserves_balance,
+            protocol_fee_accumulator_address,
+            protocol_fee_accumulator_balance,
+        } = self;
+        csv_record([
+            symbol.clone().unwrap_or_default(),
+            mint.clone(),
+            is_input_disabled.to_string(),
+            sol_value.to_string(),
+            sol_value_calculator.clone(),
+            max_share_bps.to_string(),
+            reserves_address.clone(),
+            reserves_balance.to_string(),
+            protocol_fee_accumulator_address.clone(),
+            protocol_fee_accumulator_balance.to_string(),
+        ])
+    }
+}
+
+/// Per-LST info fetched for display
+struct LstInfo<'a> {
+    lst_state: &'a LstState,
+    symbol: Option<String>,
+    reserves_addr: Pubkey,
+    reserves_balance: u64,
+    protocol_fee_accum_addr: Pubkey,
+    protocol_fee_accum_balance: u64,
+}
+
+impl From<&LstInfo<'_>> for LstView {
+    fn from(
+        LstInfo {
+            lst_state,
+            symbol,
+            reserves_addr,
+            reserves_balance,
+            protocol_fee_accum_addr,
+            protocol_fee_accum_balance,
+        }: &LstInfo,
+    ) -> Self {
+        Self {
+            symbol: symbol.clone(),
+            mint: lst_state.mint.to_string(),
+            is_input_disabled: U8Bool(lst_state.is_input_disabled).is_true(),
+            sol_value: lst_state.sol_value,
+            sol_value_calculator: lst_state.sol_value_calculator.to_string(),
+            max_share_bps: lst_state.max_share_bps,
+            reserves_address: reserves_addr.to_string(),
+            reserves_balance: *reserves_balance,
+            protocol_fee_accumulator_address: protocol_fee_accum_addr.to_string(),
+            protocol_fee_accumulator_balance: *protocol_fee_accum_balance,
+        }
+    }
 }
 
 impl ViewArgs {
     pub async fn run(args: crate::Args) {
-        let Self { raw } = match args.subcmd {
+        let Self { raw, output } = match args.subcmd {
             Subcmd::View(a) => a,
             _ => unreachable!(),
         };
 
         let rpc = args.config.nonblocking_rpc_client();
         let program_id = args.program;
+        let pdas = find_pool_pdas(program_id, args.pool);
 
-        let pool_state_addr = find_pool_state_address(program_id).0;
-        let lst_state_list_addr = find_lst_state_list_address(program_id).0;
-        let protocol_fee_id = find_protocol_fee_address(program_id).0;
+        let pool_state_addr = pdas.pool_state;
+        let lst_state_list_addr = pdas.lst_state_list;
+        let protocol_fee_id = pdas.protocol_fee;
 
         let mut main_accs = rpc
-            .get_multiple_accounts(&[pool_state_addr, lst_state_list_addr])
+            .get_multiple_accounts(&[
+                pool_state_addr,
+                lst_state_list_addr,
+                pdas.rebalance_loss_state,
+            ])
             .await
             .unwrap();
+        let rebalance_loss_state_acc = main_accs.pop().unwrap();
         let lst_state_list_acc = main_accs.pop().unwrap().unwrap();
         let pool_state_acc = main_accs.pop().unwrap().unwrap();
         let pool_state = try_pool_state(&pool_state_acc.data).unwrap();
         let lst_state_list = try_lst_state_list(&lst_state_list_acc.data).unwrap();
+        let rebalance_loss_state = rebalance_loss_state_acc
+            .as_ref()
+            .map(|acc| try_rebalance_loss_state(&acc.data).unwrap());
         let lp_mint_acc = rpc.get_account(&pool_state.lp_token_mint).await.unwrap();
+        let lp_token_supply = mint_supply(lp_mint_acc).unwrap();
+
+        let mut lst_infos = Vec::with_capacity(lst_state_list.len());
+        for lst_state in lst_state_list {
+            let mint = &lst_state.mint;
+            let sanctum_lst_opt = find_sanctum_lst_by_mint(*mint);
+            let token_program = match sanctum_lst_opt {
+                Some(s) => std::future::ready(s.token_program).await,
+                None => async { rpc.get_account(mint).await.unwrap().owner }.await,
+            };
+            let reserves_addr = create_pool_reserves_address_with_pool_state_id(
+                pool_state_addr,
+                lst_state,
+                token_program,
+            )
+            .unwrap();
+            let protocol_fee_accum_addr =
+                create_protocol_fee_accumulator_address_with_protocol_fee_id(
+                    protocol_fee_id,
+                    lst_state,
+                    token_program,
+                )
+                .unwrap();
+            let mut token_accs = rpc
+                .get_multiple_accounts(&[reserves_addr, protocol_fee_accum_addr])
+                .await
+                .unwrap();
+            let protocol_fee_accum_acc = token_accs.pop().unwrap().unwrap();
+            let reserves_acc = token_accs.pop().unwrap().unwrap();
+            lst_infos.push(LstInfo {
+                lst_state,
+                symbol: sanctum_lst_opt.map(|lst| lst.symbol.clone()),
+                reserves_addr,
+                reserves_balance: token_account_balance(reserves_acc).unwrap(),
+                protocol_fee_accum_addr,
+                protocol_fee_accum_balance: token_account_balance(protocol_fee_accum_acc).unwrap(),
+            });
+        }
+
+        match output {
+            OutputFormat::Json => {
+                let view = PoolView {
+                    program_id: program_id.to_string(),
+                    lp_token_supply,
+                    pool_state_address: pool_state_addr.to_string(),
+                    pool_state: pool_state.into(),
+                    rebalance_loss_state_address: pdas.rebalance_loss_state.to_string(),
+                    rebalance_loss_state: rebalance_loss_state.map(Into::into),
+                    protocol_fee_address: protocol_fee_id.to_string(),
+                    lst_state_list_address: lst_state_list_addr.to_string(),
+                    lst_state_list: lst_infos.iter().map(Into::into).collect(),
+                };
+                println!("{}", serde_json::to_string_pretty(&view).unwrap());
+                return;
+            }
+            OutputFormat::Csv => {
+                println!("{}", csv_record(LstView::CSV_HEADER));
+                for lst_info in &lst_infos {
+                    println!("{}", LstView::from(lst_info).csv_record());
+                }
+                return;
+            }
+            OutputFormat::Text => (),
+        }
 
         println!("Viewing info for program id: {program_id}");
-        println!(
-            "  LP token supply: {}",
-            lamports_to_sol(mint_supply(lp_mint_acc).unwrap())
-        );
+        println!("  LP token supply: {}", lamports_to_sol(lp_token_supply));
         println!("  Pool State address: {pool_state_addr}");
         if raw {
             println!("{pool_state:#?}");
@@ -69,6 +344,10 @@ impl ViewArgs {
             println!("    version: {}", pool_state.version);
             println!("    is_disabled: {}", pool_state.is_disabled);
             println!("    is_rebalancing: {}", pool_state.is_rebalancing);
+            println!(
+                "    timelock_delay_hours: {}",
+                pool_state.timelock_delay_hours
+            );
             println!("    admin: {}", pool_state.admin);
             println!(
                 "    rebalance_authority: {}",
@@ -81,6 +360,40 @@ impl ViewArgs {
             println!("    pricing_program: {}", pool_state.pricing_program);
             println!("    lp_token_mint: {}", pool_state.lp_token_mint);
         }
+        println!(
+            "  Rebalance Loss State address: {}",
+            pdas.rebalance_loss_state
+        );
+        match rebalance_loss_state {
+            None => println!(
+                "  Rebalance Loss State: not initialized, rebalances may not lose SOL value"
+            ),
+            Some(rebalance_loss_state) => {
+                if raw {
+                    println!("{rebalance_loss_state:#?}");
+                    println!();
+                } else {
+                    println!("  Rebalance Loss State:");
+                    println!(
+                        "    max_loss_bps_per_rebalance: {}",
+                        rebalance_loss_state.max_loss_bps_per_rebalance
+                    );
+                    println!(
+                        "    max_loss_bps_per_epoch: {}",
+                        rebalance_loss_state.max_loss_bps_per_epoch
+                    );
+                    println!("    epoch: {}", rebalance_loss_state.epoch);
+                    println!(
+                        "    epoch_loss: {}",
+                        lamports_to_sol(rebalance_loss_state.epoch_loss)
+                    );
+                    println!(
+                        "    epoch_start_total_sol_value: {}",
+                        lamports_to_sol(rebalance_loss_state.epoch_start_total_sol_value)
+                    );
+                }
+            }
+        }
         println!("  Protocol Fee address: {protocol_fee_id}");
         println!("  LST State List address: {lst_state_list_addr}");
 
@@ -89,55 +402,38 @@ impl ViewArgs {
             println!();
         } else {
             println!("  LST State List:");
-            for lst_state in lst_state_list {
+            for LstInfo {
+                lst_state,
+                symbol,
+                reserves_addr,
+                reserves_balance,
+                protocol_fee_accum_addr,
+                protocol_fee_accum_balance,
+            } in lst_infos
+            {
                 let LstState {
                     mint,
                     is_input_disabled,
                     sol_value,
                     sol_value_calculator,
+                    max_share_bps,
                     ..
                 } = lst_state;
-                let sanctum_lst_opt = find_sanctum_lst_by_mint(*mint);
-                println!(
-                    "    {}:",
-                    sanctum_lst_opt.map_or_else(|| mint.to_string(), |lst| lst.symbol.clone())
-                );
+                println!("    {}:", symbol.unwrap_or_else(|| mint.to_string()));
                 println!(
                     "      is_input_disabled: {}",
                     U8Bool(*is_input_disabled).is_true()
                 );
                 println!("      sol_value: {}", lamports_to_sol(*sol_value));
                 println!("      sol_value_calculator: {sol_value_calculator}");
-                let token_program = match sanctum_lst_opt {
-                    Some(s) => std::future::ready(s.token_program).await,
-                    None => async { rpc.get_account(mint).await.unwrap().owner }.await,
-                };
-                let reserves_addr = create_pool_reserves_address_with_pool_state_id(
-                    pool_state_addr,
-                    lst_state,
-                    token_program,
-                )
-                .unwrap();
-                let protocol_fee_accum_addr =
-                    create_protocol_fee_accumulator_address_with_protocol_fee_id(
-                        protocol_fee_id,
-                        lst_state,
-                        token_program,
-                    )
-                    .unwrap();
-                let mut token_accs = rpc
-                    .get_multiple_accounts(&[reserves_addr, protocol_fee_accum_addr])
-                    .await
-                    .unwrap();
-                let protocol_fee_accum_acc = token_accs.pop().unwrap().unwrap();
-                let reserves_acc = token_accs.pop().unwrap().unwrap();
+                println!("      max_share_bps: {max_share_bps}");
                 println!(
                     "      reserves {reserves_addr}: {}",
-                    lamports_to_sol(token_account_balance(reserves_acc).unwrap())
+                    lamports_to_sol(reserves_balance)
                 );
                 println!(
                     "      protocol fees {protocol_fee_accum_addr}: {}",
-                    lamports_to_sol(token_account_balance(protocol_fee_accum_acc).unwrap())
+                    lamports_to_sol(protocol_fee_accum_balance)
                 );
                 println!();
             }
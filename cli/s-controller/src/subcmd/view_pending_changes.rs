@@ -0,0 +1,55 @@
+use clap::Args;
+use s_controller_lib::{try_pending_change_list, AdminChange};
+use solana_sdk::{
+    account::from_account,
+    sysvar::clock::{self, Clock},
+};
+
+use crate::common::find_pool_pdas;
+
+use super::Subcmd;
+
+#[derive(Args, Debug)]
+#[command(long_about = "View the pool's pending changes queued in timelock mode.")]
+pub struct ViewPendingChangesArgs;
+
+impl ViewPendingChangesArgs {
+    pub async fn run(args: crate::Args) {
+        match args.subcmd {
+            Subcmd::ViewPendingChanges(_) => (),
+            _ => unreachable!(),
+        };
+
+        let rpc = args.config.nonblocking_rpc_client();
+        let program_id = args.program;
+        let pdas = find_pool_pdas(program_id, args.pool);
+
+        let mut fetched = rpc
+            .get_multiple_accounts(&[pdas.pending_change_list, clock::ID])
+            .await
+            .unwrap();
+        let clock_acc = fetched.pop().unwrap().unwrap();
+        let now = from_account::<Clock, _>(&clock_acc).unwrap().unix_timestamp;
+
+        println!("Pending Change List address: {}", pdas.pending_change_list);
+        let pending_change_list_acc = match fetched.pop().unwrap() {
+            Some(acc) => acc,
+            None => {
+                println!("  No pending changes");
+                return;
+            }
+        };
+        let pending_change_list = try_pending_change_list(&pending_change_list_acc.data).unwrap();
+        for (index, pending_change) in pending_change_list.iter().enumerate() {
+            let change = AdminChange::try_from(pending_change).unwrap();
+            let executable_at = pending_change.executable_at;
+            println!("  {index}: {change:?}");
+            println!("    executable_at: {executable_at}");
+            if now >= executable_at {
+                println!("    executable now");
+            } else {
+                println!("    executable in {}s", executable_at - now);
+            }
+        }
+    }
+}
@@ -0,0 +1,346 @@
+use clap::Args;
+use s_cli_utils::parse_signer_or_pubkey;
+use s_controller_interface::{LstState, WithdrawProtocolFeesMultiKeys};
+use s_controller_lib::{
+    try_lst_state_list, try_pool_state, withdraw_protocol_fees_multi_ix_full_for_prog,
+    WithdrawProtocolFeesMultiFreeArgs, WithdrawProtocolFeesMultiLstFreeArgs,
+    WithdrawProtocolFeesMultiLstKeys, WITHDRAW_PROTOCOL_FEES_MULTI_LST_COMPUTE_UNIT_CEIL,
+};
+use sanctum_associated_token_lib::FindAtaAddressArgs;
+use sanctum_token_lib::{token_account_balance, MintWithTokenProgram};
+use solana_readonly_account::keyed::Keyed;
+use solana_sdk::{
+    address_lookup_table_account::AddressLookupTableAccount,
+    compute_budget::ComputeBudgetInstruction, instruction::Instruction, pubkey::Pubkey,
+};
+use spl_associated_token_account::instruction::create_associated_token_account;
+
+use crate::{
+    common::{find_pool_pdas, verify_protocol_fee_beneficiary},
+    lut::{fetch_luts, ixs_fit_in_tx},
+    rpc::get_multiple_accounts_chunked,
+    tx::handle_tx,
+};
+
+use super::Subcmd;
+
+const CREATE_ATA_COMPUTE_UNIT_CEIL: u32 = 30_000;
+
+const MAX_COMPUTE_UNIT_LIMIT: u32 = 1_400_000;
+
+#[derive(Args, Debug)]
+#[command(
+    about = "Withdraw accumulated protocol fees of every LST in the pool",
+    long_about = "Withdraw the entire accumulated protocol fees of every LST in the pool to the beneficiary's associated token accounts, creating them if they don't exist.
+Packs as many LSTs as will fit into each WithdrawProtocolFeesMulti transaction, more if --lut is provided."
+)]
+pub struct WithdrawAllProtocolFeesArgs {
+    #[arg(
+        long,
+        short,
+        help = "The program's protocol fee beneficiary signer. Defaults to config wallet if not set."
+    )]
+    pub beneficiary: Option<String>,
+}
+
+/// A LST with protocol fees to withdraw
+struct LstWithdrawal {
+    lst_keys: WithdrawProtocolFeesMultiLstKeys,
+    amount: u64,
+    create_withdraw_to_ix: Option<Instruction>,
+}
+
+impl WithdrawAllProtocolFeesArgs {
+    pub async fn run(args: crate::Args) {
+        let Self { beneficiary } = match args.subcmd {
+            Subcmd::WithdrawAllProtocolFees(a) => a,
+            _ => unreachable!(),
+        };
+
+        let payer = args.config.signer();
+        let rpc = args.config.nonblocking_rpc_client();
+        let program_id = args.program;
+        let pdas = find_pool_pdas(program_id, args.pool);
+
+        let beneficiary_signer = beneficiary.map(|s| parse_signer_or_pubkey(&s).unwrap());
+        let beneficiary = beneficiary_signer.as_ref().unwrap_or(&payer);
+
+        let mut fetched_accs = rpc
+            .get_multiple_accounts(&[pdas.pool_state, pdas.lst_state_list])
+            .await
+            .unwrap();
+        let lst_state_list_acc = fetched_accs.pop().unwrap().unwrap();
+        let pool_state_acc = fetched_accs.pop().unwrap().unwrap();
+
+        let pool_state = try_pool_state(&pool_state_acc.data).unwrap();
+        verify_protocol_fee_beneficiary(pool_state, beneficiary.pubkey()).unwrap();
+
+        let keys = WithdrawProtocolFeesMultiFreeArgs {
+            pool_state: Keyed {
+                pubkey: pdas.pool_state,
+                account: &pool_state_acc,
+            },
+        }
+        .resolve_for_pool(pdas)
+        .unwrap();
+
+        let lst_state_list = try_lst_state_list(&lst_state_list_acc.data).unwrap();
+        let mints: Vec<Pubkey> = lst_state_list
+            .iter()
+            .map(|LstState { mint, .. }| *mint)
+            .collect();
+        let mint_accs = get_multiple_accounts_chunked(&rpc, &mints).await;
+        let all_lst_keys: Vec<WithdrawProtocolFeesMultiLstKeys> = mints
+            .iter()
+            .zip(mint_accs)
+            .map(|(mint, mint_acc)| {
+                let token_program = mint_acc.unwrap().owner;
+                WithdrawProtocolFeesMultiLstFreeArgs {
+                    lst_mint: MintWithTokenProgram {
+                        pubkey: *mint,
+                        token_program,
+                    },
+                    withdraw_to: FindAtaAddressArgs {
+                        wallet: beneficiary.pubkey(),
+                        mint: *mint,
+                        token_program,
+                    }
+                    .find_ata_address()
+                    .0,
+                }
+                .resolve_for_pool(pdas)
+            })
+            .collect();
+
+        // [accumulator0, withdraw_to0, accumulator1, withdraw_to1, ...]
+        let lst_token_accs: Vec<Pubkey> = all_lst_keys
+            .iter()
+            .flat_map(|k| [k.protocol_fee_accumulator, k.withdraw_to])
+            .collect();
+        let lst_token_accs = get_multiple_accounts_chunked(&rpc, &lst_token_accs).await;
+
+        let withdrawals: Vec<LstWithdrawal> = all_lst_keys
+            .into_iter()
+            .zip(lst_token_accs.chunks_exact(2))
+            .filter_map(|(lst_keys, accs)| {
+                let amount = token_account_balance(accs[0].as_ref().unwrap()).unwrap();
+                if amount == 0 {
+                    eprintln!(
+                        "No protocol fees accumulated for {}, skipping",
+                        lst_keys.lst_mint
+                    );
+                    return None;
+                }
+                let create_withdraw_to_ix = accs[1].is_none().then(|| {
+                    create_associated_token_account(
+                        &payer.pubkey(),
+                        &beneficiary.pubkey(),
+                        &lst_keys.lst_mint,
+                        &lst_keys.token_program,
+                    )
+                });
+                Some(LstWithdrawal {
+                    lst_keys,
+                    amount,
+                    create_withdraw_to_ix,
+                })
+            })
+            .collect();
+        if withdrawals.is_empty() {
+            eprintln!("No protocol fees to withdraw");
+            return;
+        }
+
+        let luts = fetch_luts(&rpc, args.lut).await;
+
+        let batches = pack_withdrawals(program_id, keys, payer.pubkey(), &withdrawals, &luts);
+
+        let mut signers = vec![payer.as_ref(), beneficiary.as_ref()];
+        signers.dedup();
+
+        // dyn Signer is not Sync, so just send the txes sequentially
+        for batch in batches {
+            // fetch blockhash on every iter to make sure it wont expire
+            let ixs = withdrawals_ixs(program_id, keys, batch);
+            let tx = args
+                .nonce
+                .build_tx(&rpc, &payer.pubkey(), &ixs, &luts, &signers)
+                .await;
+            handle_tx(&rpc, &tx, args.tx, pdas).await;
+        }
+    }
+}
+
+/// Greedily splits `withdrawals` into consecutive batches,
+/// each of which fits into a single transaction
+fn pack_withdrawals<'a>(
+    program_id: Pubkey,
+    keys: WithdrawProtocolFeesMultiKeys,
+    payer: Pubkey,
+    withdrawals: &'a [LstWithdrawal],
+    luts: &[AddressLookupTableAccount],
+) -> Vec<&'a [LstWithdrawal]> {
+    let mut res = vec![];
+    let mut start = 0;
+    for end in 1..=withdrawals.len() {
+        let candidate = &withdrawals[start..end];
+        if end - start > 1 && !batch_fits(program_id, keys, payer, candidate, luts) {
+            res.push(&withdrawals[start..end - 1]);
+            start = end - 1;
+        }
+    }
+    res.push(&withdrawals[start..]);
+    res
+}
+
+fn batch_fits(
+    program_id: Pubkey,
+    keys: WithdrawProtocolFeesMultiKeys,
+    payer: Pubkey,
+    batch: &[LstWithdrawal],
+    luts: &[AddressLookupTableAccount],
+) -> bool {
+    if compute_unit_limit(batch) > MAX_COMPUTE_UNIT_LIMIT {
+        return false;
+    }
+    ixs_fit_in_tx(payer, &withdrawals_ixs(program_id, keys, batch), luts)
+}
+
+fn compute_unit_limit(batch: &[LstWithdrawal]) -> u32 {
+    batch
+        .iter()
+        .map(
+            |LstWithdrawal {
+                 create_withdraw_to_ix,
+                 ..
+             }| {
+                WITHDRAW_PROTOCOL_FEES_MULTI_LST_COMPUTE_UNIT_CEIL
+                    + create_withdraw_to_ix
+                        .as_ref()
+                        .map_or(0, |_| CREATE_ATA_COMPUTE_UNIT_CEIL)
+            },
+        )
+        .sum()
+}
+
+fn withdrawals_ixs(
+    program_id: Pubkey,
+    keys: WithdrawProtocolFeesMultiKeys,
+    batch: &[LstWithdrawal],
+) -> Vec<Instruction> {
+    let mut ixs = vec![ComputeBudgetInstruction::set_compute_unit_limit(
+        compute_unit_limit(batch),
+    )];
+    ixs.extend(batch.iter().filter_map(|w| w.create_withdraw_to_ix.clone()));
+    let lst_keys: Vec<_> = batch.iter().map(|w| w.lst_keys).collect();
+    let amounts: Vec<_> = batch.iter().map(|w| w.amount).collect();
+    ixs.push(
+        withdraw_protocol_fees_multi_ix_full_for_prog(program_id, keys, &lst_keys, &amounts)
+            .unwrap(),
+    );
+    ixs
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_sdk::{
+        hash::Hash,
+        message::{v0::Message, VersionedMessage},
+        packet::PACKET_DATA_SIZE,
+        signature::Keypair,
+        signer::Signer,
+        transaction::VersionedTransaction,
+    };
+
+    use crate::lut::serialized_tx_len;
+
+    use super::*;
+
+    fn mock_withdrawals(n: usize) -> Vec<LstWithdrawal> {
+        (0..n)
+            .map(|_| LstWithdrawal {
+                lst_keys: WithdrawProtocolFeesMultiLstKeys {
+                    lst_mint: Pubkey::new_unique(),
+                    token_program: spl_token::ID,
+                    protocol_fee_accumulator: Pubkey::new_unique(),
+                    withdraw_to: Pubkey::new_unique(),
+                },
+                amount: 1,
+                create_withdraw_to_ix: None,
+            })
+            .collect()
+    }
+
+    fn mock_keys(beneficiary: Pubkey) -> WithdrawProtocolFeesMultiKeys {
+        WithdrawProtocolFeesMultiKeys {
+            protocol_fee_beneficiary: beneficiary,
+            protocol_fee_accumulator_auth: Pubkey::new_unique(),
+            pool_state: Pubkey::new_unique(),
+        }
+    }
+
+    fn assert_batches_fit(
+        withdrawals: &[LstWithdrawal],
+        batches: &[&[LstWithdrawal]],
+        keys: WithdrawProtocolFeesMultiKeys,
+        payer: &Keypair,
+        luts: &[AddressLookupTableAccount],
+    ) {
+        assert_eq!(
+            batches.iter().map(|b| b.len()).sum::<usize>(),
+            withdrawals.len()
+        );
+        for batch in batches {
+            let ixs = withdrawals_ixs(Pubkey::new_unique(), keys, batch);
+            let msg = VersionedMessage::V0(
+                Message::try_compile(&payer.pubkey(), &ixs, luts, Hash::default()).unwrap(),
+            );
+            let expected_len = serialized_tx_len(&msg);
+            let tx = VersionedTransaction::try_new(msg, &[payer]).unwrap();
+            let serlen = bincode::serialize(&tx).unwrap().len();
+            assert_eq!(serlen, expected_len);
+            assert!(serlen <= PACKET_DATA_SIZE);
+        }
+    }
+
+    #[test]
+    fn pack_withdrawals_fits_tx_size() {
+        let payer = Keypair::new();
+        let keys = mock_keys(payer.pubkey());
+        let withdrawals = mock_withdrawals(40);
+        let batches = pack_withdrawals(
+            Pubkey::new_unique(),
+            keys,
+            payer.pubkey(),
+            &withdrawals,
+            &[],
+        );
+        assert!(batches.len() > 1);
+        assert_batches_fit(&withdrawals, &batches, keys, &payer, &[]);
+    }
+
+    #[test]
+    fn pack_withdrawals_lut_fits_more_per_tx() {
+        let payer = Keypair::new();
+        let keys = mock_keys(payer.pubkey());
+        let withdrawals = mock_withdrawals(40);
+        let program_id = Pubkey::new_unique();
+        let luts = [AddressLookupTableAccount {
+            key: Pubkey::new_unique(),
+            addresses: withdrawals
+                .iter()
+                .flat_map(|w| {
+                    [
+                        w.lst_keys.lst_mint,
+                        w.lst_keys.protocol_fee_accumulator,
+                        w.lst_keys.withdraw_to,
+                    ]
+                })
+                .collect(),
+        }];
+        let no_lut_batches = pack_withdrawals(program_id, keys, payer.pubkey(), &withdrawals, &[]);
+        let lut_batches = pack_withdrawals(program_id, keys, payer.pubkey(), &withdrawals, &luts);
+        assert!(lut_batches.len() < no_lut_batches.len());
+        assert_batches_fit(&withdrawals, &lut_batches, keys, &payer, &luts);
+    }
+}
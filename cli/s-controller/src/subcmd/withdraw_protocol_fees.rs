@@ -2,26 +2,20 @@ use clap::{
     builder::{StringValueParser, TypedValueParser},
     Args,
 };
+use s_cli_utils::parse_signer_or_pubkey;
 use s_controller_interface::{
     withdraw_protocol_fees_ix_with_program_id, WithdrawProtocolFeesIxArgs,
 };
 use s_controller_lib::{
-    find_pool_state_address, find_protocol_fee_accumulator_address, find_protocol_fee_address,
-    try_pool_state, FindLstPdaAtaKeys, WithdrawProtocolFeesByMintFreeArgs,
-    WithdrawProtocolFeesPdas,
+    find_protocol_fee_accumulator_address_with_protocol_fee_id, try_pool_state, FindLstPdaAtaKeys,
+    WithdrawProtocolFeesByMintFreeArgs, WithdrawProtocolFeesPdas,
 };
 use sanctum_associated_token_lib::FindAtaAddressArgs;
-use sanctum_solana_cli_utils::{parse_signer, TxSendingNonblockingRpcClient};
 use sanctum_token_lib::{token_account_balance, MintWithTokenProgram};
-use solana_sdk::{
-    message::{v0::Message, VersionedMessage},
-    native_token::sol_to_lamports,
-    pubkey::Pubkey,
-    transaction::VersionedTransaction,
-};
+use solana_sdk::{native_token::sol_to_lamports, pubkey::Pubkey};
 use spl_associated_token_account::instruction::create_associated_token_account;
 
-use crate::lst_arg::LstArg;
+use crate::{common::find_pool_pdas, lst_arg::LstArg, lut::fetch_luts, tx::handle_tx};
 
 use super::Subcmd;
 
@@ -85,12 +79,13 @@ impl WithdrawProtocolFeesArgs {
         let payer = args.config.signer();
         let rpc = args.config.nonblocking_rpc_client();
         let program_id = args.program;
+        let pdas = find_pool_pdas(program_id, args.pool);
 
         let token_program = token_program.unwrap_or_else(|| {
             mint.token_program()
                 .expect("Unknown mint, token program must be provided")
         });
-        let beneficiary_signer = beneficiary.map(|s| parse_signer(&s).unwrap());
+        let beneficiary_signer = beneficiary.map(|s| parse_signer_or_pubkey(&s).unwrap());
         let beneficiary = beneficiary_signer.as_ref().unwrap_or(&payer);
         let beneficiary_ata = FindAtaAddressArgs {
             wallet: beneficiary.pubkey(),
@@ -122,12 +117,15 @@ impl WithdrawProtocolFeesArgs {
             },
         );
         let protocol_fee_accumulator_addr =
-            find_protocol_fee_accumulator_address(FindLstPdaAtaKeys {
-                lst_mint: mint.mint(),
-                token_program,
-            })
+            find_protocol_fee_accumulator_address_with_protocol_fee_id(
+                pdas.protocol_fee,
+                FindLstPdaAtaKeys {
+                    lst_mint: mint.mint(),
+                    token_program,
+                },
+            )
             .0;
-        let pool_state_addr = find_pool_state_address(program_id).0;
+        let pool_state_addr = pdas.pool_state;
 
         let mut fetched_accs = rpc
             .get_multiple_accounts(&[protocol_fee_accumulator_addr, pool_state_addr, withdraw_to])
@@ -191,7 +189,7 @@ impl WithdrawProtocolFeesArgs {
                 }
                 .resolve_with_pdas(WithdrawProtocolFeesPdas {
                     pool_state: pool_state_addr,
-                    protocol_fee_accumulator_auth: find_protocol_fee_address(program_id).0,
+                    protocol_fee_accumulator_auth: pdas.protocol_fee,
                     protocol_fee_accumulator: protocol_fee_accumulator_addr,
                 })
                 .unwrap(),
@@ -203,13 +201,12 @@ impl WithdrawProtocolFeesArgs {
         let mut signers = vec![payer.as_ref(), beneficiary.as_ref()];
         signers.dedup();
 
-        let rbh = rpc.get_latest_blockhash().await.unwrap();
-        let tx = VersionedTransaction::try_new(
-            VersionedMessage::V0(Message::try_compile(&payer.pubkey(), &ixs, &[], rbh).unwrap()),
-            &signers,
-        )
-        .unwrap();
+        let luts = fetch_luts(&rpc, args.lut).await;
+        let tx = args
+            .nonce
+            .build_tx(&rpc, &payer.pubkey(), &ixs, &luts, &signers)
+            .await;
 
-        rpc.handle_tx(&tx, args.send_mode).await;
+        handle_tx(&rpc, &tx, args.tx, pdas).await;
     }
 }
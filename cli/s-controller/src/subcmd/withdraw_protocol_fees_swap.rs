@@ -0,0 +1,227 @@
+use clap::{
+    builder::{StringValueParser, TypedValueParser},
+    Args,
+};
+use s_cli_utils::parse_signer_or_pubkey;
+use s_controller_lib::{
+    try_pool_state, withdraw_protocol_fees_swap_ix_full_for_prog, SrcDstLstIndexes,
+    SrcDstLstSolValueCalcAccountSuffixes, SrcDstLstSolValueCalcAccounts,
+    WithdrawProtocolFeesSwapByMintsFreeArgs, WithdrawProtocolFeesSwapIxFullArgs,
+};
+use sanctum_associated_token_lib::FindAtaAddressArgs;
+use sanctum_token_lib::token_account_balance;
+use solana_readonly_account::keyed::Keyed;
+use solana_sdk::{native_token::sol_to_lamports, pubkey::Pubkey};
+use spl_associated_token_account::instruction::create_associated_token_account;
+
+use crate::{
+    common::{find_pool_pdas, verify_protocol_fee_beneficiary},
+    lst_arg::LstArg,
+    lut::fetch_luts,
+    tx::handle_tx,
+};
+
+use super::Subcmd;
+
+#[derive(Args, Debug)]
+#[command(
+    about = "Withdraw accumulated protocol fees of one LST as another LST",
+    long_about = "Withdraw accumulated protocol fees of src LST, swapping them into dst LST of equal SOL value from the pool's reserves with no trading fees charged.
+Both LSTs must be on sanctum-lst-list."
+)]
+pub struct WithdrawProtocolFeesSwapArgs {
+    #[arg(
+        help = "Mint of the LST to withdraw protocol fees for. Can either be a pubkey or case-insensitive symbol of a token on sanctum-lst-list. e.g. 'bsol'",
+        value_parser = StringValueParser::new().try_map(|s| LstArg::parse_arg(&s)),
+    )]
+    pub src_mint: LstArg,
+
+    #[arg(
+        help = "Mint of the LST to receive the withdrawn protocol fees as. Can either be a pubkey or case-insensitive symbol of a token on sanctum-lst-list. e.g. 'jitosol'",
+        value_parser = StringValueParser::new().try_map(|s| LstArg::parse_arg(&s)),
+    )]
+    pub dst_mint: LstArg,
+
+    #[arg(
+        long,
+        short,
+        help = "The program's protocol fee beneficiary signer. Defaults to config wallet if not set."
+    )]
+    pub beneficiary: Option<String>,
+
+    #[arg(
+        long,
+        short,
+        help = "The dst LST token account to withdraw to. Defaults to associated token account of beneficiary, which is created if it does not exist, if not set."
+    )]
+    pub withdraw_to: Option<Pubkey>,
+
+    #[arg(
+        long,
+        short,
+        help = "Amount of src LST protocol fees to withdraw. Defaults to entire balance of the protocol fee accumulator account if not provided."
+    )]
+    pub amount: Option<f64>,
+
+    #[arg(
+        long,
+        short,
+        help = "Minimum amount of dst LST to receive. Defaults to 0 if not provided.",
+        default_value_t = 0.0
+    )]
+    pub min_amount_out: f64,
+}
+
+impl WithdrawProtocolFeesSwapArgs {
+    pub async fn run(args: crate::Args) {
+        let Self {
+            src_mint,
+            dst_mint,
+            beneficiary,
+            withdraw_to,
+            amount,
+            min_amount_out,
+        } = match args.subcmd {
+            Subcmd::WithdrawProtocolFeesSwap(a) => a,
+            _ => unreachable!(),
+        };
+
+        let payer = args.config.signer();
+        let rpc = args.config.nonblocking_rpc_client();
+        let program_id = args.program;
+        let pdas = find_pool_pdas(program_id, args.pool);
+
+        let (src_lst_calculator_accounts, dst_lst_calculator_accounts) = match (
+            src_mint.sol_value_calculator_accounts_of(),
+            dst_mint.sol_value_calculator_accounts_of(),
+        ) {
+            (Some(s), Some(d)) => (s, d),
+            _ => {
+                eprintln!("Both src and dst LSTs must be on sanctum-lst-list");
+                std::process::exit(-1);
+            }
+        };
+
+        let beneficiary_signer = beneficiary.map(|s| parse_signer_or_pubkey(&s).unwrap());
+        let beneficiary = beneficiary_signer.as_ref().unwrap_or(&payer);
+
+        let mut fetched_accs = rpc
+            .get_multiple_accounts(&[
+                pdas.pool_state,
+                pdas.lst_state_list,
+                src_mint.mint(),
+                dst_mint.mint(),
+            ])
+            .await
+            .unwrap();
+        let dst_mint_acc = fetched_accs.pop().unwrap().unwrap();
+        let src_mint_acc = fetched_accs.pop().unwrap().unwrap();
+        let lst_state_list_acc = fetched_accs.pop().unwrap().unwrap();
+        let pool_state_acc = fetched_accs.pop().unwrap().unwrap();
+
+        let pool_state = try_pool_state(&pool_state_acc.data).unwrap();
+        verify_protocol_fee_beneficiary(pool_state, beneficiary.pubkey()).unwrap();
+
+        let beneficiary_ata = FindAtaAddressArgs {
+            wallet: beneficiary.pubkey(),
+            mint: dst_mint.mint(),
+            token_program: dst_mint_acc.owner,
+        }
+        .find_ata_address()
+        .0;
+        let withdraw_to = withdraw_to.unwrap_or(beneficiary_ata);
+
+        let (
+            keys,
+            SrcDstLstIndexes {
+                src_lst_index,
+                dst_lst_index,
+            },
+            sol_val_calc_program_ids,
+        ) = WithdrawProtocolFeesSwapByMintsFreeArgs {
+            withdraw_to,
+            lst_state_list: Keyed {
+                pubkey: pdas.lst_state_list,
+                account: &lst_state_list_acc,
+            },
+            pool_state: Keyed {
+                pubkey: pdas.pool_state,
+                account: &pool_state_acc,
+            },
+            src_lst_mint: Keyed {
+                pubkey: src_mint.mint(),
+                account: &src_mint_acc,
+            },
+            dst_lst_mint: Keyed {
+                pubkey: dst_mint.mint(),
+                account: &dst_mint_acc,
+            },
+        }
+        .resolve_for_pool(pdas)
+        .unwrap();
+
+        let mut fetched_accs = rpc
+            .get_multiple_accounts(&[keys.protocol_fee_accumulator, withdraw_to])
+            .await
+            .unwrap();
+        let withdraw_to_opt = fetched_accs.pop().unwrap();
+        let protocol_fee_accumulator_acc = fetched_accs.pop().unwrap().unwrap();
+
+        let mut ixs = if withdraw_to_opt.is_none() {
+            if withdraw_to != beneficiary_ata {
+                eprintln!("Can only create withdraw_to token account if it's the associated token account of beneficiary");
+                return;
+            }
+            vec![create_associated_token_account(
+                &payer.pubkey(),
+                &beneficiary.pubkey(),
+                &dst_mint.mint(),
+                &dst_mint_acc.owner,
+            )]
+        } else {
+            vec![]
+        };
+
+        let amount = amount.map_or_else(
+            || token_account_balance(protocol_fee_accumulator_acc).unwrap(),
+            sol_to_lamports, // assume all LSTs are 9 d.p.
+        );
+        if amount == 0 {
+            eprintln!("No protocol fees to withdraw");
+            return;
+        }
+        let min_amount_out = sol_to_lamports(min_amount_out);
+
+        ixs.push(
+            withdraw_protocol_fees_swap_ix_full_for_prog(
+                program_id,
+                keys,
+                WithdrawProtocolFeesSwapIxFullArgs {
+                    src_lst_index,
+                    dst_lst_index,
+                    min_amount_out,
+                    amount,
+                },
+                SrcDstLstSolValueCalcAccounts::new(
+                    sol_val_calc_program_ids,
+                    SrcDstLstSolValueCalcAccountSuffixes {
+                        src_lst_calculator_accounts: &src_lst_calculator_accounts,
+                        dst_lst_calculator_accounts: &dst_lst_calculator_accounts,
+                    },
+                ),
+            )
+            .unwrap(),
+        );
+
+        let mut signers = vec![payer.as_ref(), beneficiary.as_ref()];
+        signers.dedup();
+
+        let luts = fetch_luts(&rpc, args.lut).await;
+        let tx = args
+            .nonce
+            .build_tx(&rpc, &payer.pubkey(), &ixs, &luts, &signers)
+            .await;
+
+        handle_tx(&rpc, &tx, args.tx, pdas).await;
+    }
+}
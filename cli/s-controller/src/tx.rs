@@ -0,0 +1,65 @@
+use clap::Args;
+use s_cli_utils::{handle_tx_or_export, TxEncoding, EXPORT_HELP, TX_SEND_MODE_HELP};
+use s_controller_lib::PoolPdas;
+use sanctum_solana_cli_utils::TxSendMode;
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_sdk::transaction::VersionedTransaction;
+
+use crate::simulate::{fetch_pool_accounts_data, simulate_tx, TxPreview};
+
+#[derive(Args, Clone, Copy, Debug)]
+pub struct TxArgs {
+    #[arg(
+        long,
+        short,
+        help = TX_SEND_MODE_HELP,
+        default_value_t = TxSendMode::default(),
+        value_enum,
+    )]
+    pub send_mode: TxSendMode,
+
+    #[arg(
+        long,
+        global = true,
+        help = "If flag set, simulates each transaction instead of handling it according to --send-mode, and outputs the changes it would make to the pool's PoolState and LstStateList, the compute units it would consume and its logs to stderr.
+Transactions are simulated independently of each other against the current state of the cluster.",
+        default_value_t = false
+    )]
+    pub simulate: bool,
+
+    #[arg(
+        long,
+        global = true,
+        help = EXPORT_HELP,
+        value_enum,
+        conflicts_with = "simulate"
+    )]
+    pub export: Option<TxEncoding>,
+}
+
+/// Simulates `tx` if `--simulate` is set, exports it if `--export` is set,
+/// else handles it according to `--send-mode`
+pub async fn handle_tx(
+    rpc: &RpcClient,
+    tx: &VersionedTransaction,
+    tx_args: TxArgs,
+    pdas: PoolPdas,
+) {
+    let TxArgs {
+        send_mode,
+        simulate,
+        export,
+    } = tx_args;
+    if simulate {
+        let pre = fetch_pool_accounts_data(rpc, pdas).await.unwrap();
+        match simulate_tx(rpc, tx, pdas).await {
+            Ok(outcome) => eprintln!("{}", TxPreview::new(&pre, &outcome)),
+            Err(e) => {
+                eprintln!("Failed to simulate transaction: {e}");
+                std::process::exit(-1);
+            }
+        }
+        return;
+    }
+    handle_tx_or_export(rpc, tx, send_mode, export).await;
+}
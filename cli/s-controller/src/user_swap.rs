@@ -0,0 +1,256 @@
+//! Quoting and swapping against the pool with [`SPoolJup`] for the user-facing
+//! swap, swap-exact-out, add-liquidity and remove-liquidity subcommands
+
+use clap::{value_parser, Args};
+use jupiter_amm_interface::{Quote, QuoteParams, SwapMode, SwapParams};
+use s_cli_utils::parse_signer_or_pubkey;
+use s_jup_interface::SPoolJup;
+use sanctum_associated_token_lib::FindAtaAddressArgs;
+use sanctum_token_ratio::BPS_DENOMINATOR;
+use solana_sdk::{native_token::lamports_to_sol, pubkey::Pubkey};
+use spl_associated_token_account::instruction::create_associated_token_account;
+
+use crate::{common::find_pool_pdas, lut::fetch_luts, rpc::fetch_s_pool, tx::handle_tx};
+
+#[derive(Args, Clone, Debug)]
+pub struct UserSwapArgs {
+    #[arg(
+        long,
+        help = "Max slippage from the quoted amount to allow, in bips. The transaction fails if the minimum amount out (exact in) or maximum amount in (exact out) after slippage cannot be met.",
+        default_value_t = 50,
+        value_parser = value_parser!(u16).range(..=i64::from(BPS_DENOMINATOR)),
+    )]
+    pub slippage_bps: u16,
+
+    #[arg(
+        long,
+        short,
+        help = "The owner of the token accounts to swap from and to. Defaults to config wallet if not set."
+    )]
+    pub authority: Option<String>,
+}
+
+/// A mint on either side of a [`UserSwap`]
+#[derive(Clone, Copy, Debug)]
+pub enum SwapMint {
+    Lst(Pubkey),
+    LpToken,
+}
+
+impl SwapMint {
+    pub fn resolve(self, s_pool: &SPoolJup) -> Pubkey {
+        match self {
+            Self::Lst(mint) => mint,
+            Self::LpToken => s_pool.lp_token_mint().unwrap(),
+        }
+    }
+}
+
+pub struct UserSwap<'a> {
+    pub input: SwapMint,
+    pub output: SwapMint,
+    /// Amount of input for [`SwapMode::ExactIn`], amount of output for [`SwapMode::ExactOut`]
+    pub amount: u64,
+    pub swap_mode: SwapMode,
+    pub args: &'a UserSwapArgs,
+}
+
+impl UserSwap<'_> {
+    /// Quotes the swap, outputs the quote to stderr,
+    /// then handles the swap transaction, creating the authority's
+    /// output associated token account if it does not exist.
+    ///
+    /// Tokens are transferred from and to the authority's associated token accounts.
+    pub async fn run(self, args: &crate::Args) {
+        let Self {
+            input,
+            output,
+            amount,
+            swap_mode,
+            args:
+                UserSwapArgs {
+                    slippage_bps,
+                    authority,
+                },
+        } = self;
+
+        let payer = args.config.signer();
+        let rpc = args.config.nonblocking_rpc_client();
+        let program_id = args.program;
+        let pdas = find_pool_pdas(program_id, args.pool);
+
+        let authority_signer = authority
+            .as_ref()
+            .map(|s| parse_signer_or_pubkey(s).unwrap());
+        let authority = authority_signer.as_ref().unwrap_or(&payer);
+
+        let s_pool = fetch_s_pool(&rpc, program_id, args.pool).await;
+        let input_mint = input.resolve(&s_pool);
+        let output_mint = output.resolve(&s_pool);
+
+        let quote = s_pool
+            .quote_full(&QuoteParams {
+                amount,
+                input_mint,
+                output_mint,
+                swap_mode,
+            })
+            .unwrap_or_else(|e| {
+                eprintln!("Failed to quote: {e}");
+                std::process::exit(-1);
+            });
+        if quote.not_enough_liquidity {
+            eprintln!("Not enough liquidity in the pool");
+            std::process::exit(-1);
+        }
+        let (in_amount, out_amount) = match swap_mode {
+            SwapMode::ExactIn => (
+                quote.in_amount,
+                min_amount_after_slippage(quote.out_amount, *slippage_bps),
+            ),
+            SwapMode::ExactOut => (
+                max_amount_after_slippage(quote.in_amount, *slippage_bps),
+                quote.out_amount,
+            ),
+        };
+        eprintln!("{}", QuoteDisplay { quote: &quote });
+        match swap_mode {
+            SwapMode::ExactIn => eprintln!("  Min out: {}", lamports_to_sol(out_amount)),
+            SwapMode::ExactOut => eprintln!("  Max in: {}", lamports_to_sol(in_amount)),
+        }
+
+        let mut fetched_accs = rpc
+            .get_multiple_accounts(&[input_mint, output_mint])
+            .await
+            .unwrap();
+        let output_token_program = fetched_accs.pop().unwrap().unwrap().owner;
+        let input_token_program = fetched_accs.pop().unwrap().unwrap().owner;
+        let [source_token_account, destination_token_account] = [
+            (input_mint, input_token_program),
+            (output_mint, output_token_program),
+        ]
+        .map(|(mint, token_program)| {
+            FindAtaAddressArgs {
+                wallet: authority.pubkey(),
+                mint,
+                token_program,
+            }
+            .find_ata_address()
+            .0
+        });
+
+        let mut fetched_accs = rpc
+            .get_multiple_accounts(&[source_token_account, destination_token_account])
+            .await
+            .unwrap();
+        let destination_token_account_opt = fetched_accs.pop().unwrap();
+        let source_token_account_opt = fetched_accs.pop().unwrap();
+        if source_token_account_opt.is_none() {
+            eprintln!("Token account {source_token_account} of {input_mint} does not exist");
+            std::process::exit(-1);
+        }
+        let mut ixs = if destination_token_account_opt.is_none() {
+            vec![create_associated_token_account(
+                &payer.pubkey(),
+                &authority.pubkey(),
+                &output_mint,
+                &output_token_program,
+            )]
+        } else {
+            vec![]
+        };
+
+        ixs.push(
+            s_pool
+                .swap_ix(
+                    &SwapParams {
+                        in_amount,
+                        out_amount,
+                        source_mint: input_mint,
+                        destination_mint: output_mint,
+                        source_token_account,
+                        destination_token_account,
+                        token_transfer_authority: authority.pubkey(),
+                        open_order_address: None,
+                        quote_mint_to_referrer: None,
+                        jupiter_program_id: &Pubkey::default(),
+                    },
+                    swap_mode,
+                )
+                .unwrap(),
+        );
+
+        let mut signers = vec![payer.as_ref(), authority.as_ref()];
+        signers.dedup();
+
+        let luts = fetch_luts(&rpc, args.lut).await;
+        let tx = args
+            .nonce
+            .build_tx(&rpc, &payer.pubkey(), &ixs, &luts, &signers)
+            .await;
+
+        handle_tx(&rpc, &tx, args.tx, pdas).await;
+    }
+}
+
+/// Human-readable [`Quote`], assuming all mints are 9 d.p.
+struct QuoteDisplay<'a> {
+    quote: &'a Quote,
+}
+
+impl std::fmt::Display for QuoteDisplay<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let Quote {
+            in_amount,
+            out_amount,
+            fee_amount,
+            fee_mint,
+            fee_pct,
+            ..
+        } = self.quote;
+        writeln!(f, "Quote:")?;
+        writeln!(f, "  In: {}", lamports_to_sol(*in_amount))?;
+        writeln!(f, "  Out: {}", lamports_to_sol(*out_amount))?;
+        write!(
+            f,
+            "  Fee: {} {fee_mint} ({fee_pct} of amount)",
+            lamports_to_sol(*fee_amount)
+        )
+    }
+}
+
+/// `amount` less `slippage_bps`, rounded down
+fn min_amount_after_slippage(amount: u64, slippage_bps: u16) -> u64 {
+    let bps = u128::from(BPS_DENOMINATOR - slippage_bps.min(BPS_DENOMINATOR));
+    // u64 * u16 / u16 cannot overflow u128 and result <= amount
+    (u128::from(amount) * bps / u128::from(BPS_DENOMINATOR)) as u64
+}
+
+/// `amount` plus `slippage_bps`, rounded up and capped at u64::MAX
+fn max_amount_after_slippage(amount: u64, slippage_bps: u16) -> u64 {
+    let bps = u128::from(BPS_DENOMINATOR) + u128::from(slippage_bps);
+    let res = (u128::from(amount) * bps).div_ceil(u128::from(BPS_DENOMINATOR));
+    u64::try_from(res).unwrap_or(u64::MAX)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn min_amount_after_slippage_cases() {
+        assert_eq!(min_amount_after_slippage(10_000, 0), 10_000);
+        assert_eq!(min_amount_after_slippage(10_000, 50), 9_950);
+        assert_eq!(min_amount_after_slippage(9_999, 1), 9_998);
+        assert_eq!(min_amount_after_slippage(10_000, BPS_DENOMINATOR), 0);
+        assert_eq!(min_amount_after_slippage(u64::MAX, 0), u64::MAX);
+    }
+
+    #[test]
+    fn max_amount_after_slippage_cases() {
+        assert_eq!(max_amount_after_slippage(10_000, 0), 10_000);
+        assert_eq!(max_amount_after_slippage(10_000, 50), 10_050);
+        assert_eq!(max_amount_after_slippage(9_999, 1), 10_000);
+        assert_eq!(max_amount_after_slippage(u64::MAX, 1), u64::MAX);
+    }
+}
@@ -49,7 +49,37 @@ pub trait TestSctrCmd {
 
     fn cmd_withdraw_protocol_fees(&mut self) -> &mut Self;
 
+    fn cmd_withdraw_all_protocol_fees(&mut self) -> &mut Self;
+
+    fn cmd_withdraw_protocol_fees_swap(&mut self) -> &mut Self;
+
     fn cmd_view(&mut self) -> &mut Self;
+
+    fn cmd_set_timelock_delay(&mut self) -> &mut Self;
+
+    fn cmd_queue_change(&mut self) -> &mut Self;
+
+    fn cmd_set_lst_cap(&mut self) -> &mut Self;
+
+    fn cmd_set_rebalance_loss_limit(&mut self) -> &mut Self;
+
+    fn cmd_sign(&mut self) -> &mut Self;
+
+    fn cmd_send_signed(&mut self) -> &mut Self;
+
+    fn cmd_swap(&mut self) -> &mut Self;
+
+    fn cmd_swap_exact_out(&mut self) -> &mut Self;
+
+    fn cmd_add_liquidity(&mut self) -> &mut Self;
+
+    fn cmd_remove_liquidity(&mut self) -> &mut Self;
+
+    fn cmd_create_lut(&mut self) -> &mut Self;
+
+    fn cmd_extend_lut(&mut self) -> &mut Self;
+
+    fn cmd_rebalance(&mut self) -> &mut Self;
 }
 
 impl TestSctrCmd for Command {
@@ -125,7 +155,67 @@ impl TestSctrCmd for Command {
         self.arg("withdraw-protocol-fees")
     }
 
+    fn cmd_withdraw_all_protocol_fees(&mut self) -> &mut Self {
+        self.arg("withdraw-all-protocol-fees")
+    }
+
+    fn cmd_withdraw_protocol_fees_swap(&mut self) -> &mut Self {
+        self.arg("withdraw-protocol-fees-swap")
+    }
+
     fn cmd_view(&mut self) -> &mut Self {
         self.arg("view")
     }
+
+    fn cmd_set_timelock_delay(&mut self) -> &mut Self {
+        self.arg("set-timelock-delay")
+    }
+
+    fn cmd_queue_change(&mut self) -> &mut Self {
+        self.arg("queue-change")
+    }
+
+    fn cmd_set_lst_cap(&mut self) -> &mut Self {
+        self.arg("set-lst-cap")
+    }
+
+    fn cmd_set_rebalance_loss_limit(&mut self) -> &mut Self {
+        self.arg("set-rebalance-loss-limit")
+    }
+
+    fn cmd_sign(&mut self) -> &mut Self {
+        self.arg("sign")
+    }
+
+    fn cmd_send_signed(&mut self) -> &mut Self {
+        self.arg("send-signed")
+    }
+
+    fn cmd_swap(&mut self) -> &mut Self {
+        self.arg("swap")
+    }
+
+    fn cmd_swap_exact_out(&mut self) -> &mut Self {
+        self.arg("swap-exact-out")
+    }
+
+    fn cmd_add_liquidity(&mut self) -> &mut Self {
+        self.arg("add-liquidity")
+    }
+
+    fn cmd_remove_liquidity(&mut self) -> &mut Self {
+        self.arg("remove-liquidity")
+    }
+
+    fn cmd_create_lut(&mut self) -> &mut Self {
+        self.arg("create-lut")
+    }
+
+    fn cmd_extend_lut(&mut self) -> &mut Self {
+        self.arg("extend-lut")
+    }
+
+    fn cmd_rebalance(&mut self) -> &mut Self {
+        self.arg("rebalance")
+    }
 }
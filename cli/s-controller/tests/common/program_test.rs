@@ -1,10 +1,16 @@
 use assert_cmd::Command;
+use sanctum_associated_token_lib::FindAtaAddressArgs;
 use sanctum_solana_test_utils::{
-    banks_rpc_server::BanksRpcServer, cli::TempCliConfig, test_fixtures_dir, ExtendedProgramTest,
+    banks_rpc_server::BanksRpcServer,
+    cli::TempCliConfig,
+    test_fixtures_dir,
+    token::{tokenkeg::TokenkegProgramTest, MockTokenAccountArgs},
+    ExtendedProgramTest,
 };
 use solana_program_test::{processor, BanksClient, ProgramTest, ProgramTestContext};
 use solana_sdk::{
     clock::Clock,
+    pubkey::Pubkey,
     signature::{read_keypair_file, Keypair},
     signer::Signer,
 };
@@ -16,6 +22,8 @@ pub trait SctrProgramTest {
     fn add_s_program(self) -> Self;
 
     fn add_flat_fee_pricing_program(self) -> Self;
+
+    fn add_tokenkeg_ata(self, wallet: Pubkey, mint: Pubkey, amount: u64) -> Self;
 }
 
 impl SctrProgramTest for ProgramTest {
@@ -36,6 +44,24 @@ impl SctrProgramTest for ProgramTest {
         );
         self
     }
+
+    fn add_tokenkeg_ata(self, wallet: Pubkey, mint: Pubkey, amount: u64) -> Self {
+        let ata = FindAtaAddressArgs {
+            wallet,
+            mint,
+            token_program: spl_token::ID,
+        }
+        .find_ata_address()
+        .0;
+        self.add_tokenkeg_account_from_args(
+            ata,
+            MockTokenAccountArgs {
+                mint,
+                authority: wallet,
+                amount,
+            },
+        )
+    }
 }
 
 pub async fn setup_with_init_auth_as_payer(
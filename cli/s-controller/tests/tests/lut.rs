@@ -0,0 +1,155 @@
+use std::{borrow::Cow, collections::HashSet, process::Output};
+
+use marinade_keys::msol;
+use s_controller_lib::{program::POOL_STATE_ID, Pool, PoolId};
+use s_controller_test_utils::{jito_marinade_no_fee_program_test, JitoMarinadeProgramTestArgs};
+use sanctum_solana_test_utils::ExtendedBanksClient;
+use solana_program_test::{BanksClient, ProgramTest};
+use solana_sdk::{
+    account::Account,
+    address_lookup_table::{
+        self,
+        state::{AddressLookupTable, LookupTableMeta},
+    },
+    pubkey::Pubkey,
+    rent::Rent,
+    signature::Keypair,
+    signer::Signer,
+};
+use test_utils::jitosol;
+
+use crate::common::{base_cmd, setup_with_payer, SctrProgramTest, TestSctrCmd};
+
+/// Same as the cli's
+const MAX_ADDRESSES_PER_EXTEND: usize = 20;
+
+fn jito_marinade_pt() -> ProgramTest {
+    jito_marinade_no_fee_program_test(JitoMarinadeProgramTestArgs {
+        jitosol_sol_value: 1_000_000_000,
+        jitosol_reserves: 1_000_000_000,
+        msol_sol_value: 1_000_000_000,
+        msol_reserves: 1_000_000_000,
+        // dont cares
+        jitosol_protocol_fee_accumulator: 0,
+        msol_protocol_fee_accumulator: 0,
+        lp_token_mint: Pubkey::new_unique(),
+        lp_token_supply: 0,
+    })
+    .add_s_program()
+}
+
+/// Executes the base64 encoded txs output by the cmd to stdout in sequence,
+/// asserting that they all succeed. Returns the number of txs and the cmd's stderr.
+async fn exec_all_b64_txs_success(bc: &mut BanksClient, output: Output) -> (usize, String) {
+    let Output {
+        stdout,
+        stderr,
+        status,
+    } = output;
+    assert!(status.success());
+    let stdout = String::from_utf8(stdout).unwrap();
+    let mut n_txs = 0;
+    for b64 in stdout.split('\n').filter(|s| !s.is_empty()) {
+        bc.exec_b64_tx(b64.as_bytes())
+            .await
+            .unwrap()
+            .result
+            .unwrap();
+        n_txs += 1;
+    }
+    (n_txs, String::from_utf8(stderr).unwrap())
+}
+
+async fn lut_addresses(bc: &mut BanksClient, lut: Pubkey) -> Vec<Pubkey> {
+    let lut_acc = bc.get_account(lut).await.unwrap().unwrap();
+    AddressLookupTable::deserialize(&lut_acc.data)
+        .unwrap()
+        .addresses
+        .to_vec()
+}
+
+fn assert_contains_pool_accounts(addresses: &[Pubkey]) {
+    let unique: HashSet<_> = addresses.iter().collect();
+    assert_eq!(unique.len(), addresses.len());
+    let pdas = Pool::find_for_prog(s_controller_lib::program::ID, PoolId::Default).pdas;
+    for expected in [
+        s_controller_lib::program::ID,
+        pdas.pool_state,
+        pdas.lst_state_list,
+        pdas.protocol_fee,
+        jitosol::ID,
+        msol::ID,
+        spl_token::ID,
+    ] {
+        assert!(addresses.contains(&expected));
+    }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn create_lut_success() {
+    let payer = Keypair::new();
+    let (mut cmd, _cfg, mut bc, _payer) = setup_with_payer(jito_marinade_pt(), payer).await;
+
+    cmd.cmd_create_lut();
+    let (n_txs, stderr) = exec_all_b64_txs_success(&mut bc, cmd.output().unwrap()).await;
+
+    // "Lookup table {lut} created with {n} addresses. ..."
+    let lut: Pubkey = stderr
+        .split_whitespace()
+        .skip_while(|w| *w != "table")
+        .nth(1)
+        .unwrap()
+        .parse()
+        .unwrap();
+    let addresses = lut_addresses(&mut bc, lut).await;
+    assert_contains_pool_accounts(&addresses);
+    // needs more than 1 extend
+    assert!(addresses.len() > MAX_ADDRESSES_PER_EXTEND);
+    // first extend is in the same tx as the create
+    assert_eq!(n_txs, addresses.len().div_ceil(MAX_ADDRESSES_PER_EXTEND));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn extend_lut_adds_only_missing() {
+    let payer = Keypair::new();
+    let lut = Pubkey::new_unique();
+    let lut_data = AddressLookupTable {
+        meta: LookupTableMeta {
+            authority: Some(payer.pubkey()),
+            ..Default::default()
+        },
+        addresses: Cow::Owned(vec![POOL_STATE_ID]),
+    }
+    .serialize_for_tests()
+    .unwrap();
+    let mut pt = jito_marinade_pt();
+    pt.add_account(
+        lut,
+        Account {
+            lamports: Rent::default().minimum_balance(lut_data.len()),
+            data: lut_data,
+            owner: address_lookup_table::program::ID,
+            executable: false,
+            rent_epoch: u64::MAX,
+        },
+    );
+    let (mut cmd, cfg, mut bc, _payer) = setup_with_payer(pt, payer).await;
+
+    cmd.cmd_extend_lut().arg(lut.to_string());
+    let (n_txs, _stderr) = exec_all_b64_txs_success(&mut bc, cmd.output().unwrap()).await;
+
+    let addresses = lut_addresses(&mut bc, lut).await;
+    assert_eq!(addresses[0], POOL_STATE_ID);
+    assert_contains_pool_accounts(&addresses);
+    assert_eq!(
+        n_txs,
+        (addresses.len() - 1).div_ceil(MAX_ADDRESSES_PER_EXTEND)
+    );
+
+    // nothing left to add
+    let mut cmd = base_cmd(&cfg);
+    cmd.cmd_extend_lut().arg(lut.to_string());
+    let (n_txs, _stderr) = exec_all_b64_txs_success(&mut bc, cmd.output().unwrap()).await;
+    assert_eq!(n_txs, 0);
+    assert_eq!(lut_addresses(&mut bc, lut).await, addresses);
+}
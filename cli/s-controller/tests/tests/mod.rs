@@ -5,15 +5,25 @@ mod disable_pool;
 mod enable_lst_input;
 mod enable_pool;
 mod init;
+mod lut;
+mod offline_tx;
+mod queue_change;
+mod rebalance;
 mod remove_disable_auth;
 mod remove_lst;
 mod set_admin;
+mod set_lst_cap;
 mod set_pricing_prog;
 mod set_protocol_fee;
 mod set_protocol_fee_beneficiary;
 mod set_rebalance_auth;
+mod set_rebalance_loss_limit;
 mod set_sol_value_calculator;
+mod set_timelock_delay;
+mod swap;
 mod sync;
 mod sync_all;
 mod view;
+mod withdraw_all_protocol_fees;
 mod withdraw_protocol_fees;
+mod withdraw_protocol_fees_swap;
@@ -0,0 +1,174 @@
+use std::process::Output;
+
+use cli_test_utils::{assert_all_txs_success_nonempty, TestCliCmd};
+use s_cli_utils::{missing_signers, TxEncoding};
+use s_controller_test_utils::{assert_admin, PoolStateProgramTest, DEFAULT_POOL_STATE};
+use sanctum_solana_test_utils::{cli::TempCliConfig, test_fixtures_dir};
+use solana_program_test::{BanksClient, ProgramTest};
+use solana_sdk::{
+    account::Account,
+    hash::Hash,
+    nonce::state::{Data, DurableNonce, State, Versions},
+    pubkey::Pubkey,
+    signature::{read_keypair_file, Keypair},
+    signer::Signer,
+    system_program,
+};
+
+use crate::common::{base_cmd, setup_with_payer, SctrProgramTest, TestSctrCmd};
+
+const INITIAL_AUTHORITY_KEY_FILE: &str = "s-controller-test-initial-authority-key.json";
+
+/// Sets up a pool whose admin keypair is not the payer's
+async fn setup_pool_with_offline_admin() -> (TempCliConfig, BanksClient, Pubkey) {
+    let pt = ProgramTest::default()
+        .add_s_program()
+        .add_pool_state(DEFAULT_POOL_STATE);
+    let admin = read_keypair_file(test_fixtures_dir().join(INITIAL_AUTHORITY_KEY_FILE))
+        .unwrap()
+        .pubkey();
+    let (_cmd, cfg, bc, _payer) = setup_with_payer(pt, Keypair::new()).await;
+    (cfg, bc, admin)
+}
+
+fn export_set_admin(cfg: &TempCliConfig, admin: Pubkey, new_admin: Pubkey) -> String {
+    let Output { stdout, status, .. } = base_cmd(cfg)
+        .cmd_set_admin()
+        .arg("--curr-admin")
+        .arg(admin.to_string())
+        .arg(new_admin.to_string())
+        .arg("--export")
+        .arg("base64")
+        .output()
+        .unwrap();
+    assert!(status.success());
+    String::from_utf8(stdout).unwrap().trim().to_owned()
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn export_then_sign() {
+    let new_admin = Pubkey::new_unique();
+    let (cfg, mut bc, admin) = setup_pool_with_offline_admin().await;
+
+    let exported = export_set_admin(&cfg, admin, new_admin);
+    let tx = TxEncoding::Base64.decode_tx(&exported).unwrap();
+    assert_eq!(missing_signers(&tx), vec![admin]);
+
+    let mut cmd = base_cmd(&cfg);
+    cmd.cmd_sign()
+        .arg("--signer")
+        .arg(test_fixtures_dir().join(INITIAL_AUTHORITY_KEY_FILE))
+        .arg(exported);
+    let exec_res = cmd.exec_b64_txs(&mut bc).await;
+    assert_all_txs_success_nonempty(&exec_res);
+    assert_admin(&mut bc, new_admin).await;
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn export_then_combine_and_send_signed() {
+    let new_admin = Pubkey::new_unique();
+    let (cfg, mut bc, admin) = setup_pool_with_offline_admin().await;
+
+    let exported = export_set_admin(&cfg, admin, new_admin);
+    let Output { stdout, status, .. } = base_cmd(&cfg)
+        .cmd_sign()
+        .arg("--signer")
+        .arg(test_fixtures_dir().join(INITIAL_AUTHORITY_KEY_FILE))
+        .arg(&exported)
+        .output()
+        .unwrap();
+    assert!(status.success());
+    let signed = String::from_utf8(stdout).unwrap().trim().to_owned();
+
+    let mut cmd = base_cmd(&cfg);
+    cmd.cmd_send_signed().arg(exported).arg(signed);
+    let exec_res = cmd.exec_b64_txs(&mut bc).await;
+    assert_all_txs_success_nonempty(&exec_res);
+    assert_admin(&mut bc, new_admin).await;
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn export_with_nonce_then_sign() {
+    let new_admin = Pubkey::new_unique();
+    let payer = Keypair::new();
+    let nonce = Pubkey::new_unique();
+    let durable_nonce = DurableNonce::from_blockhash(&Hash::new_unique());
+    let mut pt = ProgramTest::default()
+        .add_s_program()
+        .add_pool_state(DEFAULT_POOL_STATE);
+    pt.add_account(
+        nonce,
+        Account {
+            lamports: 1_000_000_000,
+            data: bincode::serialize(&Versions::new(State::Initialized(Data::new(
+                payer.pubkey(),
+                durable_nonce,
+                5000,
+            ))))
+            .unwrap(),
+            owner: system_program::ID,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+    let admin = read_keypair_file(test_fixtures_dir().join(INITIAL_AUTHORITY_KEY_FILE))
+        .unwrap()
+        .pubkey();
+    let (_cmd, cfg, mut bc, _payer) = setup_with_payer(pt, payer).await;
+
+    let Output { stdout, status, .. } = base_cmd(&cfg)
+        .cmd_set_admin()
+        .arg("--curr-admin")
+        .arg(admin.to_string())
+        .arg(new_admin.to_string())
+        .arg("--export")
+        .arg("base64")
+        .arg("--nonce")
+        .arg(nonce.to_string())
+        .output()
+        .unwrap();
+    assert!(status.success());
+    let exported = String::from_utf8(stdout).unwrap().trim().to_owned();
+    let tx = TxEncoding::Base64.decode_tx(&exported).unwrap();
+    assert_eq!(tx.message.recent_blockhash(), durable_nonce.as_hash());
+    let advance_nonce_ix = &tx.message.instructions()[0];
+    assert_eq!(
+        tx.message.static_account_keys()[usize::from(advance_nonce_ix.program_id_index)],
+        system_program::ID
+    );
+    assert_eq!(missing_signers(&tx), vec![admin]);
+
+    let mut cmd = base_cmd(&cfg);
+    cmd.cmd_sign()
+        .arg("--signer")
+        .arg(test_fixtures_dir().join(INITIAL_AUTHORITY_KEY_FILE))
+        .arg(exported);
+    let exec_res = cmd.exec_b64_txs(&mut bc).await;
+    assert_all_txs_success_nonempty(&exec_res);
+    assert_admin(&mut bc, new_admin).await;
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn send_signed_fails_if_missing_signatures() {
+    let (cfg, _bc, admin) = setup_pool_with_offline_admin().await;
+
+    let exported = export_set_admin(&cfg, admin, Pubkey::new_unique());
+    base_cmd(&cfg)
+        .cmd_send_signed()
+        .arg(exported)
+        .assert()
+        .failure();
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn pubkey_signer_without_export_fails() {
+    let (cfg, _bc, admin) = setup_pool_with_offline_admin().await;
+
+    base_cmd(&cfg)
+        .cmd_set_admin()
+        .arg("--curr-admin")
+        .arg(admin.to_string())
+        .arg(Pubkey::new_unique().to_string())
+        .assert()
+        .failure();
+}
@@ -0,0 +1,37 @@
+use cli_test_utils::{assert_all_txs_success_nonempty, TestCliCmd};
+use s_controller_interface::PoolState;
+use s_controller_lib::{program::PENDING_CHANGE_LIST_ID, try_pending_change_list, AdminChange};
+use s_controller_test_utils::{PoolStateProgramTest, DEFAULT_POOL_STATE};
+use sanctum_solana_test_utils::ExtendedBanksClient;
+use solana_program_test::ProgramTest;
+use solana_sdk::pubkey::Pubkey;
+
+use crate::common::{setup_with_init_auth_as_payer, SctrProgramTest, TestSctrCmd};
+
+#[tokio::test(flavor = "multi_thread")]
+async fn queue_change_set_admin_success_payer_admin() {
+    let new_admin = Pubkey::new_unique();
+
+    let pt = ProgramTest::default()
+        .add_s_program()
+        .add_pool_state(PoolState {
+            timelock_delay_hours: 24,
+            ..DEFAULT_POOL_STATE
+        });
+
+    let (mut cmd, _cfg, mut bc, _mock_auth_kp) = setup_with_init_auth_as_payer(pt).await;
+
+    cmd.cmd_queue_change()
+        .arg("set-admin")
+        .arg(new_admin.to_string());
+    let exec_res = cmd.exec_b64_txs(&mut bc).await;
+    assert_all_txs_success_nonempty(&exec_res);
+
+    let pending_change_list_acc = bc.get_account_unwrapped(PENDING_CHANGE_LIST_ID).await;
+    let pending_change_list = try_pending_change_list(&pending_change_list_acc.data).unwrap();
+    assert_eq!(pending_change_list.len(), 1);
+    assert_eq!(
+        AdminChange::try_from(&pending_change_list[0]).unwrap(),
+        AdminChange::SetAdmin { new_admin }
+    );
+}
@@ -0,0 +1,152 @@
+use cli_test_utils::{assert_all_txs_success_nonempty, TestCliCmd};
+use s_controller_interface::RebalanceLossState;
+use s_controller_lib::{
+    find_pool_reserves_address, program::REBALANCE_RECORD_ID, try_pool_state, FindLstPdaAtaKeys,
+    U8Bool,
+};
+use s_controller_test_utils::{
+    AddSplProgramTest, LpTokenProgramTest, LstStateListBanksClient, LstStateListProgramTest,
+    MockLstStateArgs, PoolStateBanksClient, PoolStateProgramTest, RebalanceLossStateProgramTest,
+    DEFAULT_POOL_STATE,
+};
+use sanctum_solana_test_utils::{
+    token::{tokenkeg::TokenkegProgramTest, MockMintArgs, MockTokenAccountArgs},
+    KeyedUiAccount,
+};
+use sanctum_token_lib::token_account_balance;
+use solana_program_test::{processor, BanksClient, ProgramTest};
+use solana_sdk::pubkey::Pubkey;
+use spl_calculator_lib::deserialize_stake_pool_checked;
+use spl_token::native_mint;
+use test_utils::jitosol;
+
+use crate::common::{setup_with_init_auth_as_payer, SctrProgramTest, TestSctrCmd};
+
+const WSOL_RESERVES: u64 = 10_000_000_000;
+
+const JITOSOL_RESERVES: u64 = 1_000_000_000;
+
+const JITOSOL_SOL_VALUE: u64 = 1_072_326_756;
+
+const REBALANCE_AMT: u64 = 500_000_000;
+
+const MAX_LOSS_BPS: u16 = 100;
+
+/// Pool of wSOL and jitoSOL whose rebalance authority is the test initial authority
+fn jito_wsol_program_test() -> ProgramTest {
+    let mut pt = ProgramTest::default();
+    pt.add_program(
+        "wsol_calculator",
+        wsol_calculator_lib::program::ID,
+        processor!(wsol_calculator::process_instruction),
+    );
+    let jito_stake_pool = KeyedUiAccount::from_test_fixtures_file("jito-stake-pool.json")
+        .to_keyed_account()
+        .account;
+    let jito_stake_pool = deserialize_stake_pool_checked(&jito_stake_pool).unwrap();
+    let lp_token_mint = Pubkey::new_unique();
+    let pt = pt
+        .add_spl_progs()
+        .add_jito_stake_pool()
+        // DepositSol checks that the manager fee account is a token account of the LST
+        .add_tokenkeg_account_from_args(
+            jito_stake_pool.manager_fee_account,
+            MockTokenAccountArgs {
+                mint: jitosol::ID,
+                authority: Pubkey::new_unique(),
+                amount: 0,
+            },
+        )
+        .add_tokenkeg_mint_from_args(
+            native_mint::ID,
+            MockMintArgs {
+                mint_authority: None,
+                freeze_authority: None,
+                supply: 0,
+                decimals: 9,
+            },
+        )
+        .add_mock_lst_states(&[
+            MockLstStateArgs {
+                mint: native_mint::ID,
+                sol_value: WSOL_RESERVES,
+                reserves_amt: WSOL_RESERVES,
+                protocol_fee_accumulator_amt: 0,
+                token_program: spl_token::ID,
+                sol_value_calculator: wsol_calculator_lib::program::ID,
+                is_input_disabled: false,
+            },
+            MockLstStateArgs {
+                mint: jitosol::ID,
+                sol_value: JITOSOL_SOL_VALUE,
+                reserves_amt: JITOSOL_RESERVES,
+                protocol_fee_accumulator_amt: 0,
+                token_program: spl_token::ID,
+                sol_value_calculator: spl_calculator_lib::program::ID,
+                is_input_disabled: false,
+            },
+        ])
+        .add_mock_lp_mint(lp_token_mint, 0)
+        // allow for the rounding loss of depositing into the stake pool
+        .add_rebalance_loss_state(RebalanceLossState {
+            max_loss_bps_per_rebalance: MAX_LOSS_BPS,
+            max_loss_bps_per_epoch: MAX_LOSS_BPS,
+            padding: Default::default(),
+            epoch: 0,
+            epoch_loss: 0,
+            epoch_start_total_sol_value: 0,
+        })
+        .add_s_program();
+    let mut pool_state = DEFAULT_POOL_STATE;
+    pool_state.total_sol_value = WSOL_RESERVES + JITOSOL_SOL_VALUE;
+    pool_state.lp_token_mint = lp_token_mint;
+    pt.add_pool_state(pool_state)
+}
+
+async fn pool_reserves_balance(bc: &mut BanksClient, mint: Pubkey) -> u64 {
+    let reserves = find_pool_reserves_address(FindLstPdaAtaKeys {
+        lst_mint: mint,
+        token_program: spl_token::ID,
+    })
+    .0;
+    let acc = bc.get_account(reserves).await.unwrap().unwrap();
+    token_account_balance(&acc).unwrap()
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn rebalance_wsol_to_jitosol_builtin_route() {
+    let (mut cmd, _cfg, mut bc, _mock_auth_kp) =
+        setup_with_init_auth_as_payer(jito_wsol_program_test()).await;
+
+    cmd.cmd_rebalance()
+        .arg(native_mint::ID.to_string())
+        .arg(jitosol::ID.to_string())
+        .arg("0.5");
+    let exec_res = cmd.exec_b64_txs(&mut bc).await;
+    assert_all_txs_success_nonempty(&exec_res);
+
+    assert_eq!(
+        pool_reserves_balance(&mut bc, native_mint::ID).await,
+        WSOL_RESERVES - REBALANCE_AMT
+    );
+    let jitosol_reserves = pool_reserves_balance(&mut bc, jitosol::ID).await;
+    assert!(jitosol_reserves > JITOSOL_RESERVES);
+
+    let wsol_lst_state = bc.get_lst_state(native_mint::ID).await;
+    let jitosol_lst_state = bc.get_lst_state(jitosol::ID).await;
+    assert_eq!(wsol_lst_state.sol_value, WSOL_RESERVES - REBALANCE_AMT);
+    let pool_state_acc = bc.get_pool_state_acc().await;
+    let pool_state = try_pool_state(&pool_state_acc.data).unwrap();
+    assert!(U8Bool(pool_state.is_rebalancing).is_false());
+    assert_eq!(
+        pool_state.total_sol_value,
+        wsol_lst_state.sol_value + jitosol_lst_state.sol_value
+    );
+    let old_total_sol_value = WSOL_RESERVES + JITOSOL_SOL_VALUE;
+    assert!(pool_state.total_sol_value <= old_total_sol_value);
+    assert!(
+        old_total_sol_value - pool_state.total_sol_value
+            <= old_total_sol_value * u64::from(MAX_LOSS_BPS) / 10_000
+    );
+    assert!(bc.get_account(REBALANCE_RECORD_ID).await.unwrap().is_none());
+}
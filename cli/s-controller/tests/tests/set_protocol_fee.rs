@@ -88,3 +88,28 @@ async fn set_protocol_fee_failure_no_fee_payer_init_auth() {
 
     cmd.cmd_set_protocol_fee().assert().failure();
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn set_protocol_fee_simulate_does_not_send() {
+    const NEW_TRADING_PROTOCOL_FEE_BPS: u16 = 420;
+
+    let pt = ProgramTest::default()
+        .add_s_program()
+        .add_pool_state(DEFAULT_POOL_STATE);
+
+    let (mut cmd, _cfg, mut bc, _mock_auth_kp) = setup_with_init_auth_as_payer(pt).await;
+
+    let output = cmd
+        .cmd_set_protocol_fee()
+        .arg("--trading-fee")
+        .arg(NEW_TRADING_PROTOCOL_FEE_BPS.to_string())
+        .arg("--simulate")
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    assert!(output.stdout.is_empty());
+    assert!(String::from_utf8(output.stderr)
+        .unwrap()
+        .contains("Simulation succeeded"));
+    assert_protocol_fee(&mut bc, Default::default(), Default::default()).await;
+}
@@ -0,0 +1,34 @@
+use cli_test_utils::{assert_all_txs_success_nonempty, TestCliCmd};
+use s_controller_lib::{program::REBALANCE_LOSS_STATE_ID, try_rebalance_loss_state};
+use s_controller_test_utils::{PoolStateProgramTest, DEFAULT_POOL_STATE};
+use sanctum_solana_test_utils::ExtendedBanksClient;
+use solana_program_test::ProgramTest;
+
+use crate::common::{setup_with_init_auth_as_payer, SctrProgramTest, TestSctrCmd};
+
+#[tokio::test(flavor = "multi_thread")]
+async fn set_rebalance_loss_limit_success_payer_admin() {
+    const PER_REBALANCE_BPS: u16 = 5;
+    const PER_EPOCH_BPS: u16 = 20;
+
+    let pt = ProgramTest::default()
+        .add_s_program()
+        .add_pool_state(DEFAULT_POOL_STATE);
+
+    let (mut cmd, _cfg, mut bc, _mock_auth_kp) = setup_with_init_auth_as_payer(pt).await;
+
+    cmd.cmd_set_rebalance_loss_limit()
+        .arg(PER_REBALANCE_BPS.to_string())
+        .arg(PER_EPOCH_BPS.to_string());
+    let exec_res = cmd.exec_b64_txs(&mut bc).await;
+    assert_all_txs_success_nonempty(&exec_res);
+
+    let rebalance_loss_state_acc = bc.get_account_unwrapped(REBALANCE_LOSS_STATE_ID).await;
+    let rebalance_loss_state = try_rebalance_loss_state(&rebalance_loss_state_acc.data).unwrap();
+    assert_eq!(
+        rebalance_loss_state.max_loss_bps_per_rebalance,
+        PER_REBALANCE_BPS
+    );
+    assert_eq!(rebalance_loss_state.max_loss_bps_per_epoch, PER_EPOCH_BPS);
+    assert_eq!(rebalance_loss_state.epoch_loss, 0);
+}
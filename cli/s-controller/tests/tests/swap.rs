@@ -0,0 +1,159 @@
+use cli_test_utils::{assert_all_txs_success_nonempty, TestCliCmd};
+use flat_fee_test_utils::MockFeeAccountArgs;
+use marinade_keys::msol;
+use s_controller_test_utils::{
+    jito_marinade_flat_fee_program_test, JitoMarinadeProgramTestArgs, MockProtocolFeeBps,
+};
+use sanctum_associated_token_lib::FindAtaAddressArgs;
+use sanctum_token_lib::{token_account_balance, token_account_mint};
+use solana_program_test::{BanksClient, ProgramTest};
+use solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer};
+use test_utils::jitosol;
+
+use crate::common::{setup_with_payer, SctrProgramTest, TestSctrCmd};
+
+const RESERVES: u64 = 10_000_000_000;
+
+const AMT: u64 = 1_000_000_000;
+
+fn flat_fee_program_test(lp_token_mint: Pubkey, lp_token_supply: u64) -> ProgramTest {
+    jito_marinade_flat_fee_program_test(
+        JitoMarinadeProgramTestArgs {
+            jitosol_sol_value: RESERVES,
+            msol_sol_value: RESERVES,
+            jitosol_reserves: RESERVES,
+            msol_reserves: RESERVES,
+            jitosol_protocol_fee_accumulator: 0,
+            msol_protocol_fee_accumulator: 0,
+            lp_token_mint,
+            lp_token_supply,
+        },
+        flat_fee_interface::ProgramState {
+            manager: Pubkey::default(),
+            lp_withdrawal_fee_bps: 20,
+        },
+        [
+            MockFeeAccountArgs {
+                input_fee_bps: 4,
+                output_fee_bps: 4,
+                lst_mint: jitosol::ID,
+            },
+            MockFeeAccountArgs {
+                input_fee_bps: 4,
+                output_fee_bps: 4,
+                lst_mint: msol::ID,
+            },
+        ],
+        MockProtocolFeeBps {
+            trading: 1000,
+            lp: 1000,
+        },
+    )
+    .add_s_program()
+}
+
+async fn ata_balance(bc: &mut BanksClient, wallet: Pubkey, mint: Pubkey) -> u64 {
+    let ata = FindAtaAddressArgs {
+        wallet,
+        mint,
+        token_program: spl_token::ID,
+    }
+    .find_ata_address()
+    .0;
+    let acc = bc.get_account(ata).await.unwrap().unwrap();
+    assert_eq!(token_account_mint(&acc).unwrap(), mint);
+    token_account_balance(&acc).unwrap()
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn swap_jitosol_to_msol_create_ata() {
+    let payer = Keypair::new();
+    let pt = flat_fee_program_test(Pubkey::new_unique(), 0).add_tokenkeg_ata(
+        payer.pubkey(),
+        jitosol::ID,
+        AMT,
+    );
+    let (mut cmd, _cfg, mut bc, payer) = setup_with_payer(pt, payer).await;
+
+    cmd.cmd_swap().arg("jitosol").arg("msol").arg("1");
+    let exec_res = cmd.exec_b64_txs(&mut bc).await;
+    assert_all_txs_success_nonempty(&exec_res);
+
+    assert_eq!(ata_balance(&mut bc, payer.pubkey(), jitosol::ID).await, 0);
+    assert!(ata_balance(&mut bc, payer.pubkey(), msol::ID).await > 0);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn swap_exact_out_jitosol_to_msol() {
+    const JITOSOL_BALANCE: u64 = 2 * AMT;
+
+    let payer = Keypair::new();
+    let pt = flat_fee_program_test(Pubkey::new_unique(), 0)
+        .add_tokenkeg_ata(payer.pubkey(), jitosol::ID, JITOSOL_BALANCE)
+        .add_tokenkeg_ata(payer.pubkey(), msol::ID, 0);
+    let (mut cmd, _cfg, mut bc, payer) = setup_with_payer(pt, payer).await;
+
+    cmd.cmd_swap_exact_out().arg("jitosol").arg("msol").arg("1");
+    let exec_res = cmd.exec_b64_txs(&mut bc).await;
+    assert_all_txs_success_nonempty(&exec_res);
+
+    assert_eq!(ata_balance(&mut bc, payer.pubkey(), msol::ID).await, AMT);
+    assert!(ata_balance(&mut bc, payer.pubkey(), jitosol::ID).await < JITOSOL_BALANCE);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn add_liquidity_jitosol_create_ata() {
+    let payer = Keypair::new();
+    let lp_token_mint = Pubkey::new_unique();
+    let pt = flat_fee_program_test(lp_token_mint, RESERVES).add_tokenkeg_ata(
+        payer.pubkey(),
+        jitosol::ID,
+        AMT,
+    );
+    let (mut cmd, _cfg, mut bc, payer) = setup_with_payer(pt, payer).await;
+
+    cmd.cmd_add_liquidity().arg("jitosol").arg("1");
+    let exec_res = cmd.exec_b64_txs(&mut bc).await;
+    assert_all_txs_success_nonempty(&exec_res);
+
+    assert_eq!(ata_balance(&mut bc, payer.pubkey(), jitosol::ID).await, 0);
+    assert!(ata_balance(&mut bc, payer.pubkey(), lp_token_mint).await > 0);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn remove_liquidity_jitosol_create_ata() {
+    let payer = Keypair::new();
+    let lp_token_mint = Pubkey::new_unique();
+    let pt = flat_fee_program_test(lp_token_mint, RESERVES).add_tokenkeg_ata(
+        payer.pubkey(),
+        lp_token_mint,
+        AMT,
+    );
+    let (mut cmd, _cfg, mut bc, payer) = setup_with_payer(pt, payer).await;
+
+    cmd.cmd_remove_liquidity().arg("jitosol").arg("1");
+    let exec_res = cmd.exec_b64_txs(&mut bc).await;
+    assert_all_txs_success_nonempty(&exec_res);
+
+    assert_eq!(ata_balance(&mut bc, payer.pubkey(), lp_token_mint).await, 0);
+    assert!(ata_balance(&mut bc, payer.pubkey(), jitosol::ID).await > 0);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn swap_slippage_bps_out_of_range_fails() {
+    let payer = Keypair::new();
+    let pt = flat_fee_program_test(Pubkey::new_unique(), 0).add_tokenkeg_ata(
+        payer.pubkey(),
+        jitosol::ID,
+        AMT,
+    );
+    let (mut cmd, _cfg, _bc, _payer) = setup_with_payer(pt, payer).await;
+
+    cmd.cmd_swap()
+        .arg("jitosol")
+        .arg("msol")
+        .arg("1")
+        .arg("--slippage-bps")
+        .arg("10001");
+    cmd.assert().failure();
+}
@@ -1,23 +1,25 @@
 use std::process::Output;
 
 use s_controller_test_utils::{jito_marinade_no_fee_program_test, JitoMarinadeProgramTestArgs};
+use serde_json::Value;
 use solana_sdk::pubkey::Pubkey;
 
 use crate::common::{setup_with_init_auth_as_payer, SctrProgramTest, TestSctrCmd};
 
+const ARGS: JitoMarinadeProgramTestArgs = JitoMarinadeProgramTestArgs {
+    jitosol_sol_value: 999_000_000,
+    jitosol_reserves: 900_000_000,
+    msol_sol_value: 111_000_000,
+    msol_reserves: 100_000_000,
+    jitosol_protocol_fee_accumulator: 123_321,
+    msol_protocol_fee_accumulator: 321_123,
+    lp_token_mint: Pubkey::new_from_array([1; 32]),
+    lp_token_supply: 156_234,
+};
+
 #[tokio::test(flavor = "multi_thread")]
 async fn view_basic() {
-    let pt = jito_marinade_no_fee_program_test(JitoMarinadeProgramTestArgs {
-        jitosol_sol_value: 999_000_000,
-        jitosol_reserves: 900_000_000,
-        msol_sol_value: 111_000_000,
-        msol_reserves: 100_000_000,
-        jitosol_protocol_fee_accumulator: 123_321,
-        msol_protocol_fee_accumulator: 321_123,
-        lp_token_mint: Pubkey::new_unique(),
-        lp_token_supply: 156_234,
-    })
-    .add_s_program();
+    let pt = jito_marinade_no_fee_program_test(ARGS).add_s_program();
 
     let (mut cmd, _cfg, _bc, _mock_auth_kp) = setup_with_init_auth_as_payer(pt).await;
 
@@ -26,3 +28,92 @@ async fn view_basic() {
     assert!(status.success());
     eprintln!("{}", std::str::from_utf8(&stdout).unwrap());
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn view_json() {
+    let pt = jito_marinade_no_fee_program_test(ARGS).add_s_program();
+
+    let (mut cmd, _cfg, _bc, _mock_auth_kp) = setup_with_init_auth_as_payer(pt).await;
+
+    cmd.cmd_view().arg("--output").arg("json");
+    let Output { stdout, status, .. } = cmd.output().unwrap();
+    assert!(status.success());
+    let view: Value = serde_json::from_slice(&stdout).unwrap();
+
+    assert_eq!(view["lp_token_supply"], ARGS.lp_token_supply);
+    assert_eq!(
+        view["pool_state"]["total_sol_value"],
+        ARGS.jitosol_sol_value + ARGS.msol_sol_value
+    );
+    assert_eq!(
+        view["pool_state"]["lp_token_mint"],
+        ARGS.lp_token_mint.to_string()
+    );
+    let lst_state_list = view["lst_state_list"].as_array().unwrap();
+    assert_eq!(lst_state_list.len(), 2);
+    for (sol_value, reserves, protocol_fee_accumulator) in [
+        (
+            ARGS.jitosol_sol_value,
+            ARGS.jitosol_reserves,
+            ARGS.jitosol_protocol_fee_accumulator,
+        ),
+        (
+            ARGS.msol_sol_value,
+            ARGS.msol_reserves,
+            ARGS.msol_protocol_fee_accumulator,
+        ),
+    ] {
+        let lst = lst_state_list
+            .iter()
+            .find(|lst| lst["sol_value"] == sol_value)
+            .unwrap();
+        assert_eq!(lst["reserves_balance"], reserves);
+        assert_eq!(
+            lst["protocol_fee_accumulator_balance"],
+            protocol_fee_accumulator
+        );
+    }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn view_csv() {
+    let pt = jito_marinade_no_fee_program_test(ARGS).add_s_program();
+
+    let (mut cmd, _cfg, _bc, _mock_auth_kp) = setup_with_init_auth_as_payer(pt).await;
+
+    cmd.cmd_view().arg("--output").arg("csv");
+    let Output { stdout, status, .. } = cmd.output().unwrap();
+    assert!(status.success());
+    let stdout = String::from_utf8(stdout).unwrap();
+    let mut lines = stdout.lines();
+    assert_eq!(
+        lines.next().unwrap(),
+        "symbol,mint,is_input_disabled,sol_value,sol_value_calculator,max_share_bps,reserves_address,reserves_balance,protocol_fee_accumulator_address,protocol_fee_accumulator_balance"
+    );
+    let rows: Vec<Vec<&str>> = lines.map(|l| l.split(',').collect()).collect();
+    assert_eq!(rows.len(), 2);
+    assert!(rows
+        .iter()
+        .any(|row| row[3] == ARGS.jitosol_sol_value.to_string()
+            && row[7] == ARGS.jitosol_reserves.to_string()
+            && row[9] == ARGS.jitosol_protocol_fee_accumulator.to_string()));
+    assert!(rows
+        .iter()
+        .any(|row| row[3] == ARGS.msol_sol_value.to_string()
+            && row[7] == ARGS.msol_reserves.to_string()
+            && row[9] == ARGS.msol_protocol_fee_accumulator.to_string()));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn view_raw_conflicts_with_output() {
+    let pt = jito_marinade_no_fee_program_test(ARGS).add_s_program();
+
+    let (mut cmd, _cfg, _bc, _mock_auth_kp) = setup_with_init_auth_as_payer(pt).await;
+
+    cmd.cmd_view()
+        .arg("--raw")
+        .arg("--output")
+        .arg("json")
+        .assert()
+        .failure();
+}
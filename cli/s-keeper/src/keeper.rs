@@ -0,0 +1,322 @@
+use std::{collections::HashMap, error::Error};
+
+use s_controller_lib::{
+    sync_sol_value_ix_full_for_prog, try_lst_state_list, PoolPdas, SyncSolValueByMintFreeArgs,
+    SyncSolValuePdas,
+};
+use s_sol_val_calc_prog_aggregate::{LstSolValCalc, MutableLstSolValCalc};
+use sanctum_token_lib::MintWithTokenProgram;
+use solana_client::{client_error::ClientError, nonblocking::rpc_client::RpcClient};
+use solana_sdk::{
+    account::Account,
+    instruction::Instruction,
+    message::{v0::Message, VersionedMessage},
+    pubkey::Pubkey,
+    signer::Signer,
+    transaction::VersionedTransaction,
+};
+
+use crate::{lst::TrackedLst, retry::Backoff};
+
+/// Max number of accounts a single getMultipleAccounts RPC call can fetch
+const MAX_MULTIPLE_ACCOUNTS: usize = 100;
+
+pub struct Keeper {
+    pub rpc: RpcClient,
+    pub payer: Box<dyn Signer>,
+    pub program_id: Pubkey,
+    pub pdas: PoolPdas,
+    pub backoff: Backoff,
+    /// If set, simulates sync transactions instead of sending them
+    pub dry_run: bool,
+    pub lsts: Vec<TrackedLst>,
+    /// Mints of LSTs in the pool that cannot be synced by the keeper
+    pub unsupported: Vec<Pubkey>,
+    pub last_epoch: Option<u64>,
+    /// Mints of LSTs waiting for their stake pool to be updated for the current epoch
+    pub waiting: Vec<Pubkey>,
+}
+
+impl Keeper {
+    /// Syncs the SOL value of every LST in the pool whose
+    /// stake pool has been updated for the current epoch
+    /// but has not been synced yet this epoch
+    pub async fn tick(&mut self) -> Result<(), Box<dyn Error>> {
+        let epoch = self
+            .backoff
+            .retry("getEpochInfo", || self.rpc.get_epoch_info())
+            .await?
+            .epoch;
+        let lst_state_list_acc = self
+            .backoff
+            .retry("Fetch LstStateList", || {
+                self.rpc.get_account(&self.pdas.lst_state_list)
+            })
+            .await?;
+        self.refresh_lsts(&lst_state_list_acc)?;
+
+        if self.last_epoch != Some(epoch) {
+            self.last_epoch = Some(epoch);
+            eprintln!(
+                "Epoch {epoch}: {} LSTs to sync",
+                self.lsts.iter().filter(|l| l.needs_sync(epoch)).count()
+            );
+        }
+
+        let pending: Vec<usize> = (0..self.lsts.len())
+            .filter(|i| self.lsts[*i].needs_sync(epoch))
+            .collect();
+        if pending.is_empty() {
+            return Ok(());
+        }
+        self.update_calcs(&pending).await?;
+
+        let mut waiting = vec![];
+        for i in pending {
+            let lst = &self.lsts[i];
+            let symbol = &lst.sanctum_lst.symbol;
+            if !lst.is_stake_pool_updated() {
+                waiting.push(lst.sanctum_lst.mint);
+                continue;
+            }
+            let ix = self.sync_ix(lst, &lst_state_list_acc)?;
+            match self
+                .backoff
+                .retry(&format!("Sync {symbol}"), || self.send_sync(&ix))
+                .await
+            {
+                Ok(()) => self.lsts[i].last_synced_epoch = Some(epoch),
+                Err(e) => eprintln!("Giving up syncing {symbol} for now: {e}"),
+            }
+        }
+        if waiting != self.waiting {
+            if !waiting.is_empty() {
+                eprintln!(
+                    "Waiting for stake pools of {} to be updated for epoch {epoch}",
+                    self.symbols_of(&waiting)
+                );
+            }
+            self.waiting = waiting;
+        }
+        Ok(())
+    }
+
+    /// Tracks LSTs newly added to the pool and stops tracking removed ones
+    fn refresh_lsts(&mut self, lst_state_list_acc: &Account) -> Result<(), Box<dyn Error>> {
+        let lst_state_list = try_lst_state_list(&lst_state_list_acc.data)?;
+        self.lsts.retain(|lst| {
+            lst_state_list
+                .iter()
+                .any(|lst_state| lst_state.mint == lst.sanctum_lst.mint)
+        });
+        for lst_state in lst_state_list {
+            if self.unsupported.contains(&lst_state.mint)
+                || self
+                    .lsts
+                    .iter()
+                    .any(|lst| lst.sanctum_lst.mint == lst_state.mint)
+            {
+                continue;
+            }
+            match TrackedLst::try_new(lst_state, self.pdas.pool_state) {
+                Some(lst) => {
+                    eprintln!("Tracking {}", lst.sanctum_lst.symbol);
+                    self.lsts.push(lst);
+                }
+                None => {
+                    eprintln!(
+                        "{} not on sanctum-lst-list or uses an unknown SOL value calculator, skipping",
+                        lst_state.mint
+                    );
+                    self.unsupported.push(lst_state.mint);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Fetches the accounts required to compute the SOL value of the LSTs at `indices`
+    async fn update_calcs(&mut self, indices: &[usize]) -> Result<(), Box<dyn Error>> {
+        let mut pubkeys: Vec<Pubkey> = indices
+            .iter()
+            .flat_map(|i| self.lsts[*i].calc.get_accounts_to_update())
+            .collect();
+        pubkeys.sort();
+        pubkeys.dedup();
+
+        let mut accounts = HashMap::new();
+        for chunk in pubkeys.chunks(MAX_MULTIPLE_ACCOUNTS) {
+            let fetched = self
+                .backoff
+                .retry("getMultipleAccounts", || {
+                    self.rpc.get_multiple_accounts(chunk)
+                })
+                .await?;
+            accounts.extend(
+                chunk
+                    .iter()
+                    .zip(fetched)
+                    .filter_map(|(pk, acc)| acc.map(|acc| (*pk, acc))),
+            );
+        }
+        for i in indices {
+            let lst = &mut self.lsts[*i];
+            if let Err(e) = lst.calc.update(&accounts) {
+                eprintln!(
+                    "Failed to update SOL value calculator of {}: {e}",
+                    lst.sanctum_lst.symbol
+                );
+            }
+        }
+        Ok(())
+    }
+
+    fn sync_ix(
+        &self,
+        lst: &TrackedLst,
+        lst_state_list_acc: &Account,
+    ) -> Result<Instruction, Box<dyn Error>> {
+        let (keys, index, sol_value_calculator_program_id) = SyncSolValueByMintFreeArgs {
+            lst_state_list: lst_state_list_acc,
+            lst_mint: MintWithTokenProgram {
+                pubkey: lst.sanctum_lst.mint,
+                token_program: lst.sanctum_lst.token_program,
+            },
+        }
+        .resolve_with_pdas(SyncSolValuePdas {
+            pool_state: self.pdas.pool_state,
+            lst_state_list: self.pdas.lst_state_list,
+        })?;
+        Ok(sync_sol_value_ix_full_for_prog(
+            self.program_id,
+            keys,
+            index,
+            &lst.calc.ix_accounts(),
+            sol_value_calculator_program_id,
+        )?)
+    }
+
+    /// In dry run mode, errors if the simulation fails
+    async fn send_sync(&self, ix: &Instruction) -> Result<(), Box<dyn Error>> {
+        let rbh = self.rpc.get_latest_blockhash().await?;
+        let tx = VersionedTransaction::try_new(
+            VersionedMessage::V0(Message::try_compile(
+                &self.payer.pubkey(),
+                &[ix.clone()],
+                &[],
+                rbh,
+            )?),
+            &[self.payer.as_ref()],
+        )?;
+        if self.dry_run {
+            let sim = self.rpc.simulate_transaction(&tx).await?.value;
+            if let Some(err) = sim.err {
+                eprintln!("Dry run: simulation failed. Logs: {:#?}", sim.logs);
+                return Err(ClientError::from(err).into());
+            }
+            eprintln!("Dry run: simulation succeeded");
+            return Ok(());
+        }
+        let sig = self.rpc.send_and_confirm_transaction(&tx).await?;
+        eprintln!("{sig}");
+        Ok(())
+    }
+
+    fn symbols_of(&self, mints: &[Pubkey]) -> String {
+        self.lsts
+            .iter()
+            .filter(|lst| mints.contains(&lst.sanctum_lst.mint))
+            .map(|lst| lst.sanctum_lst.symbol.as_str())
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use s_controller_lib::{Pool, PoolId};
+    use s_controller_test_utils::{
+        jito_marinade_no_fee_program_test, JitoMarinadeProgramTestArgs, LstStateListBanksClient,
+    };
+    use sanctum_solana_test_utils::banks_rpc_server::BanksRpcServer;
+    use solana_program_test::{processor, ProgramTestContext};
+    use solana_sdk::{clock::Clock, transaction::Transaction};
+    use test_utils::{jitosol, JITO_STAKE_POOL_LAST_UPDATE_EPOCH};
+
+    use super::*;
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn sync_jitosol() {
+        const EXPECTED_NEW_JITOSOL_SOL_VALUE: u64 = 1_072_326_756;
+
+        let mut program_test = jito_marinade_no_fee_program_test(JitoMarinadeProgramTestArgs {
+            jitosol_sol_value: 1_000_000_000,
+            jitosol_reserves: 1_000_000_000,
+            msol_sol_value: 1_000_000_000,
+            msol_reserves: 1_000_000_000,
+            // dont cares
+            jitosol_protocol_fee_accumulator: 0,
+            msol_protocol_fee_accumulator: 0,
+            lp_token_mint: Pubkey::new_unique(),
+            lp_token_supply: 0,
+        });
+        program_test.add_program(
+            "s_controller",
+            s_controller_lib::program::ID,
+            processor!(s_controller::entrypoint::process_instruction),
+        );
+        let ctx = program_test.start_with_context().await;
+        ctx.set_sysvar(&Clock {
+            epoch: JITO_STAKE_POOL_LAST_UPDATE_EPOCH,
+            ..Default::default()
+        });
+        let ProgramTestContext {
+            mut banks_client,
+            last_blockhash,
+            payer,
+            ..
+        } = ctx;
+        let (port, _jh) = BanksRpcServer::spawn_random_unused(banks_client.clone()).await;
+
+        let mut keeper = Keeper {
+            rpc: RpcClient::new(format!("http://127.0.0.1:{port}")),
+            payer: Box::new(payer),
+            program_id: s_controller_lib::program::ID,
+            pdas: Pool::find_for_prog(s_controller_lib::program::ID, PoolId::Default).pdas,
+            backoff: Backoff {
+                max_retries: 0,
+                initial_delay: Duration::ZERO,
+                max_delay: Duration::ZERO,
+            },
+            dry_run: false,
+            lsts: vec![],
+            unsupported: vec![],
+            last_epoch: None,
+            waiting: vec![],
+        };
+
+        let lst_state_list_acc = banks_client.get_lst_state_list_acc().await;
+        keeper.refresh_lsts(&lst_state_list_acc).unwrap();
+        assert!(keeper.unsupported.is_empty());
+        assert_eq!(keeper.lsts.len(), 2);
+
+        let i = keeper
+            .lsts
+            .iter()
+            .position(|lst| lst.sanctum_lst.mint == jitosol::ID)
+            .unwrap();
+        keeper.update_calcs(&[i]).await.unwrap();
+        let lst = &keeper.lsts[i];
+        assert!(lst.is_stake_pool_updated());
+
+        let ix = keeper.sync_ix(lst, &lst_state_list_acc).unwrap();
+        let mut tx = Transaction::new_with_payer(&[ix], Some(&keeper.payer.pubkey()));
+        tx.sign(&[keeper.payer.as_ref()], last_blockhash);
+        banks_client.process_transaction(tx).await.unwrap();
+
+        let jitosol_lst_state = banks_client.get_lst_state(jitosol::ID).await;
+        assert_eq!(jitosol_lst_state.sol_value, EXPECTED_NEW_JITOSOL_SOL_VALUE);
+    }
+}
@@ -0,0 +1,67 @@
+use s_cli_utils::{find_sanctum_lst_by_mint, SANCTUM_LST_LIST};
+use s_controller_interface::LstState;
+use s_jup_interface::{utils::try_lst_data, LstData};
+use s_sol_val_calc_prog_aggregate::{KnownLstSolValCalc, LstSolValCalc};
+use sanctum_lst_list::SanctumLst;
+use solana_sdk::{native_token::LAMPORTS_PER_SOL, pubkey::Pubkey};
+
+/// A LST in the pool that the keeper syncs
+#[derive(Debug, Clone)]
+pub struct TrackedLst {
+    pub sanctum_lst: &'static SanctumLst,
+    pub calc: KnownLstSolValCalc,
+
+    /// The last epoch this LST's SOL value was synced in
+    pub last_synced_epoch: Option<u64>,
+}
+
+impl TrackedLst {
+    /// Returns None if the LST is not on sanctum-lst-list or its sol value calculator program
+    /// does not match the one sanctum-lst-list says it should use.
+    ///
+    /// `pool_state` is the pool state of the pool the keeper is syncing.
+    pub fn try_new(lst_state: &LstState, pool_state: Pubkey) -> Option<Self> {
+        let sanctum_lst = find_sanctum_lst_by_mint(lst_state.mint)?;
+        let LstData { sol_val_calc, .. } =
+            try_lst_data(&SANCTUM_LST_LIST.sanctum_lst_list, lst_state, pool_state)?;
+        Some(Self {
+            sanctum_lst,
+            calc: sol_val_calc,
+            last_synced_epoch: None,
+        })
+    }
+
+    pub fn needs_sync(&self, epoch: u64) -> bool {
+        self.last_synced_epoch.map_or(true, |e| e < epoch)
+    }
+
+    /// Whether the LST's stake pool has been updated for the current epoch,
+    /// so that syncing now captures this epoch's staking rewards.
+    ///
+    /// The calculators refuse to compute SOL value if the stake pool's
+    /// `last_update_epoch` is behind the current epoch, so this just checks
+    /// if a SOL value can be computed with the last fetched accounts.
+    pub fn is_stake_pool_updated(&self) -> bool {
+        self.calc.lst_to_sol(LAMPORTS_PER_SOL).is_ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use s_sol_val_calc_prog_aggregate::WsolLstSolValCalc;
+
+    use super::*;
+
+    #[test]
+    fn needs_sync_once_per_epoch() {
+        let mut lst = TrackedLst {
+            sanctum_lst: &SANCTUM_LST_LIST.sanctum_lst_list[0],
+            calc: KnownLstSolValCalc::Wsol(WsolLstSolValCalc),
+            last_synced_epoch: None,
+        };
+        assert!(lst.needs_sync(0));
+        lst.last_synced_epoch = Some(5);
+        assert!(!lst.needs_sync(5));
+        assert!(lst.needs_sync(6));
+    }
+}
@@ -0,0 +1,145 @@
+use std::{str::FromStr, time::Duration};
+
+use clap::{
+    builder::{StringValueParser, TypedValueParser, ValueParser},
+    Parser,
+};
+use keeper::Keeper;
+use retry::Backoff;
+use s_cli_utils::CONFIG_HELP;
+use s_controller_lib::{Pool, PoolId};
+use sanctum_solana_cli_utils::ConfigWrapper;
+use solana_sdk::pubkey::Pubkey;
+use tokio::runtime::Runtime;
+
+mod keeper;
+mod lst;
+mod retry;
+
+#[derive(Parser, Debug)]
+#[command(
+    author,
+    version,
+    about = "Keeper that syncs the SOL value of every LST in the S pool every epoch",
+    long_about = "Long-running keeper that syncs the SOL value of every LST in the S pool every epoch.
+Watches the current epoch and syncs each LST as soon as its stake pool has been updated for the epoch, so that the pool does not trade at stale SOL values after an epoch boundary.
+Only LSTs on sanctum-lst-list are supported."
+)]
+pub struct Args {
+    #[arg(
+        long,
+        short,
+        help = CONFIG_HELP,
+        default_value = "",
+        value_parser = ValueParser::new(ConfigWrapper::parse_from_path)
+    )]
+    pub config: ConfigWrapper,
+
+    #[arg(
+        long,
+        short,
+        help = "program ID of the S controller program",
+        default_value_t = s_controller_lib::program::ID,
+        value_parser = StringValueParser::new().try_map(|s| Pubkey::from_str(&s)),
+    )]
+    pub program: Pubkey,
+
+    #[arg(
+        long,
+        help = "LP token mint of the pool to keep. Defaults to the program's default pool if not set.",
+        value_parser = StringValueParser::new().try_map(|s| Pubkey::from_str(&s)),
+    )]
+    pub pool: Option<Pubkey>,
+
+    #[arg(
+        long,
+        help = "Seconds to wait between checking for LSTs to sync",
+        default_value_t = 30
+    )]
+    pub poll_interval_secs: u64,
+
+    #[arg(
+        long,
+        help = "Number of times to retry a failed RPC call or sync transaction before giving up until the next poll",
+        default_value_t = 5
+    )]
+    pub max_retries: u32,
+
+    #[arg(
+        long,
+        help = "Milliseconds to wait before the first retry. Doubles every subsequent retry.",
+        default_value_t = 500
+    )]
+    pub initial_backoff_ms: u64,
+
+    #[arg(
+        long,
+        help = "Max milliseconds to wait between retries",
+        default_value_t = 30_000
+    )]
+    pub max_backoff_ms: u64,
+
+    #[arg(
+        long,
+        help = "If flag set, simulates sync transactions and logs the results instead of sending them",
+        default_value_t = false
+    )]
+    pub dry_run: bool,
+
+    #[arg(
+        long,
+        help = "If flag set, checks for LSTs to sync once and exits instead of running forever",
+        default_value_t = false
+    )]
+    pub once: bool,
+}
+
+fn main() {
+    let args = Args::parse();
+    let rt = Runtime::new().unwrap();
+    rt.block_on(run(args));
+}
+
+async fn run(
+    Args {
+        config,
+        program,
+        pool,
+        poll_interval_secs,
+        max_retries,
+        initial_backoff_ms,
+        max_backoff_ms,
+        dry_run,
+        once,
+    }: Args,
+) {
+    let pool_id = pool.map_or(PoolId::Default, PoolId::Keyed);
+    let mut keeper = Keeper {
+        rpc: config.nonblocking_rpc_client(),
+        payer: config.signer(),
+        program_id: program,
+        pdas: Pool::find_for_prog(program, pool_id).pdas,
+        backoff: Backoff {
+            max_retries,
+            initial_delay: Duration::from_millis(initial_backoff_ms),
+            max_delay: Duration::from_millis(max_backoff_ms),
+        },
+        dry_run,
+        lsts: vec![],
+        unsupported: vec![],
+        last_epoch: None,
+        waiting: vec![],
+    };
+    if dry_run {
+        eprintln!("Dry run: sync transactions will only be simulated");
+    }
+    loop {
+        if let Err(e) = keeper.tick().await {
+            eprintln!("Error: {e}");
+        }
+        if once {
+            return;
+        }
+        tokio::time::sleep(Duration::from_secs(poll_interval_secs)).await;
+    }
+}
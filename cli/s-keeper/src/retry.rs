@@ -0,0 +1,63 @@
+use std::{future::Future, time::Duration};
+
+/// Retry policy with exponential backoff
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Backoff {
+    /// Number of retries after the first failed attempt
+    pub max_retries: u32,
+    pub initial_delay: Duration,
+    pub max_delay: Duration,
+}
+
+impl Backoff {
+    /// Delay to wait before the `retry`th retry, 0-indexed.
+    /// Doubles every retry, capped at `max_delay`
+    pub fn delay(&self, retry: u32) -> Duration {
+        self.initial_delay
+            .checked_mul(2u32.saturating_pow(retry))
+            .map_or(self.max_delay, |d| d.min(self.max_delay))
+    }
+
+    /// Runs `f` until it succeeds or `max_retries` retries have failed,
+    /// returning the last error in the latter case
+    pub async fn retry<T, E: std::fmt::Display, Fut: Future<Output = Result<T, E>>>(
+        &self,
+        label: &str,
+        mut f: impl FnMut() -> Fut,
+    ) -> Result<T, E> {
+        let mut retry = 0;
+        loop {
+            match f().await {
+                Ok(res) => return Ok(res),
+                Err(e) => {
+                    if retry >= self.max_retries {
+                        return Err(e);
+                    }
+                    let delay = self.delay(retry);
+                    eprintln!("{label} failed: {e}. Retrying in {delay:?}");
+                    tokio::time::sleep(delay).await;
+                    retry += 1;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn delay_doubles_and_caps() {
+        let backoff = Backoff {
+            max_retries: 10,
+            initial_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(3),
+        };
+        assert_eq!(backoff.delay(0), Duration::from_millis(500));
+        assert_eq!(backoff.delay(1), Duration::from_secs(1));
+        assert_eq!(backoff.delay(2), Duration::from_secs(2));
+        assert_eq!(backoff.delay(3), Duration::from_secs(3));
+        assert_eq!(backoff.delay(u32::MAX), Duration::from_secs(3));
+    }
+}
@@ -0,0 +1,51 @@
+use solana_program::{
+    decode_error::DecodeError,
+    msg,
+    program_error::{PrintProgramError, ProgramError},
+};
+use thiserror::Error;
+#[derive(Clone, Copy, Debug, Eq, Error, num_derive::FromPrimitive, PartialEq)]
+pub enum TargetWeightError {
+    #[error("Invalid program state data")]
+    InvalidProgramStateData = 0,
+    #[error("Incorrect program state account")]
+    IncorrectProgramState = 1,
+    #[error("WeightAccount is not initialized for the given LST mint")]
+    UnsupportedLstMint = 2,
+    #[error("Given signed fee value is out of bound")]
+    SignedFeeOutOfBound = 3,
+    #[error("Given unsigned fee value is out of bound")]
+    UnsignedFeeOutOfBound = 4,
+    #[error("Math error")]
+    MathError = 5,
+    #[error("Fee params violate min_fee_bps <= base_fee_bps <= max_fee_bps")]
+    InvalidFeeParams = 6,
+    #[error("Sum of all target weights exceeds 100%")]
+    TotalTargetWeightOutOfBound = 7,
+    #[error("Pool accounts do not match the ones set in program state")]
+    IncorrectPoolAccounts = 8,
+    #[error("LST is not in the pool's LST state list")]
+    LstNotInPool = 9,
+}
+impl From<TargetWeightError> for ProgramError {
+    fn from(e: TargetWeightError) -> Self {
+        ProgramError::Custom(e as u32)
+    }
+}
+impl<T> DecodeError<T> for TargetWeightError {
+    fn type_of() -> &'static str {
+        "TargetWeightError"
+    }
+}
+impl PrintProgramError for TargetWeightError {
+    fn print<E>(&self)
+    where
+        E: 'static
+            + std::error::Error
+            + DecodeError<E>
+            + PrintProgramError
+            + num_traits::FromPrimitive,
+    {
+        msg!(&self.to_string());
+    }
+}
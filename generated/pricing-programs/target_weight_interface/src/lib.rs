@@ -0,0 +1,7 @@
+solana_program::declare_id!("tw1rJvnnddRKUsvyBBDp8VdUjS2vqkyHp75GNaiYU76");
+pub mod typedefs;
+pub use typedefs::*;
+pub mod instructions;
+pub use instructions::*;
+pub mod errors;
+pub use errors::*;
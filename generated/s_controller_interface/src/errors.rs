@@ -84,6 +84,34 @@ pub enum SControllerError {
     SwapSameLst = 35,
     #[error("Cannot add the same authority that has already been authorized")]
     DuplicateDisablePoolAuthority = 36,
+    #[error("Invalid swap route")]
+    InvalidSwapRoute = 37,
+    #[error("Invalid LSTs or amounts for multi-LST liquidity")]
+    InvalidLiquidityLsts = 38,
+    #[error("Pool is in timelock mode, this change must be queued as a pending change")]
+    PoolTimelocked = 39,
+    #[error("Pool is not in timelock mode")]
+    PoolNotTimelocked = 40,
+    #[error("The pending change's timelock delay has not elapsed")]
+    TimelockNotElapsed = 41,
+    #[error("Invalid pending change")]
+    InvalidPendingChange = 42,
+    #[error("Accounts or args do not match the pending change")]
+    PendingChangeMismatch = 43,
+    #[error("Invalid pending change list data")]
+    InvalidPendingChangeListData = 44,
+    #[error("Incorrect pending change list")]
+    IncorrectPendingChangeList = 45,
+    #[error("LST reserves would exceed the LST's max share of the pool's total SOL value")]
+    LstCapExceeded = 46,
+    #[error("Invalid LSTs or amounts for multi-LST protocol fee withdrawal")]
+    InvalidProtocolFeeWithdrawals = 47,
+    #[error("Invalid rebalance loss state data")]
+    InvalidRebalanceLossStateData = 48,
+    #[error("Attempting to set a LST's max share over 100%")]
+    LstCapTooHigh = 49,
+    #[error("Pool's own LP token cannot be added as a LST")]
+    LpTokenAsLst = 50,
 }
 impl From<SControllerError> for ProgramError {
     fn from(e: SControllerError) -> Self {
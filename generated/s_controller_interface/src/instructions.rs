@@ -33,6 +33,20 @@ pub enum SControllerProgramIx {
     EndRebalance,
     SetRebalanceAuthority,
     Initialize,
+    InitializePool,
+    SwapExactInRoute(SwapExactInRouteIxArgs),
+    AddLiquidityMulti(AddLiquidityMultiIxArgs),
+    RemoveLiquidityProRata(RemoveLiquidityProRataIxArgs),
+    AddLiquidityExactOut(AddLiquidityExactOutIxArgs),
+    RemoveLiquidityExactOut(RemoveLiquidityExactOutIxArgs),
+    SetTimelockDelay(SetTimelockDelayIxArgs),
+    QueuePendingChange(QueuePendingChangeIxArgs),
+    ExecutePendingChange(ExecutePendingChangeIxArgs),
+    CancelPendingChange(CancelPendingChangeIxArgs),
+    SetLstCap(SetLstCapIxArgs),
+    WithdrawProtocolFeesMulti(WithdrawProtocolFeesMultiIxArgs),
+    WithdrawProtocolFeesSwap(WithdrawProtocolFeesSwapIxArgs),
+    SetRebalanceLossLimit(SetRebalanceLossLimitIxArgs),
 }
 impl SControllerProgramIx {
     pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
@@ -88,6 +102,44 @@ impl SControllerProgramIx {
             END_REBALANCE_IX_DISCM => Ok(Self::EndRebalance),
             SET_REBALANCE_AUTHORITY_IX_DISCM => Ok(Self::SetRebalanceAuthority),
             INITIALIZE_IX_DISCM => Ok(Self::Initialize),
+            INITIALIZE_POOL_IX_DISCM => Ok(Self::InitializePool),
+            SWAP_EXACT_IN_ROUTE_IX_DISCM => Ok(Self::SwapExactInRoute(
+                SwapExactInRouteIxArgs::deserialize(&mut reader)?,
+            )),
+            ADD_LIQUIDITY_MULTI_IX_DISCM => Ok(Self::AddLiquidityMulti(
+                AddLiquidityMultiIxArgs::deserialize(&mut reader)?,
+            )),
+            REMOVE_LIQUIDITY_PRO_RATA_IX_DISCM => Ok(Self::RemoveLiquidityProRata(
+                RemoveLiquidityProRataIxArgs::deserialize(&mut reader)?,
+            )),
+            ADD_LIQUIDITY_EXACT_OUT_IX_DISCM => Ok(Self::AddLiquidityExactOut(
+                AddLiquidityExactOutIxArgs::deserialize(&mut reader)?,
+            )),
+            REMOVE_LIQUIDITY_EXACT_OUT_IX_DISCM => Ok(Self::RemoveLiquidityExactOut(
+                RemoveLiquidityExactOutIxArgs::deserialize(&mut reader)?,
+            )),
+            SET_TIMELOCK_DELAY_IX_DISCM => Ok(Self::SetTimelockDelay(
+                SetTimelockDelayIxArgs::deserialize(&mut reader)?,
+            )),
+            QUEUE_PENDING_CHANGE_IX_DISCM => Ok(Self::QueuePendingChange(
+                QueuePendingChangeIxArgs::deserialize(&mut reader)?,
+            )),
+            EXECUTE_PENDING_CHANGE_IX_DISCM => Ok(Self::ExecutePendingChange(
+                ExecutePendingChangeIxArgs::deserialize(&mut reader)?,
+            )),
+            CANCEL_PENDING_CHANGE_IX_DISCM => Ok(Self::CancelPendingChange(
+                CancelPendingChangeIxArgs::deserialize(&mut reader)?,
+            )),
+            SET_LST_CAP_IX_DISCM => Ok(Self::SetLstCap(SetLstCapIxArgs::deserialize(&mut reader)?)),
+            WITHDRAW_PROTOCOL_FEES_MULTI_IX_DISCM => Ok(Self::WithdrawProtocolFeesMulti(
+                WithdrawProtocolFeesMultiIxArgs::deserialize(&mut reader)?,
+            )),
+            WITHDRAW_PROTOCOL_FEES_SWAP_IX_DISCM => Ok(Self::WithdrawProtocolFeesSwap(
+                WithdrawProtocolFeesSwapIxArgs::deserialize(&mut reader)?,
+            )),
+            SET_REBALANCE_LOSS_LIMIT_IX_DISCM => Ok(Self::SetRebalanceLossLimit(
+                SetRebalanceLossLimitIxArgs::deserialize(&mut reader)?,
+            )),
             _ => Err(std::io::Error::new(
                 std::io::ErrorKind::Other,
                 format!("discm {:?} not found", maybe_discm),
@@ -162,6 +214,59 @@ impl SControllerProgramIx {
             Self::EndRebalance => writer.write_all(&[END_REBALANCE_IX_DISCM]),
             Self::SetRebalanceAuthority => writer.write_all(&[SET_REBALANCE_AUTHORITY_IX_DISCM]),
             Self::Initialize => writer.write_all(&[INITIALIZE_IX_DISCM]),
+            Self::InitializePool => writer.write_all(&[INITIALIZE_POOL_IX_DISCM]),
+            Self::SwapExactInRoute(args) => {
+                writer.write_all(&[SWAP_EXACT_IN_ROUTE_IX_DISCM])?;
+                args.serialize(&mut writer)
+            }
+            Self::AddLiquidityMulti(args) => {
+                writer.write_all(&[ADD_LIQUIDITY_MULTI_IX_DISCM])?;
+                args.serialize(&mut writer)
+            }
+            Self::RemoveLiquidityProRata(args) => {
+                writer.write_all(&[REMOVE_LIQUIDITY_PRO_RATA_IX_DISCM])?;
+                args.serialize(&mut writer)
+            }
+            Self::AddLiquidityExactOut(args) => {
+                writer.write_all(&[ADD_LIQUIDITY_EXACT_OUT_IX_DISCM])?;
+                args.serialize(&mut writer)
+            }
+            Self::RemoveLiquidityExactOut(args) => {
+                writer.write_all(&[REMOVE_LIQUIDITY_EXACT_OUT_IX_DISCM])?;
+                args.serialize(&mut writer)
+            }
+            Self::SetTimelockDelay(args) => {
+                writer.write_all(&[SET_TIMELOCK_DELAY_IX_DISCM])?;
+                args.serialize(&mut writer)
+            }
+            Self::QueuePendingChange(args) => {
+                writer.write_all(&[QUEUE_PENDING_CHANGE_IX_DISCM])?;
+                args.serialize(&mut writer)
+            }
+            Self::ExecutePendingChange(args) => {
+                writer.write_all(&[EXECUTE_PENDING_CHANGE_IX_DISCM])?;
+                args.serialize(&mut writer)
+            }
+            Self::CancelPendingChange(args) => {
+                writer.write_all(&[CANCEL_PENDING_CHANGE_IX_DISCM])?;
+                args.serialize(&mut writer)
+            }
+            Self::SetLstCap(args) => {
+                writer.write_all(&[SET_LST_CAP_IX_DISCM])?;
+                args.serialize(&mut writer)
+            }
+            Self::WithdrawProtocolFeesMulti(args) => {
+                writer.write_all(&[WITHDRAW_PROTOCOL_FEES_MULTI_IX_DISCM])?;
+                args.serialize(&mut writer)
+            }
+            Self::WithdrawProtocolFeesSwap(args) => {
+                writer.write_all(&[WITHDRAW_PROTOCOL_FEES_SWAP_IX_DISCM])?;
+                args.serialize(&mut writer)
+            }
+            Self::SetRebalanceLossLimit(args) => {
+                writer.write_all(&[SET_REBALANCE_LOSS_LIMIT_IX_DISCM])?;
+                args.serialize(&mut writer)
+            }
         }
     }
     pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
@@ -5057,7 +5162,7 @@ pub fn enable_pool_verify_account_privileges<'me, 'info>(
     enable_pool_verify_signer_privileges(accounts)?;
     Ok(())
 }
-pub const START_REBALANCE_IX_ACCOUNTS_LEN: usize = 12;
+pub const START_REBALANCE_IX_ACCOUNTS_LEN: usize = 13;
 #[derive(Copy, Clone, Debug)]
 pub struct StartRebalanceAccounts<'me, 'info> {
     ///The pool's rebalance authority
@@ -5084,6 +5189,8 @@ pub struct StartRebalanceAccounts<'me, 'info> {
     pub system_program: &'me AccountInfo<'info>,
     ///Source LST token program
     pub src_lst_token_program: &'me AccountInfo<'info>,
+    ///The pool's RebalanceLossState PDA. May be uninitialized.
+    pub rebalance_loss_state: &'me AccountInfo<'info>,
 }
 #[derive(Copy, Clone, Debug)]
 pub struct StartRebalanceKeys {
@@ -5111,6 +5218,8 @@ pub struct StartRebalanceKeys {
     pub system_program: Pubkey,
     ///Source LST token program
     pub src_lst_token_program: Pubkey,
+    ///The pool's RebalanceLossState PDA. May be uninitialized.
+    pub rebalance_loss_state: Pubkey,
 }
 impl From<StartRebalanceAccounts<'_, '_>> for StartRebalanceKeys {
     fn from(accounts: StartRebalanceAccounts) -> Self {
@@ -5127,6 +5236,7 @@ impl From<StartRebalanceAccounts<'_, '_>> for StartRebalanceKeys {
             instructions: *accounts.instructions.key,
             system_program: *accounts.system_program.key,
             src_lst_token_program: *accounts.src_lst_token_program.key,
+            rebalance_loss_state: *accounts.rebalance_loss_state.key,
         }
     }
 }
@@ -5193,6 +5303,11 @@ impl From<StartRebalanceKeys> for [AccountMeta; START_REBALANCE_IX_ACCOUNTS_LEN]
                 is_signer: false,
                 is_writable: false,
             },
+            AccountMeta {
+                pubkey: keys.rebalance_loss_state,
+                is_signer: false,
+                is_writable: false,
+            },
         ]
     }
 }
@@ -5211,6 +5326,7 @@ impl From<[Pubkey; START_REBALANCE_IX_ACCOUNTS_LEN]> for StartRebalanceKeys {
             instructions: pubkeys[9],
             system_program: pubkeys[10],
             src_lst_token_program: pubkeys[11],
+            rebalance_loss_state: pubkeys[12],
         }
     }
 }
@@ -5231,6 +5347,7 @@ impl<'info> From<StartRebalanceAccounts<'_, 'info>>
             accounts.instructions.clone(),
             accounts.system_program.clone(),
             accounts.src_lst_token_program.clone(),
+            accounts.rebalance_loss_state.clone(),
         ]
     }
 }
@@ -5251,6 +5368,7 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; START_REBALANCE_IX_ACCOUNTS_LEN]
             instructions: &arr[9],
             system_program: &arr[10],
             src_lst_token_program: &arr[11],
+            rebalance_loss_state: &arr[12],
         }
     }
 }
@@ -5264,6 +5382,7 @@ pub struct StartRebalanceIxArgs {
     pub amount: u64,
     pub min_starting_src_lst: u64,
     pub max_starting_dst_lst: u64,
+    pub min_ending_sol_value: u64,
 }
 #[derive(Clone, Debug, PartialEq)]
 pub struct StartRebalanceIxData(pub StartRebalanceIxArgs);
@@ -5370,6 +5489,10 @@ pub fn start_rebalance_verify_account_keys(
             accounts.src_lst_token_program.key,
             &keys.src_lst_token_program,
         ),
+        (
+            accounts.rebalance_loss_state.key,
+            &keys.rebalance_loss_state,
+        ),
     ] {
         if actual != expected {
             return Err((*actual, *expected));
@@ -5411,7 +5534,7 @@ pub fn start_rebalance_verify_account_privileges<'me, 'info>(
     start_rebalance_verify_signer_privileges(accounts)?;
     Ok(())
 }
-pub const END_REBALANCE_IX_ACCOUNTS_LEN: usize = 6;
+pub const END_REBALANCE_IX_ACCOUNTS_LEN: usize = 7;
 #[derive(Copy, Clone, Debug)]
 pub struct EndRebalanceAccounts<'me, 'info> {
     ///The pool's rebalance authority
@@ -5426,6 +5549,8 @@ pub struct EndRebalanceAccounts<'me, 'info> {
     pub dst_lst_mint: &'me AccountInfo<'info>,
     ///Destination LST reserves token account of the pool
     pub dst_pool_reserves: &'me AccountInfo<'info>,
+    ///The pool's RebalanceLossState PDA. May be uninitialized.
+    pub rebalance_loss_state: &'me AccountInfo<'info>,
 }
 #[derive(Copy, Clone, Debug)]
 pub struct EndRebalanceKeys {
@@ -5441,6 +5566,8 @@ pub struct EndRebalanceKeys {
     pub dst_lst_mint: Pubkey,
     ///Destination LST reserves token account of the pool
     pub dst_pool_reserves: Pubkey,
+    ///The pool's RebalanceLossState PDA. May be uninitialized.
+    pub rebalance_loss_state: Pubkey,
 }
 impl From<EndRebalanceAccounts<'_, '_>> for EndRebalanceKeys {
     fn from(accounts: EndRebalanceAccounts) -> Self {
@@ -5451,6 +5578,7 @@ impl From<EndRebalanceAccounts<'_, '_>> for EndRebalanceKeys {
             rebalance_record: *accounts.rebalance_record.key,
             dst_lst_mint: *accounts.dst_lst_mint.key,
             dst_pool_reserves: *accounts.dst_pool_reserves.key,
+            rebalance_loss_state: *accounts.rebalance_loss_state.key,
         }
     }
 }
@@ -5487,6 +5615,11 @@ impl From<EndRebalanceKeys> for [AccountMeta; END_REBALANCE_IX_ACCOUNTS_LEN] {
                 is_signer: false,
                 is_writable: false,
             },
+            AccountMeta {
+                pubkey: keys.rebalance_loss_state,
+                is_signer: false,
+                is_writable: true,
+            },
         ]
     }
 }
@@ -5499,6 +5632,7 @@ impl From<[Pubkey; END_REBALANCE_IX_ACCOUNTS_LEN]> for EndRebalanceKeys {
             rebalance_record: pubkeys[3],
             dst_lst_mint: pubkeys[4],
             dst_pool_reserves: pubkeys[5],
+            rebalance_loss_state: pubkeys[6],
         }
     }
 }
@@ -5513,6 +5647,7 @@ impl<'info> From<EndRebalanceAccounts<'_, 'info>>
             accounts.rebalance_record.clone(),
             accounts.dst_lst_mint.clone(),
             accounts.dst_pool_reserves.clone(),
+            accounts.rebalance_loss_state.clone(),
         ]
     }
 }
@@ -5527,6 +5662,7 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; END_REBALANCE_IX_ACCOUNTS_LEN]>
             rebalance_record: &arr[3],
             dst_lst_mint: &arr[4],
             dst_pool_reserves: &arr[5],
+            rebalance_loss_state: &arr[6],
         }
     }
 }
@@ -5610,6 +5746,10 @@ pub fn end_rebalance_verify_account_keys(
         (accounts.rebalance_record.key, &keys.rebalance_record),
         (accounts.dst_lst_mint.key, &keys.dst_lst_mint),
         (accounts.dst_pool_reserves.key, &keys.dst_pool_reserves),
+        (
+            accounts.rebalance_loss_state.key,
+            &keys.rebalance_loss_state,
+        ),
     ] {
         if actual != expected {
             return Err((*actual, *expected));
@@ -5624,6 +5764,7 @@ pub fn end_rebalance_verify_writable_privileges<'me, 'info>(
         accounts.pool_state,
         accounts.lst_state_list,
         accounts.rebalance_record,
+        accounts.rebalance_loss_state,
     ] {
         if !should_be_writable.is_writable {
             return Err((should_be_writable, ProgramError::InvalidAccountData));
@@ -6077,3 +6218,3657 @@ pub fn initialize_verify_account_privileges<'me, 'info>(
     initialize_verify_signer_privileges(accounts)?;
     Ok(())
 }
+pub const INITIALIZE_POOL_IX_ACCOUNTS_LEN: usize = 7;
+#[derive(Copy, Clone, Debug)]
+pub struct InitializePoolAccounts<'me, 'info> {
+    ///Account paying for rent
+    pub payer: &'me AccountInfo<'info>,
+    ///The default pool's admin
+    pub authority: &'me AccountInfo<'info>,
+    ///The default pool's state singleton PDA
+    pub default_pool_state: &'me AccountInfo<'info>,
+    ///The new pool's state PDA, keyed by lp_token_mint
+    pub pool_state: &'me AccountInfo<'info>,
+    ///The LP token mint of the new pool
+    pub lp_token_mint: &'me AccountInfo<'info>,
+    ///LP token mint's token program (Tokenkeg)
+    pub lp_token_program: &'me AccountInfo<'info>,
+    ///System program
+    pub system_program: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct InitializePoolKeys {
+    ///Account paying for rent
+    pub payer: Pubkey,
+    ///The default pool's admin
+    pub authority: Pubkey,
+    ///The default pool's state singleton PDA
+    pub default_pool_state: Pubkey,
+    ///The new pool's state PDA, keyed by lp_token_mint
+    pub pool_state: Pubkey,
+    ///The LP token mint of the new pool
+    pub lp_token_mint: Pubkey,
+    ///LP token mint's token program (Tokenkeg)
+    pub lp_token_program: Pubkey,
+    ///System program
+    pub system_program: Pubkey,
+}
+impl From<InitializePoolAccounts<'_, '_>> for InitializePoolKeys {
+    fn from(accounts: InitializePoolAccounts) -> Self {
+        Self {
+            payer: *accounts.payer.key,
+            authority: *accounts.authority.key,
+            default_pool_state: *accounts.default_pool_state.key,
+            pool_state: *accounts.pool_state.key,
+            lp_token_mint: *accounts.lp_token_mint.key,
+            lp_token_program: *accounts.lp_token_program.key,
+            system_program: *accounts.system_program.key,
+        }
+    }
+}
+impl From<InitializePoolKeys> for [AccountMeta; INITIALIZE_POOL_IX_ACCOUNTS_LEN] {
+    fn from(keys: InitializePoolKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.payer,
+                is_signer: true,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.authority,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.default_pool_state,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.pool_state,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.lp_token_mint,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.lp_token_program,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.system_program,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; INITIALIZE_POOL_IX_ACCOUNTS_LEN]> for InitializePoolKeys {
+    fn from(pubkeys: [Pubkey; INITIALIZE_POOL_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            payer: pubkeys[0],
+            authority: pubkeys[1],
+            default_pool_state: pubkeys[2],
+            pool_state: pubkeys[3],
+            lp_token_mint: pubkeys[4],
+            lp_token_program: pubkeys[5],
+            system_program: pubkeys[6],
+        }
+    }
+}
+impl<'info> From<InitializePoolAccounts<'_, 'info>>
+    for [AccountInfo<'info>; INITIALIZE_POOL_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: InitializePoolAccounts<'_, 'info>) -> Self {
+        [
+            accounts.payer.clone(),
+            accounts.authority.clone(),
+            accounts.default_pool_state.clone(),
+            accounts.pool_state.clone(),
+            accounts.lp_token_mint.clone(),
+            accounts.lp_token_program.clone(),
+            accounts.system_program.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; INITIALIZE_POOL_IX_ACCOUNTS_LEN]>
+    for InitializePoolAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; INITIALIZE_POOL_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            payer: &arr[0],
+            authority: &arr[1],
+            default_pool_state: &arr[2],
+            pool_state: &arr[3],
+            lp_token_mint: &arr[4],
+            lp_token_program: &arr[5],
+            system_program: &arr[6],
+        }
+    }
+}
+pub const INITIALIZE_POOL_IX_DISCM: u8 = 23u8;
+#[derive(Clone, Debug, PartialEq)]
+pub struct InitializePoolIxData;
+impl InitializePoolIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != INITIALIZE_POOL_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    INITIALIZE_POOL_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self)
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&[INITIALIZE_POOL_IX_DISCM])
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn initialize_pool_ix_with_program_id(
+    program_id: Pubkey,
+    keys: InitializePoolKeys,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; INITIALIZE_POOL_IX_ACCOUNTS_LEN] = keys.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: InitializePoolIxData.try_to_vec()?,
+    })
+}
+pub fn initialize_pool_ix(keys: InitializePoolKeys) -> std::io::Result<Instruction> {
+    initialize_pool_ix_with_program_id(crate::ID, keys)
+}
+pub fn initialize_pool_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: InitializePoolAccounts<'_, '_>,
+) -> ProgramResult {
+    let keys: InitializePoolKeys = accounts.into();
+    let ix = initialize_pool_ix_with_program_id(program_id, keys)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn initialize_pool_invoke(accounts: InitializePoolAccounts<'_, '_>) -> ProgramResult {
+    initialize_pool_invoke_with_program_id(crate::ID, accounts)
+}
+pub fn initialize_pool_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: InitializePoolAccounts<'_, '_>,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: InitializePoolKeys = accounts.into();
+    let ix = initialize_pool_ix_with_program_id(program_id, keys)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn initialize_pool_invoke_signed(
+    accounts: InitializePoolAccounts<'_, '_>,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    initialize_pool_invoke_signed_with_program_id(crate::ID, accounts, seeds)
+}
+pub fn initialize_pool_verify_account_keys(
+    accounts: InitializePoolAccounts<'_, '_>,
+    keys: InitializePoolKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.payer.key, &keys.payer),
+        (accounts.authority.key, &keys.authority),
+        (accounts.default_pool_state.key, &keys.default_pool_state),
+        (accounts.pool_state.key, &keys.pool_state),
+        (accounts.lp_token_mint.key, &keys.lp_token_mint),
+        (accounts.lp_token_program.key, &keys.lp_token_program),
+        (accounts.system_program.key, &keys.system_program),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn initialize_pool_verify_writable_privileges<'me, 'info>(
+    accounts: InitializePoolAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.payer, accounts.pool_state, accounts.lp_token_mint] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn initialize_pool_verify_signer_privileges<'me, 'info>(
+    accounts: InitializePoolAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.payer, accounts.authority] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn initialize_pool_verify_account_privileges<'me, 'info>(
+    accounts: InitializePoolAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    initialize_pool_verify_writable_privileges(accounts)?;
+    initialize_pool_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const SWAP_EXACT_IN_ROUTE_IX_ACCOUNTS_LEN: usize = 5;
+#[derive(Copy, Clone, Debug)]
+pub struct SwapExactInRouteAccounts<'me, 'info> {
+    ///Authority of src_lst_acc. User making the swap.
+    pub signer: &'me AccountInfo<'info>,
+    ///LST token account of the first LST on the route being swapped from
+    pub src_lst_acc: &'me AccountInfo<'info>,
+    ///LST token account of the last LST on the route to swap to
+    pub dst_lst_acc: &'me AccountInfo<'info>,
+    ///The pool's state singleton PDA
+    pub pool_state: &'me AccountInfo<'info>,
+    ///Dynamic list PDA of LstStates for each LST in the pool
+    pub lst_state_list: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct SwapExactInRouteKeys {
+    ///Authority of src_lst_acc. User making the swap.
+    pub signer: Pubkey,
+    ///LST token account of the first LST on the route being swapped from
+    pub src_lst_acc: Pubkey,
+    ///LST token account of the last LST on the route to swap to
+    pub dst_lst_acc: Pubkey,
+    ///The pool's state singleton PDA
+    pub pool_state: Pubkey,
+    ///Dynamic list PDA of LstStates for each LST in the pool
+    pub lst_state_list: Pubkey,
+}
+impl From<SwapExactInRouteAccounts<'_, '_>> for SwapExactInRouteKeys {
+    fn from(accounts: SwapExactInRouteAccounts) -> Self {
+        Self {
+            signer: *accounts.signer.key,
+            src_lst_acc: *accounts.src_lst_acc.key,
+            dst_lst_acc: *accounts.dst_lst_acc.key,
+            pool_state: *accounts.pool_state.key,
+            lst_state_list: *accounts.lst_state_list.key,
+        }
+    }
+}
+impl From<SwapExactInRouteKeys> for [AccountMeta; SWAP_EXACT_IN_ROUTE_IX_ACCOUNTS_LEN] {
+    fn from(keys: SwapExactInRouteKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.signer,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.src_lst_acc,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.dst_lst_acc,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.pool_state,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.lst_state_list,
+                is_signer: false,
+                is_writable: true,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; SWAP_EXACT_IN_ROUTE_IX_ACCOUNTS_LEN]> for SwapExactInRouteKeys {
+    fn from(pubkeys: [Pubkey; SWAP_EXACT_IN_ROUTE_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            signer: pubkeys[0],
+            src_lst_acc: pubkeys[1],
+            dst_lst_acc: pubkeys[2],
+            pool_state: pubkeys[3],
+            lst_state_list: pubkeys[4],
+        }
+    }
+}
+impl<'info> From<SwapExactInRouteAccounts<'_, 'info>>
+    for [AccountInfo<'info>; SWAP_EXACT_IN_ROUTE_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: SwapExactInRouteAccounts<'_, 'info>) -> Self {
+        [
+            accounts.signer.clone(),
+            accounts.src_lst_acc.clone(),
+            accounts.dst_lst_acc.clone(),
+            accounts.pool_state.clone(),
+            accounts.lst_state_list.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; SWAP_EXACT_IN_ROUTE_IX_ACCOUNTS_LEN]>
+    for SwapExactInRouteAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; SWAP_EXACT_IN_ROUTE_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            signer: &arr[0],
+            src_lst_acc: &arr[1],
+            dst_lst_acc: &arr[2],
+            pool_state: &arr[3],
+            lst_state_list: &arr[4],
+        }
+    }
+}
+pub const SWAP_EXACT_IN_ROUTE_IX_DISCM: u8 = 24u8;
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SwapExactInRouteIxArgs {
+    pub lst_value_calc_accs: Vec<u8>,
+    pub pricing_accs: Vec<u8>,
+    pub lst_indexes: Vec<u32>,
+    pub min_amount_out: u64,
+    pub amount: u64,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct SwapExactInRouteIxData(pub SwapExactInRouteIxArgs);
+impl From<SwapExactInRouteIxArgs> for SwapExactInRouteIxData {
+    fn from(args: SwapExactInRouteIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl SwapExactInRouteIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != SWAP_EXACT_IN_ROUTE_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    SWAP_EXACT_IN_ROUTE_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(SwapExactInRouteIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&[SWAP_EXACT_IN_ROUTE_IX_DISCM])?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn swap_exact_in_route_ix_with_program_id(
+    program_id: Pubkey,
+    keys: SwapExactInRouteKeys,
+    args: SwapExactInRouteIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; SWAP_EXACT_IN_ROUTE_IX_ACCOUNTS_LEN] = keys.into();
+    let data: SwapExactInRouteIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn swap_exact_in_route_ix(
+    keys: SwapExactInRouteKeys,
+    args: SwapExactInRouteIxArgs,
+) -> std::io::Result<Instruction> {
+    swap_exact_in_route_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn swap_exact_in_route_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: SwapExactInRouteAccounts<'_, '_>,
+    args: SwapExactInRouteIxArgs,
+) -> ProgramResult {
+    let keys: SwapExactInRouteKeys = accounts.into();
+    let ix = swap_exact_in_route_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn swap_exact_in_route_invoke(
+    accounts: SwapExactInRouteAccounts<'_, '_>,
+    args: SwapExactInRouteIxArgs,
+) -> ProgramResult {
+    swap_exact_in_route_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn swap_exact_in_route_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: SwapExactInRouteAccounts<'_, '_>,
+    args: SwapExactInRouteIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: SwapExactInRouteKeys = accounts.into();
+    let ix = swap_exact_in_route_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn swap_exact_in_route_invoke_signed(
+    accounts: SwapExactInRouteAccounts<'_, '_>,
+    args: SwapExactInRouteIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    swap_exact_in_route_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn swap_exact_in_route_verify_account_keys(
+    accounts: SwapExactInRouteAccounts<'_, '_>,
+    keys: SwapExactInRouteKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.signer.key, &keys.signer),
+        (accounts.src_lst_acc.key, &keys.src_lst_acc),
+        (accounts.dst_lst_acc.key, &keys.dst_lst_acc),
+        (accounts.pool_state.key, &keys.pool_state),
+        (accounts.lst_state_list.key, &keys.lst_state_list),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn swap_exact_in_route_verify_writable_privileges<'me, 'info>(
+    accounts: SwapExactInRouteAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [
+        accounts.src_lst_acc,
+        accounts.dst_lst_acc,
+        accounts.pool_state,
+        accounts.lst_state_list,
+    ] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn swap_exact_in_route_verify_signer_privileges<'me, 'info>(
+    accounts: SwapExactInRouteAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.signer] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn swap_exact_in_route_verify_account_privileges<'me, 'info>(
+    accounts: SwapExactInRouteAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    swap_exact_in_route_verify_writable_privileges(accounts)?;
+    swap_exact_in_route_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const ADD_LIQUIDITY_MULTI_IX_ACCOUNTS_LEN: usize = 6;
+#[derive(Copy, Clone, Debug)]
+pub struct AddLiquidityMultiAccounts<'me, 'info> {
+    ///Authority of the src LST token accounts. User who's adding liquidity.
+    pub signer: &'me AccountInfo<'info>,
+    ///LP token account to mint LP tokens to
+    pub dst_lp_acc: &'me AccountInfo<'info>,
+    ///LP token mint
+    pub lp_token_mint: &'me AccountInfo<'info>,
+    ///LP token mint's token program (Tokenkeg) for use with LP token mint
+    pub lp_token_program: &'me AccountInfo<'info>,
+    ///The pool's state singleton PDA
+    pub pool_state: &'me AccountInfo<'info>,
+    ///Dynamic list PDA of LstStates for each LST in the pool
+    pub lst_state_list: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct AddLiquidityMultiKeys {
+    ///Authority of the src LST token accounts. User who's adding liquidity.
+    pub signer: Pubkey,
+    ///LP token account to mint LP tokens to
+    pub dst_lp_acc: Pubkey,
+    ///LP token mint
+    pub lp_token_mint: Pubkey,
+    ///LP token mint's token program (Tokenkeg) for use with LP token mint
+    pub lp_token_program: Pubkey,
+    ///The pool's state singleton PDA
+    pub pool_state: Pubkey,
+    ///Dynamic list PDA of LstStates for each LST in the pool
+    pub lst_state_list: Pubkey,
+}
+impl From<AddLiquidityMultiAccounts<'_, '_>> for AddLiquidityMultiKeys {
+    fn from(accounts: AddLiquidityMultiAccounts) -> Self {
+        Self {
+            signer: *accounts.signer.key,
+            dst_lp_acc: *accounts.dst_lp_acc.key,
+            lp_token_mint: *accounts.lp_token_mint.key,
+            lp_token_program: *accounts.lp_token_program.key,
+            pool_state: *accounts.pool_state.key,
+            lst_state_list: *accounts.lst_state_list.key,
+        }
+    }
+}
+impl From<AddLiquidityMultiKeys> for [AccountMeta; ADD_LIQUIDITY_MULTI_IX_ACCOUNTS_LEN] {
+    fn from(keys: AddLiquidityMultiKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.signer,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.dst_lp_acc,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.lp_token_mint,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.lp_token_program,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.pool_state,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.lst_state_list,
+                is_signer: false,
+                is_writable: true,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; ADD_LIQUIDITY_MULTI_IX_ACCOUNTS_LEN]> for AddLiquidityMultiKeys {
+    fn from(pubkeys: [Pubkey; ADD_LIQUIDITY_MULTI_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            signer: pubkeys[0],
+            dst_lp_acc: pubkeys[1],
+            lp_token_mint: pubkeys[2],
+            lp_token_program: pubkeys[3],
+            pool_state: pubkeys[4],
+            lst_state_list: pubkeys[5],
+        }
+    }
+}
+impl<'info> From<AddLiquidityMultiAccounts<'_, 'info>>
+    for [AccountInfo<'info>; ADD_LIQUIDITY_MULTI_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: AddLiquidityMultiAccounts<'_, 'info>) -> Self {
+        [
+            accounts.signer.clone(),
+            accounts.dst_lp_acc.clone(),
+            accounts.lp_token_mint.clone(),
+            accounts.lp_token_program.clone(),
+            accounts.pool_state.clone(),
+            accounts.lst_state_list.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; ADD_LIQUIDITY_MULTI_IX_ACCOUNTS_LEN]>
+    for AddLiquidityMultiAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; ADD_LIQUIDITY_MULTI_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            signer: &arr[0],
+            dst_lp_acc: &arr[1],
+            lp_token_mint: &arr[2],
+            lp_token_program: &arr[3],
+            pool_state: &arr[4],
+            lst_state_list: &arr[5],
+        }
+    }
+}
+pub const ADD_LIQUIDITY_MULTI_IX_DISCM: u8 = 25u8;
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AddLiquidityMultiIxArgs {
+    pub lst_value_calc_accs: Vec<u8>,
+    pub pricing_accs: Vec<u8>,
+    pub lst_indexes: Vec<u32>,
+    pub lst_amounts: Vec<u64>,
+    pub min_lp_out: u64,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct AddLiquidityMultiIxData(pub AddLiquidityMultiIxArgs);
+impl From<AddLiquidityMultiIxArgs> for AddLiquidityMultiIxData {
+    fn from(args: AddLiquidityMultiIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl AddLiquidityMultiIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != ADD_LIQUIDITY_MULTI_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    ADD_LIQUIDITY_MULTI_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(AddLiquidityMultiIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&[ADD_LIQUIDITY_MULTI_IX_DISCM])?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn add_liquidity_multi_ix_with_program_id(
+    program_id: Pubkey,
+    keys: AddLiquidityMultiKeys,
+    args: AddLiquidityMultiIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; ADD_LIQUIDITY_MULTI_IX_ACCOUNTS_LEN] = keys.into();
+    let data: AddLiquidityMultiIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn add_liquidity_multi_ix(
+    keys: AddLiquidityMultiKeys,
+    args: AddLiquidityMultiIxArgs,
+) -> std::io::Result<Instruction> {
+    add_liquidity_multi_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn add_liquidity_multi_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: AddLiquidityMultiAccounts<'_, '_>,
+    args: AddLiquidityMultiIxArgs,
+) -> ProgramResult {
+    let keys: AddLiquidityMultiKeys = accounts.into();
+    let ix = add_liquidity_multi_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn add_liquidity_multi_invoke(
+    accounts: AddLiquidityMultiAccounts<'_, '_>,
+    args: AddLiquidityMultiIxArgs,
+) -> ProgramResult {
+    add_liquidity_multi_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn add_liquidity_multi_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: AddLiquidityMultiAccounts<'_, '_>,
+    args: AddLiquidityMultiIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: AddLiquidityMultiKeys = accounts.into();
+    let ix = add_liquidity_multi_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn add_liquidity_multi_invoke_signed(
+    accounts: AddLiquidityMultiAccounts<'_, '_>,
+    args: AddLiquidityMultiIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    add_liquidity_multi_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn add_liquidity_multi_verify_account_keys(
+    accounts: AddLiquidityMultiAccounts<'_, '_>,
+    keys: AddLiquidityMultiKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.signer.key, &keys.signer),
+        (accounts.dst_lp_acc.key, &keys.dst_lp_acc),
+        (accounts.lp_token_mint.key, &keys.lp_token_mint),
+        (accounts.lp_token_program.key, &keys.lp_token_program),
+        (accounts.pool_state.key, &keys.pool_state),
+        (accounts.lst_state_list.key, &keys.lst_state_list),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn add_liquidity_multi_verify_writable_privileges<'me, 'info>(
+    accounts: AddLiquidityMultiAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [
+        accounts.dst_lp_acc,
+        accounts.lp_token_mint,
+        accounts.pool_state,
+        accounts.lst_state_list,
+    ] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn add_liquidity_multi_verify_signer_privileges<'me, 'info>(
+    accounts: AddLiquidityMultiAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.signer] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn add_liquidity_multi_verify_account_privileges<'me, 'info>(
+    accounts: AddLiquidityMultiAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    add_liquidity_multi_verify_writable_privileges(accounts)?;
+    add_liquidity_multi_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const REMOVE_LIQUIDITY_PRO_RATA_IX_ACCOUNTS_LEN: usize = 6;
+#[derive(Copy, Clone, Debug)]
+pub struct RemoveLiquidityProRataAccounts<'me, 'info> {
+    ///Authority of src_lp_acc. User who's removing liquidity.
+    pub signer: &'me AccountInfo<'info>,
+    ///LP token account to burn LP tokens from
+    pub src_lp_acc: &'me AccountInfo<'info>,
+    ///LP token mint
+    pub lp_token_mint: &'me AccountInfo<'info>,
+    ///LP token mint's token program (Tokenkeg) for use with LP token mint
+    pub lp_token_program: &'me AccountInfo<'info>,
+    ///The pool's state singleton PDA
+    pub pool_state: &'me AccountInfo<'info>,
+    ///Dynamic list PDA of LstStates for each LST in the pool
+    pub lst_state_list: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct RemoveLiquidityProRataKeys {
+    ///Authority of src_lp_acc. User who's removing liquidity.
+    pub signer: Pubkey,
+    ///LP token account to burn LP tokens from
+    pub src_lp_acc: Pubkey,
+    ///LP token mint
+    pub lp_token_mint: Pubkey,
+    ///LP token mint's token program (Tokenkeg) for use with LP token mint
+    pub lp_token_program: Pubkey,
+    ///The pool's state singleton PDA
+    pub pool_state: Pubkey,
+    ///Dynamic list PDA of LstStates for each LST in the pool
+    pub lst_state_list: Pubkey,
+}
+impl From<RemoveLiquidityProRataAccounts<'_, '_>> for RemoveLiquidityProRataKeys {
+    fn from(accounts: RemoveLiquidityProRataAccounts) -> Self {
+        Self {
+            signer: *accounts.signer.key,
+            src_lp_acc: *accounts.src_lp_acc.key,
+            lp_token_mint: *accounts.lp_token_mint.key,
+            lp_token_program: *accounts.lp_token_program.key,
+            pool_state: *accounts.pool_state.key,
+            lst_state_list: *accounts.lst_state_list.key,
+        }
+    }
+}
+impl From<RemoveLiquidityProRataKeys> for [AccountMeta; REMOVE_LIQUIDITY_PRO_RATA_IX_ACCOUNTS_LEN] {
+    fn from(keys: RemoveLiquidityProRataKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.signer,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.src_lp_acc,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.lp_token_mint,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.lp_token_program,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.pool_state,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.lst_state_list,
+                is_signer: false,
+                is_writable: true,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; REMOVE_LIQUIDITY_PRO_RATA_IX_ACCOUNTS_LEN]> for RemoveLiquidityProRataKeys {
+    fn from(pubkeys: [Pubkey; REMOVE_LIQUIDITY_PRO_RATA_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            signer: pubkeys[0],
+            src_lp_acc: pubkeys[1],
+            lp_token_mint: pubkeys[2],
+            lp_token_program: pubkeys[3],
+            pool_state: pubkeys[4],
+            lst_state_list: pubkeys[5],
+        }
+    }
+}
+impl<'info> From<RemoveLiquidityProRataAccounts<'_, 'info>>
+    for [AccountInfo<'info>; REMOVE_LIQUIDITY_PRO_RATA_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: RemoveLiquidityProRataAccounts<'_, 'info>) -> Self {
+        [
+            accounts.signer.clone(),
+            accounts.src_lp_acc.clone(),
+            accounts.lp_token_mint.clone(),
+            accounts.lp_token_program.clone(),
+            accounts.pool_state.clone(),
+            accounts.lst_state_list.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; REMOVE_LIQUIDITY_PRO_RATA_IX_ACCOUNTS_LEN]>
+    for RemoveLiquidityProRataAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; REMOVE_LIQUIDITY_PRO_RATA_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            signer: &arr[0],
+            src_lp_acc: &arr[1],
+            lp_token_mint: &arr[2],
+            lp_token_program: &arr[3],
+            pool_state: &arr[4],
+            lst_state_list: &arr[5],
+        }
+    }
+}
+pub const REMOVE_LIQUIDITY_PRO_RATA_IX_DISCM: u8 = 26u8;
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RemoveLiquidityProRataIxArgs {
+    pub lst_value_calc_accs: Vec<u8>,
+    pub pricing_accs: Vec<u8>,
+    pub lp_token_amount: u64,
+    pub min_lst_outs: Vec<u64>,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct RemoveLiquidityProRataIxData(pub RemoveLiquidityProRataIxArgs);
+impl From<RemoveLiquidityProRataIxArgs> for RemoveLiquidityProRataIxData {
+    fn from(args: RemoveLiquidityProRataIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl RemoveLiquidityProRataIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != REMOVE_LIQUIDITY_PRO_RATA_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    REMOVE_LIQUIDITY_PRO_RATA_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(RemoveLiquidityProRataIxArgs::deserialize(
+            &mut reader,
+        )?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&[REMOVE_LIQUIDITY_PRO_RATA_IX_DISCM])?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn remove_liquidity_pro_rata_ix_with_program_id(
+    program_id: Pubkey,
+    keys: RemoveLiquidityProRataKeys,
+    args: RemoveLiquidityProRataIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; REMOVE_LIQUIDITY_PRO_RATA_IX_ACCOUNTS_LEN] = keys.into();
+    let data: RemoveLiquidityProRataIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn remove_liquidity_pro_rata_ix(
+    keys: RemoveLiquidityProRataKeys,
+    args: RemoveLiquidityProRataIxArgs,
+) -> std::io::Result<Instruction> {
+    remove_liquidity_pro_rata_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn remove_liquidity_pro_rata_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: RemoveLiquidityProRataAccounts<'_, '_>,
+    args: RemoveLiquidityProRataIxArgs,
+) -> ProgramResult {
+    let keys: RemoveLiquidityProRataKeys = accounts.into();
+    let ix = remove_liquidity_pro_rata_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn remove_liquidity_pro_rata_invoke(
+    accounts: RemoveLiquidityProRataAccounts<'_, '_>,
+    args: RemoveLiquidityProRataIxArgs,
+) -> ProgramResult {
+    remove_liquidity_pro_rata_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn remove_liquidity_pro_rata_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: RemoveLiquidityProRataAccounts<'_, '_>,
+    args: RemoveLiquidityProRataIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: RemoveLiquidityProRataKeys = accounts.into();
+    let ix = remove_liquidity_pro_rata_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn remove_liquidity_pro_rata_invoke_signed(
+    accounts: RemoveLiquidityProRataAccounts<'_, '_>,
+    args: RemoveLiquidityProRataIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    remove_liquidity_pro_rata_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn remove_liquidity_pro_rata_verify_account_keys(
+    accounts: RemoveLiquidityProRataAccounts<'_, '_>,
+    keys: RemoveLiquidityProRataKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.signer.key, &keys.signer),
+        (accounts.src_lp_acc.key, &keys.src_lp_acc),
+        (accounts.lp_token_mint.key, &keys.lp_token_mint),
+        (accounts.lp_token_program.key, &keys.lp_token_program),
+        (accounts.pool_state.key, &keys.pool_state),
+        (accounts.lst_state_list.key, &keys.lst_state_list),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn remove_liquidity_pro_rata_verify_writable_privileges<'me, 'info>(
+    accounts: RemoveLiquidityProRataAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [
+        accounts.src_lp_acc,
+        accounts.lp_token_mint,
+        accounts.pool_state,
+        accounts.lst_state_list,
+    ] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn remove_liquidity_pro_rata_verify_signer_privileges<'me, 'info>(
+    accounts: RemoveLiquidityProRataAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.signer] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn remove_liquidity_pro_rata_verify_account_privileges<'me, 'info>(
+    accounts: RemoveLiquidityProRataAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    remove_liquidity_pro_rata_verify_writable_privileges(accounts)?;
+    remove_liquidity_pro_rata_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const ADD_LIQUIDITY_EXACT_OUT_IX_ACCOUNTS_LEN: usize = 11;
+#[derive(Copy, Clone, Debug)]
+pub struct AddLiquidityExactOutAccounts<'me, 'info> {
+    ///Authority of src_lst_acc. User who's adding liquidity.
+    pub signer: &'me AccountInfo<'info>,
+    ///Mint of the LST
+    pub lst_mint: &'me AccountInfo<'info>,
+    ///LST token account to add liquidity from
+    pub src_lst_acc: &'me AccountInfo<'info>,
+    ///LP token account to mint new LP tokens to
+    pub dst_lp_acc: &'me AccountInfo<'info>,
+    ///LP token mint
+    pub lp_token_mint: &'me AccountInfo<'info>,
+    ///Protocol fee accumulator token account
+    pub protocol_fee_accumulator: &'me AccountInfo<'info>,
+    ///LST's token program
+    pub lst_token_program: &'me AccountInfo<'info>,
+    ///LP token mint's token program (Tokenkeg) for use with LP token mint
+    pub lp_token_program: &'me AccountInfo<'info>,
+    ///The pool's state singleton PDA
+    pub pool_state: &'me AccountInfo<'info>,
+    ///Dynamic list PDA of LstStates for each LST in the pool
+    pub lst_state_list: &'me AccountInfo<'info>,
+    ///LST reserves token account of the pool
+    pub pool_reserves: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct AddLiquidityExactOutKeys {
+    ///Authority of src_lst_acc. User who's adding liquidity.
+    pub signer: Pubkey,
+    ///Mint of the LST
+    pub lst_mint: Pubkey,
+    ///LST token account to add liquidity from
+    pub src_lst_acc: Pubkey,
+    ///LP token account to mint new LP tokens to
+    pub dst_lp_acc: Pubkey,
+    ///LP token mint
+    pub lp_token_mint: Pubkey,
+    ///Protocol fee accumulator token account
+    pub protocol_fee_accumulator: Pubkey,
+    ///LST's token program
+    pub lst_token_program: Pubkey,
+    ///LP token mint's token program (Tokenkeg) for use with LP token mint
+    pub lp_token_program: Pubkey,
+    ///The pool's state singleton PDA
+    pub pool_state: Pubkey,
+    ///Dynamic list PDA of LstStates for each LST in the pool
+    pub lst_state_list: Pubkey,
+    ///LST reserves token account of the pool
+    pub pool_reserves: Pubkey,
+}
+impl From<AddLiquidityExactOutAccounts<'_, '_>> for AddLiquidityExactOutKeys {
+    fn from(accounts: AddLiquidityExactOutAccounts) -> Self {
+        Self {
+            signer: *accounts.signer.key,
+            lst_mint: *accounts.lst_mint.key,
+            src_lst_acc: *accounts.src_lst_acc.key,
+            dst_lp_acc: *accounts.dst_lp_acc.key,
+            lp_token_mint: *accounts.lp_token_mint.key,
+            protocol_fee_accumulator: *accounts.protocol_fee_accumulator.key,
+            lst_token_program: *accounts.lst_token_program.key,
+            lp_token_program: *accounts.lp_token_program.key,
+            pool_state: *accounts.pool_state.key,
+            lst_state_list: *accounts.lst_state_list.key,
+            pool_reserves: *accounts.pool_reserves.key,
+        }
+    }
+}
+impl From<AddLiquidityExactOutKeys> for [AccountMeta; ADD_LIQUIDITY_EXACT_OUT_IX_ACCOUNTS_LEN] {
+    fn from(keys: AddLiquidityExactOutKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.signer,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.lst_mint,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.src_lst_acc,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.dst_lp_acc,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.lp_token_mint,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.protocol_fee_accumulator,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.lst_token_program,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.lp_token_program,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.pool_state,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.lst_state_list,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.pool_reserves,
+                is_signer: false,
+                is_writable: true,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; ADD_LIQUIDITY_EXACT_OUT_IX_ACCOUNTS_LEN]> for AddLiquidityExactOutKeys {
+    fn from(pubkeys: [Pubkey; ADD_LIQUIDITY_EXACT_OUT_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            signer: pubkeys[0],
+            lst_mint: pubkeys[1],
+            src_lst_acc: pubkeys[2],
+            dst_lp_acc: pubkeys[3],
+            lp_token_mint: pubkeys[4],
+            protocol_fee_accumulator: pubkeys[5],
+            lst_token_program: pubkeys[6],
+            lp_token_program: pubkeys[7],
+            pool_state: pubkeys[8],
+            lst_state_list: pubkeys[9],
+            pool_reserves: pubkeys[10],
+        }
+    }
+}
+impl<'info> From<AddLiquidityExactOutAccounts<'_, 'info>>
+    for [AccountInfo<'info>; ADD_LIQUIDITY_EXACT_OUT_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: AddLiquidityExactOutAccounts<'_, 'info>) -> Self {
+        [
+            accounts.signer.clone(),
+            accounts.lst_mint.clone(),
+            accounts.src_lst_acc.clone(),
+            accounts.dst_lp_acc.clone(),
+            accounts.lp_token_mint.clone(),
+            accounts.protocol_fee_accumulator.clone(),
+            accounts.lst_token_program.clone(),
+            accounts.lp_token_program.clone(),
+            accounts.pool_state.clone(),
+            accounts.lst_state_list.clone(),
+            accounts.pool_reserves.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; ADD_LIQUIDITY_EXACT_OUT_IX_ACCOUNTS_LEN]>
+    for AddLiquidityExactOutAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; ADD_LIQUIDITY_EXACT_OUT_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            signer: &arr[0],
+            lst_mint: &arr[1],
+            src_lst_acc: &arr[2],
+            dst_lp_acc: &arr[3],
+            lp_token_mint: &arr[4],
+            protocol_fee_accumulator: &arr[5],
+            lst_token_program: &arr[6],
+            lp_token_program: &arr[7],
+            pool_state: &arr[8],
+            lst_state_list: &arr[9],
+            pool_reserves: &arr[10],
+        }
+    }
+}
+pub const ADD_LIQUIDITY_EXACT_OUT_IX_DISCM: u8 = 27u8;
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AddLiquidityExactOutIxArgs {
+    pub lst_value_calc_accs: u8,
+    pub lst_index: u32,
+    pub lp_token_amount: u64,
+    pub max_lst_in: u64,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct AddLiquidityExactOutIxData(pub AddLiquidityExactOutIxArgs);
+impl From<AddLiquidityExactOutIxArgs> for AddLiquidityExactOutIxData {
+    fn from(args: AddLiquidityExactOutIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl AddLiquidityExactOutIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != ADD_LIQUIDITY_EXACT_OUT_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    ADD_LIQUIDITY_EXACT_OUT_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(AddLiquidityExactOutIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&[ADD_LIQUIDITY_EXACT_OUT_IX_DISCM])?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn add_liquidity_exact_out_ix_with_program_id(
+    program_id: Pubkey,
+    keys: AddLiquidityExactOutKeys,
+    args: AddLiquidityExactOutIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; ADD_LIQUIDITY_EXACT_OUT_IX_ACCOUNTS_LEN] = keys.into();
+    let data: AddLiquidityExactOutIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn add_liquidity_exact_out_ix(
+    keys: AddLiquidityExactOutKeys,
+    args: AddLiquidityExactOutIxArgs,
+) -> std::io::Result<Instruction> {
+    add_liquidity_exact_out_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn add_liquidity_exact_out_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: AddLiquidityExactOutAccounts<'_, '_>,
+    args: AddLiquidityExactOutIxArgs,
+) -> ProgramResult {
+    let keys: AddLiquidityExactOutKeys = accounts.into();
+    let ix = add_liquidity_exact_out_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn add_liquidity_exact_out_invoke(
+    accounts: AddLiquidityExactOutAccounts<'_, '_>,
+    args: AddLiquidityExactOutIxArgs,
+) -> ProgramResult {
+    add_liquidity_exact_out_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn add_liquidity_exact_out_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: AddLiquidityExactOutAccounts<'_, '_>,
+    args: AddLiquidityExactOutIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: AddLiquidityExactOutKeys = accounts.into();
+    let ix = add_liquidity_exact_out_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn add_liquidity_exact_out_invoke_signed(
+    accounts: AddLiquidityExactOutAccounts<'_, '_>,
+    args: AddLiquidityExactOutIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    add_liquidity_exact_out_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn add_liquidity_exact_out_verify_account_keys(
+    accounts: AddLiquidityExactOutAccounts<'_, '_>,
+    keys: AddLiquidityExactOutKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.signer.key, &keys.signer),
+        (accounts.lst_mint.key, &keys.lst_mint),
+        (accounts.src_lst_acc.key, &keys.src_lst_acc),
+        (accounts.dst_lp_acc.key, &keys.dst_lp_acc),
+        (accounts.lp_token_mint.key, &keys.lp_token_mint),
+        (
+            accounts.protocol_fee_accumulator.key,
+            &keys.protocol_fee_accumulator,
+        ),
+        (accounts.lst_token_program.key, &keys.lst_token_program),
+        (accounts.lp_token_program.key, &keys.lp_token_program),
+        (accounts.pool_state.key, &keys.pool_state),
+        (accounts.lst_state_list.key, &keys.lst_state_list),
+        (accounts.pool_reserves.key, &keys.pool_reserves),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn add_liquidity_exact_out_verify_writable_privileges<'me, 'info>(
+    accounts: AddLiquidityExactOutAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [
+        accounts.src_lst_acc,
+        accounts.dst_lp_acc,
+        accounts.lp_token_mint,
+        accounts.protocol_fee_accumulator,
+        accounts.pool_state,
+        accounts.lst_state_list,
+        accounts.pool_reserves,
+    ] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn add_liquidity_exact_out_verify_signer_privileges<'me, 'info>(
+    accounts: AddLiquidityExactOutAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.signer] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn add_liquidity_exact_out_verify_account_privileges<'me, 'info>(
+    accounts: AddLiquidityExactOutAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    add_liquidity_exact_out_verify_writable_privileges(accounts)?;
+    add_liquidity_exact_out_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const REMOVE_LIQUIDITY_EXACT_OUT_IX_ACCOUNTS_LEN: usize = 11;
+#[derive(Copy, Clone, Debug)]
+pub struct RemoveLiquidityExactOutAccounts<'me, 'info> {
+    ///Authority of lp_acc. User who's removing liquidity.
+    pub signer: &'me AccountInfo<'info>,
+    ///Mint of the LST
+    pub lst_mint: &'me AccountInfo<'info>,
+    ///LST token account to redeem to
+    pub dst_lst_acc: &'me AccountInfo<'info>,
+    ///LP token account to burn LP tokens from
+    pub src_lp_acc: &'me AccountInfo<'info>,
+    ///LP token mint
+    pub lp_token_mint: &'me AccountInfo<'info>,
+    ///Protocol fee accumulator token account
+    pub protocol_fee_accumulator: &'me AccountInfo<'info>,
+    ///LST's token program
+    pub lst_token_program: &'me AccountInfo<'info>,
+    ///LP token mint's token program (Tokenkeg) for use with LP token mint
+    pub lp_token_program: &'me AccountInfo<'info>,
+    ///The pool's state singleton PDA
+    pub pool_state: &'me AccountInfo<'info>,
+    ///Dynamic list PDA of LstStates for each LST in the pool
+    pub lst_state_list: &'me AccountInfo<'info>,
+    ///LST reserves token account of the pool
+    pub pool_reserves: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct RemoveLiquidityExactOutKeys {
+    ///Authority of lp_acc. User who's removing liquidity.
+    pub signer: Pubkey,
+    ///Mint of the LST
+    pub lst_mint: Pubkey,
+    ///LST token account to redeem to
+    pub dst_lst_acc: Pubkey,
+    ///LP token account to burn LP tokens from
+    pub src_lp_acc: Pubkey,
+    ///LP token mint
+    pub lp_token_mint: Pubkey,
+    ///Protocol fee accumulator token account
+    pub protocol_fee_accumulator: Pubkey,
+    ///LST's token program
+    pub lst_token_program: Pubkey,
+    ///LP token mint's token program (Tokenkeg) for use with LP token mint
+    pub lp_token_program: Pubkey,
+    ///The pool's state singleton PDA
+    pub pool_state: Pubkey,
+    ///Dynamic list PDA of LstStates for each LST in the pool
+    pub lst_state_list: Pubkey,
+    ///LST reserves token account of the pool
+    pub pool_reserves: Pubkey,
+}
+impl From<RemoveLiquidityExactOutAccounts<'_, '_>> for RemoveLiquidityExactOutKeys {
+    fn from(accounts: RemoveLiquidityExactOutAccounts) -> Self {
+        Self {
+            signer: *accounts.signer.key,
+            lst_mint: *accounts.lst_mint.key,
+            dst_lst_acc: *accounts.dst_lst_acc.key,
+            src_lp_acc: *accounts.src_lp_acc.key,
+            lp_token_mint: *accounts.lp_token_mint.key,
+            protocol_fee_accumulator: *accounts.protocol_fee_accumulator.key,
+            lst_token_program: *accounts.lst_token_program.key,
+            lp_token_program: *accounts.lp_token_program.key,
+            pool_state: *accounts.pool_state.key,
+            lst_state_list: *accounts.lst_state_list.key,
+            pool_reserves: *accounts.pool_reserves.key,
+        }
+    }
+}
+impl From<RemoveLiquidityExactOutKeys>
+    for [AccountMeta; REMOVE_LIQUIDITY_EXACT_OUT_IX_ACCOUNTS_LEN]
+{
+    fn from(keys: RemoveLiquidityExactOutKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.signer,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.lst_mint,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.dst_lst_acc,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.src_lp_acc,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.lp_token_mint,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.protocol_fee_accumulator,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.lst_token_program,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.lp_token_program,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.pool_state,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.lst_state_list,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.pool_reserves,
+                is_signer: false,
+                is_writable: true,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; REMOVE_LIQUIDITY_EXACT_OUT_IX_ACCOUNTS_LEN]> for RemoveLiquidityExactOutKeys {
+    fn from(pubkeys: [Pubkey; REMOVE_LIQUIDITY_EXACT_OUT_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            signer: pubkeys[0],
+            lst_mint: pubkeys[1],
+            dst_lst_acc: pubkeys[2],
+            src_lp_acc: pubkeys[3],
+            lp_token_mint: pubkeys[4],
+            protocol_fee_accumulator: pubkeys[5],
+            lst_token_program: pubkeys[6],
+            lp_token_program: pubkeys[7],
+            pool_state: pubkeys[8],
+            lst_state_list: pubkeys[9],
+            pool_reserves: pubkeys[10],
+        }
+    }
+}
+impl<'info> From<RemoveLiquidityExactOutAccounts<'_, 'info>>
+    for [AccountInfo<'info>; REMOVE_LIQUIDITY_EXACT_OUT_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: RemoveLiquidityExactOutAccounts<'_, 'info>) -> Self {
+        [
+            accounts.signer.clone(),
+            accounts.lst_mint.clone(),
+            accounts.dst_lst_acc.clone(),
+            accounts.src_lp_acc.clone(),
+            accounts.lp_token_mint.clone(),
+            accounts.protocol_fee_accumulator.clone(),
+            accounts.lst_token_program.clone(),
+            accounts.lp_token_program.clone(),
+            accounts.pool_state.clone(),
+            accounts.lst_state_list.clone(),
+            accounts.pool_reserves.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; REMOVE_LIQUIDITY_EXACT_OUT_IX_ACCOUNTS_LEN]>
+    for RemoveLiquidityExactOutAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; REMOVE_LIQUIDITY_EXACT_OUT_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            signer: &arr[0],
+            lst_mint: &arr[1],
+            dst_lst_acc: &arr[2],
+            src_lp_acc: &arr[3],
+            lp_token_mint: &arr[4],
+            protocol_fee_accumulator: &arr[5],
+            lst_token_program: &arr[6],
+            lp_token_program: &arr[7],
+            pool_state: &arr[8],
+            lst_state_list: &arr[9],
+            pool_reserves: &arr[10],
+        }
+    }
+}
+pub const REMOVE_LIQUIDITY_EXACT_OUT_IX_DISCM: u8 = 28u8;
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RemoveLiquidityExactOutIxArgs {
+    pub lst_value_calc_accs: u8,
+    pub lst_index: u32,
+    pub lst_amount: u64,
+    pub max_lp_in: u64,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct RemoveLiquidityExactOutIxData(pub RemoveLiquidityExactOutIxArgs);
+impl From<RemoveLiquidityExactOutIxArgs> for RemoveLiquidityExactOutIxData {
+    fn from(args: RemoveLiquidityExactOutIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl RemoveLiquidityExactOutIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != REMOVE_LIQUIDITY_EXACT_OUT_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    REMOVE_LIQUIDITY_EXACT_OUT_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(RemoveLiquidityExactOutIxArgs::deserialize(
+            &mut reader,
+        )?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&[REMOVE_LIQUIDITY_EXACT_OUT_IX_DISCM])?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn remove_liquidity_exact_out_ix_with_program_id(
+    program_id: Pubkey,
+    keys: RemoveLiquidityExactOutKeys,
+    args: RemoveLiquidityExactOutIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; REMOVE_LIQUIDITY_EXACT_OUT_IX_ACCOUNTS_LEN] = keys.into();
+    let data: RemoveLiquidityExactOutIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn remove_liquidity_exact_out_ix(
+    keys: RemoveLiquidityExactOutKeys,
+    args: RemoveLiquidityExactOutIxArgs,
+) -> std::io::Result<Instruction> {
+    remove_liquidity_exact_out_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn remove_liquidity_exact_out_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: RemoveLiquidityExactOutAccounts<'_, '_>,
+    args: RemoveLiquidityExactOutIxArgs,
+) -> ProgramResult {
+    let keys: RemoveLiquidityExactOutKeys = accounts.into();
+    let ix = remove_liquidity_exact_out_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn remove_liquidity_exact_out_invoke(
+    accounts: RemoveLiquidityExactOutAccounts<'_, '_>,
+    args: RemoveLiquidityExactOutIxArgs,
+) -> ProgramResult {
+    remove_liquidity_exact_out_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn remove_liquidity_exact_out_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: RemoveLiquidityExactOutAccounts<'_, '_>,
+    args: RemoveLiquidityExactOutIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: RemoveLiquidityExactOutKeys = accounts.into();
+    let ix = remove_liquidity_exact_out_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn remove_liquidity_exact_out_invoke_signed(
+    accounts: RemoveLiquidityExactOutAccounts<'_, '_>,
+    args: RemoveLiquidityExactOutIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    remove_liquidity_exact_out_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn remove_liquidity_exact_out_verify_account_keys(
+    accounts: RemoveLiquidityExactOutAccounts<'_, '_>,
+    keys: RemoveLiquidityExactOutKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.signer.key, &keys.signer),
+        (accounts.lst_mint.key, &keys.lst_mint),
+        (accounts.dst_lst_acc.key, &keys.dst_lst_acc),
+        (accounts.src_lp_acc.key, &keys.src_lp_acc),
+        (accounts.lp_token_mint.key, &keys.lp_token_mint),
+        (
+            accounts.protocol_fee_accumulator.key,
+            &keys.protocol_fee_accumulator,
+        ),
+        (accounts.lst_token_program.key, &keys.lst_token_program),
+        (accounts.lp_token_program.key, &keys.lp_token_program),
+        (accounts.pool_state.key, &keys.pool_state),
+        (accounts.lst_state_list.key, &keys.lst_state_list),
+        (accounts.pool_reserves.key, &keys.pool_reserves),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn remove_liquidity_exact_out_verify_writable_privileges<'me, 'info>(
+    accounts: RemoveLiquidityExactOutAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [
+        accounts.dst_lst_acc,
+        accounts.src_lp_acc,
+        accounts.lp_token_mint,
+        accounts.protocol_fee_accumulator,
+        accounts.pool_state,
+        accounts.lst_state_list,
+        accounts.pool_reserves,
+    ] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn remove_liquidity_exact_out_verify_signer_privileges<'me, 'info>(
+    accounts: RemoveLiquidityExactOutAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.signer] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn remove_liquidity_exact_out_verify_account_privileges<'me, 'info>(
+    accounts: RemoveLiquidityExactOutAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    remove_liquidity_exact_out_verify_writable_privileges(accounts)?;
+    remove_liquidity_exact_out_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const SET_TIMELOCK_DELAY_IX_ACCOUNTS_LEN: usize = 2;
+#[derive(Copy, Clone, Debug)]
+pub struct SetTimelockDelayAccounts<'me, 'info> {
+    ///The pool's admin
+    pub admin: &'me AccountInfo<'info>,
+    ///The pool's state singleton PDA
+    pub pool_state: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct SetTimelockDelayKeys {
+    ///The pool's admin
+    pub admin: Pubkey,
+    ///The pool's state singleton PDA
+    pub pool_state: Pubkey,
+}
+impl From<SetTimelockDelayAccounts<'_, '_>> for SetTimelockDelayKeys {
+    fn from(accounts: SetTimelockDelayAccounts) -> Self {
+        Self {
+            admin: *accounts.admin.key,
+            pool_state: *accounts.pool_state.key,
+        }
+    }
+}
+impl From<SetTimelockDelayKeys> for [AccountMeta; SET_TIMELOCK_DELAY_IX_ACCOUNTS_LEN] {
+    fn from(keys: SetTimelockDelayKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.admin,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.pool_state,
+                is_signer: false,
+                is_writable: true,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; SET_TIMELOCK_DELAY_IX_ACCOUNTS_LEN]> for SetTimelockDelayKeys {
+    fn from(pubkeys: [Pubkey; SET_TIMELOCK_DELAY_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            admin: pubkeys[0],
+            pool_state: pubkeys[1],
+        }
+    }
+}
+impl<'info> From<SetTimelockDelayAccounts<'_, 'info>>
+    for [AccountInfo<'info>; SET_TIMELOCK_DELAY_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: SetTimelockDelayAccounts<'_, 'info>) -> Self {
+        [accounts.admin.clone(), accounts.pool_state.clone()]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; SET_TIMELOCK_DELAY_IX_ACCOUNTS_LEN]>
+    for SetTimelockDelayAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; SET_TIMELOCK_DELAY_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            admin: &arr[0],
+            pool_state: &arr[1],
+        }
+    }
+}
+pub const SET_TIMELOCK_DELAY_IX_DISCM: u8 = 29u8;
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SetTimelockDelayIxArgs {
+    pub timelock_delay_hours: u8,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct SetTimelockDelayIxData(pub SetTimelockDelayIxArgs);
+impl From<SetTimelockDelayIxArgs> for SetTimelockDelayIxData {
+    fn from(args: SetTimelockDelayIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl SetTimelockDelayIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != SET_TIMELOCK_DELAY_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    SET_TIMELOCK_DELAY_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(SetTimelockDelayIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&[SET_TIMELOCK_DELAY_IX_DISCM])?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn set_timelock_delay_ix_with_program_id(
+    program_id: Pubkey,
+    keys: SetTimelockDelayKeys,
+    args: SetTimelockDelayIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; SET_TIMELOCK_DELAY_IX_ACCOUNTS_LEN] = keys.into();
+    let data: SetTimelockDelayIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn set_timelock_delay_ix(
+    keys: SetTimelockDelayKeys,
+    args: SetTimelockDelayIxArgs,
+) -> std::io::Result<Instruction> {
+    set_timelock_delay_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn set_timelock_delay_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: SetTimelockDelayAccounts<'_, '_>,
+    args: SetTimelockDelayIxArgs,
+) -> ProgramResult {
+    let keys: SetTimelockDelayKeys = accounts.into();
+    let ix = set_timelock_delay_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn set_timelock_delay_invoke(
+    accounts: SetTimelockDelayAccounts<'_, '_>,
+    args: SetTimelockDelayIxArgs,
+) -> ProgramResult {
+    set_timelock_delay_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn set_timelock_delay_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: SetTimelockDelayAccounts<'_, '_>,
+    args: SetTimelockDelayIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: SetTimelockDelayKeys = accounts.into();
+    let ix = set_timelock_delay_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn set_timelock_delay_invoke_signed(
+    accounts: SetTimelockDelayAccounts<'_, '_>,
+    args: SetTimelockDelayIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    set_timelock_delay_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn set_timelock_delay_verify_account_keys(
+    accounts: SetTimelockDelayAccounts<'_, '_>,
+    keys: SetTimelockDelayKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.admin.key, &keys.admin),
+        (accounts.pool_state.key, &keys.pool_state),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn set_timelock_delay_verify_writable_privileges<'me, 'info>(
+    accounts: SetTimelockDelayAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.pool_state] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn set_timelock_delay_verify_signer_privileges<'me, 'info>(
+    accounts: SetTimelockDelayAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.admin] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn set_timelock_delay_verify_account_privileges<'me, 'info>(
+    accounts: SetTimelockDelayAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    set_timelock_delay_verify_writable_privileges(accounts)?;
+    set_timelock_delay_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const QUEUE_PENDING_CHANGE_IX_ACCOUNTS_LEN: usize = 5;
+#[derive(Copy, Clone, Debug)]
+pub struct QueuePendingChangeAccounts<'me, 'info> {
+    ///Account paying for additional rent for realloc
+    pub payer: &'me AccountInfo<'info>,
+    ///The pool's admin
+    pub admin: &'me AccountInfo<'info>,
+    ///The pool's state singleton PDA
+    pub pool_state: &'me AccountInfo<'info>,
+    ///The pool's pending change list PDA
+    pub pending_change_list: &'me AccountInfo<'info>,
+    ///System program
+    pub system_program: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct QueuePendingChangeKeys {
+    ///Account paying for additional rent for realloc
+    pub payer: Pubkey,
+    ///The pool's admin
+    pub admin: Pubkey,
+    ///The pool's state singleton PDA
+    pub pool_state: Pubkey,
+    ///The pool's pending change list PDA
+    pub pending_change_list: Pubkey,
+    ///System program
+    pub system_program: Pubkey,
+}
+impl From<QueuePendingChangeAccounts<'_, '_>> for QueuePendingChangeKeys {
+    fn from(accounts: QueuePendingChangeAccounts) -> Self {
+        Self {
+            payer: *accounts.payer.key,
+            admin: *accounts.admin.key,
+            pool_state: *accounts.pool_state.key,
+            pending_change_list: *accounts.pending_change_list.key,
+            system_program: *accounts.system_program.key,
+        }
+    }
+}
+impl From<QueuePendingChangeKeys> for [AccountMeta; QUEUE_PENDING_CHANGE_IX_ACCOUNTS_LEN] {
+    fn from(keys: QueuePendingChangeKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.payer,
+                is_signer: true,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.admin,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.pool_state,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.pending_change_list,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.system_program,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; QUEUE_PENDING_CHANGE_IX_ACCOUNTS_LEN]> for QueuePendingChangeKeys {
+    fn from(pubkeys: [Pubkey; QUEUE_PENDING_CHANGE_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            payer: pubkeys[0],
+            admin: pubkeys[1],
+            pool_state: pubkeys[2],
+            pending_change_list: pubkeys[3],
+            system_program: pubkeys[4],
+        }
+    }
+}
+impl<'info> From<QueuePendingChangeAccounts<'_, 'info>>
+    for [AccountInfo<'info>; QUEUE_PENDING_CHANGE_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: QueuePendingChangeAccounts<'_, 'info>) -> Self {
+        [
+            accounts.payer.clone(),
+            accounts.admin.clone(),
+            accounts.pool_state.clone(),
+            accounts.pending_change_list.clone(),
+            accounts.system_program.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; QUEUE_PENDING_CHANGE_IX_ACCOUNTS_LEN]>
+    for QueuePendingChangeAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; QUEUE_PENDING_CHANGE_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            payer: &arr[0],
+            admin: &arr[1],
+            pool_state: &arr[2],
+            pending_change_list: &arr[3],
+            system_program: &arr[4],
+        }
+    }
+}
+pub const QUEUE_PENDING_CHANGE_IX_DISCM: u8 = 30u8;
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct QueuePendingChangeIxArgs {
+    pub kind: u8,
+    pub lst_mint: Pubkey,
+    pub new_pubkey: Pubkey,
+    pub new_trading_protocol_fee_bps: Option<u16>,
+    pub new_lp_protocol_fee_bps: Option<u16>,
+    pub new_timelock_delay_hours: u8,
+    pub new_max_share_bps: u16,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct QueuePendingChangeIxData(pub QueuePendingChangeIxArgs);
+impl From<QueuePendingChangeIxArgs> for QueuePendingChangeIxData {
+    fn from(args: QueuePendingChangeIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl QueuePendingChangeIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != QUEUE_PENDING_CHANGE_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    QUEUE_PENDING_CHANGE_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(QueuePendingChangeIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&[QUEUE_PENDING_CHANGE_IX_DISCM])?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn queue_pending_change_ix_with_program_id(
+    program_id: Pubkey,
+    keys: QueuePendingChangeKeys,
+    args: QueuePendingChangeIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; QUEUE_PENDING_CHANGE_IX_ACCOUNTS_LEN] = keys.into();
+    let data: QueuePendingChangeIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn queue_pending_change_ix(
+    keys: QueuePendingChangeKeys,
+    args: QueuePendingChangeIxArgs,
+) -> std::io::Result<Instruction> {
+    queue_pending_change_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn queue_pending_change_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: QueuePendingChangeAccounts<'_, '_>,
+    args: QueuePendingChangeIxArgs,
+) -> ProgramResult {
+    let keys: QueuePendingChangeKeys = accounts.into();
+    let ix = queue_pending_change_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn queue_pending_change_invoke(
+    accounts: QueuePendingChangeAccounts<'_, '_>,
+    args: QueuePendingChangeIxArgs,
+) -> ProgramResult {
+    queue_pending_change_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn queue_pending_change_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: QueuePendingChangeAccounts<'_, '_>,
+    args: QueuePendingChangeIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: QueuePendingChangeKeys = accounts.into();
+    let ix = queue_pending_change_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn queue_pending_change_invoke_signed(
+    accounts: QueuePendingChangeAccounts<'_, '_>,
+    args: QueuePendingChangeIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    queue_pending_change_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn queue_pending_change_verify_account_keys(
+    accounts: QueuePendingChangeAccounts<'_, '_>,
+    keys: QueuePendingChangeKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.payer.key, &keys.payer),
+        (accounts.admin.key, &keys.admin),
+        (accounts.pool_state.key, &keys.pool_state),
+        (accounts.pending_change_list.key, &keys.pending_change_list),
+        (accounts.system_program.key, &keys.system_program),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn queue_pending_change_verify_writable_privileges<'me, 'info>(
+    accounts: QueuePendingChangeAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.payer, accounts.pending_change_list] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn queue_pending_change_verify_signer_privileges<'me, 'info>(
+    accounts: QueuePendingChangeAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.payer, accounts.admin] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn queue_pending_change_verify_account_privileges<'me, 'info>(
+    accounts: QueuePendingChangeAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    queue_pending_change_verify_writable_privileges(accounts)?;
+    queue_pending_change_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const EXECUTE_PENDING_CHANGE_IX_ACCOUNTS_LEN: usize = 3;
+#[derive(Copy, Clone, Debug)]
+pub struct ExecutePendingChangeAccounts<'me, 'info> {
+    ///The account to refund SOL rent to after resizing
+    pub refund_rent_to: &'me AccountInfo<'info>,
+    ///The pool's state singleton PDA
+    pub pool_state: &'me AccountInfo<'info>,
+    ///The pool's pending change list PDA
+    pub pending_change_list: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct ExecutePendingChangeKeys {
+    ///The account to refund SOL rent to after resizing
+    pub refund_rent_to: Pubkey,
+    ///The pool's state singleton PDA
+    pub pool_state: Pubkey,
+    ///The pool's pending change list PDA
+    pub pending_change_list: Pubkey,
+}
+impl From<ExecutePendingChangeAccounts<'_, '_>> for ExecutePendingChangeKeys {
+    fn from(accounts: ExecutePendingChangeAccounts) -> Self {
+        Self {
+            refund_rent_to: *accounts.refund_rent_to.key,
+            pool_state: *accounts.pool_state.key,
+            pending_change_list: *accounts.pending_change_list.key,
+        }
+    }
+}
+impl From<ExecutePendingChangeKeys> for [AccountMeta; EXECUTE_PENDING_CHANGE_IX_ACCOUNTS_LEN] {
+    fn from(keys: ExecutePendingChangeKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.refund_rent_to,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.pool_state,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.pending_change_list,
+                is_signer: false,
+                is_writable: true,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; EXECUTE_PENDING_CHANGE_IX_ACCOUNTS_LEN]> for ExecutePendingChangeKeys {
+    fn from(pubkeys: [Pubkey; EXECUTE_PENDING_CHANGE_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            refund_rent_to: pubkeys[0],
+            pool_state: pubkeys[1],
+            pending_change_list: pubkeys[2],
+        }
+    }
+}
+impl<'info> From<ExecutePendingChangeAccounts<'_, 'info>>
+    for [AccountInfo<'info>; EXECUTE_PENDING_CHANGE_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: ExecutePendingChangeAccounts<'_, 'info>) -> Self {
+        [
+            accounts.refund_rent_to.clone(),
+            accounts.pool_state.clone(),
+            accounts.pending_change_list.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; EXECUTE_PENDING_CHANGE_IX_ACCOUNTS_LEN]>
+    for ExecutePendingChangeAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; EXECUTE_PENDING_CHANGE_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            refund_rent_to: &arr[0],
+            pool_state: &arr[1],
+            pending_change_list: &arr[2],
+        }
+    }
+}
+pub const EXECUTE_PENDING_CHANGE_IX_DISCM: u8 = 31u8;
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ExecutePendingChangeIxArgs {
+    pub pending_change_index: u32,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct ExecutePendingChangeIxData(pub ExecutePendingChangeIxArgs);
+impl From<ExecutePendingChangeIxArgs> for ExecutePendingChangeIxData {
+    fn from(args: ExecutePendingChangeIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl ExecutePendingChangeIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != EXECUTE_PENDING_CHANGE_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    EXECUTE_PENDING_CHANGE_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(ExecutePendingChangeIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&[EXECUTE_PENDING_CHANGE_IX_DISCM])?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn execute_pending_change_ix_with_program_id(
+    program_id: Pubkey,
+    keys: ExecutePendingChangeKeys,
+    args: ExecutePendingChangeIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; EXECUTE_PENDING_CHANGE_IX_ACCOUNTS_LEN] = keys.into();
+    let data: ExecutePendingChangeIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn execute_pending_change_ix(
+    keys: ExecutePendingChangeKeys,
+    args: ExecutePendingChangeIxArgs,
+) -> std::io::Result<Instruction> {
+    execute_pending_change_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn execute_pending_change_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: ExecutePendingChangeAccounts<'_, '_>,
+    args: ExecutePendingChangeIxArgs,
+) -> ProgramResult {
+    let keys: ExecutePendingChangeKeys = accounts.into();
+    let ix = execute_pending_change_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn execute_pending_change_invoke(
+    accounts: ExecutePendingChangeAccounts<'_, '_>,
+    args: ExecutePendingChangeIxArgs,
+) -> ProgramResult {
+    execute_pending_change_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn execute_pending_change_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: ExecutePendingChangeAccounts<'_, '_>,
+    args: ExecutePendingChangeIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: ExecutePendingChangeKeys = accounts.into();
+    let ix = execute_pending_change_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn execute_pending_change_invoke_signed(
+    accounts: ExecutePendingChangeAccounts<'_, '_>,
+    args: ExecutePendingChangeIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    execute_pending_change_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn execute_pending_change_verify_account_keys(
+    accounts: ExecutePendingChangeAccounts<'_, '_>,
+    keys: ExecutePendingChangeKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.refund_rent_to.key, &keys.refund_rent_to),
+        (accounts.pool_state.key, &keys.pool_state),
+        (accounts.pending_change_list.key, &keys.pending_change_list),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn execute_pending_change_verify_writable_privileges<'me, 'info>(
+    accounts: ExecutePendingChangeAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [
+        accounts.refund_rent_to,
+        accounts.pool_state,
+        accounts.pending_change_list,
+    ] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn execute_pending_change_verify_account_privileges<'me, 'info>(
+    accounts: ExecutePendingChangeAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    execute_pending_change_verify_writable_privileges(accounts)?;
+    Ok(())
+}
+pub const CANCEL_PENDING_CHANGE_IX_ACCOUNTS_LEN: usize = 5;
+#[derive(Copy, Clone, Debug)]
+pub struct CancelPendingChangeAccounts<'me, 'info> {
+    ///The pool's admin or a disable pool authority
+    pub signer: &'me AccountInfo<'info>,
+    ///The account to refund SOL rent to after resizing
+    pub refund_rent_to: &'me AccountInfo<'info>,
+    ///The pool's state singleton PDA
+    pub pool_state: &'me AccountInfo<'info>,
+    ///The pool's pending change list PDA
+    pub pending_change_list: &'me AccountInfo<'info>,
+    ///The pool's disable pool authority list singleton PDA
+    pub disable_pool_authority_list: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct CancelPendingChangeKeys {
+    ///The pool's admin or a disable pool authority
+    pub signer: Pubkey,
+    ///The account to refund SOL rent to after resizing
+    pub refund_rent_to: Pubkey,
+    ///The pool's state singleton PDA
+    pub pool_state: Pubkey,
+    ///The pool's pending change list PDA
+    pub pending_change_list: Pubkey,
+    ///The pool's disable pool authority list singleton PDA
+    pub disable_pool_authority_list: Pubkey,
+}
+impl From<CancelPendingChangeAccounts<'_, '_>> for CancelPendingChangeKeys {
+    fn from(accounts: CancelPendingChangeAccounts) -> Self {
+        Self {
+            signer: *accounts.signer.key,
+            refund_rent_to: *accounts.refund_rent_to.key,
+            pool_state: *accounts.pool_state.key,
+            pending_change_list: *accounts.pending_change_list.key,
+            disable_pool_authority_list: *accounts.disable_pool_authority_list.key,
+        }
+    }
+}
+impl From<CancelPendingChangeKeys> for [AccountMeta; CANCEL_PENDING_CHANGE_IX_ACCOUNTS_LEN] {
+    fn from(keys: CancelPendingChangeKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.signer,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.refund_rent_to,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.pool_state,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.pending_change_list,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.disable_pool_authority_list,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; CANCEL_PENDING_CHANGE_IX_ACCOUNTS_LEN]> for CancelPendingChangeKeys {
+    fn from(pubkeys: [Pubkey; CANCEL_PENDING_CHANGE_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            signer: pubkeys[0],
+            refund_rent_to: pubkeys[1],
+            pool_state: pubkeys[2],
+            pending_change_list: pubkeys[3],
+            disable_pool_authority_list: pubkeys[4],
+        }
+    }
+}
+impl<'info> From<CancelPendingChangeAccounts<'_, 'info>>
+    for [AccountInfo<'info>; CANCEL_PENDING_CHANGE_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: CancelPendingChangeAccounts<'_, 'info>) -> Self {
+        [
+            accounts.signer.clone(),
+            accounts.refund_rent_to.clone(),
+            accounts.pool_state.clone(),
+            accounts.pending_change_list.clone(),
+            accounts.disable_pool_authority_list.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; CANCEL_PENDING_CHANGE_IX_ACCOUNTS_LEN]>
+    for CancelPendingChangeAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; CANCEL_PENDING_CHANGE_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            signer: &arr[0],
+            refund_rent_to: &arr[1],
+            pool_state: &arr[2],
+            pending_change_list: &arr[3],
+            disable_pool_authority_list: &arr[4],
+        }
+    }
+}
+pub const CANCEL_PENDING_CHANGE_IX_DISCM: u8 = 32u8;
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CancelPendingChangeIxArgs {
+    pub pending_change_index: u32,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct CancelPendingChangeIxData(pub CancelPendingChangeIxArgs);
+impl From<CancelPendingChangeIxArgs> for CancelPendingChangeIxData {
+    fn from(args: CancelPendingChangeIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl CancelPendingChangeIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != CANCEL_PENDING_CHANGE_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    CANCEL_PENDING_CHANGE_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(CancelPendingChangeIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&[CANCEL_PENDING_CHANGE_IX_DISCM])?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn cancel_pending_change_ix_with_program_id(
+    program_id: Pubkey,
+    keys: CancelPendingChangeKeys,
+    args: CancelPendingChangeIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; CANCEL_PENDING_CHANGE_IX_ACCOUNTS_LEN] = keys.into();
+    let data: CancelPendingChangeIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn cancel_pending_change_ix(
+    keys: CancelPendingChangeKeys,
+    args: CancelPendingChangeIxArgs,
+) -> std::io::Result<Instruction> {
+    cancel_pending_change_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn cancel_pending_change_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: CancelPendingChangeAccounts<'_, '_>,
+    args: CancelPendingChangeIxArgs,
+) -> ProgramResult {
+    let keys: CancelPendingChangeKeys = accounts.into();
+    let ix = cancel_pending_change_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn cancel_pending_change_invoke(
+    accounts: CancelPendingChangeAccounts<'_, '_>,
+    args: CancelPendingChangeIxArgs,
+) -> ProgramResult {
+    cancel_pending_change_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn cancel_pending_change_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: CancelPendingChangeAccounts<'_, '_>,
+    args: CancelPendingChangeIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: CancelPendingChangeKeys = accounts.into();
+    let ix = cancel_pending_change_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn cancel_pending_change_invoke_signed(
+    accounts: CancelPendingChangeAccounts<'_, '_>,
+    args: CancelPendingChangeIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    cancel_pending_change_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn cancel_pending_change_verify_account_keys(
+    accounts: CancelPendingChangeAccounts<'_, '_>,
+    keys: CancelPendingChangeKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.signer.key, &keys.signer),
+        (accounts.refund_rent_to.key, &keys.refund_rent_to),
+        (accounts.pool_state.key, &keys.pool_state),
+        (accounts.pending_change_list.key, &keys.pending_change_list),
+        (
+            accounts.disable_pool_authority_list.key,
+            &keys.disable_pool_authority_list,
+        ),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn cancel_pending_change_verify_writable_privileges<'me, 'info>(
+    accounts: CancelPendingChangeAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.refund_rent_to, accounts.pending_change_list] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn cancel_pending_change_verify_signer_privileges<'me, 'info>(
+    accounts: CancelPendingChangeAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.signer] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn cancel_pending_change_verify_account_privileges<'me, 'info>(
+    accounts: CancelPendingChangeAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    cancel_pending_change_verify_writable_privileges(accounts)?;
+    cancel_pending_change_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const SET_LST_CAP_IX_ACCOUNTS_LEN: usize = 4;
+#[derive(Copy, Clone, Debug)]
+pub struct SetLstCapAccounts<'me, 'info> {
+    ///The pool's admin
+    pub admin: &'me AccountInfo<'info>,
+    ///Mint of the LST to set the cap for
+    pub lst_mint: &'me AccountInfo<'info>,
+    ///The pool's state singleton PDA
+    pub pool_state: &'me AccountInfo<'info>,
+    ///Dynamic list PDA of LstStates for each LST in the pool
+    pub lst_state_list: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct SetLstCapKeys {
+    ///The pool's admin
+    pub admin: Pubkey,
+    ///Mint of the LST to set the cap for
+    pub lst_mint: Pubkey,
+    ///The pool's state singleton PDA
+    pub pool_state: Pubkey,
+    ///Dynamic list PDA of LstStates for each LST in the pool
+    pub lst_state_list: Pubkey,
+}
+impl From<SetLstCapAccounts<'_, '_>> for SetLstCapKeys {
+    fn from(accounts: SetLstCapAccounts) -> Self {
+        Self {
+            admin: *accounts.admin.key,
+            lst_mint: *accounts.lst_mint.key,
+            pool_state: *accounts.pool_state.key,
+            lst_state_list: *accounts.lst_state_list.key,
+        }
+    }
+}
+impl From<SetLstCapKeys> for [AccountMeta; SET_LST_CAP_IX_ACCOUNTS_LEN] {
+    fn from(keys: SetLstCapKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.admin,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.lst_mint,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.pool_state,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.lst_state_list,
+                is_signer: false,
+                is_writable: true,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; SET_LST_CAP_IX_ACCOUNTS_LEN]> for SetLstCapKeys {
+    fn from(pubkeys: [Pubkey; SET_LST_CAP_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            admin: pubkeys[0],
+            lst_mint: pubkeys[1],
+            pool_state: pubkeys[2],
+            lst_state_list: pubkeys[3],
+        }
+    }
+}
+impl<'info> From<SetLstCapAccounts<'_, 'info>>
+    for [AccountInfo<'info>; SET_LST_CAP_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: SetLstCapAccounts<'_, 'info>) -> Self {
+        [
+            accounts.admin.clone(),
+            accounts.lst_mint.clone(),
+            accounts.pool_state.clone(),
+            accounts.lst_state_list.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; SET_LST_CAP_IX_ACCOUNTS_LEN]>
+    for SetLstCapAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; SET_LST_CAP_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            admin: &arr[0],
+            lst_mint: &arr[1],
+            pool_state: &arr[2],
+            lst_state_list: &arr[3],
+        }
+    }
+}
+pub const SET_LST_CAP_IX_DISCM: u8 = 33u8;
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SetLstCapIxArgs {
+    pub index: u32,
+    pub max_share_bps: u16,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct SetLstCapIxData(pub SetLstCapIxArgs);
+impl From<SetLstCapIxArgs> for SetLstCapIxData {
+    fn from(args: SetLstCapIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl SetLstCapIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != SET_LST_CAP_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    SET_LST_CAP_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(SetLstCapIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&[SET_LST_CAP_IX_DISCM])?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn set_lst_cap_ix_with_program_id(
+    program_id: Pubkey,
+    keys: SetLstCapKeys,
+    args: SetLstCapIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; SET_LST_CAP_IX_ACCOUNTS_LEN] = keys.into();
+    let data: SetLstCapIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn set_lst_cap_ix(keys: SetLstCapKeys, args: SetLstCapIxArgs) -> std::io::Result<Instruction> {
+    set_lst_cap_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn set_lst_cap_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: SetLstCapAccounts<'_, '_>,
+    args: SetLstCapIxArgs,
+) -> ProgramResult {
+    let keys: SetLstCapKeys = accounts.into();
+    let ix = set_lst_cap_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn set_lst_cap_invoke(
+    accounts: SetLstCapAccounts<'_, '_>,
+    args: SetLstCapIxArgs,
+) -> ProgramResult {
+    set_lst_cap_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn set_lst_cap_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: SetLstCapAccounts<'_, '_>,
+    args: SetLstCapIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: SetLstCapKeys = accounts.into();
+    let ix = set_lst_cap_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn set_lst_cap_invoke_signed(
+    accounts: SetLstCapAccounts<'_, '_>,
+    args: SetLstCapIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    set_lst_cap_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn set_lst_cap_verify_account_keys(
+    accounts: SetLstCapAccounts<'_, '_>,
+    keys: SetLstCapKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.admin.key, &keys.admin),
+        (accounts.lst_mint.key, &keys.lst_mint),
+        (accounts.pool_state.key, &keys.pool_state),
+        (accounts.lst_state_list.key, &keys.lst_state_list),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn set_lst_cap_verify_writable_privileges<'me, 'info>(
+    accounts: SetLstCapAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.lst_state_list] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn set_lst_cap_verify_signer_privileges<'me, 'info>(
+    accounts: SetLstCapAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.admin] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn set_lst_cap_verify_account_privileges<'me, 'info>(
+    accounts: SetLstCapAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    set_lst_cap_verify_writable_privileges(accounts)?;
+    set_lst_cap_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const WITHDRAW_PROTOCOL_FEES_MULTI_IX_ACCOUNTS_LEN: usize = 3;
+#[derive(Copy, Clone, Debug)]
+pub struct WithdrawProtocolFeesMultiAccounts<'me, 'info> {
+    ///The pool's protocol fee beneficiary
+    pub protocol_fee_beneficiary: &'me AccountInfo<'info>,
+    ///The protocol fee accumulator token account authority PDA. PDA ["protocol_fee"]
+    pub protocol_fee_accumulator_auth: &'me AccountInfo<'info>,
+    ///The pool's state singleton PDA
+    pub pool_state: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct WithdrawProtocolFeesMultiKeys {
+    ///The pool's protocol fee beneficiary
+    pub protocol_fee_beneficiary: Pubkey,
+    ///The protocol fee accumulator token account authority PDA. PDA ["protocol_fee"]
+    pub protocol_fee_accumulator_auth: Pubkey,
+    ///The pool's state singleton PDA
+    pub pool_state: Pubkey,
+}
+impl From<WithdrawProtocolFeesMultiAccounts<'_, '_>> for WithdrawProtocolFeesMultiKeys {
+    fn from(accounts: WithdrawProtocolFeesMultiAccounts) -> Self {
+        Self {
+            protocol_fee_beneficiary: *accounts.protocol_fee_beneficiary.key,
+            protocol_fee_accumulator_auth: *accounts.protocol_fee_accumulator_auth.key,
+            pool_state: *accounts.pool_state.key,
+        }
+    }
+}
+impl From<WithdrawProtocolFeesMultiKeys>
+    for [AccountMeta; WITHDRAW_PROTOCOL_FEES_MULTI_IX_ACCOUNTS_LEN]
+{
+    fn from(keys: WithdrawProtocolFeesMultiKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.protocol_fee_beneficiary,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.protocol_fee_accumulator_auth,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.pool_state,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; WITHDRAW_PROTOCOL_FEES_MULTI_IX_ACCOUNTS_LEN]>
+    for WithdrawProtocolFeesMultiKeys
+{
+    fn from(pubkeys: [Pubkey; WITHDRAW_PROTOCOL_FEES_MULTI_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            protocol_fee_beneficiary: pubkeys[0],
+            protocol_fee_accumulator_auth: pubkeys[1],
+            pool_state: pubkeys[2],
+        }
+    }
+}
+impl<'info> From<WithdrawProtocolFeesMultiAccounts<'_, 'info>>
+    for [AccountInfo<'info>; WITHDRAW_PROTOCOL_FEES_MULTI_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: WithdrawProtocolFeesMultiAccounts<'_, 'info>) -> Self {
+        [
+            accounts.protocol_fee_beneficiary.clone(),
+            accounts.protocol_fee_accumulator_auth.clone(),
+            accounts.pool_state.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; WITHDRAW_PROTOCOL_FEES_MULTI_IX_ACCOUNTS_LEN]>
+    for WithdrawProtocolFeesMultiAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; WITHDRAW_PROTOCOL_FEES_MULTI_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            protocol_fee_beneficiary: &arr[0],
+            protocol_fee_accumulator_auth: &arr[1],
+            pool_state: &arr[2],
+        }
+    }
+}
+pub const WITHDRAW_PROTOCOL_FEES_MULTI_IX_DISCM: u8 = 34u8;
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WithdrawProtocolFeesMultiIxArgs {
+    pub amounts: Vec<u64>,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct WithdrawProtocolFeesMultiIxData(pub WithdrawProtocolFeesMultiIxArgs);
+impl From<WithdrawProtocolFeesMultiIxArgs> for WithdrawProtocolFeesMultiIxData {
+    fn from(args: WithdrawProtocolFeesMultiIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl WithdrawProtocolFeesMultiIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != WITHDRAW_PROTOCOL_FEES_MULTI_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    WITHDRAW_PROTOCOL_FEES_MULTI_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(WithdrawProtocolFeesMultiIxArgs::deserialize(
+            &mut reader,
+        )?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&[WITHDRAW_PROTOCOL_FEES_MULTI_IX_DISCM])?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn withdraw_protocol_fees_multi_ix_with_program_id(
+    program_id: Pubkey,
+    keys: WithdrawProtocolFeesMultiKeys,
+    args: WithdrawProtocolFeesMultiIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; WITHDRAW_PROTOCOL_FEES_MULTI_IX_ACCOUNTS_LEN] = keys.into();
+    let data: WithdrawProtocolFeesMultiIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn withdraw_protocol_fees_multi_ix(
+    keys: WithdrawProtocolFeesMultiKeys,
+    args: WithdrawProtocolFeesMultiIxArgs,
+) -> std::io::Result<Instruction> {
+    withdraw_protocol_fees_multi_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn withdraw_protocol_fees_multi_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: WithdrawProtocolFeesMultiAccounts<'_, '_>,
+    args: WithdrawProtocolFeesMultiIxArgs,
+) -> ProgramResult {
+    let keys: WithdrawProtocolFeesMultiKeys = accounts.into();
+    let ix = withdraw_protocol_fees_multi_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn withdraw_protocol_fees_multi_invoke(
+    accounts: WithdrawProtocolFeesMultiAccounts<'_, '_>,
+    args: WithdrawProtocolFeesMultiIxArgs,
+) -> ProgramResult {
+    withdraw_protocol_fees_multi_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn withdraw_protocol_fees_multi_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: WithdrawProtocolFeesMultiAccounts<'_, '_>,
+    args: WithdrawProtocolFeesMultiIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: WithdrawProtocolFeesMultiKeys = accounts.into();
+    let ix = withdraw_protocol_fees_multi_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn withdraw_protocol_fees_multi_invoke_signed(
+    accounts: WithdrawProtocolFeesMultiAccounts<'_, '_>,
+    args: WithdrawProtocolFeesMultiIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    withdraw_protocol_fees_multi_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn withdraw_protocol_fees_multi_verify_account_keys(
+    accounts: WithdrawProtocolFeesMultiAccounts<'_, '_>,
+    keys: WithdrawProtocolFeesMultiKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (
+            accounts.protocol_fee_beneficiary.key,
+            &keys.protocol_fee_beneficiary,
+        ),
+        (
+            accounts.protocol_fee_accumulator_auth.key,
+            &keys.protocol_fee_accumulator_auth,
+        ),
+        (accounts.pool_state.key, &keys.pool_state),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn withdraw_protocol_fees_multi_verify_signer_privileges<'me, 'info>(
+    accounts: WithdrawProtocolFeesMultiAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.protocol_fee_beneficiary] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn withdraw_protocol_fees_multi_verify_account_privileges<'me, 'info>(
+    accounts: WithdrawProtocolFeesMultiAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    withdraw_protocol_fees_multi_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const WITHDRAW_PROTOCOL_FEES_SWAP_IX_ACCOUNTS_LEN: usize = 12;
+#[derive(Copy, Clone, Debug)]
+pub struct WithdrawProtocolFeesSwapAccounts<'me, 'info> {
+    ///The pool's protocol fee beneficiary
+    pub protocol_fee_beneficiary: &'me AccountInfo<'info>,
+    ///Mint of the LST whose protocol fees are being withdrawn
+    pub src_lst_mint: &'me AccountInfo<'info>,
+    ///Mint of the LST to receive the protocol fees in
+    pub dst_lst_mint: &'me AccountInfo<'info>,
+    ///The src LST protocol fee accumulator token account to withdraw from
+    pub protocol_fee_accumulator: &'me AccountInfo<'info>,
+    ///The protocol fee accumulator token account authority PDA. PDA ["protocol_fee"]
+    pub protocol_fee_accumulator_auth: &'me AccountInfo<'info>,
+    ///dst LST token account to withdraw the swapped protocol fees to
+    pub withdraw_to: &'me AccountInfo<'info>,
+    ///Source LST token program
+    pub src_lst_token_program: &'me AccountInfo<'info>,
+    ///Destination LST token program
+    pub dst_lst_token_program: &'me AccountInfo<'info>,
+    ///The pool's state singleton PDA
+    pub pool_state: &'me AccountInfo<'info>,
+    ///Dynamic list PDA of LstStates for each LST in the pool
+    pub lst_state_list: &'me AccountInfo<'info>,
+    ///Source LST reserves token account of the pool
+    pub src_pool_reserves: &'me AccountInfo<'info>,
+    ///Destination LST reserves token account of the pool
+    pub dst_pool_reserves: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct WithdrawProtocolFeesSwapKeys {
+    ///The pool's protocol fee beneficiary
+    pub protocol_fee_beneficiary: Pubkey,
+    ///Mint of the LST whose protocol fees are being withdrawn
+    pub src_lst_mint: Pubkey,
+    ///Mint of the LST to receive the protocol fees in
+    pub dst_lst_mint: Pubkey,
+    ///The src LST protocol fee accumulator token account to withdraw from
+    pub protocol_fee_accumulator: Pubkey,
+    ///The protocol fee accumulator token account authority PDA. PDA ["protocol_fee"]
+    pub protocol_fee_accumulator_auth: Pubkey,
+    ///dst LST token account to withdraw the swapped protocol fees to
+    pub withdraw_to: Pubkey,
+    ///Source LST token program
+    pub src_lst_token_program: Pubkey,
+    ///Destination LST token program
+    pub dst_lst_token_program: Pubkey,
+    ///The pool's state singleton PDA
+    pub pool_state: Pubkey,
+    ///Dynamic list PDA of LstStates for each LST in the pool
+    pub lst_state_list: Pubkey,
+    ///Source LST reserves token account of the pool
+    pub src_pool_reserves: Pubkey,
+    ///Destination LST reserves token account of the pool
+    pub dst_pool_reserves: Pubkey,
+}
+impl From<WithdrawProtocolFeesSwapAccounts<'_, '_>> for WithdrawProtocolFeesSwapKeys {
+    fn from(accounts: WithdrawProtocolFeesSwapAccounts) -> Self {
+        Self {
+            protocol_fee_beneficiary: *accounts.protocol_fee_beneficiary.key,
+            src_lst_mint: *accounts.src_lst_mint.key,
+            dst_lst_mint: *accounts.dst_lst_mint.key,
+            protocol_fee_accumulator: *accounts.protocol_fee_accumulator.key,
+            protocol_fee_accumulator_auth: *accounts.protocol_fee_accumulator_auth.key,
+            withdraw_to: *accounts.withdraw_to.key,
+            src_lst_token_program: *accounts.src_lst_token_program.key,
+            dst_lst_token_program: *accounts.dst_lst_token_program.key,
+            pool_state: *accounts.pool_state.key,
+            lst_state_list: *accounts.lst_state_list.key,
+            src_pool_reserves: *accounts.src_pool_reserves.key,
+            dst_pool_reserves: *accounts.dst_pool_reserves.key,
+        }
+    }
+}
+impl From<WithdrawProtocolFeesSwapKeys>
+    for [AccountMeta; WITHDRAW_PROTOCOL_FEES_SWAP_IX_ACCOUNTS_LEN]
+{
+    fn from(keys: WithdrawProtocolFeesSwapKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.protocol_fee_beneficiary,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.src_lst_mint,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.dst_lst_mint,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.protocol_fee_accumulator,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.protocol_fee_accumulator_auth,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.withdraw_to,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.src_lst_token_program,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.dst_lst_token_program,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.pool_state,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.lst_state_list,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.src_pool_reserves,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.dst_pool_reserves,
+                is_signer: false,
+                is_writable: true,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; WITHDRAW_PROTOCOL_FEES_SWAP_IX_ACCOUNTS_LEN]> for WithdrawProtocolFeesSwapKeys {
+    fn from(pubkeys: [Pubkey; WITHDRAW_PROTOCOL_FEES_SWAP_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            protocol_fee_beneficiary: pubkeys[0],
+            src_lst_mint: pubkeys[1],
+            dst_lst_mint: pubkeys[2],
+            protocol_fee_accumulator: pubkeys[3],
+            protocol_fee_accumulator_auth: pubkeys[4],
+            withdraw_to: pubkeys[5],
+            src_lst_token_program: pubkeys[6],
+            dst_lst_token_program: pubkeys[7],
+            pool_state: pubkeys[8],
+            lst_state_list: pubkeys[9],
+            src_pool_reserves: pubkeys[10],
+            dst_pool_reserves: pubkeys[11],
+        }
+    }
+}
+impl<'info> From<WithdrawProtocolFeesSwapAccounts<'_, 'info>>
+    for [AccountInfo<'info>; WITHDRAW_PROTOCOL_FEES_SWAP_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: WithdrawProtocolFeesSwapAccounts<'_, 'info>) -> Self {
+        [
+            accounts.protocol_fee_beneficiary.clone(),
+            accounts.src_lst_mint.clone(),
+            accounts.dst_lst_mint.clone(),
+            accounts.protocol_fee_accumulator.clone(),
+            accounts.protocol_fee_accumulator_auth.clone(),
+            accounts.withdraw_to.clone(),
+            accounts.src_lst_token_program.clone(),
+            accounts.dst_lst_token_program.clone(),
+            accounts.pool_state.clone(),
+            accounts.lst_state_list.clone(),
+            accounts.src_pool_reserves.clone(),
+            accounts.dst_pool_reserves.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; WITHDRAW_PROTOCOL_FEES_SWAP_IX_ACCOUNTS_LEN]>
+    for WithdrawProtocolFeesSwapAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; WITHDRAW_PROTOCOL_FEES_SWAP_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            protocol_fee_beneficiary: &arr[0],
+            src_lst_mint: &arr[1],
+            dst_lst_mint: &arr[2],
+            protocol_fee_accumulator: &arr[3],
+            protocol_fee_accumulator_auth: &arr[4],
+            withdraw_to: &arr[5],
+            src_lst_token_program: &arr[6],
+            dst_lst_token_program: &arr[7],
+            pool_state: &arr[8],
+            lst_state_list: &arr[9],
+            src_pool_reserves: &arr[10],
+            dst_pool_reserves: &arr[11],
+        }
+    }
+}
+pub const WITHDRAW_PROTOCOL_FEES_SWAP_IX_DISCM: u8 = 35u8;
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WithdrawProtocolFeesSwapIxArgs {
+    pub src_lst_calc_accs: u8,
+    pub src_lst_index: u32,
+    pub dst_lst_index: u32,
+    pub min_amount_out: u64,
+    pub amount: u64,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct WithdrawProtocolFeesSwapIxData(pub WithdrawProtocolFeesSwapIxArgs);
+impl From<WithdrawProtocolFeesSwapIxArgs> for WithdrawProtocolFeesSwapIxData {
+    fn from(args: WithdrawProtocolFeesSwapIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl WithdrawProtocolFeesSwapIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != WITHDRAW_PROTOCOL_FEES_SWAP_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    WITHDRAW_PROTOCOL_FEES_SWAP_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(WithdrawProtocolFeesSwapIxArgs::deserialize(
+            &mut reader,
+        )?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&[WITHDRAW_PROTOCOL_FEES_SWAP_IX_DISCM])?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn withdraw_protocol_fees_swap_ix_with_program_id(
+    program_id: Pubkey,
+    keys: WithdrawProtocolFeesSwapKeys,
+    args: WithdrawProtocolFeesSwapIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; WITHDRAW_PROTOCOL_FEES_SWAP_IX_ACCOUNTS_LEN] = keys.into();
+    let data: WithdrawProtocolFeesSwapIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn withdraw_protocol_fees_swap_ix(
+    keys: WithdrawProtocolFeesSwapKeys,
+    args: WithdrawProtocolFeesSwapIxArgs,
+) -> std::io::Result<Instruction> {
+    withdraw_protocol_fees_swap_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn withdraw_protocol_fees_swap_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: WithdrawProtocolFeesSwapAccounts<'_, '_>,
+    args: WithdrawProtocolFeesSwapIxArgs,
+) -> ProgramResult {
+    let keys: WithdrawProtocolFeesSwapKeys = accounts.into();
+    let ix = withdraw_protocol_fees_swap_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn withdraw_protocol_fees_swap_invoke(
+    accounts: WithdrawProtocolFeesSwapAccounts<'_, '_>,
+    args: WithdrawProtocolFeesSwapIxArgs,
+) -> ProgramResult {
+    withdraw_protocol_fees_swap_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn withdraw_protocol_fees_swap_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: WithdrawProtocolFeesSwapAccounts<'_, '_>,
+    args: WithdrawProtocolFeesSwapIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: WithdrawProtocolFeesSwapKeys = accounts.into();
+    let ix = withdraw_protocol_fees_swap_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn withdraw_protocol_fees_swap_invoke_signed(
+    accounts: WithdrawProtocolFeesSwapAccounts<'_, '_>,
+    args: WithdrawProtocolFeesSwapIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    withdraw_protocol_fees_swap_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn withdraw_protocol_fees_swap_verify_account_keys(
+    accounts: WithdrawProtocolFeesSwapAccounts<'_, '_>,
+    keys: WithdrawProtocolFeesSwapKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (
+            accounts.protocol_fee_beneficiary.key,
+            &keys.protocol_fee_beneficiary,
+        ),
+        (accounts.src_lst_mint.key, &keys.src_lst_mint),
+        (accounts.dst_lst_mint.key, &keys.dst_lst_mint),
+        (
+            accounts.protocol_fee_accumulator.key,
+            &keys.protocol_fee_accumulator,
+        ),
+        (
+            accounts.protocol_fee_accumulator_auth.key,
+            &keys.protocol_fee_accumulator_auth,
+        ),
+        (accounts.withdraw_to.key, &keys.withdraw_to),
+        (
+            accounts.src_lst_token_program.key,
+            &keys.src_lst_token_program,
+        ),
+        (
+            accounts.dst_lst_token_program.key,
+            &keys.dst_lst_token_program,
+        ),
+        (accounts.pool_state.key, &keys.pool_state),
+        (accounts.lst_state_list.key, &keys.lst_state_list),
+        (accounts.src_pool_reserves.key, &keys.src_pool_reserves),
+        (accounts.dst_pool_reserves.key, &keys.dst_pool_reserves),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn withdraw_protocol_fees_swap_verify_writable_privileges<'me, 'info>(
+    accounts: WithdrawProtocolFeesSwapAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [
+        accounts.protocol_fee_accumulator,
+        accounts.withdraw_to,
+        accounts.pool_state,
+        accounts.lst_state_list,
+        accounts.src_pool_reserves,
+        accounts.dst_pool_reserves,
+    ] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn withdraw_protocol_fees_swap_verify_signer_privileges<'me, 'info>(
+    accounts: WithdrawProtocolFeesSwapAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.protocol_fee_beneficiary] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn withdraw_protocol_fees_swap_verify_account_privileges<'me, 'info>(
+    accounts: WithdrawProtocolFeesSwapAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    withdraw_protocol_fees_swap_verify_writable_privileges(accounts)?;
+    withdraw_protocol_fees_swap_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const SET_REBALANCE_LOSS_LIMIT_IX_ACCOUNTS_LEN: usize = 5;
+#[derive(Copy, Clone, Debug)]
+pub struct SetRebalanceLossLimitAccounts<'me, 'info> {
+    ///Account paying for rent if the RebalanceLossState PDA is created
+    pub payer: &'me AccountInfo<'info>,
+    ///The pool's admin
+    pub admin: &'me AccountInfo<'info>,
+    ///The pool's state singleton PDA
+    pub pool_state: &'me AccountInfo<'info>,
+    ///The pool's RebalanceLossState PDA
+    pub rebalance_loss_state: &'me AccountInfo<'info>,
+    ///System program
+    pub system_program: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct SetRebalanceLossLimitKeys {
+    ///Account paying for rent if the RebalanceLossState PDA is created
+    pub payer: Pubkey,
+    ///The pool's admin
+    pub admin: Pubkey,
+    ///The pool's state singleton PDA
+    pub pool_state: Pubkey,
+    ///The pool's RebalanceLossState PDA
+    pub rebalance_loss_state: Pubkey,
+    ///System program
+    pub system_program: Pubkey,
+}
+impl From<SetRebalanceLossLimitAccounts<'_, '_>> for SetRebalanceLossLimitKeys {
+    fn from(accounts: SetRebalanceLossLimitAccounts) -> Self {
+        Self {
+            payer: *accounts.payer.key,
+            admin: *accounts.admin.key,
+            pool_state: *accounts.pool_state.key,
+            rebalance_loss_state: *accounts.rebalance_loss_state.key,
+            system_program: *accounts.system_program.key,
+        }
+    }
+}
+impl From<SetRebalanceLossLimitKeys> for [AccountMeta; SET_REBALANCE_LOSS_LIMIT_IX_ACCOUNTS_LEN] {
+    fn from(keys: SetRebalanceLossLimitKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.payer,
+                is_signer: true,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.admin,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.pool_state,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.rebalance_loss_state,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.system_program,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; SET_REBALANCE_LOSS_LIMIT_IX_ACCOUNTS_LEN]> for SetRebalanceLossLimitKeys {
+    fn from(pubkeys: [Pubkey; SET_REBALANCE_LOSS_LIMIT_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            payer: pubkeys[0],
+            admin: pubkeys[1],
+            pool_state: pubkeys[2],
+            rebalance_loss_state: pubkeys[3],
+            system_program: pubkeys[4],
+        }
+    }
+}
+impl<'info> From<SetRebalanceLossLimitAccounts<'_, 'info>>
+    for [AccountInfo<'info>; SET_REBALANCE_LOSS_LIMIT_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: SetRebalanceLossLimitAccounts<'_, 'info>) -> Self {
+        [
+            accounts.payer.clone(),
+            accounts.admin.clone(),
+            accounts.pool_state.clone(),
+            accounts.rebalance_loss_state.clone(),
+            accounts.system_program.clone(),
+        ]
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; SET_REBALANCE_LOSS_LIMIT_IX_ACCOUNTS_LEN]>
+    for SetRebalanceLossLimitAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; SET_REBALANCE_LOSS_LIMIT_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            payer: &arr[0],
+            admin: &arr[1],
+            pool_state: &arr[2],
+            rebalance_loss_state: &arr[3],
+            system_program: &arr[4],
+        }
+    }
+}
+pub const SET_REBALANCE_LOSS_LIMIT_IX_DISCM: u8 = 36u8;
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SetRebalanceLossLimitIxArgs {
+    pub max_loss_bps_per_rebalance: u16,
+    pub max_loss_bps_per_epoch: u16,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct SetRebalanceLossLimitIxData(pub SetRebalanceLossLimitIxArgs);
+impl From<SetRebalanceLossLimitIxArgs> for SetRebalanceLossLimitIxData {
+    fn from(args: SetRebalanceLossLimitIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl SetRebalanceLossLimitIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != SET_REBALANCE_LOSS_LIMIT_IX_DISCM {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "discm does not match. Expected: {:?}. Received: {:?}",
+                    SET_REBALANCE_LOSS_LIMIT_IX_DISCM, maybe_discm
+                ),
+            ));
+        }
+        Ok(Self(SetRebalanceLossLimitIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&[SET_REBALANCE_LOSS_LIMIT_IX_DISCM])?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn set_rebalance_loss_limit_ix_with_program_id(
+    program_id: Pubkey,
+    keys: SetRebalanceLossLimitKeys,
+    args: SetRebalanceLossLimitIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; SET_REBALANCE_LOSS_LIMIT_IX_ACCOUNTS_LEN] = keys.into();
+    let data: SetRebalanceLossLimitIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn set_rebalance_loss_limit_ix(
+    keys: SetRebalanceLossLimitKeys,
+    args: SetRebalanceLossLimitIxArgs,
+) -> std::io::Result<Instruction> {
+    set_rebalance_loss_limit_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn set_rebalance_loss_limit_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: SetRebalanceLossLimitAccounts<'_, '_>,
+    args: SetRebalanceLossLimitIxArgs,
+) -> ProgramResult {
+    let keys: SetRebalanceLossLimitKeys = accounts.into();
+    let ix = set_rebalance_loss_limit_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn set_rebalance_loss_limit_invoke(
+    accounts: SetRebalanceLossLimitAccounts<'_, '_>,
+    args: SetRebalanceLossLimitIxArgs,
+) -> ProgramResult {
+    set_rebalance_loss_limit_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn set_rebalance_loss_limit_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: SetRebalanceLossLimitAccounts<'_, '_>,
+    args: SetRebalanceLossLimitIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: SetRebalanceLossLimitKeys = accounts.into();
+    let ix = set_rebalance_loss_limit_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn set_rebalance_loss_limit_invoke_signed(
+    accounts: SetRebalanceLossLimitAccounts<'_, '_>,
+    args: SetRebalanceLossLimitIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    set_rebalance_loss_limit_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn set_rebalance_loss_limit_verify_account_keys(
+    accounts: SetRebalanceLossLimitAccounts<'_, '_>,
+    keys: SetRebalanceLossLimitKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (accounts.payer.key, &keys.payer),
+        (accounts.admin.key, &keys.admin),
+        (accounts.pool_state.key, &keys.pool_state),
+        (
+            accounts.rebalance_loss_state.key,
+            &keys.rebalance_loss_state,
+        ),
+        (accounts.system_program.key, &keys.system_program),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+pub fn set_rebalance_loss_limit_verify_writable_privileges<'me, 'info>(
+    accounts: SetRebalanceLossLimitAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.payer, accounts.rebalance_loss_state] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn set_rebalance_loss_limit_verify_signer_privileges<'me, 'info>(
+    accounts: SetRebalanceLossLimitAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.payer, accounts.admin] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn set_rebalance_loss_limit_verify_account_privileges<'me, 'info>(
+    accounts: SetRebalanceLossLimitAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    set_rebalance_loss_limit_verify_writable_privileges(accounts)?;
+    set_rebalance_loss_limit_verify_signer_privileges(accounts)?;
+    Ok(())
+}
@@ -11,7 +11,7 @@ pub struct PoolState {
     pub version: u8,
     pub is_disabled: u8,
     pub is_rebalancing: u8,
-    pub padding: [u8; 1],
+    pub timelock_delay_hours: u8,
     pub admin: Pubkey,
     pub rebalance_authority: Pubkey,
     pub protocol_fee_beneficiary: Pubkey,
@@ -25,7 +25,8 @@ pub struct LstState {
     pub is_input_disabled: u8,
     pub pool_reserves_bump: u8,
     pub protocol_fee_accumulator_bump: u8,
-    pub padding: [u8; 5],
+    pub padding: [u8; 3],
+    pub max_share_bps: u16,
     pub sol_value: u64,
     pub mint: Pubkey,
     pub sol_value_calculator: Pubkey,
@@ -37,4 +38,32 @@ pub struct RebalanceRecord {
     pub old_total_sol_value: u64,
     pub padding: [u8; 4],
     pub dst_lst_index: u32,
+    pub min_ending_sol_value: u64,
+}
+#[repr(C)]
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq, Pod, Copy, Zeroable)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RebalanceLossState {
+    pub max_loss_bps_per_rebalance: u16,
+    pub max_loss_bps_per_epoch: u16,
+    pub padding: [u8; 4],
+    pub epoch: u64,
+    pub epoch_loss: u64,
+    pub epoch_start_total_sol_value: u64,
+}
+#[repr(C)]
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq, Pod, Copy, Zeroable)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PendingChange {
+    pub executable_at: i64,
+    pub kind: u8,
+    pub new_timelock_delay_hours: u8,
+    pub new_trading_protocol_fee_bps: u16,
+    pub new_lp_protocol_fee_bps: u16,
+    pub is_new_trading_protocol_fee_bps_set: u8,
+    pub is_new_lp_protocol_fee_bps_set: u8,
+    pub lst_mint: Pubkey,
+    pub new_pubkey: Pubkey,
+    pub new_max_share_bps: u16,
+    pub padding: [u8; 6],
 }
@@ -0,0 +1,33 @@
+use solana_program::{
+    decode_error::DecodeError,
+    msg,
+    program_error::{PrintProgramError, ProgramError},
+};
+use thiserror::Error;
+#[derive(Clone, Copy, Debug, Eq, Error, num_derive::FromPrimitive, PartialEq)]
+pub enum EverstakeCalculatorError {
+    #[error("Everstake stake pool not yet updated for this epoch")]
+    PoolNotUpdated = 0,
+}
+impl From<EverstakeCalculatorError> for ProgramError {
+    fn from(e: EverstakeCalculatorError) -> Self {
+        ProgramError::Custom(e as u32)
+    }
+}
+impl<T> DecodeError<T> for EverstakeCalculatorError {
+    fn type_of() -> &'static str {
+        "EverstakeCalculatorError"
+    }
+}
+impl PrintProgramError for EverstakeCalculatorError {
+    fn print<E>(&self)
+    where
+        E: 'static
+            + std::error::Error
+            + DecodeError<E>
+            + PrintProgramError
+            + num_traits::FromPrimitive,
+    {
+        msg!(&self.to_string());
+    }
+}
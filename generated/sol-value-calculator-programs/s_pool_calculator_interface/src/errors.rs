@@ -0,0 +1,35 @@
+use solana_program::{
+    decode_error::DecodeError,
+    msg,
+    program_error::{PrintProgramError, ProgramError},
+};
+use thiserror::Error;
+#[derive(Clone, Copy, Debug, Eq, Error, num_derive::FromPrimitive, PartialEq)]
+pub enum SPoolCalculatorError {
+    #[error("S pool is in the middle of a rebalance")]
+    PoolRebalancing = 0,
+    #[error("S pool's pool state is writable, it may be the pool invoking this program")]
+    PoolStateWritable = 1,
+}
+impl From<SPoolCalculatorError> for ProgramError {
+    fn from(e: SPoolCalculatorError) -> Self {
+        ProgramError::Custom(e as u32)
+    }
+}
+impl<T> DecodeError<T> for SPoolCalculatorError {
+    fn type_of() -> &'static str {
+        "SPoolCalculatorError"
+    }
+}
+impl PrintProgramError for SPoolCalculatorError {
+    fn print<E>(&self)
+    where
+        E: 'static
+            + std::error::Error
+            + DecodeError<E>
+            + PrintProgramError
+            + num_traits::FromPrimitive,
+    {
+        msg!(&self.to_string());
+    }
+}
@@ -0,0 +1,3 @@
+solana_program::declare_id!("TH1S1DLoNLYCoNTA1NSERRoRANDCoP1EDTYPES11111");
+pub mod errors;
+pub use errors::*;
@@ -0,0 +1,33 @@
+use solana_program::{
+    decode_error::DecodeError,
+    msg,
+    program_error::{PrintProgramError, ProgramError},
+};
+use thiserror::Error;
+#[derive(Clone, Copy, Debug, Eq, Error, num_derive::FromPrimitive, PartialEq)]
+pub enum SvspCalculatorError {
+    #[error("SVSP pool stake account is not delegated")]
+    PoolStakeNotDelegated = 0,
+}
+impl From<SvspCalculatorError> for ProgramError {
+    fn from(e: SvspCalculatorError) -> Self {
+        ProgramError::Custom(e as u32)
+    }
+}
+impl<T> DecodeError<T> for SvspCalculatorError {
+    fn type_of() -> &'static str {
+        "SvspCalculatorError"
+    }
+}
+impl PrintProgramError for SvspCalculatorError {
+    fn print<E>(&self)
+    where
+        E: 'static
+            + std::error::Error
+            + DecodeError<E>
+            + PrintProgramError
+            + num_traits::FromPrimitive,
+    {
+        msg!(&self.to_string());
+    }
+}
@@ -0,0 +1,15 @@
+pub mod everstake_program {
+    sanctum_macros::declare_program_keys!("EverSFw9uN5t1V8kS3ficHUcKffSjwpGzUSGd7mgmSks", []);
+}
+
+pub mod everstake_program_progdata {
+    sanctum_macros::declare_program_keys!("BmVHbbVQh2jDqZwZrq5jhyrJQTpQK1KNaLWh7QwbgBzZ", []);
+}
+
+pub mod eversol_stake_pool {
+    sanctum_macros::declare_program_keys!("GUAMR8ciiaijraJeLDEDrFVaueLm9YzWWY9R7CBPL9rA", []);
+}
+
+pub mod esol {
+    sanctum_macros::declare_program_keys!("Hg35Vd8K3BS2pLB3xwC2WqQV8pmpCm3oNRGYP1PEVmCM", []);
+}
@@ -0,0 +1,15 @@
+pub mod socean_program {
+    sanctum_macros::declare_program_keys!("5ocnV1qiCgaQR8Jb8xWnVbApfaygJ8tNoZfgPwsgx9kx", []);
+}
+
+pub mod socean_program_progdata {
+    sanctum_macros::declare_program_keys!("ALmAxhSP7TDWGMHWBLjD9qS71YjqxjN1ba6JLRdzdVy", []);
+}
+
+pub mod socean_stake_pool {
+    sanctum_macros::declare_program_keys!("5oc4nmbNTda9fx8Tw57ShLD132aqDK65vuHH4RU1K4LZ", []);
+}
+
+pub mod scnsol {
+    sanctum_macros::declare_program_keys!("5oVNBeEEQvYi1cX3ir8Dx5n1P7pdxydbGF2X4TxVusJm", []);
+}
@@ -0,0 +1,7 @@
+pub mod svsp_program {
+    sanctum_macros::declare_program_keys!("SVSPxpvHdN29nkVg9rPapPNDddN5DipNLRUFhyjFThE", []);
+}
+
+pub mod svsp_program_progdata {
+    sanctum_macros::declare_program_keys!("CnA41uYcs8X64x4PAhoPooN1yo1vabvbb64eJe9H8SZJ", []);
+}
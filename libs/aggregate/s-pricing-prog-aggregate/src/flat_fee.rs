@@ -302,8 +302,7 @@ impl TryFrom<KnownPricingProg> for FlatFeePricingProg {
     fn try_from(value: KnownPricingProg) -> Result<Self, Self::Error> {
         match value {
             KnownPricingProg::FlatFee(f) => Ok(f),
-            // TODO: uncomment when we add more variants
-            // _ => Err(PricingProgErr::WrongPricingProg),
+            _ => Err(PricingProgErr::WrongPricingProg),
         }
     }
 }
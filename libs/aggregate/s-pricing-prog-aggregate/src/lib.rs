@@ -8,15 +8,18 @@ use std::collections::HashMap;
 
 mod err;
 mod flat_fee;
+mod target_weight;
 mod traits;
 
 pub use err::*;
 pub use flat_fee::*;
+pub use target_weight::*;
 pub use traits::*;
 
 #[derive(Clone, Debug)]
 pub enum KnownPricingProg {
-    FlatFee(FlatFeePricingProg), // only variant for now
+    FlatFee(FlatFeePricingProg),
+    TargetWeight(TargetWeightPricingProg),
 }
 
 impl MutablePricingProg for KnownPricingProg {
@@ -28,6 +31,9 @@ impl MutablePricingProg for KnownPricingProg {
             flat_fee_lib::program::ID => {
                 Self::FlatFee(FlatFeePricingProg::try_new(program_id, mints)?)
             }
+            target_weight_lib::program::ID => {
+                Self::TargetWeight(TargetWeightPricingProg::try_new(program_id, mints)?)
+            }
             _ => Err(PricingProgErr::UnknownPricingProg)?,
         })
     }
@@ -35,12 +41,14 @@ impl MutablePricingProg for KnownPricingProg {
     fn get_accounts_to_update_for_all_lsts(&self) -> Vec<Pubkey> {
         match self {
             Self::FlatFee(p) => p.get_accounts_to_update_for_all_lsts(),
+            Self::TargetWeight(p) => p.get_accounts_to_update_for_all_lsts(),
         }
     }
 
     fn get_accounts_to_update_for_liquidity(&self) -> Vec<Pubkey> {
         match self {
             Self::FlatFee(p) => p.get_accounts_to_update_for_liquidity(),
+            Self::TargetWeight(p) => p.get_accounts_to_update_for_liquidity(),
         }
     }
 
@@ -50,6 +58,7 @@ impl MutablePricingProg for KnownPricingProg {
     ) -> Vec<Pubkey> {
         match self {
             Self::FlatFee(p) => p.get_accounts_to_update_for_lsts(lst_mints),
+            Self::TargetWeight(p) => p.get_accounts_to_update_for_lsts(lst_mints),
         }
     }
 
@@ -59,6 +68,7 @@ impl MutablePricingProg for KnownPricingProg {
     ) -> anyhow::Result<()> {
         match self {
             Self::FlatFee(p) => p.update(account_map),
+            Self::TargetWeight(p) => p.update(account_map),
         }
     }
 }
@@ -71,6 +81,7 @@ impl PricingProg for KnownPricingProg {
     ) -> anyhow::Result<u64> {
         match self {
             Self::FlatFee(p) => p.quote_lp_tokens_to_redeem(output_lst_mint, args),
+            Self::TargetWeight(p) => p.quote_lp_tokens_to_redeem(output_lst_mint, args),
         }
     }
 
@@ -80,6 +91,7 @@ impl PricingProg for KnownPricingProg {
     ) -> anyhow::Result<Vec<AccountMeta>> {
         match self {
             Self::FlatFee(p) => p.price_lp_tokens_to_redeem_accounts(output_lst_mint),
+            Self::TargetWeight(p) => p.price_lp_tokens_to_redeem_accounts(output_lst_mint),
         }
     }
 
@@ -90,6 +102,7 @@ impl PricingProg for KnownPricingProg {
     ) -> anyhow::Result<u64> {
         match self {
             Self::FlatFee(p) => p.quote_lp_tokens_to_mint(input_lst_mint, args),
+            Self::TargetWeight(p) => p.quote_lp_tokens_to_mint(input_lst_mint, args),
         }
     }
 
@@ -99,6 +112,7 @@ impl PricingProg for KnownPricingProg {
     ) -> anyhow::Result<Vec<AccountMeta>> {
         match self {
             Self::FlatFee(p) => p.price_lp_tokens_to_mint_accounts(input_lst_mint),
+            Self::TargetWeight(p) => p.price_lp_tokens_to_mint_accounts(input_lst_mint),
         }
     }
 
@@ -109,12 +123,14 @@ impl PricingProg for KnownPricingProg {
     ) -> anyhow::Result<u64> {
         match self {
             Self::FlatFee(p) => p.quote_exact_in(keys, args),
+            Self::TargetWeight(p) => p.quote_exact_in(keys, args),
         }
     }
 
     fn price_exact_in_accounts(&self, keys: PriceExactInKeys) -> anyhow::Result<Vec<AccountMeta>> {
         match self {
             Self::FlatFee(p) => p.price_exact_in_accounts(keys),
+            Self::TargetWeight(p) => p.price_exact_in_accounts(keys),
         }
     }
 
@@ -125,6 +141,7 @@ impl PricingProg for KnownPricingProg {
     ) -> anyhow::Result<u64> {
         match self {
             Self::FlatFee(p) => p.quote_exact_out(keys, args),
+            Self::TargetWeight(p) => p.quote_exact_out(keys, args),
         }
     }
 
@@ -134,6 +151,7 @@ impl PricingProg for KnownPricingProg {
     ) -> anyhow::Result<Vec<AccountMeta>> {
         match self {
             Self::FlatFee(p) => p.price_exact_out_accounts(keys),
+            Self::TargetWeight(p) => p.price_exact_out_accounts(keys),
         }
     }
 }
@@ -143,3 +161,9 @@ impl From<FlatFeePricingProg> for KnownPricingProg {
         Self::FlatFee(value)
     }
 }
+
+impl From<TargetWeightPricingProg> for KnownPricingProg {
+    fn from(value: TargetWeightPricingProg) -> Self {
+        Self::TargetWeight(value)
+    }
+}
@@ -0,0 +1,401 @@
+use s_controller_lib::{try_lst_state_list, try_pool_state};
+use solana_program::{instruction::AccountMeta, pubkey::Pubkey};
+use solana_readonly_account::ReadonlyAccountData;
+use std::collections::HashMap;
+use target_weight_interface::{
+    PriceExactInKeys, PriceExactOutKeys, PriceLpTokensToMintKeys, ProgramState, TargetWeightError,
+    WeightAccount, PRICE_EXACT_IN_IX_ACCOUNTS_LEN, PRICE_EXACT_OUT_IX_ACCOUNTS_LEN,
+    PRICE_LP_TOKENS_TO_MINT_IX_ACCOUNTS_LEN, PRICE_LP_TOKENS_TO_REDEEM_IX_ACCOUNTS_LEN,
+};
+use target_weight_lib::{
+    account_resolvers::{
+        PriceExactInFreeArgs, PriceExactInWithBumpFreeArgs, PriceExactOutFreeArgs,
+        PriceExactOutWithBumpFreeArgs, PriceLpTokensToRedeemFreeArgs,
+    },
+    calc::{
+        calculate_price_exact_in, calculate_price_exact_out, calculate_price_lp_tokens_to_redeem,
+        CalculatePriceExactInArgs, CalculatePriceExactOutArgs, LstReserves, SwapFeeParams,
+        SwapReserves,
+    },
+    pda::{ProgramStateFindPdaArgs, WeightAccountCreatePdaArgs, WeightAccountFindPdaArgs},
+    utils::{try_program_state, try_weight_account},
+};
+
+use crate::{KnownPricingProg, MutablePricingProg, PricingProg, PricingProgErr};
+
+#[derive(Clone, Debug, Default)]
+pub struct TargetWeightPricingProg {
+    program_id: Pubkey,
+    program_state: Option<ProgramState>,
+    mints_to_weight_accounts: HashMap<Pubkey, Option<WeightAccount>>, // value = None means WeightAccount not yet fetched
+    pool_total_sol_value: Option<u64>,
+    lst_sol_values: HashMap<Pubkey, u64>,
+}
+
+impl TargetWeightPricingProg {
+    pub fn program_id(&self) -> Pubkey {
+        self.program_id
+    }
+
+    pub fn find_program_state_addr(&self) -> Pubkey {
+        ProgramStateFindPdaArgs {
+            program_id: self.program_id,
+        }
+        .get_program_state_address_and_bump_seed()
+        .0
+    }
+
+    /// Returns (pool_state, lst_state_list) of the pool the program prices for.
+    ///
+    /// Defaults to the s-controller's default pool until the program state is fetched
+    pub fn pool_accounts(&self) -> (Pubkey, Pubkey) {
+        match &self.program_state {
+            Some(ProgramState {
+                pool_state,
+                lst_state_list,
+                ..
+            }) => (*pool_state, *lst_state_list),
+            None => (
+                s_controller_lib::program::POOL_STATE_ID,
+                s_controller_lib::program::LST_STATE_LIST_ID,
+            ),
+        }
+    }
+
+    fn get_weight_account_checked(
+        &self,
+        lst_mint: &Pubkey,
+    ) -> Result<&WeightAccount, TargetWeightError> {
+        match self.mints_to_weight_accounts.get(lst_mint) {
+            Some(Some(a)) => Ok(a),
+            _ => Err(TargetWeightError::UnsupportedLstMint),
+        }
+    }
+
+    /// Returns (input_bump, output_bump)
+    fn get_cached_weight_account_bumps(
+        &self,
+        input_lst_mint: Pubkey,
+        output_lst_mint: Pubkey,
+    ) -> Option<(u8, u8)> {
+        match (
+            self.get_weight_account_checked(&input_lst_mint),
+            self.get_weight_account_checked(&output_lst_mint),
+        ) {
+            (
+                Ok(WeightAccount {
+                    bump: input_bump, ..
+                }),
+                Ok(WeightAccount {
+                    bump: output_bump, ..
+                }),
+            ) => Some((*input_bump, *output_bump)),
+            _ => None,
+        }
+    }
+
+    fn weight_account_for_mint(
+        &self,
+        lst_mint: &Pubkey,
+        weight_account_opt: &Option<WeightAccount>,
+    ) -> Pubkey {
+        let find_pda_args = WeightAccountFindPdaArgs {
+            program_id: self.program_id,
+            lst_mint: *lst_mint,
+        };
+        let bump = match weight_account_opt {
+            Some(WeightAccount { bump, .. }) => bump,
+            None => return find_pda_args.get_weight_account_address_and_bump_seed().0,
+        };
+        WeightAccountCreatePdaArgs {
+            find_pda_args,
+            bump: *bump,
+        }
+        .get_weight_account_address()
+        .map_or_else(
+            |_e| find_pda_args.get_weight_account_address_and_bump_seed().0,
+            |pk| pk,
+        )
+    }
+
+    fn swap_pricing_state(
+        &self,
+        input_lst_mint: &Pubkey,
+        output_lst_mint: &Pubkey,
+    ) -> Result<(SwapFeeParams, SwapReserves), TargetWeightError> {
+        let fee_params = SwapFeeParams::from(
+            self.program_state
+                .as_ref()
+                .ok_or(TargetWeightError::InvalidProgramStateData)?,
+        );
+        let input_target_weight_bps = self
+            .get_weight_account_checked(input_lst_mint)?
+            .target_weight_bps;
+        let output_target_weight_bps = self
+            .get_weight_account_checked(output_lst_mint)?
+            .target_weight_bps;
+        let pool_total_sol_value = self
+            .pool_total_sol_value
+            .ok_or(TargetWeightError::IncorrectPoolAccounts)?;
+        let lst_sol_value = |mint: &Pubkey| {
+            self.lst_sol_values
+                .get(mint)
+                .copied()
+                .ok_or(TargetWeightError::LstNotInPool)
+        };
+        Ok((
+            fee_params,
+            SwapReserves {
+                pool_total_sol_value,
+                input: LstReserves {
+                    sol_value: lst_sol_value(input_lst_mint)?,
+                    target_weight_bps: input_target_weight_bps,
+                },
+                output: LstReserves {
+                    sol_value: lst_sol_value(output_lst_mint)?,
+                    target_weight_bps: output_target_weight_bps,
+                },
+            },
+        ))
+    }
+}
+
+impl MutablePricingProg for TargetWeightPricingProg {
+    fn try_new<I: Iterator<Item = Pubkey>>(
+        program_id: Pubkey,
+        mints: I,
+    ) -> Result<Self, PricingProgErr>
+    where
+        Self: Sized,
+    {
+        Ok(Self {
+            program_id,
+            program_state: None,
+            mints_to_weight_accounts: mints.map(|pk| (pk, None)).collect(),
+            pool_total_sol_value: None,
+            lst_sol_values: HashMap::new(),
+        })
+    }
+
+    fn get_accounts_to_update_for_liquidity(&self) -> Vec<Pubkey> {
+        vec![self.find_program_state_addr()]
+    }
+
+    fn get_accounts_to_update_for_all_lsts(&self) -> Vec<Pubkey> {
+        let (pool_state, lst_state_list) = self.pool_accounts();
+        self.mints_to_weight_accounts
+            .iter()
+            .map(|(lst_mint, weight_account_opt)| {
+                self.weight_account_for_mint(lst_mint, weight_account_opt)
+            })
+            .chain([self.find_program_state_addr(), pool_state, lst_state_list])
+            .collect()
+    }
+
+    fn get_accounts_to_update_for_lsts<I: Iterator<Item = Pubkey>>(
+        &self,
+        lst_mints: I,
+    ) -> Vec<Pubkey> {
+        let (pool_state, lst_state_list) = self.pool_accounts();
+        lst_mints
+            .map(|lst_mint| {
+                let weight_account_opt = self
+                    .mints_to_weight_accounts
+                    .get(&lst_mint)
+                    .map_or_else(|| &None, |opt| opt);
+                self.weight_account_for_mint(&lst_mint, weight_account_opt)
+            })
+            .chain([self.find_program_state_addr(), pool_state, lst_state_list])
+            .collect()
+    }
+
+    fn update<D: ReadonlyAccountData>(
+        &mut self,
+        account_map: &HashMap<Pubkey, D>,
+    ) -> anyhow::Result<()> {
+        let psa = self.find_program_state_addr();
+        if let Some(acc) = account_map.get(&psa) {
+            self.program_state = Some(*try_program_state(&acc.data())?);
+        }
+
+        let (pool_state, lst_state_list) = self.pool_accounts();
+        if let Some(acc) = account_map.get(&pool_state) {
+            self.pool_total_sol_value = Some(try_pool_state(&acc.data())?.total_sol_value);
+        }
+        if let Some(acc) = account_map.get(&lst_state_list) {
+            self.lst_sol_values = try_lst_state_list(&acc.data())?
+                .iter()
+                .map(|lst_state| (lst_state.mint, lst_state.sol_value))
+                .collect();
+        }
+
+        for (lst_mint, weight_account_opt) in self.mints_to_weight_accounts.iter_mut() {
+            let find_pda_args = WeightAccountFindPdaArgs {
+                program_id: self.program_id,
+                lst_mint: *lst_mint,
+            };
+            let waa = match weight_account_opt {
+                Some(WeightAccount { bump, .. }) => WeightAccountCreatePdaArgs {
+                    find_pda_args,
+                    bump: *bump,
+                }
+                .get_weight_account_address()?,
+                None => find_pda_args.get_weight_account_address_and_bump_seed().0,
+            };
+            if let Some(acc) = account_map.get(&waa) {
+                *weight_account_opt = Some(*try_weight_account(&acc.data())?);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl PricingProg for TargetWeightPricingProg {
+    fn quote_lp_tokens_to_redeem(
+        &self,
+        _output_lst_mint: Pubkey,
+        pricing_programs_interface::PriceLpTokensToRedeemIxArgs { sol_value, .. }: &pricing_programs_interface::PriceLpTokensToRedeemIxArgs,
+    ) -> anyhow::Result<u64> {
+        let lp_withdrawal_fee_bps = self
+            .program_state
+            .ok_or(TargetWeightError::InvalidProgramStateData)?
+            .lp_withdrawal_fee_bps;
+        Ok(calculate_price_lp_tokens_to_redeem(
+            lp_withdrawal_fee_bps,
+            *sol_value,
+        )?)
+    }
+
+    fn price_lp_tokens_to_redeem_accounts(
+        &self,
+        output_lst_mint: Pubkey,
+    ) -> anyhow::Result<Vec<AccountMeta>> {
+        Ok(
+            <[AccountMeta; PRICE_LP_TOKENS_TO_REDEEM_IX_ACCOUNTS_LEN]>::from(
+                PriceLpTokensToRedeemFreeArgs { output_lst_mint }.resolve_for_prog(self.program_id),
+            )
+            .into(),
+        )
+    }
+
+    fn quote_lp_tokens_to_mint(
+        &self,
+        _input_lst_mint: Pubkey,
+        pricing_programs_interface::PriceLpTokensToMintIxArgs { sol_value, .. }: &pricing_programs_interface::PriceLpTokensToMintIxArgs,
+    ) -> anyhow::Result<u64> {
+        Ok(*sol_value)
+    }
+
+    fn price_lp_tokens_to_mint_accounts(
+        &self,
+        input_lst_mint: Pubkey,
+    ) -> anyhow::Result<Vec<AccountMeta>> {
+        Ok(
+            <[AccountMeta; PRICE_LP_TOKENS_TO_MINT_IX_ACCOUNTS_LEN]>::from(
+                PriceLpTokensToMintKeys { input_lst_mint },
+            )
+            .into(),
+        )
+    }
+
+    fn quote_exact_in(
+        &self,
+        pricing_programs_interface::PriceExactInKeys {
+            input_lst_mint,
+            output_lst_mint,
+        }: pricing_programs_interface::PriceExactInKeys,
+        pricing_programs_interface::PriceExactInIxArgs { sol_value, .. }: &pricing_programs_interface::PriceExactInIxArgs,
+    ) -> anyhow::Result<u64> {
+        let (fee_params, reserves) = self.swap_pricing_state(&input_lst_mint, &output_lst_mint)?;
+        Ok(calculate_price_exact_in(CalculatePriceExactInArgs {
+            fee_params,
+            reserves,
+            in_sol_value: *sol_value,
+        })?)
+    }
+
+    fn price_exact_in_accounts(
+        &self,
+        pricing_programs_interface::PriceExactInKeys {
+            input_lst_mint,
+            output_lst_mint,
+        }: pricing_programs_interface::PriceExactInKeys,
+    ) -> anyhow::Result<Vec<AccountMeta>> {
+        let (pool_state, lst_state_list) = self.pool_accounts();
+        let args = PriceExactInFreeArgs {
+            input_lst_mint,
+            output_lst_mint,
+            pool_state,
+            lst_state_list,
+        };
+        let keys: PriceExactInKeys = match self
+            .get_cached_weight_account_bumps(input_lst_mint, output_lst_mint)
+        {
+            Some((input_weight_acc_bump, output_weight_acc_bump)) => PriceExactInWithBumpFreeArgs {
+                args,
+                input_weight_acc_bump,
+                output_weight_acc_bump,
+            }
+            .resolve_for_prog(self.program_id)?,
+            None => args.resolve_for_prog(self.program_id),
+        };
+        Ok(<[AccountMeta; PRICE_EXACT_IN_IX_ACCOUNTS_LEN]>::from(keys).into())
+    }
+
+    fn quote_exact_out(
+        &self,
+        pricing_programs_interface::PriceExactOutKeys {
+            input_lst_mint,
+            output_lst_mint,
+        }: pricing_programs_interface::PriceExactOutKeys,
+        pricing_programs_interface::PriceExactOutIxArgs { sol_value, .. }: &pricing_programs_interface::PriceExactOutIxArgs,
+    ) -> anyhow::Result<u64> {
+        let (fee_params, reserves) = self.swap_pricing_state(&input_lst_mint, &output_lst_mint)?;
+        Ok(calculate_price_exact_out(CalculatePriceExactOutArgs {
+            fee_params,
+            reserves,
+            out_sol_value: *sol_value,
+        })?)
+    }
+
+    fn price_exact_out_accounts(
+        &self,
+        pricing_programs_interface::PriceExactOutKeys {
+            input_lst_mint,
+            output_lst_mint,
+        }: pricing_programs_interface::PriceExactOutKeys,
+    ) -> anyhow::Result<Vec<AccountMeta>> {
+        let (pool_state, lst_state_list) = self.pool_accounts();
+        let args = PriceExactOutFreeArgs {
+            input_lst_mint,
+            output_lst_mint,
+            pool_state,
+            lst_state_list,
+        };
+        let keys: PriceExactOutKeys =
+            match self.get_cached_weight_account_bumps(input_lst_mint, output_lst_mint) {
+                Some((input_weight_acc_bump, output_weight_acc_bump)) => {
+                    PriceExactOutWithBumpFreeArgs {
+                        args,
+                        input_weight_acc_bump,
+                        output_weight_acc_bump,
+                    }
+                    .resolve_for_prog(self.program_id)?
+                }
+                None => args.resolve_for_prog(self.program_id),
+            };
+        Ok(<[AccountMeta; PRICE_EXACT_OUT_IX_ACCOUNTS_LEN]>::from(keys).into())
+    }
+}
+
+impl TryFrom<KnownPricingProg> for TargetWeightPricingProg {
+    type Error = PricingProgErr;
+
+    fn try_from(value: KnownPricingProg) -> Result<Self, Self::Error> {
+        match value {
+            KnownPricingProg::TargetWeight(t) => Ok(t),
+            _ => Err(PricingProgErr::WrongPricingProg),
+        }
+    }
+}
@@ -0,0 +1,121 @@
+use borsh::BorshDeserialize;
+use everstake_calculator_interface::EverstakeStakePool;
+use everstake_calculator_lib::{
+    EverstakeSolValCalc, EverstakeStakePoolCalc, EVERSTAKE_LST_SOL_COMMON_INTERMEDIATE_KEYS,
+};
+use everstake_keys::{esol, eversol_stake_pool};
+use sanctum_token_ratio::{ReversibleRatio, U64ValueRange};
+use sol_value_calculator_lib::SolValueCalculator;
+use solana_program::{clock::Clock, instruction::AccountMeta, pubkey::Pubkey, sysvar};
+use solana_readonly_account::ReadonlyAccountData;
+use spl_calculator_lib::resolve_to_account_metas_for_calc;
+use std::{collections::HashMap, error::Error, fmt::Display};
+
+use crate::{KnownLstSolValCalc, LstSolValCalc, LstSolValCalcErr, MutableLstSolValCalc};
+
+#[derive(Clone, Debug, Default)]
+pub struct EverstakeLstSolValCalc {
+    pub calc: Option<EverstakeStakePoolCalc>,
+    pub clock: Option<Clock>,
+}
+
+impl MutableLstSolValCalc for EverstakeLstSolValCalc {
+    fn get_accounts_to_update(&self) -> Vec<Pubkey> {
+        vec![eversol_stake_pool::ID, sysvar::clock::ID]
+    }
+
+    fn update<D: ReadonlyAccountData>(
+        &mut self,
+        account_map: &HashMap<Pubkey, D>,
+    ) -> anyhow::Result<()> {
+        if let Some(acc) = account_map.get(&sysvar::clock::ID) {
+            self.clock = Some(bincode::deserialize::<Clock>(&acc.data())?);
+        }
+        if let Some(acc) = account_map.get(&eversol_stake_pool::ID) {
+            self.calc = Some(EverstakeStakePoolCalc::from(
+                EverstakeStakePool::deserialize(&mut acc.data().as_ref())?,
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl LstSolValCalc for EverstakeLstSolValCalc {
+    fn sol_value_calculator_program_id(&self) -> Pubkey {
+        everstake_calculator_lib::program::ID
+    }
+
+    fn lst_mint(&self) -> Pubkey {
+        esol::ID
+    }
+
+    fn lst_to_sol(&self, lst_amount: u64) -> anyhow::Result<U64ValueRange> {
+        let calc = self
+            .calc
+            .ok_or(EverstakeLstSolValCalcErr::StateNotFetched)?;
+        let clock = self
+            .clock
+            .as_ref()
+            .ok_or(EverstakeLstSolValCalcErr::ClockNotFetched)?;
+        calc.verify_pool_updated_for_this_epoch(clock)?;
+        Ok(calc.calc_lst_to_sol(lst_amount)?)
+    }
+
+    fn sol_to_lst(&self, lamports: u64) -> anyhow::Result<U64ValueRange> {
+        let calc = self
+            .calc
+            .ok_or(EverstakeLstSolValCalcErr::StateNotFetched)?;
+        let clock = self
+            .clock
+            .as_ref()
+            .ok_or(EverstakeLstSolValCalcErr::ClockNotFetched)?;
+        calc.verify_pool_updated_for_this_epoch(clock)?;
+        Ok(calc.calc_sol_to_lst(lamports)?)
+    }
+
+    fn lst_to_sol_before_withdrawal_fee(&self, lst_amount: u64) -> anyhow::Result<u64> {
+        let calc = self
+            .calc
+            .ok_or(EverstakeLstSolValCalcErr::StateNotFetched)?;
+        let clock = self
+            .clock
+            .as_ref()
+            .ok_or(EverstakeLstSolValCalcErr::ClockNotFetched)?;
+        calc.verify_pool_updated_for_this_epoch(clock)?;
+        Ok(calc.lst_to_lamports_ratio().apply(lst_amount)?)
+    }
+
+    fn ix_accounts(&self) -> Vec<AccountMeta> {
+        Vec::from(resolve_to_account_metas_for_calc::<EverstakeSolValCalc>(
+            EVERSTAKE_LST_SOL_COMMON_INTERMEDIATE_KEYS,
+        ))
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum EverstakeLstSolValCalcErr {
+    StateNotFetched,
+    ClockNotFetched,
+}
+
+impl Display for EverstakeLstSolValCalcErr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::StateNotFetched => f.write_str("everstake stake pool not yet fetched"),
+            Self::ClockNotFetched => f.write_str("clock not yet fetched"),
+        }
+    }
+}
+
+impl Error for EverstakeLstSolValCalcErr {}
+
+impl TryFrom<KnownLstSolValCalc> for EverstakeLstSolValCalc {
+    type Error = LstSolValCalcErr;
+
+    fn try_from(value: KnownLstSolValCalc) -> Result<Self, Self::Error> {
+        match value {
+            KnownLstSolValCalc::Everstake(s) => Ok(s),
+            _ => Err(LstSolValCalcErr::WrongLstSolValCalc),
+        }
+    }
+}
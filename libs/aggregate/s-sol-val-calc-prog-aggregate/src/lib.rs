@@ -6,37 +6,57 @@ use solana_readonly_account::ReadonlyAccountData;
 use std::collections::HashMap;
 
 mod err;
+mod everstake;
 mod lido;
 mod marinade;
+mod registry;
+mod s_pool;
 mod sanctum_spl;
+mod socean;
 mod spl;
+mod svsp;
 mod traits;
 mod wsol;
 
 pub use err::*;
+pub use everstake::*;
 pub use lido::*;
 pub use marinade::*;
+pub use registry::*;
+pub use s_pool::*;
 pub use sanctum_spl::*;
+pub use socean::*;
 pub use spl::*;
+pub use svsp::*;
 pub use traits::*;
 pub use wsol::*;
 
 #[derive(Debug, Clone)]
 pub enum KnownLstSolValCalc {
+    Everstake(EverstakeLstSolValCalc),
     Lido(LidoLstSolValCalc),
     Marinade(MarinadeLstSolValCalc),
     Spl(SplLstSolValCalc),
+    SPool(SPoolLstSolValCalc),
     SanctumSpl(SanctumSplLstSolValCalc),
+    Socean(SoceanLstSolValCalc),
+    Svsp(SvspLstSolValCalc),
     Wsol(WsolLstSolValCalc),
+    /// Calculator created at runtime by a [`SolValCalcRegistry`]
+    Dyn(Box<dyn DynLstSolValCalc>),
 }
 
 impl MutableLstSolValCalc for KnownLstSolValCalc {
     fn get_accounts_to_update(&self) -> Vec<Pubkey> {
         match self {
+            Self::Everstake(s) => s.get_accounts_to_update(),
             Self::Lido(s) => s.get_accounts_to_update(),
             Self::Marinade(s) => s.get_accounts_to_update(),
             Self::Spl(s) => s.get_accounts_to_update(),
+            Self::SPool(s) => s.get_accounts_to_update(),
             Self::SanctumSpl(s) => s.get_accounts_to_update(),
+            Self::Socean(s) => s.get_accounts_to_update(),
+            Self::Svsp(s) => s.get_accounts_to_update(),
             Self::Wsol(s) => s.get_accounts_to_update(),
         }
     }
@@ -46,11 +66,21 @@ impl MutableLstSolValCalc for KnownLstSolValCalc {
         account_map: &HashMap<Pubkey, D>,
     ) -> anyhow::Result<()> {
         match self {
+            Self::Everstake(s) => s.update(account_map),
             Self::Lido(s) => s.update(account_map),
             Self::Marinade(s) => s.update(account_map),
             Self::Spl(s) => s.update(account_map),
+            Self::SPool(s) => s.update(account_map),
             Self::SanctumSpl(s) => s.update(account_map),
+            Self::Socean(s) => s.update(account_map),
+            Self::Svsp(s) => s.update(account_map),
             Self::Wsol(s) => s.update(account_map),
+            Self::Dyn(s) => {
+                let keys = s.get_accounts_to_update();
+                with_account_data_map(account_map, keys, |account_data_map| {
+                    s.update(account_data_map)
+                })
+            }
         }
     }
 }
@@ -58,55 +88,101 @@ impl MutableLstSolValCalc for KnownLstSolValCalc {
 impl LstSolValCalc for KnownLstSolValCalc {
     fn sol_value_calculator_program_id(&self) -> Pubkey {
         match self {
+            Self::Everstake(s) => s.sol_value_calculator_program_id(),
             Self::Lido(s) => s.sol_value_calculator_program_id(),
             Self::Marinade(s) => s.sol_value_calculator_program_id(),
             Self::Spl(s) => s.sol_value_calculator_program_id(),
+            Self::SPool(s) => s.sol_value_calculator_program_id(),
             Self::SanctumSpl(s) => s.sol_value_calculator_program_id(),
+            Self::Socean(s) => s.sol_value_calculator_program_id(),
+            Self::Svsp(s) => s.sol_value_calculator_program_id(),
             Self::Wsol(s) => s.sol_value_calculator_program_id(),
+            Self::Dyn(s) => s.sol_value_calculator_program_id(),
         }
     }
 
     fn lst_mint(&self) -> Pubkey {
         match self {
+            Self::Everstake(s) => s.lst_mint(),
             Self::Lido(s) => s.lst_mint(),
             Self::Marinade(s) => s.lst_mint(),
             Self::Spl(s) => s.lst_mint(),
+            Self::SPool(s) => s.lst_mint(),
             Self::SanctumSpl(s) => s.lst_mint(),
+            Self::Socean(s) => s.lst_mint(),
+            Self::Svsp(s) => s.lst_mint(),
             Self::Wsol(s) => s.lst_mint(),
+            Self::Dyn(s) => s.lst_mint(),
         }
     }
 
     fn lst_to_sol(&self, lst_amount: u64) -> anyhow::Result<sanctum_token_ratio::U64ValueRange> {
         match self {
+            Self::Everstake(s) => s.lst_to_sol(lst_amount),
             Self::Lido(s) => s.lst_to_sol(lst_amount),
             Self::Marinade(s) => s.lst_to_sol(lst_amount),
             Self::Spl(s) => s.lst_to_sol(lst_amount),
+            Self::SPool(s) => s.lst_to_sol(lst_amount),
             Self::SanctumSpl(s) => s.lst_to_sol(lst_amount),
+            Self::Socean(s) => s.lst_to_sol(lst_amount),
+            Self::Svsp(s) => s.lst_to_sol(lst_amount),
             Self::Wsol(s) => s.lst_to_sol(lst_amount),
+            Self::Dyn(s) => s.lst_to_sol(lst_amount),
         }
     }
 
     fn sol_to_lst(&self, lamports: u64) -> anyhow::Result<sanctum_token_ratio::U64ValueRange> {
         match self {
+            Self::Everstake(s) => s.sol_to_lst(lamports),
             Self::Lido(s) => s.sol_to_lst(lamports),
             Self::Marinade(s) => s.sol_to_lst(lamports),
             Self::Spl(s) => s.sol_to_lst(lamports),
+            Self::SPool(s) => s.sol_to_lst(lamports),
             Self::SanctumSpl(s) => s.sol_to_lst(lamports),
+            Self::Socean(s) => s.sol_to_lst(lamports),
+            Self::Svsp(s) => s.sol_to_lst(lamports),
             Self::Wsol(s) => s.sol_to_lst(lamports),
+            Self::Dyn(s) => s.sol_to_lst(lamports),
+        }
+    }
+
+    fn lst_to_sol_before_withdrawal_fee(&self, lst_amount: u64) -> anyhow::Result<u64> {
+        match self {
+            Self::Everstake(s) => s.lst_to_sol_before_withdrawal_fee(lst_amount),
+            Self::Lido(s) => s.lst_to_sol_before_withdrawal_fee(lst_amount),
+            Self::Marinade(s) => s.lst_to_sol_before_withdrawal_fee(lst_amount),
+            Self::Spl(s) => s.lst_to_sol_before_withdrawal_fee(lst_amount),
+            Self::SPool(s) => s.lst_to_sol_before_withdrawal_fee(lst_amount),
+            Self::SanctumSpl(s) => s.lst_to_sol_before_withdrawal_fee(lst_amount),
+            Self::Socean(s) => s.lst_to_sol_before_withdrawal_fee(lst_amount),
+            Self::Svsp(s) => s.lst_to_sol_before_withdrawal_fee(lst_amount),
+            Self::Wsol(s) => s.lst_to_sol_before_withdrawal_fee(lst_amount),
+            Self::Dyn(s) => s.lst_to_sol_before_withdrawal_fee(lst_amount),
         }
     }
 
     fn ix_accounts(&self) -> Vec<AccountMeta> {
         match self {
+            Self::Everstake(s) => s.ix_accounts(),
             Self::Lido(s) => s.ix_accounts(),
             Self::Marinade(s) => s.ix_accounts(),
             Self::Spl(s) => s.ix_accounts(),
+            Self::SPool(s) => s.ix_accounts(),
             Self::SanctumSpl(s) => s.ix_accounts(),
+            Self::Socean(s) => s.ix_accounts(),
+            Self::Svsp(s) => s.ix_accounts(),
             Self::Wsol(s) => s.ix_accounts(),
+            Self::Dyn(s) => s.ix_accounts(),
         }
     }
 }
 
+impl From<EverstakeLstSolValCalc> for KnownLstSolValCalc {
+    fn from(value: EverstakeLstSolValCalc) -> Self {
+        Self::Everstake(value)
+    }
+}
+
 impl From<LidoLstSolValCalc> for KnownLstSolValCalc {
     fn from(value: LidoLstSolValCalc) -> Self {
         Self::Lido(value)
@@ -119,20 +195,44 @@ impl From<MarinadeLstSolValCalc> for KnownLstSolValCalc {
     }
 }
 
+impl From<SoceanLstSolValCalc> for KnownLstSolValCalc {
+    fn from(value: SoceanLstSolValCalc) -> Self {
+        Self::Socean(value)
+    }
+}
+
 impl From<SplLstSolValCalc> for KnownLstSolValCalc {
     fn from(value: SplLstSolValCalc) -> Self {
         Self::Spl(value)
     }
 }
 
+impl From<SPoolLstSolValCalc> for KnownLstSolValCalc {
+    fn from(value: SPoolLstSolValCalc) -> Self {
+        Self::SPool(value)
+    }
+}
+
 impl From<SanctumSplLstSolValCalc> for KnownLstSolValCalc {
     fn from(value: SanctumSplLstSolValCalc) -> Self {
         Self::SanctumSpl(value)
     }
 }
 
+impl From<SvspLstSolValCalc> for KnownLstSolValCalc {
+    fn from(value: SvspLstSolValCalc) -> Self {
+        Self::Svsp(value)
+    }
+}
+
 impl From<WsolLstSolValCalc> for KnownLstSolValCalc {
     fn from(value: WsolLstSolValCalc) -> Self {
         Self::Wsol(value)
     }
 }
+
+impl From<Box<dyn DynLstSolValCalc>> for KnownLstSolValCalc {
+    fn from(value: Box<dyn DynLstSolValCalc>) -> Self {
+        Self::Dyn(value)
+    }
+}
@@ -4,7 +4,7 @@ use marinade_calculator_lib::{
     MarinadeSolValCalc, MarinadeStateCalc, MARINADE_LST_SOL_COMMON_INTERMEDIATE_KEYS,
 };
 use marinade_keys::{marinade_state, msol};
-use sanctum_token_ratio::U64ValueRange;
+use sanctum_token_ratio::{MathError, ReversibleRatio, U64ValueRange};
 use sol_value_calculator_lib::SolValueCalculator;
 use solana_program::{instruction::AccountMeta, pubkey::Pubkey};
 use solana_readonly_account::ReadonlyAccountData;
@@ -57,6 +57,13 @@ impl LstSolValCalc for MarinadeLstSolValCalc {
         Ok(calc.calc_sol_to_lst(lamports)?)
     }
 
+    fn lst_to_sol_before_withdrawal_fee(&self, lst_amount: u64) -> anyhow::Result<u64> {
+        let calc = self.calc.ok_or(MarinadeLstSolValCalcErr::StateNotFetched)?;
+        calc.verify_can_withdraw_stake()?;
+        let ratio = calc.msol_to_sol_ratio().ok_or(MathError)?;
+        Ok(ratio.apply(lst_amount)?)
+    }
+
     fn ix_accounts(&self) -> Vec<AccountMeta> {
         Vec::from(resolve_to_account_metas_for_calc::<MarinadeSolValCalc>(
             MARINADE_LST_SOL_COMMON_INTERMEDIATE_KEYS,
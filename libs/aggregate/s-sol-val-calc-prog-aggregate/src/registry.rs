@@ -0,0 +1,132 @@
+use std::{collections::HashMap, fmt::Debug, sync::Arc};
+
+use anyhow::anyhow;
+use s_controller_interface::LstState;
+use solana_program::pubkey::Pubkey;
+use solana_readonly_account::ReadonlyAccountData;
+
+use crate::{KnownLstSolValCalc, LstSolValCalc};
+
+/// Data of fetched accounts, keyed by account pubkey
+pub type AccountDataMap<'a> = HashMap<Pubkey, &'a [u8]>;
+
+/// Object-safe counterpart of [`crate::MutableLstSolValCalc`] for SOL value calculators
+/// that are not known to this crate and are instead created at runtime by a [`SolValCalcRegistry`]
+pub trait DynLstSolValCalc: LstSolValCalc + Debug + Send + Sync {
+    fn get_accounts_to_update(&self) -> Vec<Pubkey>;
+
+    /// Same semantics as [`crate::MutableLstSolValCalc::update`]:
+    /// - no-ops if account to update is not in account_data_map
+    /// - errors if account exists but deserialization failed / other failure
+    fn update(&mut self, account_data_map: &AccountDataMap) -> anyhow::Result<()>;
+
+    fn clone_box(&self) -> Box<dyn DynLstSolValCalc>;
+}
+
+impl Clone for Box<dyn DynLstSolValCalc> {
+    fn clone(&self) -> Self {
+        self.clone_box()
+    }
+}
+
+/// Creates the calculator for a single LST from its [`LstState`] and whatever accounts have been fetched.
+///
+/// `account_data_map` may be empty, in which case the created calculator should request
+/// the accounts it needs through [`DynLstSolValCalc::get_accounts_to_update`]
+pub type SolValCalcFactory = Arc<
+    dyn Fn(&LstState, &AccountDataMap<'_>) -> anyhow::Result<Box<dyn DynLstSolValCalc>>
+        + Send
+        + Sync,
+>;
+
+/// Runtime registry of [`SolValCalcFactory`]s keyed by SOL value calculator program ID
+#[derive(Clone, Default)]
+pub struct SolValCalcRegistry {
+    factories: HashMap<Pubkey, SolValCalcFactory>,
+}
+
+impl Debug for SolValCalcRegistry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SolValCalcRegistry")
+            .field("programs", &self.factories.keys().collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+impl SolValCalcRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers `factory` for `sol_value_calculator_program_id`,
+    /// returning the previously registered factory, if any
+    pub fn register<F>(
+        &mut self,
+        sol_value_calculator_program_id: Pubkey,
+        factory: F,
+    ) -> Option<SolValCalcFactory>
+    where
+        F: Fn(&LstState, &AccountDataMap<'_>) -> anyhow::Result<Box<dyn DynLstSolValCalc>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.factories
+            .insert(sol_value_calculator_program_id, Arc::new(factory))
+    }
+
+    pub fn unregister(
+        &mut self,
+        sol_value_calculator_program_id: &Pubkey,
+    ) -> Option<SolValCalcFactory> {
+        self.factories.remove(sol_value_calculator_program_id)
+    }
+
+    pub fn contains(&self, sol_value_calculator_program_id: &Pubkey) -> bool {
+        self.factories.contains_key(sol_value_calculator_program_id)
+    }
+
+    /// Returns None if no factory is registered for `lst_state.sol_value_calculator`.
+    ///
+    /// Errors if the factory fails or creates a calculator for a different LST mint or program
+    pub fn create(
+        &self,
+        lst_state: &LstState,
+        account_data_map: &AccountDataMap,
+    ) -> Option<anyhow::Result<KnownLstSolValCalc>> {
+        let factory = self.factories.get(&lst_state.sol_value_calculator)?;
+        Some(factory(lst_state, account_data_map).and_then(|calc| {
+            if calc.sol_value_calculator_program_id() != lst_state.sol_value_calculator
+                || calc.lst_mint() != lst_state.mint
+            {
+                return Err(anyhow!(
+                    "factory for {} created calculator for mint {} program {}",
+                    lst_state.sol_value_calculator,
+                    calc.lst_mint(),
+                    calc.sol_value_calculator_program_id()
+                ));
+            }
+            Ok(KnownLstSolValCalc::Dyn(calc))
+        }))
+    }
+}
+
+/// Runs `f` with an [`AccountDataMap`] view of the entries of `account_map` whose keys are in `keys`
+pub fn with_account_data_map<D: ReadonlyAccountData, R>(
+    account_map: &HashMap<Pubkey, D>,
+    keys: impl IntoIterator<Item = Pubkey>,
+    f: impl FnOnce(&AccountDataMap) -> R,
+) -> R {
+    let account_datas: Vec<_> = keys
+        .into_iter()
+        .filter_map(|pubkey| account_map.get(&pubkey).map(|acc| (pubkey, acc.data())))
+        .collect();
+    let account_data_map = account_datas
+        .iter()
+        .map(|(pubkey, data)| {
+            let data: &[u8] = data;
+            (*pubkey, data)
+        })
+        .collect();
+    f(&account_data_map)
+}
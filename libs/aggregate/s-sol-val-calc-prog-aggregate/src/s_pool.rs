@@ -0,0 +1,122 @@
+use s_controller_lib::{try_pool_state, U8Bool};
+use s_pool_calculator_lib::{s_pool_sol_val_calc_account_metas, SPoolLpCalc};
+use sanctum_token_lib::mint_supply;
+use sanctum_token_ratio::U64ValueRange;
+use sol_value_calculator_lib::SolValueCalculator;
+use solana_program::{instruction::AccountMeta, pubkey::Pubkey};
+use solana_readonly_account::ReadonlyAccountData;
+use std::{collections::HashMap, error::Error, fmt::Display};
+
+use crate::{KnownLstSolValCalc, LstSolValCalc, LstSolValCalcErr, MutableLstSolValCalc};
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SPoolLstSolValCalc {
+    pub lst_mint: Pubkey,
+    pub pool_state_addr: Pubkey,
+    pub pool_total_sol_value: Option<u64>,
+    pub is_rebalancing: Option<bool>,
+    pub lp_token_supply: Option<u64>,
+}
+
+impl SPoolLstSolValCalc {
+    /// `lst_mint` is the LP token mint of the S pool at `pool_state_addr`
+    pub fn from_keys(pool_state_addr: Pubkey, lst_mint: Pubkey) -> Self {
+        Self {
+            lst_mint,
+            pool_state_addr,
+            ..Default::default()
+        }
+    }
+
+    pub fn calc(&self) -> Result<SPoolLpCalc, SPoolLstSolValCalcErr> {
+        Ok(SPoolLpCalc {
+            pool_total_sol_value: self
+                .pool_total_sol_value
+                .ok_or(SPoolLstSolValCalcErr::PoolStateNotFetched)?,
+            lp_token_supply: self
+                .lp_token_supply
+                .ok_or(SPoolLstSolValCalcErr::LpTokenMintNotFetched)?,
+            is_rebalancing: self
+                .is_rebalancing
+                .ok_or(SPoolLstSolValCalcErr::PoolStateNotFetched)?,
+        })
+    }
+}
+
+impl MutableLstSolValCalc for SPoolLstSolValCalc {
+    fn get_accounts_to_update(&self) -> Vec<Pubkey> {
+        vec![self.pool_state_addr, self.lst_mint]
+    }
+
+    fn update<D: ReadonlyAccountData>(
+        &mut self,
+        account_map: &HashMap<Pubkey, D>,
+    ) -> anyhow::Result<()> {
+        if let Some(acc) = account_map.get(&self.pool_state_addr) {
+            let pool_state = try_pool_state(&acc.data())?;
+            self.pool_total_sol_value = Some(pool_state.total_sol_value);
+            self.is_rebalancing = Some(U8Bool(pool_state.is_rebalancing).is_true());
+        }
+        if let Some(acc) = account_map.get(&self.lst_mint) {
+            self.lp_token_supply = Some(mint_supply(acc)?);
+        }
+        Ok(())
+    }
+}
+
+impl LstSolValCalc for SPoolLstSolValCalc {
+    fn sol_value_calculator_program_id(&self) -> Pubkey {
+        s_pool_calculator_lib::program::ID
+    }
+
+    fn lst_mint(&self) -> Pubkey {
+        self.lst_mint
+    }
+
+    fn lst_to_sol(&self, lst_amount: u64) -> anyhow::Result<U64ValueRange> {
+        let calc = self.calc()?;
+        calc.verify_pool_not_rebalancing()?;
+        Ok(calc.calc_lst_to_sol(lst_amount)?)
+    }
+
+    fn sol_to_lst(&self, lamports: u64) -> anyhow::Result<U64ValueRange> {
+        let calc = self.calc()?;
+        calc.verify_pool_not_rebalancing()?;
+        Ok(calc.calc_sol_to_lst(lamports)?)
+    }
+
+    fn ix_accounts(&self) -> Vec<AccountMeta> {
+        Vec::from(s_pool_sol_val_calc_account_metas(
+            self.pool_state_addr,
+            self.lst_mint,
+        ))
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum SPoolLstSolValCalcErr {
+    LpTokenMintNotFetched,
+    PoolStateNotFetched,
+}
+
+impl Display for SPoolLstSolValCalcErr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::LpTokenMintNotFetched => f.write_str("LP token mint not yet fetched"),
+            Self::PoolStateNotFetched => f.write_str("pool state not yet fetched"),
+        }
+    }
+}
+
+impl Error for SPoolLstSolValCalcErr {}
+
+impl TryFrom<KnownLstSolValCalc> for SPoolLstSolValCalc {
+    type Error = LstSolValCalcErr;
+
+    fn try_from(value: KnownLstSolValCalc) -> Result<Self, Self::Error> {
+        match value {
+            KnownLstSolValCalc::SPool(s) => Ok(s),
+            _ => Err(LstSolValCalcErr::WrongLstSolValCalc),
+        }
+    }
+}
@@ -1,6 +1,6 @@
 use generic_pool_calculator_interface::GenericPoolCalculatorError;
 use generic_pool_calculator_lib::account_resolvers::LstSolCommonIntermediateKeys;
-use sanctum_token_ratio::U64ValueRange;
+use sanctum_token_ratio::{ReversibleRatio, U64ValueRange};
 use sol_value_calculator_lib::SolValueCalculator;
 use solana_program::{
     instruction::AccountMeta,
@@ -109,6 +109,16 @@ impl LstSolValCalc for SanctumSplLstSolValCalc {
         Ok(calc.calc_sol_to_lst(lamports)?)
     }
 
+    fn lst_to_sol_before_withdrawal_fee(&self, lst_amount: u64) -> anyhow::Result<u64> {
+        let calc = self.calc.ok_or(SplLstSolValCalcErr::StakePoolNotFetched)?;
+        let clock = self
+            .clock
+            .as_ref()
+            .ok_or(SplLstSolValCalcErr::ClockNotFetched)?;
+        calc.verify_pool_updated_for_this_epoch(clock)?;
+        Ok(calc.lst_to_lamports_ratio().apply(lst_amount)?)
+    }
+
     fn ix_accounts(&self) -> Vec<AccountMeta> {
         Vec::from(resolve_to_account_metas_for_calc::<SanctumSplSolValCalc>(
             LstSolCommonIntermediateKeys {
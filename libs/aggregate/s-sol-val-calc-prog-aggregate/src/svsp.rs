@@ -0,0 +1,114 @@
+use sanctum_token_lib::mint_supply;
+use sanctum_token_ratio::U64ValueRange;
+use sol_value_calculator_lib::SolValueCalculator;
+use solana_program::{instruction::AccountMeta, pubkey::Pubkey};
+use solana_readonly_account::ReadonlyAccountData;
+use std::{collections::HashMap, error::Error, fmt::Display};
+use svsp_calculator_lib::{
+    find_svsp_pool_mint, find_svsp_pool_stake, read_pool_stake_delegation,
+    svsp_sol_val_calc_account_metas, SvspStakeCalc,
+};
+
+use crate::{KnownLstSolValCalc, LstSolValCalc, LstSolValCalcErr, MutableLstSolValCalc};
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SvspLstSolValCalc {
+    pub lst_mint: Pubkey,
+    pub pool_addr: Pubkey,
+    pub pool_stake_addr: Pubkey,
+    pub pool_stake: Option<u64>,
+    pub lst_supply: Option<u64>,
+}
+
+impl SvspLstSolValCalc {
+    /// All other accounts are PDAs of the SVSP pool
+    pub fn from_pool_addr(pool_addr: Pubkey) -> Self {
+        Self {
+            lst_mint: find_svsp_pool_mint(&pool_addr).0,
+            pool_addr,
+            pool_stake_addr: find_svsp_pool_stake(&pool_addr).0,
+            pool_stake: None,
+            lst_supply: None,
+        }
+    }
+
+    pub fn calc(&self) -> Result<SvspStakeCalc, SvspLstSolValCalcErr> {
+        Ok(SvspStakeCalc {
+            pool_stake: self
+                .pool_stake
+                .ok_or(SvspLstSolValCalcErr::PoolStakeNotFetched)?,
+            lst_supply: self
+                .lst_supply
+                .ok_or(SvspLstSolValCalcErr::LstMintNotFetched)?,
+        })
+    }
+}
+
+impl MutableLstSolValCalc for SvspLstSolValCalc {
+    fn get_accounts_to_update(&self) -> Vec<Pubkey> {
+        vec![self.lst_mint, self.pool_stake_addr]
+    }
+
+    fn update<D: ReadonlyAccountData>(
+        &mut self,
+        account_map: &HashMap<Pubkey, D>,
+    ) -> anyhow::Result<()> {
+        if let Some(acc) = account_map.get(&self.lst_mint) {
+            self.lst_supply = Some(mint_supply(acc)?);
+        }
+        if let Some(acc) = account_map.get(&self.pool_stake_addr) {
+            self.pool_stake = Some(read_pool_stake_delegation(acc)?);
+        }
+        Ok(())
+    }
+}
+
+impl LstSolValCalc for SvspLstSolValCalc {
+    fn sol_value_calculator_program_id(&self) -> Pubkey {
+        svsp_calculator_lib::program::ID
+    }
+
+    fn lst_mint(&self) -> Pubkey {
+        self.lst_mint
+    }
+
+    fn lst_to_sol(&self, lst_amount: u64) -> anyhow::Result<U64ValueRange> {
+        Ok(self.calc()?.calc_lst_to_sol(lst_amount)?)
+    }
+
+    fn sol_to_lst(&self, lamports: u64) -> anyhow::Result<U64ValueRange> {
+        Ok(self.calc()?.calc_sol_to_lst(lamports)?)
+    }
+
+    fn ix_accounts(&self) -> Vec<AccountMeta> {
+        Vec::from(svsp_sol_val_calc_account_metas(self.pool_addr))
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum SvspLstSolValCalcErr {
+    LstMintNotFetched,
+    PoolStakeNotFetched,
+}
+
+impl Display for SvspLstSolValCalcErr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::LstMintNotFetched => f.write_str("LST mint not yet fetched"),
+            Self::PoolStakeNotFetched => f.write_str("pool stake account not yet fetched"),
+        }
+    }
+}
+
+impl Error for SvspLstSolValCalcErr {}
+
+impl TryFrom<KnownLstSolValCalc> for SvspLstSolValCalc {
+    type Error = LstSolValCalcErr;
+
+    fn try_from(value: KnownLstSolValCalc) -> Result<Self, Self::Error> {
+        match value {
+            KnownLstSolValCalc::Svsp(s) => Ok(s),
+            _ => Err(LstSolValCalcErr::WrongLstSolValCalc),
+        }
+    }
+}
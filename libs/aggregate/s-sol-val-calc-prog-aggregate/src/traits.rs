@@ -59,6 +59,14 @@ pub trait LstSolValCalc {
     /// Returns LST value range of `lamports`
     fn sol_to_lst(&self, lamports: u64) -> anyhow::Result<U64ValueRange>;
 
+    /// Returns lamport value of `lst_amount` before the LST's stake pool
+    /// charges its withdrawal fee. [`Self::lst_to_sol`] is net of this fee.
+    ///
+    /// Defaults to the min of [`Self::lst_to_sol`] for LSTs without withdrawal fees
+    fn lst_to_sol_before_withdrawal_fee(&self, lst_amount: u64) -> anyhow::Result<u64> {
+        Ok(self.lst_to_sol(lst_amount)?.get_min())
+    }
+
     /// Returns the account inputs to the program's SolToLst and LstToSol
     /// instructions. Both should be the same.
     ///
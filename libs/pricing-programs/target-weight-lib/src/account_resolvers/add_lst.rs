@@ -0,0 +1,72 @@
+use solana_program::{pubkey::Pubkey, system_program};
+use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountPubkey};
+use target_weight_interface::{AddLstKeys, ProgramState, TargetWeightError};
+
+use crate::{
+    pda::{ProgramStateFindPdaArgs, WeightAccountCreatePdaArgs, WeightAccountFindPdaArgs},
+    program::{self, STATE_ID},
+    utils::try_program_state,
+};
+
+pub struct AddLstFreeArgs<S: ReadonlyAccountPubkey + ReadonlyAccountData> {
+    pub payer: Pubkey,
+    pub state_acc: S,
+    pub lst_mint: Pubkey,
+}
+
+impl<S: ReadonlyAccountPubkey + ReadonlyAccountData> AddLstFreeArgs<S> {
+    pub fn resolve(self) -> Result<(AddLstKeys, WeightAccountCreatePdaArgs), TargetWeightError> {
+        self.resolve_inner(STATE_ID, program::ID)
+    }
+
+    pub fn resolve_for_prog(
+        self,
+        program_id: Pubkey,
+    ) -> Result<(AddLstKeys, WeightAccountCreatePdaArgs), TargetWeightError> {
+        let state_id = ProgramStateFindPdaArgs { program_id }
+            .get_program_state_address_and_bump_seed()
+            .0;
+
+        self.resolve_inner(state_id, program_id)
+    }
+
+    pub fn resolve_inner(
+        self,
+        state_id: Pubkey,
+        program_id: Pubkey,
+    ) -> Result<(AddLstKeys, WeightAccountCreatePdaArgs), TargetWeightError> {
+        let Self {
+            payer,
+            state_acc,
+            lst_mint,
+        } = self;
+
+        if *state_acc.pubkey() != state_id {
+            return Err(TargetWeightError::IncorrectProgramState);
+        }
+
+        let bytes = &state_acc.data();
+        let state: &ProgramState = try_program_state(bytes)?;
+
+        let find_pda_args = WeightAccountFindPdaArgs {
+            lst_mint,
+            program_id,
+        };
+        let (weight_acc, bump) = find_pda_args.get_weight_account_address_and_bump_seed();
+
+        Ok((
+            AddLstKeys {
+                manager: state.manager,
+                payer,
+                weight_acc,
+                lst_mint,
+                state: state_id,
+                system_program: system_program::ID,
+            },
+            WeightAccountCreatePdaArgs {
+                find_pda_args,
+                bump,
+            },
+        ))
+    }
+}
@@ -0,0 +1,30 @@
+use solana_program::{pubkey::Pubkey, system_program};
+use target_weight_interface::InitializeKeys;
+
+use crate::{pda::ProgramStateFindPdaArgs, program as target_weight_program};
+
+pub struct InitializeFreeArgs {
+    pub payer: Pubkey,
+}
+
+impl InitializeFreeArgs {
+    pub fn resolve(&self) -> InitializeKeys {
+        self.resolve_inner(target_weight_program::STATE_ID)
+    }
+
+    pub fn resolve_for_prog(&self, program_id: Pubkey) -> InitializeKeys {
+        let state_id = ProgramStateFindPdaArgs { program_id }
+            .get_program_state_address_and_bump_seed()
+            .0;
+
+        self.resolve_inner(state_id)
+    }
+
+    fn resolve_inner(&self, state_id: Pubkey) -> InitializeKeys {
+        InitializeKeys {
+            payer: self.payer,
+            state: state_id,
+            system_program: system_program::ID,
+        }
+    }
+}
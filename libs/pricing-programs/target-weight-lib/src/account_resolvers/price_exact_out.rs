@@ -0,0 +1,123 @@
+use solana_program::{
+    instruction::AccountMeta,
+    pubkey::{Pubkey, PubkeyError},
+};
+use target_weight_interface::{PriceExactOutKeys, PRICE_EXACT_OUT_IX_ACCOUNTS_LEN};
+
+use crate::{
+    pda::{ProgramStateFindPdaArgs, WeightAccountCreatePdaArgs, WeightAccountFindPdaArgs},
+    program as target_weight_program,
+};
+
+/// Uses find_program_address, for use with
+/// - initial creation
+/// - client side
+pub struct PriceExactOutFreeArgs {
+    pub input_lst_mint: Pubkey,
+    pub output_lst_mint: Pubkey,
+
+    /// The pool state account set in the program state
+    pub pool_state: Pubkey,
+
+    /// The LST state list account set in the program state
+    pub lst_state_list: Pubkey,
+}
+
+impl PriceExactOutFreeArgs {
+    pub fn resolve(self) -> PriceExactOutKeys {
+        self.resolve_inner(target_weight_program::STATE_ID, target_weight_program::ID)
+    }
+
+    pub fn resolve_for_prog(self, program_id: Pubkey) -> PriceExactOutKeys {
+        let state_id = ProgramStateFindPdaArgs { program_id }
+            .get_program_state_address_and_bump_seed()
+            .0;
+
+        self.resolve_inner(state_id, program_id)
+    }
+
+    fn resolve_inner(self, state_id: Pubkey, program_id: Pubkey) -> PriceExactOutKeys {
+        let input_find_pda_args = WeightAccountFindPdaArgs {
+            lst_mint: self.input_lst_mint,
+            program_id,
+        };
+        let (input_weight_acc, _bump) =
+            input_find_pda_args.get_weight_account_address_and_bump_seed();
+
+        let output_find_pda_args = WeightAccountFindPdaArgs {
+            lst_mint: self.output_lst_mint,
+            program_id,
+        };
+        let (output_weight_acc, _bump) =
+            output_find_pda_args.get_weight_account_address_and_bump_seed();
+
+        PriceExactOutKeys {
+            input_lst_mint: self.input_lst_mint,
+            output_lst_mint: self.output_lst_mint,
+            state: state_id,
+            input_weight_acc,
+            output_weight_acc,
+            pool_state: self.pool_state,
+            lst_state_list: self.lst_state_list,
+        }
+    }
+
+    pub fn resolve_to_account_metas(self) -> [AccountMeta; PRICE_EXACT_OUT_IX_ACCOUNTS_LEN] {
+        let keys = self.resolve();
+        keys.into()
+    }
+}
+
+pub struct PriceExactOutWithBumpFreeArgs {
+    pub args: PriceExactOutFreeArgs,
+    pub input_weight_acc_bump: u8,
+    pub output_weight_acc_bump: u8,
+}
+
+impl PriceExactOutWithBumpFreeArgs {
+    pub fn resolve(self) -> Result<PriceExactOutKeys, PubkeyError> {
+        self.resolve_inner(target_weight_program::STATE_ID, target_weight_program::ID)
+    }
+
+    pub fn resolve_for_prog(self, program_id: Pubkey) -> Result<PriceExactOutKeys, PubkeyError> {
+        let state_id = ProgramStateFindPdaArgs { program_id }
+            .get_program_state_address_and_bump_seed()
+            .0;
+
+        self.resolve_inner(state_id, program_id)
+    }
+
+    fn resolve_inner(
+        self,
+        state_id: Pubkey,
+        program_id: Pubkey,
+    ) -> Result<PriceExactOutKeys, PubkeyError> {
+        let input_create_pda_args = WeightAccountCreatePdaArgs {
+            find_pda_args: WeightAccountFindPdaArgs {
+                lst_mint: self.args.input_lst_mint,
+                program_id,
+            },
+            bump: self.input_weight_acc_bump,
+        };
+        let input_weight_acc = input_create_pda_args.get_weight_account_address()?;
+
+        let output_create_pda_args = WeightAccountCreatePdaArgs {
+            find_pda_args: WeightAccountFindPdaArgs {
+                lst_mint: self.args.output_lst_mint,
+                program_id,
+            },
+            bump: self.output_weight_acc_bump,
+        };
+        let output_weight_acc = output_create_pda_args.get_weight_account_address()?;
+
+        Ok(PriceExactOutKeys {
+            input_lst_mint: self.args.input_lst_mint,
+            output_lst_mint: self.args.output_lst_mint,
+            state: state_id,
+            input_weight_acc,
+            output_weight_acc,
+            pool_state: self.args.pool_state,
+            lst_state_list: self.args.lst_state_list,
+        })
+    }
+}
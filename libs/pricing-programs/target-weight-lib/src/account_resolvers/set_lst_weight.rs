@@ -0,0 +1,103 @@
+use solana_program::pubkey::Pubkey;
+use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountPubkey};
+use target_weight_interface::{ProgramState, SetLstWeightKeys, TargetWeightError};
+
+use crate::{
+    pda::{ProgramStateFindPdaArgs, WeightAccountFindPdaArgs},
+    program as target_weight_program,
+    utils::try_program_state,
+};
+
+pub struct SetLstWeightByMintFreeArgs<S: ReadonlyAccountPubkey + ReadonlyAccountData> {
+    pub lst_mint: Pubkey,
+    pub state_acc: S,
+}
+
+impl<S: ReadonlyAccountPubkey + ReadonlyAccountData> SetLstWeightByMintFreeArgs<S> {
+    pub fn resolve(self) -> Result<SetLstWeightKeys, TargetWeightError> {
+        self.resolve_inner(target_weight_program::STATE_ID, target_weight_program::ID)
+    }
+
+    pub fn resolve_for_prog(
+        self,
+        program_id: Pubkey,
+    ) -> Result<SetLstWeightKeys, TargetWeightError> {
+        let state_id = ProgramStateFindPdaArgs { program_id }
+            .get_program_state_address_and_bump_seed()
+            .0;
+
+        self.resolve_inner(state_id, program_id)
+    }
+
+    fn resolve_inner(
+        self,
+        state_id: Pubkey,
+        program_id: Pubkey,
+    ) -> Result<SetLstWeightKeys, TargetWeightError> {
+        let SetLstWeightByMintFreeArgs {
+            lst_mint,
+            state_acc,
+        } = self;
+
+        if *state_acc.pubkey() != state_id {
+            return Err(TargetWeightError::IncorrectProgramState);
+        }
+
+        let find_pda_args = WeightAccountFindPdaArgs {
+            lst_mint,
+            program_id,
+        };
+        let (weight_acc, _bump) = find_pda_args.get_weight_account_address_and_bump_seed();
+
+        let bytes = &state_acc.data();
+        let state: &ProgramState = try_program_state(bytes)?;
+
+        Ok(SetLstWeightKeys {
+            manager: state.manager,
+            weight_acc,
+            state: state_id,
+        })
+    }
+}
+
+pub struct SetLstWeightFreeArgs<S: ReadonlyAccountPubkey + ReadonlyAccountData> {
+    pub weight_acc: Pubkey,
+    pub state_acc: S,
+}
+
+impl<S: ReadonlyAccountPubkey + ReadonlyAccountData> SetLstWeightFreeArgs<S> {
+    pub fn resolve(self) -> Result<SetLstWeightKeys, TargetWeightError> {
+        self.resolve_inner(target_weight_program::STATE_ID)
+    }
+
+    pub fn resolve_for_prog(
+        self,
+        program_id: Pubkey,
+    ) -> Result<SetLstWeightKeys, TargetWeightError> {
+        let state_id = ProgramStateFindPdaArgs { program_id }
+            .get_program_state_address_and_bump_seed()
+            .0;
+
+        self.resolve_inner(state_id)
+    }
+
+    fn resolve_inner(self, state_id: Pubkey) -> Result<SetLstWeightKeys, TargetWeightError> {
+        let SetLstWeightFreeArgs {
+            weight_acc,
+            state_acc,
+        } = self;
+
+        if *state_acc.pubkey() != state_id {
+            return Err(TargetWeightError::IncorrectProgramState);
+        }
+
+        let bytes = &state_acc.data();
+        let state: &ProgramState = try_program_state(bytes)?;
+
+        Ok(SetLstWeightKeys {
+            manager: state.manager,
+            weight_acc,
+            state: state_id,
+        })
+    }
+}
@@ -0,0 +1,46 @@
+use solana_program::pubkey::Pubkey;
+use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountPubkey};
+use target_weight_interface::{ProgramState, SetManagerKeys, TargetWeightError};
+
+use crate::{
+    pda::ProgramStateFindPdaArgs, program as target_weight_program, utils::try_program_state,
+};
+
+pub struct SetManagerFreeArgs<S: ReadonlyAccountPubkey + ReadonlyAccountData> {
+    pub new_manager: Pubkey,
+    pub state_acc: S,
+}
+
+impl<S: ReadonlyAccountPubkey + ReadonlyAccountData> SetManagerFreeArgs<S> {
+    pub fn resolve(self) -> Result<SetManagerKeys, TargetWeightError> {
+        self.resolve_inner(target_weight_program::STATE_ID)
+    }
+
+    pub fn resolve_for_prog(self, program_id: Pubkey) -> Result<SetManagerKeys, TargetWeightError> {
+        let state_id = ProgramStateFindPdaArgs { program_id }
+            .get_program_state_address_and_bump_seed()
+            .0;
+
+        self.resolve_inner(state_id)
+    }
+
+    fn resolve_inner(self, state_id: Pubkey) -> Result<SetManagerKeys, TargetWeightError> {
+        let SetManagerFreeArgs {
+            new_manager,
+            state_acc,
+        } = self;
+
+        if *state_acc.pubkey() != state_id {
+            return Err(TargetWeightError::IncorrectProgramState);
+        }
+
+        let bytes = &state_acc.data();
+        let state: &ProgramState = try_program_state(bytes)?;
+
+        Ok(SetManagerKeys {
+            current_manager: state.manager,
+            new_manager,
+            state: state_id,
+        })
+    }
+}
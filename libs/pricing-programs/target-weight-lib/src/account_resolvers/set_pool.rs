@@ -0,0 +1,46 @@
+use solana_program::pubkey::Pubkey;
+use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountPubkey};
+use target_weight_interface::{ProgramState, SetPoolKeys, TargetWeightError};
+
+use crate::{
+    pda::ProgramStateFindPdaArgs, program as target_weight_program, utils::try_program_state,
+};
+
+pub struct SetPoolFreeArgs<S: ReadonlyAccountPubkey + ReadonlyAccountData> {
+    pub pool_state: Pubkey,
+    pub state_acc: S,
+}
+
+impl<S: ReadonlyAccountPubkey + ReadonlyAccountData> SetPoolFreeArgs<S> {
+    pub fn resolve(self) -> Result<SetPoolKeys, TargetWeightError> {
+        self.resolve_inner(target_weight_program::STATE_ID)
+    }
+
+    pub fn resolve_for_prog(self, program_id: Pubkey) -> Result<SetPoolKeys, TargetWeightError> {
+        let state_id = ProgramStateFindPdaArgs { program_id }
+            .get_program_state_address_and_bump_seed()
+            .0;
+
+        self.resolve_inner(state_id)
+    }
+
+    fn resolve_inner(self, state_id: Pubkey) -> Result<SetPoolKeys, TargetWeightError> {
+        let SetPoolFreeArgs {
+            pool_state,
+            state_acc,
+        } = self;
+
+        if *state_acc.pubkey() != state_id {
+            return Err(TargetWeightError::IncorrectProgramState);
+        }
+
+        let bytes = &state_acc.data();
+        let state: &ProgramState = try_program_state(bytes)?;
+
+        Ok(SetPoolKeys {
+            manager: state.manager,
+            state: state_id,
+            pool_state,
+        })
+    }
+}
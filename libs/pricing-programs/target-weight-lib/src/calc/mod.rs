@@ -0,0 +1,12 @@
+mod common;
+mod price_exact_in;
+mod price_exact_out;
+mod price_lp_tokens_to_redeem;
+mod swap_fee;
+
+pub use price_exact_in::*;
+pub use price_exact_out::*;
+pub use price_lp_tokens_to_redeem::*;
+pub use swap_fee::*;
+
+const BPS_DENOMINATOR_I16: i16 = 10_000;
@@ -0,0 +1,33 @@
+use sanctum_token_ratio::ReversibleRatio;
+use target_weight_interface::TargetWeightError;
+
+use super::{
+    calculate_swap_fee_bps, common::out_sol_value_ratio, CalculateSwapFeeBpsArgs, SwapFeeParams,
+    SwapReserves,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CalculatePriceExactOutArgs {
+    pub fee_params: SwapFeeParams,
+    pub reserves: SwapReserves,
+    pub out_sol_value: u64,
+}
+
+/// Returns `in_sol_value`
+pub fn calculate_price_exact_out(
+    CalculatePriceExactOutArgs {
+        fee_params,
+        reserves,
+        out_sol_value,
+    }: CalculatePriceExactOutArgs,
+) -> Result<u64, TargetWeightError> {
+    let fee_bps = calculate_swap_fee_bps(CalculateSwapFeeBpsArgs {
+        fee_params,
+        reserves,
+        swap_sol_value: out_sol_value,
+    })?;
+    Ok(out_sol_value_ratio(fee_bps)?
+        .reverse(out_sol_value)
+        .map_err(|_e| TargetWeightError::MathError)?
+        .get_max())
+}
@@ -0,0 +1,109 @@
+use target_weight_interface::{ProgramState, TargetWeightError};
+
+const BPS_DENOMINATOR_I128: i128 = 10_000;
+
+/// The swap fee params of [`ProgramState`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SwapFeeParams {
+    pub base_fee_bps: i16,
+    pub min_fee_bps: i16,
+    pub max_fee_bps: i16,
+    pub imbalance_fee_bps: u16,
+}
+
+impl From<&ProgramState> for SwapFeeParams {
+    fn from(
+        ProgramState {
+            base_fee_bps,
+            min_fee_bps,
+            max_fee_bps,
+            imbalance_fee_bps,
+            ..
+        }: &ProgramState,
+    ) -> Self {
+        Self {
+            base_fee_bps: *base_fee_bps,
+            min_fee_bps: *min_fee_bps,
+            max_fee_bps: *max_fee_bps,
+            imbalance_fee_bps: *imbalance_fee_bps,
+        }
+    }
+}
+
+/// The pool's reserves of a single LST
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct LstReserves {
+    pub sol_value: u64,
+    pub target_weight_bps: u16,
+}
+
+/// State of the pool's reserves before a swap
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct SwapReserves {
+    pub pool_total_sol_value: u64,
+    pub input: LstReserves,
+    pub output: LstReserves,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CalculateSwapFeeBpsArgs {
+    pub fee_params: SwapFeeParams,
+    pub reserves: SwapReserves,
+    pub swap_sol_value: u64,
+}
+
+/// Returns the fee in bps to charge for a swap of `swap_sol_value`.
+///
+/// The weights of the input and output reserves are measured at the midpoint of the swap,
+/// after the input reserves have gained and the output reserves have lost half of `swap_sol_value`.
+/// The pool's total SOL value is assumed to stay unchanged over the swap.
+///
+/// - `input_deviation_bps = input_weight_bps - input.target_weight_bps`
+/// - `output_deviation_bps = output.target_weight_bps - output_weight_bps`
+/// - `fee_bps = base_fee_bps + imbalance_fee_bps * (input_deviation_bps + output_deviation_bps) / 10_000`,
+///   clamped to `[min_fee_bps, max_fee_bps]`
+///
+/// so fees rise when the swap pushes reserves away from their targets
+/// and fall, possibly below zero, when the swap restores balance.
+pub fn calculate_swap_fee_bps(
+    CalculateSwapFeeBpsArgs {
+        fee_params:
+            SwapFeeParams {
+                base_fee_bps,
+                min_fee_bps,
+                max_fee_bps,
+                imbalance_fee_bps,
+            },
+        reserves:
+            SwapReserves {
+                pool_total_sol_value,
+                input,
+                output,
+            },
+        swap_sol_value,
+    }: CalculateSwapFeeBpsArgs,
+) -> Result<i16, TargetWeightError> {
+    if min_fee_bps > max_fee_bps {
+        return Err(TargetWeightError::InvalidFeeParams);
+    }
+    if pool_total_sol_value == 0 {
+        return Ok(base_fee_bps.clamp(min_fee_bps, max_fee_bps));
+    }
+    // intermediate values are at most ~3 * u64::MAX * 10_000, no risk of i128 overflow
+    let double_total = i128::from(pool_total_sol_value) * 2;
+    let swap_sol_value = i128::from(swap_sol_value);
+    let input_weight_bps =
+        (i128::from(input.sol_value) * 2 + swap_sol_value) * BPS_DENOMINATOR_I128 / double_total;
+    let output_weight_bps =
+        (i128::from(output.sol_value) * 2 - swap_sol_value) * BPS_DENOMINATOR_I128 / double_total;
+    let input_deviation_bps = input_weight_bps - i128::from(input.target_weight_bps);
+    let output_deviation_bps = i128::from(output.target_weight_bps) - output_weight_bps;
+    let imbalance_adj_bps = i128::from(imbalance_fee_bps)
+        * (input_deviation_bps + output_deviation_bps)
+        / BPS_DENOMINATOR_I128;
+    let fee_bps = (i128::from(base_fee_bps) + imbalance_adj_bps)
+        .clamp(i128::from(min_fee_bps), i128::from(max_fee_bps));
+    fee_bps
+        .try_into()
+        .map_err(|_e| TargetWeightError::MathError)
+}
@@ -0,0 +1,51 @@
+use target_weight_interface::{SetFeeParamsIxArgs, TargetWeightError};
+
+use crate::MAX_TOTAL_TARGET_WEIGHT_BPS;
+
+const MAX_SIGNED_FEE_BPS: i16 = 10_000;
+const MAX_UNSIGNED_FEE_BPS: u16 = 10_000;
+
+pub fn verify_signed_fee_bps_bound(fee_bps_i16: i16) -> Result<(), TargetWeightError> {
+    if !(-MAX_SIGNED_FEE_BPS..=MAX_SIGNED_FEE_BPS).contains(&fee_bps_i16) {
+        return Err(TargetWeightError::SignedFeeOutOfBound);
+    }
+    Ok(())
+}
+
+pub fn verify_unsigned_fee_bps_bound(fee_bps_u16: u16) -> Result<(), TargetWeightError> {
+    if !(0..=MAX_UNSIGNED_FEE_BPS).contains(&fee_bps_u16) {
+        return Err(TargetWeightError::UnsignedFeeOutOfBound);
+    }
+    Ok(())
+}
+
+/// Verifies the fee params are each within bounds and
+/// that `min_fee_bps <= base_fee_bps <= max_fee_bps`
+pub fn verify_fee_params(
+    SetFeeParamsIxArgs {
+        lp_withdrawal_fee_bps,
+        base_fee_bps,
+        min_fee_bps,
+        max_fee_bps,
+        imbalance_fee_bps,
+    }: &SetFeeParamsIxArgs,
+) -> Result<(), TargetWeightError> {
+    verify_unsigned_fee_bps_bound(*lp_withdrawal_fee_bps)?;
+    verify_unsigned_fee_bps_bound(*imbalance_fee_bps)?;
+    verify_signed_fee_bps_bound(*base_fee_bps)?;
+    verify_signed_fee_bps_bound(*min_fee_bps)?;
+    verify_signed_fee_bps_bound(*max_fee_bps)?;
+    if !(*min_fee_bps..=*max_fee_bps).contains(base_fee_bps) {
+        return Err(TargetWeightError::InvalidFeeParams);
+    }
+    Ok(())
+}
+
+pub fn verify_total_target_weight_bound(
+    total_target_weight_bps: u16,
+) -> Result<(), TargetWeightError> {
+    if total_target_weight_bps > MAX_TOTAL_TARGET_WEIGHT_BPS {
+        return Err(TargetWeightError::TotalTargetWeightOutOfBound);
+    }
+    Ok(())
+}
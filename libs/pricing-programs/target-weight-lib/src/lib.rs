@@ -0,0 +1,55 @@
+pub mod account_resolvers;
+pub mod calc;
+pub mod fee_bound;
+pub mod pda;
+pub mod utils;
+
+pub mod program {
+    pub const STATE_SIZE: usize = 108;
+    pub const WEIGHT_ACCOUNT_SIZE: usize = 4;
+
+    static_assertions::const_assert_eq!(
+        std::mem::size_of::<target_weight_interface::ProgramState>(),
+        STATE_SIZE,
+    );
+
+    static_assertions::const_assert_eq!(
+        std::mem::size_of::<target_weight_interface::WeightAccount>(),
+        WEIGHT_ACCOUNT_SIZE,
+    );
+
+    sanctum_macros::declare_program_keys!(
+        "tw1rJvnnddRKUsvyBBDp8VdUjS2vqkyHp75GNaiYU76",
+        [("state", b"state")]
+    );
+}
+
+pub mod initial_constants {
+    pub mod initial_manager {
+        #[cfg(feature = "testing")]
+        sanctum_macros::declare_program_keys!("J5aMuYiKNHUzMTpUS85413DxxvDVjNXs63EXW5twG1Mx", []);
+
+        #[cfg(not(feature = "testing"))]
+        sanctum_macros::declare_program_keys!("CK9cEJT7K7oRrMCcEbBQRGqHLGpxKXWnKvW7nHSDMHD1", []);
+    }
+
+    pub const INITIAL_LP_WITHDRAWAL_FEE_BPS: u16 = 5;
+
+    pub const INITIAL_BASE_FEE_BPS: i16 = 1;
+
+    pub const INITIAL_MIN_FEE_BPS: i16 = 0;
+
+    pub const INITIAL_MAX_FEE_BPS: i16 = 100;
+
+    /// Fee added for a swap that moves reserves 100% away from their targets
+    pub const INITIAL_IMBALANCE_FEE_BPS: u16 = 100;
+}
+
+/// Sum of all LSTs' target weights may not exceed this
+pub const MAX_TOTAL_TARGET_WEIGHT_BPS: u16 = 10_000;
+
+// Compute Unit ceilings for instructions
+
+pub const ADD_LST_IX_COMPUTE_UNIT_CEIL: u32 = 30_000;
+
+pub const SET_FEE_PARAMS_COMPUTE_UNIT_CEIL: u32 = 10_000;
@@ -0,0 +1,40 @@
+use bytemuck::{try_from_bytes, try_from_bytes_mut};
+
+use s_controller_interface::LstState;
+use solana_program::pubkey::Pubkey;
+use target_weight_interface::{ProgramState, TargetWeightError, WeightAccount};
+
+pub fn try_program_state(
+    program_state_acc_data: &[u8],
+) -> Result<&ProgramState, TargetWeightError> {
+    try_from_bytes(program_state_acc_data).map_err(|_e| TargetWeightError::InvalidProgramStateData)
+}
+
+pub fn try_program_state_mut(
+    program_state_acc_data: &mut [u8],
+) -> Result<&mut ProgramState, TargetWeightError> {
+    try_from_bytes_mut(program_state_acc_data)
+        .map_err(|_e| TargetWeightError::InvalidProgramStateData)
+}
+
+pub fn try_weight_account(weight_acc_data: &[u8]) -> Result<&WeightAccount, TargetWeightError> {
+    try_from_bytes(weight_acc_data).map_err(|_e| TargetWeightError::UnsupportedLstMint)
+}
+
+pub fn try_weight_account_mut(
+    weight_acc_data: &mut [u8],
+) -> Result<&mut WeightAccount, TargetWeightError> {
+    try_from_bytes_mut(weight_acc_data).map_err(|_e| TargetWeightError::UnsupportedLstMint)
+}
+
+/// Returns the SOL value of the pool's reserves of `lst_mint`
+pub fn find_lst_sol_value(
+    lst_state_list: &[LstState],
+    lst_mint: &Pubkey,
+) -> Result<u64, TargetWeightError> {
+    lst_state_list
+        .iter()
+        .find(|lst_state| lst_state.mint == *lst_mint)
+        .map(|lst_state| lst_state.sol_value)
+        .ok_or(TargetWeightError::LstNotInPool)
+}
@@ -0,0 +1,158 @@
+use borsh::BorshDeserialize;
+use data_encoding::BASE64;
+use s_controller_lib::SControllerEvent;
+use solana_program::pubkey::Pubkey;
+
+use crate::DecodeEventError;
+
+const PROGRAM_LOG_PREFIX: &str = "Program ";
+const PROGRAM_DATA_LOG_PREFIX: &str = "Program data: ";
+const LOG_TRUNCATED: &str = "Log truncated";
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct DecodedEvent {
+    /// The pool_state of the pool the event was emitted for
+    pub pool_state: Pubkey,
+    pub event: SControllerEvent,
+}
+
+/// Decodes all events logged by `program_id` in a transaction's log messages,
+/// in the order they were logged.
+///
+/// The program invocation stack is tracked so that `Program data: ` log lines
+/// of other programs, including those invoked by `program_id`, are ignored.
+pub fn decode_events<S: AsRef<str>>(
+    program_id: &Pubkey,
+    log_messages: &[S],
+) -> Result<Vec<DecodedEvent>, DecodeEventError> {
+    let program_id = program_id.to_string();
+    let mut invoke_stack: Vec<&str> = Vec::new();
+    let mut res = Vec::new();
+    for log in log_messages.iter().map(AsRef::as_ref) {
+        if log == LOG_TRUNCATED {
+            return Err(DecodeEventError::LogsTruncated);
+        }
+        if let Some(data) = log.strip_prefix(PROGRAM_DATA_LOG_PREFIX) {
+            if invoke_stack.last() == Some(&program_id.as_str()) {
+                res.push(decode_program_data(data)?);
+            }
+            continue;
+        }
+        let mut words = match log.strip_prefix(PROGRAM_LOG_PREFIX) {
+            Some(rest) => rest.split(' '),
+            None => continue,
+        };
+        let (program, status) = match (words.next(), words.next()) {
+            // "Program log: ", "Program return: " etc
+            (Some(program), _) if program.ends_with(':') => continue,
+            (Some(program), Some(status)) => (program, status),
+            _ => continue,
+        };
+        if status == "invoke" {
+            invoke_stack.push(program);
+        } else if status == "success" || status.starts_with("failed") {
+            if invoke_stack.pop() != Some(program) {
+                return Err(DecodeEventError::UnbalancedInvokes);
+            }
+        }
+    }
+    Ok(res)
+}
+
+/// Decodes the space-separated base64 fields of a single `Program data: ` log line
+pub fn decode_program_data(data: &str) -> Result<DecodedEvent, DecodeEventError> {
+    let fields: Vec<&str> = data.split(' ').collect();
+    let (pool_state, event) = match fields.as_slice() {
+        [pool_state, event] => (*pool_state, *event),
+        _ => return Err(DecodeEventError::InvalidFieldCount(fields.len())),
+    };
+    let pool_state = BASE64
+        .decode(pool_state.as_bytes())
+        .map_err(|_e| DecodeEventError::InvalidBase64)?;
+    let pool_state =
+        Pubkey::try_from(pool_state.as_slice()).map_err(|_e| DecodeEventError::InvalidPoolState)?;
+    let event = BASE64
+        .decode(event.as_bytes())
+        .map_err(|_e| DecodeEventError::InvalidBase64)?;
+    let event = SControllerEvent::try_from_slice(&event)?;
+    Ok(DecodedEvent { pool_state, event })
+}
+
+#[cfg(test)]
+mod tests {
+    use borsh::BorshSerialize;
+
+    use super::*;
+
+    fn program_data_log(pool_state: &Pubkey, event: &SControllerEvent) -> String {
+        format!(
+            "{PROGRAM_DATA_LOG_PREFIX}{} {}",
+            BASE64.encode(pool_state.as_ref()),
+            BASE64.encode(&event.try_to_vec().unwrap())
+        )
+    }
+
+    #[test]
+    fn decode_only_program_events() {
+        let program_id = Pubkey::new_unique();
+        let other_program_id = Pubkey::new_unique();
+        let pool_state = Pubkey::new_unique();
+        let sync_event = SControllerEvent::SyncSolValue {
+            lst_mint: Pubkey::new_unique(),
+            old_sol_value: 1,
+            new_sol_value: 2,
+        };
+        let admin_event = SControllerEvent::SetAdmin {
+            admin: Pubkey::new_unique(),
+        };
+        let logs = [
+            format!("Program {program_id} invoke [1]"),
+            "Program log: Instruction: SyncSolValue".to_owned(),
+            format!("Program {other_program_id} invoke [2]"),
+            program_data_log(&pool_state, &admin_event),
+            "Program return: abc AQ==".to_owned(),
+            format!("Program {other_program_id} consumed 1000 of 190000 compute units"),
+            format!("Program {other_program_id} success"),
+            program_data_log(&pool_state, &sync_event),
+            format!("Program {program_id} consumed 10000 of 200000 compute units"),
+            format!("Program {program_id} success"),
+            format!("Program {other_program_id} invoke [1]"),
+            program_data_log(&pool_state, &admin_event),
+            format!("Program {other_program_id} success"),
+        ];
+        assert_eq!(
+            decode_events(&program_id, &logs).unwrap(),
+            vec![DecodedEvent {
+                pool_state,
+                event: sync_event,
+            }]
+        );
+    }
+
+    #[test]
+    fn decode_truncated_logs_fails() {
+        let program_id = Pubkey::new_unique();
+        let logs = [
+            format!("Program {program_id} invoke [1]"),
+            LOG_TRUNCATED.to_owned(),
+        ];
+        assert!(matches!(
+            decode_events(&program_id, &logs),
+            Err(DecodeEventError::LogsTruncated)
+        ));
+    }
+
+    #[test]
+    fn decode_invalid_program_data_fails() {
+        let program_id = Pubkey::new_unique();
+        let logs = [
+            format!("Program {program_id} invoke [1]"),
+            format!("{PROGRAM_DATA_LOG_PREFIX}AQ=="),
+            format!("Program {program_id} success"),
+        ];
+        assert!(matches!(
+            decode_events(&program_id, &logs),
+            Err(DecodeEventError::InvalidFieldCount(1))
+        ));
+    }
+}
@@ -0,0 +1,22 @@
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum DecodeEventError {
+    #[error("Program data log line has invalid base64")]
+    InvalidBase64,
+
+    #[error("Program data log line has {0} fields, expected 2")]
+    InvalidFieldCount(usize),
+
+    #[error("Program data log line has invalid pool state address")]
+    InvalidPoolState,
+
+    #[error("Program data log line has invalid event data: {0}")]
+    InvalidEventData(#[from] std::io::Error),
+
+    #[error("Program log messages are unbalanced")]
+    UnbalancedInvokes,
+
+    #[error("Program log messages were truncated")]
+    LogsTruncated,
+}
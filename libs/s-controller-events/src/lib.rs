@@ -0,0 +1,10 @@
+//! Decodes [`SControllerEvent`]s logged by the S controller program from transaction log messages.
+//! See [`s_controller_lib::SControllerEvent`] for the log format.
+
+mod decode;
+mod err;
+
+pub use decode::*;
+pub use err::*;
+
+pub use s_controller_lib::{LiquidityLstAmounts, SControllerEvent};
@@ -3,9 +3,7 @@ use solana_program::{pubkey::Pubkey, system_program};
 use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountPubkey};
 
 use crate::{
-    find_disable_pool_authority_list_address, find_pool_state_address,
-    program::{DISABLE_POOL_AUTHORITY_LIST_ID, POOL_STATE_ID},
-    try_pool_state,
+    find_disable_pool_authority_list_address, find_pool_state_address, try_pool_state, PoolPdas,
 };
 
 #[derive(Clone, Copy, Debug)]
@@ -17,7 +15,18 @@ pub struct AddDisablePoolAuthorityFreeArgs<S: ReadonlyAccountData + ReadonlyAcco
 
 impl<S: ReadonlyAccountData + ReadonlyAccountPubkey> AddDisablePoolAuthorityFreeArgs<S> {
     pub fn resolve(&self) -> Result<AddDisablePoolAuthorityKeys, SControllerError> {
-        if *self.pool_state_acc.pubkey() != POOL_STATE_ID {
+        self.resolve_for_pool(PoolPdas::DEFAULT)
+    }
+
+    pub fn resolve_for_pool(
+        &self,
+        PoolPdas {
+            pool_state: pool_state_id,
+            disable_pool_authority_list,
+            ..
+        }: PoolPdas,
+    ) -> Result<AddDisablePoolAuthorityKeys, SControllerError> {
+        if *self.pool_state_acc.pubkey() != pool_state_id {
             return Err(SControllerError::IncorrectPoolState);
         }
 
@@ -27,9 +36,9 @@ impl<S: ReadonlyAccountData + ReadonlyAccountPubkey> AddDisablePoolAuthorityFree
         Ok(AddDisablePoolAuthorityKeys {
             payer: self.payer,
             admin: pool_state.admin,
-            pool_state: POOL_STATE_ID,
+            pool_state: pool_state_id,
             new_authority: self.new_authority,
-            disable_pool_authority_list: DISABLE_POOL_AUTHORITY_LIST_ID,
+            disable_pool_authority_list,
             system_program: system_program::ID,
         })
     }
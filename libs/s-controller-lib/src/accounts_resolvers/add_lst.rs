@@ -5,9 +5,7 @@ use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountOwner, Readonl
 use crate::{
     find_lst_state_list_address, find_pool_reserves_address_with_pool_state_id,
     find_pool_state_address, find_protocol_fee_accumulator_address_with_protocol_fee_id,
-    find_protocol_fee_address,
-    program::{LST_STATE_LIST_ID, POOL_STATE_ID, PROTOCOL_FEE_ID},
-    try_pool_state, FindLstPdaAtaKeys,
+    find_protocol_fee_address, try_pool_state, FindLstPdaAtaKeys, PoolPdas,
 };
 
 #[derive(Clone, Copy, Debug)]
@@ -36,13 +34,25 @@ impl<
     > AddLstFreeArgs<S, M>
 {
     pub fn resolve(self) -> Result<(AddLstKeys, LstStateBumps), SControllerError> {
-        if *self.pool_state.pubkey() != POOL_STATE_ID {
+        self.resolve_for_pool(PoolPdas::DEFAULT)
+    }
+
+    pub fn resolve_for_pool(
+        self,
+        PoolPdas {
+            pool_state,
+            lst_state_list,
+            protocol_fee,
+            ..
+        }: PoolPdas,
+    ) -> Result<(AddLstKeys, LstStateBumps), SControllerError> {
+        if *self.pool_state.pubkey() != pool_state {
             return Err(SControllerError::IncorrectPoolState);
         }
         self.resolve_inner(ResolveInner {
-            pool_state: POOL_STATE_ID,
-            protocol_fee_accumulator_auth: PROTOCOL_FEE_ID,
-            lst_state_list: LST_STATE_LIST_ID,
+            pool_state,
+            protocol_fee_accumulator_auth: protocol_fee,
+            lst_state_list,
         })
     }
 }
@@ -76,6 +86,9 @@ impl<S: ReadonlyAccountData, M: ReadonlyAccountOwner + ReadonlyAccountPubkey> Ad
 
         let pool_state_data = pool_state_acc.data();
         let pool_state_data = try_pool_state(&pool_state_data)?;
+        if *lst_mint.pubkey() == pool_state_data.lp_token_mint {
+            return Err(SControllerError::LpTokenAsLst);
+        }
 
         let find_pda_keys = FindLstPdaAtaKeys {
             lst_mint: *lst_mint.pubkey(),
@@ -0,0 +1,38 @@
+use s_controller_interface::CancelPendingChangeKeys;
+use solana_program::pubkey::Pubkey;
+
+use crate::PoolPdas;
+
+#[derive(Clone, Copy, Debug)]
+pub struct CancelPendingChangeFreeArgs {
+    pub signer: Pubkey,
+    pub refund_rent_to: Pubkey,
+}
+
+impl CancelPendingChangeFreeArgs {
+    pub fn resolve(&self) -> CancelPendingChangeKeys {
+        self.resolve_for_pool(PoolPdas::DEFAULT)
+    }
+
+    pub fn resolve_for_pool(
+        &self,
+        PoolPdas {
+            pool_state,
+            pending_change_list,
+            disable_pool_authority_list,
+            ..
+        }: PoolPdas,
+    ) -> CancelPendingChangeKeys {
+        CancelPendingChangeKeys {
+            signer: self.signer,
+            refund_rent_to: self.refund_rent_to,
+            pool_state,
+            pending_change_list,
+            disable_pool_authority_list,
+        }
+    }
+
+    pub fn resolve_for_prog(&self, program_id: Pubkey) -> CancelPendingChangeKeys {
+        self.resolve_for_pool(PoolPdas::find_for_program_id(program_id))
+    }
+}
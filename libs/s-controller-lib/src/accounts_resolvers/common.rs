@@ -1,6 +1,8 @@
 use solana_program::pubkey::Pubkey;
 
-use crate::{find_lst_state_list_address, find_pool_state_address, find_protocol_fee_address};
+use crate::{
+    find_lst_state_list_address, find_pool_state_address, find_protocol_fee_address, PoolPdas,
+};
 
 #[derive(Clone, Copy, Debug)]
 pub struct SwapLiquidityPdas {
@@ -18,3 +20,20 @@ impl SwapLiquidityPdas {
         }
     }
 }
+
+impl From<PoolPdas> for SwapLiquidityPdas {
+    fn from(
+        PoolPdas {
+            pool_state,
+            lst_state_list,
+            protocol_fee,
+            ..
+        }: PoolPdas,
+    ) -> Self {
+        Self {
+            pool_state,
+            lst_state_list,
+            protocol_fee,
+        }
+    }
+}
@@ -5,9 +5,8 @@ use solana_program::pubkey::Pubkey;
 use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountPubkey};
 
 use crate::{
-    find_lst_state_list_address, find_pool_state_address,
-    program::{LST_STATE_LIST_ID, POOL_STATE_ID},
-    try_find_lst_mint_on_list, try_lst_state_list, try_pool_state,
+    find_lst_state_list_address, find_pool_state_address, try_find_lst_mint_on_list,
+    try_lst_state_list, try_pool_state, PoolPdas,
 };
 
 struct DisableEnableLstInputComputedKeys {
@@ -29,16 +28,19 @@ impl<
         L: ReadonlyAccountData + ReadonlyAccountPubkey,
     > DisableEnableLstInputFreeArgs<S, L>
 {
-    fn compute_keys(&self) -> Result<DisableEnableLstInputComputedKeys, SControllerError> {
+    fn compute_keys(
+        &self,
+        pdas: &PoolPdas,
+    ) -> Result<DisableEnableLstInputComputedKeys, SControllerError> {
         let Self {
             lst_index,
             pool_state: pool_state_account,
             lst_state_list: lst_state_list_account,
         } = self;
-        if *pool_state_account.pubkey() != POOL_STATE_ID {
+        if *pool_state_account.pubkey() != pdas.pool_state {
             return Err(SControllerError::IncorrectPoolState);
         }
-        if *lst_state_list_account.pubkey() != LST_STATE_LIST_ID {
+        if *lst_state_list_account.pubkey() != pdas.lst_state_list {
             return Err(SControllerError::IncorrectLstStateList);
         }
 
@@ -59,22 +61,36 @@ impl<
     }
 
     pub fn resolve_disable(&self) -> Result<DisableLstInputKeys, SControllerError> {
-        let DisableEnableLstInputComputedKeys { admin, lst_mint } = self.compute_keys()?;
+        self.resolve_disable_for_pool(PoolPdas::DEFAULT)
+    }
+
+    pub fn resolve_disable_for_pool(
+        &self,
+        pdas: PoolPdas,
+    ) -> Result<DisableLstInputKeys, SControllerError> {
+        let DisableEnableLstInputComputedKeys { admin, lst_mint } = self.compute_keys(&pdas)?;
         Ok(DisableLstInputKeys {
             admin,
             lst_mint,
-            pool_state: POOL_STATE_ID,
-            lst_state_list: LST_STATE_LIST_ID,
+            pool_state: pdas.pool_state,
+            lst_state_list: pdas.lst_state_list,
         })
     }
 
     pub fn resolve_enable(&self) -> Result<EnableLstInputKeys, SControllerError> {
-        let DisableEnableLstInputComputedKeys { admin, lst_mint } = self.compute_keys()?;
+        self.resolve_enable_for_pool(PoolPdas::DEFAULT)
+    }
+
+    pub fn resolve_enable_for_pool(
+        &self,
+        pdas: PoolPdas,
+    ) -> Result<EnableLstInputKeys, SControllerError> {
+        let DisableEnableLstInputComputedKeys { admin, lst_mint } = self.compute_keys(&pdas)?;
         Ok(EnableLstInputKeys {
             admin,
             lst_mint,
-            pool_state: POOL_STATE_ID,
-            lst_state_list: LST_STATE_LIST_ID,
+            pool_state: pdas.pool_state,
+            lst_state_list: pdas.lst_state_list,
         })
     }
 }
@@ -117,6 +133,14 @@ impl<S: ReadonlyAccountData, L: ReadonlyAccountData> DisableEnableLstInputByMint
 
     /// Returns (keys, index of lst_mint in lst_state_list)
     pub fn resolve_disable(&self) -> Result<(DisableLstInputKeys, usize), SControllerError> {
+        self.resolve_disable_for_pool(PoolPdas::DEFAULT)
+    }
+
+    /// Returns (keys, index of lst_mint in lst_state_list)
+    pub fn resolve_disable_for_prog(
+        &self,
+        program_id: Pubkey,
+    ) -> Result<(DisableLstInputKeys, usize), SControllerError> {
         let (DisableEnableLstInputComputedKeys { admin, lst_mint }, lst_index) =
             self.compute_keys_and_index()?;
 
@@ -124,17 +148,21 @@ impl<S: ReadonlyAccountData, L: ReadonlyAccountData> DisableEnableLstInputByMint
             DisableLstInputKeys {
                 admin,
                 lst_mint,
-                pool_state: POOL_STATE_ID,
-                lst_state_list: LST_STATE_LIST_ID,
+                pool_state: find_pool_state_address(program_id).0,
+                lst_state_list: find_lst_state_list_address(program_id).0,
             },
             lst_index,
         ))
     }
 
     /// Returns (keys, index of lst_mint in lst_state_list)
-    pub fn resolve_disable_for_prog(
+    pub fn resolve_disable_for_pool(
         &self,
-        program_id: Pubkey,
+        PoolPdas {
+            pool_state,
+            lst_state_list,
+            ..
+        }: PoolPdas,
     ) -> Result<(DisableLstInputKeys, usize), SControllerError> {
         let (DisableEnableLstInputComputedKeys { admin, lst_mint }, lst_index) =
             self.compute_keys_and_index()?;
@@ -143,8 +171,8 @@ impl<S: ReadonlyAccountData, L: ReadonlyAccountData> DisableEnableLstInputByMint
             DisableLstInputKeys {
                 admin,
                 lst_mint,
-                pool_state: find_pool_state_address(program_id).0,
-                lst_state_list: find_lst_state_list_address(program_id).0,
+                pool_state,
+                lst_state_list,
             },
             lst_index,
         ))
@@ -152,6 +180,18 @@ impl<S: ReadonlyAccountData, L: ReadonlyAccountData> DisableEnableLstInputByMint
 
     /// Returns (keys, index of lst_mint in lst_state_list)
     pub fn resolve_enable(&self) -> Result<(EnableLstInputKeys, usize), SControllerError> {
+        self.resolve_enable_for_pool(PoolPdas::DEFAULT)
+    }
+
+    /// Returns (keys, index of lst_mint in lst_state_list)
+    pub fn resolve_enable_for_pool(
+        &self,
+        PoolPdas {
+            pool_state,
+            lst_state_list,
+            ..
+        }: PoolPdas,
+    ) -> Result<(EnableLstInputKeys, usize), SControllerError> {
         let (DisableEnableLstInputComputedKeys { admin, lst_mint }, lst_index) =
             self.compute_keys_and_index()?;
 
@@ -159,8 +199,8 @@ impl<S: ReadonlyAccountData, L: ReadonlyAccountData> DisableEnableLstInputByMint
             EnableLstInputKeys {
                 admin,
                 lst_mint,
-                pool_state: POOL_STATE_ID,
-                lst_state_list: LST_STATE_LIST_ID,
+                pool_state,
+                lst_state_list,
             },
             lst_index,
         ))
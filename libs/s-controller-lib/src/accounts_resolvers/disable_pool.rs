@@ -1,10 +1,7 @@
 use s_controller_interface::DisablePoolKeys;
 use solana_program::pubkey::Pubkey;
 
-use crate::{
-    find_disable_pool_authority_list_address, find_pool_state_address,
-    program::{DISABLE_POOL_AUTHORITY_LIST_ID, POOL_STATE_ID},
-};
+use crate::{find_disable_pool_authority_list_address, find_pool_state_address, PoolPdas};
 
 #[derive(Clone, Copy, Debug)]
 pub struct DisablePoolFreeArgs {
@@ -13,7 +10,18 @@ pub struct DisablePoolFreeArgs {
 
 impl DisablePoolFreeArgs {
     pub fn resolve(&self) -> DisablePoolKeys {
-        self.resolve_inner(POOL_STATE_ID, DISABLE_POOL_AUTHORITY_LIST_ID)
+        self.resolve_for_pool(PoolPdas::DEFAULT)
+    }
+
+    pub fn resolve_for_pool(
+        &self,
+        PoolPdas {
+            pool_state,
+            disable_pool_authority_list,
+            ..
+        }: PoolPdas,
+    ) -> DisablePoolKeys {
+        self.resolve_inner(pool_state, disable_pool_authority_list)
     }
 
     pub fn resolve_for_prog(&self, program_id: Pubkey) -> DisablePoolKeys {
@@ -2,7 +2,7 @@ use s_controller_interface::{EnablePoolKeys, SControllerError};
 use solana_program::pubkey::Pubkey;
 use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountPubkey};
 
-use crate::{find_pool_state_address, program::POOL_STATE_ID, try_pool_state};
+use crate::{find_pool_state_address, try_pool_state, PoolPdas};
 
 #[derive(Clone, Copy, Debug)]
 pub struct EnablePoolFreeArgs<S> {
@@ -11,10 +11,17 @@ pub struct EnablePoolFreeArgs<S> {
 
 impl<S: ReadonlyAccountData + ReadonlyAccountPubkey> EnablePoolFreeArgs<S> {
     pub fn resolve(&self) -> Result<EnablePoolKeys, SControllerError> {
-        if *self.pool_state_acc.pubkey() != POOL_STATE_ID {
+        self.resolve_for_pool(PoolPdas::DEFAULT)
+    }
+
+    pub fn resolve_for_pool(
+        &self,
+        PoolPdas { pool_state, .. }: PoolPdas,
+    ) -> Result<EnablePoolKeys, SControllerError> {
+        if *self.pool_state_acc.pubkey() != pool_state {
             return Err(SControllerError::IncorrectPoolState);
         }
-        self.resolve_with_pool_state_id(POOL_STATE_ID)
+        self.resolve_with_pool_state_id(pool_state)
     }
 }
 
@@ -4,9 +4,8 @@ use s_controller_interface::{
 use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountOwner, ReadonlyAccountPubkey};
 
 use crate::{
-    create_pool_reserves_address, index_to_usize,
-    program::{LST_STATE_LIST_ID, POOL_STATE_ID, REBALANCE_RECORD_ID},
-    try_lst_state_list, try_match_lst_mint_on_list, try_pool_state, try_rebalance_record,
+    create_pool_reserves_address_with_pool_state_id, index_to_usize, try_lst_state_list,
+    try_match_lst_mint_on_list, try_pool_state, try_rebalance_record, PoolPdas,
 };
 
 /// Requires an existing rebalance_record account.
@@ -28,13 +27,27 @@ impl<
 {
     /// Returns (keys, dst_lst_index)
     pub fn resolve(self) -> Result<(EndRebalanceKeys, usize), SControllerError> {
-        if *self.pool_state.pubkey() != POOL_STATE_ID {
+        self.resolve_for_pool(PoolPdas::DEFAULT)
+    }
+
+    /// Returns (keys, dst_lst_index)
+    pub fn resolve_for_pool(
+        self,
+        PoolPdas {
+            pool_state: pool_state_id,
+            lst_state_list: lst_state_list_id,
+            rebalance_record: rebalance_record_id,
+            rebalance_loss_state: rebalance_loss_state_id,
+            ..
+        }: PoolPdas,
+    ) -> Result<(EndRebalanceKeys, usize), SControllerError> {
+        if *self.pool_state.pubkey() != pool_state_id {
             return Err(SControllerError::IncorrectPoolState);
         }
-        if *self.lst_state_list.pubkey() != LST_STATE_LIST_ID {
+        if *self.lst_state_list.pubkey() != lst_state_list_id {
             return Err(SControllerError::IncorrectLstStateList);
         }
-        if *self.rebalance_record.pubkey() != REBALANCE_RECORD_ID {
+        if *self.rebalance_record.pubkey() != rebalance_record_id {
             return Err(SControllerError::IncorrectRebalanceRecord);
         }
 
@@ -51,17 +64,21 @@ impl<
 
         let dst_lst_state =
             try_match_lst_mint_on_list(*self.dst_lst_mint.pubkey(), list, dst_lst_index)?;
-        let dst_pool_reserves =
-            create_pool_reserves_address(dst_lst_state, *self.dst_lst_mint.owner())?;
+        let dst_pool_reserves = create_pool_reserves_address_with_pool_state_id(
+            pool_state_id,
+            dst_lst_state,
+            *self.dst_lst_mint.owner(),
+        )?;
 
         Ok((
             EndRebalanceKeys {
                 rebalance_authority: pool_state.rebalance_authority,
                 dst_lst_mint: dst_lst_state.mint,
                 dst_pool_reserves,
-                pool_state: POOL_STATE_ID,
-                lst_state_list: LST_STATE_LIST_ID,
-                rebalance_record: REBALANCE_RECORD_ID,
+                pool_state: pool_state_id,
+                lst_state_list: lst_state_list_id,
+                rebalance_record: rebalance_record_id,
+                rebalance_loss_state: rebalance_loss_state_id,
             },
             dst_lst_index,
         ))
@@ -85,6 +102,7 @@ impl<'a> EndRebalanceFromStartRebalanceKeys<'a> {
             pool_state,
             lst_state_list,
             rebalance_record,
+            rebalance_loss_state,
             ..
         }) = self;
         EndRebalanceKeys {
@@ -94,6 +112,7 @@ impl<'a> EndRebalanceFromStartRebalanceKeys<'a> {
             pool_state: *pool_state,
             lst_state_list: *lst_state_list,
             rebalance_record: *rebalance_record,
+            rebalance_loss_state: *rebalance_loss_state,
         }
     }
 }
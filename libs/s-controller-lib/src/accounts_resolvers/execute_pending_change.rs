@@ -0,0 +1,37 @@
+use s_controller_interface::ExecutePendingChangeKeys;
+use solana_program::pubkey::Pubkey;
+
+use crate::PoolPdas;
+
+/// The accounts of the direct instruction that performs the pending change
+/// must be appended to the resolved instruction,
+/// see [`crate::execute_pending_change_ix_full`]
+#[derive(Clone, Copy, Debug)]
+pub struct ExecutePendingChangeFreeArgs {
+    pub refund_rent_to: Pubkey,
+}
+
+impl ExecutePendingChangeFreeArgs {
+    pub fn resolve(&self) -> ExecutePendingChangeKeys {
+        self.resolve_for_pool(PoolPdas::DEFAULT)
+    }
+
+    pub fn resolve_for_pool(
+        &self,
+        PoolPdas {
+            pool_state,
+            pending_change_list,
+            ..
+        }: PoolPdas,
+    ) -> ExecutePendingChangeKeys {
+        ExecutePendingChangeKeys {
+            refund_rent_to: self.refund_rent_to,
+            pool_state,
+            pending_change_list,
+        }
+    }
+
+    pub fn resolve_for_prog(&self, program_id: Pubkey) -> ExecutePendingChangeKeys {
+        self.resolve_for_pool(PoolPdas::find_for_program_id(program_id))
+    }
+}
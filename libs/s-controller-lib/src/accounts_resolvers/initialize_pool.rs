@@ -0,0 +1,66 @@
+use s_controller_interface::{InitializePoolKeys, SControllerError};
+use solana_program::{pubkey::Pubkey, system_program};
+use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountPubkey};
+
+use crate::{
+    find_pool_state_address, find_pool_state_address_for_pool, program::POOL_STATE_ID,
+    try_pool_state, PoolId,
+};
+
+#[derive(Clone, Copy, Debug)]
+pub struct InitializePoolFreeArgs<S> {
+    pub payer: Pubkey,
+    pub lp_token_mint: Pubkey,
+    pub default_pool_state: S,
+}
+
+impl<S: ReadonlyAccountData + ReadonlyAccountPubkey> InitializePoolFreeArgs<S> {
+    /// Returns (keys, new pool_state PDA bump)
+    pub fn resolve(self) -> Result<(InitializePoolKeys, u8), SControllerError> {
+        if *self.default_pool_state.pubkey() != POOL_STATE_ID {
+            return Err(SControllerError::IncorrectPoolState);
+        }
+        self.resolve_inner(crate::program::ID, POOL_STATE_ID)
+    }
+}
+
+impl<S: ReadonlyAccountData> InitializePoolFreeArgs<S> {
+    /// Returns (keys, new pool_state PDA bump)
+    pub fn resolve_for_prog(
+        self,
+        program_id: Pubkey,
+    ) -> Result<(InitializePoolKeys, u8), SControllerError> {
+        self.resolve_inner(program_id, find_pool_state_address(program_id).0)
+    }
+
+    fn resolve_inner(
+        self,
+        program_id: Pubkey,
+        default_pool_state_id: Pubkey,
+    ) -> Result<(InitializePoolKeys, u8), SControllerError> {
+        let Self {
+            payer,
+            lp_token_mint,
+            default_pool_state,
+        } = self;
+
+        let default_pool_state_data = default_pool_state.data();
+        let default_pool_state = try_pool_state(&default_pool_state_data)?;
+
+        let (pool_state, pool_state_bump) =
+            find_pool_state_address_for_pool(program_id, PoolId::Keyed(lp_token_mint));
+
+        Ok((
+            InitializePoolKeys {
+                payer,
+                authority: default_pool_state.admin,
+                default_pool_state: default_pool_state_id,
+                pool_state,
+                lp_token_mint,
+                lp_token_program: spl_token::ID,
+                system_program: system_program::ID,
+            },
+            pool_state_bump,
+        ))
+    }
+}
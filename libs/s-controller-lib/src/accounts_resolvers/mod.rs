@@ -1,45 +1,67 @@
 mod add_disable_pool_authority;
 mod add_liquidity;
 mod add_lst;
+mod cancel_pending_change;
 mod common;
 mod disable_enable_lst_input;
 mod disable_pool;
 mod enable_pool;
 mod end_rebalance;
+mod execute_pending_change;
 mod initialize;
+mod initialize_pool;
+mod multi_liquidity;
+mod queue_pending_change;
 mod remove_disable_pool_authority;
 mod remove_liquidity;
 mod remove_lst;
 mod set_admin;
+mod set_lst_cap;
 mod set_pricing_program;
 mod set_protocol_fee;
 mod set_protocol_fee_beneficiary;
 mod set_rebalance_authority;
+mod set_rebalance_loss_limit;
 mod set_sol_value_calculator;
+mod set_timelock_delay;
 mod start_rebalance;
 mod swap;
+mod swap_route;
 mod sync_sol_value;
 mod withdraw_protocol_fees;
+mod withdraw_protocol_fees_multi;
+mod withdraw_protocol_fees_swap;
 
 pub use add_disable_pool_authority::*;
 pub use add_liquidity::*;
 pub use add_lst::*;
+pub use cancel_pending_change::*;
 pub use common::*;
 pub use disable_enable_lst_input::*;
 pub use disable_pool::*;
 pub use enable_pool::*;
 pub use end_rebalance::*;
+pub use execute_pending_change::*;
 pub use initialize::*;
+pub use initialize_pool::*;
+pub use multi_liquidity::*;
+pub use queue_pending_change::*;
 pub use remove_disable_pool_authority::*;
 pub use remove_liquidity::*;
 pub use remove_lst::*;
 pub use set_admin::*;
+pub use set_lst_cap::*;
 pub use set_pricing_program::*;
 pub use set_protocol_fee::*;
 pub use set_protocol_fee_beneficiary::*;
 pub use set_rebalance_authority::*;
+pub use set_rebalance_loss_limit::*;
 pub use set_sol_value_calculator::*;
+pub use set_timelock_delay::*;
 pub use start_rebalance::*;
 pub use swap::*;
+pub use swap_route::*;
 pub use sync_sol_value::*;
 pub use withdraw_protocol_fees::*;
+pub use withdraw_protocol_fees_multi::*;
+pub use withdraw_protocol_fees_swap::*;
@@ -0,0 +1,406 @@
+use s_controller_interface::{
+    AddLiquidityMultiKeys, LstState, RemoveLiquidityProRataKeys, SControllerError,
+};
+use solana_program::{instruction::AccountMeta, pubkey::Pubkey};
+use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountOwner, ReadonlyAccountPubkey};
+
+use crate::{
+    create_pool_reserves_address_with_pool_state_id,
+    create_protocol_fee_accumulator_address_with_protocol_fee_id, try_find_lst_mint_on_list,
+    try_lst_state_list, try_match_lst_mint_on_list, try_pool_state, PoolPdas, SwapLiquidityPdas,
+    MULTI_LIQUIDITY_LST_ACCOUNTS_LEN,
+};
+
+/// The accounts of a single LST of an AddLiquidityMulti or RemoveLiquidityProRata instruction.
+///
+/// These are appended to the instruction's accounts for each LST, in order,
+/// before any SOL value calculator or pricing program accounts.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MultiLiquidityLstKeys {
+    pub lst_mint: Pubkey,
+    pub lst_token_program: Pubkey,
+    /// User's LST token account.
+    /// src for AddLiquidityMulti, dst for RemoveLiquidityProRata
+    pub lst_acc: Pubkey,
+    pub pool_reserves: Pubkey,
+    pub protocol_fee_accumulator: Pubkey,
+}
+
+impl From<MultiLiquidityLstKeys> for [AccountMeta; MULTI_LIQUIDITY_LST_ACCOUNTS_LEN] {
+    fn from(
+        MultiLiquidityLstKeys {
+            lst_mint,
+            lst_token_program,
+            lst_acc,
+            pool_reserves,
+            protocol_fee_accumulator,
+        }: MultiLiquidityLstKeys,
+    ) -> Self {
+        [
+            AccountMeta::new_readonly(lst_mint, false),
+            AccountMeta::new_readonly(lst_token_program, false),
+            AccountMeta::new(lst_acc, false),
+            AccountMeta::new(pool_reserves, false),
+            AccountMeta::new(protocol_fee_accumulator, false),
+        ]
+    }
+}
+
+fn multi_liquidity_lst_keys(
+    SwapLiquidityPdas {
+        pool_state,
+        protocol_fee,
+        ..
+    }: SwapLiquidityPdas,
+    lst_state: &LstState,
+    lst_mint: Pubkey,
+    lst_token_program: Pubkey,
+    lst_acc: Pubkey,
+) -> Result<MultiLiquidityLstKeys, SControllerError> {
+    Ok(MultiLiquidityLstKeys {
+        lst_mint,
+        lst_token_program,
+        lst_acc,
+        pool_reserves: create_pool_reserves_address_with_pool_state_id(
+            pool_state,
+            lst_state,
+            lst_token_program,
+        )?,
+        protocol_fee_accumulator: create_protocol_fee_accumulator_address_with_protocol_fee_id(
+            protocol_fee,
+            lst_state,
+            lst_token_program,
+        )?,
+    })
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct AddLiquidityMultiFreeArgs<S: ReadonlyAccountData + ReadonlyAccountPubkey> {
+    pub signer: Pubkey,
+    pub dst_lp_acc: Pubkey,
+    pub pool_state: S,
+}
+
+impl<S: ReadonlyAccountData + ReadonlyAccountPubkey> AddLiquidityMultiFreeArgs<S> {
+    pub fn resolve(&self) -> Result<AddLiquidityMultiKeys, SControllerError> {
+        self.resolve_for_pool(PoolPdas::DEFAULT)
+    }
+
+    pub fn resolve_for_pool(
+        &self,
+        PoolPdas {
+            pool_state: pool_state_id,
+            lst_state_list,
+            ..
+        }: PoolPdas,
+    ) -> Result<AddLiquidityMultiKeys, SControllerError> {
+        let Self {
+            signer,
+            dst_lp_acc,
+            pool_state: pool_state_account,
+        } = self;
+        if *pool_state_account.pubkey() != pool_state_id {
+            return Err(SControllerError::IncorrectPoolState);
+        }
+        let pool_state_data = pool_state_account.data();
+        let pool_state = try_pool_state(&pool_state_data)?;
+        Ok(AddLiquidityMultiKeys {
+            signer: *signer,
+            dst_lp_acc: *dst_lp_acc,
+            lp_token_mint: pool_state.lp_token_mint,
+            lp_token_program: spl_token::ID,
+            pool_state: pool_state_id,
+            lst_state_list,
+        })
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct RemoveLiquidityProRataFreeArgs<S: ReadonlyAccountData + ReadonlyAccountPubkey> {
+    pub signer: Pubkey,
+    pub src_lp_acc: Pubkey,
+    pub pool_state: S,
+}
+
+impl<S: ReadonlyAccountData + ReadonlyAccountPubkey> RemoveLiquidityProRataFreeArgs<S> {
+    pub fn resolve(&self) -> Result<RemoveLiquidityProRataKeys, SControllerError> {
+        self.resolve_for_pool(PoolPdas::DEFAULT)
+    }
+
+    pub fn resolve_for_pool(
+        &self,
+        PoolPdas {
+            pool_state: pool_state_id,
+            lst_state_list,
+            ..
+        }: PoolPdas,
+    ) -> Result<RemoveLiquidityProRataKeys, SControllerError> {
+        let Self {
+            signer,
+            src_lp_acc,
+            pool_state: pool_state_account,
+        } = self;
+        if *pool_state_account.pubkey() != pool_state_id {
+            return Err(SControllerError::IncorrectPoolState);
+        }
+        let pool_state_data = pool_state_account.data();
+        let pool_state = try_pool_state(&pool_state_data)?;
+        Ok(RemoveLiquidityProRataKeys {
+            signer: *signer,
+            src_lp_acc: *src_lp_acc,
+            lp_token_mint: pool_state.lp_token_mint,
+            lp_token_program: spl_token::ID,
+            pool_state: pool_state_id,
+            lst_state_list,
+        })
+    }
+}
+
+/// Resolves the [`MultiLiquidityLstKeys`] of the LST at `lst_index` on the pool's list
+pub struct MultiLiquidityLstFreeArgs<
+    M: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+    L: ReadonlyAccountData + ReadonlyAccountPubkey,
+> {
+    pub lst_index: usize,
+    pub lst_acc: Pubkey,
+    pub lst_mint: M,
+    pub lst_state_list: L,
+}
+
+impl<
+        M: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+        L: ReadonlyAccountData + ReadonlyAccountPubkey,
+    > MultiLiquidityLstFreeArgs<M, L>
+{
+    pub fn resolve(&self) -> Result<MultiLiquidityLstKeys, SControllerError> {
+        self.resolve_with_pdas(PoolPdas::DEFAULT.into())
+    }
+
+    pub fn resolve_with_pdas(
+        &self,
+        pdas: SwapLiquidityPdas,
+    ) -> Result<MultiLiquidityLstKeys, SControllerError> {
+        let Self {
+            lst_index,
+            lst_acc,
+            lst_mint,
+            lst_state_list: lst_state_list_account,
+        } = self;
+        if *lst_state_list_account.pubkey() != pdas.lst_state_list {
+            return Err(SControllerError::IncorrectLstStateList);
+        }
+
+        let lst_state_list_acc_data = lst_state_list_account.data();
+        let lst_state_list = try_lst_state_list(&lst_state_list_acc_data)?;
+
+        let lst_state = try_match_lst_mint_on_list(*lst_mint.pubkey(), lst_state_list, *lst_index)?;
+        multi_liquidity_lst_keys(
+            pdas,
+            lst_state,
+            *lst_mint.pubkey(),
+            *lst_mint.owner(),
+            *lst_acc,
+        )
+    }
+}
+
+/// A LST mint and the user's token account for it
+#[derive(Clone, Copy, Debug)]
+pub struct MultiLiquidityLst<M: ReadonlyAccountOwner + ReadonlyAccountPubkey> {
+    pub lst_mint: M,
+    pub lst_acc: Pubkey,
+}
+
+/// Per-LST data of a resolved AddLiquidityMulti or RemoveLiquidityProRata instruction, in order
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MultiLiquidityResolved {
+    pub lst_keys: Vec<MultiLiquidityLstKeys>,
+    pub lst_indexes: Vec<usize>,
+    pub sol_value_calculator_program_ids: Vec<Pubkey>,
+    pub pricing_program_id: Pubkey,
+}
+
+/// Iterates through lst_state_list to find the lst indexes of every LST being added.
+/// Suitable for use on client side.
+/// Does not check identity of pool_state and lst_state_list
+pub struct AddLiquidityMultiByMintsFreeArgs<
+    'a,
+    S: ReadonlyAccountData,
+    L: ReadonlyAccountData,
+    M: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+> {
+    pub signer: Pubkey,
+    pub dst_lp_acc: Pubkey,
+    pub pool_state: S,
+    pub lst_state_list: L,
+    /// LSTs to add and the user's src token accounts for them
+    pub lsts: &'a [MultiLiquidityLst<M>],
+}
+
+impl<
+        'a,
+        S: ReadonlyAccountData,
+        L: ReadonlyAccountData,
+        M: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+    > AddLiquidityMultiByMintsFreeArgs<'a, S, L, M>
+{
+    pub fn resolve(
+        &self,
+    ) -> Result<(AddLiquidityMultiKeys, MultiLiquidityResolved), SControllerError> {
+        self.resolve_with_pdas(PoolPdas::DEFAULT.into())
+    }
+
+    pub fn resolve_for_prog(
+        &self,
+        program_id: Pubkey,
+    ) -> Result<(AddLiquidityMultiKeys, MultiLiquidityResolved), SControllerError> {
+        self.resolve_with_pdas(SwapLiquidityPdas::find_for_program_id(program_id))
+    }
+
+    pub fn resolve_with_pdas(
+        &self,
+        pdas: SwapLiquidityPdas,
+    ) -> Result<(AddLiquidityMultiKeys, MultiLiquidityResolved), SControllerError> {
+        let Self {
+            signer,
+            dst_lp_acc,
+            pool_state: pool_state_account,
+            lst_state_list: lst_state_list_account,
+            lsts,
+        } = self;
+        if lsts.is_empty() {
+            return Err(SControllerError::InvalidLiquidityLsts);
+        }
+
+        let lst_state_list_acc_data = lst_state_list_account.data();
+        let lst_state_list = try_lst_state_list(&lst_state_list_acc_data)?;
+        let pool_state_data = pool_state_account.data();
+        let pool_state = try_pool_state(&pool_state_data)?;
+
+        let mut resolved = MultiLiquidityResolved {
+            pricing_program_id: pool_state.pricing_program,
+            ..Default::default()
+        };
+        for MultiLiquidityLst { lst_mint, lst_acc } in lsts.iter() {
+            let (lst_index, lst_state) =
+                try_find_lst_mint_on_list(*lst_mint.pubkey(), lst_state_list)?;
+            if resolved.lst_indexes.contains(&lst_index) {
+                return Err(SControllerError::InvalidLiquidityLsts);
+            }
+            resolved.lst_keys.push(multi_liquidity_lst_keys(
+                pdas,
+                lst_state,
+                *lst_mint.pubkey(),
+                *lst_mint.owner(),
+                *lst_acc,
+            )?);
+            resolved.lst_indexes.push(lst_index);
+            resolved
+                .sol_value_calculator_program_ids
+                .push(lst_state.sol_value_calculator);
+        }
+
+        Ok((
+            AddLiquidityMultiKeys {
+                signer: *signer,
+                dst_lp_acc: *dst_lp_acc,
+                lp_token_mint: pool_state.lp_token_mint,
+                lp_token_program: spl_token::ID,
+                pool_state: pdas.pool_state,
+                lst_state_list: pdas.lst_state_list,
+            },
+            resolved,
+        ))
+    }
+}
+
+/// Suitable for use on client side.
+/// Does not check identity of pool_state and lst_state_list
+pub struct RemoveLiquidityProRataByMintsFreeArgs<
+    'a,
+    S: ReadonlyAccountData,
+    L: ReadonlyAccountData,
+    M: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+> {
+    pub signer: Pubkey,
+    pub src_lp_acc: Pubkey,
+    pub pool_state: S,
+    pub lst_state_list: L,
+    /// Every LST in the pool, in the same order as lst_state_list,
+    /// and the user's dst token accounts for them
+    pub lsts: &'a [MultiLiquidityLst<M>],
+}
+
+impl<
+        'a,
+        S: ReadonlyAccountData,
+        L: ReadonlyAccountData,
+        M: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+    > RemoveLiquidityProRataByMintsFreeArgs<'a, S, L, M>
+{
+    pub fn resolve(
+        &self,
+    ) -> Result<(RemoveLiquidityProRataKeys, MultiLiquidityResolved), SControllerError> {
+        self.resolve_with_pdas(PoolPdas::DEFAULT.into())
+    }
+
+    pub fn resolve_for_prog(
+        &self,
+        program_id: Pubkey,
+    ) -> Result<(RemoveLiquidityProRataKeys, MultiLiquidityResolved), SControllerError> {
+        self.resolve_with_pdas(SwapLiquidityPdas::find_for_program_id(program_id))
+    }
+
+    pub fn resolve_with_pdas(
+        &self,
+        pdas: SwapLiquidityPdas,
+    ) -> Result<(RemoveLiquidityProRataKeys, MultiLiquidityResolved), SControllerError> {
+        let Self {
+            signer,
+            src_lp_acc,
+            pool_state: pool_state_account,
+            lst_state_list: lst_state_list_account,
+            lsts,
+        } = self;
+
+        let lst_state_list_acc_data = lst_state_list_account.data();
+        let lst_state_list = try_lst_state_list(&lst_state_list_acc_data)?;
+        if lsts.is_empty() || lsts.len() != lst_state_list.len() {
+            return Err(SControllerError::InvalidLiquidityLsts);
+        }
+        let pool_state_data = pool_state_account.data();
+        let pool_state = try_pool_state(&pool_state_data)?;
+
+        let mut resolved = MultiLiquidityResolved {
+            pricing_program_id: pool_state.pricing_program,
+            ..Default::default()
+        };
+        for (lst_index, MultiLiquidityLst { lst_mint, lst_acc }) in lsts.iter().enumerate() {
+            let lst_state =
+                try_match_lst_mint_on_list(*lst_mint.pubkey(), lst_state_list, lst_index)?;
+            resolved.lst_keys.push(multi_liquidity_lst_keys(
+                pdas,
+                lst_state,
+                *lst_mint.pubkey(),
+                *lst_mint.owner(),
+                *lst_acc,
+            )?);
+            resolved.lst_indexes.push(lst_index);
+            resolved
+                .sol_value_calculator_program_ids
+                .push(lst_state.sol_value_calculator);
+        }
+
+        Ok((
+            RemoveLiquidityProRataKeys {
+                signer: *signer,
+                src_lp_acc: *src_lp_acc,
+                lp_token_mint: pool_state.lp_token_mint,
+                lp_token_program: spl_token::ID,
+                pool_state: pdas.pool_state,
+                lst_state_list: pdas.lst_state_list,
+            },
+            resolved,
+        ))
+    }
+}
@@ -0,0 +1,48 @@
+use s_controller_interface::{QueuePendingChangeKeys, SControllerError};
+use solana_program::{pubkey::Pubkey, system_program};
+use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountPubkey};
+
+use crate::{try_pool_state, PoolPdas};
+
+#[derive(Clone, Copy, Debug)]
+pub struct QueuePendingChangeFreeArgs<S: ReadonlyAccountData + ReadonlyAccountPubkey> {
+    pub payer: Pubkey,
+    pub pool_state: S,
+}
+
+impl<S: ReadonlyAccountData + ReadonlyAccountPubkey> QueuePendingChangeFreeArgs<S> {
+    pub fn resolve(&self) -> Result<QueuePendingChangeKeys, SControllerError> {
+        self.resolve_for_pool(PoolPdas::DEFAULT)
+    }
+
+    pub fn resolve_for_pool(
+        &self,
+        PoolPdas {
+            pool_state: pool_state_id,
+            pending_change_list,
+            ..
+        }: PoolPdas,
+    ) -> Result<QueuePendingChangeKeys, SControllerError> {
+        if *self.pool_state.pubkey() != pool_state_id {
+            return Err(SControllerError::IncorrectPoolState);
+        }
+
+        let pool_state_data = self.pool_state.data();
+        let pool_state = try_pool_state(&pool_state_data)?;
+
+        Ok(QueuePendingChangeKeys {
+            payer: self.payer,
+            admin: pool_state.admin,
+            pool_state: pool_state_id,
+            pending_change_list,
+            system_program: system_program::ID,
+        })
+    }
+
+    pub fn resolve_for_prog(
+        &self,
+        program_id: Pubkey,
+    ) -> Result<QueuePendingChangeKeys, SControllerError> {
+        self.resolve_for_pool(PoolPdas::find_for_program_id(program_id))
+    }
+}
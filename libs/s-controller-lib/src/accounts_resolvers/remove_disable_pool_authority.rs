@@ -6,8 +6,7 @@ use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountPubkey};
 
 use crate::{
     find_disable_pool_authority_list_address, find_pool_state_address, index_to_u32,
-    program::{DISABLE_POOL_AUTHORITY_LIST_ID, POOL_STATE_ID},
-    try_disable_pool_authority_list, try_find_element_in_list,
+    try_disable_pool_authority_list, try_find_element_in_list, PoolPdas,
 };
 
 #[derive(Clone, Copy, Debug)]
@@ -28,10 +27,21 @@ impl<
     > RemoveDisablePoolAuthorityFreeArgs<S, L>
 {
     pub fn resolve(&self) -> Result<RemoveDisablePoolAuthorityKeys, SControllerError> {
-        if *self.pool_state_acc.pubkey() != POOL_STATE_ID {
+        self.resolve_for_pool(PoolPdas::DEFAULT)
+    }
+
+    pub fn resolve_for_pool(
+        &self,
+        PoolPdas {
+            pool_state,
+            disable_pool_authority_list,
+            ..
+        }: PoolPdas,
+    ) -> Result<RemoveDisablePoolAuthorityKeys, SControllerError> {
+        if *self.pool_state_acc.pubkey() != pool_state {
             return Err(SControllerError::IncorrectPoolState);
         }
-        if *self.disable_pool_authority_list.pubkey() != DISABLE_POOL_AUTHORITY_LIST_ID {
+        if *self.disable_pool_authority_list.pubkey() != disable_pool_authority_list {
             return Err(SControllerError::IncorrectDisablePoolAuthorityList);
         }
 
@@ -44,9 +54,9 @@ impl<
         Ok(RemoveDisablePoolAuthorityKeys {
             refund_rent_to: self.refund_rent_to,
             signer: self.signer,
-            pool_state: POOL_STATE_ID,
+            pool_state,
             authority: *authority,
-            disable_pool_authority_list: DISABLE_POOL_AUTHORITY_LIST_ID,
+            disable_pool_authority_list,
         })
     }
 }
@@ -77,10 +87,7 @@ impl<
         ),
         SControllerError,
     > {
-        self.resolve_with_pdas(RemoveDisablePoolAuthorityPdas {
-            pool_state: POOL_STATE_ID,
-            disable_pool_authority_list: DISABLE_POOL_AUTHORITY_LIST_ID,
-        })
+        self.resolve_with_pdas(PoolPdas::DEFAULT.into())
     }
 }
 
@@ -90,6 +97,21 @@ pub struct RemoveDisablePoolAuthorityPdas {
     pub disable_pool_authority_list: Pubkey,
 }
 
+impl From<PoolPdas> for RemoveDisablePoolAuthorityPdas {
+    fn from(
+        PoolPdas {
+            pool_state,
+            disable_pool_authority_list,
+            ..
+        }: PoolPdas,
+    ) -> Self {
+        Self {
+            pool_state,
+            disable_pool_authority_list,
+        }
+    }
+}
+
 impl<S: ReadonlyAccountData, L: ReadonlyAccountData>
     RemoveDisablePoolAuthorityByPubkeyFreeArgs<S, L>
 {
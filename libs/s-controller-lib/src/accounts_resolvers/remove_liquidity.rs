@@ -1,14 +1,12 @@
-use s_controller_interface::{RemoveLiquidityKeys, SControllerError};
+use s_controller_interface::{RemoveLiquidityExactOutKeys, RemoveLiquidityKeys, SControllerError};
 use solana_program::pubkey::Pubkey;
 use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountOwner, ReadonlyAccountPubkey};
 
 use crate::{
-    create_pool_reserves_address, create_pool_reserves_address_with_pool_state_id,
-    create_protocol_fee_accumulator_address,
-    create_protocol_fee_accumulator_address_with_protocol_fee_id,
-    program::{LST_STATE_LIST_ID, POOL_STATE_ID, PROTOCOL_FEE_ID},
-    try_find_lst_mint_on_list, try_lst_state_list, try_match_lst_mint_on_list, try_pool_state,
-    AddRemoveLiquidityProgramIds, SwapLiquidityPdas,
+    create_pool_reserves_address_with_pool_state_id,
+    create_protocol_fee_accumulator_address_with_protocol_fee_id, try_find_lst_mint_on_list,
+    try_lst_state_list, try_match_lst_mint_on_list, try_pool_state, AddRemoveLiquidityProgramIds,
+    PoolPdas, SwapLiquidityPdas,
 };
 
 #[derive(Clone, Copy, Debug)]
@@ -33,6 +31,18 @@ impl<
     > RemoveLiquidityFreeArgs<S, L, M>
 {
     pub fn resolve(self) -> Result<RemoveLiquidityKeys, SControllerError> {
+        self.resolve_for_pool(PoolPdas::DEFAULT)
+    }
+
+    pub fn resolve_for_pool(
+        self,
+        PoolPdas {
+            pool_state: pool_state_id,
+            lst_state_list: lst_state_list_id,
+            protocol_fee: protocol_fee_id,
+            ..
+        }: PoolPdas,
+    ) -> Result<RemoveLiquidityKeys, SControllerError> {
         let Self {
             lst_index,
             signer,
@@ -42,19 +52,27 @@ impl<
             lst_state_list: lst_state_list_account,
             lst_mint,
         } = self;
-        if *pool_state_account.pubkey() != POOL_STATE_ID {
+        if *pool_state_account.pubkey() != pool_state_id {
             return Err(SControllerError::IncorrectPoolState);
         }
-        if *lst_state_list_account.pubkey() != LST_STATE_LIST_ID {
+        if *lst_state_list_account.pubkey() != lst_state_list_id {
             return Err(SControllerError::IncorrectLstStateList);
         }
 
         let lst_state_list_acc_data = lst_state_list_account.data();
         let lst_state_list = try_lst_state_list(&lst_state_list_acc_data)?;
         let lst_state = try_match_lst_mint_on_list(*lst_mint.pubkey(), lst_state_list, lst_index)?;
-        let pool_reserves = create_pool_reserves_address(lst_state, *lst_mint.owner())?;
+        let pool_reserves = create_pool_reserves_address_with_pool_state_id(
+            pool_state_id,
+            lst_state,
+            *lst_mint.owner(),
+        )?;
         let protocol_fee_accumulator =
-            create_protocol_fee_accumulator_address(lst_state, *lst_mint.owner())?;
+            create_protocol_fee_accumulator_address_with_protocol_fee_id(
+                protocol_fee_id,
+                lst_state,
+                *lst_mint.owner(),
+            )?;
 
         let pool_state_data = pool_state_account.data();
         let pool_state = try_pool_state(&pool_state_data)?;
@@ -67,11 +85,23 @@ impl<
             protocol_fee_accumulator,
             lst_token_program: *lst_mint.owner(),
             lp_token_program: spl_token::ID,
-            pool_state: POOL_STATE_ID,
-            lst_state_list: LST_STATE_LIST_ID,
+            pool_state: pool_state_id,
+            lst_state_list: lst_state_list_id,
             pool_reserves,
         })
     }
+
+    pub fn resolve_exact_out(self) -> Result<RemoveLiquidityExactOutKeys, SControllerError> {
+        self.resolve_exact_out_for_pool(PoolPdas::DEFAULT)
+    }
+
+    pub fn resolve_exact_out_for_pool(
+        self,
+        pdas: PoolPdas,
+    ) -> Result<RemoveLiquidityExactOutKeys, SControllerError> {
+        self.resolve_for_pool(pdas)
+            .map(remove_liquidity_exact_out_keys)
+    }
 }
 
 /// Iterates through lst_state_list to find lst_index.
@@ -103,11 +133,7 @@ impl<
     pub fn resolve(
         self,
     ) -> Result<(RemoveLiquidityKeys, usize, AddRemoveLiquidityProgramIds), SControllerError> {
-        self.resolve_with_pdas(SwapLiquidityPdas {
-            pool_state: POOL_STATE_ID,
-            lst_state_list: LST_STATE_LIST_ID,
-            protocol_fee: PROTOCOL_FEE_ID,
-        })
+        self.resolve_with_pdas(PoolPdas::DEFAULT.into())
     }
 
     pub fn resolve_for_prog(
@@ -175,4 +201,87 @@ impl<
             },
         ))
     }
+
+    /// Does not check identity of pool_state and lst_state_list
+    /// Returns:
+    /// (partial instructions keys, index of lst on lst_state_list, additional program IDs)
+    pub fn resolve_exact_out(
+        self,
+    ) -> Result<
+        (
+            RemoveLiquidityExactOutKeys,
+            usize,
+            AddRemoveLiquidityProgramIds,
+        ),
+        SControllerError,
+    > {
+        self.resolve_exact_out_with_pdas(PoolPdas::DEFAULT.into())
+    }
+
+    pub fn resolve_exact_out_for_prog(
+        self,
+        program_id: Pubkey,
+    ) -> Result<
+        (
+            RemoveLiquidityExactOutKeys,
+            usize,
+            AddRemoveLiquidityProgramIds,
+        ),
+        SControllerError,
+    > {
+        self.resolve_exact_out_with_pdas(SwapLiquidityPdas::find_for_program_id(program_id))
+    }
+
+    /// Does not check identity of pool_state and lst_state_list
+    /// Returns:
+    /// (partial instructions keys, index of lst on lst_state_list, additional program IDs)
+    pub fn resolve_exact_out_with_pdas(
+        self,
+        pdas: SwapLiquidityPdas,
+    ) -> Result<
+        (
+            RemoveLiquidityExactOutKeys,
+            usize,
+            AddRemoveLiquidityProgramIds,
+        ),
+        SControllerError,
+    > {
+        let (keys, lst_index, program_ids) = self.resolve_with_pdas(pdas)?;
+        Ok((
+            remove_liquidity_exact_out_keys(keys),
+            lst_index,
+            program_ids,
+        ))
+    }
+}
+
+/// RemoveLiquidityExactOut has the same accounts as RemoveLiquidity
+fn remove_liquidity_exact_out_keys(
+    RemoveLiquidityKeys {
+        signer,
+        lst_mint,
+        dst_lst_acc,
+        src_lp_acc,
+        lp_token_mint,
+        protocol_fee_accumulator,
+        lst_token_program,
+        lp_token_program,
+        pool_state,
+        lst_state_list,
+        pool_reserves,
+    }: RemoveLiquidityKeys,
+) -> RemoveLiquidityExactOutKeys {
+    RemoveLiquidityExactOutKeys {
+        signer,
+        lst_mint,
+        dst_lst_acc,
+        src_lp_acc,
+        lp_token_mint,
+        protocol_fee_accumulator,
+        lst_token_program,
+        lp_token_program,
+        pool_state,
+        lst_state_list,
+        pool_reserves,
+    }
 }
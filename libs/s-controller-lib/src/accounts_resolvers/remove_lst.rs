@@ -3,10 +3,10 @@ use solana_program::pubkey::Pubkey;
 use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountOwner, ReadonlyAccountPubkey};
 
 use crate::{
-    create_pool_reserves_address, create_protocol_fee_accumulator_address,
-    find_lst_state_list_address, find_pool_state_address, find_protocol_fee_address,
-    program::{LST_STATE_LIST_ID, POOL_STATE_ID, PROTOCOL_FEE_ID},
-    try_find_lst_mint_on_list, try_lst_state_list, try_match_lst_mint_on_list, try_pool_state,
+    create_pool_reserves_address_with_pool_state_id,
+    create_protocol_fee_accumulator_address_with_protocol_fee_id, find_lst_state_list_address,
+    find_pool_state_address, find_protocol_fee_address, try_find_lst_mint_on_list,
+    try_lst_state_list, try_match_lst_mint_on_list, try_pool_state, PoolPdas,
 };
 
 /// Must ensure protocol_fee_accumulator and pool_reserves token accounts
@@ -31,6 +31,18 @@ impl<
     > RemoveLstFreeArgs<S, L, M>
 {
     pub fn resolve(self) -> Result<RemoveLstKeys, SControllerError> {
+        self.resolve_for_pool(PoolPdas::DEFAULT)
+    }
+
+    pub fn resolve_for_pool(
+        self,
+        PoolPdas {
+            pool_state: pool_state_id,
+            lst_state_list: lst_state_list_id,
+            protocol_fee: protocol_fee_id,
+            ..
+        }: PoolPdas,
+    ) -> Result<RemoveLstKeys, SControllerError> {
         let Self {
             lst_index,
             refund_rent_to,
@@ -38,10 +50,10 @@ impl<
             lst_state_list: lst_state_list_account,
             lst_mint,
         } = self;
-        if *pool_state_account.pubkey() != POOL_STATE_ID {
+        if *pool_state_account.pubkey() != pool_state_id {
             return Err(SControllerError::IncorrectPoolState);
         }
-        if *lst_state_list_account.pubkey() != LST_STATE_LIST_ID {
+        if *lst_state_list_account.pubkey() != lst_state_list_id {
             return Err(SControllerError::IncorrectLstStateList);
         }
         let lst_state_list_acc_data = lst_state_list_account.data();
@@ -49,9 +61,17 @@ impl<
 
         let lst_state = try_match_lst_mint_on_list(*lst_mint.pubkey(), lst_state_list, lst_index)?;
 
-        let pool_reserves = create_pool_reserves_address(lst_state, *lst_mint.owner())?;
+        let pool_reserves = create_pool_reserves_address_with_pool_state_id(
+            pool_state_id,
+            lst_state,
+            *lst_mint.owner(),
+        )?;
         let protocol_fee_accumulator =
-            create_protocol_fee_accumulator_address(lst_state, *lst_mint.owner())?;
+            create_protocol_fee_accumulator_address_with_protocol_fee_id(
+                protocol_fee_id,
+                lst_state,
+                *lst_mint.owner(),
+            )?;
 
         let pool_state_acc_data = pool_state_account.data();
         let pool_state = try_pool_state(&pool_state_acc_data)?;
@@ -62,9 +82,9 @@ impl<
             lst_mint: *lst_mint.pubkey(),
             pool_reserves,
             protocol_fee_accumulator,
-            protocol_fee_accumulator_auth: PROTOCOL_FEE_ID,
-            pool_state: POOL_STATE_ID,
-            lst_state_list: LST_STATE_LIST_ID,
+            protocol_fee_accumulator_auth: protocol_fee_id,
+            pool_state: pool_state_id,
+            lst_state_list: lst_state_list_id,
             lst_token_program: *lst_mint.owner(),
         })
     }
@@ -88,6 +108,23 @@ pub struct RemoveLstPdas {
     pub lst_state_list: Pubkey,
 }
 
+impl From<PoolPdas> for RemoveLstPdas {
+    fn from(
+        PoolPdas {
+            pool_state,
+            lst_state_list,
+            protocol_fee,
+            ..
+        }: PoolPdas,
+    ) -> Self {
+        Self {
+            pool_state,
+            protocol_fee_accumulator_auth: protocol_fee,
+            lst_state_list,
+        }
+    }
+}
+
 impl<
         S: ReadonlyAccountData,
         L: ReadonlyAccountData,
@@ -96,11 +133,7 @@ impl<
 {
     /// Does not check identity of pool_state and lst_state_list
     pub fn resolve(self) -> Result<(RemoveLstKeys, RemoveLstIxArgs), SControllerError> {
-        self.resolve_with_pdas(RemoveLstPdas {
-            pool_state: POOL_STATE_ID,
-            protocol_fee_accumulator_auth: PROTOCOL_FEE_ID,
-            lst_state_list: LST_STATE_LIST_ID,
-        })
+        self.resolve_with_pdas(PoolPdas::DEFAULT.into())
     }
 
     pub fn resolve_for_prog(
@@ -135,9 +168,17 @@ impl<
 
         let (lst_index, lst_state) =
             try_find_lst_mint_on_list(*lst_mint.pubkey(), lst_state_list_deser)?;
-        let pool_reserves = create_pool_reserves_address(lst_state, *lst_mint.owner())?;
+        let pool_reserves = create_pool_reserves_address_with_pool_state_id(
+            pool_state,
+            lst_state,
+            *lst_mint.owner(),
+        )?;
         let protocol_fee_accumulator =
-            create_protocol_fee_accumulator_address(lst_state, *lst_mint.owner())?;
+            create_protocol_fee_accumulator_address_with_protocol_fee_id(
+                protocol_fee_accumulator_auth,
+                lst_state,
+                *lst_mint.owner(),
+            )?;
 
         let pool_state_acc_data = pool_state_account.data();
         let pool_state_deser = try_pool_state(&pool_state_acc_data)?;
@@ -0,0 +1,125 @@
+use s_controller_interface::{LstState, PoolState, SControllerError, SetLstCapKeys};
+use solana_program::pubkey::Pubkey;
+use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountPubkey};
+
+use crate::{
+    find_lst_state_list_address, find_pool_state_address, try_find_lst_mint_on_list,
+    try_lst_state_list, try_pool_state, PoolPdas,
+};
+
+pub struct SetLstCapFreeArgs<
+    S: ReadonlyAccountData + ReadonlyAccountPubkey,
+    L: ReadonlyAccountData + ReadonlyAccountPubkey,
+> {
+    pub lst_index: usize,
+    pub pool_state: S,
+    pub lst_state_list: L,
+}
+
+impl<
+        S: ReadonlyAccountData + ReadonlyAccountPubkey,
+        L: ReadonlyAccountData + ReadonlyAccountPubkey,
+    > SetLstCapFreeArgs<S, L>
+{
+    pub fn resolve(&self) -> Result<SetLstCapKeys, SControllerError> {
+        self.resolve_for_pool(PoolPdas::DEFAULT)
+    }
+
+    pub fn resolve_for_pool(&self, pdas: PoolPdas) -> Result<SetLstCapKeys, SControllerError> {
+        let Self {
+            lst_index,
+            pool_state: pool_state_account,
+            lst_state_list: lst_state_list_account,
+        } = self;
+        if *pool_state_account.pubkey() != pdas.pool_state {
+            return Err(SControllerError::IncorrectPoolState);
+        }
+        if *lst_state_list_account.pubkey() != pdas.lst_state_list {
+            return Err(SControllerError::IncorrectLstStateList);
+        }
+
+        let lst_state_list_data = lst_state_list_account.data();
+        let lst_state_list = try_lst_state_list(&lst_state_list_data)?;
+        let LstState { mint, .. } = lst_state_list
+            .get(*lst_index)
+            .ok_or(SControllerError::InvalidLstIndex)?;
+
+        let pool_state_data = pool_state_account.data();
+        let PoolState { admin, .. } = try_pool_state(&pool_state_data)?;
+
+        Ok(SetLstCapKeys {
+            admin: *admin,
+            lst_mint: *mint,
+            pool_state: pdas.pool_state,
+            lst_state_list: pdas.lst_state_list,
+        })
+    }
+}
+
+/// Iterates through lst_state_list to find lst_index.
+/// Suitable for use on client-side.
+/// Does not check identity of pool_state and lst_state_list
+pub struct SetLstCapByMintFreeArgs<S: ReadonlyAccountData, L: ReadonlyAccountData> {
+    pub lst_mint: Pubkey,
+    pub pool_state: S,
+    pub lst_state_list: L,
+}
+
+impl<S: ReadonlyAccountData, L: ReadonlyAccountData> SetLstCapByMintFreeArgs<S, L> {
+    /// Returns (keys, index of lst_mint in lst_state_list)
+    pub fn resolve(&self) -> Result<(SetLstCapKeys, usize), SControllerError> {
+        self.resolve_for_pool(PoolPdas::DEFAULT)
+    }
+
+    /// Returns (keys, index of lst_mint in lst_state_list)
+    pub fn resolve_for_prog(
+        &self,
+        program_id: Pubkey,
+    ) -> Result<(SetLstCapKeys, usize), SControllerError> {
+        self.resolve_with_pdas(
+            find_pool_state_address(program_id).0,
+            find_lst_state_list_address(program_id).0,
+        )
+    }
+
+    /// Returns (keys, index of lst_mint in lst_state_list)
+    pub fn resolve_for_pool(
+        &self,
+        PoolPdas {
+            pool_state,
+            lst_state_list,
+            ..
+        }: PoolPdas,
+    ) -> Result<(SetLstCapKeys, usize), SControllerError> {
+        self.resolve_with_pdas(pool_state, lst_state_list)
+    }
+
+    fn resolve_with_pdas(
+        &self,
+        pool_state: Pubkey,
+        lst_state_list: Pubkey,
+    ) -> Result<(SetLstCapKeys, usize), SControllerError> {
+        let Self {
+            lst_mint,
+            pool_state: pool_state_account,
+            lst_state_list: lst_state_list_account,
+        } = self;
+        let lst_state_list_data = lst_state_list_account.data();
+        let lst_state_list_entries = try_lst_state_list(&lst_state_list_data)?;
+        let (lst_index, LstState { mint, .. }) =
+            try_find_lst_mint_on_list(*lst_mint, lst_state_list_entries)?;
+
+        let pool_state_data = pool_state_account.data();
+        let PoolState { admin, .. } = try_pool_state(&pool_state_data)?;
+
+        Ok((
+            SetLstCapKeys {
+                admin: *admin,
+                lst_mint: *mint,
+                pool_state,
+                lst_state_list,
+            },
+            lst_index,
+        ))
+    }
+}
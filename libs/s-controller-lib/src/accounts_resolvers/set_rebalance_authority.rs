@@ -2,7 +2,7 @@ use s_controller_interface::{SControllerError, SetRebalanceAuthorityKeys};
 use solana_program::pubkey::Pubkey;
 use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountPubkey};
 
-use crate::{find_pool_state_address, program::POOL_STATE_ID, try_pool_state};
+use crate::{find_pool_state_address, try_pool_state, PoolPdas};
 
 #[derive(Clone, Copy, Debug)]
 pub struct SetRebalanceAuthorityFreeArgs {
@@ -12,7 +12,14 @@ pub struct SetRebalanceAuthorityFreeArgs {
 
 impl SetRebalanceAuthorityFreeArgs {
     pub fn resolve(self) -> SetRebalanceAuthorityKeys {
-        self.resolve_with_pool_state_id(POOL_STATE_ID)
+        self.resolve_for_pool(PoolPdas::DEFAULT)
+    }
+
+    pub fn resolve_for_pool(
+        self,
+        PoolPdas { pool_state, .. }: PoolPdas,
+    ) -> SetRebalanceAuthorityKeys {
+        self.resolve_with_pool_state_id(pool_state)
     }
 
     pub fn resolve_for_prog(self, program_id: Pubkey) -> SetRebalanceAuthorityKeys {
@@ -0,0 +1,48 @@
+use s_controller_interface::{SControllerError, SetRebalanceLossLimitKeys};
+use solana_program::{pubkey::Pubkey, system_program};
+use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountPubkey};
+
+use crate::{try_pool_state, PoolPdas};
+
+#[derive(Clone, Copy, Debug)]
+pub struct SetRebalanceLossLimitFreeArgs<S: ReadonlyAccountData + ReadonlyAccountPubkey> {
+    pub payer: Pubkey,
+    pub pool_state: S,
+}
+
+impl<S: ReadonlyAccountData + ReadonlyAccountPubkey> SetRebalanceLossLimitFreeArgs<S> {
+    pub fn resolve(&self) -> Result<SetRebalanceLossLimitKeys, SControllerError> {
+        self.resolve_for_pool(PoolPdas::DEFAULT)
+    }
+
+    pub fn resolve_for_pool(
+        &self,
+        PoolPdas {
+            pool_state: pool_state_id,
+            rebalance_loss_state,
+            ..
+        }: PoolPdas,
+    ) -> Result<SetRebalanceLossLimitKeys, SControllerError> {
+        if *self.pool_state.pubkey() != pool_state_id {
+            return Err(SControllerError::IncorrectPoolState);
+        }
+
+        let pool_state_data = self.pool_state.data();
+        let pool_state = try_pool_state(&pool_state_data)?;
+
+        Ok(SetRebalanceLossLimitKeys {
+            payer: self.payer,
+            admin: pool_state.admin,
+            pool_state: pool_state_id,
+            rebalance_loss_state,
+            system_program: system_program::ID,
+        })
+    }
+
+    pub fn resolve_for_prog(
+        &self,
+        program_id: Pubkey,
+    ) -> Result<SetRebalanceLossLimitKeys, SControllerError> {
+        self.resolve_for_pool(PoolPdas::find_for_program_id(program_id))
+    }
+}
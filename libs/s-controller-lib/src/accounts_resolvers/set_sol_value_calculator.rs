@@ -3,9 +3,9 @@ use solana_program::pubkey::Pubkey;
 use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountOwner, ReadonlyAccountPubkey};
 
 use crate::{
-    create_pool_reserves_address, find_lst_state_list_address, find_pool_state_address,
-    program::{LST_STATE_LIST_ID, POOL_STATE_ID},
-    try_find_lst_mint_on_list, try_lst_state_list, try_match_lst_mint_on_list, try_pool_state,
+    create_pool_reserves_address_with_pool_state_id, find_lst_state_list_address,
+    find_pool_state_address, try_find_lst_mint_on_list, try_lst_state_list,
+    try_match_lst_mint_on_list, try_pool_state, PoolPdas,
 };
 
 #[derive(Clone, Copy, Debug)]
@@ -28,20 +28,31 @@ impl<
     > SetSolValueCalculatorFreeArgs<S, L, M>
 {
     pub fn resolve(&self) -> Result<SetSolValueCalculatorKeys, SControllerError> {
+        self.resolve_for_pool(PoolPdas::DEFAULT)
+    }
+
+    pub fn resolve_for_pool(
+        &self,
+        PoolPdas {
+            pool_state: pool_state_id,
+            lst_state_list: lst_state_list_id,
+            ..
+        }: PoolPdas,
+    ) -> Result<SetSolValueCalculatorKeys, SControllerError> {
         let Self {
             pool_state: pool_state_account,
             lst_state_list: lst_state_list_account,
             ..
         } = self;
-        if *pool_state_account.pubkey() != POOL_STATE_ID {
+        if *pool_state_account.pubkey() != pool_state_id {
             return Err(SControllerError::IncorrectPoolState);
         }
-        if *lst_state_list_account.pubkey() != LST_STATE_LIST_ID {
+        if *lst_state_list_account.pubkey() != lst_state_list_id {
             return Err(SControllerError::IncorrectLstStateList);
         }
         self.resolve_inner(ResolveInner {
-            pool_state_id: POOL_STATE_ID,
-            lst_state_list_id: LST_STATE_LIST_ID,
+            pool_state_id,
+            lst_state_list_id,
         })
     }
 }
@@ -78,7 +89,11 @@ impl<
         let lst_state_list_data = lst_state_list_acc.data();
         let lst_state_list = try_lst_state_list(&lst_state_list_data)?;
         let lst_state = try_match_lst_mint_on_list(*lst_mint.pubkey(), lst_state_list, *lst_index)?;
-        let pool_reserves = create_pool_reserves_address(lst_state, *lst_mint.owner())?;
+        let pool_reserves = create_pool_reserves_address_with_pool_state_id(
+            pool_state_id,
+            lst_state,
+            *lst_mint.owner(),
+        )?;
 
         let pool_state_data = pool_state_acc.data();
         let pool_state = try_pool_state(&pool_state_data)?;
@@ -111,9 +126,21 @@ impl<
 {
     /// Returns (keys, lst_index)
     pub fn resolve(&self) -> Result<(SetSolValueCalculatorKeys, usize), SControllerError> {
+        self.resolve_for_pool(PoolPdas::DEFAULT)
+    }
+
+    /// Returns (keys, lst_index)
+    pub fn resolve_for_pool(
+        &self,
+        PoolPdas {
+            pool_state,
+            lst_state_list,
+            ..
+        }: PoolPdas,
+    ) -> Result<(SetSolValueCalculatorKeys, usize), SControllerError> {
         self.resolve_inner(ResolveInner {
-            pool_state_id: POOL_STATE_ID,
-            lst_state_list_id: LST_STATE_LIST_ID,
+            pool_state_id: pool_state,
+            lst_state_list_id: lst_state_list,
         })
     }
 
@@ -142,7 +169,11 @@ impl<
         let lst_state_list_data = lst_state_list_account.data();
         let lst_state_list = try_lst_state_list(&lst_state_list_data)?;
         let (lst_index, lst_state) = try_find_lst_mint_on_list(*lst_mint.pubkey(), lst_state_list)?;
-        let pool_reserves = create_pool_reserves_address(lst_state, *lst_mint.owner())?;
+        let pool_reserves = create_pool_reserves_address_with_pool_state_id(
+            pool_state_id,
+            lst_state,
+            *lst_mint.owner(),
+        )?;
 
         let pool_state_data = pool_state_account.data();
         let pool_state = try_pool_state(&pool_state_data)?;
@@ -0,0 +1,47 @@
+use s_controller_interface::{SControllerError, SetTimelockDelayKeys};
+use solana_program::pubkey::Pubkey;
+use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountPubkey};
+
+use crate::{try_pool_state, PoolPdas};
+
+#[derive(Clone, Copy, Debug)]
+pub struct SetTimelockDelayFreeArgs<S: ReadonlyAccountData + ReadonlyAccountPubkey> {
+    pub pool_state: S,
+}
+
+impl<S: ReadonlyAccountData + ReadonlyAccountPubkey> SetTimelockDelayFreeArgs<S> {
+    pub fn resolve(self) -> Result<SetTimelockDelayKeys, SControllerError> {
+        self.resolve_for_pool(PoolPdas::DEFAULT)
+    }
+
+    pub fn resolve_for_pool(
+        self,
+        PoolPdas { pool_state, .. }: PoolPdas,
+    ) -> Result<SetTimelockDelayKeys, SControllerError> {
+        self.resolve_inner(pool_state)
+    }
+
+    pub fn resolve_for_prog(
+        self,
+        program_id: Pubkey,
+    ) -> Result<SetTimelockDelayKeys, SControllerError> {
+        self.resolve_for_pool(PoolPdas::find_for_program_id(program_id))
+    }
+
+    fn resolve_inner(
+        self,
+        pool_state_id: Pubkey,
+    ) -> Result<SetTimelockDelayKeys, SControllerError> {
+        if *self.pool_state.pubkey() != pool_state_id {
+            return Err(SControllerError::IncorrectPoolState);
+        }
+
+        let pool_state_data = self.pool_state.data();
+        let pool_state = try_pool_state(&pool_state_data)?;
+
+        Ok(SetTimelockDelayKeys {
+            admin: pool_state.admin,
+            pool_state: pool_state_id,
+        })
+    }
+}
@@ -3,10 +3,9 @@ use solana_program::{pubkey::Pubkey, system_program, sysvar};
 use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountOwner, ReadonlyAccountPubkey};
 
 use crate::{
-    create_pool_reserves_address,
-    program::{LST_STATE_LIST_ID, POOL_STATE_ID, REBALANCE_RECORD_ID},
-    try_find_lst_mint_on_list, try_lst_state_list, try_match_lst_mint_on_list, try_pool_state,
-    SrcDstLstIndexes, SrcDstLstSolValueCalcProgramIds,
+    create_pool_reserves_address_with_pool_state_id, try_find_lst_mint_on_list, try_lst_state_list,
+    try_match_lst_mint_on_list, try_pool_state, PoolPdas, SrcDstLstIndexes,
+    SrcDstLstSolValueCalcProgramIds,
 };
 
 #[derive(Clone, Copy, Debug)]
@@ -33,10 +32,23 @@ impl<
     > StartRebalanceFreeArgs<SM, DM, S, L>
 {
     pub fn resolve(self) -> Result<StartRebalanceKeys, SControllerError> {
-        if *self.lst_state_list.pubkey() != LST_STATE_LIST_ID {
+        self.resolve_for_pool(PoolPdas::DEFAULT)
+    }
+
+    pub fn resolve_for_pool(
+        self,
+        PoolPdas {
+            pool_state: pool_state_id,
+            lst_state_list: lst_state_list_id,
+            rebalance_record: rebalance_record_id,
+            rebalance_loss_state: rebalance_loss_state_id,
+            ..
+        }: PoolPdas,
+    ) -> Result<StartRebalanceKeys, SControllerError> {
+        if *self.lst_state_list.pubkey() != lst_state_list_id {
             return Err(SControllerError::IncorrectLstStateList);
         }
-        if *self.pool_state.pubkey() != POOL_STATE_ID {
+        if *self.pool_state.pubkey() != pool_state_id {
             return Err(SControllerError::IncorrectPoolState);
         }
 
@@ -45,22 +57,28 @@ impl<
 
         let src_lst_state =
             try_match_lst_mint_on_list(*self.src_lst_mint.pubkey(), list, self.src_lst_index)?;
-        let src_pool_reserves =
-            create_pool_reserves_address(src_lst_state, *self.src_lst_mint.owner())?;
+        let src_pool_reserves = create_pool_reserves_address_with_pool_state_id(
+            pool_state_id,
+            src_lst_state,
+            *self.src_lst_mint.owner(),
+        )?;
 
         let dst_lst_state =
             try_match_lst_mint_on_list(*self.dst_lst_mint.pubkey(), list, self.dst_lst_index)?;
-        let dst_pool_reserves =
-            create_pool_reserves_address(dst_lst_state, *self.dst_lst_mint.owner())?;
+        let dst_pool_reserves = create_pool_reserves_address_with_pool_state_id(
+            pool_state_id,
+            dst_lst_state,
+            *self.dst_lst_mint.owner(),
+        )?;
 
         let pool_state_acc_data = self.pool_state.data();
         let pool_state = try_pool_state(&pool_state_acc_data)?;
 
         Ok(StartRebalanceKeys {
             rebalance_authority: pool_state.rebalance_authority,
-            pool_state: POOL_STATE_ID,
-            lst_state_list: LST_STATE_LIST_ID,
-            rebalance_record: REBALANCE_RECORD_ID,
+            pool_state: pool_state_id,
+            lst_state_list: lst_state_list_id,
+            rebalance_record: rebalance_record_id,
             src_lst_mint: src_lst_state.mint,
             dst_lst_mint: dst_lst_state.mint,
             src_pool_reserves,
@@ -69,6 +87,7 @@ impl<
             instructions: sysvar::instructions::ID,
             system_program: system_program::ID,
             src_lst_token_program: *self.src_lst_mint.owner(),
+            rebalance_loss_state: rebalance_loss_state_id,
         })
     }
 }
@@ -106,10 +125,46 @@ impl<
         ),
         SControllerError,
     > {
-        if *self.lst_state_list.pubkey() != LST_STATE_LIST_ID {
+        self.resolve_for_pool(PoolPdas::DEFAULT)
+    }
+
+    /// Returns (keys, lst indexes, sol value calculator program IDs)
+    pub fn resolve_for_prog(
+        self,
+        program_id: Pubkey,
+    ) -> Result<
+        (
+            StartRebalanceKeys,
+            SrcDstLstIndexes,
+            SrcDstLstSolValueCalcProgramIds,
+        ),
+        SControllerError,
+    > {
+        self.resolve_for_pool(PoolPdas::find_for_program_id(program_id))
+    }
+
+    /// Returns (keys, lst indexes, sol value calculator program IDs)
+    pub fn resolve_for_pool(
+        self,
+        PoolPdas {
+            pool_state: pool_state_id,
+            lst_state_list: lst_state_list_id,
+            rebalance_record: rebalance_record_id,
+            rebalance_loss_state: rebalance_loss_state_id,
+            ..
+        }: PoolPdas,
+    ) -> Result<
+        (
+            StartRebalanceKeys,
+            SrcDstLstIndexes,
+            SrcDstLstSolValueCalcProgramIds,
+        ),
+        SControllerError,
+    > {
+        if *self.lst_state_list.pubkey() != lst_state_list_id {
             return Err(SControllerError::IncorrectLstStateList);
         }
-        if *self.pool_state.pubkey() != POOL_STATE_ID {
+        if *self.pool_state.pubkey() != pool_state_id {
             return Err(SControllerError::IncorrectPoolState);
         }
 
@@ -118,13 +173,19 @@ impl<
 
         let (src_lst_index, src_lst_state) =
             try_find_lst_mint_on_list(*self.src_lst_mint.pubkey(), list)?;
-        let src_pool_reserves =
-            create_pool_reserves_address(src_lst_state, *self.src_lst_mint.owner())?;
+        let src_pool_reserves = create_pool_reserves_address_with_pool_state_id(
+            pool_state_id,
+            src_lst_state,
+            *self.src_lst_mint.owner(),
+        )?;
 
         let (dst_lst_index, dst_lst_state) =
             try_find_lst_mint_on_list(*self.dst_lst_mint.pubkey(), list)?;
-        let dst_pool_reserves =
-            create_pool_reserves_address(dst_lst_state, *self.dst_lst_mint.owner())?;
+        let dst_pool_reserves = create_pool_reserves_address_with_pool_state_id(
+            pool_state_id,
+            dst_lst_state,
+            *self.dst_lst_mint.owner(),
+        )?;
 
         let pool_state_acc_data = self.pool_state.data();
         let pool_state = try_pool_state(&pool_state_acc_data)?;
@@ -132,9 +193,9 @@ impl<
         Ok((
             StartRebalanceKeys {
                 rebalance_authority: pool_state.rebalance_authority,
-                pool_state: POOL_STATE_ID,
-                lst_state_list: LST_STATE_LIST_ID,
-                rebalance_record: REBALANCE_RECORD_ID,
+                pool_state: pool_state_id,
+                lst_state_list: lst_state_list_id,
+                rebalance_record: rebalance_record_id,
                 src_lst_mint: src_lst_state.mint,
                 dst_lst_mint: dst_lst_state.mint,
                 src_pool_reserves,
@@ -143,6 +204,7 @@ impl<
                 instructions: sysvar::instructions::ID,
                 system_program: system_program::ID,
                 src_lst_token_program: *self.src_lst_mint.owner(),
+                rebalance_loss_state: rebalance_loss_state_id,
             },
             SrcDstLstIndexes {
                 src_lst_index,
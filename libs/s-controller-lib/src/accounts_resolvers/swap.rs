@@ -3,11 +3,9 @@ use solana_program::pubkey::Pubkey;
 use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountOwner, ReadonlyAccountPubkey};
 
 use crate::{
-    create_pool_reserves_address, create_pool_reserves_address_with_pool_state_id,
-    create_protocol_fee_accumulator_address,
-    create_protocol_fee_accumulator_address_with_protocol_fee_id,
-    program::{LST_STATE_LIST_ID, POOL_STATE_ID, PROTOCOL_FEE_ID},
-    try_find_lst_mint_on_list, try_lst_state_list, try_match_lst_mint_on_list, SrcDstLstIndexes,
+    create_pool_reserves_address_with_pool_state_id,
+    create_protocol_fee_accumulator_address_with_protocol_fee_id, try_find_lst_mint_on_list,
+    try_lst_state_list, try_match_lst_mint_on_list, PoolPdas, SrcDstLstIndexes,
     SrcDstLstSolValueCalcProgramIds, SwapLiquidityPdas,
 };
 
@@ -38,7 +36,7 @@ impl<
         L: ReadonlyAccountData + ReadonlyAccountPubkey,
     > SwapFreeArgs<SM, DM, L>
 {
-    fn compute_keys(&self) -> Result<SwapComputedKeys, SControllerError> {
+    fn compute_keys(&self, pdas: &PoolPdas) -> Result<SwapComputedKeys, SControllerError> {
         let Self {
             lst_state_list: lst_state_list_account,
             src_lst_mint,
@@ -47,7 +45,7 @@ impl<
             dst_lst_index,
             ..
         } = self;
-        if *lst_state_list_account.pubkey() != LST_STATE_LIST_ID {
+        if *lst_state_list_account.pubkey() != pdas.lst_state_list {
             return Err(SControllerError::IncorrectLstStateList);
         }
 
@@ -56,13 +54,25 @@ impl<
 
         let src_lst_state =
             try_match_lst_mint_on_list(*src_lst_mint.pubkey(), lst_state_list, *src_lst_index)?;
-        let src_pool_reserves = create_pool_reserves_address(src_lst_state, *src_lst_mint.owner())?;
+        let src_pool_reserves = create_pool_reserves_address_with_pool_state_id(
+            pdas.pool_state,
+            src_lst_state,
+            *src_lst_mint.owner(),
+        )?;
 
         let dst_lst_state =
             try_match_lst_mint_on_list(*dst_lst_mint.pubkey(), lst_state_list, *dst_lst_index)?;
-        let dst_pool_reserves = create_pool_reserves_address(dst_lst_state, *dst_lst_mint.owner())?;
+        let dst_pool_reserves = create_pool_reserves_address_with_pool_state_id(
+            pdas.pool_state,
+            dst_lst_state,
+            *dst_lst_mint.owner(),
+        )?;
         let protocol_fee_accumulator =
-            create_protocol_fee_accumulator_address(dst_lst_state, *dst_lst_mint.owner())?;
+            create_protocol_fee_accumulator_address_with_protocol_fee_id(
+                pdas.protocol_fee,
+                dst_lst_state,
+                *dst_lst_mint.owner(),
+            )?;
 
         Ok(SwapComputedKeys {
             src_pool_reserves,
@@ -72,11 +82,18 @@ impl<
     }
 
     pub fn resolve_exact_in(&self) -> Result<SwapExactInKeys, SControllerError> {
+        self.resolve_exact_in_for_pool(PoolPdas::DEFAULT)
+    }
+
+    pub fn resolve_exact_in_for_pool(
+        &self,
+        pdas: PoolPdas,
+    ) -> Result<SwapExactInKeys, SControllerError> {
         let SwapComputedKeys {
             src_pool_reserves,
             dst_pool_reserves,
             protocol_fee_accumulator,
-        } = self.compute_keys()?;
+        } = self.compute_keys(&pdas)?;
         let Self {
             signer,
             src_lst_acc,
@@ -94,19 +111,26 @@ impl<
             protocol_fee_accumulator,
             src_lst_token_program: *src_lst_mint.owner(),
             dst_lst_token_program: *dst_lst_mint.owner(),
-            pool_state: POOL_STATE_ID,
-            lst_state_list: LST_STATE_LIST_ID,
+            pool_state: pdas.pool_state,
+            lst_state_list: pdas.lst_state_list,
             src_pool_reserves,
             dst_pool_reserves,
         })
     }
 
     pub fn resolve_exact_out(&self) -> Result<SwapExactOutKeys, SControllerError> {
+        self.resolve_exact_out_for_pool(PoolPdas::DEFAULT)
+    }
+
+    pub fn resolve_exact_out_for_pool(
+        &self,
+        pdas: PoolPdas,
+    ) -> Result<SwapExactOutKeys, SControllerError> {
         let SwapComputedKeys {
             src_pool_reserves,
             dst_pool_reserves,
             protocol_fee_accumulator,
-        } = self.compute_keys()?;
+        } = self.compute_keys(&pdas)?;
         let Self {
             signer,
             src_lst_acc,
@@ -124,8 +148,8 @@ impl<
             protocol_fee_accumulator,
             src_lst_token_program: *src_lst_mint.owner(),
             dst_lst_token_program: *dst_lst_mint.owner(),
-            pool_state: POOL_STATE_ID,
-            lst_state_list: LST_STATE_LIST_ID,
+            pool_state: pdas.pool_state,
+            lst_state_list: pdas.lst_state_list,
             src_pool_reserves,
             dst_pool_reserves,
         })
@@ -166,11 +190,7 @@ impl<
         ),
         SControllerError,
     > {
-        self.resolve_exact_in_with_pdas(SwapLiquidityPdas {
-            pool_state: POOL_STATE_ID,
-            lst_state_list: LST_STATE_LIST_ID,
-            protocol_fee: PROTOCOL_FEE_ID,
-        })
+        self.resolve_exact_in_with_pdas(PoolPdas::DEFAULT.into())
     }
 
     /// Returns
@@ -185,11 +205,7 @@ impl<
         ),
         SControllerError,
     > {
-        self.resolve_exact_out_with_pdas(SwapLiquidityPdas {
-            pool_state: POOL_STATE_ID,
-            lst_state_list: LST_STATE_LIST_ID,
-            protocol_fee: PROTOCOL_FEE_ID,
-        })
+        self.resolve_exact_out_with_pdas(PoolPdas::DEFAULT.into())
     }
 
     /// Returns
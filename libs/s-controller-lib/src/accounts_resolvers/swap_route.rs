@@ -0,0 +1,229 @@
+use s_controller_interface::{SControllerError, SwapExactInRouteKeys};
+use solana_program::{instruction::AccountMeta, pubkey::Pubkey};
+use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountOwner, ReadonlyAccountPubkey};
+
+use crate::{
+    create_pool_reserves_address_with_pool_state_id,
+    create_protocol_fee_accumulator_address_with_protocol_fee_id, try_find_lst_mint_on_list,
+    try_lst_state_list, try_match_lst_mint_on_list, PoolPdas, SwapLiquidityPdas,
+    MAX_SWAP_ROUTE_LSTS, SWAP_ROUTE_LST_ACCOUNTS_LEN,
+};
+
+/// The accounts of a single LST on a swap route.
+///
+/// These are appended to SwapExactInRoute's accounts for each LST on the route,
+/// in route order, before any SOL value calculator or pricing program accounts.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SwapRouteLstKeys {
+    pub lst_mint: Pubkey,
+    pub lst_token_program: Pubkey,
+    pub pool_reserves: Pubkey,
+    /// Only written to if this LST is not the first LST on the route
+    pub protocol_fee_accumulator: Pubkey,
+}
+
+impl From<SwapRouteLstKeys> for [AccountMeta; SWAP_ROUTE_LST_ACCOUNTS_LEN] {
+    fn from(
+        SwapRouteLstKeys {
+            lst_mint,
+            lst_token_program,
+            pool_reserves,
+            protocol_fee_accumulator,
+        }: SwapRouteLstKeys,
+    ) -> Self {
+        [
+            AccountMeta::new_readonly(lst_mint, false),
+            AccountMeta::new_readonly(lst_token_program, false),
+            AccountMeta::new(pool_reserves, false),
+            AccountMeta::new(protocol_fee_accumulator, false),
+        ]
+    }
+}
+
+pub struct SwapExactInRouteFreeArgs {
+    pub signer: Pubkey,
+    pub src_lst_acc: Pubkey,
+    pub dst_lst_acc: Pubkey,
+}
+
+impl SwapExactInRouteFreeArgs {
+    pub fn resolve(&self) -> SwapExactInRouteKeys {
+        self.resolve_for_pool(PoolPdas::DEFAULT)
+    }
+
+    pub fn resolve_for_pool(
+        &self,
+        PoolPdas {
+            pool_state,
+            lst_state_list,
+            ..
+        }: PoolPdas,
+    ) -> SwapExactInRouteKeys {
+        let Self {
+            signer,
+            src_lst_acc,
+            dst_lst_acc,
+        } = self;
+        SwapExactInRouteKeys {
+            signer: *signer,
+            src_lst_acc: *src_lst_acc,
+            dst_lst_acc: *dst_lst_acc,
+            pool_state,
+            lst_state_list,
+        }
+    }
+}
+
+/// Resolves the [`SwapRouteLstKeys`] of the LST at `lst_index` on the pool's list
+pub struct SwapRouteLstFreeArgs<
+    M: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+    L: ReadonlyAccountData + ReadonlyAccountPubkey,
+> {
+    pub lst_index: usize,
+    pub lst_mint: M,
+    pub lst_state_list: L,
+}
+
+impl<
+        M: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+        L: ReadonlyAccountData + ReadonlyAccountPubkey,
+    > SwapRouteLstFreeArgs<M, L>
+{
+    pub fn resolve(&self) -> Result<SwapRouteLstKeys, SControllerError> {
+        self.resolve_with_pdas(PoolPdas::DEFAULT.into())
+    }
+
+    pub fn resolve_with_pdas(
+        &self,
+        pdas: SwapLiquidityPdas,
+    ) -> Result<SwapRouteLstKeys, SControllerError> {
+        let Self {
+            lst_index,
+            lst_mint,
+            lst_state_list: lst_state_list_account,
+        } = self;
+        if *lst_state_list_account.pubkey() != pdas.lst_state_list {
+            return Err(SControllerError::IncorrectLstStateList);
+        }
+
+        let lst_state_list_acc_data = lst_state_list_account.data();
+        let lst_state_list = try_lst_state_list(&lst_state_list_acc_data)?;
+
+        let lst_state = try_match_lst_mint_on_list(*lst_mint.pubkey(), lst_state_list, *lst_index)?;
+        let lst_token_program = *lst_mint.owner();
+        Ok(SwapRouteLstKeys {
+            lst_mint: *lst_mint.pubkey(),
+            lst_token_program,
+            pool_reserves: create_pool_reserves_address_with_pool_state_id(
+                pdas.pool_state,
+                lst_state,
+                lst_token_program,
+            )?,
+            protocol_fee_accumulator: create_protocol_fee_accumulator_address_with_protocol_fee_id(
+                pdas.protocol_fee,
+                lst_state,
+                lst_token_program,
+            )?,
+        })
+    }
+}
+
+/// Iterates through lst_state_list to find the lst indexes of every LST on the route.
+/// Suitable for use on client side.
+/// Does not check identity of lst_state_list
+pub struct SwapRouteByMintsFreeArgs<
+    'a,
+    M: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+    L: ReadonlyAccountData,
+> {
+    pub signer: Pubkey,
+    pub src_lst_acc: Pubkey,
+    pub dst_lst_acc: Pubkey,
+    /// Mints of every LST on the route, including the first (src) and last (dst) LST
+    pub lst_mints: &'a [M],
+    pub lst_state_list: L,
+}
+
+/// Per-LST data of a resolved swap route, in route order
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SwapRouteResolved {
+    pub lst_keys: Vec<SwapRouteLstKeys>,
+    pub lst_indexes: Vec<usize>,
+    pub sol_value_calculator_program_ids: Vec<Pubkey>,
+}
+
+impl<'a, M: ReadonlyAccountOwner + ReadonlyAccountPubkey, L: ReadonlyAccountData>
+    SwapRouteByMintsFreeArgs<'a, M, L>
+{
+    pub fn resolve_exact_in(
+        &self,
+    ) -> Result<(SwapExactInRouteKeys, SwapRouteResolved), SControllerError> {
+        self.resolve_exact_in_with_pdas(PoolPdas::DEFAULT.into())
+    }
+
+    pub fn resolve_exact_in_for_prog(
+        &self,
+        program_id: Pubkey,
+    ) -> Result<(SwapExactInRouteKeys, SwapRouteResolved), SControllerError> {
+        self.resolve_exact_in_with_pdas(SwapLiquidityPdas::find_for_program_id(program_id))
+    }
+
+    pub fn resolve_exact_in_with_pdas(
+        &self,
+        pdas: SwapLiquidityPdas,
+    ) -> Result<(SwapExactInRouteKeys, SwapRouteResolved), SControllerError> {
+        let Self {
+            signer,
+            src_lst_acc,
+            dst_lst_acc,
+            lst_mints,
+            lst_state_list: lst_state_list_account,
+        } = self;
+        if lst_mints.len() < 2 || lst_mints.len() > MAX_SWAP_ROUTE_LSTS {
+            return Err(SControllerError::InvalidSwapRoute);
+        }
+
+        let lst_state_list_acc_data = lst_state_list_account.data();
+        let lst_state_list = try_lst_state_list(&lst_state_list_acc_data)?;
+
+        let mut resolved = SwapRouteResolved::default();
+        for lst_mint in lst_mints.iter() {
+            let (lst_index, lst_state) =
+                try_find_lst_mint_on_list(*lst_mint.pubkey(), lst_state_list)?;
+            if resolved.lst_indexes.contains(&lst_index) {
+                return Err(SControllerError::SwapSameLst);
+            }
+            let lst_token_program = *lst_mint.owner();
+            resolved.lst_keys.push(SwapRouteLstKeys {
+                lst_mint: *lst_mint.pubkey(),
+                lst_token_program,
+                pool_reserves: create_pool_reserves_address_with_pool_state_id(
+                    pdas.pool_state,
+                    lst_state,
+                    lst_token_program,
+                )?,
+                protocol_fee_accumulator:
+                    create_protocol_fee_accumulator_address_with_protocol_fee_id(
+                        pdas.protocol_fee,
+                        lst_state,
+                        lst_token_program,
+                    )?,
+            });
+            resolved.lst_indexes.push(lst_index);
+            resolved
+                .sol_value_calculator_program_ids
+                .push(lst_state.sol_value_calculator);
+        }
+
+        Ok((
+            SwapExactInRouteKeys {
+                signer: *signer,
+                src_lst_acc: *src_lst_acc,
+                dst_lst_acc: *dst_lst_acc,
+                pool_state: pdas.pool_state,
+                lst_state_list: pdas.lst_state_list,
+            },
+            resolved,
+        ))
+    }
+}
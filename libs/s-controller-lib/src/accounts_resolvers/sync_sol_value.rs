@@ -3,9 +3,9 @@ use solana_program::pubkey::Pubkey;
 use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountOwner, ReadonlyAccountPubkey};
 
 use crate::{
-    create_pool_reserves_address, find_lst_state_list_address, find_pool_state_address,
-    program::{LST_STATE_LIST_ID, POOL_STATE_ID},
-    try_find_lst_mint_on_list, try_lst_state_list, try_match_lst_mint_on_list,
+    create_pool_reserves_address_with_pool_state_id, find_lst_state_list_address,
+    find_pool_state_address, try_find_lst_mint_on_list, try_lst_state_list,
+    try_match_lst_mint_on_list, PoolPdas,
 };
 
 #[derive(Clone, Copy, Debug)]
@@ -24,19 +24,34 @@ impl<
     > SyncSolValueFreeArgs<L, M>
 {
     pub fn resolve(self) -> Result<SyncSolValueKeys, SControllerError> {
-        if *self.lst_state_list.pubkey() != LST_STATE_LIST_ID {
+        self.resolve_for_pool(PoolPdas::DEFAULT)
+    }
+
+    pub fn resolve_for_pool(
+        self,
+        PoolPdas {
+            pool_state,
+            lst_state_list,
+            ..
+        }: PoolPdas,
+    ) -> Result<SyncSolValueKeys, SControllerError> {
+        if *self.lst_state_list.pubkey() != lst_state_list {
             return Err(SControllerError::IncorrectLstStateList);
         }
         let lst_state_list_acc_data = self.lst_state_list.data();
         let list = try_lst_state_list(&lst_state_list_acc_data)?;
 
         let lst_state = try_match_lst_mint_on_list(*self.lst_mint.pubkey(), list, self.lst_index)?;
-        let pool_reserves = create_pool_reserves_address(lst_state, *self.lst_mint.owner())?;
+        let pool_reserves = create_pool_reserves_address_with_pool_state_id(
+            pool_state,
+            lst_state,
+            *self.lst_mint.owner(),
+        )?;
 
         Ok(SyncSolValueKeys {
             lst_mint: lst_state.mint,
-            pool_state: POOL_STATE_ID,
-            lst_state_list: LST_STATE_LIST_ID,
+            pool_state,
+            lst_state_list,
             pool_reserves,
         })
     }
@@ -56,16 +71,28 @@ pub struct SyncSolValuePdas {
     pub lst_state_list: Pubkey,
 }
 
+impl From<PoolPdas> for SyncSolValuePdas {
+    fn from(
+        PoolPdas {
+            pool_state,
+            lst_state_list,
+            ..
+        }: PoolPdas,
+    ) -> Self {
+        Self {
+            pool_state,
+            lst_state_list,
+        }
+    }
+}
+
 impl<L: ReadonlyAccountData, M: ReadonlyAccountOwner + ReadonlyAccountPubkey>
     SyncSolValueByMintFreeArgs<L, M>
 {
     /// Does not check identity of pool_state and lst_state_list
     /// Returns (keys, index of lst_mint on lst_state_list, sol value calculator program ID)
     pub fn resolve(self) -> Result<(SyncSolValueKeys, usize, Pubkey), SControllerError> {
-        self.resolve_with_pdas(SyncSolValuePdas {
-            pool_state: POOL_STATE_ID,
-            lst_state_list: LST_STATE_LIST_ID,
-        })
+        self.resolve_with_pdas(PoolPdas::DEFAULT.into())
     }
 
     /// Does not check identity of pool_state and lst_state_list
@@ -93,7 +120,11 @@ impl<L: ReadonlyAccountData, M: ReadonlyAccountOwner + ReadonlyAccountPubkey>
         let list = try_lst_state_list(&lst_state_list_acc_data)?;
 
         let (lst_index, lst_state) = try_find_lst_mint_on_list(*self.lst_mint.pubkey(), list)?;
-        let pool_reserves = create_pool_reserves_address(lst_state, *self.lst_mint.owner())?;
+        let pool_reserves = create_pool_reserves_address_with_pool_state_id(
+            pool_state,
+            lst_state,
+            *self.lst_mint.owner(),
+        )?;
 
         Ok((
             SyncSolValueKeys {
@@ -4,10 +4,8 @@ use solana_program::{program_error::ProgramError, pubkey::Pubkey};
 use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountOwner, ReadonlyAccountPubkey};
 
 use crate::{
-    find_pool_state_address, find_protocol_fee_accumulator_address,
-    find_protocol_fee_accumulator_address_with_protocol_fee_id, find_protocol_fee_address,
-    program::{POOL_STATE_ID, PROTOCOL_FEE_ID},
-    try_pool_state, FindLstPdaAtaKeys,
+    find_pool_state_address, find_protocol_fee_accumulator_address_with_protocol_fee_id,
+    find_protocol_fee_address, try_pool_state, FindLstPdaAtaKeys, PoolPdas,
 };
 
 #[derive(Clone, Copy, Debug)]
@@ -29,21 +27,35 @@ impl<
     > WithdrawProtocolFeesFreeArgs<S, W>
 {
     pub fn resolve(self) -> Result<WithdrawProtocolFeesKeys, ProgramError> {
+        self.resolve_for_pool(PoolPdas::DEFAULT)
+    }
+
+    pub fn resolve_for_pool(
+        self,
+        PoolPdas {
+            pool_state: pool_state_id,
+            protocol_fee: protocol_fee_id,
+            ..
+        }: PoolPdas,
+    ) -> Result<WithdrawProtocolFeesKeys, ProgramError> {
         let WithdrawProtocolFeesFreeArgs {
             pool_state,
             withdraw_to,
         } = self;
 
-        if *pool_state.pubkey() != POOL_STATE_ID {
+        if *pool_state.pubkey() != pool_state_id {
             return Err(SControllerError::IncorrectPoolState.into());
         }
 
         let lst_mint = token_account_mint(&withdraw_to)?;
         let (protocol_fee_accumulator, _protocol_fee_accumulator_bump) =
-            find_protocol_fee_accumulator_address(FindLstPdaAtaKeys {
-                lst_mint,
-                token_program: *withdraw_to.owner(),
-            });
+            find_protocol_fee_accumulator_address_with_protocol_fee_id(
+                protocol_fee_id,
+                FindLstPdaAtaKeys {
+                    lst_mint,
+                    token_program: *withdraw_to.owner(),
+                },
+            );
         WithdrawProtocolFeesByMintFreeArgs {
             pool_state,
             withdraw_to: *withdraw_to.pubkey(),
@@ -53,8 +65,8 @@ impl<
             },
         }
         .resolve_with_pdas(WithdrawProtocolFeesPdas {
-            pool_state: POOL_STATE_ID,
-            protocol_fee_accumulator_auth: PROTOCOL_FEE_ID,
+            pool_state: pool_state_id,
+            protocol_fee_accumulator_auth: protocol_fee_id,
             protocol_fee_accumulator,
         })
     }
@@ -90,6 +102,29 @@ impl<S: ReadonlyAccountData, M: ReadonlyAccountOwner + ReadonlyAccountPubkey>
         })
     }
 
+    pub fn resolve_for_pool(
+        self,
+        PoolPdas {
+            pool_state,
+            protocol_fee,
+            ..
+        }: PoolPdas,
+    ) -> Result<WithdrawProtocolFeesKeys, ProgramError> {
+        let protocol_fee_accumulator = find_protocol_fee_accumulator_address_with_protocol_fee_id(
+            protocol_fee,
+            FindLstPdaAtaKeys {
+                lst_mint: *self.lst_mint.pubkey(),
+                token_program: *self.lst_mint.owner(),
+            },
+        )
+        .0;
+        self.resolve_with_pdas(WithdrawProtocolFeesPdas {
+            pool_state,
+            protocol_fee_accumulator_auth: protocol_fee,
+            protocol_fee_accumulator,
+        })
+    }
+
     pub fn resolve_with_pdas(
         self,
         WithdrawProtocolFeesPdas {
@@ -0,0 +1,122 @@
+use s_controller_interface::{SControllerError, WithdrawProtocolFeesMultiKeys};
+use solana_program::{instruction::AccountMeta, program_error::ProgramError, pubkey::Pubkey};
+use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountOwner, ReadonlyAccountPubkey};
+
+use crate::{
+    find_protocol_fee_accumulator_address_with_protocol_fee_id, find_protocol_fee_address,
+    try_pool_state, FindLstPdaAtaKeys, PoolPdas, WITHDRAW_PROTOCOL_FEES_MULTI_LST_ACCOUNTS_LEN,
+};
+
+#[derive(Clone, Copy, Debug)]
+pub struct WithdrawProtocolFeesMultiFreeArgs<S> {
+    pub pool_state: S,
+}
+
+impl<S: ReadonlyAccountData + ReadonlyAccountPubkey> WithdrawProtocolFeesMultiFreeArgs<S> {
+    pub fn resolve(self) -> Result<WithdrawProtocolFeesMultiKeys, ProgramError> {
+        self.resolve_for_pool(PoolPdas::DEFAULT)
+    }
+
+    pub fn resolve_for_pool(
+        self,
+        PoolPdas {
+            pool_state: pool_state_id,
+            protocol_fee,
+            ..
+        }: PoolPdas,
+    ) -> Result<WithdrawProtocolFeesMultiKeys, ProgramError> {
+        let Self { pool_state } = self;
+
+        if *pool_state.pubkey() != pool_state_id {
+            return Err(SControllerError::IncorrectPoolState.into());
+        }
+
+        let pool_state_data = pool_state.data();
+        let protocol_fee_beneficiary = try_pool_state(&pool_state_data)?.protocol_fee_beneficiary;
+
+        Ok(WithdrawProtocolFeesMultiKeys {
+            protocol_fee_beneficiary,
+            protocol_fee_accumulator_auth: protocol_fee,
+            pool_state: pool_state_id,
+        })
+    }
+}
+
+/// The accounts of a single LST of a WithdrawProtocolFeesMulti instruction.
+///
+/// These are appended to the instruction's accounts for each LST, in order.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct WithdrawProtocolFeesMultiLstKeys {
+    pub lst_mint: Pubkey,
+    pub token_program: Pubkey,
+    pub protocol_fee_accumulator: Pubkey,
+    pub withdraw_to: Pubkey,
+}
+
+impl From<WithdrawProtocolFeesMultiLstKeys>
+    for [AccountMeta; WITHDRAW_PROTOCOL_FEES_MULTI_LST_ACCOUNTS_LEN]
+{
+    fn from(
+        WithdrawProtocolFeesMultiLstKeys {
+            lst_mint,
+            token_program,
+            protocol_fee_accumulator,
+            withdraw_to,
+        }: WithdrawProtocolFeesMultiLstKeys,
+    ) -> Self {
+        [
+            AccountMeta::new_readonly(lst_mint, false),
+            AccountMeta::new_readonly(token_program, false),
+            AccountMeta::new(protocol_fee_accumulator, false),
+            AccountMeta::new(withdraw_to, false),
+        ]
+    }
+}
+
+/// A LST mint and the token account to withdraw its accumulated protocol fees to
+#[derive(Clone, Copy, Debug)]
+pub struct WithdrawProtocolFeesMultiLstFreeArgs<M> {
+    pub lst_mint: M,
+    pub withdraw_to: Pubkey,
+}
+
+impl<M: ReadonlyAccountOwner + ReadonlyAccountPubkey> WithdrawProtocolFeesMultiLstFreeArgs<M> {
+    pub fn resolve(&self) -> WithdrawProtocolFeesMultiLstKeys {
+        self.resolve_for_pool(PoolPdas::DEFAULT)
+    }
+
+    pub fn resolve_for_prog(&self, program_id: Pubkey) -> WithdrawProtocolFeesMultiLstKeys {
+        self.resolve_with_protocol_fee_id(find_protocol_fee_address(program_id).0)
+    }
+
+    pub fn resolve_for_pool(
+        &self,
+        PoolPdas { protocol_fee, .. }: PoolPdas,
+    ) -> WithdrawProtocolFeesMultiLstKeys {
+        self.resolve_with_protocol_fee_id(protocol_fee)
+    }
+
+    pub fn resolve_with_protocol_fee_id(
+        &self,
+        protocol_fee_id: Pubkey,
+    ) -> WithdrawProtocolFeesMultiLstKeys {
+        let Self {
+            lst_mint,
+            withdraw_to,
+        } = self;
+        let (protocol_fee_accumulator, _bump) =
+            find_protocol_fee_accumulator_address_with_protocol_fee_id(
+                protocol_fee_id,
+                FindLstPdaAtaKeys {
+                    lst_mint: *lst_mint.pubkey(),
+                    token_program: *lst_mint.owner(),
+                },
+            );
+        WithdrawProtocolFeesMultiLstKeys {
+            lst_mint: *lst_mint.pubkey(),
+            token_program: *lst_mint.owner(),
+            protocol_fee_accumulator,
+            withdraw_to: *withdraw_to,
+        }
+    }
+}
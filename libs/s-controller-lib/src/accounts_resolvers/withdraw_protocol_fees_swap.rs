@@ -0,0 +1,227 @@
+use s_controller_interface::{LstState, SControllerError, WithdrawProtocolFeesSwapKeys};
+use solana_program::pubkey::Pubkey;
+use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountOwner, ReadonlyAccountPubkey};
+
+use crate::{
+    create_pool_reserves_address_with_pool_state_id,
+    create_protocol_fee_accumulator_address_with_protocol_fee_id, try_find_lst_mint_on_list,
+    try_lst_state_list, try_match_lst_mint_on_list, try_pool_state, PoolPdas, SrcDstLstIndexes,
+    SrcDstLstSolValueCalcProgramIds,
+};
+
+#[derive(Clone, Copy, Debug)]
+pub struct WithdrawProtocolFeesSwapFreeArgs<
+    SM: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+    DM: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+    S: ReadonlyAccountData + ReadonlyAccountPubkey,
+    L: ReadonlyAccountData + ReadonlyAccountPubkey,
+> {
+    pub withdraw_to: Pubkey,
+    pub src_lst_index: usize,
+    pub dst_lst_index: usize,
+    pub lst_state_list: L,
+    pub pool_state: S,
+    pub src_lst_mint: SM,
+    pub dst_lst_mint: DM,
+}
+
+impl<
+        SM: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+        DM: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+        S: ReadonlyAccountData + ReadonlyAccountPubkey,
+        L: ReadonlyAccountData + ReadonlyAccountPubkey,
+    > WithdrawProtocolFeesSwapFreeArgs<SM, DM, S, L>
+{
+    pub fn resolve(self) -> Result<WithdrawProtocolFeesSwapKeys, SControllerError> {
+        self.resolve_for_pool(PoolPdas::DEFAULT)
+    }
+
+    pub fn resolve_for_pool(
+        self,
+        pdas: PoolPdas,
+    ) -> Result<WithdrawProtocolFeesSwapKeys, SControllerError> {
+        if *self.lst_state_list.pubkey() != pdas.lst_state_list {
+            return Err(SControllerError::IncorrectLstStateList);
+        }
+        if *self.pool_state.pubkey() != pdas.pool_state {
+            return Err(SControllerError::IncorrectPoolState);
+        }
+
+        let lst_state_list_acc_data = self.lst_state_list.data();
+        let list = try_lst_state_list(&lst_state_list_acc_data)?;
+        let src_lst_state =
+            try_match_lst_mint_on_list(*self.src_lst_mint.pubkey(), list, self.src_lst_index)?;
+        let dst_lst_state =
+            try_match_lst_mint_on_list(*self.dst_lst_mint.pubkey(), list, self.dst_lst_index)?;
+
+        withdraw_protocol_fees_swap_keys(
+            pdas,
+            &self.pool_state,
+            WithdrawProtocolFeesSwapLsts {
+                src_lst_state,
+                src_lst_token_program: *self.src_lst_mint.owner(),
+                dst_lst_state,
+                dst_lst_token_program: *self.dst_lst_mint.owner(),
+            },
+            self.withdraw_to,
+        )
+    }
+}
+
+/// Iterates through lst_state_list to find the lst indexes.
+/// Suitable for use on client side
+#[derive(Clone, Copy, Debug)]
+pub struct WithdrawProtocolFeesSwapByMintsFreeArgs<
+    SM: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+    DM: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+    S: ReadonlyAccountData + ReadonlyAccountPubkey,
+    L: ReadonlyAccountData + ReadonlyAccountPubkey,
+> {
+    pub withdraw_to: Pubkey,
+    pub lst_state_list: L,
+    pub pool_state: S,
+    pub src_lst_mint: SM,
+    pub dst_lst_mint: DM,
+}
+
+impl<
+        SM: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+        DM: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+        S: ReadonlyAccountData + ReadonlyAccountPubkey,
+        L: ReadonlyAccountData + ReadonlyAccountPubkey,
+    > WithdrawProtocolFeesSwapByMintsFreeArgs<SM, DM, S, L>
+{
+    /// Returns (keys, lst indexes, sol value calculator program IDs)
+    pub fn resolve(
+        self,
+    ) -> Result<
+        (
+            WithdrawProtocolFeesSwapKeys,
+            SrcDstLstIndexes,
+            SrcDstLstSolValueCalcProgramIds,
+        ),
+        SControllerError,
+    > {
+        self.resolve_for_pool(PoolPdas::DEFAULT)
+    }
+
+    /// Returns (keys, lst indexes, sol value calculator program IDs)
+    pub fn resolve_for_prog(
+        self,
+        program_id: Pubkey,
+    ) -> Result<
+        (
+            WithdrawProtocolFeesSwapKeys,
+            SrcDstLstIndexes,
+            SrcDstLstSolValueCalcProgramIds,
+        ),
+        SControllerError,
+    > {
+        self.resolve_for_pool(PoolPdas::find_for_program_id(program_id))
+    }
+
+    /// Returns (keys, lst indexes, sol value calculator program IDs)
+    pub fn resolve_for_pool(
+        self,
+        pdas: PoolPdas,
+    ) -> Result<
+        (
+            WithdrawProtocolFeesSwapKeys,
+            SrcDstLstIndexes,
+            SrcDstLstSolValueCalcProgramIds,
+        ),
+        SControllerError,
+    > {
+        if *self.lst_state_list.pubkey() != pdas.lst_state_list {
+            return Err(SControllerError::IncorrectLstStateList);
+        }
+        if *self.pool_state.pubkey() != pdas.pool_state {
+            return Err(SControllerError::IncorrectPoolState);
+        }
+
+        let lst_state_list_acc_data = self.lst_state_list.data();
+        let list = try_lst_state_list(&lst_state_list_acc_data)?;
+        let (src_lst_index, src_lst_state) =
+            try_find_lst_mint_on_list(*self.src_lst_mint.pubkey(), list)?;
+        let (dst_lst_index, dst_lst_state) =
+            try_find_lst_mint_on_list(*self.dst_lst_mint.pubkey(), list)?;
+
+        let keys = withdraw_protocol_fees_swap_keys(
+            pdas,
+            &self.pool_state,
+            WithdrawProtocolFeesSwapLsts {
+                src_lst_state,
+                src_lst_token_program: *self.src_lst_mint.owner(),
+                dst_lst_state,
+                dst_lst_token_program: *self.dst_lst_mint.owner(),
+            },
+            self.withdraw_to,
+        )?;
+
+        Ok((
+            keys,
+            SrcDstLstIndexes {
+                src_lst_index,
+                dst_lst_index,
+            },
+            SrcDstLstSolValueCalcProgramIds {
+                src_lst_calculator_program_id: src_lst_state.sol_value_calculator,
+                dst_lst_calculator_program_id: dst_lst_state.sol_value_calculator,
+            },
+        ))
+    }
+}
+
+struct WithdrawProtocolFeesSwapLsts<'a> {
+    src_lst_state: &'a LstState,
+    src_lst_token_program: Pubkey,
+    dst_lst_state: &'a LstState,
+    dst_lst_token_program: Pubkey,
+}
+
+fn withdraw_protocol_fees_swap_keys<S: ReadonlyAccountData>(
+    PoolPdas {
+        pool_state: pool_state_id,
+        lst_state_list,
+        protocol_fee,
+        ..
+    }: PoolPdas,
+    pool_state_account: &S,
+    WithdrawProtocolFeesSwapLsts {
+        src_lst_state,
+        src_lst_token_program,
+        dst_lst_state,
+        dst_lst_token_program,
+    }: WithdrawProtocolFeesSwapLsts,
+    withdraw_to: Pubkey,
+) -> Result<WithdrawProtocolFeesSwapKeys, SControllerError> {
+    let pool_state_acc_data = pool_state_account.data();
+    let pool_state = try_pool_state(&pool_state_acc_data)?;
+
+    Ok(WithdrawProtocolFeesSwapKeys {
+        protocol_fee_beneficiary: pool_state.protocol_fee_beneficiary,
+        src_lst_mint: src_lst_state.mint,
+        dst_lst_mint: dst_lst_state.mint,
+        protocol_fee_accumulator: create_protocol_fee_accumulator_address_with_protocol_fee_id(
+            protocol_fee,
+            src_lst_state,
+            src_lst_token_program,
+        )?,
+        protocol_fee_accumulator_auth: protocol_fee,
+        withdraw_to,
+        src_lst_token_program,
+        dst_lst_token_program,
+        pool_state: pool_state_id,
+        lst_state_list,
+        src_pool_reserves: create_pool_reserves_address_with_pool_state_id(
+            pool_state_id,
+            src_lst_state,
+            src_lst_token_program,
+        )?,
+        dst_pool_reserves: create_pool_reserves_address_with_pool_state_id(
+            pool_state_id,
+            dst_lst_state,
+            dst_lst_token_program,
+        )?,
+    })
+}
@@ -1,5 +1,7 @@
 use bytemuck::{try_from_bytes, try_from_bytes_mut, AnyBitPattern};
-use s_controller_interface::{LstState, PoolState, RebalanceRecord, SControllerError};
+use s_controller_interface::{
+    LstState, PendingChange, PoolState, RebalanceLossState, RebalanceRecord, SControllerError,
+};
 use solana_program::pubkey::Pubkey;
 
 /// Tries to reinterpret `pool_state_acc_data` bytes as a PoolState
@@ -54,6 +56,32 @@ pub fn try_rebalance_record_mut(
         .map_err(|_e| SControllerError::InvalidRebalanceRecordData)
 }
 
+pub fn try_rebalance_loss_state(
+    rebalance_loss_state_acc_data: &[u8],
+) -> Result<&RebalanceLossState, SControllerError> {
+    try_from_bytes(rebalance_loss_state_acc_data)
+        .map_err(|_e| SControllerError::InvalidRebalanceLossStateData)
+}
+
+pub fn try_rebalance_loss_state_mut(
+    rebalance_loss_state_acc_data: &mut [u8],
+) -> Result<&mut RebalanceLossState, SControllerError> {
+    try_from_bytes_mut(rebalance_loss_state_acc_data)
+        .map_err(|_e| SControllerError::InvalidRebalanceLossStateData)
+}
+
+pub fn try_pending_change_list(
+    pending_change_list_acc_data: &[u8],
+) -> Result<&[PendingChange], SControllerError> {
+    try_list(pending_change_list_acc_data).ok_or(SControllerError::InvalidPendingChangeListData)
+}
+
+pub fn try_pending_change_list_mut(
+    pending_change_list_acc_data: &mut [u8],
+) -> Result<&mut [PendingChange], SControllerError> {
+    try_list_mut(pending_change_list_acc_data).ok_or(SControllerError::InvalidPendingChangeListData)
+}
+
 /// Tries to reinterpret `list_acc_data` bytes as a slice.
 ///
 /// `list_acc_data` should only contain data of the items, no headers etc
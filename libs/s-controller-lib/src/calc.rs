@@ -59,6 +59,95 @@ pub fn calc_lp_tokens_sol_value(
     .apply(lp_tokens_amount)
 }
 
+/// Inverse of [`calc_lp_tokens_to_mint`].
+///
+/// Returns min SOL value, after fees, that needs to be added to the pool
+/// in order to mint `lp_tokens_amount` LP tokens
+pub fn calc_lp_tokens_to_mint_sol_value(
+    LpTokenRateArgs {
+        lp_token_supply,
+        pool_total_sol_value,
+    }: LpTokenRateArgs,
+    lp_tokens_amount: u64,
+) -> Result<u64, MathError> {
+    // same edge-cases as calc_lp_tokens_to_mint()
+    if lp_token_supply == 0 {
+        return Ok(lp_tokens_amount.saturating_sub(pool_total_sol_value));
+    }
+    if pool_total_sol_value == 0 {
+        return Ok(lp_tokens_amount);
+    }
+    CeilDiv(U64Ratio {
+        num: pool_total_sol_value,
+        denom: lp_token_supply,
+    })
+    .apply(lp_tokens_amount)
+}
+
+/// Inverse of [`calc_lp_tokens_sol_value`].
+///
+/// Returns min amount of LP tokens whose SOL value is at least `sol_value`.
+/// Returns 0 if LP tokens have no SOL value
+pub fn calc_lp_tokens_for_sol_value(
+    LpTokenRateArgs {
+        lp_token_supply,
+        pool_total_sol_value,
+    }: LpTokenRateArgs,
+    sol_value: u64,
+) -> Result<u64, MathError> {
+    if pool_total_sol_value == 0 || lp_token_supply == 0 {
+        return Ok(0);
+    }
+    CeilDiv(U64Ratio {
+        num: lp_token_supply,
+        denom: pool_total_sol_value,
+    })
+    .apply(sol_value)
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ExactOutPricingArgs {
+    /// SOL value that was priced
+    pub sol_value: u64,
+
+    /// Result of pricing `sol_value` with the pricing program
+    pub sol_value_after_fees: u64,
+}
+
+/// The pricing program's PriceLpTokensToMint and PriceLpTokensToRedeem only price in one direction.
+/// For the ExactOut liquidity instructions, an initial pricing that falls short of
+/// `target_sol_value_after_fees` is used to estimate the fee rate charged by the pricing program.
+///
+/// Returns the SOL value that should yield at least `target_sol_value_after_fees`
+/// after fees if the pricing program charges a proportional fee.
+/// The result must be priced again to verify this.
+pub fn calc_exact_out_sol_value_before_fees(
+    ExactOutPricingArgs {
+        sol_value,
+        sol_value_after_fees,
+    }: ExactOutPricingArgs,
+    target_sol_value_after_fees: u64,
+) -> Result<u64, MathError> {
+    if sol_value_after_fees == 0 {
+        return Err(MathError);
+    }
+    CeilDiv(U64Ratio {
+        num: sol_value,
+        denom: sol_value_after_fees,
+    })
+    .apply(target_sol_value_after_fees)
+}
+
+/// Returns the SOL value of the portion of `fees_sol_value`, the fees charged by the pricing program,
+/// that goes to the protocol. The remainder stays in the pool, accruing to LPs.
+pub fn calc_protocol_fees_sol_value(
+    fees_sol_value: u64,
+    protocol_fee_bps: u16,
+) -> Result<u64, MathError> {
+    let aaf = CeilDiv(U64BpsFee::try_new(protocol_fee_bps)?).apply(fees_sol_value)?;
+    Ok(aaf.fee_charged())
+}
+
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub struct CalcAddLiquidityArgs {
     pub lst_amount: u64,
@@ -91,8 +180,8 @@ pub fn calc_add_liquidity_protocol_fees(
     }: CalcAddLiquidityArgs,
 ) -> Result<CalcAddLiquidityProtocolFeesResult, MathError> {
     let lp_fees_sol_value = lst_amount_sol_value.saturating_sub(lst_amount_sol_value_after_fees);
-    let aaf = CeilDiv(U64BpsFee::try_new(lp_protocol_fee_bps)?).apply(lp_fees_sol_value)?;
-    let protocol_fees_sol_value = aaf.fee_charged();
+    let protocol_fees_sol_value =
+        calc_protocol_fees_sol_value(lp_fees_sol_value, lp_protocol_fee_bps)?;
     let to_protocol_fees_lst_amount = FloorDiv(U64Ratio {
         num: lst_amount,
         denom: lst_amount_sol_value,
@@ -131,8 +220,8 @@ pub fn calc_remove_liquidity_protocol_fees(
     }: CalcRemoveLiquidityProtocolFeesArgs,
 ) -> Result<u64, MathError> {
     let lp_fees_sol_value = lp_tokens_sol_value.saturating_sub(lp_tokens_sol_value_after_fees);
-    let aaf = CeilDiv(U64BpsFee::try_new(lp_protocol_fee_bps)?).apply(lp_fees_sol_value)?;
-    let protocol_fees_sol_value = aaf.fee_charged();
+    let protocol_fees_sol_value =
+        calc_protocol_fees_sol_value(lp_fees_sol_value, lp_protocol_fee_bps)?;
     let to_protocol_fees_lst_amount = FloorDiv(U64Ratio {
         num: to_user_lst_amount,
         denom: lp_tokens_sol_value_after_fees,
@@ -166,8 +255,8 @@ pub fn calc_swap_protocol_fees(
     }: CalcSwapProtocolFeesArgs,
 ) -> Result<u64, MathError> {
     let fees_sol_value = in_sol_value.saturating_sub(out_sol_value);
-    let aaf = CeilDiv(U64BpsFee::try_new(trading_protocol_fee_bps)?).apply(fees_sol_value)?;
-    let protocol_fees_sol_value = aaf.fee_charged();
+    let protocol_fees_sol_value =
+        calc_protocol_fees_sol_value(fees_sol_value, trading_protocol_fee_bps)?;
     let to_protocol_fees_lst_amount = FloorDiv(U64Ratio {
         num: dst_lst_out,
         denom: out_sol_value,
@@ -175,3 +264,34 @@ pub fn calc_swap_protocol_fees(
     .apply(protocol_fees_sol_value)?;
     Ok(to_protocol_fees_lst_amount)
 }
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ProRataShareArgs {
+    /// synced sol_value of the LST's LstState
+    pub lst_sol_value: u64,
+
+    /// synced total_sol_value of the pool
+    pub pool_total_sol_value: u64,
+}
+
+/// Returns the portion of `amount` attributable to a single LST
+/// based on its share of the pool's total SOL value.
+///
+/// Used by RemoveLiquidityProRata to split both the LP tokens being redeemed
+/// and their SOL value across every LST in the pool
+pub fn calc_pro_rata_share(
+    ProRataShareArgs {
+        lst_sol_value,
+        pool_total_sol_value,
+    }: ProRataShareArgs,
+    amount: u64,
+) -> Result<u64, MathError> {
+    if pool_total_sol_value == 0 {
+        return Ok(0);
+    }
+    FloorDiv(U64Ratio {
+        num: lst_sol_value,
+        denom: pool_total_sol_value,
+    })
+    .apply(amount)
+}
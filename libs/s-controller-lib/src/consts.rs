@@ -18,5 +18,23 @@ pub const DEFAULT_LP_PROTOCOL_FEE_BPS: u16 = 1_000;
 
 pub const DEFAULT_PRICING_PROGRAM: Pubkey = flat_fee_lib::program::ID;
 
+/// Max number of LSTs, including src and dst, on a SwapExactInRoute route
+pub const MAX_SWAP_ROUTE_LSTS: usize = 4;
+
+/// Number of accounts SwapExactInRoute takes for each LST on the route:
+/// lst_mint, lst_token_program, pool_reserves, protocol_fee_accumulator
+pub const SWAP_ROUTE_LST_ACCOUNTS_LEN: usize = 4;
+
+/// Number of accounts AddLiquidityMulti and RemoveLiquidityProRata take for each LST:
+/// lst_mint, lst_token_program, lst_acc, pool_reserves, protocol_fee_accumulator
+pub const MULTI_LIQUIDITY_LST_ACCOUNTS_LEN: usize = 5;
+
+/// Number of accounts WithdrawProtocolFeesMulti takes for each LST:
+/// lst_mint, token_program, protocol_fee_accumulator, withdraw_to
+pub const WITHDRAW_PROTOCOL_FEES_MULTI_LST_ACCOUNTS_LEN: usize = 4;
+
 // Compute Unit ceilings for instructions
 pub const ADD_LST_IX_COMPUTE_UNIT_CEIL: u32 = 100_000;
+
+/// Per LST withdrawn from. PDA search for the accumulator + transfer_checked CPI
+pub const WITHDRAW_PROTOCOL_FEES_MULTI_LST_COMPUTE_UNIT_CEIL: u32 = 30_000;
@@ -0,0 +1,212 @@
+//! Events logged by the S controller program for indexers.
+//!
+//! Each event is logged with `sol_log_data()` as a single `Program data: ` log line
+//! with 2 base64-encoded fields:
+//! 1. the pool's pool_state address
+//! 2. the borsh-serialized [`SControllerEvent`]
+//!
+//! Variants must only ever be appended to [`SControllerEvent`]
+//! since the borsh discriminant is the variant's index.
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use s_controller_interface::PendingChange;
+use solana_program::pubkey::Pubkey;
+
+/// Amounts of a single LST involved in a multi-LST liquidity instruction
+#[derive(Clone, Copy, Debug, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct LiquidityLstAmounts {
+    pub lst_mint: Pubkey,
+
+    /// Amount of LST transferred into or out of the pool,
+    /// excluding `protocol_fee_amount`
+    pub lst_amount: u64,
+
+    /// Amount of LST transferred to the protocol fee accumulator
+    pub protocol_fee_amount: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, BorshSerialize, BorshDeserialize)]
+pub enum SControllerEvent {
+    SyncSolValue {
+        lst_mint: Pubkey,
+        old_sol_value: u64,
+        new_sol_value: u64,
+    },
+
+    /// Emitted once per SwapExactIn/SwapExactOut and once per hop of a SwapExactInRoute
+    Swap {
+        src_lst_mint: Pubkey,
+        dst_lst_mint: Pubkey,
+
+        /// Amount of src LST transferred into the pool
+        src_lst_amount: u64,
+
+        /// Amount of dst LST transferred out of the pool to the user,
+        /// excluding `protocol_fee_amount`
+        dst_lst_amount: u64,
+
+        in_sol_value: u64,
+        out_sol_value: u64,
+
+        /// Amount of dst LST transferred to the protocol fee accumulator
+        protocol_fee_amount: u64,
+    },
+
+    AddLiquidity {
+        lst_mint: Pubkey,
+
+        /// Amount of LST transferred into the pool reserves, excluding `protocol_fee_amount`
+        lst_amount: u64,
+
+        /// Amount of LST transferred to the protocol fee accumulator
+        protocol_fee_amount: u64,
+
+        sol_value: u64,
+        sol_value_after_fees: u64,
+        lp_tokens_minted: u64,
+    },
+
+    RemoveLiquidity {
+        lst_mint: Pubkey,
+        lp_tokens_burnt: u64,
+
+        /// Amount of LST transferred out of the pool to the user, excluding `protocol_fee_amount`
+        lst_amount: u64,
+
+        /// Amount of LST transferred to the protocol fee accumulator
+        protocol_fee_amount: u64,
+
+        sol_value: u64,
+        sol_value_after_fees: u64,
+    },
+
+    AddLiquidityMulti {
+        lsts: Vec<LiquidityLstAmounts>,
+        lp_tokens_minted: u64,
+    },
+
+    RemoveLiquidityProRata {
+        lsts: Vec<LiquidityLstAmounts>,
+        lp_tokens_burnt: u64,
+    },
+
+    /// Emitted once per WithdrawProtocolFees and once per LST of a WithdrawProtocolFeesMulti
+    WithdrawProtocolFees {
+        lst_mint: Pubkey,
+        amount: u64,
+        withdraw_to: Pubkey,
+    },
+
+    StartRebalance {
+        src_lst_mint: Pubkey,
+        dst_lst_mint: Pubkey,
+        amount: u64,
+    },
+
+    EndRebalance {
+        old_total_sol_value: u64,
+        new_total_sol_value: u64,
+    },
+
+    /// Emitted by both Initialize and InitializePool
+    Initialize {
+        lp_token_mint: Pubkey,
+    },
+
+    AddLst {
+        lst_mint: Pubkey,
+        sol_value_calculator: Pubkey,
+    },
+
+    RemoveLst {
+        lst_mint: Pubkey,
+    },
+
+    /// Emitted by both DisableLstInput and EnableLstInput
+    SetLstInputDisabled {
+        lst_mint: Pubkey,
+        is_input_disabled: bool,
+    },
+
+    SetSolValueCalculator {
+        lst_mint: Pubkey,
+        sol_value_calculator: Pubkey,
+    },
+
+    SetAdmin {
+        admin: Pubkey,
+    },
+
+    /// Contains the pool's protocol fees after the change
+    SetProtocolFee {
+        trading_protocol_fee_bps: u16,
+        lp_protocol_fee_bps: u16,
+    },
+
+    SetProtocolFeeBeneficiary {
+        protocol_fee_beneficiary: Pubkey,
+    },
+
+    SetPricingProgram {
+        pricing_program: Pubkey,
+    },
+
+    SetRebalanceAuthority {
+        rebalance_authority: Pubkey,
+    },
+
+    AddDisablePoolAuthority {
+        authority: Pubkey,
+    },
+
+    RemoveDisablePoolAuthority {
+        authority: Pubkey,
+    },
+
+    /// Emitted by both DisablePool and EnablePool
+    SetPoolDisabled {
+        is_disabled: bool,
+    },
+
+    SetTimelockDelay {
+        timelock_delay_hours: u8,
+    },
+
+    QueuePendingChange {
+        pending_change: PendingChange,
+    },
+
+    /// Emitted after the event of the change itself, e.g. SetAdmin
+    ExecutePendingChange {
+        pending_change: PendingChange,
+    },
+
+    CancelPendingChange {
+        pending_change: PendingChange,
+    },
+
+    SetLstCap {
+        lst_mint: Pubkey,
+        max_share_bps: u16,
+    },
+
+    /// Emitted instead of WithdrawProtocolFees by WithdrawProtocolFeesSwap
+    WithdrawProtocolFeesSwap {
+        src_lst_mint: Pubkey,
+        dst_lst_mint: Pubkey,
+
+        /// Amount of src LST transferred from the protocol fee accumulator into the pool
+        src_lst_amount: u64,
+
+        /// Amount of dst LST transferred out of the pool to `withdraw_to`
+        dst_lst_amount: u64,
+
+        sol_value: u64,
+        withdraw_to: Pubkey,
+    },
+
+    SetRebalanceLossLimit {
+        max_loss_bps_per_rebalance: u16,
+        max_loss_bps_per_epoch: u16,
+    },
+}
@@ -8,7 +8,7 @@ use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountOwner, Readonl
 use crate::{
     index_to_u32, ix_extend_with_pricing_program_price_lp_accounts,
     ix_extend_with_sol_value_calculator_accounts, AddLiquidityByMintFreeArgs,
-    AddRemoveLiquidityAccountSuffixes, AddRemoveLiquidityExtraAccounts,
+    AddRemoveLiquidityAccountSuffixes, AddRemoveLiquidityExtraAccounts, SwapLiquidityPdas,
 };
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -120,3 +120,25 @@ pub fn add_liquidity_ix_by_mint_full_for_prog<
     )?;
     Ok(ix)
 }
+
+/// For pools other than the default pool, e.g. ones created with `InitializePool`
+pub fn add_liquidity_ix_by_mint_full_for_pool<
+    S: ReadonlyAccountData,
+    L: ReadonlyAccountData,
+    M: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+>(
+    program_id: Pubkey,
+    pdas: SwapLiquidityPdas,
+    free_args: AddLiquidityByMintFreeArgs<S, L, M>,
+    amts: AddLiquidityIxAmts,
+    account_suffixes: AddRemoveLiquidityAccountSuffixes,
+) -> Result<Instruction, ProgramError> {
+    let (keys, lst_index, program_ids) = free_args.resolve_with_pdas(pdas)?;
+    let ix = add_liquidity_ix_full_for_prog(
+        program_id,
+        keys,
+        AddLiquidityIxFullArgs { lst_index, amts },
+        AddRemoveLiquidityExtraAccounts::new(program_ids, account_suffixes),
+    )?;
+    Ok(ix)
+}
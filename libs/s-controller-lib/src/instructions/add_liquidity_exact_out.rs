@@ -0,0 +1,145 @@
+use s_controller_interface::{
+    add_liquidity_exact_out_ix_with_program_id, AddLiquidityExactOutIxArgs,
+    AddLiquidityExactOutIxData, AddLiquidityExactOutKeys, SControllerError,
+};
+use solana_program::{instruction::Instruction, program_error::ProgramError, pubkey::Pubkey};
+use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountOwner, ReadonlyAccountPubkey};
+
+use crate::{
+    index_to_u32, ix_extend_with_pricing_program_price_lp_accounts,
+    ix_extend_with_sol_value_calculator_accounts, AddLiquidityByMintFreeArgs,
+    AddRemoveLiquidityAccountSuffixes, AddRemoveLiquidityExtraAccounts, SwapLiquidityPdas,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AddLiquidityExactOutIxAmts {
+    pub lp_token_amount: u64,
+    pub max_lst_in: u64,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AddLiquidityExactOutIxFullArgs {
+    pub lst_index: usize,
+    pub amts: AddLiquidityExactOutIxAmts,
+}
+
+pub fn add_liquidity_exact_out_ix_full(
+    accounts: AddLiquidityExactOutKeys,
+    args: AddLiquidityExactOutIxFullArgs,
+    extra_accounts: AddRemoveLiquidityExtraAccounts,
+) -> Result<Instruction, ProgramError> {
+    add_liquidity_exact_out_ix_full_for_prog(crate::program::ID, accounts, args, extra_accounts)
+}
+
+pub fn add_liquidity_exact_out_ix_full_for_prog(
+    program_id: Pubkey,
+    accounts: AddLiquidityExactOutKeys,
+    AddLiquidityExactOutIxFullArgs {
+        lst_index,
+        amts:
+            AddLiquidityExactOutIxAmts {
+                lp_token_amount,
+                max_lst_in,
+            },
+    }: AddLiquidityExactOutIxFullArgs,
+    AddRemoveLiquidityExtraAccounts {
+        lst_calculator_program_id,
+        pricing_program_id,
+        lst_calculator_accounts,
+        pricing_program_price_lp_accounts,
+    }: AddRemoveLiquidityExtraAccounts,
+) -> Result<Instruction, ProgramError> {
+    let lst_index = index_to_u32(lst_index)?;
+    let mut ix = add_liquidity_exact_out_ix_with_program_id(
+        program_id,
+        accounts,
+        AddLiquidityExactOutIxArgs {
+            lst_value_calc_accs: 0,
+            lst_index,
+            lp_token_amount,
+            max_lst_in,
+        },
+    )?;
+    let lst_value_calc_accs = ix_extend_with_sol_value_calculator_accounts(
+        &mut ix,
+        lst_calculator_accounts,
+        lst_calculator_program_id,
+    )
+    .map_err(|_e| SControllerError::MathError)?;
+    ix_extend_with_pricing_program_price_lp_accounts(
+        &mut ix,
+        pricing_program_price_lp_accounts,
+        pricing_program_id,
+    )
+    .map_err(|_e| SControllerError::MathError)?;
+    // overwrite lst_value_calc_accs now that calculator accounts have been added
+    let mut overwrite = &mut ix.data[..];
+    AddLiquidityExactOutIxData(AddLiquidityExactOutIxArgs {
+        lst_value_calc_accs,
+        lst_index,
+        lp_token_amount,
+        max_lst_in,
+    })
+    .serialize(&mut overwrite)?;
+    Ok(ix)
+}
+
+pub fn add_liquidity_exact_out_ix_by_mint_full<
+    S: ReadonlyAccountData,
+    L: ReadonlyAccountData,
+    M: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+>(
+    free_args: AddLiquidityByMintFreeArgs<S, L, M>,
+    amts: AddLiquidityExactOutIxAmts,
+    account_suffixes: AddRemoveLiquidityAccountSuffixes,
+) -> Result<Instruction, ProgramError> {
+    let (keys, lst_index, program_ids) = free_args.resolve_exact_out()?;
+    let ix = add_liquidity_exact_out_ix_full(
+        keys,
+        AddLiquidityExactOutIxFullArgs { lst_index, amts },
+        AddRemoveLiquidityExtraAccounts::new(program_ids, account_suffixes),
+    )?;
+    Ok(ix)
+}
+
+pub fn add_liquidity_exact_out_ix_by_mint_full_for_prog<
+    S: ReadonlyAccountData,
+    L: ReadonlyAccountData,
+    M: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+>(
+    program_id: Pubkey,
+    free_args: AddLiquidityByMintFreeArgs<S, L, M>,
+    amts: AddLiquidityExactOutIxAmts,
+    account_suffixes: AddRemoveLiquidityAccountSuffixes,
+) -> Result<Instruction, ProgramError> {
+    let (keys, lst_index, program_ids) = free_args.resolve_exact_out_for_prog(program_id)?;
+    let ix = add_liquidity_exact_out_ix_full_for_prog(
+        program_id,
+        keys,
+        AddLiquidityExactOutIxFullArgs { lst_index, amts },
+        AddRemoveLiquidityExtraAccounts::new(program_ids, account_suffixes),
+    )?;
+    Ok(ix)
+}
+
+/// For pools other than the default pool, e.g. ones created with `InitializePool`
+pub fn add_liquidity_exact_out_ix_by_mint_full_for_pool<
+    S: ReadonlyAccountData,
+    L: ReadonlyAccountData,
+    M: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+>(
+    program_id: Pubkey,
+    pdas: SwapLiquidityPdas,
+    free_args: AddLiquidityByMintFreeArgs<S, L, M>,
+    amts: AddLiquidityExactOutIxAmts,
+    account_suffixes: AddRemoveLiquidityAccountSuffixes,
+) -> Result<Instruction, ProgramError> {
+    let (keys, lst_index, program_ids) = free_args.resolve_exact_out_with_pdas(pdas)?;
+    let ix = add_liquidity_exact_out_ix_full_for_prog(
+        program_id,
+        keys,
+        AddLiquidityExactOutIxFullArgs { lst_index, amts },
+        AddRemoveLiquidityExtraAccounts::new(program_ids, account_suffixes),
+    )?;
+    Ok(ix)
+}
@@ -0,0 +1,221 @@
+use s_controller_interface::{
+    add_liquidity_multi_ix_with_program_id, AddLiquidityMultiIxArgs, AddLiquidityMultiIxData,
+    AddLiquidityMultiKeys, SControllerError,
+};
+use solana_program::{
+    instruction::{AccountMeta, Instruction},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountOwner, ReadonlyAccountPubkey};
+
+use crate::{
+    index_to_u32, ix_extend_with_pricing_program_price_lp_accounts,
+    ix_extend_with_sol_value_calculator_accounts, AddLiquidityMultiByMintsFreeArgs,
+    LstSolValueCalcAccounts, MultiLiquidityLstKeys, MultiLiquidityResolved, PoolPdas,
+    SwapLiquidityPdas, MULTI_LIQUIDITY_LST_ACCOUNTS_LEN,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AddLiquidityMultiIxAmts<'me> {
+    /// Amount of each LST to add, in the same order as the LSTs
+    pub lst_amounts: &'me [u64],
+    pub min_lp_out: u64,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AddLiquidityMultiIxFullArgs<'me> {
+    pub lst_indexes: &'me [usize],
+    pub amts: AddLiquidityMultiIxAmts<'me>,
+}
+
+/// Args:
+/// - `lst_keys`: [`MultiLiquidityLstKeys`] of every LST being added, in order
+/// - `sol_val_calc_accounts`: SOL value calculator accounts of every LST being added, in order
+/// - `pricing_program_accounts`: pricing program PriceLpTokensToMint accounts of every LST being added, in order.
+///     Should include the common interface account prefixes but exclude the program ID
+pub fn add_liquidity_multi_ix_full(
+    accounts: AddLiquidityMultiKeys,
+    args: AddLiquidityMultiIxFullArgs,
+    lst_keys: &[MultiLiquidityLstKeys],
+    sol_val_calc_accounts: &[LstSolValueCalcAccounts],
+    pricing_program_accounts: &[&[AccountMeta]],
+    pricing_program_id: Pubkey,
+) -> Result<Instruction, ProgramError> {
+    add_liquidity_multi_ix_full_for_prog(
+        crate::program::ID,
+        accounts,
+        args,
+        lst_keys,
+        sol_val_calc_accounts,
+        pricing_program_accounts,
+        pricing_program_id,
+    )
+}
+
+pub fn add_liquidity_multi_ix_full_for_prog(
+    program_id: Pubkey,
+    accounts: AddLiquidityMultiKeys,
+    AddLiquidityMultiIxFullArgs {
+        lst_indexes,
+        amts: AddLiquidityMultiIxAmts {
+            lst_amounts,
+            min_lp_out,
+        },
+    }: AddLiquidityMultiIxFullArgs,
+    lst_keys: &[MultiLiquidityLstKeys],
+    sol_val_calc_accounts: &[LstSolValueCalcAccounts],
+    pricing_program_accounts: &[&[AccountMeta]],
+    pricing_program_id: Pubkey,
+) -> Result<Instruction, ProgramError> {
+    let n_lsts = lst_indexes.len();
+    if n_lsts == 0
+        || lst_amounts.len() != n_lsts
+        || lst_keys.len() != n_lsts
+        || sol_val_calc_accounts.len() != n_lsts
+        || pricing_program_accounts.len() != n_lsts
+    {
+        return Err(SControllerError::InvalidLiquidityLsts.into());
+    }
+    let lst_indexes = lst_indexes
+        .iter()
+        .map(|i| index_to_u32(*i))
+        .collect::<Result<Vec<_>, _>>()?;
+    let mut ix = add_liquidity_multi_ix_with_program_id(
+        program_id,
+        accounts,
+        AddLiquidityMultiIxArgs {
+            // empty vecs replaced by ix_extend below
+            lst_value_calc_accs: vec![],
+            pricing_accs: vec![],
+            lst_indexes: lst_indexes.clone(),
+            lst_amounts: lst_amounts.to_vec(),
+            min_lp_out,
+        },
+    )?;
+    for keys in lst_keys {
+        let metas: [AccountMeta; MULTI_LIQUIDITY_LST_ACCOUNTS_LEN] = (*keys).into();
+        ix.accounts.extend(metas);
+    }
+    let lst_value_calc_accs = sol_val_calc_accounts
+        .iter()
+        .map(
+            |LstSolValueCalcAccounts {
+                 calculator_program_id,
+                 calculator_accounts,
+             }| {
+                ix_extend_with_sol_value_calculator_accounts(
+                    &mut ix,
+                    calculator_accounts,
+                    *calculator_program_id,
+                )
+                .map_err(|_e| SControllerError::MathError)
+            },
+        )
+        .collect::<Result<Vec<_>, _>>()?;
+    let pricing_accs = pricing_program_accounts
+        .iter()
+        .map(|lst_pricing_accounts| {
+            ix_extend_with_pricing_program_price_lp_accounts(
+                &mut ix,
+                lst_pricing_accounts,
+                pricing_program_id,
+            )
+            .map_err(|_e| SControllerError::MathError)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    ix.data = AddLiquidityMultiIxData(AddLiquidityMultiIxArgs {
+        lst_value_calc_accs,
+        pricing_accs,
+        lst_indexes,
+        lst_amounts: lst_amounts.to_vec(),
+        min_lp_out,
+    })
+    .try_to_vec()?;
+    Ok(ix)
+}
+
+/// Args:
+/// - `sol_val_calc_account_suffixes`: SOL value calculator accounts of every LST being added, in order.
+///     Should include the common interface account prefixes but exclude the program ID
+/// - `pricing_program_accounts`: pricing program PriceLpTokensToMint accounts of every LST being added, in order.
+///     Should include the common interface account prefixes but exclude the program ID
+pub fn add_liquidity_multi_ix_by_mints_full<
+    S: ReadonlyAccountData,
+    L: ReadonlyAccountData,
+    M: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+>(
+    free_args: AddLiquidityMultiByMintsFreeArgs<S, L, M>,
+    amts: AddLiquidityMultiIxAmts,
+    sol_val_calc_account_suffixes: &[&[AccountMeta]],
+    pricing_program_accounts: &[&[AccountMeta]],
+) -> Result<Instruction, ProgramError> {
+    add_liquidity_multi_ix_by_mints_full_for_pool(
+        crate::program::ID,
+        PoolPdas::DEFAULT.into(),
+        free_args,
+        amts,
+        sol_val_calc_account_suffixes,
+        pricing_program_accounts,
+    )
+}
+
+/// For pools other than the default pool, e.g. ones created with `InitializePool`
+pub fn add_liquidity_multi_ix_by_mints_full_for_pool<
+    S: ReadonlyAccountData,
+    L: ReadonlyAccountData,
+    M: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+>(
+    program_id: Pubkey,
+    pdas: SwapLiquidityPdas,
+    free_args: AddLiquidityMultiByMintsFreeArgs<S, L, M>,
+    amts: AddLiquidityMultiIxAmts,
+    sol_val_calc_account_suffixes: &[&[AccountMeta]],
+    pricing_program_accounts: &[&[AccountMeta]],
+) -> Result<Instruction, ProgramError> {
+    let (
+        keys,
+        MultiLiquidityResolved {
+            lst_keys,
+            lst_indexes,
+            sol_value_calculator_program_ids,
+            pricing_program_id,
+        },
+    ) = free_args.resolve_with_pdas(pdas)?;
+    let sol_val_calc_accounts = zip_sol_val_calc_accounts(
+        &sol_value_calculator_program_ids,
+        sol_val_calc_account_suffixes,
+    )?;
+    add_liquidity_multi_ix_full_for_prog(
+        program_id,
+        keys,
+        AddLiquidityMultiIxFullArgs {
+            lst_indexes: &lst_indexes,
+            amts,
+        },
+        &lst_keys,
+        &sol_val_calc_accounts,
+        pricing_program_accounts,
+        pricing_program_id,
+    )
+}
+
+/// Pairs each LST's resolved SOL value calculator program ID with its account suffix
+pub(crate) fn zip_sol_val_calc_accounts<'me>(
+    sol_value_calculator_program_ids: &[Pubkey],
+    sol_val_calc_account_suffixes: &[&'me [AccountMeta]],
+) -> Result<Vec<LstSolValueCalcAccounts<'me>>, SControllerError> {
+    if sol_val_calc_account_suffixes.len() != sol_value_calculator_program_ids.len() {
+        return Err(SControllerError::InvalidLiquidityLsts);
+    }
+    Ok(sol_value_calculator_program_ids
+        .iter()
+        .zip(sol_val_calc_account_suffixes.iter().copied())
+        .map(
+            |(calculator_program_id, calculator_accounts)| LstSolValueCalcAccounts {
+                calculator_program_id: *calculator_program_id,
+                calculator_accounts,
+            },
+        )
+        .collect())
+}
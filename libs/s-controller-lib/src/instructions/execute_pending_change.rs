@@ -0,0 +1,40 @@
+use s_controller_interface::{
+    execute_pending_change_ix_with_program_id, ExecutePendingChangeIxArgs, ExecutePendingChangeKeys,
+};
+use solana_program::{instruction::Instruction, program_error::ProgramError, pubkey::Pubkey};
+
+use crate::index_to_u32;
+
+/// `direct_ix` is the instruction that would perform the pending change
+/// if the pool were not timelocked, e.g. one created with `set_admin_ix()`.
+/// Its accounts are appended to the `ExecutePendingChange` instruction.
+pub fn execute_pending_change_ix_full(
+    keys: ExecutePendingChangeKeys,
+    pending_change_index: usize,
+    direct_ix: Instruction,
+) -> Result<Instruction, ProgramError> {
+    execute_pending_change_ix_full_for_prog(
+        crate::program::ID,
+        keys,
+        pending_change_index,
+        direct_ix,
+    )
+}
+
+pub fn execute_pending_change_ix_full_for_prog(
+    program_id: Pubkey,
+    keys: ExecutePendingChangeKeys,
+    pending_change_index: usize,
+    direct_ix: Instruction,
+) -> Result<Instruction, ProgramError> {
+    let pending_change_index = index_to_u32(pending_change_index)?;
+    let mut ix = execute_pending_change_ix_with_program_id(
+        program_id,
+        keys,
+        ExecutePendingChangeIxArgs {
+            pending_change_index,
+        },
+    )?;
+    ix.accounts.extend(direct_ix.accounts);
+    Ok(ix)
+}
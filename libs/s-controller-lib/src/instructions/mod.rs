@@ -2,23 +2,43 @@
 //! e.g. those that requires additional accounts for SOL value calculator and pricing program CPI calls
 
 mod add_liquidity;
+mod add_liquidity_exact_out;
+mod add_liquidity_multi;
 mod disable_enable_lst_input;
 mod end_rebalance;
+mod execute_pending_change;
+mod rebalance;
 mod remove_liquidity;
+mod remove_liquidity_exact_out;
+mod remove_liquidity_pro_rata;
+mod set_lst_cap;
 mod set_sol_value_calculator;
 mod start_rebalance;
 mod swap_exact_in;
+mod swap_exact_in_route;
 mod swap_exact_out;
 mod sync_sol_value;
 mod utils;
+mod withdraw_protocol_fees_multi;
+mod withdraw_protocol_fees_swap;
 
 pub use add_liquidity::*;
+pub use add_liquidity_exact_out::*;
+pub use add_liquidity_multi::*;
 pub use disable_enable_lst_input::*;
 pub use end_rebalance::*;
+pub use execute_pending_change::*;
+pub use rebalance::*;
 pub use remove_liquidity::*;
+pub use remove_liquidity_exact_out::*;
+pub use remove_liquidity_pro_rata::*;
+pub use set_lst_cap::*;
 pub use set_sol_value_calculator::*;
 pub use start_rebalance::*;
 pub use swap_exact_in::*;
+pub use swap_exact_in_route::*;
 pub use swap_exact_out::*;
 pub use sync_sol_value::*;
 pub use utils::*;
+pub use withdraw_protocol_fees_multi::*;
+pub use withdraw_protocol_fees_swap::*;
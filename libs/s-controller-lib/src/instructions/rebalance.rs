@@ -0,0 +1,19 @@
+use solana_program::{instruction::Instruction, program_error::ProgramError};
+
+use crate::end_rebalance_ix_from_start_rebalance_ix;
+
+/// Sandwiches `intermediate_ixs` between `start_rebalance_ix` and its corresponding
+/// EndRebalance instruction.
+///
+/// `intermediate_ixs` should convert the src LST withdrawn by StartRebalance into
+/// dst LST of at least equal SOL value and deposit it into the pool's dst LST reserves.
+pub fn rebalance_ixs(
+    start_rebalance_ix: Instruction,
+    intermediate_ixs: impl IntoIterator<Item = Instruction>,
+) -> Result<Vec<Instruction>, ProgramError> {
+    let end_rebalance_ix = end_rebalance_ix_from_start_rebalance_ix(&start_rebalance_ix)?;
+    let mut res = vec![start_rebalance_ix];
+    res.extend(intermediate_ixs);
+    res.push(end_rebalance_ix);
+    Ok(res)
+}
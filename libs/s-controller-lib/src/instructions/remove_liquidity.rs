@@ -8,7 +8,7 @@ use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountOwner, Readonl
 use crate::{
     index_to_u32, ix_extend_with_pricing_program_price_lp_accounts,
     ix_extend_with_sol_value_calculator_accounts, AddRemoveLiquidityAccountSuffixes,
-    AddRemoveLiquidityExtraAccounts, RemoveLiquidityByMintFreeArgs,
+    AddRemoveLiquidityExtraAccounts, RemoveLiquidityByMintFreeArgs, SwapLiquidityPdas,
 };
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -121,3 +121,25 @@ pub fn remove_liquidity_ix_by_mint_full_for_prog<
     )?;
     Ok(ix)
 }
+
+/// For pools other than the default pool, e.g. ones created with `InitializePool`
+pub fn remove_liquidity_ix_by_mint_full_for_pool<
+    S: ReadonlyAccountData,
+    L: ReadonlyAccountData,
+    M: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+>(
+    program_id: Pubkey,
+    pdas: SwapLiquidityPdas,
+    free_args: RemoveLiquidityByMintFreeArgs<S, L, M>,
+    amts: RemoveLiquidityIxAmts,
+    account_suffixes: AddRemoveLiquidityAccountSuffixes,
+) -> Result<Instruction, ProgramError> {
+    let (keys, lst_index, program_ids) = free_args.resolve_with_pdas(pdas)?;
+    let ix = remove_liquidity_ix_full_for_prog(
+        program_id,
+        keys,
+        RemoveLiquidityIxFullArgs { lst_index, amts },
+        AddRemoveLiquidityExtraAccounts::new(program_ids, account_suffixes),
+    )?;
+    Ok(ix)
+}
@@ -0,0 +1,145 @@
+use s_controller_interface::{
+    remove_liquidity_exact_out_ix_with_program_id, RemoveLiquidityExactOutIxArgs,
+    RemoveLiquidityExactOutIxData, RemoveLiquidityExactOutKeys, SControllerError,
+};
+use solana_program::{instruction::Instruction, program_error::ProgramError, pubkey::Pubkey};
+use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountOwner, ReadonlyAccountPubkey};
+
+use crate::{
+    index_to_u32, ix_extend_with_pricing_program_price_lp_accounts,
+    ix_extend_with_sol_value_calculator_accounts, AddRemoveLiquidityAccountSuffixes,
+    AddRemoveLiquidityExtraAccounts, RemoveLiquidityByMintFreeArgs, SwapLiquidityPdas,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RemoveLiquidityExactOutIxAmts {
+    pub lst_amount: u64,
+    pub max_lp_in: u64,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RemoveLiquidityExactOutIxFullArgs {
+    pub lst_index: usize,
+    pub amts: RemoveLiquidityExactOutIxAmts,
+}
+
+pub fn remove_liquidity_exact_out_ix_full(
+    accounts: RemoveLiquidityExactOutKeys,
+    args: RemoveLiquidityExactOutIxFullArgs,
+    extra_accounts: AddRemoveLiquidityExtraAccounts,
+) -> Result<Instruction, ProgramError> {
+    remove_liquidity_exact_out_ix_full_for_prog(crate::program::ID, accounts, args, extra_accounts)
+}
+
+pub fn remove_liquidity_exact_out_ix_full_for_prog(
+    program_id: Pubkey,
+    accounts: RemoveLiquidityExactOutKeys,
+    RemoveLiquidityExactOutIxFullArgs {
+        lst_index,
+        amts:
+            RemoveLiquidityExactOutIxAmts {
+                lst_amount,
+                max_lp_in,
+            },
+    }: RemoveLiquidityExactOutIxFullArgs,
+    AddRemoveLiquidityExtraAccounts {
+        lst_calculator_program_id,
+        pricing_program_id,
+        lst_calculator_accounts,
+        pricing_program_price_lp_accounts,
+    }: AddRemoveLiquidityExtraAccounts,
+) -> Result<Instruction, ProgramError> {
+    let lst_index = index_to_u32(lst_index)?;
+    let mut ix = remove_liquidity_exact_out_ix_with_program_id(
+        program_id,
+        accounts,
+        RemoveLiquidityExactOutIxArgs {
+            lst_value_calc_accs: 0,
+            lst_index,
+            lst_amount,
+            max_lp_in,
+        },
+    )?;
+    let lst_value_calc_accs = ix_extend_with_sol_value_calculator_accounts(
+        &mut ix,
+        lst_calculator_accounts,
+        lst_calculator_program_id,
+    )
+    .map_err(|_e| SControllerError::MathError)?;
+    ix_extend_with_pricing_program_price_lp_accounts(
+        &mut ix,
+        pricing_program_price_lp_accounts,
+        pricing_program_id,
+    )
+    .map_err(|_e| SControllerError::MathError)?;
+    // overwrite lst_value_calc_accs now that calculator accounts have been added
+    let mut overwrite = &mut ix.data[..];
+    RemoveLiquidityExactOutIxData(RemoveLiquidityExactOutIxArgs {
+        lst_value_calc_accs,
+        lst_index,
+        lst_amount,
+        max_lp_in,
+    })
+    .serialize(&mut overwrite)?;
+    Ok(ix)
+}
+
+pub fn remove_liquidity_exact_out_ix_by_mint_full<
+    S: ReadonlyAccountData,
+    L: ReadonlyAccountData,
+    M: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+>(
+    free_args: RemoveLiquidityByMintFreeArgs<S, L, M>,
+    amts: RemoveLiquidityExactOutIxAmts,
+    account_suffixes: AddRemoveLiquidityAccountSuffixes,
+) -> Result<Instruction, ProgramError> {
+    let (keys, lst_index, program_ids) = free_args.resolve_exact_out()?;
+    let ix = remove_liquidity_exact_out_ix_full(
+        keys,
+        RemoveLiquidityExactOutIxFullArgs { lst_index, amts },
+        AddRemoveLiquidityExtraAccounts::new(program_ids, account_suffixes),
+    )?;
+    Ok(ix)
+}
+
+pub fn remove_liquidity_exact_out_ix_by_mint_full_for_prog<
+    S: ReadonlyAccountData,
+    L: ReadonlyAccountData,
+    M: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+>(
+    program_id: Pubkey,
+    free_args: RemoveLiquidityByMintFreeArgs<S, L, M>,
+    amts: RemoveLiquidityExactOutIxAmts,
+    account_suffixes: AddRemoveLiquidityAccountSuffixes,
+) -> Result<Instruction, ProgramError> {
+    let (keys, lst_index, program_ids) = free_args.resolve_exact_out_for_prog(program_id)?;
+    let ix = remove_liquidity_exact_out_ix_full_for_prog(
+        program_id,
+        keys,
+        RemoveLiquidityExactOutIxFullArgs { lst_index, amts },
+        AddRemoveLiquidityExtraAccounts::new(program_ids, account_suffixes),
+    )?;
+    Ok(ix)
+}
+
+/// For pools other than the default pool, e.g. ones created with `InitializePool`
+pub fn remove_liquidity_exact_out_ix_by_mint_full_for_pool<
+    S: ReadonlyAccountData,
+    L: ReadonlyAccountData,
+    M: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+>(
+    program_id: Pubkey,
+    pdas: SwapLiquidityPdas,
+    free_args: RemoveLiquidityByMintFreeArgs<S, L, M>,
+    amts: RemoveLiquidityExactOutIxAmts,
+    account_suffixes: AddRemoveLiquidityAccountSuffixes,
+) -> Result<Instruction, ProgramError> {
+    let (keys, lst_index, program_ids) = free_args.resolve_exact_out_with_pdas(pdas)?;
+    let ix = remove_liquidity_exact_out_ix_full_for_prog(
+        program_id,
+        keys,
+        RemoveLiquidityExactOutIxFullArgs { lst_index, amts },
+        AddRemoveLiquidityExtraAccounts::new(program_ids, account_suffixes),
+    )?;
+    Ok(ix)
+}
@@ -0,0 +1,184 @@
+use s_controller_interface::{
+    remove_liquidity_pro_rata_ix_with_program_id, RemoveLiquidityProRataIxArgs,
+    RemoveLiquidityProRataIxData, RemoveLiquidityProRataKeys, SControllerError,
+};
+use solana_program::{
+    instruction::{AccountMeta, Instruction},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountOwner, ReadonlyAccountPubkey};
+
+use crate::{
+    ix_extend_with_pricing_program_price_lp_accounts, ix_extend_with_sol_value_calculator_accounts,
+    zip_sol_val_calc_accounts, LstSolValueCalcAccounts, MultiLiquidityLstKeys,
+    MultiLiquidityResolved, PoolPdas, RemoveLiquidityProRataByMintsFreeArgs, SwapLiquidityPdas,
+    MULTI_LIQUIDITY_LST_ACCOUNTS_LEN,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RemoveLiquidityProRataIxAmts<'me> {
+    pub lp_token_amount: u64,
+    /// Min amount of each LST to receive, in the same order as lst_state_list
+    pub min_lst_outs: &'me [u64],
+}
+
+/// Args:
+/// - `lst_keys`: [`MultiLiquidityLstKeys`] of every LST in the pool, in lst_state_list order
+/// - `sol_val_calc_accounts`: SOL value calculator accounts of every LST in the pool, in lst_state_list order
+/// - `pricing_program_accounts`: pricing program PriceLpTokensToRedeem accounts of every LST in the pool,
+///     in lst_state_list order.
+///     Should include the common interface account prefixes but exclude the program ID
+pub fn remove_liquidity_pro_rata_ix_full(
+    accounts: RemoveLiquidityProRataKeys,
+    amts: RemoveLiquidityProRataIxAmts,
+    lst_keys: &[MultiLiquidityLstKeys],
+    sol_val_calc_accounts: &[LstSolValueCalcAccounts],
+    pricing_program_accounts: &[&[AccountMeta]],
+    pricing_program_id: Pubkey,
+) -> Result<Instruction, ProgramError> {
+    remove_liquidity_pro_rata_ix_full_for_prog(
+        crate::program::ID,
+        accounts,
+        amts,
+        lst_keys,
+        sol_val_calc_accounts,
+        pricing_program_accounts,
+        pricing_program_id,
+    )
+}
+
+pub fn remove_liquidity_pro_rata_ix_full_for_prog(
+    program_id: Pubkey,
+    accounts: RemoveLiquidityProRataKeys,
+    RemoveLiquidityProRataIxAmts {
+        lp_token_amount,
+        min_lst_outs,
+    }: RemoveLiquidityProRataIxAmts,
+    lst_keys: &[MultiLiquidityLstKeys],
+    sol_val_calc_accounts: &[LstSolValueCalcAccounts],
+    pricing_program_accounts: &[&[AccountMeta]],
+    pricing_program_id: Pubkey,
+) -> Result<Instruction, ProgramError> {
+    let n_lsts = min_lst_outs.len();
+    if n_lsts == 0
+        || lst_keys.len() != n_lsts
+        || sol_val_calc_accounts.len() != n_lsts
+        || pricing_program_accounts.len() != n_lsts
+    {
+        return Err(SControllerError::InvalidLiquidityLsts.into());
+    }
+    let mut ix = remove_liquidity_pro_rata_ix_with_program_id(
+        program_id,
+        accounts,
+        RemoveLiquidityProRataIxArgs {
+            // empty vecs replaced by ix_extend below
+            lst_value_calc_accs: vec![],
+            pricing_accs: vec![],
+            lp_token_amount,
+            min_lst_outs: min_lst_outs.to_vec(),
+        },
+    )?;
+    for keys in lst_keys {
+        let metas: [AccountMeta; MULTI_LIQUIDITY_LST_ACCOUNTS_LEN] = (*keys).into();
+        ix.accounts.extend(metas);
+    }
+    let lst_value_calc_accs = sol_val_calc_accounts
+        .iter()
+        .map(
+            |LstSolValueCalcAccounts {
+                 calculator_program_id,
+                 calculator_accounts,
+             }| {
+                ix_extend_with_sol_value_calculator_accounts(
+                    &mut ix,
+                    calculator_accounts,
+                    *calculator_program_id,
+                )
+                .map_err(|_e| SControllerError::MathError)
+            },
+        )
+        .collect::<Result<Vec<_>, _>>()?;
+    let pricing_accs = pricing_program_accounts
+        .iter()
+        .map(|lst_pricing_accounts| {
+            ix_extend_with_pricing_program_price_lp_accounts(
+                &mut ix,
+                lst_pricing_accounts,
+                pricing_program_id,
+            )
+            .map_err(|_e| SControllerError::MathError)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    ix.data = RemoveLiquidityProRataIxData(RemoveLiquidityProRataIxArgs {
+        lst_value_calc_accs,
+        pricing_accs,
+        lp_token_amount,
+        min_lst_outs: min_lst_outs.to_vec(),
+    })
+    .try_to_vec()?;
+    Ok(ix)
+}
+
+/// Args:
+/// - `sol_val_calc_account_suffixes`: SOL value calculator accounts of every LST in the pool, in lst_state_list order.
+///     Should include the common interface account prefixes but exclude the program ID
+/// - `pricing_program_accounts`: pricing program PriceLpTokensToRedeem accounts of every LST in the pool,
+///     in lst_state_list order.
+///     Should include the common interface account prefixes but exclude the program ID
+pub fn remove_liquidity_pro_rata_ix_by_mints_full<
+    S: ReadonlyAccountData,
+    L: ReadonlyAccountData,
+    M: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+>(
+    free_args: RemoveLiquidityProRataByMintsFreeArgs<S, L, M>,
+    amts: RemoveLiquidityProRataIxAmts,
+    sol_val_calc_account_suffixes: &[&[AccountMeta]],
+    pricing_program_accounts: &[&[AccountMeta]],
+) -> Result<Instruction, ProgramError> {
+    remove_liquidity_pro_rata_ix_by_mints_full_for_pool(
+        crate::program::ID,
+        PoolPdas::DEFAULT.into(),
+        free_args,
+        amts,
+        sol_val_calc_account_suffixes,
+        pricing_program_accounts,
+    )
+}
+
+/// For pools other than the default pool, e.g. ones created with `InitializePool`
+pub fn remove_liquidity_pro_rata_ix_by_mints_full_for_pool<
+    S: ReadonlyAccountData,
+    L: ReadonlyAccountData,
+    M: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+>(
+    program_id: Pubkey,
+    pdas: SwapLiquidityPdas,
+    free_args: RemoveLiquidityProRataByMintsFreeArgs<S, L, M>,
+    amts: RemoveLiquidityProRataIxAmts,
+    sol_val_calc_account_suffixes: &[&[AccountMeta]],
+    pricing_program_accounts: &[&[AccountMeta]],
+) -> Result<Instruction, ProgramError> {
+    let (
+        keys,
+        MultiLiquidityResolved {
+            lst_keys,
+            sol_value_calculator_program_ids,
+            pricing_program_id,
+            ..
+        },
+    ) = free_args.resolve_with_pdas(pdas)?;
+    let sol_val_calc_accounts = zip_sol_val_calc_accounts(
+        &sol_value_calculator_program_ids,
+        sol_val_calc_account_suffixes,
+    )?;
+    remove_liquidity_pro_rata_ix_full_for_prog(
+        program_id,
+        keys,
+        amts,
+        &lst_keys,
+        &sol_val_calc_accounts,
+        pricing_program_accounts,
+        pricing_program_id,
+    )
+}
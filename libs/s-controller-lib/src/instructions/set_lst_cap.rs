@@ -0,0 +1,21 @@
+use s_controller_interface::{set_lst_cap_ix, SetLstCapIxArgs};
+use solana_program::{instruction::Instruction, program_error::ProgramError};
+use solana_readonly_account::ReadonlyAccountData;
+
+use crate::{index_to_u32, SetLstCapByMintFreeArgs};
+
+pub fn set_lst_cap_ix_by_mint_full<S: ReadonlyAccountData, L: ReadonlyAccountData>(
+    free_args: &SetLstCapByMintFreeArgs<S, L>,
+    max_share_bps: u16,
+) -> Result<Instruction, ProgramError> {
+    let (keys, lst_index) = free_args.resolve()?;
+    let index = index_to_u32(lst_index)?;
+    let ix = set_lst_cap_ix(
+        keys,
+        SetLstCapIxArgs {
+            index,
+            max_share_bps,
+        },
+    )?;
+    Ok(ix)
+}
@@ -10,7 +10,8 @@ use solana_program::{
 use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountOwner, ReadonlyAccountPubkey};
 
 use crate::{
-    index_to_u32, ix_extend_with_sol_value_calculator_accounts, SetSolValueCalculatorByMintFreeArgs,
+    index_to_u32, ix_extend_with_sol_value_calculator_accounts, PoolPdas,
+    SetSolValueCalculatorByMintFreeArgs,
 };
 
 pub fn set_sol_value_calculator_ix_full(
@@ -59,7 +60,28 @@ pub fn set_sol_value_calculator_ix_by_mint_full_with_program_id<
     sol_value_calculator_accounts: &[AccountMeta],
     sol_value_calculator_program_id: Pubkey,
 ) -> Result<Instruction, ProgramError> {
-    let (keys, lst_index) = free_args.resolve_for_prog(program_id)?;
+    set_sol_value_calculator_ix_by_mint_full_for_pool(
+        program_id,
+        PoolPdas::find_for_program_id(program_id),
+        free_args,
+        sol_value_calculator_accounts,
+        sol_value_calculator_program_id,
+    )
+}
+
+/// For pools other than the default pool, e.g. ones created with `InitializePool`
+pub fn set_sol_value_calculator_ix_by_mint_full_for_pool<
+    S: ReadonlyAccountData,
+    L: ReadonlyAccountData,
+    M: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+>(
+    program_id: Pubkey,
+    pdas: PoolPdas,
+    free_args: &SetSolValueCalculatorByMintFreeArgs<S, L, M>,
+    sol_value_calculator_accounts: &[AccountMeta],
+    sol_value_calculator_program_id: Pubkey,
+) -> Result<Instruction, ProgramError> {
+    let (keys, lst_index) = free_args.resolve_for_pool(pdas)?;
     let lst_index = index_to_u32(lst_index)?;
     let mut ix = set_sol_value_calculator_ix_with_program_id(
         program_id,
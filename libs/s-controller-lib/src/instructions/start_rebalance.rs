@@ -1,8 +1,8 @@
 use s_controller_interface::{
-    start_rebalance_ix, SControllerError, StartRebalanceIxArgs, StartRebalanceIxData,
-    StartRebalanceKeys,
+    start_rebalance_ix_with_program_id, SControllerError, StartRebalanceIxArgs,
+    StartRebalanceIxData, StartRebalanceKeys,
 };
-use solana_program::{instruction::Instruction, program_error::ProgramError};
+use solana_program::{instruction::Instruction, program_error::ProgramError, pubkey::Pubkey};
 use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountOwner, ReadonlyAccountPubkey};
 
 use crate::{
@@ -17,6 +17,10 @@ pub struct StartRebalanceIxLstAmts {
     pub amount: u64,
     pub min_starting_src_lst: u64,
     pub max_starting_dst_lst: u64,
+    /// Min total SOL value of the pool after the rebalance.
+    /// Raised to the min allowed by the pool's rebalance loss limits if lower,
+    /// so 0 means allowing the pool to lose as much SOL value as the limits allow.
+    pub min_ending_sol_value: u64,
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -27,6 +31,15 @@ pub struct StartRebalanceIxFullArgs {
 }
 
 pub fn start_rebalance_ix_full(
+    accounts: StartRebalanceKeys,
+    args: StartRebalanceIxFullArgs,
+    sol_val_calc_keys: SrcDstLstSolValueCalcAccounts,
+) -> Result<Instruction, ProgramError> {
+    start_rebalance_ix_full_for_prog(crate::program::ID, accounts, args, sol_val_calc_keys)
+}
+
+pub fn start_rebalance_ix_full_for_prog(
+    program_id: Pubkey,
     accounts: StartRebalanceKeys,
     StartRebalanceIxFullArgs {
         src_lst_index,
@@ -36,13 +49,15 @@ pub fn start_rebalance_ix_full(
                 amount,
                 min_starting_src_lst,
                 max_starting_dst_lst,
+                min_ending_sol_value,
             },
     }: StartRebalanceIxFullArgs,
     sol_val_calc_keys: SrcDstLstSolValueCalcAccounts,
 ) -> Result<Instruction, ProgramError> {
     let src_lst_index = index_to_u32(src_lst_index)?;
     let dst_lst_index = index_to_u32(dst_lst_index)?;
-    let mut ix = start_rebalance_ix(
+    let mut ix = start_rebalance_ix_with_program_id(
+        program_id,
         accounts,
         StartRebalanceIxArgs {
             src_lst_calc_accs: 0,
@@ -51,6 +66,7 @@ pub fn start_rebalance_ix_full(
             amount,
             min_starting_src_lst,
             max_starting_dst_lst,
+            min_ending_sol_value,
         },
     )?;
     let extend_count =
@@ -65,6 +81,7 @@ pub fn start_rebalance_ix_full(
         amount,
         min_starting_src_lst,
         max_starting_dst_lst,
+        min_ending_sol_value,
     })
     .serialize(&mut overwrite)?;
     Ok(ix)
@@ -79,6 +96,25 @@ pub fn start_rebalance_ix_by_mints_full<
     free_args: StartRebalanceByMintsFreeArgs<SM, DM, S, L>,
     lst_amts: StartRebalanceIxLstAmts,
     sol_val_calc_account_suffixes: SrcDstLstSolValueCalcAccountSuffixes,
+) -> Result<Instruction, ProgramError> {
+    start_rebalance_ix_by_mints_full_for_prog(
+        crate::program::ID,
+        free_args,
+        lst_amts,
+        sol_val_calc_account_suffixes,
+    )
+}
+
+pub fn start_rebalance_ix_by_mints_full_for_prog<
+    SM: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+    DM: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+    S: ReadonlyAccountData + ReadonlyAccountPubkey,
+    L: ReadonlyAccountData + ReadonlyAccountPubkey,
+>(
+    program_id: Pubkey,
+    free_args: StartRebalanceByMintsFreeArgs<SM, DM, S, L>,
+    lst_amts: StartRebalanceIxLstAmts,
+    sol_val_calc_account_suffixes: SrcDstLstSolValueCalcAccountSuffixes,
 ) -> Result<Instruction, ProgramError> {
     let (
         start_rebalance_keys,
@@ -87,8 +123,9 @@ pub fn start_rebalance_ix_by_mints_full<
             dst_lst_index,
         },
         program_ids,
-    ) = free_args.resolve()?;
-    start_rebalance_ix_full(
+    ) = free_args.resolve_for_prog(program_id)?;
+    start_rebalance_ix_full_for_prog(
+        program_id,
         start_rebalance_keys,
         StartRebalanceIxFullArgs {
             src_lst_index,
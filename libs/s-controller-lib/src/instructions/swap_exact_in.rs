@@ -13,7 +13,7 @@ use crate::{
     index_to_u32, ix_extend_with_pricing_program_price_swap_accounts,
     ix_extend_with_src_dst_sol_value_calculator_accounts, SrcDstLstIndexes,
     SrcDstLstSolValueCalcAccountSuffixes, SrcDstLstSolValueCalcAccounts,
-    SrcDstLstSolValueCalcExtendCount, SwapByMintsFreeArgs,
+    SrcDstLstSolValueCalcExtendCount, SwapByMintsFreeArgs, SwapLiquidityPdas,
 };
 
 #[derive(Clone, Copy, Debug)]
@@ -181,3 +181,47 @@ pub fn swap_exact_in_ix_by_mint_full_for_prog<
     )?;
     Ok(ix)
 }
+
+/// For pools other than the default pool, e.g. ones created with `InitializePool`
+pub fn swap_exact_in_ix_by_mint_full_for_pool<
+    SM: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+    DM: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+    L: ReadonlyAccountData,
+>(
+    program_id: Pubkey,
+    pdas: SwapLiquidityPdas,
+    free_args: SwapByMintsFreeArgs<SM, DM, L>,
+    SwapExactInAmounts {
+        min_amount_out,
+        amount,
+    }: SwapExactInAmounts,
+    src_dst_lst_sol_value_calc_account_suffixes: SrcDstLstSolValueCalcAccountSuffixes,
+    pricing_program_accounts: &[AccountMeta],
+    pricing_program_id: Pubkey,
+) -> Result<Instruction, ProgramError> {
+    let (
+        keys,
+        SrcDstLstIndexes {
+            src_lst_index,
+            dst_lst_index,
+        },
+        src_dst_lst_sol_value_calc_program_ids,
+    ) = free_args.resolve_exact_in_with_pdas(pdas)?;
+    let ix = swap_exact_in_ix_full_for_prog(
+        program_id,
+        keys,
+        SwapExactInIxFullArgs {
+            src_lst_index,
+            dst_lst_index,
+            min_amount_out,
+            amount,
+        },
+        SrcDstLstSolValueCalcAccounts::new(
+            src_dst_lst_sol_value_calc_program_ids,
+            src_dst_lst_sol_value_calc_account_suffixes,
+        ),
+        pricing_program_accounts,
+        pricing_program_id,
+    )?;
+    Ok(ix)
+}
@@ -0,0 +1,210 @@
+use s_controller_interface::{
+    swap_exact_in_route_ix_with_program_id, SControllerError, SwapExactInRouteIxArgs,
+    SwapExactInRouteIxData, SwapExactInRouteKeys,
+};
+use solana_program::{
+    instruction::{AccountMeta, Instruction},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountOwner, ReadonlyAccountPubkey};
+
+use crate::{
+    index_to_u32, ix_extend_with_pricing_program_price_swap_accounts,
+    ix_extend_with_sol_value_calculator_accounts, PoolPdas, SwapExactInAmounts, SwapLiquidityPdas,
+    SwapRouteByMintsFreeArgs, SwapRouteLstKeys, SwapRouteResolved, SWAP_ROUTE_LST_ACCOUNTS_LEN,
+};
+
+/// calculator_accounts should include the common interface account prefixes
+/// but exclude the program ID
+#[derive(Debug, Clone, Copy)]
+pub struct LstSolValueCalcAccounts<'me> {
+    pub calculator_program_id: Pubkey,
+    pub calculator_accounts: &'me [AccountMeta],
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct SwapExactInRouteIxFullArgs<'me> {
+    /// Indexes of every LST on the route, including the first (src) and last (dst) LST
+    pub lst_indexes: &'me [usize],
+    pub min_amount_out: u64,
+    pub amount: u64,
+}
+
+/// Args:
+/// - `route_lst_keys`: [`SwapRouteLstKeys`] of every LST on the route, in route order
+/// - `sol_val_calc_accounts`: SOL value calculator accounts of every LST on the route, in route order
+/// - `pricing_program_accounts`: pricing program PriceExactIn accounts of every hop on the route, in route order.
+///     Should include the common interface account prefixes but exclude the program ID
+pub fn swap_exact_in_route_ix_full(
+    accounts: SwapExactInRouteKeys,
+    args: SwapExactInRouteIxFullArgs,
+    route_lst_keys: &[SwapRouteLstKeys],
+    sol_val_calc_accounts: &[LstSolValueCalcAccounts],
+    pricing_program_accounts: &[&[AccountMeta]],
+    pricing_program_id: Pubkey,
+) -> Result<Instruction, ProgramError> {
+    swap_exact_in_route_ix_full_for_prog(
+        crate::program::ID,
+        accounts,
+        args,
+        route_lst_keys,
+        sol_val_calc_accounts,
+        pricing_program_accounts,
+        pricing_program_id,
+    )
+}
+
+pub fn swap_exact_in_route_ix_full_for_prog(
+    program_id: Pubkey,
+    accounts: SwapExactInRouteKeys,
+    SwapExactInRouteIxFullArgs {
+        lst_indexes,
+        min_amount_out,
+        amount,
+    }: SwapExactInRouteIxFullArgs,
+    route_lst_keys: &[SwapRouteLstKeys],
+    sol_val_calc_accounts: &[LstSolValueCalcAccounts],
+    pricing_program_accounts: &[&[AccountMeta]],
+    pricing_program_id: Pubkey,
+) -> Result<Instruction, ProgramError> {
+    if route_lst_keys.len() != lst_indexes.len()
+        || sol_val_calc_accounts.len() != lst_indexes.len()
+        || pricing_program_accounts.len() + 1 != lst_indexes.len()
+    {
+        return Err(SControllerError::InvalidSwapRoute.into());
+    }
+    let lst_indexes = lst_indexes
+        .iter()
+        .map(|i| index_to_u32(*i))
+        .collect::<Result<Vec<_>, _>>()?;
+    let mut ix = swap_exact_in_route_ix_with_program_id(
+        program_id,
+        accounts,
+        SwapExactInRouteIxArgs {
+            // empty vecs replaced by ix_extend below
+            lst_value_calc_accs: vec![],
+            pricing_accs: vec![],
+            lst_indexes: lst_indexes.clone(),
+            min_amount_out,
+            amount,
+        },
+    )?;
+    for keys in route_lst_keys {
+        let metas: [AccountMeta; SWAP_ROUTE_LST_ACCOUNTS_LEN] = (*keys).into();
+        ix.accounts.extend(metas);
+    }
+    let lst_value_calc_accs = sol_val_calc_accounts
+        .iter()
+        .map(
+            |LstSolValueCalcAccounts {
+                 calculator_program_id,
+                 calculator_accounts,
+             }| {
+                ix_extend_with_sol_value_calculator_accounts(
+                    &mut ix,
+                    calculator_accounts,
+                    *calculator_program_id,
+                )
+                .map_err(|_e| SControllerError::MathError)
+            },
+        )
+        .collect::<Result<Vec<_>, _>>()?;
+    let pricing_accs = pricing_program_accounts
+        .iter()
+        .map(|hop_accounts| {
+            ix_extend_with_pricing_program_price_swap_accounts(
+                &mut ix,
+                hop_accounts,
+                pricing_program_id,
+            )
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    ix.data = SwapExactInRouteIxData(SwapExactInRouteIxArgs {
+        lst_value_calc_accs,
+        pricing_accs,
+        lst_indexes,
+        min_amount_out,
+        amount,
+    })
+    .try_to_vec()?;
+    Ok(ix)
+}
+
+/// Args:
+/// - `sol_val_calc_account_suffixes`: SOL value calculator accounts of every LST on the route, in route order.
+///     Should include the common interface account prefixes but exclude the program ID
+/// - `pricing_program_accounts`: pricing program PriceExactIn accounts of every hop on the route, in route order.
+///     Should include the common interface account prefixes but exclude the program ID
+pub fn swap_exact_in_route_ix_by_mints_full<
+    M: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+    L: ReadonlyAccountData,
+>(
+    free_args: SwapRouteByMintsFreeArgs<M, L>,
+    amounts: SwapExactInAmounts,
+    sol_val_calc_account_suffixes: &[&[AccountMeta]],
+    pricing_program_accounts: &[&[AccountMeta]],
+    pricing_program_id: Pubkey,
+) -> Result<Instruction, ProgramError> {
+    swap_exact_in_route_ix_by_mints_full_for_pool(
+        crate::program::ID,
+        PoolPdas::DEFAULT.into(),
+        free_args,
+        amounts,
+        sol_val_calc_account_suffixes,
+        pricing_program_accounts,
+        pricing_program_id,
+    )
+}
+
+/// For pools other than the default pool, e.g. ones created with `InitializePool`
+pub fn swap_exact_in_route_ix_by_mints_full_for_pool<
+    M: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+    L: ReadonlyAccountData,
+>(
+    program_id: Pubkey,
+    pdas: SwapLiquidityPdas,
+    free_args: SwapRouteByMintsFreeArgs<M, L>,
+    SwapExactInAmounts {
+        min_amount_out,
+        amount,
+    }: SwapExactInAmounts,
+    sol_val_calc_account_suffixes: &[&[AccountMeta]],
+    pricing_program_accounts: &[&[AccountMeta]],
+    pricing_program_id: Pubkey,
+) -> Result<Instruction, ProgramError> {
+    let (
+        keys,
+        SwapRouteResolved {
+            lst_keys,
+            lst_indexes,
+            sol_value_calculator_program_ids,
+        },
+    ) = free_args.resolve_exact_in_with_pdas(pdas)?;
+    if sol_val_calc_account_suffixes.len() != sol_value_calculator_program_ids.len() {
+        return Err(SControllerError::InvalidSwapRoute.into());
+    }
+    let sol_val_calc_accounts: Vec<LstSolValueCalcAccounts> = sol_value_calculator_program_ids
+        .into_iter()
+        .zip(sol_val_calc_account_suffixes.iter().copied())
+        .map(
+            |(calculator_program_id, calculator_accounts)| LstSolValueCalcAccounts {
+                calculator_program_id,
+                calculator_accounts,
+            },
+        )
+        .collect();
+    swap_exact_in_route_ix_full_for_prog(
+        program_id,
+        keys,
+        SwapExactInRouteIxFullArgs {
+            lst_indexes: &lst_indexes,
+            min_amount_out,
+            amount,
+        },
+        &lst_keys,
+        &sol_val_calc_accounts,
+        pricing_program_accounts,
+        pricing_program_id,
+    )
+}
@@ -0,0 +1,45 @@
+use s_controller_interface::{
+    withdraw_protocol_fees_multi_ix_with_program_id, SControllerError,
+    WithdrawProtocolFeesMultiIxArgs, WithdrawProtocolFeesMultiKeys,
+};
+use solana_program::{
+    instruction::{AccountMeta, Instruction},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::{WithdrawProtocolFeesMultiLstKeys, WITHDRAW_PROTOCOL_FEES_MULTI_LST_ACCOUNTS_LEN};
+
+/// Args:
+/// - `lst_keys`: [`WithdrawProtocolFeesMultiLstKeys`] of every LST to withdraw protocol fees from, in order
+/// - `amounts`: amount of each LST to withdraw, in the same order as `lst_keys`
+pub fn withdraw_protocol_fees_multi_ix_full(
+    keys: WithdrawProtocolFeesMultiKeys,
+    lst_keys: &[WithdrawProtocolFeesMultiLstKeys],
+    amounts: &[u64],
+) -> Result<Instruction, ProgramError> {
+    withdraw_protocol_fees_multi_ix_full_for_prog(crate::program::ID, keys, lst_keys, amounts)
+}
+
+pub fn withdraw_protocol_fees_multi_ix_full_for_prog(
+    program_id: Pubkey,
+    keys: WithdrawProtocolFeesMultiKeys,
+    lst_keys: &[WithdrawProtocolFeesMultiLstKeys],
+    amounts: &[u64],
+) -> Result<Instruction, ProgramError> {
+    if lst_keys.is_empty() || lst_keys.len() != amounts.len() {
+        return Err(SControllerError::InvalidProtocolFeeWithdrawals.into());
+    }
+    let mut ix = withdraw_protocol_fees_multi_ix_with_program_id(
+        program_id,
+        keys,
+        WithdrawProtocolFeesMultiIxArgs {
+            amounts: amounts.to_vec(),
+        },
+    )?;
+    for lst in lst_keys {
+        let metas: [AccountMeta; WITHDRAW_PROTOCOL_FEES_MULTI_LST_ACCOUNTS_LEN] = (*lst).into();
+        ix.accounts.extend(metas);
+    }
+    Ok(ix)
+}
@@ -0,0 +1,126 @@
+use s_controller_interface::{
+    withdraw_protocol_fees_swap_ix_with_program_id, SControllerError,
+    WithdrawProtocolFeesSwapIxArgs, WithdrawProtocolFeesSwapIxData, WithdrawProtocolFeesSwapKeys,
+};
+use solana_program::{instruction::Instruction, program_error::ProgramError, pubkey::Pubkey};
+use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountOwner, ReadonlyAccountPubkey};
+
+use crate::{
+    index_to_u32, ix_extend_with_src_dst_sol_value_calculator_accounts, SrcDstLstIndexes,
+    SrcDstLstSolValueCalcAccountSuffixes, SrcDstLstSolValueCalcAccounts, SwapExactInAmounts,
+    WithdrawProtocolFeesSwapByMintsFreeArgs,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct WithdrawProtocolFeesSwapIxFullArgs {
+    pub src_lst_index: usize,
+    pub dst_lst_index: usize,
+    pub min_amount_out: u64,
+    pub amount: u64,
+}
+
+pub fn withdraw_protocol_fees_swap_ix_full(
+    accounts: WithdrawProtocolFeesSwapKeys,
+    args: WithdrawProtocolFeesSwapIxFullArgs,
+    sol_val_calc_accounts: SrcDstLstSolValueCalcAccounts,
+) -> Result<Instruction, ProgramError> {
+    withdraw_protocol_fees_swap_ix_full_for_prog(
+        crate::program::ID,
+        accounts,
+        args,
+        sol_val_calc_accounts,
+    )
+}
+
+pub fn withdraw_protocol_fees_swap_ix_full_for_prog(
+    program_id: Pubkey,
+    accounts: WithdrawProtocolFeesSwapKeys,
+    WithdrawProtocolFeesSwapIxFullArgs {
+        src_lst_index,
+        dst_lst_index,
+        min_amount_out,
+        amount,
+    }: WithdrawProtocolFeesSwapIxFullArgs,
+    sol_val_calc_accounts: SrcDstLstSolValueCalcAccounts,
+) -> Result<Instruction, ProgramError> {
+    let src_lst_index = index_to_u32(src_lst_index)?;
+    let dst_lst_index = index_to_u32(dst_lst_index)?;
+    let mut ix = withdraw_protocol_fees_swap_ix_with_program_id(
+        program_id,
+        accounts,
+        WithdrawProtocolFeesSwapIxArgs {
+            src_lst_calc_accs: 0,
+            src_lst_index,
+            dst_lst_index,
+            min_amount_out,
+            amount,
+        },
+    )?;
+    let extend_count =
+        ix_extend_with_src_dst_sol_value_calculator_accounts(&mut ix, sol_val_calc_accounts)
+            .map_err(|_e| SControllerError::MathError)?;
+    // TODO: better way to update src_lst_calc_accs than double serialization here
+    let mut overwrite = &mut ix.data[..];
+    WithdrawProtocolFeesSwapIxData(WithdrawProtocolFeesSwapIxArgs {
+        src_lst_calc_accs: extend_count.src_lst,
+        src_lst_index,
+        dst_lst_index,
+        min_amount_out,
+        amount,
+    })
+    .serialize(&mut overwrite)?;
+    Ok(ix)
+}
+
+pub fn withdraw_protocol_fees_swap_ix_by_mints_full<
+    SM: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+    DM: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+    S: ReadonlyAccountData + ReadonlyAccountPubkey,
+    L: ReadonlyAccountData + ReadonlyAccountPubkey,
+>(
+    free_args: WithdrawProtocolFeesSwapByMintsFreeArgs<SM, DM, S, L>,
+    amounts: SwapExactInAmounts,
+    sol_val_calc_account_suffixes: SrcDstLstSolValueCalcAccountSuffixes,
+) -> Result<Instruction, ProgramError> {
+    withdraw_protocol_fees_swap_ix_by_mints_full_for_prog(
+        crate::program::ID,
+        free_args,
+        amounts,
+        sol_val_calc_account_suffixes,
+    )
+}
+
+pub fn withdraw_protocol_fees_swap_ix_by_mints_full_for_prog<
+    SM: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+    DM: ReadonlyAccountOwner + ReadonlyAccountPubkey,
+    S: ReadonlyAccountData + ReadonlyAccountPubkey,
+    L: ReadonlyAccountData + ReadonlyAccountPubkey,
+>(
+    program_id: Pubkey,
+    free_args: WithdrawProtocolFeesSwapByMintsFreeArgs<SM, DM, S, L>,
+    SwapExactInAmounts {
+        min_amount_out,
+        amount,
+    }: SwapExactInAmounts,
+    sol_val_calc_account_suffixes: SrcDstLstSolValueCalcAccountSuffixes,
+) -> Result<Instruction, ProgramError> {
+    let (
+        keys,
+        SrcDstLstIndexes {
+            src_lst_index,
+            dst_lst_index,
+        },
+        program_ids,
+    ) = free_args.resolve_for_prog(program_id)?;
+    withdraw_protocol_fees_swap_ix_full_for_prog(
+        program_id,
+        keys,
+        WithdrawProtocolFeesSwapIxFullArgs {
+            src_lst_index,
+            dst_lst_index,
+            min_amount_out,
+            amount,
+        },
+        SrcDstLstSolValueCalcAccounts::new(program_ids, sol_val_calc_account_suffixes),
+    )
+}
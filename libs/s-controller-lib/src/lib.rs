@@ -1,6 +1,6 @@
 use s_controller_interface::{
-    LstState, PoolState, RebalanceRecord, SWAP_EXACT_IN_IX_ACCOUNTS_LEN,
-    SWAP_EXACT_OUT_IX_ACCOUNTS_LEN,
+    LstState, PendingChange, PoolState, RebalanceLossState, RebalanceRecord,
+    SWAP_EXACT_IN_IX_ACCOUNTS_LEN, SWAP_EXACT_OUT_IX_ACCOUNTS_LEN,
 };
 use static_assertions::const_assert_eq;
 
@@ -9,10 +9,14 @@ mod accounts_serde;
 mod calc;
 mod consts;
 mod disable_pool_authority_list;
+mod events;
 mod instructions;
 mod lst_indexes;
 mod lst_state_list;
 mod pda;
+mod pending_change;
+mod pool;
+mod rebalance_loss;
 mod state;
 mod u8bool;
 
@@ -21,10 +25,14 @@ pub use accounts_serde::*;
 pub use calc::*;
 pub use consts::*;
 pub use disable_pool_authority_list::*;
+pub use events::*;
 pub use instructions::*;
 pub use lst_indexes::*;
 pub use lst_state_list::*;
 pub use pda::*;
+pub use pending_change::*;
+pub use pool::*;
+pub use rebalance_loss::*;
 pub use state::*;
 pub use u8bool::*;
 
@@ -46,7 +54,7 @@ const_assert_eq!(
     SWAP_EXACT_OUT_IX_ACCOUNTS_LEN
 );
 
-pub const REBALANCE_RECORD_SIZE: usize = 16;
+pub const REBALANCE_RECORD_SIZE: usize = 24;
 const_assert_eq!(
     std::mem::size_of::<RebalanceRecord>(),
     REBALANCE_RECORD_SIZE
@@ -57,6 +65,22 @@ const_assert_eq!(
     REBALANCE_RECORD_ALIGN
 );
 
+pub const PENDING_CHANGE_SIZE: usize = 88;
+const_assert_eq!(std::mem::size_of::<PendingChange>(), PENDING_CHANGE_SIZE);
+pub const PENDING_CHANGE_ALIGN: usize = 8;
+const_assert_eq!(std::mem::align_of::<PendingChange>(), PENDING_CHANGE_ALIGN);
+
+pub const REBALANCE_LOSS_STATE_SIZE: usize = 32;
+const_assert_eq!(
+    std::mem::size_of::<RebalanceLossState>(),
+    REBALANCE_LOSS_STATE_SIZE
+);
+pub const REBALANCE_LOSS_STATE_ALIGN: usize = 8;
+const_assert_eq!(
+    std::mem::align_of::<RebalanceLossState>(),
+    REBALANCE_LOSS_STATE_ALIGN
+);
+
 // putting these consts here instead of in consts.rs
 // so that we dont forget to update the declare_program_keys!()
 // macro below if we change them
@@ -68,6 +92,8 @@ pub const LST_STATE_LIST_PDA_SEED: &[u8] = b"lst-state-list";
 pub const DISABLE_POOL_AUTHORITY_LIST_PDA_SEED: &[u8] = b"disable-pool-authority-list";
 pub const REBALANCE_RECORD_PDA_SEED: &[u8] = b"rebalance-record";
 pub const PROTOCOL_FEE_PDA_SEED: &[u8] = b"protocol-fee";
+pub const PENDING_CHANGE_LIST_PDA_SEED: &[u8] = b"pending-change-list";
+pub const REBALANCE_LOSS_STATE_PDA_SEED: &[u8] = b"rebalance-loss-state";
 
 pub mod program {
     sanctum_macros::declare_program_keys!(
@@ -81,6 +107,12 @@ pub mod program {
             ),
             ("rebalance-record", b"rebalance-record"),
             ("protocol-fee", b"protocol-fee"),
+            ("pending-change-list", b"pending-change-list"),
+            ("rebalance-loss-state", b"rebalance-loss-state"),
         ]
     );
 }
+
+pub mod program_progdata {
+    sanctum_macros::declare_program_keys!("ALmAxhSP7TDWGMHWBLjD9qS71YjqxjN1ba6JLRdzdVy", []);
+}
@@ -4,8 +4,9 @@ use solana_program::pubkey::Pubkey;
 
 use crate::{
     program::{POOL_STATE_ID, PROTOCOL_FEE_ID},
-    DISABLE_POOL_AUTHORITY_LIST_PDA_SEED, LST_STATE_LIST_PDA_SEED, POOL_STATE_PDA_SEED,
-    PROTOCOL_FEE_PDA_SEED, REBALANCE_RECORD_PDA_SEED,
+    PoolId, DISABLE_POOL_AUTHORITY_LIST_PDA_SEED, LST_STATE_LIST_PDA_SEED,
+    PENDING_CHANGE_LIST_PDA_SEED, POOL_STATE_PDA_SEED, PROTOCOL_FEE_PDA_SEED,
+    REBALANCE_LOSS_STATE_PDA_SEED, REBALANCE_RECORD_PDA_SEED,
 };
 
 pub fn create_pool_reserves_address(
@@ -147,3 +148,74 @@ pub fn find_rebalance_record_address(program_id: Pubkey) -> (Pubkey, u8) {
 pub fn find_protocol_fee_address(program_id: Pubkey) -> (Pubkey, u8) {
     Pubkey::find_program_address(&[PROTOCOL_FEE_PDA_SEED], &program_id)
 }
+
+/// Finds the pending change list PDA
+/// For dynamic program IDs.
+/// If using crate's program ID, you can use [`crate::program::PENDING_CHANGE_LIST_ID`] directly
+pub fn find_pending_change_list_address(program_id: Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[PENDING_CHANGE_LIST_PDA_SEED], &program_id)
+}
+
+/// Finds the rebalance loss state PDA
+/// For dynamic program IDs.
+/// If using crate's program ID, you can use [`crate::program::REBALANCE_LOSS_STATE_ID`] directly
+pub fn find_rebalance_loss_state_address(program_id: Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[REBALANCE_LOSS_STATE_PDA_SEED], &program_id)
+}
+
+/// Finds the pool state PDA of the pool identified by `pool_id`.
+/// For [`PoolId::Default`], this is the same as [`find_pool_state_address`]
+pub fn find_pool_state_address_for_pool(program_id: Pubkey, pool_id: PoolId) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[POOL_STATE_PDA_SEED, pool_id.seed()], &program_id)
+}
+
+/// Finds the lst_state_list PDA of the pool identified by `pool_id`.
+/// For [`PoolId::Default`], this is the same as [`find_lst_state_list_address`]
+pub fn find_lst_state_list_address_for_pool(program_id: Pubkey, pool_id: PoolId) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[LST_STATE_LIST_PDA_SEED, pool_id.seed()], &program_id)
+}
+
+/// Finds the disable pool authority list PDA of the pool identified by `pool_id`.
+/// For [`PoolId::Default`], this is the same as [`find_disable_pool_authority_list_address`]
+pub fn find_disable_pool_authority_list_address_for_pool(
+    program_id: Pubkey,
+    pool_id: PoolId,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[DISABLE_POOL_AUTHORITY_LIST_PDA_SEED, pool_id.seed()],
+        &program_id,
+    )
+}
+
+/// Finds the rebalance record PDA of the pool identified by `pool_id`.
+/// For [`PoolId::Default`], this is the same as [`find_rebalance_record_address`]
+pub fn find_rebalance_record_address_for_pool(program_id: Pubkey, pool_id: PoolId) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[REBALANCE_RECORD_PDA_SEED, pool_id.seed()], &program_id)
+}
+
+/// Finds the protocol fee auth PDA of the pool identified by `pool_id`.
+/// For [`PoolId::Default`], this is the same as [`find_protocol_fee_address`]
+pub fn find_protocol_fee_address_for_pool(program_id: Pubkey, pool_id: PoolId) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[PROTOCOL_FEE_PDA_SEED, pool_id.seed()], &program_id)
+}
+
+/// Finds the pending change list PDA of the pool identified by `pool_id`.
+/// For [`PoolId::Default`], this is the same as [`find_pending_change_list_address`]
+pub fn find_pending_change_list_address_for_pool(
+    program_id: Pubkey,
+    pool_id: PoolId,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[PENDING_CHANGE_LIST_PDA_SEED, pool_id.seed()], &program_id)
+}
+
+/// Finds the rebalance loss state PDA of the pool identified by `pool_id`.
+/// For [`PoolId::Default`], this is the same as [`find_rebalance_loss_state_address`]
+pub fn find_rebalance_loss_state_address_for_pool(
+    program_id: Pubkey,
+    pool_id: PoolId,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[REBALANCE_LOSS_STATE_PDA_SEED, pool_id.seed()],
+        &program_id,
+    )
+}
@@ -0,0 +1,298 @@
+use s_controller_interface::{PendingChange, QueuePendingChangeIxArgs, SControllerError};
+use solana_program::pubkey::Pubkey;
+
+use crate::U8Bool;
+
+pub const SECONDS_PER_HOUR: i64 = 60 * 60;
+
+/// Discriminant stored in [`PendingChange::kind`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum PendingChangeKind {
+    SetAdmin = 0,
+    SetPricingProgram = 1,
+    SetSolValueCalculator = 2,
+    SetProtocolFee = 3,
+    RemoveLst = 4,
+    SetTimelockDelay = 5,
+    AddLst = 6,
+    SetRebalanceAuthority = 7,
+    SetProtocolFeeBeneficiary = 8,
+    AddDisablePoolAuthority = 9,
+    RemoveDisablePoolAuthority = 10,
+    SetLstCap = 11,
+}
+
+impl TryFrom<u8> for PendingChangeKind {
+    type Error = SControllerError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        Ok(match value {
+            0 => Self::SetAdmin,
+            1 => Self::SetPricingProgram,
+            2 => Self::SetSolValueCalculator,
+            3 => Self::SetProtocolFee,
+            4 => Self::RemoveLst,
+            5 => Self::SetTimelockDelay,
+            6 => Self::AddLst,
+            7 => Self::SetRebalanceAuthority,
+            8 => Self::SetProtocolFeeBeneficiary,
+            9 => Self::AddDisablePoolAuthority,
+            10 => Self::RemoveDisablePoolAuthority,
+            11 => Self::SetLstCap,
+            _ => return Err(SControllerError::InvalidPendingChange),
+        })
+    }
+}
+
+/// A sensitive admin change that must go through the pending change list
+/// if the pool is in timelock mode.
+///
+/// Typed view of the flat [`PendingChange`] and [`QueuePendingChangeIxArgs`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AdminChange {
+    SetAdmin {
+        new_admin: Pubkey,
+    },
+    SetPricingProgram {
+        new_pricing_program: Pubkey,
+    },
+    SetSolValueCalculator {
+        lst_mint: Pubkey,
+        new_sol_value_calculator: Pubkey,
+    },
+    SetProtocolFee {
+        new_trading_protocol_fee_bps: Option<u16>,
+        new_lp_protocol_fee_bps: Option<u16>,
+    },
+    RemoveLst {
+        lst_mint: Pubkey,
+    },
+    SetTimelockDelay {
+        new_timelock_delay_hours: u8,
+    },
+    AddLst {
+        lst_mint: Pubkey,
+        sol_value_calculator: Pubkey,
+    },
+    SetRebalanceAuthority {
+        new_rebalance_authority: Pubkey,
+    },
+    SetProtocolFeeBeneficiary {
+        new_beneficiary: Pubkey,
+    },
+    AddDisablePoolAuthority {
+        new_authority: Pubkey,
+    },
+    /// The authority's index on the list is looked up at execution time
+    /// since it may shift as other authorities are removed
+    RemoveDisablePoolAuthority {
+        authority: Pubkey,
+    },
+    SetLstCap {
+        lst_mint: Pubkey,
+        max_share_bps: u16,
+    },
+}
+
+impl AdminChange {
+    pub const fn kind(&self) -> PendingChangeKind {
+        match self {
+            Self::SetAdmin { .. } => PendingChangeKind::SetAdmin,
+            Self::SetPricingProgram { .. } => PendingChangeKind::SetPricingProgram,
+            Self::SetSolValueCalculator { .. } => PendingChangeKind::SetSolValueCalculator,
+            Self::SetProtocolFee { .. } => PendingChangeKind::SetProtocolFee,
+            Self::RemoveLst { .. } => PendingChangeKind::RemoveLst,
+            Self::SetTimelockDelay { .. } => PendingChangeKind::SetTimelockDelay,
+            Self::AddLst { .. } => PendingChangeKind::AddLst,
+            Self::SetRebalanceAuthority { .. } => PendingChangeKind::SetRebalanceAuthority,
+            Self::SetProtocolFeeBeneficiary { .. } => PendingChangeKind::SetProtocolFeeBeneficiary,
+            Self::AddDisablePoolAuthority { .. } => PendingChangeKind::AddDisablePoolAuthority,
+            Self::RemoveDisablePoolAuthority { .. } => {
+                PendingChangeKind::RemoveDisablePoolAuthority
+            }
+            Self::SetLstCap { .. } => PendingChangeKind::SetLstCap,
+        }
+    }
+
+    pub fn to_pending_change(self, executable_at: i64) -> PendingChange {
+        let QueuePendingChangeIxArgs {
+            kind,
+            lst_mint,
+            new_pubkey,
+            new_trading_protocol_fee_bps,
+            new_lp_protocol_fee_bps,
+            new_timelock_delay_hours,
+            new_max_share_bps,
+        } = self.into();
+        PendingChange {
+            executable_at,
+            kind,
+            new_timelock_delay_hours,
+            new_trading_protocol_fee_bps: new_trading_protocol_fee_bps.unwrap_or_default(),
+            new_lp_protocol_fee_bps: new_lp_protocol_fee_bps.unwrap_or_default(),
+            is_new_trading_protocol_fee_bps_set: new_trading_protocol_fee_bps.is_some().into(),
+            is_new_lp_protocol_fee_bps_set: new_lp_protocol_fee_bps.is_some().into(),
+            lst_mint,
+            new_pubkey,
+            new_max_share_bps,
+            padding: [0u8; 6],
+        }
+    }
+}
+
+impl From<AdminChange> for QueuePendingChangeIxArgs {
+    fn from(change: AdminChange) -> Self {
+        let mut args = Self {
+            kind: change.kind() as u8,
+            lst_mint: Pubkey::default(),
+            new_pubkey: Pubkey::default(),
+            new_trading_protocol_fee_bps: None,
+            new_lp_protocol_fee_bps: None,
+            new_timelock_delay_hours: 0,
+            new_max_share_bps: 0,
+        };
+        match change {
+            AdminChange::SetAdmin { new_admin } => args.new_pubkey = new_admin,
+            AdminChange::SetPricingProgram {
+                new_pricing_program,
+            } => args.new_pubkey = new_pricing_program,
+            AdminChange::SetSolValueCalculator {
+                lst_mint,
+                new_sol_value_calculator,
+            } => {
+                args.lst_mint = lst_mint;
+                args.new_pubkey = new_sol_value_calculator;
+            }
+            AdminChange::SetProtocolFee {
+                new_trading_protocol_fee_bps,
+                new_lp_protocol_fee_bps,
+            } => {
+                args.new_trading_protocol_fee_bps = new_trading_protocol_fee_bps;
+                args.new_lp_protocol_fee_bps = new_lp_protocol_fee_bps;
+            }
+            AdminChange::RemoveLst { lst_mint } => args.lst_mint = lst_mint,
+            AdminChange::SetTimelockDelay {
+                new_timelock_delay_hours,
+            } => args.new_timelock_delay_hours = new_timelock_delay_hours,
+            AdminChange::AddLst {
+                lst_mint,
+                sol_value_calculator,
+            } => {
+                args.lst_mint = lst_mint;
+                args.new_pubkey = sol_value_calculator;
+            }
+            AdminChange::SetRebalanceAuthority {
+                new_rebalance_authority,
+            } => args.new_pubkey = new_rebalance_authority,
+            AdminChange::SetProtocolFeeBeneficiary { new_beneficiary } => {
+                args.new_pubkey = new_beneficiary
+            }
+            AdminChange::AddDisablePoolAuthority { new_authority } => {
+                args.new_pubkey = new_authority
+            }
+            AdminChange::RemoveDisablePoolAuthority { authority } => args.new_pubkey = authority,
+            AdminChange::SetLstCap {
+                lst_mint,
+                max_share_bps,
+            } => {
+                args.lst_mint = lst_mint;
+                args.new_max_share_bps = max_share_bps;
+            }
+        }
+        args
+    }
+}
+
+impl TryFrom<&QueuePendingChangeIxArgs> for AdminChange {
+    type Error = SControllerError;
+
+    fn try_from(
+        QueuePendingChangeIxArgs {
+            kind,
+            lst_mint,
+            new_pubkey,
+            new_trading_protocol_fee_bps,
+            new_lp_protocol_fee_bps,
+            new_timelock_delay_hours,
+            new_max_share_bps,
+        }: &QueuePendingChangeIxArgs,
+    ) -> Result<Self, Self::Error> {
+        Ok(match PendingChangeKind::try_from(*kind)? {
+            PendingChangeKind::SetAdmin => Self::SetAdmin {
+                new_admin: *new_pubkey,
+            },
+            PendingChangeKind::SetPricingProgram => Self::SetPricingProgram {
+                new_pricing_program: *new_pubkey,
+            },
+            PendingChangeKind::SetSolValueCalculator => Self::SetSolValueCalculator {
+                lst_mint: *lst_mint,
+                new_sol_value_calculator: *new_pubkey,
+            },
+            PendingChangeKind::SetProtocolFee => Self::SetProtocolFee {
+                new_trading_protocol_fee_bps: *new_trading_protocol_fee_bps,
+                new_lp_protocol_fee_bps: *new_lp_protocol_fee_bps,
+            },
+            PendingChangeKind::RemoveLst => Self::RemoveLst {
+                lst_mint: *lst_mint,
+            },
+            PendingChangeKind::SetTimelockDelay => Self::SetTimelockDelay {
+                new_timelock_delay_hours: *new_timelock_delay_hours,
+            },
+            PendingChangeKind::AddLst => Self::AddLst {
+                lst_mint: *lst_mint,
+                sol_value_calculator: *new_pubkey,
+            },
+            PendingChangeKind::SetRebalanceAuthority => Self::SetRebalanceAuthority {
+                new_rebalance_authority: *new_pubkey,
+            },
+            PendingChangeKind::SetProtocolFeeBeneficiary => Self::SetProtocolFeeBeneficiary {
+                new_beneficiary: *new_pubkey,
+            },
+            PendingChangeKind::AddDisablePoolAuthority => Self::AddDisablePoolAuthority {
+                new_authority: *new_pubkey,
+            },
+            PendingChangeKind::RemoveDisablePoolAuthority => Self::RemoveDisablePoolAuthority {
+                authority: *new_pubkey,
+            },
+            PendingChangeKind::SetLstCap => Self::SetLstCap {
+                lst_mint: *lst_mint,
+                max_share_bps: *new_max_share_bps,
+            },
+        })
+    }
+}
+
+impl TryFrom<&PendingChange> for AdminChange {
+    type Error = SControllerError;
+
+    fn try_from(pending_change: &PendingChange) -> Result<Self, Self::Error> {
+        let new_trading_protocol_fee_bps =
+            U8Bool(pending_change.is_new_trading_protocol_fee_bps_set)
+                .is_true()
+                .then_some(pending_change.new_trading_protocol_fee_bps);
+        let new_lp_protocol_fee_bps = U8Bool(pending_change.is_new_lp_protocol_fee_bps_set)
+            .is_true()
+            .then_some(pending_change.new_lp_protocol_fee_bps);
+        Self::try_from(&QueuePendingChangeIxArgs {
+            kind: pending_change.kind,
+            lst_mint: pending_change.lst_mint,
+            new_pubkey: pending_change.new_pubkey,
+            new_trading_protocol_fee_bps,
+            new_lp_protocol_fee_bps,
+            new_timelock_delay_hours: pending_change.new_timelock_delay_hours,
+            new_max_share_bps: pending_change.new_max_share_bps,
+        })
+    }
+}
+
+/// Returns the unix timestamp at which a change queued at `now` can be executed
+pub fn pending_change_executable_at(
+    now: i64,
+    timelock_delay_hours: u8,
+) -> Result<i64, SControllerError> {
+    i64::from(timelock_delay_hours)
+        .checked_mul(SECONDS_PER_HOUR)
+        .and_then(|delay| now.checked_add(delay))
+        .ok_or(SControllerError::MathError)
+}
@@ -0,0 +1,245 @@
+use s_controller_interface::SControllerError;
+use solana_program::pubkey::Pubkey;
+use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountPubkey};
+
+use crate::{
+    find_disable_pool_authority_list_address_for_pool, find_lst_state_list_address_for_pool,
+    find_pending_change_list_address_for_pool, find_pool_state_address_for_pool,
+    find_protocol_fee_address_for_pool, find_rebalance_loss_state_address_for_pool,
+    find_rebalance_record_address_for_pool,
+    program::{
+        DISABLE_POOL_AUTHORITY_LIST_BUMP, DISABLE_POOL_AUTHORITY_LIST_ID, LST_STATE_LIST_BUMP,
+        LST_STATE_LIST_ID, PENDING_CHANGE_LIST_BUMP, PENDING_CHANGE_LIST_ID, POOL_STATE_BUMP,
+        POOL_STATE_ID, PROTOCOL_FEE_BUMP, PROTOCOL_FEE_ID, REBALANCE_LOSS_STATE_BUMP,
+        REBALANCE_LOSS_STATE_ID, REBALANCE_RECORD_BUMP, REBALANCE_RECORD_ID,
+    },
+    try_pool_state, DISABLE_POOL_AUTHORITY_LIST_PDA_SEED, LST_STATE_LIST_PDA_SEED,
+    PENDING_CHANGE_LIST_PDA_SEED, POOL_STATE_PDA_SEED, PROTOCOL_FEE_PDA_SEED,
+    REBALANCE_LOSS_STATE_PDA_SEED, REBALANCE_RECORD_PDA_SEED,
+};
+
+/// Identifies one of the pools managed by a s-controller program.
+///
+/// All of a pool's PDAs are derived from `[<pda seed>, <pool id seed>]`.
+/// The pool id seed is empty for the default pool, which makes its PDAs
+/// the same as the original singleton PDAs.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PoolId {
+    /// The original pool created with `Initialize`
+    Default,
+
+    /// An additional pool created with `InitializePool`, keyed by its LP token mint
+    Keyed(Pubkey),
+}
+
+impl PoolId {
+    pub fn seed(&self) -> &[u8] {
+        match self {
+            Self::Default => &[],
+            Self::Keyed(lp_token_mint) => lp_token_mint.as_ref(),
+        }
+    }
+}
+
+/// Addresses of all the PDAs belonging to a pool
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PoolPdas {
+    pub pool_state: Pubkey,
+    pub lst_state_list: Pubkey,
+    pub disable_pool_authority_list: Pubkey,
+    pub rebalance_record: Pubkey,
+    pub protocol_fee: Pubkey,
+    pub pending_change_list: Pubkey,
+    pub rebalance_loss_state: Pubkey,
+}
+
+impl PoolPdas {
+    /// PDAs of the default pool of the crate's program ID
+    pub const DEFAULT: Self = Self {
+        pool_state: POOL_STATE_ID,
+        lst_state_list: LST_STATE_LIST_ID,
+        disable_pool_authority_list: DISABLE_POOL_AUTHORITY_LIST_ID,
+        rebalance_record: REBALANCE_RECORD_ID,
+        protocol_fee: PROTOCOL_FEE_ID,
+        pending_change_list: PENDING_CHANGE_LIST_ID,
+        rebalance_loss_state: REBALANCE_LOSS_STATE_ID,
+    };
+
+    /// PDAs of the default pool.
+    /// For dynamic program IDs.
+    pub fn find_for_program_id(program_id: Pubkey) -> Self {
+        Pool::find_for_prog(program_id, PoolId::Default).pdas
+    }
+
+    /// PDAs of the pool keyed by `lp_token_mint`.
+    /// For dynamic program IDs.
+    pub fn find_keyed_for_program_id(program_id: Pubkey, lp_token_mint: Pubkey) -> Self {
+        Pool::find_for_prog(program_id, PoolId::Keyed(lp_token_mint)).pdas
+    }
+}
+
+/// Bumps of all the PDAs belonging to a pool
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PoolPdaBumps {
+    pub pool_state: u8,
+    pub lst_state_list: u8,
+    pub disable_pool_authority_list: u8,
+    pub rebalance_record: u8,
+    pub protocol_fee: u8,
+    pub pending_change_list: u8,
+    pub rebalance_loss_state: u8,
+}
+
+impl PoolPdaBumps {
+    /// PDA bumps of the default pool of the crate's program ID
+    pub const DEFAULT: Self = Self {
+        pool_state: POOL_STATE_BUMP,
+        lst_state_list: LST_STATE_LIST_BUMP,
+        disable_pool_authority_list: DISABLE_POOL_AUTHORITY_LIST_BUMP,
+        rebalance_record: REBALANCE_RECORD_BUMP,
+        protocol_fee: PROTOCOL_FEE_BUMP,
+        pending_change_list: PENDING_CHANGE_LIST_BUMP,
+        rebalance_loss_state: REBALANCE_LOSS_STATE_BUMP,
+    };
+}
+
+/// A pool's id, PDAs and their bumps.
+/// Everything needed to verify and sign for a pool's PDAs.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Pool {
+    pub id: PoolId,
+    pub pdas: PoolPdas,
+    pub bumps: PoolPdaBumps,
+}
+
+impl Pool {
+    /// The default pool of the crate's program ID
+    pub const DEFAULT: Self = Self {
+        id: PoolId::Default,
+        pdas: PoolPdas::DEFAULT,
+        bumps: PoolPdaBumps::DEFAULT,
+    };
+
+    /// Uses the precomputed [`Self::DEFAULT`] for the default pool
+    pub fn find(id: PoolId) -> Self {
+        match id {
+            PoolId::Default => Self::DEFAULT,
+            PoolId::Keyed(_) => Self::find_for_prog(crate::program::ID, id),
+        }
+    }
+
+    /// For dynamic program IDs.
+    /// If using crate's program ID, use [`Self::find`] instead
+    pub fn find_for_prog(program_id: Pubkey, id: PoolId) -> Self {
+        let (pool_state, pool_state_bump) = find_pool_state_address_for_pool(program_id, id);
+        let (lst_state_list, lst_state_list_bump) =
+            find_lst_state_list_address_for_pool(program_id, id);
+        let (disable_pool_authority_list, disable_pool_authority_list_bump) =
+            find_disable_pool_authority_list_address_for_pool(program_id, id);
+        let (rebalance_record, rebalance_record_bump) =
+            find_rebalance_record_address_for_pool(program_id, id);
+        let (protocol_fee, protocol_fee_bump) = find_protocol_fee_address_for_pool(program_id, id);
+        let (pending_change_list, pending_change_list_bump) =
+            find_pending_change_list_address_for_pool(program_id, id);
+        let (rebalance_loss_state, rebalance_loss_state_bump) =
+            find_rebalance_loss_state_address_for_pool(program_id, id);
+        Self {
+            id,
+            pdas: PoolPdas {
+                pool_state,
+                lst_state_list,
+                disable_pool_authority_list,
+                rebalance_record,
+                protocol_fee,
+                pending_change_list,
+                rebalance_loss_state,
+            },
+            bumps: PoolPdaBumps {
+                pool_state: pool_state_bump,
+                lst_state_list: lst_state_list_bump,
+                disable_pool_authority_list: disable_pool_authority_list_bump,
+                rebalance_record: rebalance_record_bump,
+                protocol_fee: protocol_fee_bump,
+                pending_change_list: pending_change_list_bump,
+                rebalance_loss_state: rebalance_loss_state_bump,
+            },
+        }
+    }
+
+    /// Determines which pool of the crate's program ID `pool_state` belongs to.
+    ///
+    /// Keyed pools are identified by the LP token mint stored in their pool state,
+    /// so their PDAs need to be found, which costs more compute than the default pool.
+    pub fn try_from_pool_state<S: ReadonlyAccountData + ReadonlyAccountPubkey>(
+        pool_state_acc: S,
+    ) -> Result<Self, SControllerError> {
+        if *pool_state_acc.pubkey() == POOL_STATE_ID {
+            return Ok(Self::DEFAULT);
+        }
+        let lp_token_mint = {
+            let pool_state_data = pool_state_acc.data();
+            try_pool_state(&pool_state_data)?.lp_token_mint
+        };
+        let pool = Self::find(PoolId::Keyed(lp_token_mint));
+        if pool.pdas.pool_state != *pool_state_acc.pubkey() {
+            return Err(SControllerError::IncorrectPoolState);
+        }
+        Ok(pool)
+    }
+
+    pub fn pool_state_seeds(&self) -> [&[u8]; 3] {
+        [
+            POOL_STATE_PDA_SEED,
+            self.id.seed(),
+            std::slice::from_ref(&self.bumps.pool_state),
+        ]
+    }
+
+    pub fn lst_state_list_seeds(&self) -> [&[u8]; 3] {
+        [
+            LST_STATE_LIST_PDA_SEED,
+            self.id.seed(),
+            std::slice::from_ref(&self.bumps.lst_state_list),
+        ]
+    }
+
+    pub fn disable_pool_authority_list_seeds(&self) -> [&[u8]; 3] {
+        [
+            DISABLE_POOL_AUTHORITY_LIST_PDA_SEED,
+            self.id.seed(),
+            std::slice::from_ref(&self.bumps.disable_pool_authority_list),
+        ]
+    }
+
+    pub fn rebalance_record_seeds(&self) -> [&[u8]; 3] {
+        [
+            REBALANCE_RECORD_PDA_SEED,
+            self.id.seed(),
+            std::slice::from_ref(&self.bumps.rebalance_record),
+        ]
+    }
+
+    pub fn protocol_fee_seeds(&self) -> [&[u8]; 3] {
+        [
+            PROTOCOL_FEE_PDA_SEED,
+            self.id.seed(),
+            std::slice::from_ref(&self.bumps.protocol_fee),
+        ]
+    }
+
+    pub fn pending_change_list_seeds(&self) -> [&[u8]; 3] {
+        [
+            PENDING_CHANGE_LIST_PDA_SEED,
+            self.id.seed(),
+            std::slice::from_ref(&self.bumps.pending_change_list),
+        ]
+    }
+
+    pub fn rebalance_loss_state_seeds(&self) -> [&[u8]; 3] {
+        [
+            REBALANCE_LOSS_STATE_PDA_SEED,
+            self.id.seed(),
+            std::slice::from_ref(&self.bumps.rebalance_loss_state),
+        ]
+    }
+}
@@ -0,0 +1,66 @@
+use s_controller_interface::{RebalanceLossState, SControllerError};
+use sanctum_token_ratio::BPS_DENOMINATOR;
+
+/// Returns the max SOL value a rebalance in `epoch` may lose,
+/// given the pool's total SOL value before the rebalance.
+///
+/// This is the lesser of:
+/// - `max_loss_bps_per_rebalance` of `old_total_sol_value`
+/// - `max_loss_bps_per_epoch` of the pool's total SOL value before the first rebalance of `epoch`,
+///   less the SOL value already lost to rebalances this epoch
+///
+/// If no rebalance has ended in `epoch` yet, this rebalance is the epoch's first
+/// and its `old_total_sol_value` is the epoch's starting total SOL value.
+pub fn max_rebalance_loss(
+    rebalance_loss_state: &RebalanceLossState,
+    old_total_sol_value: u64,
+    epoch: u64,
+) -> u64 {
+    let RebalanceLossState {
+        max_loss_bps_per_rebalance,
+        max_loss_bps_per_epoch,
+        epoch: state_epoch,
+        epoch_loss,
+        epoch_start_total_sol_value,
+        ..
+    } = rebalance_loss_state;
+    let (epoch_loss_so_far, epoch_start_total_sol_value) = if *state_epoch == epoch {
+        (*epoch_loss, *epoch_start_total_sol_value)
+    } else {
+        (0, old_total_sol_value)
+    };
+    let per_rebalance = bps_of(old_total_sol_value, *max_loss_bps_per_rebalance);
+    let per_epoch_remaining = bps_of(epoch_start_total_sol_value, *max_loss_bps_per_epoch)
+        .saturating_sub(epoch_loss_so_far);
+    per_rebalance.min(per_epoch_remaining)
+}
+
+/// Adds `loss` to the SOL value lost to rebalances in `epoch`.
+///
+/// If `epoch` is a new epoch, the cumulative loss is reset first and
+/// `old_total_sol_value`, the pool's total SOL value before this rebalance,
+/// is snapshotted as the epoch's starting total SOL value
+pub fn record_rebalance_loss(
+    rebalance_loss_state: &mut RebalanceLossState,
+    old_total_sol_value: u64,
+    loss: u64,
+    epoch: u64,
+) -> Result<(), SControllerError> {
+    if rebalance_loss_state.epoch != epoch {
+        rebalance_loss_state.epoch = epoch;
+        rebalance_loss_state.epoch_loss = 0;
+        rebalance_loss_state.epoch_start_total_sol_value = old_total_sol_value;
+    }
+    rebalance_loss_state.epoch_loss = rebalance_loss_state
+        .epoch_loss
+        .checked_add(loss)
+        .ok_or(SControllerError::MathError)?;
+    Ok(())
+}
+
+/// floor(`amount` * `bps` / 10_000), capped at `amount`
+fn bps_of(amount: u64, bps: u16) -> u64 {
+    let bps = bps.min(BPS_DENOMINATOR);
+    // u64 * u16 / u16 cannot overflow u128 and result <= amount
+    (u128::from(amount) * u128::from(bps) / u128::from(BPS_DENOMINATOR)) as u64
+}
@@ -1,4 +1,5 @@
 use s_controller_interface::{LstState, PoolState, SControllerError};
+use sanctum_token_ratio::BPS_DENOMINATOR;
 use solana_readonly_account::ReadonlyAccountData;
 
 use crate::{try_pool_state, U8Bool};
@@ -23,6 +24,18 @@ pub fn sync_sol_value_with_retval(
     Ok(())
 }
 
+/// Returns true if `lst_state.sol_value` exceeds
+/// `lst_state.max_share_bps` of `pool_state.total_sol_value`.
+/// `max_share_bps == 0` means the LST is uncapped.
+pub fn is_lst_over_cap(pool_state: &PoolState, lst_state: &LstState) -> bool {
+    if lst_state.max_share_bps == 0 {
+        return false;
+    }
+    let share = u128::from(lst_state.sol_value) * u128::from(BPS_DENOMINATOR);
+    let cap = u128::from(pool_state.total_sol_value) * u128::from(lst_state.max_share_bps);
+    share > cap
+}
+
 /// For nice method call syntax both onchain and offchain,
 /// and to reduce scope of borrowing AccountInfo.data
 /// to avoid CPI account data borrow failed errors.
@@ -2,10 +2,12 @@ mod assertions;
 mod disable_pool_authority_list;
 mod lst_state;
 mod program_test;
+mod rebalance_loss_state;
 mod state;
 
 pub use assertions::*;
 pub use disable_pool_authority_list::*;
 pub use lst_state::*;
 pub use program_test::*;
+pub use rebalance_loss_state::*;
 pub use state::*;
@@ -59,6 +59,7 @@ pub fn mock_lst_state(
         pool_reserves_bump,
         protocol_fee_accumulator_bump,
         padding: Default::default(),
+        max_share_bps: 0,
         sol_value_calculator,
     };
     let reserves_account = mock_tokenkeg_account(MockTokenAccountArgs {
@@ -0,0 +1,52 @@
+use async_trait::async_trait;
+use s_controller_interface::RebalanceLossState;
+use s_controller_lib::{
+    program::REBALANCE_LOSS_STATE_ID, try_rebalance_loss_state_mut, REBALANCE_LOSS_STATE_SIZE,
+};
+use sanctum_solana_test_utils::{
+    est_rent_exempt_lamports, ExtendedBanksClient, ExtendedProgramTest, IntoAccount,
+};
+use solana_program_test::{BanksClient, ProgramTest};
+use solana_sdk::account::Account;
+
+pub struct MockRebalanceLossState(pub RebalanceLossState);
+
+impl IntoAccount for MockRebalanceLossState {
+    fn into_account(self) -> Account {
+        let mut data = vec![0u8; REBALANCE_LOSS_STATE_SIZE];
+        let dst = try_rebalance_loss_state_mut(&mut data).unwrap();
+        *dst = self.0;
+        Account {
+            lamports: est_rent_exempt_lamports(REBALANCE_LOSS_STATE_SIZE),
+            data,
+            owner: s_controller_lib::program::ID,
+            executable: false,
+            rent_epoch: u64::MAX,
+        }
+    }
+}
+
+#[async_trait]
+pub trait RebalanceLossStateBanksClient {
+    async fn get_rebalance_loss_state_acc(&mut self) -> Account;
+}
+
+#[async_trait]
+impl RebalanceLossStateBanksClient for BanksClient {
+    async fn get_rebalance_loss_state_acc(&mut self) -> Account {
+        self.get_account_unwrapped(REBALANCE_LOSS_STATE_ID).await
+    }
+}
+
+pub trait RebalanceLossStateProgramTest {
+    fn add_rebalance_loss_state(self, rebalance_loss_state: RebalanceLossState) -> Self;
+}
+
+impl RebalanceLossStateProgramTest for ProgramTest {
+    fn add_rebalance_loss_state(self, rebalance_loss_state: RebalanceLossState) -> Self {
+        self.add_account_chained(
+            REBALANCE_LOSS_STATE_ID,
+            MockRebalanceLossState(rebalance_loss_state).into_account(),
+        )
+    }
+}
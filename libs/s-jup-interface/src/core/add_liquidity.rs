@@ -1,22 +1,19 @@
 use anyhow::anyhow;
 use jupiter_amm_interface::{Quote, QuoteParams, SwapAndAccountMetas, SwapParams};
-use pricing_programs_interface::PriceLpTokensToMintIxArgs;
-use s_controller_interface::SControllerError;
 use s_controller_lib::{
-    add_liquidity_ix_by_mint_full_for_prog, calc_lp_tokens_to_mint, try_pool_state,
-    AddLiquidityByMintFreeArgs, AddLiquidityIxAmts, AddRemoveLiquidityAccountSuffixes,
-    LpTokenRateArgs,
+    add_liquidity_ix_by_mint_full_for_pool, AddLiquidityByMintFreeArgs, AddLiquidityIxAmts,
+    AddRemoveLiquidityAccountSuffixes,
 };
 use s_pricing_prog_aggregate::PricingProg;
+use s_quote::{LiquidityQuoteRequest, QuoteMode};
 use s_sol_val_calc_prog_aggregate::LstSolValCalc;
 use sanctum_token_lib::MintWithTokenProgram;
-use sanctum_token_ratio::AmtsAfterFeeBuilder;
 use solana_readonly_account::ReadonlyAccountData;
 use solana_sdk::instruction::Instruction;
 
 use crate::{LstData, SPool};
 
-use super::{apply_sync_sol_value, calc_quote_fees};
+use super::liquidity_quote_to_jup;
 
 impl<S: ReadonlyAccountData, L: ReadonlyAccountData> SPool<S, L> {
     pub(crate) fn quote_add_liquidity(
@@ -25,54 +22,14 @@ impl<S: ReadonlyAccountData, L: ReadonlyAccountData> SPool<S, L> {
             amount, input_mint, ..
         }: &QuoteParams,
     ) -> anyhow::Result<Quote> {
-        let pool_state_data = self.pool_state_data()?;
-        let pool_state = try_pool_state(&pool_state_data)?;
-        let pricing_prog = self
-            .pricing_prog
-            .as_ref()
-            .ok_or_else(|| anyhow!("pricing program not fetched"))?;
-        let lp_token_supply = self
-            .lp_mint_supply
-            .ok_or_else(|| anyhow!("LP mint not fetched"))?;
-
-        let (input_lst_state, input_lst_data) = self.find_ready_lst(*input_mint)?;
-        let (pool_state, _input_lst_state, _input_reserves_balance) =
-            apply_sync_sol_value(*pool_state, input_lst_state, input_lst_data)?;
-
-        let lst_amount_sol_value = input_lst_data.sol_val_calc.lst_to_sol(*amount)?.get_min();
-
-        let lst_amount_sol_value_after_fees = pricing_prog.quote_lp_tokens_to_mint(
-            *input_mint,
-            &PriceLpTokensToMintIxArgs {
+        let quote = self
+            .quote_pool()?
+            .quote_add_liquidity(&LiquidityQuoteRequest {
+                lst: self.quote_lst(*input_mint)?,
                 amount: *amount,
-                sol_value: lst_amount_sol_value,
-            },
-        )?;
-        if lst_amount_sol_value_after_fees > lst_amount_sol_value {
-            return Err(SControllerError::PoolWouldLoseSolValue.into());
-        }
-        let lp_tokens_to_mint = calc_lp_tokens_to_mint(
-            LpTokenRateArgs {
-                lp_token_supply,
-                pool_total_sol_value: pool_state.total_sol_value,
-            },
-            lst_amount_sol_value_after_fees,
-        )?;
-        let (fee_amount, fee_pct) = calc_quote_fees(
-            AmtsAfterFeeBuilder::new_amt_bef_fee(lst_amount_sol_value)
-                .with_amt_aft_fee(lst_amount_sol_value_after_fees)?,
-            &input_lst_data.sol_val_calc,
-        )?;
-        Ok(Quote {
-            not_enough_liquidity: false,
-            min_in_amount: None,
-            min_out_amount: None,
-            in_amount: *amount,
-            out_amount: lp_tokens_to_mint,
-            fee_mint: *input_mint,
-            fee_amount,
-            fee_pct,
-        })
+                mode: QuoteMode::ExactIn,
+            })?;
+        liquidity_quote_to_jup(&quote)
     }
 
     pub(crate) fn add_liquidity_ix(
@@ -95,8 +52,9 @@ impl<S: ReadonlyAccountData, L: ReadonlyAccountData> SPool<S, L> {
                 ..
             },
         ) = self.find_ready_lst(*source_mint)?;
-        Ok(add_liquidity_ix_by_mint_full_for_prog(
+        Ok(add_liquidity_ix_by_mint_full_for_pool(
             self.program_id,
+            self.swap_liquidity_pdas(),
             AddLiquidityByMintFreeArgs {
                 signer: *token_transfer_authority,
                 src_lst_acc: *source_token_account,
@@ -0,0 +1,84 @@
+use anyhow::anyhow;
+use jupiter_amm_interface::{Quote, QuoteParams, SwapParams};
+use s_controller_lib::{
+    add_liquidity_exact_out_ix_by_mint_full_for_pool, AddLiquidityByMintFreeArgs,
+    AddLiquidityExactOutIxAmts, AddRemoveLiquidityAccountSuffixes,
+};
+use s_pricing_prog_aggregate::PricingProg;
+use s_quote::{LiquidityQuoteRequest, QuoteMode};
+use s_sol_val_calc_prog_aggregate::LstSolValCalc;
+use sanctum_token_lib::MintWithTokenProgram;
+use solana_readonly_account::ReadonlyAccountData;
+use solana_sdk::instruction::Instruction;
+
+use crate::{LstData, SPool};
+
+use super::liquidity_quote_to_jup;
+
+impl<S: ReadonlyAccountData, L: ReadonlyAccountData> SPool<S, L> {
+    pub(crate) fn quote_add_liquidity_exact_out(
+        &self,
+        QuoteParams {
+            amount, input_mint, ..
+        }: &QuoteParams,
+    ) -> anyhow::Result<Quote> {
+        let quote = self
+            .quote_pool()?
+            .quote_add_liquidity(&LiquidityQuoteRequest {
+                lst: self.quote_lst(*input_mint)?,
+                amount: *amount,
+                mode: QuoteMode::ExactOut,
+            })?;
+        liquidity_quote_to_jup(&quote)
+    }
+
+    pub(crate) fn add_liquidity_exact_out_ix(
+        &self,
+        SwapParams {
+            in_amount,
+            out_amount,
+            source_mint,
+            source_token_account,
+            destination_token_account,
+            token_transfer_authority,
+            ..
+        }: &SwapParams,
+    ) -> anyhow::Result<Instruction> {
+        let (
+            _,
+            LstData {
+                token_program: src_token_program,
+                sol_val_calc: src_sol_val_calc,
+                ..
+            },
+        ) = self.find_ready_lst(*source_mint)?;
+        Ok(add_liquidity_exact_out_ix_by_mint_full_for_pool(
+            self.program_id,
+            self.swap_liquidity_pdas(),
+            AddLiquidityByMintFreeArgs {
+                signer: *token_transfer_authority,
+                src_lst_acc: *source_token_account,
+                dst_lp_acc: *destination_token_account,
+                pool_state: self
+                    .pool_state_account
+                    .as_ref()
+                    .ok_or_else(|| anyhow!("Pool state not fetched"))?,
+                lst_state_list: &self.lst_state_list_account,
+                lst_mint: MintWithTokenProgram {
+                    pubkey: *source_mint,
+                    token_program: *src_token_program,
+                },
+            },
+            AddLiquidityExactOutIxAmts {
+                lp_token_amount: *out_amount,
+                max_lst_in: *in_amount,
+            },
+            AddRemoveLiquidityAccountSuffixes {
+                lst_calculator_accounts: &src_sol_val_calc.ix_accounts(),
+                pricing_program_price_lp_accounts: &self
+                    .pricing_prog()?
+                    .price_lp_tokens_to_mint_accounts(*source_mint)?,
+            },
+        )?)
+    }
+}
@@ -1,46 +1,95 @@
 use anyhow::anyhow;
+use jupiter_amm_interface::Quote;
 use rust_decimal::{prelude::FromPrimitive, Decimal};
-use s_controller_interface::{LstState, PoolState};
-use s_controller_lib::sync_sol_value_with_retval;
-use s_sol_val_calc_prog_aggregate::{KnownLstSolValCalc, LstSolValCalc};
-use sanctum_token_ratio::AmtsAfterFee;
+use s_controller_lib::try_pool_state;
+use s_quote::{LiquidityQuote, QuoteFees, QuoteLst, QuotePool, SwapQuote};
+use solana_readonly_account::ReadonlyAccountData;
+use solana_sdk::pubkey::Pubkey;
 
-use crate::LstData;
+use crate::SPool;
 
-/// Returns
-/// (updated pool state, update lst state, reserves balance)
-pub fn apply_sync_sol_value(
-    mut pool_state: PoolState,
-    mut lst_state: LstState,
-    LstData {
-        sol_val_calc,
-        reserves_balance,
-        token_program: _,
-    }: &LstData,
-) -> anyhow::Result<(PoolState, LstState, u64)> {
-    let reserves_balance = *reserves_balance
-        .as_ref()
-        .ok_or_else(|| anyhow!("Reserves balance not fetched"))?;
-    let ret_sol_val = sol_val_calc.lst_to_sol(reserves_balance)?;
-    sync_sol_value_with_retval(&mut pool_state, &mut lst_state, ret_sol_val.get_min())?;
-    Ok((pool_state, lst_state, reserves_balance))
+impl<S: ReadonlyAccountData, L: ReadonlyAccountData> SPool<S, L> {
+    /// Snapshot of the pool's fetched state to quote against with s-quote
+    pub fn quote_pool(&self) -> anyhow::Result<QuotePool<'_>> {
+        let pool_state_data = self.pool_state_data()?;
+        let pool_state = *try_pool_state(&pool_state_data)?;
+        Ok(QuotePool {
+            pool_state,
+            pricing_prog: self.pricing_prog()?,
+            lp_token_supply: self.lp_mint_supply,
+        })
+    }
+
+    /// Snapshot of the fetched state of the LST with mint `lst_mint` to quote against with s-quote
+    pub fn quote_lst(&self, lst_mint: Pubkey) -> anyhow::Result<QuoteLst<'_>> {
+        let (lst_state, lst_data) = self.find_ready_lst(lst_mint)?;
+        let reserves_balance = lst_data
+            .reserves_balance
+            .ok_or_else(|| anyhow!("Reserves balance not fetched"))?;
+        Ok(QuoteLst {
+            lst_state,
+            sol_val_calc: &lst_data.sol_val_calc,
+            reserves_balance,
+        })
+    }
+}
+
+pub fn swap_quote_to_jup(
+    SwapQuote {
+        in_amount,
+        out_amount,
+        fees,
+        not_enough_liquidity,
+        ..
+    }: &SwapQuote,
+) -> anyhow::Result<Quote> {
+    to_jup_quote(*in_amount, *out_amount, fees, *not_enough_liquidity)
+}
+
+pub fn liquidity_quote_to_jup(
+    LiquidityQuote {
+        in_amount,
+        out_amount,
+        fees,
+        not_enough_liquidity,
+        ..
+    }: &LiquidityQuote,
+) -> anyhow::Result<Quote> {
+    to_jup_quote(*in_amount, *out_amount, fees, *not_enough_liquidity)
+}
+
+pub fn to_jup_quote(
+    in_amount: u64,
+    out_amount: u64,
+    fees: &QuoteFees,
+    not_enough_liquidity: bool,
+) -> anyhow::Result<Quote> {
+    Ok(Quote {
+        not_enough_liquidity,
+        min_in_amount: None,
+        min_out_amount: None,
+        in_amount,
+        out_amount,
+        fee_mint: fees.mint,
+        fee_amount: fees.amount,
+        fee_pct: calc_fee_pct(fees)?,
+    })
 }
 
-/// Returns (fee_amount, fee_pct)
 /// fee_pct is [0.0, 1.0], not [0, 100],
 /// so 0.1 (NOT 10.0) means 10%
-pub fn calc_quote_fees(
-    sol_value_amts: AmtsAfterFee,
-    sol_val_calc: &KnownLstSolValCalc,
-) -> anyhow::Result<(u64, Decimal)> {
-    let fee_amount_sol = sol_value_amts.fee_charged();
-    let fee_pct_num = Decimal::from_u64(fee_amount_sol)
-        .ok_or_else(|| anyhow!("Decimal conv error fees_charged"))?;
-    let fee_pct_denom = Decimal::from_u64(sol_value_amts.amt_before_fee()?)
+pub fn calc_fee_pct(
+    QuoteFees {
+        sol_value_before_fees,
+        sol_value,
+        ..
+    }: &QuoteFees,
+) -> anyhow::Result<Decimal> {
+    let fee_pct_num =
+        Decimal::from_u64(*sol_value).ok_or_else(|| anyhow!("Decimal conv error fees_charged"))?;
+    let fee_pct_denom = Decimal::from_u64(*sol_value_before_fees)
         .ok_or_else(|| anyhow!("Decimal conv error amt_before_fee"))?;
-    let fee_pct = fee_pct_num
+    fee_pct_num
         .checked_div(fee_pct_denom)
-        .ok_or_else(|| anyhow!("Decimal fee_pct div err"))?;
-    let fee_amount = sol_val_calc.sol_to_lst(fee_amount_sol)?.get_min();
-    Ok((fee_amount, fee_pct))
+        .ok_or_else(|| anyhow!("Decimal fee_pct div err"))
 }
@@ -0,0 +1,196 @@
+use jupiter_amm_interface::Quote;
+use s_controller_interface::SControllerError;
+use s_quote::{LiquidityQuoteRequest, QuoteLst, QuoteMode, QuotePool, SwapQuoteRequest};
+use sanctum_token_ratio::BPS_DENOMINATOR;
+use solana_readonly_account::ReadonlyAccountData;
+use solana_sdk::pubkey::Pubkey;
+
+use crate::SPool;
+
+use super::{liquidity_quote_to_jup, swap_quote_to_jup};
+
+/// Pool and LST snapshots to quote a pair of mints against repeatedly
+#[derive(Clone, Copy)]
+enum PairQuoter<'a> {
+    Swap {
+        pool: QuotePool<'a>,
+        input: QuoteLst<'a>,
+        output: QuoteLst<'a>,
+    },
+    AddLiquidity {
+        pool: QuotePool<'a>,
+        lst: QuoteLst<'a>,
+    },
+    RemoveLiquidity {
+        pool: QuotePool<'a>,
+        lst: QuoteLst<'a>,
+    },
+}
+
+impl PairQuoter<'_> {
+    fn quote(&self, amount: u64, mode: QuoteMode) -> anyhow::Result<Quote> {
+        match self {
+            Self::Swap {
+                pool,
+                input,
+                output,
+            } => swap_quote_to_jup(&pool.quote_swap(&SwapQuoteRequest {
+                input: *input,
+                output: *output,
+                amount,
+                mode,
+            })?),
+            Self::AddLiquidity { pool, lst } => {
+                liquidity_quote_to_jup(&pool.quote_add_liquidity(&LiquidityQuoteRequest {
+                    lst: *lst,
+                    amount,
+                    mode,
+                })?)
+            }
+            Self::RemoveLiquidity { pool, lst } => {
+                liquidity_quote_to_jup(&pool.quote_remove_liquidity(&LiquidityQuoteRequest {
+                    lst: *lst,
+                    amount,
+                    mode,
+                })?)
+            }
+        }
+    }
+
+    /// Balance of the output LST's reserves, None when adding liquidity
+    fn output_reserves_balance(&self) -> Option<u64> {
+        match self {
+            Self::Swap { output, .. } => Some(output.reserves_balance),
+            Self::RemoveLiquidity { lst, .. } => Some(lst.reserves_balance),
+            Self::AddLiquidity { .. } => None,
+        }
+    }
+
+    /// Returns whether adding liquidity of the LST is limited only by the pool's arithmetic:
+    /// no cap, or a cap of 100% that can never be exceeded
+    fn is_uncapped_add_liquidity(&self) -> bool {
+        match self {
+            Self::AddLiquidity { lst, .. } => {
+                lst.lst_state.max_share_bps == 0 || lst.lst_state.max_share_bps >= BPS_DENOMINATOR
+            }
+            Self::Swap { .. } | Self::RemoveLiquidity { .. } => false,
+        }
+    }
+
+    /// Returns an amount that cannot be swapped exact in:
+    /// - the exact out quote of the entire output reserves balance if there is one
+    /// - else the smallest power of 2 that cannot be swapped, e.g. the first that exceeds the input LST's cap
+    fn exact_in_upper_bound(&self) -> anyhow::Result<u64> {
+        if let Some(in_amount) = self.output_reserves_balance().and_then(|reserves_balance| {
+            self.quote(reserves_balance, QuoteMode::ExactOut)
+                .ok()
+                .map(|quote| quote.in_amount)
+        }) {
+            return Ok(in_amount);
+        }
+        let mut upper_bound: u64 = 1;
+        while self.can_swap_exact_in(upper_bound)? {
+            upper_bound = upper_bound
+                .checked_mul(2)
+                .ok_or(SControllerError::MathError)?;
+        }
+        Ok(upper_bound)
+    }
+
+    /// Returns whether an exact in quote of `amount` can be executed:
+    /// - true if the amount is too small to be quoted
+    /// - false if the output reserves cannot cover it or it exceeds the input LST's cap
+    fn can_swap_exact_in(&self, amount: u64) -> anyhow::Result<bool> {
+        match self.quote(amount, QuoteMode::ExactIn) {
+            Ok(quote) => Ok(!quote.not_enough_liquidity),
+            Err(e) => match e.downcast_ref::<SControllerError>() {
+                Some(SControllerError::ZeroValue) => Ok(true),
+                Some(SControllerError::LstCapExceeded) => Ok(false),
+                _ => Err(e),
+            },
+        }
+    }
+}
+
+impl<S: ReadonlyAccountData, L: ReadonlyAccountData> SPool<S, L> {
+    fn pair_quoter(
+        &self,
+        input_mint: Pubkey,
+        output_mint: Pubkey,
+    ) -> anyhow::Result<PairQuoter<'_>> {
+        let lp_mint = self.lp_token_mint()?;
+        let pool = self.quote_pool()?;
+        Ok(if input_mint == lp_mint {
+            PairQuoter::RemoveLiquidity {
+                pool,
+                lst: self.quote_lst(output_mint)?,
+            }
+        } else if output_mint == lp_mint {
+            PairQuoter::AddLiquidity {
+                pool,
+                lst: self.quote_lst(input_mint)?,
+            }
+        } else {
+            PairQuoter::Swap {
+                pool,
+                input: self.quote_lst(input_mint)?,
+                output: self.quote_lst(output_mint)?,
+            }
+        })
+    }
+
+    /// Quotes exact in swaps of each of `amounts` from `input_mint` to `output_mint`
+    /// against the same snapshot of the pool, in order.
+    ///
+    /// Errors if the pool or either mint is not ready to be quoted.
+    /// Each amount that fails to quote has its own error.
+    pub fn depth_curve(
+        &self,
+        input_mint: Pubkey,
+        output_mint: Pubkey,
+        amounts: &[u64],
+    ) -> anyhow::Result<Vec<anyhow::Result<Quote>>> {
+        let quoter = self.pair_quoter(input_mint, output_mint)?;
+        Ok(amounts
+            .iter()
+            .map(|amount| quoter.quote(*amount, QuoteMode::ExactIn))
+            .collect())
+    }
+
+    /// Max exact in amount of `input_mint` that can be swapped for `output_mint`
+    /// before the output LST's reserves can no longer cover the amount out
+    /// plus the protocol fees deducted from them, or the input LST exceeds its cap.
+    ///
+    /// Returns None if the amount is unbounded, which is the case when adding liquidity
+    /// with an LST that has no cap since no reserves are paid out.
+    ///
+    /// The exact out quote of the entire output reserves balance is an upper bound.
+    /// When adding liquidity, the upper bound is instead found by doubling the amount
+    /// until it exceeds the LST's cap. The upper bound is then narrowed down with exact in quotes.
+    pub fn max_swappable_amount(
+        &self,
+        input_mint: Pubkey,
+        output_mint: Pubkey,
+    ) -> anyhow::Result<Option<u64>> {
+        let quoter = self.pair_quoter(input_mint, output_mint)?;
+        if quoter.is_uncapped_add_liquidity() {
+            return Ok(None);
+        }
+        let upper_bound = quoter.exact_in_upper_bound()?;
+        if quoter.can_swap_exact_in(upper_bound)? {
+            return Ok(Some(upper_bound));
+        }
+        // invariant: can swap lo, cannot swap hi
+        let mut lo = 0;
+        let mut hi = upper_bound;
+        while hi - lo > 1 {
+            let mid = lo + (hi - lo) / 2;
+            if quoter.can_swap_exact_in(mid)? {
+                lo = mid;
+            } else {
+                hi = mid;
+            }
+        }
+        Ok(Some(lo))
+    }
+}
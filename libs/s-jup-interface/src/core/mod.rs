@@ -1,6 +1,5 @@
 //! Core jup quoting and swapping functionality
 
-use anyhow::anyhow;
 use jupiter_amm_interface::{Quote, QuoteParams, SwapAndAccountMetas, SwapMode, SwapParams};
 use s_controller_interface::LstState;
 use s_controller_lib::try_lst_state_list;
@@ -10,14 +9,23 @@ use solana_sdk::{instruction::Instruction, pubkey::Pubkey};
 use crate::SPool;
 
 mod add_liquidity;
+mod add_liquidity_exact_out;
 mod common;
+mod depth;
+mod quote_detailed;
 mod remove_liquidity;
+mod remove_liquidity_exact_out;
 mod swap_exact_in;
+mod swap_exact_in_route;
 mod swap_exact_out;
 
 pub use add_liquidity::*;
+pub use add_liquidity_exact_out::*;
+pub use quote_detailed::*;
 pub use remove_liquidity::*;
+pub use remove_liquidity_exact_out::*;
 pub use swap_exact_in::*;
+pub use swap_exact_in_route::*;
 pub use swap_exact_out::*;
 
 use common::*;
@@ -31,15 +39,15 @@ impl<S: ReadonlyAccountData, L: ReadonlyAccountData> SPool<S, L> {
     ) -> anyhow::Result<Instruction> {
         let lp_mint = self.lp_token_mint()?;
         if swap_params.source_mint == lp_mint {
-            if let SwapMode::ExactOut = swap_mode {
-                return Err(anyhow!("ExactOut not supported for remove liquidity"));
+            match swap_mode {
+                SwapMode::ExactIn => self.remove_liquidity_ix(swap_params),
+                SwapMode::ExactOut => self.remove_liquidity_exact_out_ix(swap_params),
             }
-            self.remove_liquidity_ix(swap_params)
         } else if swap_params.destination_mint == lp_mint {
-            if let SwapMode::ExactOut = swap_mode {
-                return Err(anyhow!("ExactOut not supported for add liquidity"));
+            match swap_mode {
+                SwapMode::ExactIn => self.add_liquidity_ix(swap_params),
+                SwapMode::ExactOut => self.add_liquidity_exact_out_ix(swap_params),
             }
-            self.add_liquidity_ix(swap_params)
         } else {
             match swap_mode {
                 SwapMode::ExactIn => self.swap_exact_in_ix(swap_params),
@@ -51,15 +59,15 @@ impl<S: ReadonlyAccountData, L: ReadonlyAccountData> SPool<S, L> {
     pub fn quote_full(&self, quote_params: &QuoteParams) -> anyhow::Result<Quote> {
         let lp_mint = self.lp_token_mint()?;
         if quote_params.input_mint == lp_mint {
-            if let SwapMode::ExactOut = quote_params.swap_mode {
-                return Err(anyhow!("ExactOut not supported for remove liquidity"));
+            match quote_params.swap_mode {
+                SwapMode::ExactIn => self.quote_remove_liquidity(quote_params),
+                SwapMode::ExactOut => self.quote_remove_liquidity_exact_out(quote_params),
             }
-            self.quote_remove_liquidity(quote_params)
         } else if quote_params.output_mint == lp_mint {
-            if let SwapMode::ExactOut = quote_params.swap_mode {
-                return Err(anyhow!("ExactOut not supported for add liquidity"));
+            match quote_params.swap_mode {
+                SwapMode::ExactIn => self.quote_add_liquidity(quote_params),
+                SwapMode::ExactOut => self.quote_add_liquidity_exact_out(quote_params),
             }
-            self.quote_add_liquidity(quote_params)
         } else {
             match quote_params.swap_mode {
                 SwapMode::ExactIn => self.quote_swap_exact_in(quote_params),
@@ -0,0 +1,147 @@
+use jupiter_amm_interface::{Quote, QuoteParams, SwapMode};
+use s_quote::{
+    LiquidityQuote, LiquidityQuoteRequest, QuoteFees, QuoteLst, QuoteMode, SwapQuote,
+    SwapQuoteRequest,
+};
+use sanctum_token_ratio::U64ValueRange;
+use solana_readonly_account::ReadonlyAccountData;
+
+use crate::SPool;
+
+use super::{liquidity_quote_to_jup, swap_quote_to_jup};
+
+/// Every intermediate value of a quote, following the procedures of the
+/// swap and liquidity instructions in docs/s-controller-program/instructions.md
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DetailedQuote {
+    Swap(DetailedSwapQuote),
+    AddLiquidity(DetailedLiquidityQuote),
+    RemoveLiquidity(DetailedLiquidityQuote),
+}
+
+impl DetailedQuote {
+    /// Fees charged by the pricing program, split into LP and protocol fees
+    pub fn fees(&self) -> &QuoteFees {
+        match self {
+            Self::Swap(q) => &q.quote.fees,
+            Self::AddLiquidity(q) | Self::RemoveLiquidity(q) => &q.quote.fees,
+        }
+    }
+
+    pub fn to_quote(&self) -> anyhow::Result<Quote> {
+        match self {
+            Self::Swap(q) => swap_quote_to_jup(&q.quote),
+            Self::AddLiquidity(q) | Self::RemoveLiquidity(q) => liquidity_quote_to_jup(&q.quote),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DetailedSwapQuote {
+    pub quote: SwapQuote,
+
+    /// SOL value of the withdrawal fee the input LST's stake pool charges on `quote.in_amount`,
+    /// which the input LST's SOL value calculator deducts
+    pub input_withdrawal_fee_sol_value: u64,
+
+    /// SOL value of the withdrawal fee the output LST's stake pool charges on `quote.out_amount`,
+    /// which the output LST's SOL value calculator deducts
+    pub output_withdrawal_fee_sol_value: u64,
+
+    /// Max amount lost to the input LST's SOL value calculator rounding, the spread of `quote.in_range`.
+    /// In lamports for exact in, input LST atomics for exact out
+    pub in_rounding: u64,
+
+    /// Max amount lost to the output LST's SOL value calculator rounding, the spread of `quote.out_range`.
+    /// In output LST atomics for exact in, lamports for exact out
+    pub out_rounding: u64,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DetailedLiquidityQuote {
+    pub quote: LiquidityQuote,
+
+    /// SOL value of the withdrawal fee the LST's stake pool charges on
+    /// the amount of LST added or removed, which the LST's SOL value calculator deducts
+    pub withdrawal_fee_sol_value: u64,
+
+    /// Max amount lost to the LST's SOL value calculator rounding, the spread of `quote.lst_range`.
+    /// In LST atomics for exact in remove liquidity, lamports otherwise
+    pub rounding: u64,
+}
+
+impl DetailedLiquidityQuote {
+    fn new(lst: &QuoteLst, quote: LiquidityQuote, lst_amount: u64) -> anyhow::Result<Self> {
+        Ok(Self {
+            withdrawal_fee_sol_value: lst.withdrawal_fee_sol_value(lst_amount)?,
+            rounding: range_spread(&quote.lst_range),
+            quote,
+        })
+    }
+}
+
+impl<S: ReadonlyAccountData, L: ReadonlyAccountData> SPool<S, L> {
+    /// Same as [`Self::quote_full`], but returns every intermediate value of the quote
+    pub fn quote_detailed(
+        &self,
+        QuoteParams {
+            amount,
+            input_mint,
+            output_mint,
+            swap_mode,
+        }: &QuoteParams,
+    ) -> anyhow::Result<DetailedQuote> {
+        let lp_mint = self.lp_token_mint()?;
+        let quote_pool = self.quote_pool()?;
+        let mode = match swap_mode {
+            SwapMode::ExactIn => QuoteMode::ExactIn,
+            SwapMode::ExactOut => QuoteMode::ExactOut,
+        };
+        if *input_mint == lp_mint {
+            let lst = self.quote_lst(*output_mint)?;
+            let quote = quote_pool.quote_remove_liquidity(&LiquidityQuoteRequest {
+                lst,
+                amount: *amount,
+                mode,
+            })?;
+            Ok(DetailedQuote::RemoveLiquidity(DetailedLiquidityQuote::new(
+                &lst,
+                quote,
+                quote.out_amount,
+            )?))
+        } else if *output_mint == lp_mint {
+            let lst = self.quote_lst(*input_mint)?;
+            let quote = quote_pool.quote_add_liquidity(&LiquidityQuoteRequest {
+                lst,
+                amount: *amount,
+                mode,
+            })?;
+            Ok(DetailedQuote::AddLiquidity(DetailedLiquidityQuote::new(
+                &lst,
+                quote,
+                quote.in_amount,
+            )?))
+        } else {
+            let input = self.quote_lst(*input_mint)?;
+            let output = self.quote_lst(*output_mint)?;
+            let quote = quote_pool.quote_swap(&SwapQuoteRequest {
+                input,
+                output,
+                amount: *amount,
+                mode,
+            })?;
+            Ok(DetailedQuote::Swap(DetailedSwapQuote {
+                input_withdrawal_fee_sol_value: input.withdrawal_fee_sol_value(quote.in_amount)?,
+                output_withdrawal_fee_sol_value: output
+                    .withdrawal_fee_sol_value(quote.out_amount)?,
+                in_rounding: range_spread(&quote.in_range),
+                out_rounding: range_spread(&quote.out_range),
+                quote,
+            }))
+        }
+    }
+}
+
+fn range_spread(range: &U64ValueRange) -> u64 {
+    range.get_max().saturating_sub(range.get_min())
+}
@@ -1,23 +1,19 @@
 use anyhow::anyhow;
 use jupiter_amm_interface::{Quote, QuoteParams, SwapAndAccountMetas, SwapParams};
-use pricing_programs_interface::PriceLpTokensToRedeemIxArgs;
-use s_controller_interface::SControllerError;
 use s_controller_lib::{
-    calc_lp_tokens_sol_value, calc_remove_liquidity_protocol_fees,
-    remove_liquidity_ix_by_mint_full_for_prog, try_pool_state, AddRemoveLiquidityAccountSuffixes,
-    CalcRemoveLiquidityProtocolFeesArgs, LpTokenRateArgs, RemoveLiquidityByMintFreeArgs,
-    RemoveLiquidityIxAmts,
+    remove_liquidity_ix_by_mint_full_for_pool, AddRemoveLiquidityAccountSuffixes,
+    RemoveLiquidityByMintFreeArgs, RemoveLiquidityIxAmts,
 };
 use s_pricing_prog_aggregate::PricingProg;
+use s_quote::{LiquidityQuoteRequest, QuoteMode};
 use s_sol_val_calc_prog_aggregate::LstSolValCalc;
 use sanctum_token_lib::MintWithTokenProgram;
-use sanctum_token_ratio::AmtsAfterFeeBuilder;
 use solana_readonly_account::ReadonlyAccountData;
 use solana_sdk::instruction::Instruction;
 
 use crate::{LstData, SPool};
 
-use super::{apply_sync_sol_value, calc_quote_fees};
+use super::liquidity_quote_to_jup;
 
 impl<S: ReadonlyAccountData, L: ReadonlyAccountData> SPool<S, L> {
     pub(crate) fn quote_remove_liquidity(
@@ -28,69 +24,14 @@ impl<S: ReadonlyAccountData, L: ReadonlyAccountData> SPool<S, L> {
             ..
         }: &QuoteParams,
     ) -> anyhow::Result<Quote> {
-        let pool_state_data = self.pool_state_data()?;
-        let pool_state = try_pool_state(&pool_state_data)?;
-        let pricing_prog = self
-            .pricing_prog
-            .as_ref()
-            .ok_or_else(|| anyhow!("pricing program not fetched"))?;
-        let lp_token_supply = self
-            .lp_mint_supply
-            .ok_or_else(|| anyhow!("LP mint not fetched"))?;
-
-        let (output_lst_state, output_lst_data) = self.find_ready_lst(*output_mint)?;
-        let (pool_state, _output_lst_state, output_reserves_balance) =
-            apply_sync_sol_value(*pool_state, output_lst_state, output_lst_data)?;
-
-        let pool_total_sol_value = pool_state.total_sol_value;
-        let lp_tokens_sol_value = calc_lp_tokens_sol_value(
-            LpTokenRateArgs {
-                lp_token_supply,
-                pool_total_sol_value,
-            },
-            *amount,
-        )?;
-
-        let lp_tokens_sol_value_after_fees = pricing_prog.quote_lp_tokens_to_redeem(
-            *output_mint,
-            &PriceLpTokensToRedeemIxArgs {
+        let quote = self
+            .quote_pool()?
+            .quote_remove_liquidity(&LiquidityQuoteRequest {
+                lst: self.quote_lst(*output_mint)?,
                 amount: *amount,
-                sol_value: lp_tokens_sol_value,
-            },
-        )?;
-        if lp_tokens_sol_value_after_fees > lp_tokens_sol_value {
-            return Err(SControllerError::PoolWouldLoseSolValue.into());
-        }
-        let to_user_lst_amount = output_lst_data
-            .sol_val_calc
-            .sol_to_lst(lp_tokens_sol_value_after_fees)?
-            .get_min();
-        let to_protocol_fees_lst_amount =
-            calc_remove_liquidity_protocol_fees(CalcRemoveLiquidityProtocolFeesArgs {
-                lp_tokens_sol_value,
-                lp_tokens_sol_value_after_fees,
-                to_user_lst_amount,
-                lp_protocol_fee_bps: pool_state.lp_protocol_fee_bps,
+                mode: QuoteMode::ExactIn,
             })?;
-        let total_dst_lst_out = to_user_lst_amount
-            .checked_add(to_protocol_fees_lst_amount)
-            .ok_or(SControllerError::MathError)?;
-        let not_enough_liquidity = total_dst_lst_out > output_reserves_balance;
-        let (fee_amount, fee_pct) = calc_quote_fees(
-            AmtsAfterFeeBuilder::new_amt_bef_fee(lp_tokens_sol_value)
-                .with_amt_aft_fee(lp_tokens_sol_value_after_fees)?,
-            &output_lst_data.sol_val_calc,
-        )?;
-        Ok(Quote {
-            not_enough_liquidity,
-            min_in_amount: None,
-            min_out_amount: None,
-            in_amount: *amount,
-            out_amount: to_user_lst_amount,
-            fee_mint: *output_mint,
-            fee_amount,
-            fee_pct,
-        })
+        liquidity_quote_to_jup(&quote)
     }
 
     pub(crate) fn remove_liquidity_ix(
@@ -113,8 +54,9 @@ impl<S: ReadonlyAccountData, L: ReadonlyAccountData> SPool<S, L> {
                 ..
             },
         ) = self.find_ready_lst(*destination_mint)?;
-        Ok(remove_liquidity_ix_by_mint_full_for_prog(
+        Ok(remove_liquidity_ix_by_mint_full_for_pool(
             self.program_id,
+            self.swap_liquidity_pdas(),
             RemoveLiquidityByMintFreeArgs {
                 signer: *token_transfer_authority,
                 src_lp_acc: *source_token_account,
@@ -0,0 +1,86 @@
+use anyhow::anyhow;
+use jupiter_amm_interface::{Quote, QuoteParams, SwapParams};
+use s_controller_lib::{
+    remove_liquidity_exact_out_ix_by_mint_full_for_pool, AddRemoveLiquidityAccountSuffixes,
+    RemoveLiquidityByMintFreeArgs, RemoveLiquidityExactOutIxAmts,
+};
+use s_pricing_prog_aggregate::PricingProg;
+use s_quote::{LiquidityQuoteRequest, QuoteMode};
+use s_sol_val_calc_prog_aggregate::LstSolValCalc;
+use sanctum_token_lib::MintWithTokenProgram;
+use solana_readonly_account::ReadonlyAccountData;
+use solana_sdk::instruction::Instruction;
+
+use crate::{LstData, SPool};
+
+use super::liquidity_quote_to_jup;
+
+impl<S: ReadonlyAccountData, L: ReadonlyAccountData> SPool<S, L> {
+    pub(crate) fn quote_remove_liquidity_exact_out(
+        &self,
+        QuoteParams {
+            amount,
+            output_mint,
+            ..
+        }: &QuoteParams,
+    ) -> anyhow::Result<Quote> {
+        let quote = self
+            .quote_pool()?
+            .quote_remove_liquidity(&LiquidityQuoteRequest {
+                lst: self.quote_lst(*output_mint)?,
+                amount: *amount,
+                mode: QuoteMode::ExactOut,
+            })?;
+        liquidity_quote_to_jup(&quote)
+    }
+
+    pub(crate) fn remove_liquidity_exact_out_ix(
+        &self,
+        SwapParams {
+            in_amount,
+            out_amount,
+            destination_mint,
+            source_token_account,
+            destination_token_account,
+            token_transfer_authority,
+            ..
+        }: &SwapParams,
+    ) -> anyhow::Result<Instruction> {
+        let (
+            _,
+            LstData {
+                token_program: dst_token_program,
+                sol_val_calc: dst_sol_val_calc,
+                ..
+            },
+        ) = self.find_ready_lst(*destination_mint)?;
+        Ok(remove_liquidity_exact_out_ix_by_mint_full_for_pool(
+            self.program_id,
+            self.swap_liquidity_pdas(),
+            RemoveLiquidityByMintFreeArgs {
+                signer: *token_transfer_authority,
+                src_lp_acc: *source_token_account,
+                dst_lst_acc: *destination_token_account,
+                pool_state: self
+                    .pool_state_account
+                    .as_ref()
+                    .ok_or_else(|| anyhow!("Pool state not fetched"))?,
+                lst_state_list: &self.lst_state_list_account,
+                lst_mint: MintWithTokenProgram {
+                    pubkey: *destination_mint,
+                    token_program: *dst_token_program,
+                },
+            },
+            RemoveLiquidityExactOutIxAmts {
+                lst_amount: *out_amount,
+                max_lp_in: *in_amount,
+            },
+            AddRemoveLiquidityAccountSuffixes {
+                lst_calculator_accounts: &dst_sol_val_calc.ix_accounts(),
+                pricing_program_price_lp_accounts: &self
+                    .pricing_prog()?
+                    .price_lp_tokens_to_redeem_accounts(*destination_mint)?,
+            },
+        )?)
+    }
+}
@@ -0,0 +1,148 @@
+use jupiter_amm_interface::{Quote, QuoteParams, SwapParams};
+use pricing_programs_interface::PriceExactInKeys;
+use s_controller_interface::SControllerError;
+use s_controller_lib::{
+    swap_exact_in_route_ix_by_mints_full_for_pool, try_pool_state, SwapExactInAmounts,
+    SwapRouteByMintsFreeArgs, MAX_SWAP_ROUTE_LSTS,
+};
+use s_pricing_prog_aggregate::PricingProg;
+use s_quote::{SwapRouteQuote, SwapRouteQuoteRequest};
+use s_sol_val_calc_prog_aggregate::LstSolValCalc;
+use sanctum_token_lib::MintWithTokenProgram;
+use solana_readonly_account::ReadonlyAccountData;
+use solana_sdk::{instruction::Instruction, pubkey::Pubkey};
+
+use crate::{LstData, SPool};
+
+use super::to_jup_quote;
+
+impl<S: ReadonlyAccountData, L: ReadonlyAccountData> SPool<S, L> {
+    /// Quotes a SwapExactInRoute from `input_mint` to `output_mint`,
+    /// going through `intermediate_mints` in order.
+    ///
+    /// `fee_amount` and `fee_pct` are the total fees charged across all hops,
+    /// denominated in `output_mint`
+    pub fn quote_swap_exact_in_route(
+        &self,
+        QuoteParams {
+            amount,
+            input_mint,
+            output_mint,
+            ..
+        }: &QuoteParams,
+        intermediate_mints: &[Pubkey],
+    ) -> anyhow::Result<Quote> {
+        let route = route_mints(*input_mint, intermediate_mints, *output_mint)?
+            .into_iter()
+            .map(|mint| self.quote_lst(mint))
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        let SwapRouteQuote {
+            in_amount,
+            out_amount,
+            fees,
+            not_enough_liquidity,
+            ..
+        } = self
+            .quote_pool()?
+            .quote_swap_exact_in_route(&SwapRouteQuoteRequest {
+                route: &route,
+                amount: *amount,
+            })?;
+        to_jup_quote(in_amount, out_amount, &fees, not_enough_liquidity)
+    }
+
+    /// SwapExactInRoute instruction from `source_mint` to `destination_mint`,
+    /// going through `intermediate_mints` in order.
+    ///
+    /// `swap_params.out_amount` is used as the minimum amount out of the entire route
+    pub fn swap_exact_in_route_ix(
+        &self,
+        SwapParams {
+            in_amount,
+            out_amount,
+            source_mint,
+            destination_mint,
+            source_token_account,
+            destination_token_account,
+            token_transfer_authority,
+            ..
+        }: &SwapParams,
+        intermediate_mints: &[Pubkey],
+    ) -> anyhow::Result<Instruction> {
+        let route_mints = route_mints(*source_mint, intermediate_mints, *destination_mint)?;
+        let mut lst_mints = Vec::with_capacity(route_mints.len());
+        let mut sol_val_calc_accounts = Vec::with_capacity(route_mints.len());
+        for mint in route_mints.iter() {
+            let (
+                _,
+                LstData {
+                    token_program,
+                    sol_val_calc,
+                    ..
+                },
+            ) = self.find_ready_lst(*mint)?;
+            lst_mints.push(MintWithTokenProgram {
+                pubkey: *mint,
+                token_program: *token_program,
+            });
+            sol_val_calc_accounts.push(sol_val_calc.ix_accounts());
+        }
+        let pricing_prog = self.pricing_prog()?;
+        let pricing_program_accounts = route_mints
+            .windows(2)
+            .map(|hop_mints| {
+                pricing_prog.price_exact_in_accounts(PriceExactInKeys {
+                    input_lst_mint: hop_mints[0],
+                    output_lst_mint: hop_mints[1],
+                })
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        let pricing_program = {
+            let pool_state_data = self.pool_state_data()?;
+            try_pool_state(&pool_state_data)?.pricing_program
+        };
+
+        let sol_val_calc_account_suffixes: Vec<_> =
+            sol_val_calc_accounts.iter().map(Vec::as_slice).collect();
+        let pricing_program_account_suffixes: Vec<_> =
+            pricing_program_accounts.iter().map(Vec::as_slice).collect();
+        Ok(swap_exact_in_route_ix_by_mints_full_for_pool(
+            self.program_id,
+            self.swap_liquidity_pdas(),
+            SwapRouteByMintsFreeArgs {
+                signer: *token_transfer_authority,
+                src_lst_acc: *source_token_account,
+                dst_lst_acc: *destination_token_account,
+                lst_mints: &lst_mints,
+                lst_state_list: &self.lst_state_list_account,
+            },
+            SwapExactInAmounts {
+                min_amount_out: *out_amount,
+                amount: *in_amount,
+            },
+            &sol_val_calc_account_suffixes,
+            &pricing_program_account_suffixes,
+            pricing_program,
+        )?)
+    }
+}
+
+fn route_mints(
+    input_mint: Pubkey,
+    intermediate_mints: &[Pubkey],
+    output_mint: Pubkey,
+) -> anyhow::Result<Vec<Pubkey>> {
+    let route_mints: Vec<Pubkey> = std::iter::once(input_mint)
+        .chain(intermediate_mints.iter().copied())
+        .chain(std::iter::once(output_mint))
+        .collect();
+    if route_mints.len() > MAX_SWAP_ROUTE_LSTS {
+        return Err(SControllerError::InvalidSwapRoute.into());
+    }
+    for (i, mint) in route_mints.iter().enumerate() {
+        if route_mints[..i].contains(mint) {
+            return Err(SControllerError::SwapSameLst.into());
+        }
+    }
+    Ok(route_mints)
+}
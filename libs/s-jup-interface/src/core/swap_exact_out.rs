@@ -1,22 +1,19 @@
-use anyhow::anyhow;
 use jupiter_amm_interface::{Quote, QuoteParams, SwapAndAccountMetas, SwapParams};
-use pricing_programs_interface::{PriceExactOutIxArgs, PriceExactOutKeys};
-use s_controller_interface::SControllerError;
+use pricing_programs_interface::PriceExactOutKeys;
 use s_controller_lib::{
-    calc_swap_protocol_fees, swap_exact_out_ix_by_mint_full_for_prog, try_pool_state,
-    CalcSwapProtocolFeesArgs, SrcDstLstSolValueCalcAccountSuffixes, SwapByMintsFreeArgs,
-    SwapExactOutAmounts,
+    swap_exact_out_ix_by_mint_full_for_pool, try_pool_state, SrcDstLstSolValueCalcAccountSuffixes,
+    SwapByMintsFreeArgs, SwapExactOutAmounts,
 };
 use s_pricing_prog_aggregate::PricingProg;
+use s_quote::{QuoteMode, SwapQuoteRequest};
 use s_sol_val_calc_prog_aggregate::LstSolValCalc;
 use sanctum_token_lib::MintWithTokenProgram;
-use sanctum_token_ratio::AmtsAfterFeeBuilder;
 use solana_readonly_account::ReadonlyAccountData;
 use solana_sdk::instruction::Instruction;
 
 use crate::{LstData, SPool};
 
-use super::{apply_sync_sol_value, calc_quote_fees};
+use super::swap_quote_to_jup;
 
 impl<S: ReadonlyAccountData, L: ReadonlyAccountData> SPool<S, L> {
     pub(crate) fn quote_swap_exact_out(
@@ -28,68 +25,13 @@ impl<S: ReadonlyAccountData, L: ReadonlyAccountData> SPool<S, L> {
             ..
         }: &QuoteParams,
     ) -> anyhow::Result<Quote> {
-        let pool_state_data = self.pool_state_data()?;
-        let pool_state = try_pool_state(&pool_state_data)?;
-        let pricing_prog = self
-            .pricing_prog
-            .as_ref()
-            .ok_or_else(|| anyhow!("pricing program not fetched"))?;
-
-        let (input_lst_state, input_lst_data) = self.find_ready_lst(*input_mint)?;
-        let (pool_state, _input_lst_state, _input_reserves_balance) =
-            apply_sync_sol_value(*pool_state, input_lst_state, input_lst_data)?;
-        let (output_lst_state, output_lst_data) = self.find_ready_lst(*output_mint)?;
-        let (pool_state, _output_lst_state, output_reserves_balance) =
-            apply_sync_sol_value(pool_state, output_lst_state, output_lst_data)?;
-
-        let out_sol_value = output_lst_data.sol_val_calc.lst_to_sol(*amount)?.get_max();
-        if out_sol_value == 0 {
-            return Err(SControllerError::ZeroValue.into());
-        }
-        let in_sol_value = pricing_prog.quote_exact_out(
-            PriceExactOutKeys {
-                input_lst_mint: *input_mint,
-                output_lst_mint: *output_mint,
-            },
-            &PriceExactOutIxArgs {
-                amount: *amount,
-                sol_value: out_sol_value,
-            },
-        )?;
-        if out_sol_value > in_sol_value {
-            return Err(SControllerError::PoolWouldLoseSolValue.into());
-        }
-        let src_lst_in = input_lst_data
-            .sol_val_calc
-            .sol_to_lst(in_sol_value)?
-            .get_max();
-        if src_lst_in == 0 {
-            return Err(SControllerError::ZeroValue.into());
-        }
-        let to_protocol_fees_lst_amount = calc_swap_protocol_fees(CalcSwapProtocolFeesArgs {
-            in_sol_value,
-            out_sol_value,
-            dst_lst_out: *amount,
-            trading_protocol_fee_bps: pool_state.trading_protocol_fee_bps,
+        let quote = self.quote_pool()?.quote_swap(&SwapQuoteRequest {
+            input: self.quote_lst(*input_mint)?,
+            output: self.quote_lst(*output_mint)?,
+            amount: *amount,
+            mode: QuoteMode::ExactOut,
         })?;
-        let total_dst_lst_out = amount
-            .checked_add(to_protocol_fees_lst_amount)
-            .ok_or(SControllerError::MathError)?;
-        let not_enough_liquidity = total_dst_lst_out > output_reserves_balance;
-        let (fee_amount, fee_pct) = calc_quote_fees(
-            AmtsAfterFeeBuilder::new_amt_bef_fee(in_sol_value).with_amt_aft_fee(out_sol_value)?,
-            &output_lst_data.sol_val_calc,
-        )?;
-        Ok(Quote {
-            not_enough_liquidity,
-            min_in_amount: None,
-            min_out_amount: None,
-            in_amount: src_lst_in,
-            out_amount: *amount,
-            fee_mint: *output_mint,
-            fee_amount,
-            fee_pct,
-        })
+        swap_quote_to_jup(&quote)
     }
 
     pub(crate) fn swap_exact_out_ix(
@@ -125,8 +67,9 @@ impl<S: ReadonlyAccountData, L: ReadonlyAccountData> SPool<S, L> {
             let pool_state_data = self.pool_state_data()?;
             try_pool_state(&pool_state_data)?.pricing_program
         };
-        Ok(swap_exact_out_ix_by_mint_full_for_prog(
+        Ok(swap_exact_out_ix_by_mint_full_for_pool(
             self.program_id,
+            self.swap_liquidity_pdas(),
             SwapByMintsFreeArgs {
                 signer: *token_transfer_authority,
                 src_lst_acc: *source_token_account,
@@ -1,12 +1,13 @@
-use s_controller_lib::{
-    find_lst_state_list_address, find_pool_state_address, try_lst_state_list, try_pool_state,
-};
+use std::collections::HashMap;
+
+use s_controller_lib::{try_lst_state_list, try_pool_state, Pool, PoolId, PoolPdas};
+use s_sol_val_calc_prog_aggregate::{AccountDataMap, SolValCalcRegistry};
 use sanctum_lst_list::SanctumLst;
 use solana_program::pubkey::Pubkey;
-use solana_readonly_account::ReadonlyAccountData;
+use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountOwner};
 
 use crate::{
-    utils::{try_lst_data, try_pricing_prog},
+    utils::{lst_token_program, try_lst_data, try_pricing_prog, try_registered_lst_data},
     SPool,
 };
 
@@ -36,6 +37,21 @@ impl Default for SPoolInitKeys {
     }
 }
 
+impl From<PoolPdas> for SPoolInitKeys {
+    fn from(
+        PoolPdas {
+            lst_state_list,
+            pool_state,
+            ..
+        }: PoolPdas,
+    ) -> Self {
+        Self {
+            lst_state_list,
+            pool_state,
+        }
+    }
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct SPoolInitAccounts<S, L> {
     pub lst_state_list: L,
@@ -46,10 +62,12 @@ impl<S, L> SPool<S, L> {
     /// Gets the list of accounts that must be fetched first to initialize
     /// SPool by passing the result into [`Self::from_fetched_accounts`]
     pub fn init_keys(program_id: Pubkey) -> SPoolInitKeys {
-        SPoolInitKeys {
-            lst_state_list: find_lst_state_list_address(program_id).0,
-            pool_state: find_pool_state_address(program_id).0,
-        }
+        Self::init_keys_for_pool(program_id, PoolId::Default)
+    }
+
+    /// [`Self::init_keys`] for the pool identified by `pool_id`
+    pub fn init_keys_for_pool(program_id: Pubkey, pool_id: PoolId) -> SPoolInitKeys {
+        Pool::find_for_prog(program_id, pool_id).pdas.into()
     }
 }
 
@@ -62,29 +80,90 @@ impl<S, L: ReadonlyAccountData> SPool<S, L> {
         lst_state_list_account: L,
         lst_list: &[SanctumLst],
     ) -> anyhow::Result<Self> {
-        let SPoolInitKeys {
+        Self::from_lst_state_list_account_for_pool(
+            program_id,
+            PoolId::Default,
+            lst_state_list_account,
+            lst_list,
+        )
+    }
+
+    /// [`Self::from_lst_state_list_account`] for the pool identified by `pool_id`
+    pub fn from_lst_state_list_account_for_pool(
+        program_id: Pubkey,
+        pool_id: PoolId,
+        lst_state_list_account: L,
+        lst_list: &[SanctumLst],
+    ) -> anyhow::Result<Self> {
+        let PoolPdas {
             lst_state_list: lst_state_list_addr,
             pool_state: pool_state_addr,
-        } = Self::init_keys(program_id);
+            protocol_fee: protocol_fee_addr,
+            ..
+        } = Pool::find_for_prog(program_id, pool_id).pdas;
+        // sanctum-lst-list only lists the LP token of the default S pool
+        let s_pool_state = Pool::find_for_prog(program_id, PoolId::Default)
+            .pdas
+            .pool_state;
         let lst_data_list = {
             let lst_state_list_account_data = lst_state_list_account.data();
             let lst_state_list = try_lst_state_list(&lst_state_list_account_data)?;
             lst_state_list
                 .iter()
-                .map(|lst_state| try_lst_data(lst_list, lst_state))
+                .map(|lst_state| try_lst_data(lst_list, lst_state, s_pool_state))
                 .collect()
         };
         Ok(Self {
             program_id,
+            pool_id,
             lst_state_list_addr,
             pool_state_addr,
+            protocol_fee_addr,
             pool_state_account: None,
             pricing_prog: None,
             lp_mint_supply: None,
             lst_state_list_account,
             lst_data_list,
+            sol_val_calc_registry: SolValCalcRegistry::default(),
         })
     }
+
+    /// Sets the registry used to create sol value calculators at runtime and reinitializes
+    /// the `lst_data_list` entries of LSTs whose sol value calculator program is registered.
+    /// Registered calculators take precedence over those known to `s_sol_val_calc_prog_aggregate`.
+    ///
+    /// `mint_accounts` must contain the fetched mint accounts of LSTs that were not previously
+    /// initialized and whose sol value calculator program is registered,
+    /// since their token programs are the owners of their mint accounts.
+    pub fn with_sol_val_calc_registry<D: ReadonlyAccountOwner>(
+        mut self,
+        registry: SolValCalcRegistry,
+        mint_accounts: &HashMap<Pubkey, D>,
+    ) -> anyhow::Result<Self> {
+        {
+            let lst_state_list_account_data = self.lst_state_list_account.data();
+            let lst_state_list = try_lst_state_list(&lst_state_list_account_data)?;
+            for (lst_state, lst_data) in lst_state_list.iter().zip(self.lst_data_list.iter_mut()) {
+                if !registry.contains(&lst_state.sol_value_calculator) {
+                    continue;
+                }
+                let token_program = match lst_data {
+                    Some(ld) => ld.token_program,
+                    None => lst_token_program(mint_accounts, &lst_state.mint)?,
+                };
+                if let Some(res) = try_registered_lst_data(
+                    &registry,
+                    lst_state,
+                    token_program,
+                    &AccountDataMap::new(),
+                ) {
+                    *lst_data = Some(res?);
+                }
+            }
+        }
+        self.sol_val_calc_registry = registry;
+        Ok(self)
+    }
 }
 
 impl<S: ReadonlyAccountData, L: ReadonlyAccountData> SPool<S, L> {
@@ -92,6 +171,16 @@ impl<S: ReadonlyAccountData, L: ReadonlyAccountData> SPool<S, L> {
     ///  - this update updates the various sol value calculator programs and pricing program
     pub fn from_init_accounts(
         program_id: Pubkey,
+        init_accounts: SPoolInitAccounts<S, L>,
+        lst_list: &[SanctumLst],
+    ) -> anyhow::Result<Self> {
+        Self::from_init_accounts_for_pool(program_id, PoolId::Default, init_accounts, lst_list)
+    }
+
+    /// [`Self::from_init_accounts`] for the pool identified by `pool_id`
+    pub fn from_init_accounts_for_pool(
+        program_id: Pubkey,
+        pool_id: PoolId,
         SPoolInitAccounts {
             lst_state_list: lst_state_list_acc,
             pool_state: pool_state_acc,
@@ -105,7 +194,12 @@ impl<S: ReadonlyAccountData, L: ReadonlyAccountData> SPool<S, L> {
             let pool_state = try_pool_state(&pool_state_acc_data)?;
             try_pricing_prog(pool_state, lst_state_list)?
         };
-        let mut res = Self::from_lst_state_list_account(program_id, lst_state_list_acc, lst_list)?;
+        let mut res = Self::from_lst_state_list_account_for_pool(
+            program_id,
+            pool_id,
+            lst_state_list_acc,
+            lst_list,
+        )?;
         res.pool_state_account = Some(pool_state_acc);
         res.pricing_prog = Some(pricing_prog);
         Ok(res)
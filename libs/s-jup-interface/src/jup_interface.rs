@@ -2,7 +2,7 @@ use anyhow::anyhow;
 use jupiter_amm_interface::{
     AccountMap, Amm, KeyedAccount, Quote, QuoteParams, SwapAndAccountMetas, SwapParams,
 };
-use s_controller_lib::find_lst_state_list_address;
+use s_controller_lib::{find_lst_state_list_address_for_pool, PoolId};
 use sanctum_lst_list::SanctumLstList;
 use solana_sdk::pubkey::Pubkey;
 use std::str::FromStr;
@@ -14,7 +14,10 @@ pub const LABEL: &str = "Sanctum Infinity";
 impl Amm for SPoolJup {
     /// Initialized by lst_state_list account, NOT pool_state.
     ///
-    /// params can optionally be a b58-encoded pubkey string that is the S controller program's program_id.
+    /// params can optionally be either:
+    /// - a b58-encoded pubkey string that is the S controller program's program_id
+    /// - an object `{ "program_id": <b58 pubkey>, "lp_token_mint": <b58 pubkey> }`, both fields optional,
+    ///   where `lp_token_mint` selects a pool created with `InitializePool` instead of the default pool
     ///
     /// Must be updated 2 more times before it can be used, see docs for [`Self::from_lst_state_list_account`]
     fn from_keyed_account(
@@ -27,26 +30,47 @@ impl Amm for SPoolJup {
     where
         Self: Sized,
     {
-        let (program_id, lst_state_list_addr) = match params {
+        let (program_id, pool_id) = match params {
             // default to INF if program_id params not provided
-            None => (
-                s_controller_lib::program::ID,
-                s_controller_lib::program::LST_STATE_LIST_ID,
-            ),
+            None => (s_controller_lib::program::ID, PoolId::Default),
+            Some(serde_json::Value::Object(map)) => {
+                let program_id = match map.get("program_id") {
+                    Some(v) => Pubkey::from_str(&serde_json::from_value::<String>(v.clone())?)?,
+                    None => s_controller_lib::program::ID,
+                };
+                let pool_id = match map.get("lp_token_mint") {
+                    Some(v) => PoolId::Keyed(Pubkey::from_str(&serde_json::from_value::<String>(
+                        v.clone(),
+                    )?)?),
+                    None => PoolId::Default,
+                };
+                (program_id, pool_id)
+            }
             Some(value) => {
                 // TODO: maybe unnecessary clone() here?
                 let program_id =
                     Pubkey::from_str(&serde_json::from_value::<String>(value.clone())?)?;
-                (program_id, find_lst_state_list_address(program_id).0)
+                (program_id, PoolId::Default)
             }
         };
+        let lst_state_list_addr =
+            if program_id == s_controller_lib::program::ID && pool_id == PoolId::Default {
+                s_controller_lib::program::LST_STATE_LIST_ID
+            } else {
+                find_lst_state_list_address_for_pool(program_id, pool_id).0
+            };
         if *key != lst_state_list_addr {
             return Err(anyhow!(
                 "Incorrect LST state list addr. Expected {lst_state_list_addr}. Got {key}"
             ));
         }
         let SanctumLstList { sanctum_lst_list } = SanctumLstList::load();
-        Self::from_lst_state_list_account(program_id, account.clone(), &sanctum_lst_list)
+        Self::from_lst_state_list_account_for_pool(
+            program_id,
+            pool_id,
+            account.clone(),
+            &sanctum_lst_list,
+        )
     }
 
     fn label(&self) -> String {
@@ -57,9 +81,13 @@ impl Amm for SPoolJup {
         self.program_id
     }
 
-    /// S Pools are 1 per program, so just use program ID as key
+    /// The default S Pool is 1 per program, so just use program ID as key.
+    /// Other pools use their pool state address.
     fn key(&self) -> Pubkey {
-        self.program_id()
+        match self.pool_id {
+            PoolId::Default => self.program_id(),
+            PoolId::Keyed(_) => self.pool_state_addr,
+        }
     }
 
     fn get_reserve_mints(&self) -> Vec<Pubkey> {
@@ -93,7 +121,6 @@ impl Amm for SPoolJup {
         true
     }
 
-    /// TODO: this is not true for AddLiquidity and RemoveLiquidity
     fn supports_exact_out(&self) -> bool {
         true
     }
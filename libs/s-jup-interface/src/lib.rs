@@ -1,14 +1,14 @@
 use anyhow::anyhow;
 use s_controller_interface::LstState;
-use s_controller_lib::{try_lst_state_list, try_pool_state};
+use s_controller_lib::{try_lst_state_list, try_pool_state, PoolId, SwapLiquidityPdas};
 use s_pricing_prog_aggregate::KnownPricingProg;
-use s_sol_val_calc_prog_aggregate::KnownLstSolValCalc;
+use s_sol_val_calc_prog_aggregate::{KnownLstSolValCalc, SolValCalcRegistry};
 use sanctum_associated_token_lib::{CreateAtaAddressArgs, FindAtaAddressArgs};
 use solana_program::pubkey::{Pubkey, PubkeyError};
 use solana_readonly_account::ReadonlyAccountData;
 use solana_sdk::account::Account;
 
-pub(crate) mod utils;
+pub mod utils;
 
 mod core;
 mod init;
@@ -33,8 +33,10 @@ pub type SPoolJup = SPool<Account, Account>;
 #[derive(Debug, Clone)]
 pub struct SPool<S, L> {
     pub program_id: Pubkey,
+    pub pool_id: PoolId,
     pub lst_state_list_addr: Pubkey,
     pub pool_state_addr: Pubkey,
+    pub protocol_fee_addr: Pubkey,
     pub lp_mint_supply: Option<u64>,
     // pool_state optional since lst_state_list is the KeyedAccount we initialize with
     pub pool_state_account: Option<S>,
@@ -44,19 +46,25 @@ pub struct SPool<S, L> {
     // None means we don't know how to handle the given lst
     // this could be due to incomplete data or unknown LST sol value calculator program
     pub lst_data_list: Vec<Option<LstData>>,
+    // creates sol value calculators for LSTs whose calculator programs
+    // are not known to s_sol_val_calc_prog_aggregate
+    pub sol_val_calc_registry: SolValCalcRegistry,
 }
 
 impl<S, L: Default> Default for SPool<S, L> {
     fn default() -> Self {
         Self {
             program_id: s_controller_lib::program::ID,
+            pool_id: PoolId::Default,
             lst_state_list_addr: s_controller_lib::program::LST_STATE_LIST_ID,
             pool_state_addr: s_controller_lib::program::POOL_STATE_ID,
+            protocol_fee_addr: s_controller_lib::program::PROTOCOL_FEE_ID,
             lp_mint_supply: None,
             pool_state_account: None,
             pricing_prog: None,
             lst_state_list_account: L::default(),
             lst_data_list: Vec::new(),
+            sol_val_calc_registry: SolValCalcRegistry::default(),
         }
     }
 }
@@ -70,6 +78,14 @@ impl<S, L> SPool<S, L> {
             .ok_or_else(|| anyhow!("pricing program not fetched"))
     }
 
+    pub fn swap_liquidity_pdas(&self) -> SwapLiquidityPdas {
+        SwapLiquidityPdas {
+            pool_state: self.pool_state_addr,
+            lst_state_list: self.lst_state_list_addr,
+            protocol_fee: self.protocol_fee_addr,
+        }
+    }
+
     pub fn pool_reserves_account(
         &self,
         LstState {
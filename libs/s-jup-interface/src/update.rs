@@ -3,12 +3,15 @@ use std::collections::HashMap;
 use s_controller_interface::LstState;
 use s_controller_lib::{try_lst_state_list, try_pool_state};
 use s_pricing_prog_aggregate::MutablePricingProg;
-use s_sol_val_calc_prog_aggregate::{LstSolValCalc, MutableLstSolValCalc};
+use s_sol_val_calc_prog_aggregate::{with_account_data_map, LstSolValCalc, MutableLstSolValCalc};
 use sanctum_token_lib::{mint_supply, token_account_balance};
-use solana_readonly_account::ReadonlyAccountData;
+use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountOwner};
 use solana_sdk::pubkey::Pubkey;
 
-use crate::{utils::try_pricing_prog, LstData, SPool};
+use crate::{
+    utils::{lst_token_program, try_pricing_prog, try_registered_lst_data},
+    LstData, SPool,
+};
 
 impl<S: ReadonlyAccountData, L: ReadonlyAccountData> SPool<S, L> {
     pub fn get_accounts_to_update_full(&self) -> Vec<Pubkey> {
@@ -25,7 +28,7 @@ impl<S: ReadonlyAccountData, L: ReadonlyAccountData> SPool<S, L> {
     }
 }
 
-impl<D: ReadonlyAccountData + Clone> SPool<D, D> {
+impl<D: ReadonlyAccountData + ReadonlyAccountOwner + Clone> SPool<D, D> {
     pub fn update_full(&mut self, account_map: &HashMap<Pubkey, D>) -> anyhow::Result<()> {
         // returns the first encountered error, but tries to update everything eagerly
         // even after encountering an error
@@ -90,6 +93,13 @@ impl<S, L: ReadonlyAccountData> SPool<S, L> {
     ) -> Vec<Pubkey> {
         let lst_data = match lst_data.as_ref() {
             Some(l) => l,
+            // mint account is needed for the token program of LSTs to be initialized by the registry
+            None if self
+                .sol_val_calc_registry
+                .contains(&lst_state.sol_value_calculator) =>
+            {
+                return vec![lst_state.mint]
+            }
             None => return vec![],
         };
         let mut res = lst_data.sol_val_calc.get_accounts_to_update();
@@ -170,7 +180,7 @@ impl<S, L: ReadonlyAccountData> SPool<S, L> {
             .fold(Ok(()), |res, curr_res| res.and(curr_res))
     }
 }
-impl<S, L: ReadonlyAccountData + Clone> SPool<S, L> {
+impl<S, L: ReadonlyAccountData + ReadonlyAccountOwner + Clone> SPool<S, L> {
     pub fn update_lst_state_list(
         &mut self,
         account_map: &HashMap<Pubkey, L>,
@@ -185,6 +195,8 @@ impl<S, L: ReadonlyAccountData + Clone> SPool<S, L> {
         //   SanctumLstList to reinitialize the KnownLstSolValCalc
         // - if list was extended, the new entries will just be None and we cant handle it. Otherwise we would need a
         //   SanctumLstList to initialize the KnownLstSolValCalc
+        // - entries invalidated to None by the above are then reinitialized by sol_val_calc_registry
+        //   if their sol val calc program is registered, taking their token programs from their fetched mint accounts
         let lst_state_list_acc_data = self.lst_state_list_account.data();
         let lst_state_list = try_lst_state_list(&lst_state_list_acc_data)?;
         let new_lst_state_list_account_data = new_lst_state_list_account.data();
@@ -200,7 +212,7 @@ impl<S, L: ReadonlyAccountData + Clone> SPool<S, L> {
             drop(lst_state_list_acc_data);
             drop(new_lst_state_list_account_data);
             self.lst_state_list_account = new_lst_state_list_account;
-            return Ok(());
+            return self.init_registered_lst_data(account_map);
         }
         // Either at least 1 sol value calculator changed or mint changed:
         // rebuild entire lst_data vec by cloning from old vec
@@ -249,7 +261,53 @@ impl<S, L: ReadonlyAccountData + Clone> SPool<S, L> {
         drop(lst_state_list_acc_data);
         drop(new_lst_state_list_account_data);
         self.lst_state_list_account = new_lst_state_list_account;
-        Ok(())
+        self.init_registered_lst_data(account_map)
+    }
+
+    /// Initializes the `None` entries of `lst_data_list` whose sol value calculator program
+    /// is registered in `sol_val_calc_registry`.
+    ///
+    /// Errors for such entries whose mint account is not in `account_map`,
+    /// leaving them to be retried on the next update
+    fn init_registered_lst_data(&mut self, account_map: &HashMap<Pubkey, L>) -> anyhow::Result<()> {
+        let lst_state_list_acc_data = self.lst_state_list_account.data();
+        let lst_state_list = try_lst_state_list(&lst_state_list_acc_data)?;
+        let mut res = Ok(());
+        with_account_data_map(
+            account_map,
+            account_map.keys().copied(),
+            |account_data_map| {
+                for (lst_state, lst_data) in lst_state_list
+                    .iter()
+                    .zip(self.lst_data_list.iter_mut())
+                    .filter(|(lst_state, lst_data)| {
+                        lst_data.is_none()
+                            && self
+                                .sol_val_calc_registry
+                                .contains(&lst_state.sol_value_calculator)
+                    })
+                {
+                    let token_program = match lst_token_program(account_map, &lst_state.mint) {
+                        Ok(t) => t,
+                        Err(e) => {
+                            res = res.and(Err(e));
+                            continue;
+                        }
+                    };
+                    match try_registered_lst_data(
+                        &self.sol_val_calc_registry,
+                        lst_state,
+                        token_program,
+                        account_data_map,
+                    ) {
+                        Some(Ok(ld)) => *lst_data = Some(ld),
+                        Some(Err(e)) => res = res.and(Err(e)),
+                        None => (),
+                    }
+                }
+            },
+        );
+        res
     }
 }
 
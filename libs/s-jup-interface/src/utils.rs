@@ -1,10 +1,16 @@
+use std::collections::HashMap;
+
+use anyhow::anyhow;
 use s_controller_interface::{LstState, PoolState};
 use s_pricing_prog_aggregate::{KnownPricingProg, MutablePricingProg};
 use s_sol_val_calc_prog_aggregate::{
-    KnownLstSolValCalc, LidoLstSolValCalc, LstSolValCalc, MarinadeLstSolValCalc,
-    SanctumSplLstSolValCalc, SplLstSolValCalc, SplLstSolValCalcInitKeys, WsolLstSolValCalc,
+    AccountDataMap, KnownLstSolValCalc, LidoLstSolValCalc, LstSolValCalc, MarinadeLstSolValCalc,
+    SPoolLstSolValCalc, SanctumSplLstSolValCalc, SolValCalcRegistry, SplLstSolValCalc,
+    SplLstSolValCalcInitKeys, WsolLstSolValCalc,
 };
 use sanctum_lst_list::{PoolInfo, SanctumLst, SplPoolAccounts};
+use solana_program::pubkey::Pubkey;
+use solana_readonly_account::ReadonlyAccountOwner;
 
 use crate::LstData;
 
@@ -18,6 +24,11 @@ pub fn try_pricing_prog(
     )?)
 }
 
+/// Returns None if the LST is not in `lst_list` or its sol value calculator program
+/// does not match the one `lst_list` says it should use.
+///
+/// `s_pool_state` is the pool state of the S pool whose LP token the LST is,
+/// used if `lst_list` says the LST is an S pool LP token
 pub fn try_lst_data(
     lst_list: &[SanctumLst],
     LstState {
@@ -25,6 +36,7 @@ pub fn try_lst_data(
         sol_value_calculator,
         ..
     }: &LstState,
+    s_pool_state: Pubkey,
 ) -> Option<LstData> {
     let SanctumLst {
         pool,
@@ -47,7 +59,9 @@ pub fn try_lst_data(
                 stake_pool_addr: *pool,
             }))
         }
-        PoolInfo::SPool(_) => None?,
+        PoolInfo::SPool(_) => {
+            KnownLstSolValCalc::SPool(SPoolLstSolValCalc::from_keys(s_pool_state, *mint))
+        }
     };
     if *sol_value_calculator != calc.sol_value_calculator_program_id() {
         None
@@ -59,3 +73,32 @@ pub fn try_lst_data(
         })
     }
 }
+
+/// Returns None if `registry` has no factory for the LST's sol value calculator program
+pub fn try_registered_lst_data(
+    registry: &SolValCalcRegistry,
+    lst_state: &LstState,
+    token_program: Pubkey,
+    account_data_map: &AccountDataMap,
+) -> Option<anyhow::Result<LstData>> {
+    registry.create(lst_state, account_data_map).map(|res| {
+        res.map(|sol_val_calc| LstData {
+            sol_val_calc,
+            reserves_balance: None,
+            token_program,
+        })
+    })
+}
+
+/// Token program of `mint`, which is the owner of its mint account.
+///
+/// Errors if the mint account is not in `mint_accounts`
+pub fn lst_token_program<D: ReadonlyAccountOwner>(
+    mint_accounts: &HashMap<Pubkey, D>,
+    mint: &Pubkey,
+) -> anyhow::Result<Pubkey> {
+    mint_accounts
+        .get(mint)
+        .map(|acc| *acc.owner())
+        .ok_or_else(|| anyhow!("LST mint {mint} not fetched"))
+}
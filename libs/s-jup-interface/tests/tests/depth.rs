@@ -0,0 +1,199 @@
+use flat_fee_test_utils::MockFeeAccountArgs;
+use jupiter_amm_interface::{Amm, QuoteParams, SwapMode};
+use marinade_keys::msol;
+use s_controller_interface::SControllerError;
+use s_controller_lib::{set_lst_cap_ix_by_mint_full, SetLstCapByMintFreeArgs};
+use s_controller_test_utils::{
+    jito_marinade_flat_fee_program_test, JitoMarinadeProgramTestArgs, LstStateListBanksClient,
+    MockProtocolFeeBps, PoolStateBanksClient,
+};
+use sanctum_solana_test_utils::test_fixtures_dir;
+use solana_program_test::ProgramTestContext;
+use solana_sdk::{
+    clock::Clock, pubkey::Pubkey, signature::read_keypair_file, signer::Signer,
+    transaction::Transaction,
+};
+use test_utils::{jitosol, JITO_STAKE_POOL_LAST_UPDATE_EPOCH};
+
+use crate::fully_init_amm;
+
+const RESERVES: u64 = 10_000_000_000;
+
+/// jitoSOL and mSOL pool with `RESERVES` of each, 1 LP token worth ~1 lamport
+async fn jito_marinade_depth_test(lp_token_mint: Pubkey) -> ProgramTestContext {
+    let pt = jito_marinade_flat_fee_program_test(
+        JitoMarinadeProgramTestArgs {
+            jitosol_sol_value: RESERVES,
+            msol_sol_value: RESERVES,
+            jitosol_reserves: RESERVES,
+            msol_reserves: RESERVES,
+            jitosol_protocol_fee_accumulator: 0,
+            msol_protocol_fee_accumulator: 0,
+            lp_token_mint,
+            lp_token_supply: 2 * RESERVES,
+        },
+        flat_fee_interface::ProgramState {
+            manager: Pubkey::default(),
+            lp_withdrawal_fee_bps: 20,
+        },
+        [
+            MockFeeAccountArgs {
+                input_fee_bps: 4,
+                output_fee_bps: 4,
+                lst_mint: jitosol::ID,
+            },
+            MockFeeAccountArgs {
+                input_fee_bps: 4,
+                output_fee_bps: 4,
+                lst_mint: msol::ID,
+            },
+        ],
+        MockProtocolFeeBps {
+            trading: 1000,
+            lp: 1000,
+        },
+    )
+    .add_s_program();
+    let ctx = pt.start_with_context().await;
+    ctx.set_sysvar(&Clock {
+        epoch: JITO_STAKE_POOL_LAST_UPDATE_EPOCH,
+        ..Default::default()
+    });
+    ctx
+}
+
+fn exact_in(amount: u64, input_mint: Pubkey, output_mint: Pubkey) -> QuoteParams {
+    QuoteParams {
+        amount,
+        input_mint,
+        output_mint,
+        swap_mode: SwapMode::ExactIn,
+    }
+}
+
+#[tokio::test]
+async fn depth_curve_and_max_swappable_amount_jito_marinade() {
+    let ProgramTestContext {
+        banks_client: mut bc,
+        ..
+    } = jito_marinade_depth_test(Pubkey::new_unique()).await;
+
+    let s = fully_init_amm(&mut bc, s_controller_lib::program::ID).await;
+    let quote_params = |amount| exact_in(amount, jitosol::ID, msol::ID);
+
+    let amounts = [1_000_000_000, 2_000_000_000, 5_000_000_000, 2 * RESERVES];
+    let curve = s.depth_curve(jitosol::ID, msol::ID, &amounts).unwrap();
+    assert_eq!(curve.len(), amounts.len());
+    let mut prev_out_amount = 0;
+    for (amount, point) in amounts.iter().zip(curve) {
+        let point = point.unwrap();
+        let quote = s.quote(&quote_params(*amount)).unwrap();
+        assert_eq!(point.in_amount, quote.in_amount);
+        assert_eq!(point.out_amount, quote.out_amount);
+        assert_eq!(point.not_enough_liquidity, quote.not_enough_liquidity);
+        assert!(point.out_amount > prev_out_amount);
+        prev_out_amount = point.out_amount;
+    }
+    assert!(
+        s.quote(&quote_params(2 * RESERVES))
+            .unwrap()
+            .not_enough_liquidity
+    );
+
+    let max = s
+        .max_swappable_amount(jitosol::ID, msol::ID)
+        .unwrap()
+        .unwrap();
+    assert!(!s.quote(&quote_params(max)).unwrap().not_enough_liquidity);
+    assert!(
+        s.quote(&quote_params(max + 1))
+            .unwrap()
+            .not_enough_liquidity
+    );
+}
+
+#[tokio::test]
+async fn max_swappable_amount_remove_liquidity() {
+    let lp_token_mint = Pubkey::new_unique();
+    let ProgramTestContext {
+        banks_client: mut bc,
+        ..
+    } = jito_marinade_depth_test(lp_token_mint).await;
+
+    let s = fully_init_amm(&mut bc, s_controller_lib::program::ID).await;
+    let quote_params = |amount| exact_in(amount, lp_token_mint, msol::ID);
+
+    let max = s
+        .max_swappable_amount(lp_token_mint, msol::ID)
+        .unwrap()
+        .unwrap();
+    // at most the LP tokens worth all of the mSOL reserves
+    assert!(max <= RESERVES);
+    assert!(!s.quote(&quote_params(max)).unwrap().not_enough_liquidity);
+    assert!(
+        s.quote(&quote_params(max + 1))
+            .unwrap()
+            .not_enough_liquidity
+    );
+}
+
+#[tokio::test]
+async fn max_swappable_amount_add_liquidity_uncapped_is_unbounded() {
+    let lp_token_mint = Pubkey::new_unique();
+    let ProgramTestContext {
+        banks_client: mut bc,
+        ..
+    } = jito_marinade_depth_test(lp_token_mint).await;
+
+    let s = fully_init_amm(&mut bc, s_controller_lib::program::ID).await;
+
+    assert_eq!(
+        s.max_swappable_amount(jitosol::ID, lp_token_mint).unwrap(),
+        None
+    );
+}
+
+#[tokio::test]
+async fn max_swappable_amount_add_liquidity_capped() {
+    // jitoSOL starts at ~49% of the pool
+    const JITOSOL_MAX_SHARE_BPS: u16 = 6_000;
+
+    let mock_auth_kp =
+        read_keypair_file(test_fixtures_dir().join("s-controller-test-initial-authority-key.json"))
+            .unwrap();
+    let lp_token_mint = Pubkey::new_unique();
+    let ProgramTestContext {
+        banks_client: mut bc,
+        payer,
+        last_blockhash,
+        ..
+    } = jito_marinade_depth_test(lp_token_mint).await;
+
+    let ix = set_lst_cap_ix_by_mint_full(
+        &SetLstCapByMintFreeArgs {
+            lst_mint: jitosol::ID,
+            pool_state: bc.get_pool_state_acc().await,
+            lst_state_list: bc.get_lst_state_list_acc().await,
+        },
+        JITOSOL_MAX_SHARE_BPS,
+    )
+    .unwrap();
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &mock_auth_kp], last_blockhash);
+    bc.process_transaction(tx).await.unwrap();
+
+    let s = fully_init_amm(&mut bc, s_controller_lib::program::ID).await;
+    let quote_params = |amount| exact_in(amount, jitosol::ID, lp_token_mint);
+
+    let max = s
+        .max_swappable_amount(jitosol::ID, lp_token_mint)
+        .unwrap()
+        .unwrap();
+    assert!(max > 0);
+    assert!(!s.quote(&quote_params(max)).unwrap().not_enough_liquidity);
+    let err = s.quote(&quote_params(max + 1)).unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<SControllerError>(),
+        Some(&SControllerError::LstCapExceeded)
+    );
+}
@@ -0,0 +1,100 @@
+use flat_fee_test_utils::MockFeeAccountArgs;
+use jupiter_amm_interface::{Amm, QuoteParams, SwapMode};
+use marinade_keys::msol;
+use s_controller_interface::SControllerError;
+use s_controller_lib::{set_lst_cap_ix_by_mint_full, SetLstCapByMintFreeArgs};
+use s_controller_test_utils::{
+    jito_marinade_flat_fee_program_test, JitoMarinadeProgramTestArgs, LstStateListBanksClient,
+    MockProtocolFeeBps, PoolStateBanksClient,
+};
+use sanctum_solana_test_utils::test_fixtures_dir;
+use solana_program_test::ProgramTestContext;
+use solana_sdk::{
+    clock::Clock, pubkey::Pubkey, signature::read_keypair_file, signer::Signer,
+    transaction::Transaction,
+};
+use test_utils::{jitosol, JITO_STAKE_POOL_LAST_UPDATE_EPOCH};
+
+use crate::{fully_init_amm, MiscProgramTest};
+
+#[tokio::test]
+async fn swap_exact_in_quote_over_src_lst_cap() {
+    const AMT: u64 = 1_000_000_000;
+    // jitoSOL starts at ~49% of the pool, swapping 1 jitoSOL in pushes it to ~54%
+    const JITOSOL_MAX_SHARE_BPS: u16 = 5_200;
+
+    let mock_auth_kp =
+        read_keypair_file(test_fixtures_dir().join("s-controller-test-initial-authority-key.json"))
+            .unwrap();
+    let pt = jito_marinade_flat_fee_program_test(
+        JitoMarinadeProgramTestArgs {
+            jitosol_sol_value: 10_000_000_000,
+            msol_sol_value: 10_000_000_000,
+            jitosol_reserves: 10_000_000_000,
+            msol_reserves: 10_000_000_000,
+            jitosol_protocol_fee_accumulator: 0,
+            msol_protocol_fee_accumulator: 0,
+            lp_token_mint: Pubkey::new_unique(),
+            lp_token_supply: 0,
+        },
+        flat_fee_interface::ProgramState {
+            manager: Pubkey::default(),
+            lp_withdrawal_fee_bps: 0,
+        },
+        [
+            MockFeeAccountArgs {
+                input_fee_bps: 4,
+                output_fee_bps: 4,
+                lst_mint: jitosol::ID,
+            },
+            MockFeeAccountArgs {
+                input_fee_bps: 4,
+                output_fee_bps: 4,
+                lst_mint: msol::ID,
+            },
+        ],
+        MockProtocolFeeBps {
+            trading: 1000,
+            lp: 1000,
+        },
+    )
+    .add_s_program();
+    let ctx = pt.start_with_context().await;
+    ctx.set_sysvar(&Clock {
+        epoch: JITO_STAKE_POOL_LAST_UPDATE_EPOCH,
+        ..Default::default()
+    });
+    let ProgramTestContext {
+        banks_client: mut bc,
+        payer,
+        last_blockhash,
+        ..
+    } = ctx;
+
+    let ix = set_lst_cap_ix_by_mint_full(
+        &SetLstCapByMintFreeArgs {
+            lst_mint: jitosol::ID,
+            pool_state: bc.get_pool_state_acc().await,
+            lst_state_list: bc.get_lst_state_list_acc().await,
+        },
+        JITOSOL_MAX_SHARE_BPS,
+    )
+    .unwrap();
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &mock_auth_kp], last_blockhash);
+    bc.process_transaction(tx).await.unwrap();
+
+    let s = fully_init_amm(&mut bc, s_controller_lib::program::ID).await;
+    let err = s
+        .quote(&QuoteParams {
+            amount: AMT,
+            input_mint: jitosol::ID,
+            output_mint: msol::ID,
+            swap_mode: SwapMode::ExactIn,
+        })
+        .unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<SControllerError>(),
+        Some(&SControllerError::LstCapExceeded)
+    );
+}
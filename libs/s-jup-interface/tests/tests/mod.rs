@@ -2,6 +2,10 @@
 //! TODO: test lst state list changed
 
 mod add_liquidity;
+mod depth;
+mod lst_cap;
+mod quote_detailed;
 mod remove_liquidity;
+mod sol_val_calc_registry;
 mod swap_exact_in;
 mod swap_exact_out;
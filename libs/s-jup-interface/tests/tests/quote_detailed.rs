@@ -0,0 +1,161 @@
+use flat_fee_test_utils::MockFeeAccountArgs;
+use jupiter_amm_interface::{Amm, Quote, QuoteParams, SwapMode};
+use marinade_keys::msol;
+use s_controller_test_utils::{
+    jito_marinade_flat_fee_program_test, JitoMarinadeProgramTestArgs, MockProtocolFeeBps,
+};
+use s_jup_interface::{DetailedLiquidityQuote, DetailedQuote, DetailedSwapQuote, SPoolJup};
+use s_quote::QuoteFees;
+use solana_program_test::{BanksClient, ProgramTestContext};
+use solana_sdk::{clock::Clock, pubkey::Pubkey};
+use test_utils::{jitosol, JITO_STAKE_POOL_LAST_UPDATE_EPOCH};
+
+use crate::fully_init_amm;
+
+const AMT: u64 = 1_000_000_000;
+
+async fn start(lp_token_mint: Pubkey) -> BanksClient {
+    let pt = jito_marinade_flat_fee_program_test(
+        JitoMarinadeProgramTestArgs {
+            jitosol_sol_value: 10_000_000_000,
+            msol_sol_value: 10_000_000_000,
+            jitosol_reserves: 10_000_000_000,
+            msol_reserves: 10_000_000_000,
+            jitosol_protocol_fee_accumulator: 0,
+            msol_protocol_fee_accumulator: 0,
+            lp_token_mint,
+            lp_token_supply: 10_000_000_000,
+        },
+        flat_fee_interface::ProgramState {
+            manager: Pubkey::default(),
+            lp_withdrawal_fee_bps: 20,
+        },
+        [
+            MockFeeAccountArgs {
+                input_fee_bps: 4,
+                output_fee_bps: 4,
+                lst_mint: jitosol::ID,
+            },
+            MockFeeAccountArgs {
+                input_fee_bps: 4,
+                output_fee_bps: 4,
+                lst_mint: msol::ID,
+            },
+        ],
+        MockProtocolFeeBps {
+            trading: 1000,
+            lp: 1000,
+        },
+    )
+    .add_s_program();
+    let ctx = pt.start_with_context().await;
+    ctx.set_sysvar(&Clock {
+        epoch: JITO_STAKE_POOL_LAST_UPDATE_EPOCH,
+        ..Default::default()
+    });
+    let ProgramTestContext { banks_client, .. } = ctx;
+    banks_client
+}
+
+fn assert_matches_quote(s: &SPoolJup, quote_params: &QuoteParams, detailed: &DetailedQuote) {
+    let Quote {
+        in_amount,
+        out_amount,
+        fee_amount,
+        fee_mint,
+        fee_pct,
+        not_enough_liquidity,
+        ..
+    } = s.quote(quote_params).unwrap();
+    let converted = detailed.to_quote().unwrap();
+    assert_eq!(converted.in_amount, in_amount);
+    assert_eq!(converted.out_amount, out_amount);
+    assert_eq!(converted.fee_amount, fee_amount);
+    assert_eq!(converted.fee_mint, fee_mint);
+    assert_eq!(converted.fee_pct, fee_pct);
+    assert_eq!(converted.not_enough_liquidity, not_enough_liquidity);
+}
+
+fn assert_fees_split(
+    QuoteFees {
+        sol_value,
+        protocol_sol_value,
+        lp_sol_value,
+        ..
+    }: &QuoteFees,
+) {
+    assert!(*sol_value > 0);
+    assert!(*protocol_sol_value > 0);
+    assert!(*lp_sol_value > *protocol_sol_value);
+    assert_eq!(protocol_sol_value + lp_sol_value, *sol_value);
+}
+
+#[tokio::test]
+async fn quote_detailed_swap_exact_in_jito_marinade() {
+    let mut bc = start(Pubkey::new_unique()).await;
+    let s = fully_init_amm(&mut bc, s_controller_lib::program::ID).await;
+
+    let quote_params = QuoteParams {
+        amount: AMT,
+        input_mint: jitosol::ID,
+        output_mint: msol::ID,
+        swap_mode: SwapMode::ExactIn,
+    };
+    let detailed = s.quote_detailed(&quote_params).unwrap();
+    assert_matches_quote(&s, &quote_params, &detailed);
+
+    let DetailedQuote::Swap(DetailedSwapQuote {
+        quote,
+        in_rounding,
+        out_rounding,
+        ..
+    }) = detailed
+    else {
+        panic!("expected swap, got {detailed:?}");
+    };
+    assert_eq!(quote.in_amount, AMT);
+    assert_eq!(quote.in_sol_value, quote.in_range.get_min());
+    assert_eq!(quote.out_amount, quote.out_range.get_min());
+    assert_eq!(
+        in_rounding,
+        quote.in_range.get_max() - quote.in_range.get_min()
+    );
+    assert_eq!(
+        out_rounding,
+        quote.out_range.get_max() - quote.out_range.get_min()
+    );
+    assert_eq!(
+        quote.in_sol_value - quote.out_sol_value,
+        quote.fees.sol_value
+    );
+    assert!(quote.protocol_fee_lst_amount > 0);
+    assert_fees_split(&quote.fees);
+}
+
+#[tokio::test]
+async fn quote_detailed_remove_liquidity_jito() {
+    let lp_token_mint = Pubkey::new_unique();
+    let mut bc = start(lp_token_mint).await;
+    let s = fully_init_amm(&mut bc, s_controller_lib::program::ID).await;
+
+    let quote_params = QuoteParams {
+        amount: AMT,
+        input_mint: lp_token_mint,
+        output_mint: jitosol::ID,
+        swap_mode: SwapMode::ExactIn,
+    };
+    let detailed = s.quote_detailed(&quote_params).unwrap();
+    assert_matches_quote(&s, &quote_params, &detailed);
+
+    let DetailedQuote::RemoveLiquidity(DetailedLiquidityQuote { quote, .. }) = detailed else {
+        panic!("expected remove liquidity, got {detailed:?}");
+    };
+    assert_eq!(quote.in_amount, AMT);
+    assert_eq!(quote.out_amount, quote.lst_range.get_min());
+    assert_eq!(
+        quote.sol_value - quote.sol_value_after_fees,
+        quote.fees.sol_value
+    );
+    assert!(quote.protocol_fee_lst_amount > 0);
+    assert_fees_split(&quote.fees);
+}
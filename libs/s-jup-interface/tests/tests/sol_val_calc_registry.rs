@@ -0,0 +1,164 @@
+use std::collections::HashMap;
+
+use flat_fee_test_utils::MockFeeAccountArgs;
+use jupiter_amm_interface::{Amm, QuoteParams, SwapMode};
+use marinade_keys::msol;
+use s_controller_test_utils::{
+    jito_marinade_flat_fee_program_test, JitoMarinadeProgramTestArgs, MockProtocolFeeBps,
+};
+use s_sol_val_calc_prog_aggregate::{
+    AccountDataMap, DynLstSolValCalc, KnownLstSolValCalc, LstSolValCalc, SolValCalcRegistry,
+};
+use sanctum_token_ratio::U64ValueRange;
+use solana_program::instruction::AccountMeta;
+use solana_program_test::ProgramTestContext;
+use solana_sdk::{account::Account, clock::Clock, pubkey::Pubkey};
+use test_utils::{jitosol, JITO_STAKE_POOL_LAST_UPDATE_EPOCH};
+
+use crate::{fully_init_amm, UpdatingSPoolJup};
+
+/// Third-party style calculator that just replays a snapshot of an already updated calculator
+#[derive(Debug, Clone)]
+struct SnapshotLstSolValCalc(KnownLstSolValCalc);
+
+impl LstSolValCalc for SnapshotLstSolValCalc {
+    fn sol_value_calculator_program_id(&self) -> Pubkey {
+        self.0.sol_value_calculator_program_id()
+    }
+
+    fn lst_mint(&self) -> Pubkey {
+        self.0.lst_mint()
+    }
+
+    fn lst_to_sol(&self, lst_amount: u64) -> anyhow::Result<U64ValueRange> {
+        self.0.lst_to_sol(lst_amount)
+    }
+
+    fn sol_to_lst(&self, lamports: u64) -> anyhow::Result<U64ValueRange> {
+        self.0.sol_to_lst(lamports)
+    }
+
+    fn ix_accounts(&self) -> Vec<AccountMeta> {
+        self.0.ix_accounts()
+    }
+}
+
+impl DynLstSolValCalc for SnapshotLstSolValCalc {
+    fn get_accounts_to_update(&self) -> Vec<Pubkey> {
+        Vec::new()
+    }
+
+    fn update(&mut self, _account_data_map: &AccountDataMap) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn clone_box(&self) -> Box<dyn DynLstSolValCalc> {
+        Box::new(self.clone())
+    }
+}
+
+#[tokio::test]
+async fn registered_sol_val_calc_makes_unknown_lst_tradeable() {
+    const RESERVES: u64 = 10_000_000_000;
+
+    let pt = jito_marinade_flat_fee_program_test(
+        JitoMarinadeProgramTestArgs {
+            jitosol_sol_value: RESERVES,
+            msol_sol_value: RESERVES,
+            jitosol_reserves: RESERVES,
+            msol_reserves: RESERVES,
+            jitosol_protocol_fee_accumulator: 0,
+            msol_protocol_fee_accumulator: 0,
+            lp_token_mint: Pubkey::new_unique(),
+            lp_token_supply: 0,
+        },
+        flat_fee_interface::ProgramState {
+            manager: Pubkey::default(),
+            lp_withdrawal_fee_bps: 0,
+        },
+        [
+            MockFeeAccountArgs {
+                input_fee_bps: 1,
+                output_fee_bps: 2,
+                lst_mint: jitosol::ID,
+            },
+            MockFeeAccountArgs {
+                input_fee_bps: 3,
+                output_fee_bps: 4,
+                lst_mint: msol::ID,
+            },
+        ],
+        MockProtocolFeeBps {
+            trading: 1000,
+            lp: 1000,
+        },
+    )
+    .add_s_program();
+    let ctx = pt.start_with_context().await;
+    ctx.set_sysvar(&Clock {
+        epoch: JITO_STAKE_POOL_LAST_UPDATE_EPOCH,
+        ..Default::default()
+    });
+    let ProgramTestContext {
+        banks_client: mut bc,
+        ..
+    } = ctx;
+
+    let mut s = fully_init_amm(&mut bc, s_controller_lib::program::ID).await;
+    let quote_params = QuoteParams {
+        amount: 1_000_000_000,
+        input_mint: jitosol::ID,
+        output_mint: msol::ID,
+        swap_mode: SwapMode::ExactIn,
+    };
+    let expected = s.quote(&quote_params).unwrap();
+
+    let msol_index = s
+        .lst_data_list
+        .iter()
+        .position(|ld| {
+            ld.as_ref()
+                .map_or(false, |ld| ld.sol_val_calc.lst_mint() == msol::ID)
+        })
+        .unwrap();
+    let msol_calc = s.lst_data_list[msol_index]
+        .as_ref()
+        .unwrap()
+        .sol_val_calc
+        .clone();
+    let marinade_calc_program = msol_calc.sol_value_calculator_program_id();
+
+    // simulate msol's sol value calculator program being unknown
+    s.lst_data_list[msol_index] = None;
+    assert!(s.quote(&quote_params).is_err());
+
+    let mut registry = SolValCalcRegistry::new();
+    registry.register(
+        marinade_calc_program,
+        move |_lst_state, _account_data_map| {
+            let calc: Box<dyn DynLstSolValCalc> =
+                Box::new(SnapshotLstSolValCalc(msol_calc.clone()));
+            Ok(calc)
+        },
+    );
+    // token program is taken from the mint account of LSTs that were not initialized
+    assert!(s
+        .clone()
+        .with_sol_val_calc_registry(registry.clone(), &HashMap::<Pubkey, Account>::new())
+        .is_err());
+    let msol_mint_acc = bc.get_account(msol::ID).await.unwrap().unwrap();
+    let s = s
+        .with_sol_val_calc_registry(registry, &HashMap::from([(msol::ID, msol_mint_acc)]))
+        .unwrap()
+        .update_with_banks(&mut bc)
+        .await;
+
+    assert!(matches!(
+        s.lst_data_list[msol_index].as_ref().unwrap().sol_val_calc,
+        KnownLstSolValCalc::Dyn(_)
+    ));
+    let quote = s.quote(&quote_params).unwrap();
+    assert_eq!(quote.in_amount, expected.in_amount);
+    assert_eq!(quote.out_amount, expected.out_amount);
+    assert_eq!(quote.fee_amount, expected.fee_amount);
+}
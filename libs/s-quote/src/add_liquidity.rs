@@ -0,0 +1,299 @@
+use pricing_programs_interface::PriceLpTokensToMintIxArgs;
+use s_controller_interface::SControllerError;
+use s_controller_lib::{
+    calc_add_liquidity_protocol_fees, calc_exact_out_sol_value_before_fees, calc_lp_tokens_to_mint,
+    calc_lp_tokens_to_mint_sol_value, CalcAddLiquidityArgs, ExactOutPricingArgs, LpTokenRateArgs,
+};
+use sanctum_token_ratio::U64ValueRange;
+
+use crate::{LiquidityQuote, LiquidityQuoteRequest, QuoteFees, QuoteLst, QuoteMode, QuotePool};
+
+#[derive(Clone, Copy, Debug)]
+struct QuotedLstAmount {
+    lst_amount: u64,
+    lst_range: U64ValueRange,
+    lst_amount_sol_value: u64,
+    lst_amount_sol_value_after_fees: u64,
+}
+
+impl QuotePool<'_> {
+    pub fn quote_add_liquidity(
+        &self,
+        request: &LiquidityQuoteRequest,
+    ) -> anyhow::Result<LiquidityQuote> {
+        match request.mode {
+            QuoteMode::ExactIn => self.quote_add_liquidity_exact_in(request),
+            QuoteMode::ExactOut => self.quote_add_liquidity_exact_out(request),
+        }
+    }
+
+    fn quote_add_liquidity_exact_in(
+        &self,
+        LiquidityQuoteRequest { lst, amount, .. }: &LiquidityQuoteRequest,
+    ) -> anyhow::Result<LiquidityQuote> {
+        let lp_token_supply = self.lp_token_supply()?;
+        let (pool_state, lst_state) = lst.sync_sol_value(self.pool_state)?;
+
+        let lst_range = lst.sol_val_calc.lst_to_sol(*amount)?;
+        let lst_amount_sol_value = lst_range.get_min();
+        let lst_amount_sol_value_after_fees = self.pricing_prog.quote_lp_tokens_to_mint(
+            lst.mint(),
+            &PriceLpTokensToMintIxArgs {
+                amount: *amount,
+                sol_value: lst_amount_sol_value,
+            },
+        )?;
+        if lst_amount_sol_value_after_fees > lst_amount_sol_value {
+            return Err(SControllerError::PoolWouldLoseSolValue.into());
+        }
+        lst.verify_within_cap(pool_state, lst_state, *amount, 0)?;
+        let lp_tokens_to_mint = calc_lp_tokens_to_mint(
+            LpTokenRateArgs {
+                lp_token_supply,
+                pool_total_sol_value: pool_state.total_sol_value,
+            },
+            lst_amount_sol_value_after_fees,
+        )?;
+        let protocol_fee_lst_amount = calc_add_liquidity_protocol_fees(CalcAddLiquidityArgs {
+            lst_amount: *amount,
+            lst_amount_sol_value,
+            lst_amount_sol_value_after_fees,
+            lp_protocol_fee_bps: pool_state.lp_protocol_fee_bps,
+        })?
+        .to_protocol_fees_lst_amount;
+        let fees = QuoteFees::new(
+            lst.mint(),
+            lst.sol_val_calc,
+            lst_amount_sol_value,
+            lst_amount_sol_value_after_fees,
+            pool_state.lp_protocol_fee_bps,
+        )?;
+        Ok(LiquidityQuote {
+            in_amount: *amount,
+            out_amount: lp_tokens_to_mint,
+            sol_value: lst_amount_sol_value,
+            sol_value_after_fees: lst_amount_sol_value_after_fees,
+            lst_range,
+            protocol_fee_lst_amount,
+            fees,
+            not_enough_liquidity: false,
+        })
+    }
+
+    fn quote_add_liquidity_exact_out(
+        &self,
+        LiquidityQuoteRequest { lst, amount, .. }: &LiquidityQuoteRequest,
+    ) -> anyhow::Result<LiquidityQuote> {
+        let lp_token_supply = self.lp_token_supply()?;
+        let (pool_state, lst_state) = lst.sync_sol_value(self.pool_state)?;
+
+        let target_sol_value_after_fees = calc_lp_tokens_to_mint_sol_value(
+            LpTokenRateArgs {
+                lp_token_supply,
+                pool_total_sol_value: pool_state.total_sol_value,
+            },
+            *amount,
+        )?;
+        if target_sol_value_after_fees == 0 {
+            return Err(SControllerError::ZeroValue.into());
+        }
+
+        let mut quoted = self.quote_lst_amount_for_sol_value(lst, target_sol_value_after_fees)?;
+        if quoted.lst_amount_sol_value_after_fees < target_sol_value_after_fees {
+            let sol_value_before_fees = calc_exact_out_sol_value_before_fees(
+                ExactOutPricingArgs {
+                    sol_value: quoted.lst_amount_sol_value,
+                    sol_value_after_fees: quoted.lst_amount_sol_value_after_fees,
+                },
+                target_sol_value_after_fees,
+            )?;
+            quoted = self.quote_lst_amount_for_sol_value(lst, sol_value_before_fees)?;
+        }
+        let QuotedLstAmount {
+            lst_amount,
+            lst_range,
+            lst_amount_sol_value,
+            lst_amount_sol_value_after_fees,
+        } = quoted;
+        if lst_amount_sol_value_after_fees > lst_amount_sol_value
+            || lst_amount_sol_value_after_fees < target_sol_value_after_fees
+        {
+            return Err(SControllerError::PoolWouldLoseSolValue.into());
+        }
+        lst.verify_within_cap(pool_state, lst_state, lst_amount, 0)?;
+        let protocol_fee_lst_amount = calc_add_liquidity_protocol_fees(CalcAddLiquidityArgs {
+            lst_amount,
+            lst_amount_sol_value,
+            lst_amount_sol_value_after_fees,
+            lp_protocol_fee_bps: pool_state.lp_protocol_fee_bps,
+        })?
+        .to_protocol_fees_lst_amount;
+        let fees = QuoteFees::new(
+            lst.mint(),
+            lst.sol_val_calc,
+            lst_amount_sol_value,
+            lst_amount_sol_value_after_fees,
+            pool_state.lp_protocol_fee_bps,
+        )?;
+        Ok(LiquidityQuote {
+            in_amount: lst_amount,
+            out_amount: *amount,
+            sol_value: lst_amount_sol_value,
+            sol_value_after_fees: lst_amount_sol_value_after_fees,
+            lst_range,
+            protocol_fee_lst_amount,
+            fees,
+            not_enough_liquidity: false,
+        })
+    }
+
+    /// Quotes the min amount of LST worth at least `sol_value`
+    fn quote_lst_amount_for_sol_value(
+        &self,
+        lst: &QuoteLst,
+        sol_value: u64,
+    ) -> anyhow::Result<QuotedLstAmount> {
+        let lst_amount = lst.sol_val_calc.sol_to_lst(sol_value)?.get_max();
+        if lst_amount == 0 {
+            return Err(SControllerError::ZeroValue.into());
+        }
+        let lst_range = lst.sol_val_calc.lst_to_sol(lst_amount)?;
+        let lst_amount_sol_value = lst_range.get_min();
+        let lst_amount_sol_value_after_fees = self.pricing_prog.quote_lp_tokens_to_mint(
+            lst.mint(),
+            &PriceLpTokensToMintIxArgs {
+                amount: lst_amount,
+                sol_value: lst_amount_sol_value,
+            },
+        )?;
+        Ok(QuotedLstAmount {
+            lst_amount,
+            lst_range,
+            lst_amount_sol_value,
+            lst_amount_sol_value_after_fees,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use s_controller_interface::SControllerError;
+    use sanctum_token_ratio::U64ValueRange;
+
+    use crate::{
+        test_utils::{
+            mock_pool_state, mock_quote_lst, stale_lst_a, LST_A, LST_B, PRICING_PROG,
+            RESERVES_BALANCE,
+        },
+        LiquidityQuote, LiquidityQuoteRequest, QuoteFees, QuoteMode, QuotePool,
+    };
+
+    /// Pool of `stale_lst_a()` and `synced_lst_b()`.
+    /// 1 LP token is worth 1 lamport once LST_A is synced
+    fn quote_pool() -> QuotePool<'static> {
+        QuotePool {
+            pool_state: mock_pool_state(2 * RESERVES_BALANCE),
+            pricing_prog: &PRICING_PROG,
+            lp_token_supply: Some(21_000_000_000),
+        }
+    }
+
+    fn quote_add_a(amount: u64, mode: QuoteMode) -> anyhow::Result<LiquidityQuote> {
+        quote_pool().quote_add_liquidity(&LiquidityQuoteRequest {
+            lst: stale_lst_a(),
+            amount,
+            mode,
+        })
+    }
+
+    #[test]
+    fn add_liquidity_exact_in_basic() {
+        assert_eq!(
+            quote_add_a(1_000_000_000, QuoteMode::ExactIn).unwrap(),
+            LiquidityQuote {
+                in_amount: 1_000_000_000,
+                out_amount: 1_099_120_000,
+                sol_value: 1_100_000_000,
+                sol_value_after_fees: 1_099_120_000,
+                lst_range: U64ValueRange::single(1_100_000_000),
+                protocol_fee_lst_amount: 80_000,
+                fees: QuoteFees {
+                    mint: LST_A.mint,
+                    amount: 800_000,
+                    sol_value_before_fees: 1_100_000_000,
+                    sol_value: 880_000,
+                    protocol_sol_value: 88_000,
+                    lp_sol_value: 792_000,
+                },
+                not_enough_liquidity: false,
+            }
+        );
+    }
+
+    #[test]
+    fn add_liquidity_exact_out_basic() {
+        assert_eq!(
+            quote_add_a(1_000_000_000, QuoteMode::ExactOut).unwrap(),
+            LiquidityQuote {
+                in_amount: 909_818_766,
+                out_amount: 1_000_000_000,
+                sol_value: 1_000_800_642,
+                // at least the 1_000_000_000 lamports required to mint the LP tokens
+                sol_value_after_fees: 1_000_000_001,
+                lst_range: U64ValueRange::single(1_000_800_642),
+                protocol_fee_lst_amount: 72_786,
+                fees: QuoteFees {
+                    mint: LST_A.mint,
+                    amount: 727_855,
+                    sol_value_before_fees: 1_000_800_642,
+                    sol_value: 800_641,
+                    protocol_sol_value: 80_065,
+                    lp_sol_value: 720_576,
+                },
+                not_enough_liquidity: false,
+            }
+        );
+    }
+
+    #[test]
+    fn add_liquidity_exact_out_zero_amount_fails() {
+        assert_eq!(
+            quote_add_a(0, QuoteMode::ExactOut)
+                .unwrap_err()
+                .downcast_ref::<SControllerError>(),
+            Some(&SControllerError::ZeroValue)
+        );
+    }
+
+    #[test]
+    fn add_liquidity_empty_pool_mints_1_to_1() {
+        let quote = QuotePool {
+            pool_state: mock_pool_state(0),
+            pricing_prog: &PRICING_PROG,
+            lp_token_supply: Some(0),
+        }
+        .quote_add_liquidity(&LiquidityQuoteRequest {
+            lst: mock_quote_lst(&LST_B, 0, 0),
+            amount: 1_000_000_000,
+            mode: QuoteMode::ExactIn,
+        })
+        .unwrap();
+        assert_eq!(quote.sol_value_after_fees, 999_200_000);
+        assert_eq!(quote.out_amount, 999_200_000);
+        assert_eq!(quote.protocol_fee_lst_amount, 80_000);
+    }
+
+    #[test]
+    fn add_liquidity_lp_mint_not_fetched_fails() {
+        let res = QuotePool {
+            lp_token_supply: None,
+            ..quote_pool()
+        }
+        .quote_add_liquidity(&LiquidityQuoteRequest {
+            lst: stale_lst_a(),
+            amount: 1_000_000_000,
+            mode: QuoteMode::ExactIn,
+        });
+        assert!(res.is_err());
+    }
+}
@@ -0,0 +1,19 @@
+//! Offchain quoting for the S controller program's swap and liquidity instructions.
+//!
+//! Every quote follows the same procedure as the corresponding instruction,
+//! starting with syncing the SOL value of the LSTs involved.
+
+mod add_liquidity;
+mod pool;
+mod remove_liquidity;
+mod request;
+mod response;
+mod swap;
+mod swap_route;
+
+#[cfg(test)]
+mod test_utils;
+
+pub use pool::*;
+pub use request::*;
+pub use response::*;
@@ -0,0 +1,101 @@
+use anyhow::anyhow;
+use s_controller_interface::{LstState, PoolState, SControllerError};
+use s_controller_lib::{is_lst_over_cap, sync_sol_value_with_retval};
+use s_pricing_prog_aggregate::PricingProg;
+use s_sol_val_calc_prog_aggregate::LstSolValCalc;
+use solana_program::pubkey::Pubkey;
+
+/// Snapshot of the pool to quote against
+#[derive(Clone, Copy)]
+pub struct QuotePool<'a> {
+    /// The pool's PoolState, before syncing the SOL value of any LST
+    pub pool_state: PoolState,
+
+    pub pricing_prog: &'a dyn PricingProg,
+
+    /// Supply of the pool's LP token mint. Only required for liquidity quotes
+    pub lp_token_supply: Option<u64>,
+}
+
+impl QuotePool<'_> {
+    pub(crate) fn lp_token_supply(&self) -> anyhow::Result<u64> {
+        self.lp_token_supply
+            .ok_or_else(|| anyhow!("LP mint not fetched"))
+    }
+}
+
+/// Snapshot of one of the pool's LSTs to quote against
+#[derive(Clone, Copy)]
+pub struct QuoteLst<'a> {
+    /// The LST's entry in the pool's LstStateList, before syncing its SOL value
+    pub lst_state: LstState,
+
+    pub sol_val_calc: &'a dyn LstSolValCalc,
+
+    /// Token balance of the pool's reserves of the LST
+    pub reserves_balance: u64,
+}
+
+impl QuoteLst<'_> {
+    pub fn mint(&self) -> Pubkey {
+        self.lst_state.mint
+    }
+
+    /// SOL value of the withdrawal fee the LST's stake pool charges on `lst_amount`.
+    /// The SOL value calculator's LstToSol is net of this fee.
+    pub fn withdrawal_fee_sol_value(&self, lst_amount: u64) -> anyhow::Result<u64> {
+        let before_fee = self
+            .sol_val_calc
+            .lst_to_sol_before_withdrawal_fee(lst_amount)?;
+        let after_fee = self.sol_val_calc.lst_to_sol(lst_amount)?.get_min();
+        Ok(before_fee.saturating_sub(after_fee))
+    }
+
+    /// Syncs the LST's SOL value to its reserves balance,
+    /// same as the program does at the start of every swap and liquidity instruction.
+    ///
+    /// Returns (updated pool state, updated lst state)
+    pub(crate) fn sync_sol_value(
+        &self,
+        mut pool_state: PoolState,
+    ) -> anyhow::Result<(PoolState, LstState)> {
+        let mut lst_state = self.lst_state;
+        let ret_sol_val = self.sol_val_calc.lst_to_sol(self.reserves_balance)?;
+        sync_sol_value_with_retval(&mut pool_state, &mut lst_state, ret_sol_val.get_min())?;
+        Ok((pool_state, lst_state))
+    }
+
+    /// Errors with [`SControllerError::LstCapExceeded`] if depositing `lst_amount_in` into
+    /// this LST's reserves while `sol_value_out` leaves the pool would push it
+    /// over its max share of the pool's total SOL value.
+    ///
+    /// `pool_state` and `lst_state` should be the results of [`Self::sync_sol_value`].
+    pub(crate) fn verify_within_cap(
+        &self,
+        mut pool_state: PoolState,
+        mut lst_state: LstState,
+        lst_amount_in: u64,
+        sol_value_out: u64,
+    ) -> anyhow::Result<()> {
+        if lst_state.max_share_bps == 0 {
+            return Ok(());
+        }
+        let new_reserves_balance = self
+            .reserves_balance
+            .checked_add(lst_amount_in)
+            .ok_or(SControllerError::MathError)?;
+        let new_sol_value = self
+            .sol_val_calc
+            .lst_to_sol(new_reserves_balance)?
+            .get_min();
+        sync_sol_value_with_retval(&mut pool_state, &mut lst_state, new_sol_value)?;
+        pool_state.total_sol_value = pool_state
+            .total_sol_value
+            .checked_sub(sol_value_out)
+            .ok_or(SControllerError::MathError)?;
+        if is_lst_over_cap(&pool_state, &lst_state) {
+            return Err(SControllerError::LstCapExceeded.into());
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,305 @@
+use pricing_programs_interface::PriceLpTokensToRedeemIxArgs;
+use s_controller_interface::SControllerError;
+use s_controller_lib::{
+    calc_exact_out_sol_value_before_fees, calc_lp_tokens_for_sol_value, calc_lp_tokens_sol_value,
+    calc_remove_liquidity_protocol_fees, CalcRemoveLiquidityProtocolFeesArgs, ExactOutPricingArgs,
+    LpTokenRateArgs,
+};
+use solana_program::pubkey::Pubkey;
+
+use crate::{LiquidityQuote, LiquidityQuoteRequest, QuoteFees, QuoteMode, QuotePool};
+
+#[derive(Clone, Copy, Debug)]
+struct QuotedLpTokenAmount {
+    lp_token_amount: u64,
+    lp_tokens_sol_value: u64,
+    lp_tokens_sol_value_after_fees: u64,
+}
+
+impl QuotePool<'_> {
+    pub fn quote_remove_liquidity(
+        &self,
+        request: &LiquidityQuoteRequest,
+    ) -> anyhow::Result<LiquidityQuote> {
+        match request.mode {
+            QuoteMode::ExactIn => self.quote_remove_liquidity_exact_in(request),
+            QuoteMode::ExactOut => self.quote_remove_liquidity_exact_out(request),
+        }
+    }
+
+    fn quote_remove_liquidity_exact_in(
+        &self,
+        LiquidityQuoteRequest { lst, amount, .. }: &LiquidityQuoteRequest,
+    ) -> anyhow::Result<LiquidityQuote> {
+        let lp_token_supply = self.lp_token_supply()?;
+        let (pool_state, _lst_state) = lst.sync_sol_value(self.pool_state)?;
+
+        let lp_tokens_sol_value = calc_lp_tokens_sol_value(
+            LpTokenRateArgs {
+                lp_token_supply,
+                pool_total_sol_value: pool_state.total_sol_value,
+            },
+            *amount,
+        )?;
+        let lp_tokens_sol_value_after_fees = self.pricing_prog.quote_lp_tokens_to_redeem(
+            lst.mint(),
+            &PriceLpTokensToRedeemIxArgs {
+                amount: *amount,
+                sol_value: lp_tokens_sol_value,
+            },
+        )?;
+        if lp_tokens_sol_value_after_fees > lp_tokens_sol_value {
+            return Err(SControllerError::PoolWouldLoseSolValue.into());
+        }
+        let lst_range = lst
+            .sol_val_calc
+            .sol_to_lst(lp_tokens_sol_value_after_fees)?;
+        let to_user_lst_amount = lst_range.get_min();
+        let protocol_fee_lst_amount =
+            calc_remove_liquidity_protocol_fees(CalcRemoveLiquidityProtocolFeesArgs {
+                lp_tokens_sol_value,
+                lp_tokens_sol_value_after_fees,
+                to_user_lst_amount,
+                lp_protocol_fee_bps: pool_state.lp_protocol_fee_bps,
+            })?;
+        let total_dst_lst_out = to_user_lst_amount
+            .checked_add(protocol_fee_lst_amount)
+            .ok_or(SControllerError::MathError)?;
+        let fees = QuoteFees::new(
+            lst.mint(),
+            lst.sol_val_calc,
+            lp_tokens_sol_value,
+            lp_tokens_sol_value_after_fees,
+            pool_state.lp_protocol_fee_bps,
+        )?;
+        Ok(LiquidityQuote {
+            in_amount: *amount,
+            out_amount: to_user_lst_amount,
+            sol_value: lp_tokens_sol_value,
+            sol_value_after_fees: lp_tokens_sol_value_after_fees,
+            lst_range,
+            protocol_fee_lst_amount,
+            fees,
+            not_enough_liquidity: total_dst_lst_out > lst.reserves_balance,
+        })
+    }
+
+    fn quote_remove_liquidity_exact_out(
+        &self,
+        LiquidityQuoteRequest { lst, amount, .. }: &LiquidityQuoteRequest,
+    ) -> anyhow::Result<LiquidityQuote> {
+        let lp_token_supply = self.lp_token_supply()?;
+        let (pool_state, _lst_state) = lst.sync_sol_value(self.pool_state)?;
+
+        let lp_token_rate_args = LpTokenRateArgs {
+            lp_token_supply,
+            pool_total_sol_value: pool_state.total_sol_value,
+        };
+        let lst_range = lst.sol_val_calc.lst_to_sol(*amount)?;
+        let target_sol_value_after_fees = lst_range.get_max();
+        if target_sol_value_after_fees == 0 {
+            return Err(SControllerError::ZeroValue.into());
+        }
+
+        let mut quoted = self.quote_lp_tokens_for_sol_value(
+            lp_token_rate_args,
+            lst.mint(),
+            target_sol_value_after_fees,
+        )?;
+        if quoted.lp_tokens_sol_value_after_fees < target_sol_value_after_fees {
+            let sol_value_before_fees = calc_exact_out_sol_value_before_fees(
+                ExactOutPricingArgs {
+                    sol_value: quoted.lp_tokens_sol_value,
+                    sol_value_after_fees: quoted.lp_tokens_sol_value_after_fees,
+                },
+                target_sol_value_after_fees,
+            )?;
+            quoted = self.quote_lp_tokens_for_sol_value(
+                lp_token_rate_args,
+                lst.mint(),
+                sol_value_before_fees,
+            )?;
+        }
+        let QuotedLpTokenAmount {
+            lp_token_amount,
+            lp_tokens_sol_value,
+            lp_tokens_sol_value_after_fees,
+        } = quoted;
+        if lp_tokens_sol_value_after_fees > lp_tokens_sol_value
+            || lp_tokens_sol_value_after_fees < target_sol_value_after_fees
+        {
+            return Err(SControllerError::PoolWouldLoseSolValue.into());
+        }
+
+        let protocol_fee_lst_amount =
+            calc_remove_liquidity_protocol_fees(CalcRemoveLiquidityProtocolFeesArgs {
+                lp_tokens_sol_value,
+                lp_tokens_sol_value_after_fees,
+                to_user_lst_amount: *amount,
+                lp_protocol_fee_bps: pool_state.lp_protocol_fee_bps,
+            })?;
+        let total_dst_lst_out = amount
+            .checked_add(protocol_fee_lst_amount)
+            .ok_or(SControllerError::MathError)?;
+        let fees = QuoteFees::new(
+            lst.mint(),
+            lst.sol_val_calc,
+            lp_tokens_sol_value,
+            lp_tokens_sol_value_after_fees,
+            pool_state.lp_protocol_fee_bps,
+        )?;
+        Ok(LiquidityQuote {
+            in_amount: lp_token_amount,
+            out_amount: *amount,
+            sol_value: lp_tokens_sol_value,
+            sol_value_after_fees: lp_tokens_sol_value_after_fees,
+            lst_range,
+            protocol_fee_lst_amount,
+            fees,
+            not_enough_liquidity: total_dst_lst_out > lst.reserves_balance,
+        })
+    }
+
+    /// Quotes the min amount of LP tokens worth at least `sol_value`
+    fn quote_lp_tokens_for_sol_value(
+        &self,
+        lp_token_rate_args: LpTokenRateArgs,
+        lst_mint: Pubkey,
+        sol_value: u64,
+    ) -> anyhow::Result<QuotedLpTokenAmount> {
+        let lp_token_amount = calc_lp_tokens_for_sol_value(lp_token_rate_args, sol_value)?;
+        if lp_token_amount == 0 {
+            return Err(SControllerError::ZeroValue.into());
+        }
+        let lp_tokens_sol_value = calc_lp_tokens_sol_value(lp_token_rate_args, lp_token_amount)?;
+        let lp_tokens_sol_value_after_fees = self.pricing_prog.quote_lp_tokens_to_redeem(
+            lst_mint,
+            &PriceLpTokensToRedeemIxArgs {
+                amount: lp_token_amount,
+                sol_value: lp_tokens_sol_value,
+            },
+        )?;
+        Ok(QuotedLpTokenAmount {
+            lp_token_amount,
+            lp_tokens_sol_value,
+            lp_tokens_sol_value_after_fees,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use s_controller_interface::SControllerError;
+    use sanctum_token_ratio::U64ValueRange;
+
+    use crate::{
+        test_utils::{
+            mock_pool_state, mock_quote_lst, stale_lst_a, LST_A, LST_B, PRICING_PROG,
+            RESERVES_BALANCE,
+        },
+        LiquidityQuote, LiquidityQuoteRequest, QuoteFees, QuoteMode, QuotePool,
+    };
+
+    /// Pool of `stale_lst_a()` and `synced_lst_b()`.
+    /// 1 LP token is worth 1 lamport once LST_A is synced
+    fn quote_pool() -> QuotePool<'static> {
+        QuotePool {
+            pool_state: mock_pool_state(2 * RESERVES_BALANCE),
+            pricing_prog: &PRICING_PROG,
+            lp_token_supply: Some(21_000_000_000),
+        }
+    }
+
+    fn quote_remove_a(amount: u64, mode: QuoteMode) -> anyhow::Result<LiquidityQuote> {
+        quote_pool().quote_remove_liquidity(&LiquidityQuoteRequest {
+            lst: stale_lst_a(),
+            amount,
+            mode,
+        })
+    }
+
+    #[test]
+    fn remove_liquidity_exact_in_basic() {
+        assert_eq!(
+            quote_remove_a(1_000_000_000, QuoteMode::ExactIn).unwrap(),
+            LiquidityQuote {
+                in_amount: 1_000_000_000,
+                out_amount: 908_363_636,
+                sol_value: 1_000_000_000,
+                sol_value_after_fees: 999_200_000,
+                lst_range: U64ValueRange::from_min_max_unchecked(908_363_636, 908_363_637),
+                protocol_fee_lst_amount: 72_727,
+                fees: QuoteFees {
+                    mint: LST_A.mint,
+                    amount: 727_272,
+                    sol_value_before_fees: 1_000_000_000,
+                    sol_value: 800_000,
+                    protocol_sol_value: 80_000,
+                    lp_sol_value: 720_000,
+                },
+                not_enough_liquidity: false,
+            }
+        );
+    }
+
+    #[test]
+    fn remove_liquidity_exact_out_basic() {
+        assert_eq!(
+            quote_remove_a(1_000_000_000, QuoteMode::ExactOut).unwrap(),
+            LiquidityQuote {
+                in_amount: 1_100_880_705,
+                out_amount: 1_000_000_000,
+                sol_value: 1_100_880_705,
+                sol_value_after_fees: 1_100_000_000,
+                lst_range: U64ValueRange::single(1_100_000_000),
+                protocol_fee_lst_amount: 80_064,
+                fees: QuoteFees {
+                    mint: LST_A.mint,
+                    amount: 800_640,
+                    sol_value_before_fees: 1_100_880_705,
+                    sol_value: 880_705,
+                    protocol_sol_value: 88_071,
+                    lp_sol_value: 792_634,
+                },
+                not_enough_liquidity: false,
+            }
+        );
+    }
+
+    #[test]
+    fn remove_liquidity_exact_in_more_than_reserves() {
+        let quote = quote_remove_a(15_000_000_000, QuoteMode::ExactIn).unwrap();
+        assert_eq!(quote.out_amount, 13_625_454_545);
+        assert_eq!(quote.protocol_fee_lst_amount, 1_090_909);
+        assert!(quote.not_enough_liquidity);
+    }
+
+    #[test]
+    fn remove_liquidity_exact_out_zero_amount_fails() {
+        assert_eq!(
+            quote_remove_a(0, QuoteMode::ExactOut)
+                .unwrap_err()
+                .downcast_ref::<SControllerError>(),
+            Some(&SControllerError::ZeroValue)
+        );
+    }
+
+    #[test]
+    fn remove_liquidity_exact_out_empty_pool_fails() {
+        // LP tokens have no SOL value
+        let res = QuotePool {
+            pool_state: mock_pool_state(0),
+            pricing_prog: &PRICING_PROG,
+            lp_token_supply: Some(0),
+        }
+        .quote_remove_liquidity(&LiquidityQuoteRequest {
+            lst: mock_quote_lst(&LST_B, 0, 0),
+            amount: 1_000_000_000,
+            mode: QuoteMode::ExactOut,
+        });
+        assert_eq!(
+            res.unwrap_err().downcast_ref::<SControllerError>(),
+            Some(&SControllerError::ZeroValue)
+        );
+    }
+}
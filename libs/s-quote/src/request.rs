@@ -0,0 +1,40 @@
+use crate::QuoteLst;
+
+/// Which side of a quote the requested amount is for
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum QuoteMode {
+    /// The amount is the exact amount in, quote the amount out
+    #[default]
+    ExactIn,
+
+    /// The amount is the exact amount out, quote the amount in
+    ExactOut,
+}
+
+/// Request to quote a swap from `input` to `output`
+#[derive(Clone, Copy)]
+pub struct SwapQuoteRequest<'a> {
+    pub input: QuoteLst<'a>,
+    pub output: QuoteLst<'a>,
+    pub amount: u64,
+    pub mode: QuoteMode,
+}
+
+/// Request to quote adding liquidity in `lst` or removing liquidity in `lst`.
+///
+/// `amount` is in `lst` atomics for [`QuoteMode::ExactIn`] add liquidity and [`QuoteMode::ExactOut`] remove liquidity,
+/// LP token atomics otherwise.
+#[derive(Clone, Copy)]
+pub struct LiquidityQuoteRequest<'a> {
+    pub lst: QuoteLst<'a>,
+    pub amount: u64,
+    pub mode: QuoteMode,
+}
+
+/// Request to quote an exact in swap through every LST in `route` in order,
+/// from `route[0]` to `route[route.len() - 1]`
+#[derive(Clone, Copy)]
+pub struct SwapRouteQuoteRequest<'a> {
+    pub route: &'a [QuoteLst<'a>],
+    pub amount: u64,
+}
@@ -0,0 +1,147 @@
+use s_controller_interface::SControllerError;
+use s_controller_lib::calc_protocol_fees_sol_value;
+use s_sol_val_calc_prog_aggregate::LstSolValCalc;
+use sanctum_token_ratio::U64ValueRange;
+use solana_program::pubkey::Pubkey;
+
+/// Fees charged by the pricing program in a quote
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct QuoteFees {
+    /// Mint of the LST fees are denominated in:
+    /// the output LST for swaps and remove liquidity, the input LST for add liquidity
+    pub mint: Pubkey,
+
+    /// SOL value of fees charged, converted to `mint` atomics, rounded down
+    pub amount: u64,
+
+    /// SOL value priced by the pricing program, before fees
+    pub sol_value_before_fees: u64,
+
+    /// SOL value of fees charged.
+    /// `sol_value = protocol_sol_value + lp_sol_value`
+    pub sol_value: u64,
+
+    /// Portion of `sol_value` paid to the protocol fee accumulator
+    pub protocol_sol_value: u64,
+
+    /// Portion of `sol_value` that stays in the pool, accruing to LPs
+    pub lp_sol_value: u64,
+}
+
+impl QuoteFees {
+    /// `sol_val_calc` is the SOL value calculator of `mint`
+    pub(crate) fn new(
+        mint: Pubkey,
+        sol_val_calc: &dyn LstSolValCalc,
+        sol_value_before_fees: u64,
+        sol_value_after_fees: u64,
+        protocol_fee_bps: u16,
+    ) -> anyhow::Result<Self> {
+        let sol_value = sol_value_before_fees
+            .checked_sub(sol_value_after_fees)
+            .ok_or(SControllerError::PoolWouldLoseSolValue)?;
+        let protocol_sol_value = calc_protocol_fees_sol_value(sol_value, protocol_fee_bps)?;
+        let lp_sol_value = sol_value
+            .checked_sub(protocol_sol_value)
+            .ok_or(SControllerError::MathError)?;
+        Ok(Self {
+            mint,
+            amount: sol_val_calc.sol_to_lst(sol_value)?.get_min(),
+            sol_value_before_fees,
+            sol_value,
+            protocol_sol_value,
+            lp_sol_value,
+        })
+    }
+}
+
+/// Quote for SwapExactIn or SwapExactOut
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SwapQuote {
+    pub in_amount: u64,
+
+    pub out_amount: u64,
+
+    /// SOL value of `in_amount` priced by the pricing program
+    pub in_sol_value: u64,
+
+    /// SOL value of `out_amount`, `in_sol_value` after the pricing program's fees
+    pub out_sol_value: u64,
+
+    /// Range returned by the input LST's SOL value calculator converting between `in_amount` and `in_sol_value`:
+    /// LstToSol(in_amount) for exact in, SolToLst(in_sol_value) for exact out.
+    ///
+    /// The quote uses the end of the range that is unfavourable to the user.
+    pub in_range: U64ValueRange,
+
+    /// Range returned by the output LST's SOL value calculator converting between `out_amount` and `out_sol_value`:
+    /// SolToLst(out_sol_value) for exact in, LstToSol(out_amount) for exact out.
+    ///
+    /// The quote uses the end of the range that is unfavourable to the user.
+    pub out_range: U64ValueRange,
+
+    /// Amount of output LST transferred from the pool's reserves to the protocol fee accumulator
+    pub protocol_fee_lst_amount: u64,
+
+    pub fees: QuoteFees,
+
+    /// True if the pool's output LST reserves cannot cover `out_amount + protocol_fee_lst_amount`
+    pub not_enough_liquidity: bool,
+}
+
+/// Quote for AddLiquidity, AddLiquidityExactOut, RemoveLiquidity or RemoveLiquidityExactOut
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LiquidityQuote {
+    /// LST amount to add for add liquidity, LP token amount to burn for remove liquidity
+    pub in_amount: u64,
+
+    /// LP token amount to mint for add liquidity, LST amount to receive for remove liquidity
+    pub out_amount: u64,
+
+    /// SOL value of the LST added or the LP tokens burnt, before fees
+    pub sol_value: u64,
+
+    /// `sol_value` after the pricing program's fees,
+    /// the SOL value of the LP tokens minted or the LST received
+    pub sol_value_after_fees: u64,
+
+    /// Range returned by the LST's SOL value calculator converting between the LST amount and its SOL value:
+    /// - LstToSol(in_amount) for add liquidity
+    /// - SolToLst(sol_value_after_fees) for exact in remove liquidity
+    /// - LstToSol(out_amount) for exact out remove liquidity
+    ///
+    /// The quote uses the end of the range that is unfavourable to the user.
+    pub lst_range: U64ValueRange,
+
+    /// Amount of LST transferred to the protocol fee accumulator,
+    /// out of the LST added for add liquidity, from the pool's reserves for remove liquidity
+    pub protocol_fee_lst_amount: u64,
+
+    pub fees: QuoteFees,
+
+    /// True if the pool's LST reserves cannot cover `out_amount + protocol_fee_lst_amount`.
+    /// Always false for add liquidity
+    pub not_enough_liquidity: bool,
+}
+
+/// Quote for SwapExactInRoute
+#[derive(Clone, Debug, PartialEq)]
+pub struct SwapRouteQuote {
+    pub in_amount: u64,
+
+    pub out_amount: u64,
+
+    /// Quote for each hop of the route, in order.
+    /// The `in_amount` of each hop is the `out_amount` of the previous hop
+    pub hops: Vec<SwapQuote>,
+
+    /// Total fees charged across all hops, denominated in the route's output LST.
+    ///
+    /// `sol_value_before_fees` is the SOL value of the first hop's input and
+    /// `sol_value` is the difference between that and the SOL value of the last hop's output.
+    /// `protocol_sol_value` is the sum of every hop's protocol fees.
+    pub fees: QuoteFees,
+
+    /// True if any hop's output LST reserves cannot cover its output
+    pub not_enough_liquidity: bool,
+}
@@ -0,0 +1,298 @@
+use pricing_programs_interface::{
+    PriceExactInIxArgs, PriceExactInKeys, PriceExactOutIxArgs, PriceExactOutKeys,
+};
+use s_controller_interface::SControllerError;
+use s_controller_lib::{calc_swap_protocol_fees, CalcSwapProtocolFeesArgs};
+
+use crate::{QuoteFees, QuoteMode, QuotePool, SwapQuote, SwapQuoteRequest};
+
+impl QuotePool<'_> {
+    pub fn quote_swap(&self, request: &SwapQuoteRequest) -> anyhow::Result<SwapQuote> {
+        match request.mode {
+            QuoteMode::ExactIn => self.quote_swap_exact_in(request),
+            QuoteMode::ExactOut => self.quote_swap_exact_out(request),
+        }
+    }
+
+    fn quote_swap_exact_in(
+        &self,
+        SwapQuoteRequest {
+            input,
+            output,
+            amount,
+            ..
+        }: &SwapQuoteRequest,
+    ) -> anyhow::Result<SwapQuote> {
+        let (pool_state, input_lst_state) = input.sync_sol_value(self.pool_state)?;
+        let (pool_state, _output_lst_state) = output.sync_sol_value(pool_state)?;
+
+        let in_range = input.sol_val_calc.lst_to_sol(*amount)?;
+        let in_sol_value = in_range.get_min();
+        if in_sol_value == 0 {
+            return Err(SControllerError::ZeroValue.into());
+        }
+        let out_sol_value = self.pricing_prog.quote_exact_in(
+            PriceExactInKeys {
+                input_lst_mint: input.mint(),
+                output_lst_mint: output.mint(),
+            },
+            &PriceExactInIxArgs {
+                amount: *amount,
+                sol_value: in_sol_value,
+            },
+        )?;
+        if out_sol_value > in_sol_value {
+            return Err(SControllerError::PoolWouldLoseSolValue.into());
+        }
+        let out_range = output.sol_val_calc.sol_to_lst(out_sol_value)?;
+        let dst_lst_out = out_range.get_min();
+        if dst_lst_out == 0 {
+            return Err(SControllerError::ZeroValue.into());
+        }
+        let protocol_fee_lst_amount = calc_swap_protocol_fees(CalcSwapProtocolFeesArgs {
+            in_sol_value,
+            out_sol_value,
+            dst_lst_out,
+            trading_protocol_fee_bps: pool_state.trading_protocol_fee_bps,
+        })?;
+        let total_dst_lst_out = dst_lst_out
+            .checked_add(protocol_fee_lst_amount)
+            .ok_or(SControllerError::MathError)?;
+        let not_enough_liquidity = total_dst_lst_out > output.reserves_balance;
+        input.verify_within_cap(
+            pool_state,
+            input_lst_state,
+            *amount,
+            output.sol_val_calc.lst_to_sol(total_dst_lst_out)?.get_min(),
+        )?;
+        let fees = QuoteFees::new(
+            output.mint(),
+            output.sol_val_calc,
+            in_sol_value,
+            out_sol_value,
+            pool_state.trading_protocol_fee_bps,
+        )?;
+        Ok(SwapQuote {
+            in_amount: *amount,
+            out_amount: dst_lst_out,
+            in_sol_value,
+            out_sol_value,
+            in_range,
+            out_range,
+            protocol_fee_lst_amount,
+            fees,
+            not_enough_liquidity,
+        })
+    }
+
+    fn quote_swap_exact_out(
+        &self,
+        SwapQuoteRequest {
+            input,
+            output,
+            amount,
+            ..
+        }: &SwapQuoteRequest,
+    ) -> anyhow::Result<SwapQuote> {
+        let (pool_state, input_lst_state) = input.sync_sol_value(self.pool_state)?;
+        let (pool_state, _output_lst_state) = output.sync_sol_value(pool_state)?;
+
+        let out_range = output.sol_val_calc.lst_to_sol(*amount)?;
+        let out_sol_value = out_range.get_max();
+        if out_sol_value == 0 {
+            return Err(SControllerError::ZeroValue.into());
+        }
+        let in_sol_value = self.pricing_prog.quote_exact_out(
+            PriceExactOutKeys {
+                input_lst_mint: input.mint(),
+                output_lst_mint: output.mint(),
+            },
+            &PriceExactOutIxArgs {
+                amount: *amount,
+                sol_value: out_sol_value,
+            },
+        )?;
+        if out_sol_value > in_sol_value {
+            return Err(SControllerError::PoolWouldLoseSolValue.into());
+        }
+        let in_range = input.sol_val_calc.sol_to_lst(in_sol_value)?;
+        let src_lst_in = in_range.get_max();
+        if src_lst_in == 0 {
+            return Err(SControllerError::ZeroValue.into());
+        }
+        let protocol_fee_lst_amount = calc_swap_protocol_fees(CalcSwapProtocolFeesArgs {
+            in_sol_value,
+            out_sol_value,
+            dst_lst_out: *amount,
+            trading_protocol_fee_bps: pool_state.trading_protocol_fee_bps,
+        })?;
+        let total_dst_lst_out = amount
+            .checked_add(protocol_fee_lst_amount)
+            .ok_or(SControllerError::MathError)?;
+        let not_enough_liquidity = total_dst_lst_out > output.reserves_balance;
+        input.verify_within_cap(
+            pool_state,
+            input_lst_state,
+            src_lst_in,
+            output.sol_val_calc.lst_to_sol(total_dst_lst_out)?.get_min(),
+        )?;
+        let fees = QuoteFees::new(
+            output.mint(),
+            output.sol_val_calc,
+            in_sol_value,
+            out_sol_value,
+            pool_state.trading_protocol_fee_bps,
+        )?;
+        Ok(SwapQuote {
+            in_amount: src_lst_in,
+            out_amount: *amount,
+            in_sol_value,
+            out_sol_value,
+            in_range,
+            out_range,
+            protocol_fee_lst_amount,
+            fees,
+            not_enough_liquidity,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use s_controller_interface::SControllerError;
+    use sanctum_token_ratio::U64ValueRange;
+
+    use crate::{
+        test_utils::{
+            mock_pool_state, mock_quote_lst, stale_lst_a, synced_lst_b, LST_A, LST_B, PRICING_PROG,
+            RESERVES_BALANCE,
+        },
+        QuoteFees, QuoteMode, QuotePool, SwapQuote, SwapQuoteRequest,
+    };
+
+    fn quote_pool() -> QuotePool<'static> {
+        QuotePool {
+            pool_state: mock_pool_state(2 * RESERVES_BALANCE),
+            pricing_prog: &PRICING_PROG,
+            lp_token_supply: None,
+        }
+    }
+
+    fn quote_a_to_b(amount: u64, mode: QuoteMode) -> anyhow::Result<SwapQuote> {
+        quote_pool().quote_swap(&SwapQuoteRequest {
+            input: stale_lst_a(),
+            output: synced_lst_b(),
+            amount,
+            mode,
+        })
+    }
+
+    fn assert_err(res: anyhow::Result<SwapQuote>, expected: SControllerError) {
+        assert_eq!(
+            res.unwrap_err().downcast_ref::<SControllerError>(),
+            Some(&expected)
+        );
+    }
+
+    #[test]
+    fn swap_exact_in_basic() {
+        assert_eq!(
+            quote_a_to_b(1_000_000_000, QuoteMode::ExactIn).unwrap(),
+            SwapQuote {
+                in_amount: 1_000_000_000,
+                out_amount: 1_099_120_000,
+                in_sol_value: 1_100_000_000,
+                out_sol_value: 1_099_120_000,
+                in_range: U64ValueRange::single(1_100_000_000),
+                out_range: U64ValueRange::single(1_099_120_000),
+                protocol_fee_lst_amount: 88_000,
+                fees: QuoteFees {
+                    mint: LST_B.mint,
+                    amount: 880_000,
+                    sol_value_before_fees: 1_100_000_000,
+                    sol_value: 880_000,
+                    protocol_sol_value: 88_000,
+                    lp_sol_value: 792_000,
+                },
+                not_enough_liquidity: false,
+            }
+        );
+    }
+
+    #[test]
+    fn swap_exact_out_basic() {
+        assert_eq!(
+            quote_a_to_b(1_000_000_000, QuoteMode::ExactOut).unwrap(),
+            SwapQuote {
+                // rounded up from 909_818_764.5
+                in_amount: 909_818_765,
+                out_amount: 1_000_000_000,
+                in_sol_value: 1_000_800_641,
+                out_sol_value: 1_000_000_000,
+                in_range: U64ValueRange::from_min_max_unchecked(909_818_764, 909_818_765),
+                out_range: U64ValueRange::single(1_000_000_000),
+                protocol_fee_lst_amount: 80_065,
+                fees: QuoteFees {
+                    mint: LST_B.mint,
+                    amount: 800_641,
+                    sol_value_before_fees: 1_000_800_641,
+                    sol_value: 800_641,
+                    protocol_sol_value: 80_065,
+                    lp_sol_value: 720_576,
+                },
+                not_enough_liquidity: false,
+            }
+        );
+    }
+
+    #[test]
+    fn swap_exact_in_protocol_fee_rounds_up() {
+        let SwapQuote {
+            out_amount,
+            protocol_fee_lst_amount,
+            fees,
+            ..
+        } = quote_a_to_b(10, QuoteMode::ExactIn).unwrap();
+        // 11 lamports in, 10 out after 8 bps fees rounded down
+        assert_eq!(out_amount, 10);
+        // protocol gets all of the 1 lamport fee: 10% rounded up
+        assert_eq!(protocol_fee_lst_amount, 1);
+        assert_eq!(fees.sol_value, 1);
+        assert_eq!(fees.protocol_sol_value, 1);
+        assert_eq!(fees.lp_sol_value, 0);
+    }
+
+    #[test]
+    fn swap_zero_amount_fails() {
+        for mode in [QuoteMode::ExactIn, QuoteMode::ExactOut] {
+            assert_err(quote_a_to_b(0, mode), SControllerError::ZeroValue);
+        }
+    }
+
+    #[test]
+    fn swap_exact_in_out_amount_rounds_to_zero_fails() {
+        // 1 atomic of LST_A is worth 1 lamport, 0 after fees
+        assert_err(
+            quote_a_to_b(1, QuoteMode::ExactIn),
+            SControllerError::ZeroValue,
+        );
+    }
+
+    #[test]
+    fn swap_from_empty_pool_not_enough_liquidity() {
+        let res = QuotePool {
+            pool_state: mock_pool_state(0),
+            pricing_prog: &PRICING_PROG,
+            lp_token_supply: None,
+        }
+        .quote_swap(&SwapQuoteRequest {
+            input: mock_quote_lst(&LST_A, 0, 0),
+            output: mock_quote_lst(&LST_B, 0, 0),
+            amount: 1_000_000_000,
+            mode: QuoteMode::ExactIn,
+        })
+        .unwrap();
+        assert_eq!(res.out_amount, 1_099_120_000);
+        assert!(res.not_enough_liquidity);
+    }
+}
@@ -0,0 +1,404 @@
+use pricing_programs_interface::{PriceExactInIxArgs, PriceExactInKeys};
+use s_controller_interface::SControllerError;
+use s_controller_lib::{calc_swap_protocol_fees, CalcSwapProtocolFeesArgs, MAX_SWAP_ROUTE_LSTS};
+
+use crate::{QuoteFees, QuotePool, SwapQuote, SwapRouteQuote, SwapRouteQuoteRequest};
+
+impl QuotePool<'_> {
+    /// Quotes a SwapExactInRoute through every LST of `request.route` in order.
+    ///
+    /// Every LST on the route is synced once before any hop is priced, same as the program does.
+    ///
+    /// Like the program, individual hops may gain SOL value as long as the route as a whole
+    /// does not make the pool lose SOL value.
+    pub fn quote_swap_exact_in_route(
+        &self,
+        SwapRouteQuoteRequest { route, amount }: &SwapRouteQuoteRequest,
+    ) -> anyhow::Result<SwapRouteQuote> {
+        if route.len() < 2 || route.len() > MAX_SWAP_ROUTE_LSTS {
+            return Err(SControllerError::InvalidSwapRoute.into());
+        }
+        for (i, lst) in route.iter().enumerate() {
+            if route[..i].iter().any(|prev| prev.mint() == lst.mint()) {
+                return Err(SControllerError::SwapSameLst.into());
+            }
+        }
+
+        let mut pool_state = self.pool_state;
+        let mut input_lst_state = None;
+        for lst in route.iter() {
+            let (synced_pool_state, synced_lst_state) = lst.sync_sol_value(pool_state)?;
+            pool_state = synced_pool_state;
+            input_lst_state.get_or_insert(synced_lst_state);
+        }
+
+        let mut hops = Vec::with_capacity(route.len() - 1);
+        let mut hop_amount_in = *amount;
+        let mut last_total_dst_lst_out = 0;
+        for hop_lsts in route.windows(2) {
+            let (src, dst) = (&hop_lsts[0], &hop_lsts[1]);
+
+            let in_range = src.sol_val_calc.lst_to_sol(hop_amount_in)?;
+            let in_sol_value = in_range.get_min();
+            if in_sol_value == 0 {
+                return Err(SControllerError::ZeroValue.into());
+            }
+            let out_sol_value = self.pricing_prog.quote_exact_in(
+                PriceExactInKeys {
+                    input_lst_mint: src.mint(),
+                    output_lst_mint: dst.mint(),
+                },
+                &PriceExactInIxArgs {
+                    amount: hop_amount_in,
+                    sol_value: in_sol_value,
+                },
+            )?;
+            let out_range = dst.sol_val_calc.sol_to_lst(out_sol_value)?;
+            let dst_lst_out = out_range.get_min();
+            if dst_lst_out == 0 {
+                return Err(SControllerError::ZeroValue.into());
+            }
+            let protocol_fee_lst_amount = calc_swap_protocol_fees(CalcSwapProtocolFeesArgs {
+                in_sol_value,
+                out_sol_value,
+                dst_lst_out,
+                trading_protocol_fee_bps: pool_state.trading_protocol_fee_bps,
+            })?;
+            let total_dst_lst_out = dst_lst_out
+                .checked_add(protocol_fee_lst_amount)
+                .ok_or(SControllerError::MathError)?;
+            // a hop that gains SOL value charges no fees, same as calc_swap_protocol_fees()
+            let fees = QuoteFees::new(
+                dst.mint(),
+                dst.sol_val_calc,
+                in_sol_value,
+                out_sol_value.min(in_sol_value),
+                pool_state.trading_protocol_fee_bps,
+            )?;
+            hops.push(SwapQuote {
+                in_amount: hop_amount_in,
+                out_amount: dst_lst_out,
+                in_sol_value,
+                out_sol_value,
+                in_range,
+                out_range,
+                protocol_fee_lst_amount,
+                fees,
+                not_enough_liquidity: total_dst_lst_out > dst.reserves_balance,
+            });
+            last_total_dst_lst_out = total_dst_lst_out;
+            hop_amount_in = dst_lst_out;
+        }
+
+        // route.len() >= 2 so there's at least one hop
+        let input = &route[0];
+        let output = &route[route.len() - 1];
+        let first_hop = &hops[0];
+        let last_hop = &hops[hops.len() - 1];
+
+        // only the route's input LST receives a net inflow.
+        // Protocol fees charged on intermediate hops are ignored, so this is slightly lenient
+        if let Some(input_lst_state) = input_lst_state {
+            input.verify_within_cap(
+                pool_state,
+                input_lst_state,
+                *amount,
+                output
+                    .sol_val_calc
+                    .lst_to_sol(last_total_dst_lst_out)?
+                    .get_min(),
+            )?;
+        }
+
+        let sol_value_before_fees = first_hop.in_sol_value;
+        let sol_value = sol_value_before_fees
+            .checked_sub(last_hop.out_sol_value)
+            .ok_or(SControllerError::PoolWouldLoseSolValue)?;
+        let protocol_sol_value = hops
+            .iter()
+            .try_fold(0u64, |sum, hop| {
+                sum.checked_add(hop.fees.protocol_sol_value)
+            })
+            .ok_or(SControllerError::MathError)?;
+        let fees = QuoteFees {
+            mint: output.mint(),
+            amount: output.sol_val_calc.sol_to_lst(sol_value)?.get_min(),
+            sol_value_before_fees,
+            sol_value,
+            protocol_sol_value,
+            // each hop's fees are charged on a SOL value that has already been
+            // rounded by the previous hop's conversions, so the sum of protocol fees
+            // may slightly exceed the route's total fees
+            lp_sol_value: sol_value.saturating_sub(protocol_sol_value),
+        };
+        Ok(SwapRouteQuote {
+            in_amount: *amount,
+            out_amount: hop_amount_in,
+            not_enough_liquidity: hops.iter().any(|hop| hop.not_enough_liquidity),
+            hops,
+            fees,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pricing_programs_interface::{
+        PriceExactInIxArgs, PriceExactInKeys, PriceExactOutIxArgs, PriceExactOutKeys,
+        PriceLpTokensToMintIxArgs, PriceLpTokensToRedeemIxArgs,
+    };
+    use s_controller_interface::SControllerError;
+    use s_pricing_prog_aggregate::PricingProg;
+    use solana_program::{instruction::AccountMeta, pubkey::Pubkey};
+
+    use crate::{
+        test_utils::{
+            mock_pool_state, mock_quote_lst, stale_lst_a, synced_lst_b, LST_A, LST_C, PRICING_PROG,
+            RESERVES_BALANCE,
+        },
+        QuoteFees, QuoteLst, QuoteMode, QuotePool, SwapQuoteRequest, SwapRouteQuote,
+        SwapRouteQuoteRequest,
+    };
+
+    fn synced_lst_c() -> QuoteLst<'static> {
+        mock_quote_lst(&LST_C, RESERVES_BALANCE, RESERVES_BALANCE * 4 / 5)
+    }
+
+    fn quote_pool() -> QuotePool<'static> {
+        QuotePool {
+            pool_state: mock_pool_state(3 * RESERVES_BALANCE),
+            pricing_prog: &PRICING_PROG,
+            lp_token_supply: None,
+        }
+    }
+
+    fn quote_route(route: &[QuoteLst], amount: u64) -> anyhow::Result<SwapRouteQuote> {
+        quote_pool().quote_swap_exact_in_route(&SwapRouteQuoteRequest { route, amount })
+    }
+
+    fn quote_route_with_pricing_prog(
+        route: &[QuoteLst],
+        amount: u64,
+        pricing_prog: &dyn PricingProg,
+    ) -> anyhow::Result<SwapRouteQuote> {
+        QuotePool {
+            pricing_prog,
+            ..quote_pool()
+        }
+        .quote_swap_exact_in_route(&SwapRouteQuoteRequest { route, amount })
+    }
+
+    /// Pricing program that pays a `bonus_bps` bonus on swaps out of `LST_A`
+    /// and charges `PRICING_PROG`'s fees on every other swap
+    #[derive(Clone, Copy, Debug)]
+    struct LstABonusPricingProg {
+        bonus_bps: u64,
+    }
+
+    impl PricingProg for LstABonusPricingProg {
+        fn quote_lp_tokens_to_redeem(
+            &self,
+            output_lst_mint: Pubkey,
+            args: &PriceLpTokensToRedeemIxArgs,
+        ) -> anyhow::Result<u64> {
+            PRICING_PROG.quote_lp_tokens_to_redeem(output_lst_mint, args)
+        }
+
+        fn price_lp_tokens_to_redeem_accounts(
+            &self,
+            output_lst_mint: Pubkey,
+        ) -> anyhow::Result<Vec<AccountMeta>> {
+            PRICING_PROG.price_lp_tokens_to_redeem_accounts(output_lst_mint)
+        }
+
+        fn quote_lp_tokens_to_mint(
+            &self,
+            input_lst_mint: Pubkey,
+            args: &PriceLpTokensToMintIxArgs,
+        ) -> anyhow::Result<u64> {
+            PRICING_PROG.quote_lp_tokens_to_mint(input_lst_mint, args)
+        }
+
+        fn price_lp_tokens_to_mint_accounts(
+            &self,
+            input_lst_mint: Pubkey,
+        ) -> anyhow::Result<Vec<AccountMeta>> {
+            PRICING_PROG.price_lp_tokens_to_mint_accounts(input_lst_mint)
+        }
+
+        fn quote_exact_in(
+            &self,
+            keys: PriceExactInKeys,
+            args: &PriceExactInIxArgs,
+        ) -> anyhow::Result<u64> {
+            if keys.input_lst_mint == LST_A.mint {
+                Ok(args.sol_value * (10_000 + self.bonus_bps) / 10_000)
+            } else {
+                PRICING_PROG.quote_exact_in(keys, args)
+            }
+        }
+
+        fn price_exact_in_accounts(
+            &self,
+            keys: PriceExactInKeys,
+        ) -> anyhow::Result<Vec<AccountMeta>> {
+            PRICING_PROG.price_exact_in_accounts(keys)
+        }
+
+        fn quote_exact_out(
+            &self,
+            keys: PriceExactOutKeys,
+            args: &PriceExactOutIxArgs,
+        ) -> anyhow::Result<u64> {
+            PRICING_PROG.quote_exact_out(keys, args)
+        }
+
+        fn price_exact_out_accounts(
+            &self,
+            keys: PriceExactOutKeys,
+        ) -> anyhow::Result<Vec<AccountMeta>> {
+            PRICING_PROG.price_exact_out_accounts(keys)
+        }
+    }
+
+    fn assert_err(res: anyhow::Result<SwapRouteQuote>, expected: SControllerError) {
+        assert_eq!(
+            res.unwrap_err().downcast_ref::<SControllerError>(),
+            Some(&expected)
+        );
+    }
+
+    #[test]
+    fn route_hops_match_swap_exact_in() {
+        let route = [stale_lst_a(), synced_lst_b(), synced_lst_c()];
+        let quote = quote_route(&route, 1_000_000_000).unwrap();
+        assert_eq!(quote.hops.len(), 2);
+        let mut amount = 1_000_000_000;
+        for (hop, lsts) in quote.hops.iter().zip(route.windows(2)) {
+            let swap_quote = quote_pool()
+                .quote_swap(&SwapQuoteRequest {
+                    input: lsts[0],
+                    output: lsts[1],
+                    amount,
+                    mode: QuoteMode::ExactIn,
+                })
+                .unwrap();
+            assert_eq!(*hop, swap_quote);
+            amount = swap_quote.out_amount;
+        }
+        assert_eq!(quote.in_amount, 1_000_000_000);
+        assert_eq!(quote.out_amount, amount);
+    }
+
+    #[test]
+    fn route_basic() {
+        let SwapRouteQuote {
+            in_amount,
+            out_amount,
+            hops,
+            fees,
+            not_enough_liquidity,
+        } = quote_route(
+            &[stale_lst_a(), synced_lst_b(), synced_lst_c()],
+            1_000_000_000,
+        )
+        .unwrap();
+        assert_eq!(in_amount, 1_000_000_000);
+        assert_eq!(hops[0].out_amount, 1_099_120_000);
+        assert_eq!(hops[1].in_amount, 1_099_120_000);
+        assert_eq!(hops[1].out_sol_value, 1_098_240_704);
+        assert_eq!(out_amount, 878_592_563);
+        assert_eq!(hops[0].protocol_fee_lst_amount, 88_000);
+        assert_eq!(hops[1].protocol_fee_lst_amount, 70_343);
+        assert_eq!(
+            fees,
+            QuoteFees {
+                mint: LST_C.mint,
+                amount: 1_407_436,
+                sol_value_before_fees: 1_100_000_000,
+                sol_value: 1_759_296,
+                protocol_sol_value: 175_930,
+                lp_sol_value: 1_583_366,
+            }
+        );
+        assert!(!not_enough_liquidity);
+    }
+
+    #[test]
+    fn route_hop_gaining_sol_value_offset_by_next_hop() {
+        let SwapRouteQuote {
+            out_amount,
+            hops,
+            fees,
+            ..
+        } = quote_route_with_pricing_prog(
+            &[stale_lst_a(), synced_lst_b(), synced_lst_c()],
+            1_000_000_000,
+            &LstABonusPricingProg { bonus_bps: 5 },
+        )
+        .unwrap();
+        // first hop gains SOL value
+        assert_eq!(hops[0].in_sol_value, 1_100_000_000);
+        assert_eq!(hops[0].out_sol_value, 1_100_550_000);
+        assert_eq!(hops[0].protocol_fee_lst_amount, 0);
+        assert_eq!(hops[0].fees.sol_value, 0);
+        // second hop loses more than the first hop gained
+        assert_eq!(hops[1].in_sol_value, 1_100_550_000);
+        assert_eq!(hops[1].out_sol_value, 1_099_669_560);
+        assert_eq!(out_amount, 879_735_648);
+        assert_eq!(fees.sol_value_before_fees, 1_100_000_000);
+        assert_eq!(fees.sol_value, 330_440);
+    }
+
+    #[test]
+    fn route_gaining_sol_value_fails() {
+        // first hop gains more than the second hop loses
+        assert_err(
+            quote_route_with_pricing_prog(
+                &[stale_lst_a(), synced_lst_b(), synced_lst_c()],
+                1_000_000_000,
+                &LstABonusPricingProg { bonus_bps: 20 },
+            ),
+            SControllerError::PoolWouldLoseSolValue,
+        );
+    }
+
+    #[test]
+    fn route_single_lst_fails() {
+        assert_err(
+            quote_route(&[stale_lst_a()], 1_000_000_000),
+            SControllerError::InvalidSwapRoute,
+        );
+    }
+
+    #[test]
+    fn route_repeated_lst_fails() {
+        assert_err(
+            quote_route(
+                &[stale_lst_a(), synced_lst_b(), stale_lst_a()],
+                1_000_000_000,
+            ),
+            SControllerError::SwapSameLst,
+        );
+    }
+
+    #[test]
+    fn route_zero_amount_fails() {
+        assert_err(
+            quote_route(&[stale_lst_a(), synced_lst_b(), synced_lst_c()], 0),
+            SControllerError::ZeroValue,
+        );
+    }
+
+    #[test]
+    fn route_through_empty_reserves_not_enough_liquidity() {
+        let quote = quote_route(
+            &[stale_lst_a(), mock_quote_lst(&LST_C, 0, 0), synced_lst_b()],
+            1_000_000_000,
+        )
+        .unwrap();
+        assert!(quote.hops[0].not_enough_liquidity);
+        assert!(!quote.hops[1].not_enough_liquidity);
+        assert!(quote.not_enough_liquidity);
+    }
+}
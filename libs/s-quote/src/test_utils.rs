@@ -0,0 +1,213 @@
+//! Mock SOL value calculator and pricing program with simple fixed rates,
+//! so that quotes can be checked against hand-computed program math
+
+use pricing_programs_interface::{
+    PriceExactInIxArgs, PriceExactInKeys, PriceExactOutIxArgs, PriceExactOutKeys,
+    PriceLpTokensToMintIxArgs, PriceLpTokensToRedeemIxArgs,
+};
+use s_controller_interface::{LstState, PoolState};
+use s_pricing_prog_aggregate::PricingProg;
+use s_sol_val_calc_prog_aggregate::LstSolValCalc;
+use sanctum_token_ratio::U64ValueRange;
+use solana_program::{instruction::AccountMeta, pubkey::Pubkey};
+
+use crate::QuoteLst;
+
+pub const TRADING_PROTOCOL_FEE_BPS: u16 = 1_000;
+
+pub const LP_PROTOCOL_FEE_BPS: u16 = 1_000;
+
+pub const PRICING_PROG: MockPricingProg = MockPricingProg { fee_bps: 8 };
+
+/// 1 LST = 1.1 SOL
+pub const LST_A: MockLstSolValCalc = MockLstSolValCalc {
+    mint: Pubkey::new_from_array([1u8; 32]),
+    sol: 11,
+    lst: 10,
+};
+
+/// 1 LST = 1 SOL
+pub const LST_B: MockLstSolValCalc = MockLstSolValCalc {
+    mint: Pubkey::new_from_array([2u8; 32]),
+    sol: 1,
+    lst: 1,
+};
+
+/// 1 LST = 1.25 SOL
+pub const LST_C: MockLstSolValCalc = MockLstSolValCalc {
+    mint: Pubkey::new_from_array([3u8; 32]),
+    sol: 5,
+    lst: 4,
+};
+
+pub const RESERVES_BALANCE: u64 = 10_000_000_000;
+
+/// `LST_A` with `RESERVES_BALANCE` reserves whose SOL value has not been synced yet.
+/// Its SOL value is 11 SOL after syncing
+pub fn stale_lst_a() -> QuoteLst<'static> {
+    mock_quote_lst(&LST_A, RESERVES_BALANCE, RESERVES_BALANCE)
+}
+
+/// `LST_B` with `RESERVES_BALANCE` reserves and SOL value
+pub fn synced_lst_b() -> QuoteLst<'static> {
+    mock_quote_lst(&LST_B, RESERVES_BALANCE, RESERVES_BALANCE)
+}
+
+/// LST whose `lst` atomics are worth `sol` lamports.
+///
+/// Like the SPL calculator, LstToSol rounds down while
+/// SolToLst returns the range between rounding down and rounding up
+#[derive(Clone, Copy, Debug)]
+pub struct MockLstSolValCalc {
+    pub mint: Pubkey,
+    pub sol: u64,
+    pub lst: u64,
+}
+
+fn floor_mul_div(amt: u64, num: u64, denom: u64) -> anyhow::Result<u64> {
+    let res = u128::from(amt) * u128::from(num) / u128::from(denom);
+    Ok(res.try_into()?)
+}
+
+fn ceil_mul_div(amt: u64, num: u64, denom: u64) -> anyhow::Result<u64> {
+    let res = (u128::from(amt) * u128::from(num)).div_ceil(u128::from(denom));
+    Ok(res.try_into()?)
+}
+
+impl LstSolValCalc for MockLstSolValCalc {
+    fn sol_value_calculator_program_id(&self) -> Pubkey {
+        Pubkey::default()
+    }
+
+    fn lst_mint(&self) -> Pubkey {
+        self.mint
+    }
+
+    fn lst_to_sol(&self, lst_amount: u64) -> anyhow::Result<U64ValueRange> {
+        Ok(U64ValueRange::single(floor_mul_div(
+            lst_amount, self.sol, self.lst,
+        )?))
+    }
+
+    fn sol_to_lst(&self, lamports: u64) -> anyhow::Result<U64ValueRange> {
+        Ok(U64ValueRange::from_min_max_unchecked(
+            floor_mul_div(lamports, self.lst, self.sol)?,
+            ceil_mul_div(lamports, self.lst, self.sol)?,
+        ))
+    }
+
+    fn ix_accounts(&self) -> Vec<AccountMeta> {
+        Vec::new()
+    }
+}
+
+/// Pricing program that charges the same `fee_bps` on every instruction
+#[derive(Clone, Copy, Debug)]
+pub struct MockPricingProg {
+    pub fee_bps: u16,
+}
+
+impl MockPricingProg {
+    fn after_fee(&self, sol_value: u64) -> anyhow::Result<u64> {
+        floor_mul_div(sol_value, 10_000 - u64::from(self.fee_bps), 10_000)
+    }
+}
+
+impl PricingProg for MockPricingProg {
+    fn quote_lp_tokens_to_redeem(
+        &self,
+        _output_lst_mint: Pubkey,
+        PriceLpTokensToRedeemIxArgs { sol_value, .. }: &PriceLpTokensToRedeemIxArgs,
+    ) -> anyhow::Result<u64> {
+        self.after_fee(*sol_value)
+    }
+
+    fn price_lp_tokens_to_redeem_accounts(
+        &self,
+        _output_lst_mint: Pubkey,
+    ) -> anyhow::Result<Vec<AccountMeta>> {
+        Ok(Vec::new())
+    }
+
+    fn quote_lp_tokens_to_mint(
+        &self,
+        _input_lst_mint: Pubkey,
+        PriceLpTokensToMintIxArgs { sol_value, .. }: &PriceLpTokensToMintIxArgs,
+    ) -> anyhow::Result<u64> {
+        self.after_fee(*sol_value)
+    }
+
+    fn price_lp_tokens_to_mint_accounts(
+        &self,
+        _input_lst_mint: Pubkey,
+    ) -> anyhow::Result<Vec<AccountMeta>> {
+        Ok(Vec::new())
+    }
+
+    fn quote_exact_in(
+        &self,
+        _keys: PriceExactInKeys,
+        PriceExactInIxArgs { sol_value, .. }: &PriceExactInIxArgs,
+    ) -> anyhow::Result<u64> {
+        self.after_fee(*sol_value)
+    }
+
+    fn price_exact_in_accounts(&self, _keys: PriceExactInKeys) -> anyhow::Result<Vec<AccountMeta>> {
+        Ok(Vec::new())
+    }
+
+    /// ceil(sol_value * 10_000 / (10_000 - fee_bps))
+    fn quote_exact_out(
+        &self,
+        _keys: PriceExactOutKeys,
+        PriceExactOutIxArgs { sol_value, .. }: &PriceExactOutIxArgs,
+    ) -> anyhow::Result<u64> {
+        ceil_mul_div(*sol_value, 10_000, 10_000 - u64::from(self.fee_bps))
+    }
+
+    fn price_exact_out_accounts(
+        &self,
+        _keys: PriceExactOutKeys,
+    ) -> anyhow::Result<Vec<AccountMeta>> {
+        Ok(Vec::new())
+    }
+}
+
+pub fn mock_pool_state(total_sol_value: u64) -> PoolState {
+    PoolState {
+        total_sol_value,
+        trading_protocol_fee_bps: TRADING_PROTOCOL_FEE_BPS,
+        lp_protocol_fee_bps: LP_PROTOCOL_FEE_BPS,
+        version: 0,
+        is_disabled: 0,
+        is_rebalancing: 0,
+        timelock_delay_hours: 0,
+        admin: Pubkey::default(),
+        rebalance_authority: Pubkey::default(),
+        protocol_fee_beneficiary: Pubkey::default(),
+        pricing_program: Pubkey::default(),
+        lp_token_mint: Pubkey::default(),
+    }
+}
+
+/// `sol_value` is the LST's SOL value before syncing
+pub fn mock_quote_lst(
+    sol_val_calc: &MockLstSolValCalc,
+    sol_value: u64,
+    reserves_balance: u64,
+) -> QuoteLst<'_> {
+    QuoteLst {
+        lst_state: LstState {
+            is_input_disabled: 0,
+            pool_reserves_bump: 0,
+            protocol_fee_accumulator_bump: 0,
+            padding: [0u8; 3],
+            max_share_bps: 0,
+            sol_value,
+            mint: sol_val_calc.mint,
+            sol_value_calculator: Pubkey::default(),
+        },
+        sol_val_calc,
+        reserves_balance,
+    }
+}
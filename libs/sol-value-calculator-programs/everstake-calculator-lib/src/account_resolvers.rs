@@ -0,0 +1,39 @@
+use borsh::BorshDeserialize;
+use everstake_calculator_interface::{AccountType, EverstakeStakePool};
+use everstake_keys::{esol, eversol_stake_pool};
+use generic_pool_calculator_interface::{GenericPoolCalculatorError, LST_TO_SOL_IX_ACCOUNTS_LEN};
+use generic_pool_calculator_lib::{
+    account_resolvers::LstSolCommonIntermediateKeys, GenericPoolSolValCalc,
+};
+use solana_program::instruction::AccountMeta;
+use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountOwner};
+
+use crate::EverstakeSolValCalc;
+
+pub const EVERSTAKE_LST_SOL_COMMON_INTERMEDIATE_KEYS: LstSolCommonIntermediateKeys =
+    LstSolCommonIntermediateKeys {
+        lst_mint: esol::ID,
+        pool_state: eversol_stake_pool::ID,
+    };
+
+pub fn everstake_sol_val_calc_account_metas() -> [AccountMeta; LST_TO_SOL_IX_ACCOUNTS_LEN] {
+    let everstake_sol_val_calc_keys: generic_pool_calculator_interface::SolToLstKeys =
+        EVERSTAKE_LST_SOL_COMMON_INTERMEDIATE_KEYS
+            .resolve::<EverstakeSolValCalc>()
+            .into();
+    everstake_sol_val_calc_keys.into()
+}
+
+pub fn deserialize_everstake_stake_pool_checked<S: ReadonlyAccountData + ReadonlyAccountOwner>(
+    stake_pool_account: S,
+) -> Result<EverstakeStakePool, GenericPoolCalculatorError> {
+    if *stake_pool_account.owner() != EverstakeSolValCalc::POOL_PROGRAM_ID {
+        return Err(GenericPoolCalculatorError::InvalidStakePoolProgramData);
+    }
+    let stake_pool = EverstakeStakePool::deserialize(&mut stake_pool_account.data().as_ref())
+        .map_err(|_e| GenericPoolCalculatorError::InvalidStakePoolProgramData)?;
+    if stake_pool.account_type != AccountType::StakePool {
+        return Err(GenericPoolCalculatorError::InvalidStakePoolProgramData);
+    }
+    Ok(stake_pool)
+}
@@ -0,0 +1,166 @@
+use everstake_calculator_interface::{EverstakeCalculatorError, EverstakeStakePool, Fee};
+use sanctum_token_ratio::{
+    CeilDiv, FloorDiv, MathError, ReversibleFee, ReversibleRatio, U64FeeRatio, U64Ratio,
+    U64ValueRange,
+};
+use sol_value_calculator_lib::SolValueCalculator;
+use solana_program::{clock::Clock, program_error::ProgramError};
+
+/// Parameters from EverstakeStakePool required to calculate SOL value
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct EverstakeStakePoolCalc {
+    pub last_update_epoch: u64,
+    pub total_lamports: u64,
+    pub pool_token_supply: u64,
+    pub stake_withdrawal_fee_numerator: u64,
+    pub stake_withdrawal_fee_denominator: u64,
+}
+
+impl From<&EverstakeStakePool> for EverstakeStakePoolCalc {
+    fn from(
+        EverstakeStakePool {
+            total_lamports,
+            pool_token_supply,
+            last_update_epoch,
+            stake_withdrawal_fee:
+                Fee {
+                    denominator,
+                    numerator,
+                },
+            ..
+        }: &EverstakeStakePool,
+    ) -> Self {
+        Self {
+            last_update_epoch: *last_update_epoch,
+            total_lamports: *total_lamports,
+            pool_token_supply: *pool_token_supply,
+            stake_withdrawal_fee_numerator: *numerator,
+            stake_withdrawal_fee_denominator: *denominator,
+        }
+    }
+}
+
+impl From<EverstakeStakePool> for EverstakeStakePoolCalc {
+    fn from(value: EverstakeStakePool) -> Self {
+        (&value).into()
+    }
+}
+
+impl EverstakeStakePoolCalc {
+    pub const fn verify_pool_updated_for_this_epoch(
+        &self,
+        clock: &Clock,
+    ) -> Result<(), EverstakeCalculatorError> {
+        if self.last_update_epoch == clock.epoch {
+            Ok(())
+        } else {
+            Err(EverstakeCalculatorError::PoolNotUpdated)
+        }
+    }
+
+    pub const fn lst_to_lamports_ratio(&self) -> FloorDiv<U64Ratio<u64, u64>> {
+        let Self {
+            total_lamports,
+            pool_token_supply,
+            ..
+        } = self;
+        FloorDiv(U64Ratio {
+            num: *total_lamports,
+            denom: *pool_token_supply,
+        })
+    }
+
+    pub fn stake_withdrawal_fee(&self) -> Result<CeilDiv<U64FeeRatio<u64, u64>>, MathError> {
+        U64FeeRatio::try_from_fee_num_and_denom(
+            self.stake_withdrawal_fee_numerator,
+            self.stake_withdrawal_fee_denominator,
+        )
+        .map(CeilDiv)
+    }
+}
+
+/// Assumes:
+/// - stake pool manager is always valid, so stake withdraw fee will always be charged
+/// - stake pool always has active and transient stake, so withdraw_source != StakeWithdrawSource::ValidatorRemoval
+/// - stake pool has been updated for this epoch
+///
+/// Withdrawal math is the same as SPL's: fees are applied on pool_tokens first
+/// and then amt_after_fee is converted to its lamports equivalent.
+///
+/// Both steps round in the pool's favour like SPL, i.e. the fee charged is rounded up
+/// and the lamports withdrawn are rounded down, so the SOL value is never overestimated
+/// regardless of the rounding directions of Everstake's fork.
+impl SolValueCalculator for EverstakeStakePoolCalc {
+    fn calc_lst_to_sol(&self, pool_tokens: u64) -> Result<U64ValueRange, ProgramError> {
+        let aaf = self.stake_withdrawal_fee()?.apply(pool_tokens)?;
+        let pool_tokens_burnt = aaf.amt_after_fee();
+        let withdraw_lamports = self.lst_to_lamports_ratio().apply(pool_tokens_burnt)?;
+        Ok(U64ValueRange::single(withdraw_lamports))
+    }
+
+    fn calc_sol_to_lst(&self, withdraw_lamports: u64) -> Result<U64ValueRange, ProgramError> {
+        let r = self.lst_to_lamports_ratio().reverse(withdraw_lamports)?;
+        let fee = self.stake_withdrawal_fee()?;
+        let min = fee.reverse_from_amt_after_fee(r.get_min())?.get_min();
+        let max = fee.reverse_from_amt_after_fee(r.get_max())?.get_max();
+        Ok(U64ValueRange::try_from_min_max(min, max)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    prop_compose! {
+        fn fee_rate_lte_one()
+            (denominator in any::<u64>())
+            (numerator in 0..=denominator, denominator in Just(denominator)) -> Fee {
+                Fee { denominator, numerator }
+            }
+    }
+
+    prop_compose! {
+        fn everstake_stake_pool_calc()
+            (Fee { denominator, numerator } in fee_rate_lte_one(), total_lamports: u64, pool_token_supply: u64) -> EverstakeStakePoolCalc {
+                EverstakeStakePoolCalc {
+                    last_update_epoch: 0,
+                    total_lamports,
+                    pool_token_supply,
+                    stake_withdrawal_fee_numerator: numerator,
+                    stake_withdrawal_fee_denominator: denominator,
+                }
+            }
+    }
+
+    prop_compose! {
+        fn everstake_stake_pool_and_lst_amount()
+            (calc in everstake_stake_pool_calc())
+            (pool_token in 0..=calc.pool_token_supply, calc in Just(calc)) -> (u64, EverstakeStakePoolCalc) {
+                (pool_token, calc)
+            }
+    }
+
+    proptest! {
+        #[test]
+        fn lst_sol_round_trip((pool_tokens, calc) in everstake_stake_pool_and_lst_amount()) {
+            let r = calc.calc_lst_to_sol(pool_tokens).unwrap();
+            let sol_amt = r.get_min();
+            let max_sol_amt = r.get_max();
+            prop_assert_eq!(sol_amt, max_sol_amt);
+            let r = calc.calc_sol_to_lst(sol_amt).unwrap();
+            let min = r.get_min();
+            let max = r.get_max();
+
+            // round trip from min should not exceed original
+            let min_round_trip = calc.calc_lst_to_sol(min).unwrap();
+            prop_assert!(sol_amt >= min_round_trip.get_min(), "{sol_amt} {}", min_round_trip.get_min());
+            prop_assert!(sol_amt >= min_round_trip.get_max(), "{sol_amt} {}", min_round_trip.get_max());
+
+            // round trip from max should not be smaller than original
+            let max_round_trip = calc.calc_lst_to_sol(max).unwrap();
+            prop_assert!(sol_amt <= max_round_trip.get_min(), "{sol_amt} {}", max_round_trip.get_min());
+            prop_assert!(sol_amt <= max_round_trip.get_max(), "{sol_amt} {}", max_round_trip.get_max());
+        }
+    }
+}
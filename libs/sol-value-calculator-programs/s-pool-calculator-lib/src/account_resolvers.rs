@@ -0,0 +1,112 @@
+use generic_pool_calculator_interface::{
+    GenericPoolCalculatorError, LstToSolKeys, LST_TO_SOL_IX_ACCOUNTS_LEN,
+};
+use generic_pool_calculator_lib::{
+    account_resolvers::{LstSolCommonIntermediateArgs, LstSolCommonIntermediateKeys},
+    GenericPoolSolValCalc,
+};
+use s_controller_interface::PoolState;
+use s_controller_lib::{try_pool_state, Pool};
+use solana_program::{instruction::AccountMeta, pubkey::Pubkey};
+use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountOwner, ReadonlyAccountPubkey};
+
+use crate::SPoolSolValCalc;
+
+/// Returns a copy of the PoolState of a S pool
+pub fn deserialize_s_pool_state_checked<
+    S: ReadonlyAccountData + ReadonlyAccountOwner + ReadonlyAccountPubkey,
+>(
+    pool_state: S,
+) -> Result<PoolState, GenericPoolCalculatorError> {
+    if *pool_state.owner() != SPoolSolValCalc::POOL_PROGRAM_ID {
+        return Err(GenericPoolCalculatorError::InvalidStakePoolProgramData);
+    }
+    // Other S controller accounts may have the same size as PoolState,
+    // so check that this is the pool state PDA of the pool it claims to be
+    Pool::try_from_pool_state(&pool_state)
+        .map_err(|_e| GenericPoolCalculatorError::WrongPoolAccountType)?;
+    let data = pool_state.data();
+    let pool_state =
+        try_pool_state(&data).map_err(|_e| GenericPoolCalculatorError::WrongPoolAccountType)?;
+    Ok(*pool_state)
+}
+
+pub struct SPoolLstSolCommonFreeArgs<
+    S: ReadonlyAccountPubkey + ReadonlyAccountData + ReadonlyAccountOwner,
+    Q: ReadonlyAccountPubkey + ReadonlyAccountData,
+> {
+    pub pool_state: S,
+    pub s_controller_prog: Q,
+}
+
+impl<
+        S: ReadonlyAccountPubkey + ReadonlyAccountData + ReadonlyAccountOwner,
+        Q: ReadonlyAccountPubkey + ReadonlyAccountData,
+    > SPoolLstSolCommonFreeArgs<S, Q>
+{
+    pub fn resolve(
+        self,
+    ) -> Result<(LstSolCommonIntermediateArgs<Q>, PoolState), GenericPoolCalculatorError> {
+        if *self.s_controller_prog.pubkey() != SPoolSolValCalc::POOL_PROGRAM_ID {
+            return Err(GenericPoolCalculatorError::WrongPoolProgram);
+        }
+        let pool_state = deserialize_s_pool_state_checked(&self.pool_state)?;
+        Ok((
+            LstSolCommonIntermediateArgs {
+                lst_mint: pool_state.lp_token_mint,
+                pool_state: *self.pool_state.pubkey(),
+                pool_program: self.s_controller_prog,
+            },
+            pool_state,
+        ))
+    }
+}
+
+/// Struct that uses defined const for POOL_PROGRAM_PROGDATA
+/// so that it can be used without fetching POOL_PROGRAM
+pub struct SPoolLstSolCommonFreeArgsConst<
+    S: ReadonlyAccountPubkey + ReadonlyAccountData + ReadonlyAccountOwner,
+> {
+    pub pool_state: S,
+}
+
+impl<S: ReadonlyAccountPubkey + ReadonlyAccountData + ReadonlyAccountOwner>
+    SPoolLstSolCommonFreeArgsConst<S>
+{
+    pub fn resolve(self) -> Result<LstSolCommonIntermediateKeys, GenericPoolCalculatorError> {
+        let pool_state = deserialize_s_pool_state_checked(&self.pool_state)?;
+        Ok(s_pool_lst_sol_common_intermediate_keys(
+            *self.pool_state.pubkey(),
+            pool_state.lp_token_mint,
+        ))
+    }
+
+    pub fn resolve_to_account_metas(
+        self,
+    ) -> Result<[AccountMeta; LST_TO_SOL_IX_ACCOUNTS_LEN], GenericPoolCalculatorError> {
+        let keys = self.resolve()?;
+        let keys: LstToSolKeys = keys.resolve::<SPoolSolValCalc>().into();
+        Ok(keys.into())
+    }
+}
+
+/// Use if the S pool's LP token mint is already known, e.g. from sanctum-lst-list
+pub const fn s_pool_lst_sol_common_intermediate_keys(
+    pool_state: Pubkey,
+    lp_token_mint: Pubkey,
+) -> LstSolCommonIntermediateKeys {
+    LstSolCommonIntermediateKeys {
+        lst_mint: lp_token_mint,
+        pool_state,
+    }
+}
+
+pub fn s_pool_sol_val_calc_account_metas(
+    pool_state: Pubkey,
+    lp_token_mint: Pubkey,
+) -> [AccountMeta; LST_TO_SOL_IX_ACCOUNTS_LEN] {
+    let keys: LstToSolKeys = s_pool_lst_sol_common_intermediate_keys(pool_state, lp_token_mint)
+        .resolve::<SPoolSolValCalc>()
+        .into();
+    keys.into()
+}
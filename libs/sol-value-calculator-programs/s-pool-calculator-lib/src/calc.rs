@@ -0,0 +1,116 @@
+use s_controller_interface::PoolState;
+use s_controller_lib::U8Bool;
+use s_pool_calculator_interface::SPoolCalculatorError;
+use sanctum_token_ratio::{FloorDiv, ReversibleRatio, U64Ratio, U64ValueRange};
+use sol_value_calculator_lib::SolValueCalculator;
+use solana_program::program_error::ProgramError;
+
+/// Parameters from the S pool's PoolState and LP token mint required to calculate SOL value
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SPoolLpCalc {
+    pub pool_total_sol_value: u64,
+    pub lp_token_supply: u64,
+    pub is_rebalancing: bool,
+}
+
+impl SPoolLpCalc {
+    pub const fn from_pool_state(pool_state: &PoolState, lp_token_supply: u64) -> Self {
+        Self {
+            pool_total_sol_value: pool_state.total_sol_value,
+            lp_token_supply,
+            is_rebalancing: U8Bool(pool_state.is_rebalancing).is_true(),
+        }
+    }
+
+    /// total_sol_value is decremented by StartRebalance and only restored by EndRebalance,
+    /// so it cannot be relied on while a rebalance is in progress
+    pub const fn verify_pool_not_rebalancing(&self) -> Result<(), SPoolCalculatorError> {
+        if self.is_rebalancing {
+            Err(SPoolCalculatorError::PoolRebalancing)
+        } else {
+            Ok(())
+        }
+    }
+
+    pub const fn lp_token_to_lamports_ratio(&self) -> FloorDiv<U64Ratio<u64, u64>> {
+        FloorDiv(U64Ratio {
+            num: self.pool_total_sol_value,
+            denom: self.lp_token_supply,
+        })
+    }
+}
+
+/// Assumes:
+/// - pool is not rebalancing
+/// - total_sol_value of the pool is up to date. It is only as fresh as the pool's last SyncSolValue.
+impl SolValueCalculator for SPoolLpCalc {
+    // Reference: `calc_lp_tokens_sol_value()` used in `process_remove_liquidity()`
+    fn calc_lst_to_sol(&self, lp_token_amount: u64) -> Result<U64ValueRange, ProgramError> {
+        let lamports = self.lp_token_to_lamports_ratio().apply(lp_token_amount)?;
+        Ok(U64ValueRange::single(lamports))
+    }
+
+    fn calc_sol_to_lst(&self, lamports: u64) -> Result<U64ValueRange, ProgramError> {
+        Ok(self.lp_token_to_lamports_ratio().reverse(lamports)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    prop_compose! {
+        fn s_pool_lp_calc()
+            (pool_total_sol_value: u64, lp_token_supply: u64) -> SPoolLpCalc {
+                SPoolLpCalc {
+                    pool_total_sol_value,
+                    lp_token_supply,
+                    is_rebalancing: false,
+                }
+            }
+    }
+
+    prop_compose! {
+        fn s_pool_lp_calc_and_lp_token_amount()
+            (calc in s_pool_lp_calc())
+            (lp_token_amount in 0..=calc.lp_token_supply, calc in Just(calc)) -> (u64, SPoolLpCalc) {
+                (lp_token_amount, calc)
+            }
+    }
+
+    proptest! {
+        #[test]
+        fn lst_sol_round_trip((lp_token_amount, calc) in s_pool_lp_calc_and_lp_token_amount()) {
+            let r = calc.calc_lst_to_sol(lp_token_amount).unwrap();
+            let sol_amt = r.get_min();
+            let max_sol_amt = r.get_max();
+            prop_assert_eq!(sol_amt, max_sol_amt);
+            let r = calc.calc_sol_to_lst(sol_amt).unwrap();
+            let min = r.get_min();
+            let max = r.get_max();
+
+            // round trip from min should not exceed original
+            let min_round_trip = calc.calc_lst_to_sol(min).unwrap();
+            prop_assert!(sol_amt >= min_round_trip.get_min(), "{sol_amt} {}", min_round_trip.get_min());
+            prop_assert!(sol_amt >= min_round_trip.get_max(), "{sol_amt} {}", min_round_trip.get_max());
+
+            // round trip from max should not be smaller than original
+            let max_round_trip = calc.calc_lst_to_sol(max).unwrap();
+            prop_assert!(sol_amt <= max_round_trip.get_min(), "{sol_amt} {}", max_round_trip.get_min());
+            prop_assert!(sol_amt <= max_round_trip.get_max(), "{sol_amt} {}", max_round_trip.get_max());
+        }
+
+        #[test]
+        fn matches_s_controller_lp_token_sol_value((lp_token_amount, calc) in s_pool_lp_calc_and_lp_token_amount()) {
+            let expected = s_controller_lib::calc_lp_tokens_sol_value(
+                s_controller_lib::LpTokenRateArgs {
+                    lp_token_supply: calc.lp_token_supply,
+                    pool_total_sol_value: calc.pool_total_sol_value,
+                },
+                lp_token_amount,
+            ).unwrap();
+            prop_assert_eq!(calc.calc_lst_to_sol(lp_token_amount).unwrap(), U64ValueRange::single(expected));
+        }
+    }
+}
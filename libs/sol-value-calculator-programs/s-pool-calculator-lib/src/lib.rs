@@ -0,0 +1,32 @@
+use generic_pool_calculator_lib::GenericPoolSolValCalc;
+use solana_program::pubkey::Pubkey;
+
+mod account_resolvers;
+mod calc;
+mod instructions;
+
+pub use account_resolvers::*;
+pub use calc::*;
+pub use instructions::*;
+
+pub mod program {
+    sanctum_macros::declare_program_keys!(
+        "sPoovfVH7XqxkLT4qerui5JJ4VTv4zZJ1hes1He1SuJ",
+        [("s_pool_calculator_state", b"state")]
+    );
+}
+
+pub mod initial_manager {
+    sanctum_macros::declare_program_keys!("CK9cEJT7K7oRrMCcEbBQRGqHLGpxKXWnKvW7nHSDMHD1", []);
+}
+
+/// Values the LP token of a S pool, so that it can be held as an LST by another S pool
+pub struct SPoolSolValCalc;
+
+impl GenericPoolSolValCalc for SPoolSolValCalc {
+    const POOL_PROGRAM_ID: Pubkey = s_controller_lib::program::ID;
+    const POOL_PROGRAM_PROGDATA_ID: Pubkey = s_controller_lib::program_progdata::ID;
+    const CALCULATOR_STATE_PDA: Pubkey = program::S_POOL_CALCULATOR_STATE_ID;
+    const CALCULATOR_STATE_BUMP: u8 = program::S_POOL_CALCULATOR_STATE_BUMP;
+    const ID: Pubkey = program::ID;
+}
@@ -0,0 +1,39 @@
+use borsh::BorshDeserialize;
+use generic_pool_calculator_interface::{GenericPoolCalculatorError, LST_TO_SOL_IX_ACCOUNTS_LEN};
+use generic_pool_calculator_lib::{
+    account_resolvers::LstSolCommonIntermediateKeys, GenericPoolSolValCalc,
+};
+use socean_calculator_interface::{AccountType, SoceanStakePool};
+use socean_keys::{scnsol, socean_stake_pool};
+use solana_program::instruction::AccountMeta;
+use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountOwner};
+
+use crate::SoceanSolValCalc;
+
+pub const SOCEAN_LST_SOL_COMMON_INTERMEDIATE_KEYS: LstSolCommonIntermediateKeys =
+    LstSolCommonIntermediateKeys {
+        lst_mint: scnsol::ID,
+        pool_state: socean_stake_pool::ID,
+    };
+
+pub fn socean_sol_val_calc_account_metas() -> [AccountMeta; LST_TO_SOL_IX_ACCOUNTS_LEN] {
+    let socean_sol_val_calc_keys: generic_pool_calculator_interface::SolToLstKeys =
+        SOCEAN_LST_SOL_COMMON_INTERMEDIATE_KEYS
+            .resolve::<SoceanSolValCalc>()
+            .into();
+    socean_sol_val_calc_keys.into()
+}
+
+pub fn deserialize_socean_stake_pool_checked<S: ReadonlyAccountData + ReadonlyAccountOwner>(
+    stake_pool_account: S,
+) -> Result<SoceanStakePool, GenericPoolCalculatorError> {
+    if *stake_pool_account.owner() != SoceanSolValCalc::POOL_PROGRAM_ID {
+        return Err(GenericPoolCalculatorError::InvalidStakePoolProgramData);
+    }
+    let stake_pool = SoceanStakePool::deserialize(&mut stake_pool_account.data().as_ref())
+        .map_err(|_e| GenericPoolCalculatorError::InvalidStakePoolProgramData)?;
+    if stake_pool.account_type != AccountType::StakePool {
+        return Err(GenericPoolCalculatorError::InvalidStakePoolProgramData);
+    }
+    Ok(stake_pool)
+}
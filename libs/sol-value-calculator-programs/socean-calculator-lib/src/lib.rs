@@ -0,0 +1,32 @@
+use generic_pool_calculator_lib::GenericPoolSolValCalc;
+use socean_keys::{socean_program, socean_program_progdata};
+use solana_program::pubkey::Pubkey;
+
+mod account_resolvers;
+mod calc;
+mod instructions;
+
+pub use account_resolvers::*;
+pub use calc::*;
+pub use instructions::*;
+
+pub mod program {
+    sanctum_macros::declare_program_keys!(
+        "socnX3vE1tuaBairRYjECCzm7NUMETHyoQnjZ9Ma8Sf",
+        [("socean_calculator_state", b"state")]
+    );
+}
+
+pub mod initial_manager {
+    sanctum_macros::declare_program_keys!("CK9cEJT7K7oRrMCcEbBQRGqHLGpxKXWnKvW7nHSDMHD1", []);
+}
+
+pub struct SoceanSolValCalc;
+
+impl GenericPoolSolValCalc for SoceanSolValCalc {
+    const POOL_PROGRAM_ID: Pubkey = socean_program::ID;
+    const POOL_PROGRAM_PROGDATA_ID: Pubkey = socean_program_progdata::ID;
+    const CALCULATOR_STATE_PDA: Pubkey = program::SOCEAN_CALCULATOR_STATE_ID;
+    const CALCULATOR_STATE_BUMP: u8 = program::SOCEAN_CALCULATOR_STATE_BUMP;
+    const ID: Pubkey = program::ID;
+}
@@ -0,0 +1,111 @@
+use borsh::BorshDeserialize;
+use generic_pool_calculator_interface::{
+    GenericPoolCalculatorError, LstToSolKeys, LST_TO_SOL_IX_ACCOUNTS_LEN,
+};
+use generic_pool_calculator_lib::{
+    account_resolvers::{LstSolCommonIntermediateArgs, LstSolCommonIntermediateKeys},
+    GenericPoolSolValCalc,
+};
+use solana_program::{
+    instruction::AccountMeta,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    stake::{self, state::StakeStateV2},
+};
+use solana_readonly_account::{ReadonlyAccountData, ReadonlyAccountOwner, ReadonlyAccountPubkey};
+use svsp_calculator_interface::{SinglePoolAccountType, SvspCalculatorError, SvspPool};
+
+use crate::{find_svsp_pool_mint, find_svsp_pool_stake, SvspSolValCalc};
+
+/// LstToSol and SolToLst take the pool stake account as an additional
+/// account after the generic pool calculator interface's accounts
+pub const SVSP_LST_SOL_COMMON_ACCOUNTS_LEN: usize = LST_TO_SOL_IX_ACCOUNTS_LEN + 1;
+
+pub fn deserialize_svsp_pool_checked<S: ReadonlyAccountData + ReadonlyAccountOwner>(
+    svsp_pool: S,
+) -> Result<SvspPool, GenericPoolCalculatorError> {
+    if *svsp_pool.owner() != SvspSolValCalc::POOL_PROGRAM_ID {
+        return Err(GenericPoolCalculatorError::InvalidStakePoolProgramData);
+    }
+    let pool = SvspPool::deserialize(&mut svsp_pool.data().as_ref())
+        .map_err(|_e| GenericPoolCalculatorError::InvalidStakePoolProgramData)?;
+    if pool.account_type != SinglePoolAccountType::Pool {
+        return Err(GenericPoolCalculatorError::WrongPoolAccountType);
+    }
+    Ok(pool)
+}
+
+/// Reads `delegation.stake` of a SVSP pool stake account
+pub fn read_svsp_pool_stake<S: ReadonlyAccountData + ReadonlyAccountOwner>(
+    pool_stake: S,
+) -> Result<u64, ProgramError> {
+    if *pool_stake.owner() != stake::program::ID {
+        return Err(GenericPoolCalculatorError::InvalidStakePoolProgramData.into());
+    }
+    read_pool_stake_delegation(pool_stake)
+}
+
+/// NB: does not check owner of the stake account
+pub fn read_pool_stake_delegation<D: ReadonlyAccountData>(
+    pool_stake: D,
+) -> Result<u64, ProgramError> {
+    let stake_state = StakeStateV2::deserialize(&mut pool_stake.data().as_ref())
+        .map_err(|_e| GenericPoolCalculatorError::InvalidStakePoolProgramData)?;
+    let delegation = stake_state
+        .delegation()
+        .ok_or(SvspCalculatorError::PoolStakeNotDelegated)?;
+    Ok(delegation.stake)
+}
+
+pub struct SvspLstSolCommonFreeArgs<
+    S: ReadonlyAccountPubkey + ReadonlyAccountData + ReadonlyAccountOwner,
+    Q: ReadonlyAccountPubkey + ReadonlyAccountData,
+> {
+    pub svsp_pool: S,
+    pub svsp_prog: Q,
+}
+
+impl<
+        S: ReadonlyAccountPubkey + ReadonlyAccountData + ReadonlyAccountOwner,
+        Q: ReadonlyAccountPubkey + ReadonlyAccountData,
+    > SvspLstSolCommonFreeArgs<S, Q>
+{
+    pub fn resolve(self) -> Result<LstSolCommonIntermediateArgs<Q>, GenericPoolCalculatorError> {
+        if *self.svsp_prog.pubkey() != SvspSolValCalc::POOL_PROGRAM_ID {
+            return Err(GenericPoolCalculatorError::WrongPoolProgram);
+        }
+        deserialize_svsp_pool_checked(&self.svsp_pool)?;
+        let pool = *self.svsp_pool.pubkey();
+        Ok(LstSolCommonIntermediateArgs {
+            lst_mint: find_svsp_pool_mint(&pool).0,
+            pool_state: pool,
+            pool_program: self.svsp_prog,
+        })
+    }
+}
+
+/// Every other account is a PDA of the pool, so no accounts need to be fetched
+pub fn svsp_lst_sol_common_intermediate_keys(pool: Pubkey) -> LstSolCommonIntermediateKeys {
+    LstSolCommonIntermediateKeys {
+        lst_mint: find_svsp_pool_mint(&pool).0,
+        pool_state: pool,
+    }
+}
+
+pub fn svsp_sol_val_calc_account_metas(
+    pool: Pubkey,
+) -> [AccountMeta; SVSP_LST_SOL_COMMON_ACCOUNTS_LEN] {
+    let keys: LstToSolKeys = svsp_lst_sol_common_intermediate_keys(pool)
+        .resolve::<SvspSolValCalc>()
+        .into();
+    let [lst_mint, state, pool_state, pool_program, pool_program_data]: [AccountMeta;
+        LST_TO_SOL_IX_ACCOUNTS_LEN] = keys.into();
+    [
+        lst_mint,
+        state,
+        pool_state,
+        pool_program,
+        pool_program_data,
+        AccountMeta::new_readonly(find_svsp_pool_stake(&pool).0, false),
+    ]
+}
@@ -0,0 +1,91 @@
+use sanctum_token_ratio::{FloorDiv, ReversibleRatio, U64Ratio, U64ValueRange};
+use sol_value_calculator_lib::SolValueCalculator;
+use solana_program::{native_token::LAMPORTS_PER_SOL, program_error::ProgramError};
+
+/// Lamports that the SVSP program never allows to be withdrawn from the pool stake account,
+/// `max(stake_program_minimum_delegation, LAMPORTS_PER_SOL)`.
+///
+/// Hardcoded since the stake program's minimum delegation is currently below 1 SOL
+/// and reading it requires a CPI.
+pub const SVSP_MINIMUM_POOL_BALANCE: u64 = LAMPORTS_PER_SOL;
+
+/// Parameters from the SVSP pool stake account and LST mint required to calculate SOL value
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SvspStakeCalc {
+    /// `delegation.stake` of the pool stake account
+    pub pool_stake: u64,
+    pub lst_supply: u64,
+}
+
+/// Reference
+/// https://github.com/solana-labs/solana-program-library/blob/master/single-pool/program/src/processor.rs
+impl SvspStakeCalc {
+    /// Net excess value: stake that is withdrawable by LST holders
+    pub const fn total_nev(&self) -> u64 {
+        self.pool_stake.saturating_sub(SVSP_MINIMUM_POOL_BALANCE)
+    }
+
+    pub const fn lst_to_lamports_ratio(&self) -> FloorDiv<U64Ratio<u64, u64>> {
+        FloorDiv(U64Ratio {
+            num: self.total_nev(),
+            denom: self.lst_supply,
+        })
+    }
+}
+
+impl SolValueCalculator for SvspStakeCalc {
+    // Reference: `calculate_withdraw_amount()` used in `process_withdraw_stake()`
+    fn calc_lst_to_sol(&self, lst_amount: u64) -> Result<U64ValueRange, ProgramError> {
+        let lamports = self.lst_to_lamports_ratio().apply(lst_amount)?;
+        Ok(U64ValueRange::single(lamports))
+    }
+
+    fn calc_sol_to_lst(&self, lamports: u64) -> Result<U64ValueRange, ProgramError> {
+        Ok(self.lst_to_lamports_ratio().reverse(lamports)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    prop_compose! {
+        fn svsp_calc()
+            (
+                pool_stake in (SVSP_MINIMUM_POOL_BALANCE + 1)..=u64::MAX,
+                lst_supply in 1..=u64::MAX,
+            ) -> SvspStakeCalc {
+                SvspStakeCalc {
+                    pool_stake,
+                    lst_supply,
+                }
+            }
+    }
+
+    prop_compose! {
+        fn svsp_calc_and_lst_amount()
+            (calc in svsp_calc())
+            (lst_amount in 0..=calc.lst_supply, calc in Just(calc)) -> (u64, SvspStakeCalc) {
+                (lst_amount, calc)
+            }
+    }
+
+    proptest! {
+        #[test]
+        fn lst_sol_round_trip((lst_amount, calc) in svsp_calc_and_lst_amount()) {
+            let r = calc.calc_lst_to_sol(lst_amount).unwrap();
+            let sol_amt = r.get_min();
+            prop_assert_eq!(sol_amt, r.get_max());
+            let r = calc.calc_sol_to_lst(sol_amt).unwrap();
+
+            // round trip from min should not exceed original
+            let min_round_trip = calc.calc_lst_to_sol(r.get_min()).unwrap();
+            prop_assert!(sol_amt >= min_round_trip.get_max(), "{sol_amt} {}", min_round_trip.get_max());
+
+            // round trip from max should not be smaller than original
+            let max_round_trip = calc.calc_lst_to_sol(r.get_max()).unwrap();
+            prop_assert!(sol_amt <= max_round_trip.get_min(), "{sol_amt} {}", max_round_trip.get_min());
+        }
+    }
+}
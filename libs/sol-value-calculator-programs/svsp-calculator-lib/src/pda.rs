@@ -0,0 +1,24 @@
+use generic_pool_calculator_lib::GenericPoolSolValCalc;
+use solana_program::pubkey::Pubkey;
+
+use crate::SvspSolValCalc;
+
+pub const SVSP_POOL_MINT_SEED: &[u8] = b"mint";
+
+pub const SVSP_POOL_STAKE_SEED: &[u8] = b"stake";
+
+/// LST mint of a SVSP pool, located at SVSP PDA ["mint", pool]
+pub fn find_svsp_pool_mint(pool: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[SVSP_POOL_MINT_SEED, pool.as_ref()],
+        &SvspSolValCalc::POOL_PROGRAM_ID,
+    )
+}
+
+/// The single stake account of a SVSP pool, located at SVSP PDA ["stake", pool]
+pub fn find_svsp_pool_stake(pool: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[SVSP_POOL_STAKE_SEED, pool.as_ref()],
+        &SvspSolValCalc::POOL_PROGRAM_ID,
+    )
+}
@@ -0,0 +1,41 @@
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+use target_weight_interface::TargetWeightProgramIx;
+
+use crate::processor::*;
+
+#[cfg(not(feature = "no-entrypoint"))]
+solana_program::entrypoint!(process_instruction);
+
+pub fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    if *program_id != target_weight_lib::program::ID {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    let ix = TargetWeightProgramIx::deserialize(instruction_data)?;
+    solana_program::msg!("{:?}", ix);
+
+    match ix {
+        TargetWeightProgramIx::PriceExactIn(args) => process_price_exact_in(accounts, args),
+        TargetWeightProgramIx::PriceExactOut(args) => process_price_exact_out(accounts, args),
+        TargetWeightProgramIx::PriceLpTokensToMint(args) => {
+            process_price_lp_tokens_to_mint(accounts, args)
+        }
+        TargetWeightProgramIx::PriceLpTokensToRedeem(args) => {
+            process_price_lp_tokens_to_redeem(accounts, args)
+        }
+        TargetWeightProgramIx::SetPool => process_set_pool(accounts),
+        TargetWeightProgramIx::SetFeeParams(args) => process_set_fee_params(accounts, args),
+        TargetWeightProgramIx::SetLstWeight(args) => process_set_lst_weight(accounts, args),
+        TargetWeightProgramIx::RemoveLst => process_remove_lst(accounts),
+        TargetWeightProgramIx::AddLst(args) => process_add_lst(accounts, args),
+        TargetWeightProgramIx::SetManager => process_set_manager(accounts),
+        TargetWeightProgramIx::Initialize => process_initialize(accounts),
+    }
+}
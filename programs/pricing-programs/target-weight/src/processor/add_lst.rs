@@ -0,0 +1,104 @@
+use sanctum_misc_utils::{
+    load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
+};
+use sanctum_s_common::token::verify_tokenkeg_or_22_mint;
+use sanctum_system_program_lib::{
+    init_rent_exempt_account_invoke_signed, InitRentExemptAccountArgs,
+};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+};
+use system_program_interface::CreateAccountAccounts;
+use target_weight_interface::{
+    add_lst_verify_account_keys, add_lst_verify_account_privileges, AddLstAccounts, AddLstIxArgs,
+    AddLstKeys, TargetWeightError,
+};
+use target_weight_lib::{
+    account_resolvers::AddLstFreeArgs,
+    fee_bound::verify_total_target_weight_bound,
+    pda::WeightAccountCreatePdaArgs,
+    program,
+    utils::{try_program_state, try_program_state_mut, try_weight_account_mut},
+};
+
+pub fn process_add_lst(accounts: &[AccountInfo], args: AddLstIxArgs) -> ProgramResult {
+    let (
+        AddLstAccounts {
+            payer,
+            weight_acc,
+            state,
+            ..
+        },
+        AddLstIxArgs { target_weight_bps },
+        create_pda_args,
+        total_target_weight_bps,
+    ) = verify_add_lst(accounts, args)?;
+
+    init_rent_exempt_account_invoke_signed(
+        CreateAccountAccounts {
+            from: payer,
+            to: weight_acc,
+        },
+        InitRentExemptAccountArgs {
+            space: program::WEIGHT_ACCOUNT_SIZE,
+            owner: program::ID,
+        },
+        &[create_pda_args.to_signer_seeds().as_slice()],
+    )?;
+
+    let mut bytes = weight_acc.try_borrow_mut_data()?;
+    let weight_acc = try_weight_account_mut(&mut bytes)?;
+
+    weight_acc.bump = create_pda_args.bump;
+    weight_acc.target_weight_bps = target_weight_bps;
+
+    let mut bytes = state.try_borrow_mut_data()?;
+    let state = try_program_state_mut(&mut bytes)?;
+
+    state.total_target_weight_bps = total_target_weight_bps;
+
+    Ok(())
+}
+
+/// Returns the new `total_target_weight_bps` as the last tuple element
+fn verify_add_lst<'me, 'info>(
+    accounts: &'me [AccountInfo<'info>],
+    args: AddLstIxArgs,
+) -> Result<
+    (
+        AddLstAccounts<'me, 'info>,
+        AddLstIxArgs,
+        WeightAccountCreatePdaArgs,
+        u16,
+    ),
+    ProgramError,
+> {
+    let actual: AddLstAccounts = load_accounts(accounts)?;
+
+    let free_args = AddLstFreeArgs {
+        payer: *actual.payer.key,
+        state_acc: actual.state,
+        lst_mint: *actual.lst_mint.key,
+    };
+    let (expected, weight_account_create_pda_args): (AddLstKeys, WeightAccountCreatePdaArgs) =
+        free_args.resolve()?;
+
+    add_lst_verify_account_keys(actual, expected).map_err(log_and_return_wrong_acc_err)?;
+    add_lst_verify_account_privileges(actual).map_err(log_and_return_acc_privilege_err)?;
+
+    verify_tokenkeg_or_22_mint(actual.lst_mint)?;
+
+    let state_bytes = actual.state.try_borrow_data()?;
+    let total_target_weight_bps = try_program_state(&state_bytes)?
+        .total_target_weight_bps
+        .checked_add(args.target_weight_bps)
+        .ok_or(TargetWeightError::TotalTargetWeightOutOfBound)?;
+    verify_total_target_weight_bound(total_target_weight_bps)?;
+
+    Ok((
+        actual,
+        args,
+        weight_account_create_pda_args,
+        total_target_weight_bps,
+    ))
+}
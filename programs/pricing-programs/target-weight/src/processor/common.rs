@@ -0,0 +1,74 @@
+use s_controller_lib::{try_lst_state_list, try_pool_state};
+use solana_program::{account_info::AccountInfo, program_error::ProgramError};
+use target_weight_interface::{ProgramState, TargetWeightError};
+use target_weight_lib::{
+    calc::{LstReserves, SwapFeeParams, SwapReserves},
+    utils::{find_lst_sol_value, try_program_state, try_weight_account},
+};
+
+/// Accounts common to `PriceExactIn` and `PriceExactOut`
+#[derive(Clone, Copy)]
+pub struct SwapPricingAccounts<'me, 'info> {
+    pub input_lst_mint: &'me AccountInfo<'info>,
+    pub output_lst_mint: &'me AccountInfo<'info>,
+    pub state: &'me AccountInfo<'info>,
+    pub input_weight_acc: &'me AccountInfo<'info>,
+    pub output_weight_acc: &'me AccountInfo<'info>,
+    pub pool_state: &'me AccountInfo<'info>,
+    pub lst_state_list: &'me AccountInfo<'info>,
+}
+
+pub fn verify_pool_accounts(
+    state: &ProgramState,
+    pool_state: &AccountInfo,
+    lst_state_list: &AccountInfo,
+) -> Result<(), TargetWeightError> {
+    if *pool_state.key != state.pool_state || *lst_state_list.key != state.lst_state_list {
+        return Err(TargetWeightError::IncorrectPoolAccounts);
+    }
+    Ok(())
+}
+
+/// Reads the fee params and the input and output reserves of the pool from verified accounts
+pub fn load_swap_pricing_state(
+    SwapPricingAccounts {
+        input_lst_mint,
+        output_lst_mint,
+        state,
+        input_weight_acc,
+        output_weight_acc,
+        pool_state,
+        lst_state_list,
+    }: SwapPricingAccounts,
+) -> Result<(SwapFeeParams, SwapReserves), ProgramError> {
+    let state_bytes = state.try_borrow_data()?;
+    let fee_params = SwapFeeParams::from(try_program_state(&state_bytes)?);
+
+    let input_weight_acc_bytes = input_weight_acc.try_borrow_data()?;
+    let input_target_weight_bps = try_weight_account(&input_weight_acc_bytes)?.target_weight_bps;
+    let output_weight_acc_bytes = output_weight_acc.try_borrow_data()?;
+    let output_target_weight_bps = try_weight_account(&output_weight_acc_bytes)?.target_weight_bps;
+
+    let pool_state_bytes = pool_state.try_borrow_data()?;
+    let pool_total_sol_value = try_pool_state(&pool_state_bytes)?.total_sol_value;
+
+    let lst_state_list_bytes = lst_state_list.try_borrow_data()?;
+    let lst_state_list = try_lst_state_list(&lst_state_list_bytes)?;
+    let input_sol_value = find_lst_sol_value(lst_state_list, input_lst_mint.key)?;
+    let output_sol_value = find_lst_sol_value(lst_state_list, output_lst_mint.key)?;
+
+    Ok((
+        fee_params,
+        SwapReserves {
+            pool_total_sol_value,
+            input: LstReserves {
+                sol_value: input_sol_value,
+                target_weight_bps: input_target_weight_bps,
+            },
+            output: LstReserves {
+                sol_value: output_sol_value,
+                target_weight_bps: output_target_weight_bps,
+            },
+        },
+    ))
+}
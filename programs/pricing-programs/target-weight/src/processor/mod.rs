@@ -0,0 +1,24 @@
+mod add_lst;
+mod common;
+mod initialize;
+mod price_exact_in;
+mod price_exact_out;
+mod price_lp_tokens_to_mint;
+mod price_lp_tokens_to_redeem;
+mod remove_lst;
+mod set_fee_params;
+mod set_lst_weight;
+mod set_manager;
+mod set_pool;
+
+pub use add_lst::*;
+pub use initialize::*;
+pub use price_exact_in::*;
+pub use price_exact_out::*;
+pub use price_lp_tokens_to_mint::*;
+pub use price_lp_tokens_to_redeem::*;
+pub use remove_lst::*;
+pub use set_fee_params::*;
+pub use set_lst_weight::*;
+pub use set_manager::*;
+pub use set_pool::*;
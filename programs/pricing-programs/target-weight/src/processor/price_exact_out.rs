@@ -0,0 +1,82 @@
+use sanctum_misc_utils::{load_accounts, log_and_return_wrong_acc_err};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program::set_return_data,
+    program_error::ProgramError,
+};
+use target_weight_interface::{
+    price_exact_out_verify_account_keys, PriceExactOutAccounts, PriceExactOutIxArgs,
+    PriceExactOutKeys,
+};
+use target_weight_lib::{
+    account_resolvers::{PriceExactOutFreeArgs, PriceExactOutWithBumpFreeArgs},
+    calc::{calculate_price_exact_out, CalculatePriceExactOutArgs},
+    utils::{try_program_state, try_weight_account},
+};
+
+use super::common::{load_swap_pricing_state, verify_pool_accounts, SwapPricingAccounts};
+
+pub fn process_price_exact_out(
+    accounts: &[AccountInfo],
+    PriceExactOutIxArgs { sol_value, .. }: PriceExactOutIxArgs,
+) -> ProgramResult {
+    let PriceExactOutAccounts {
+        input_lst_mint,
+        output_lst_mint,
+        state,
+        input_weight_acc,
+        output_weight_acc,
+        pool_state,
+        lst_state_list,
+    } = verify_price_exact_out(accounts)?;
+
+    let (fee_params, reserves) = load_swap_pricing_state(SwapPricingAccounts {
+        input_lst_mint,
+        output_lst_mint,
+        state,
+        input_weight_acc,
+        output_weight_acc,
+        pool_state,
+        lst_state_list,
+    })?;
+
+    let result = calculate_price_exact_out(CalculatePriceExactOutArgs {
+        fee_params,
+        reserves,
+        out_sol_value: sol_value,
+    })?;
+    let result_le = result.to_le_bytes();
+    set_return_data(&result_le);
+
+    Ok(())
+}
+
+fn verify_price_exact_out<'me, 'info>(
+    accounts: &'me [AccountInfo<'info>],
+) -> Result<PriceExactOutAccounts<'me, 'info>, ProgramError> {
+    let actual: PriceExactOutAccounts = load_accounts(accounts)?;
+
+    let state_bytes = actual.state.try_borrow_data()?;
+    let state = try_program_state(&state_bytes)?;
+    verify_pool_accounts(state, actual.pool_state, actual.lst_state_list)?;
+
+    let input_weight_acc_bytes = actual.input_weight_acc.try_borrow_data()?;
+    let input_weight_acc_bump = try_weight_account(&input_weight_acc_bytes)?.bump;
+    let output_weight_acc_bytes = actual.output_weight_acc.try_borrow_data()?;
+    let output_weight_acc_bump = try_weight_account(&output_weight_acc_bytes)?.bump;
+
+    let free_args = PriceExactOutWithBumpFreeArgs {
+        args: PriceExactOutFreeArgs {
+            input_lst_mint: *actual.input_lst_mint.key,
+            output_lst_mint: *actual.output_lst_mint.key,
+            pool_state: state.pool_state,
+            lst_state_list: state.lst_state_list,
+        },
+        input_weight_acc_bump,
+        output_weight_acc_bump,
+    };
+    let expected: PriceExactOutKeys = free_args.resolve()?;
+
+    price_exact_out_verify_account_keys(actual, expected).map_err(log_and_return_wrong_acc_err)?;
+
+    Ok(actual)
+}
@@ -0,0 +1,61 @@
+use sanctum_misc_utils::{
+    load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
+};
+use sanctum_system_program_lib::{close_account, CloseAccountAccounts};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+};
+use target_weight_interface::{
+    remove_lst_verify_account_keys, remove_lst_verify_account_privileges, RemoveLstAccounts,
+    RemoveLstKeys, TargetWeightError,
+};
+use target_weight_lib::{
+    account_resolvers::RemoveLstFreeArgs,
+    utils::{try_program_state_mut, try_weight_account},
+};
+
+pub fn process_remove_lst(accounts: &[AccountInfo]) -> ProgramResult {
+    let RemoveLstAccounts {
+        weight_acc,
+        refund_rent_to,
+        state,
+        ..
+    } = verify_remove_lst(accounts)?;
+
+    {
+        let weight_acc_bytes = weight_acc.try_borrow_data()?;
+        let target_weight_bps = try_weight_account(&weight_acc_bytes)?.target_weight_bps;
+
+        let mut state_bytes = state.try_borrow_mut_data()?;
+        let state = try_program_state_mut(&mut state_bytes)?;
+        state.total_target_weight_bps = state
+            .total_target_weight_bps
+            .checked_sub(target_weight_bps)
+            .ok_or(TargetWeightError::MathError)?;
+    }
+
+    close_account(CloseAccountAccounts {
+        refund_rent_to,
+        close: weight_acc,
+    })?;
+
+    Ok(())
+}
+
+fn verify_remove_lst<'me, 'info>(
+    accounts: &'me [AccountInfo<'info>],
+) -> Result<RemoveLstAccounts<'me, 'info>, ProgramError> {
+    let actual: RemoveLstAccounts = load_accounts(accounts)?;
+
+    let free_args = RemoveLstFreeArgs {
+        refund_rent_to: *actual.refund_rent_to.key,
+        state_acc: actual.state,
+        lst_mint: *actual.lst_mint.key,
+    };
+    let expected: RemoveLstKeys = free_args.resolve()?;
+
+    remove_lst_verify_account_keys(actual, expected).map_err(log_and_return_wrong_acc_err)?;
+    remove_lst_verify_account_privileges(actual).map_err(log_and_return_acc_privilege_err)?;
+
+    Ok(actual)
+}
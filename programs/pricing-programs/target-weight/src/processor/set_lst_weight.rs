@@ -0,0 +1,56 @@
+use sanctum_misc_utils::{
+    load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
+};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+};
+use target_weight_interface::{
+    set_lst_weight_verify_account_keys, set_lst_weight_verify_account_privileges,
+    SetLstWeightAccounts, SetLstWeightIxArgs, SetLstWeightKeys, TargetWeightError,
+};
+use target_weight_lib::{
+    account_resolvers::SetLstWeightFreeArgs,
+    fee_bound::verify_total_target_weight_bound,
+    utils::{try_program_state_mut, try_weight_account_mut},
+};
+
+pub fn process_set_lst_weight(accounts: &[AccountInfo], args: SetLstWeightIxArgs) -> ProgramResult {
+    let SetLstWeightAccounts {
+        weight_acc, state, ..
+    } = verify_set_lst_weight(accounts)?;
+
+    let mut weight_acc_bytes = weight_acc.try_borrow_mut_data()?;
+    let weight_acc = try_weight_account_mut(&mut weight_acc_bytes)?;
+    let mut state_bytes = state.try_borrow_mut_data()?;
+    let state = try_program_state_mut(&mut state_bytes)?;
+
+    let total_target_weight_bps = state
+        .total_target_weight_bps
+        .checked_sub(weight_acc.target_weight_bps)
+        .ok_or(TargetWeightError::MathError)?
+        .checked_add(args.target_weight_bps)
+        .ok_or(TargetWeightError::TotalTargetWeightOutOfBound)?;
+    verify_total_target_weight_bound(total_target_weight_bps)?;
+
+    weight_acc.target_weight_bps = args.target_weight_bps;
+    state.total_target_weight_bps = total_target_weight_bps;
+
+    Ok(())
+}
+
+fn verify_set_lst_weight<'me, 'info>(
+    accounts: &'me [AccountInfo<'info>],
+) -> Result<SetLstWeightAccounts<'me, 'info>, ProgramError> {
+    let actual: SetLstWeightAccounts = load_accounts(accounts)?;
+
+    let free_args = SetLstWeightFreeArgs {
+        state_acc: actual.state,
+        weight_acc: *actual.weight_acc.key,
+    };
+    let expected: SetLstWeightKeys = free_args.resolve()?;
+
+    set_lst_weight_verify_account_keys(actual, expected).map_err(log_and_return_wrong_acc_err)?;
+    set_lst_weight_verify_account_privileges(actual).map_err(log_and_return_acc_privilege_err)?;
+
+    Ok(actual)
+}
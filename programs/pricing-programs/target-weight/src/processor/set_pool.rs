@@ -0,0 +1,46 @@
+use s_controller_lib::{try_pool_state, Pool};
+use sanctum_misc_utils::{
+    load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
+};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+};
+use target_weight_interface::{
+    set_pool_verify_account_keys, set_pool_verify_account_privileges, SetPoolAccounts, SetPoolKeys,
+};
+use target_weight_lib::{account_resolvers::SetPoolFreeArgs, utils::try_program_state_mut};
+
+pub fn process_set_pool(accounts: &[AccountInfo]) -> ProgramResult {
+    let (SetPoolAccounts { state, .. }, pool) = verify_set_pool(accounts)?;
+
+    let mut bytes = state.try_borrow_mut_data()?;
+    let state = try_program_state_mut(&mut bytes)?;
+
+    state.pool_state = pool.pdas.pool_state;
+    state.lst_state_list = pool.pdas.lst_state_list;
+
+    Ok(())
+}
+
+fn verify_set_pool<'me, 'info>(
+    accounts: &'me [AccountInfo<'info>],
+) -> Result<(SetPoolAccounts<'me, 'info>, Pool), ProgramError> {
+    let actual: SetPoolAccounts = load_accounts(accounts)?;
+
+    let free_args = SetPoolFreeArgs {
+        pool_state: *actual.pool_state.key,
+        state_acc: actual.state,
+    };
+    let expected: SetPoolKeys = free_args.resolve()?;
+
+    set_pool_verify_account_keys(actual, expected).map_err(log_and_return_wrong_acc_err)?;
+    set_pool_verify_account_privileges(actual).map_err(log_and_return_acc_privilege_err)?;
+
+    if *actual.pool_state.owner != s_controller_lib::program::ID {
+        return Err(ProgramError::IllegalOwner);
+    }
+    try_pool_state(&actual.pool_state.try_borrow_data()?)?;
+    let pool = Pool::try_from_pool_state(actual.pool_state)?;
+
+    Ok((actual, pool))
+}
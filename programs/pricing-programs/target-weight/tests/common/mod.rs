@@ -0,0 +1,9 @@
+mod pool;
+mod program_test;
+mod state;
+mod weight_account;
+
+pub use pool::*;
+pub use program_test::*;
+pub use state::*;
+pub use weight_account::*;
@@ -0,0 +1,42 @@
+use s_controller_interface::LstState;
+use s_controller_test_utils::{LstStateListProgramTest, PoolStateProgramTest, DEFAULT_POOL_STATE};
+use solana_program::pubkey::Pubkey;
+use solana_program_test::ProgramTest;
+
+#[derive(Clone, Copy, Debug)]
+pub struct MockPoolLst {
+    pub mint: Pubkey,
+    pub sol_value: u64,
+}
+
+pub trait MockDefaultPoolProgramTest {
+    /// Adds the default pool's pool state and LST state list,
+    /// with the pool's total SOL value set to the sum of `lsts`' SOL values
+    fn add_mock_default_pool(self, lsts: &[MockPoolLst]) -> Self;
+}
+
+impl MockDefaultPoolProgramTest for ProgramTest {
+    fn add_mock_default_pool(self, lsts: &[MockPoolLst]) -> Self {
+        let lst_states: Vec<LstState> = lsts
+            .iter()
+            .map(|MockPoolLst { mint, sol_value }| LstState {
+                is_input_disabled: 0,
+                pool_reserves_bump: 0,
+                protocol_fee_accumulator_bump: 0,
+                padding: [0u8; 3],
+                max_share_bps: 0,
+                sol_value: *sol_value,
+                mint: *mint,
+                sol_value_calculator: Pubkey::default(),
+            })
+            .collect();
+        let total_sol_value = lsts.iter().map(|lst| lst.sol_value).sum();
+        self.add_pool_state({
+            let mut pool_state = DEFAULT_POOL_STATE;
+            pool_state.total_sol_value = total_sol_value;
+            pool_state.pricing_program = target_weight_lib::program::ID;
+            pool_state
+        })
+        .add_lst_state_list(&lst_states)
+    }
+}
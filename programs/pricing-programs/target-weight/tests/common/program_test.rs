@@ -0,0 +1,62 @@
+use sanctum_solana_test_utils::IntoAccount;
+use solana_program::pubkey::Pubkey;
+use solana_program_test::{processor, ProgramTest};
+use target_weight_interface::ProgramState;
+use target_weight_lib::program::STATE_ID;
+
+use super::{
+    MockDefaultPoolProgramTest, MockPoolLst, MockProgramState, MockWeightAccount,
+    MockWeightAccountArgs,
+};
+
+pub fn normal_program_test(
+    state: ProgramState,
+    mock_weight_accounts: &[MockWeightAccountArgs],
+) -> ProgramTest {
+    let mut program_test = ProgramTest::default();
+    program_test.add_program(
+        "target_weight",
+        target_weight_lib::program::ID,
+        processor!(target_weight::entrypoint::process_instruction),
+    );
+    program_test.add_account(STATE_ID, MockProgramState(state).into_account());
+    for mwa in mock_weight_accounts {
+        let (acc, addr) = mwa.to_weight_account_and_addr();
+        program_test.add_account(addr, MockWeightAccount(acc).into_account());
+    }
+    program_test
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct MockSwapPricingLst {
+    pub mint: Pubkey,
+    pub sol_value: u64,
+    pub target_weight_bps: u16,
+}
+
+/// Program test with the default pool containing `lsts` set up
+/// and a weight account for each LST in `lsts`
+pub fn swap_pricing_program_test(state: ProgramState, lsts: &[MockSwapPricingLst]) -> ProgramTest {
+    let mock_weight_accounts: Vec<MockWeightAccountArgs> = lsts
+        .iter()
+        .map(|lst| MockWeightAccountArgs {
+            target_weight_bps: lst.target_weight_bps,
+            lst_mint: lst.mint,
+        })
+        .collect();
+    let mock_pool_lsts: Vec<MockPoolLst> = lsts
+        .iter()
+        .map(|lst| MockPoolLst {
+            mint: lst.mint,
+            sol_value: lst.sol_value,
+        })
+        .collect();
+    normal_program_test(
+        ProgramState {
+            total_target_weight_bps: lsts.iter().map(|lst| lst.target_weight_bps).sum(),
+            ..state
+        },
+        &mock_weight_accounts,
+    )
+    .add_mock_default_pool(&mock_pool_lsts)
+}
@@ -0,0 +1,61 @@
+use async_trait::async_trait;
+use s_controller_lib::program::{LST_STATE_LIST_ID, POOL_STATE_ID};
+use sanctum_solana_test_utils::{est_rent_exempt_lamports, ExtendedBanksClient, IntoAccount};
+use solana_program_test::BanksClient;
+use solana_sdk::account::Account;
+use target_weight_interface::ProgramState;
+use target_weight_lib::{
+    initial_constants::{
+        initial_manager, INITIAL_BASE_FEE_BPS, INITIAL_IMBALANCE_FEE_BPS,
+        INITIAL_LP_WITHDRAWAL_FEE_BPS, INITIAL_MAX_FEE_BPS, INITIAL_MIN_FEE_BPS,
+    },
+    program::STATE_SIZE,
+    utils::{try_program_state, try_program_state_mut},
+};
+
+pub const DEFAULT_PROGRAM_STATE: ProgramState = ProgramState {
+    manager: initial_manager::ID,
+    pool_state: POOL_STATE_ID,
+    lst_state_list: LST_STATE_LIST_ID,
+    lp_withdrawal_fee_bps: INITIAL_LP_WITHDRAWAL_FEE_BPS,
+    base_fee_bps: INITIAL_BASE_FEE_BPS,
+    min_fee_bps: INITIAL_MIN_FEE_BPS,
+    max_fee_bps: INITIAL_MAX_FEE_BPS,
+    imbalance_fee_bps: INITIAL_IMBALANCE_FEE_BPS,
+    total_target_weight_bps: 0,
+};
+
+pub struct MockProgramState(pub ProgramState);
+
+impl IntoAccount for MockProgramState {
+    fn into_account(self) -> Account {
+        let mut data = vec![0u8; STATE_SIZE];
+        let dst = try_program_state_mut(&mut data).unwrap();
+        *dst = self.0;
+        Account {
+            lamports: est_rent_exempt_lamports(STATE_SIZE),
+            data,
+            owner: target_weight_lib::program::ID,
+            executable: false,
+            rent_epoch: u64::MAX,
+        }
+    }
+}
+
+#[async_trait]
+pub trait TargetWeightProgramTestBanksClient {
+    async fn get_target_weight_program_state_acc(&mut self) -> Account;
+
+    async fn get_target_weight_program_state(&mut self) -> ProgramState {
+        let acc = self.get_target_weight_program_state_acc().await;
+        *try_program_state(&acc.data).unwrap()
+    }
+}
+
+#[async_trait]
+impl TargetWeightProgramTestBanksClient for BanksClient {
+    async fn get_target_weight_program_state_acc(&mut self) -> Account {
+        self.get_account_unwrapped(target_weight_lib::program::STATE_ID)
+            .await
+    }
+}
@@ -0,0 +1,79 @@
+use sanctum_solana_test_utils::{est_rent_exempt_lamports, ExtendedBanksClient, IntoAccount};
+use solana_program::pubkey::Pubkey;
+use solana_program_test::BanksClient;
+use solana_sdk::account::Account;
+use target_weight_interface::WeightAccount;
+use target_weight_lib::{
+    pda::WeightAccountFindPdaArgs,
+    program::{self, WEIGHT_ACCOUNT_SIZE},
+    utils::{try_weight_account, try_weight_account_mut},
+};
+
+pub struct MockWeightAccount(pub WeightAccount);
+
+impl IntoAccount for MockWeightAccount {
+    fn into_account(self) -> Account {
+        let mut data = vec![0u8; WEIGHT_ACCOUNT_SIZE];
+        let dst = try_weight_account_mut(&mut data).unwrap();
+        *dst = self.0;
+        Account {
+            lamports: est_rent_exempt_lamports(WEIGHT_ACCOUNT_SIZE),
+            data,
+            owner: program::ID,
+            executable: false,
+            rent_epoch: u64::MAX,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct MockWeightAccountArgs {
+    pub target_weight_bps: u16,
+    pub lst_mint: Pubkey,
+}
+
+impl MockWeightAccountArgs {
+    pub fn to_weight_account_and_addr(&self) -> (WeightAccount, Pubkey) {
+        let (addr, bump) = WeightAccountFindPdaArgs {
+            lst_mint: self.lst_mint,
+            program_id: program::ID,
+        }
+        .get_weight_account_address_and_bump_seed();
+        (
+            WeightAccount {
+                bump,
+                padding: 0u8,
+                target_weight_bps: self.target_weight_bps,
+            },
+            addr,
+        )
+    }
+}
+
+pub async fn verify_weight_account(
+    banks_client: &mut BanksClient,
+    lst_mint: Pubkey,
+    expected_target_weight_bps: u16,
+) {
+    let (addr, bump) = WeightAccountFindPdaArgs {
+        lst_mint,
+        program_id: program::ID,
+    }
+    .get_weight_account_address_and_bump_seed();
+    let actual_acc = banks_client.get_account_unwrapped(addr).await;
+    let actual = try_weight_account(&actual_acc.data).unwrap();
+    assert_eq!(actual.bump, bump);
+    assert_eq!(actual.target_weight_bps, expected_target_weight_bps);
+}
+
+pub async fn verify_weight_account_does_not_exist(
+    banks_client: &mut BanksClient,
+    lst_mint: Pubkey,
+) {
+    let (addr, _bump) = WeightAccountFindPdaArgs {
+        lst_mint,
+        program_id: program::ID,
+    }
+    .get_weight_account_address_and_bump_seed();
+    assert!(banks_client.get_account(addr).await.unwrap().is_none());
+}
@@ -0,0 +1,152 @@
+use sanctum_solana_test_utils::{
+    assert_custom_err, assert_program_error,
+    token::{tokenkeg::TokenkegProgramTest, MockMintArgs},
+};
+use solana_program::{program_error::ProgramError, pubkey::Pubkey};
+use solana_program_test::ProgramTest;
+use solana_readonly_account::sdk::KeyedAccount;
+use solana_sdk::{signature::Keypair, signer::Signer, transaction::Transaction};
+use target_weight_interface::{add_lst_ix, AddLstIxArgs, ProgramState, TargetWeightError};
+use target_weight_lib::{account_resolvers::AddLstFreeArgs, program::STATE_ID};
+
+use crate::common::{
+    normal_program_test, verify_weight_account, verify_weight_account_does_not_exist,
+    TargetWeightProgramTestBanksClient, DEFAULT_PROGRAM_STATE,
+};
+
+fn add_lst_program_test(total_target_weight_bps: u16) -> (ProgramTest, Keypair, Pubkey) {
+    let manager = Keypair::new();
+    let lst_mint = Pubkey::new_unique();
+
+    let program_test = normal_program_test(
+        ProgramState {
+            manager: manager.pubkey(),
+            total_target_weight_bps,
+            ..DEFAULT_PROGRAM_STATE
+        },
+        &[],
+    )
+    .add_tokenkeg_mint_from_args(
+        lst_mint,
+        MockMintArgs {
+            mint_authority: None,
+            freeze_authority: None,
+            supply: 0,
+            decimals: 9,
+        },
+    );
+    (program_test, manager, lst_mint)
+}
+
+#[tokio::test]
+async fn add_lst_basic() {
+    const STARTING_TOTAL_TARGET_WEIGHT_BPS: u16 = 4_000;
+    const TARGET_WEIGHT_BPS: u16 = 6_000;
+
+    let (program_test, manager, lst_mint) = add_lst_program_test(STARTING_TOTAL_TARGET_WEIGHT_BPS);
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    verify_weight_account_does_not_exist(&mut banks_client, lst_mint).await;
+    let state_acc = banks_client.get_target_weight_program_state_acc().await;
+
+    let (keys, _pda) = AddLstFreeArgs {
+        payer: payer.pubkey(),
+        lst_mint,
+        state_acc: KeyedAccount {
+            pubkey: STATE_ID,
+            account: state_acc,
+        },
+    }
+    .resolve()
+    .unwrap();
+    let ix = add_lst_ix(
+        keys,
+        AddLstIxArgs {
+            target_weight_bps: TARGET_WEIGHT_BPS,
+        },
+    )
+    .unwrap();
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &manager], last_blockhash);
+
+    banks_client.process_transaction(tx).await.unwrap();
+
+    verify_weight_account(&mut banks_client, lst_mint, TARGET_WEIGHT_BPS).await;
+    let state = banks_client.get_target_weight_program_state().await;
+    assert_eq!(
+        state.total_target_weight_bps,
+        STARTING_TOTAL_TARGET_WEIGHT_BPS + TARGET_WEIGHT_BPS
+    );
+}
+
+#[tokio::test]
+async fn add_lst_fail_total_target_weight_exceeded() {
+    let (program_test, manager, lst_mint) = add_lst_program_test(4_000);
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let state_acc = banks_client.get_target_weight_program_state_acc().await;
+    let keyed_state_acc = KeyedAccount {
+        pubkey: STATE_ID,
+        account: state_acc,
+    };
+
+    for bad_target_weight_bps in [6_001, u16::MAX] {
+        let (keys, _pda) = AddLstFreeArgs {
+            payer: payer.pubkey(),
+            lst_mint,
+            state_acc: &keyed_state_acc,
+        }
+        .resolve()
+        .unwrap();
+        let ix = add_lst_ix(
+            keys,
+            AddLstIxArgs {
+                target_weight_bps: bad_target_weight_bps,
+            },
+        )
+        .unwrap();
+
+        let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+        tx.sign(&[&payer, &manager], last_blockhash);
+
+        let err = banks_client.process_transaction(tx).await.unwrap_err();
+        assert_custom_err(err, TargetWeightError::TotalTargetWeightOutOfBound);
+
+        verify_weight_account_does_not_exist(&mut banks_client, lst_mint).await;
+    }
+}
+
+#[tokio::test]
+async fn add_lst_fail_unauthorized() {
+    let (program_test, _manager, lst_mint) = add_lst_program_test(0);
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let state_acc = banks_client.get_target_weight_program_state_acc().await;
+    let (mut keys, _pda) = AddLstFreeArgs {
+        payer: payer.pubkey(),
+        lst_mint,
+        state_acc: KeyedAccount {
+            pubkey: STATE_ID,
+            account: state_acc,
+        },
+    }
+    .resolve()
+    .unwrap();
+    keys.manager = payer.pubkey();
+    let ix = add_lst_ix(
+        keys,
+        AddLstIxArgs {
+            target_weight_bps: 0,
+        },
+    )
+    .unwrap();
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer], last_blockhash);
+
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_program_error(err, ProgramError::InvalidArgument);
+
+    verify_weight_account_does_not_exist(&mut banks_client, lst_mint).await;
+}
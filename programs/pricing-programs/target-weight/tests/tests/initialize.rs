@@ -0,0 +1,34 @@
+use solana_program_test::{processor, ProgramTest};
+use solana_sdk::{signer::Signer, transaction::Transaction};
+use target_weight_interface::initialize_ix;
+use target_weight_lib::account_resolvers::InitializeFreeArgs;
+
+use crate::common::{TargetWeightProgramTestBanksClient, DEFAULT_PROGRAM_STATE};
+
+#[tokio::test]
+async fn initialize_basic() {
+    let mut program_test = ProgramTest::default();
+    program_test.add_program(
+        "target_weight",
+        target_weight_lib::program::ID,
+        processor!(target_weight::entrypoint::process_instruction),
+    );
+
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let ix = initialize_ix(
+        InitializeFreeArgs {
+            payer: payer.pubkey(),
+        }
+        .resolve(),
+    )
+    .unwrap();
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer], last_blockhash);
+
+    banks_client.process_transaction(tx).await.unwrap();
+
+    let state = banks_client.get_target_weight_program_state().await;
+    assert_eq!(state, DEFAULT_PROGRAM_STATE);
+}
@@ -0,0 +1,9 @@
+mod add_lst;
+mod initialize;
+mod price_exact_in;
+mod price_exact_out;
+mod price_lp_tokens_to_redeem;
+mod remove_lst;
+mod set_fee_params;
+mod set_lst_weight;
+mod set_pool;
@@ -0,0 +1,189 @@
+use s_controller_lib::program::{LST_STATE_LIST_ID, POOL_STATE_ID};
+use sanctum_solana_test_utils::{assert_custom_err, IntoAccount};
+use solana_program::pubkey::Pubkey;
+use solana_sdk::{signer::Signer, transaction::Transaction};
+use target_weight_interface::{
+    price_exact_in_ix, PriceExactInIxArgs, PriceExactInKeys, ProgramState, TargetWeightError,
+};
+use target_weight_lib::account_resolvers::PriceExactInFreeArgs;
+use test_utils::BorshReturnDataBanksClient;
+
+use crate::common::*;
+
+const IN_SOL_VALUE: u64 = 10_000_000_000;
+
+const FEE_PARAMS_STATE: ProgramState = ProgramState {
+    base_fee_bps: 10,
+    min_fee_bps: -20,
+    max_fee_bps: 100,
+    imbalance_fee_bps: 500,
+    ..DEFAULT_PROGRAM_STATE
+};
+
+fn price_exact_in_keys(input_lst_mint: Pubkey, output_lst_mint: Pubkey) -> PriceExactInKeys {
+    PriceExactInFreeArgs {
+        input_lst_mint,
+        output_lst_mint,
+        pool_state: POOL_STATE_ID,
+        lst_state_list: LST_STATE_LIST_ID,
+    }
+    .resolve()
+}
+
+#[tokio::test]
+async fn price_exact_in_away_from_target() {
+    // pool: 50 SOL input LST, 50 SOL output LST, both targeting 50%
+    // midpoint weights: input 55%, output 45%
+    // deviation = 500 + 500 = 1000 bps
+    // fee = 10 + 500 * 1000 / 10_000 = 60 bps
+    const EXPECTED_OUT_SOL_VALUE: u64 = 9_940_000_000;
+
+    let input_lst_mint = Pubkey::new_unique();
+    let output_lst_mint = Pubkey::new_unique();
+    let program_test = swap_pricing_program_test(
+        FEE_PARAMS_STATE,
+        &[
+            MockSwapPricingLst {
+                mint: input_lst_mint,
+                sol_value: 50_000_000_000,
+                target_weight_bps: 5_000,
+            },
+            MockSwapPricingLst {
+                mint: output_lst_mint,
+                sol_value: 50_000_000_000,
+                target_weight_bps: 5_000,
+            },
+        ],
+    );
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let ix = price_exact_in_ix(
+        price_exact_in_keys(input_lst_mint, output_lst_mint),
+        PriceExactInIxArgs {
+            amount: IN_SOL_VALUE,
+            sol_value: IN_SOL_VALUE,
+        },
+    )
+    .unwrap();
+
+    banks_client
+        .exec_verify_borsh_return_data::<u64, 8>(&payer, last_blockhash, ix, EXPECTED_OUT_SOL_VALUE)
+        .await;
+}
+
+#[tokio::test]
+async fn price_exact_in_toward_target_rebate() {
+    // pool: 30 SOL input LST, 70 SOL output LST, both targeting 50%
+    // midpoint weights: input 35%, output 65%
+    // deviation = -1500 + -1500 = -3000 bps
+    // fee = 10 + 500 * -3000 / 10_000 = -140 bps, clamped to min_fee_bps = -20 bps
+    const EXPECTED_OUT_SOL_VALUE: u64 = 10_020_000_000;
+
+    let input_lst_mint = Pubkey::new_unique();
+    let output_lst_mint = Pubkey::new_unique();
+    let program_test = swap_pricing_program_test(
+        FEE_PARAMS_STATE,
+        &[
+            MockSwapPricingLst {
+                mint: input_lst_mint,
+                sol_value: 30_000_000_000,
+                target_weight_bps: 5_000,
+            },
+            MockSwapPricingLst {
+                mint: output_lst_mint,
+                sol_value: 70_000_000_000,
+                target_weight_bps: 5_000,
+            },
+        ],
+    );
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let ix = price_exact_in_ix(
+        price_exact_in_keys(input_lst_mint, output_lst_mint),
+        PriceExactInIxArgs {
+            amount: IN_SOL_VALUE,
+            sol_value: IN_SOL_VALUE,
+        },
+    )
+    .unwrap();
+
+    banks_client
+        .exec_verify_borsh_return_data::<u64, 8>(&payer, last_blockhash, ix, EXPECTED_OUT_SOL_VALUE)
+        .await;
+}
+
+#[tokio::test]
+async fn price_exact_in_fail_lst_not_in_pool() {
+    let input_lst_mint = Pubkey::new_unique();
+    let output_lst_mint = Pubkey::new_unique();
+    let mut program_test = swap_pricing_program_test(
+        FEE_PARAMS_STATE,
+        &[MockSwapPricingLst {
+            mint: input_lst_mint,
+            sol_value: 50_000_000_000,
+            target_weight_bps: 5_000,
+        }],
+    );
+    // weight account but no reserves in the pool
+    let (acc, addr) = MockWeightAccountArgs {
+        target_weight_bps: 5_000,
+        lst_mint: output_lst_mint,
+    }
+    .to_weight_account_and_addr();
+    program_test.add_account(addr, MockWeightAccount(acc).into_account());
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let ix = price_exact_in_ix(
+        price_exact_in_keys(input_lst_mint, output_lst_mint),
+        PriceExactInIxArgs {
+            amount: IN_SOL_VALUE,
+            sol_value: IN_SOL_VALUE,
+        },
+    )
+    .unwrap();
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer], last_blockhash);
+
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_custom_err(err, TargetWeightError::LstNotInPool);
+}
+
+#[tokio::test]
+async fn price_exact_in_fail_incorrect_pool_accounts() {
+    let input_lst_mint = Pubkey::new_unique();
+    let output_lst_mint = Pubkey::new_unique();
+    let program_test = swap_pricing_program_test(
+        FEE_PARAMS_STATE,
+        &[
+            MockSwapPricingLst {
+                mint: input_lst_mint,
+                sol_value: 50_000_000_000,
+                target_weight_bps: 5_000,
+            },
+            MockSwapPricingLst {
+                mint: output_lst_mint,
+                sol_value: 50_000_000_000,
+                target_weight_bps: 5_000,
+            },
+        ],
+    );
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let mut keys = price_exact_in_keys(input_lst_mint, output_lst_mint);
+    keys.lst_state_list = Pubkey::new_unique();
+    let ix = price_exact_in_ix(
+        keys,
+        PriceExactInIxArgs {
+            amount: IN_SOL_VALUE,
+            sol_value: IN_SOL_VALUE,
+        },
+    )
+    .unwrap();
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer], last_blockhash);
+
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_custom_err(err, TargetWeightError::IncorrectPoolAccounts);
+}
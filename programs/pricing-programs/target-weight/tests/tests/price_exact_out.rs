@@ -0,0 +1,60 @@
+use s_controller_lib::program::{LST_STATE_LIST_ID, POOL_STATE_ID};
+use solana_program::pubkey::Pubkey;
+use target_weight_interface::{price_exact_out_ix, PriceExactOutIxArgs, ProgramState};
+use target_weight_lib::account_resolvers::PriceExactOutFreeArgs;
+use test_utils::BorshReturnDataBanksClient;
+
+use crate::common::*;
+
+#[tokio::test]
+async fn price_exact_out_away_from_target() {
+    const OUT_SOL_VALUE: u64 = 10_000_000_000;
+    // pool: 50 SOL input LST, 50 SOL output LST, both targeting 50%
+    // fee = 10 + 500 * 1000 / 10_000 = 60 bps
+    // in_sol_value = ceil(10_000_000_000 * 10_000 / 9_940)
+    const EXPECTED_IN_SOL_VALUE: u64 = 10_060_362_174;
+
+    let input_lst_mint = Pubkey::new_unique();
+    let output_lst_mint = Pubkey::new_unique();
+    let program_test = swap_pricing_program_test(
+        ProgramState {
+            base_fee_bps: 10,
+            min_fee_bps: -20,
+            max_fee_bps: 100,
+            imbalance_fee_bps: 500,
+            ..DEFAULT_PROGRAM_STATE
+        },
+        &[
+            MockSwapPricingLst {
+                mint: input_lst_mint,
+                sol_value: 50_000_000_000,
+                target_weight_bps: 5_000,
+            },
+            MockSwapPricingLst {
+                mint: output_lst_mint,
+                sol_value: 50_000_000_000,
+                target_weight_bps: 5_000,
+            },
+        ],
+    );
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let ix = price_exact_out_ix(
+        PriceExactOutFreeArgs {
+            input_lst_mint,
+            output_lst_mint,
+            pool_state: POOL_STATE_ID,
+            lst_state_list: LST_STATE_LIST_ID,
+        }
+        .resolve(),
+        PriceExactOutIxArgs {
+            amount: OUT_SOL_VALUE,
+            sol_value: OUT_SOL_VALUE,
+        },
+    )
+    .unwrap();
+
+    banks_client
+        .exec_verify_borsh_return_data::<u64, 8>(&payer, last_blockhash, ix, EXPECTED_IN_SOL_VALUE)
+        .await;
+}
@@ -0,0 +1,45 @@
+use solana_program::pubkey::Pubkey;
+use target_weight_interface::{
+    price_lp_tokens_to_redeem_ix, PriceLpTokensToRedeemIxArgs, ProgramState,
+};
+use target_weight_lib::account_resolvers::PriceLpTokensToRedeemFreeArgs;
+use test_utils::BorshReturnDataBanksClient;
+
+use crate::common::*;
+
+#[tokio::test]
+async fn price_lp_tokens_to_redeem_basic() {
+    const SOL_VALUE: u64 = 1_000_000_000;
+    // 5 bps
+    const EXPECTED_SOL_VALUE_AFTER_FEES: u64 = 999_500_000;
+
+    let program_test = normal_program_test(
+        ProgramState {
+            lp_withdrawal_fee_bps: 5,
+            ..DEFAULT_PROGRAM_STATE
+        },
+        &[],
+    );
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let ix = price_lp_tokens_to_redeem_ix(
+        PriceLpTokensToRedeemFreeArgs {
+            output_lst_mint: Pubkey::new_unique(),
+        }
+        .resolve(),
+        PriceLpTokensToRedeemIxArgs {
+            amount: SOL_VALUE,
+            sol_value: SOL_VALUE,
+        },
+    )
+    .unwrap();
+
+    banks_client
+        .exec_verify_borsh_return_data::<u64, 8>(
+            &payer,
+            last_blockhash,
+            ix,
+            EXPECTED_SOL_VALUE_AFTER_FEES,
+        )
+        .await;
+}
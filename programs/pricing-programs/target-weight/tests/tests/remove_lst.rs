@@ -0,0 +1,59 @@
+use sanctum_solana_test_utils::ExtendedBanksClient;
+use solana_program::pubkey::Pubkey;
+use solana_readonly_account::sdk::KeyedAccount;
+use solana_sdk::{signature::Keypair, signer::Signer, transaction::Transaction};
+use target_weight_interface::{remove_lst_ix, ProgramState};
+use target_weight_lib::{account_resolvers::RemoveLstFreeArgs, program::STATE_ID};
+
+use crate::common::*;
+
+#[tokio::test]
+async fn remove_lst_basic() {
+    const TARGET_WEIGHT_BPS: u16 = 2_500;
+    const OTHER_LSTS_TARGET_WEIGHT_BPS: u16 = 7_500;
+
+    let manager = Keypair::new();
+    let lst_mint = Pubkey::new_unique();
+    let refund_rent_to = Pubkey::new_unique();
+
+    let program_test = normal_program_test(
+        ProgramState {
+            manager: manager.pubkey(),
+            total_target_weight_bps: TARGET_WEIGHT_BPS + OTHER_LSTS_TARGET_WEIGHT_BPS,
+            ..DEFAULT_PROGRAM_STATE
+        },
+        &[MockWeightAccountArgs {
+            target_weight_bps: TARGET_WEIGHT_BPS,
+            lst_mint,
+        }],
+    );
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let state_acc = banks_client.get_target_weight_program_state_acc().await;
+    let keys = RemoveLstFreeArgs {
+        refund_rent_to,
+        lst_mint,
+        state_acc: KeyedAccount {
+            pubkey: STATE_ID,
+            account: state_acc,
+        },
+    }
+    .resolve()
+    .unwrap();
+    let weight_acc_lamports = banks_client
+        .get_account_unwrapped(keys.weight_acc)
+        .await
+        .lamports;
+    let ix = remove_lst_ix(keys).unwrap();
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &manager], last_blockhash);
+
+    banks_client.process_transaction(tx).await.unwrap();
+
+    verify_weight_account_does_not_exist(&mut banks_client, lst_mint).await;
+    let state = banks_client.get_target_weight_program_state().await;
+    assert_eq!(state.total_target_weight_bps, OTHER_LSTS_TARGET_WEIGHT_BPS);
+    let refund_rent_to_acc = banks_client.get_account_unwrapped(refund_rent_to).await;
+    assert_eq!(refund_rent_to_acc.lamports, weight_acc_lamports);
+}
@@ -0,0 +1,175 @@
+use sanctum_solana_test_utils::{assert_custom_err, assert_program_error};
+use solana_program::program_error::ProgramError;
+use solana_readonly_account::sdk::KeyedAccount;
+use solana_sdk::{signature::Keypair, signer::Signer, transaction::Transaction};
+use target_weight_interface::{
+    set_fee_params_ix, ProgramState, SetFeeParamsIxArgs, TargetWeightError,
+};
+use target_weight_lib::{account_resolvers::SetFeeParamsFreeArgs, program::STATE_ID};
+
+use crate::common::*;
+
+const FEE_PARAMS: SetFeeParamsIxArgs = SetFeeParamsIxArgs {
+    lp_withdrawal_fee_bps: 3,
+    base_fee_bps: 5,
+    min_fee_bps: -10,
+    max_fee_bps: 50,
+    imbalance_fee_bps: 250,
+};
+
+#[tokio::test]
+async fn set_fee_params_basic() {
+    let manager = Keypair::new();
+
+    let program_test = normal_program_test(
+        ProgramState {
+            manager: manager.pubkey(),
+            ..DEFAULT_PROGRAM_STATE
+        },
+        &[],
+    );
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let state_acc = banks_client.get_target_weight_program_state_acc().await;
+    let ix = set_fee_params_ix(
+        SetFeeParamsFreeArgs {
+            state_acc: KeyedAccount {
+                pubkey: STATE_ID,
+                account: state_acc,
+            },
+        }
+        .resolve()
+        .unwrap(),
+        FEE_PARAMS,
+    )
+    .unwrap();
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &manager], last_blockhash);
+
+    banks_client.process_transaction(tx).await.unwrap();
+
+    let state = banks_client.get_target_weight_program_state().await;
+    assert_eq!(
+        state,
+        ProgramState {
+            manager: manager.pubkey(),
+            lp_withdrawal_fee_bps: FEE_PARAMS.lp_withdrawal_fee_bps,
+            base_fee_bps: FEE_PARAMS.base_fee_bps,
+            min_fee_bps: FEE_PARAMS.min_fee_bps,
+            max_fee_bps: FEE_PARAMS.max_fee_bps,
+            imbalance_fee_bps: FEE_PARAMS.imbalance_fee_bps,
+            ..DEFAULT_PROGRAM_STATE
+        }
+    );
+}
+
+#[tokio::test]
+async fn set_fee_params_fail_invalid_fee_params() {
+    let bad_fee_params: [(SetFeeParamsIxArgs, TargetWeightError); 4] = [
+        (
+            SetFeeParamsIxArgs {
+                base_fee_bps: -11,
+                ..FEE_PARAMS
+            },
+            TargetWeightError::InvalidFeeParams,
+        ),
+        (
+            SetFeeParamsIxArgs {
+                base_fee_bps: 51,
+                ..FEE_PARAMS
+            },
+            TargetWeightError::InvalidFeeParams,
+        ),
+        (
+            SetFeeParamsIxArgs {
+                max_fee_bps: 10_001,
+                ..FEE_PARAMS
+            },
+            TargetWeightError::SignedFeeOutOfBound,
+        ),
+        (
+            SetFeeParamsIxArgs {
+                lp_withdrawal_fee_bps: 10_001,
+                ..FEE_PARAMS
+            },
+            TargetWeightError::UnsignedFeeOutOfBound,
+        ),
+    ];
+
+    let manager = Keypair::new();
+
+    let program_test = normal_program_test(
+        ProgramState {
+            manager: manager.pubkey(),
+            ..DEFAULT_PROGRAM_STATE
+        },
+        &[],
+    );
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let state_acc = banks_client.get_target_weight_program_state_acc().await;
+    let keyed_state_acc = KeyedAccount {
+        pubkey: STATE_ID,
+        account: state_acc,
+    };
+
+    for (args, expected_err) in bad_fee_params {
+        let ix = set_fee_params_ix(
+            SetFeeParamsFreeArgs {
+                state_acc: &keyed_state_acc,
+            }
+            .resolve()
+            .unwrap(),
+            args,
+        )
+        .unwrap();
+
+        let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+        tx.sign(&[&payer, &manager], last_blockhash);
+
+        let err = banks_client.process_transaction(tx).await.unwrap_err();
+        assert_custom_err(err, expected_err);
+    }
+
+    let state = banks_client.get_target_weight_program_state().await;
+    assert_eq!(
+        state,
+        ProgramState {
+            manager: manager.pubkey(),
+            ..DEFAULT_PROGRAM_STATE
+        }
+    );
+}
+
+#[tokio::test]
+async fn set_fee_params_fail_unauthorized() {
+    let manager = Keypair::new();
+
+    let program_test = normal_program_test(
+        ProgramState {
+            manager: manager.pubkey(),
+            ..DEFAULT_PROGRAM_STATE
+        },
+        &[],
+    );
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let state_acc = banks_client.get_target_weight_program_state_acc().await;
+    let mut keys = SetFeeParamsFreeArgs {
+        state_acc: KeyedAccount {
+            pubkey: STATE_ID,
+            account: state_acc,
+        },
+    }
+    .resolve()
+    .unwrap();
+    keys.manager = payer.pubkey();
+    let ix = set_fee_params_ix(keys, FEE_PARAMS).unwrap();
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer], last_blockhash);
+
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_program_error(err, ProgramError::InvalidArgument);
+}
@@ -0,0 +1,107 @@
+use sanctum_solana_test_utils::assert_custom_err;
+use solana_program::pubkey::Pubkey;
+use solana_readonly_account::sdk::KeyedAccount;
+use solana_sdk::{signature::Keypair, signer::Signer, transaction::Transaction};
+use target_weight_interface::{
+    set_lst_weight_ix, ProgramState, SetLstWeightIxArgs, TargetWeightError,
+};
+use target_weight_lib::{account_resolvers::SetLstWeightByMintFreeArgs, program::STATE_ID};
+
+use crate::common::*;
+
+const STARTING_TARGET_WEIGHT_BPS: u16 = 3_000;
+const OTHER_LSTS_TARGET_WEIGHT_BPS: u16 = 5_000;
+
+#[tokio::test]
+async fn set_lst_weight_basic() {
+    const NEW_TARGET_WEIGHT_BPS: u16 = 5_000;
+
+    let manager = Keypair::new();
+    let lst_mint = Pubkey::new_unique();
+
+    let program_test = normal_program_test(
+        ProgramState {
+            manager: manager.pubkey(),
+            total_target_weight_bps: STARTING_TARGET_WEIGHT_BPS + OTHER_LSTS_TARGET_WEIGHT_BPS,
+            ..DEFAULT_PROGRAM_STATE
+        },
+        &[MockWeightAccountArgs {
+            target_weight_bps: STARTING_TARGET_WEIGHT_BPS,
+            lst_mint,
+        }],
+    );
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let state_acc = banks_client.get_target_weight_program_state_acc().await;
+    let ix = set_lst_weight_ix(
+        SetLstWeightByMintFreeArgs {
+            lst_mint,
+            state_acc: KeyedAccount {
+                pubkey: STATE_ID,
+                account: state_acc,
+            },
+        }
+        .resolve()
+        .unwrap(),
+        SetLstWeightIxArgs {
+            target_weight_bps: NEW_TARGET_WEIGHT_BPS,
+        },
+    )
+    .unwrap();
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &manager], last_blockhash);
+
+    banks_client.process_transaction(tx).await.unwrap();
+
+    verify_weight_account(&mut banks_client, lst_mint, NEW_TARGET_WEIGHT_BPS).await;
+    let state = banks_client.get_target_weight_program_state().await;
+    assert_eq!(
+        state.total_target_weight_bps,
+        NEW_TARGET_WEIGHT_BPS + OTHER_LSTS_TARGET_WEIGHT_BPS
+    );
+}
+
+#[tokio::test]
+async fn set_lst_weight_fail_total_target_weight_exceeded() {
+    let manager = Keypair::new();
+    let lst_mint = Pubkey::new_unique();
+
+    let program_test = normal_program_test(
+        ProgramState {
+            manager: manager.pubkey(),
+            total_target_weight_bps: STARTING_TARGET_WEIGHT_BPS + OTHER_LSTS_TARGET_WEIGHT_BPS,
+            ..DEFAULT_PROGRAM_STATE
+        },
+        &[MockWeightAccountArgs {
+            target_weight_bps: STARTING_TARGET_WEIGHT_BPS,
+            lst_mint,
+        }],
+    );
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let state_acc = banks_client.get_target_weight_program_state_acc().await;
+    let ix = set_lst_weight_ix(
+        SetLstWeightByMintFreeArgs {
+            lst_mint,
+            state_acc: KeyedAccount {
+                pubkey: STATE_ID,
+                account: state_acc,
+            },
+        }
+        .resolve()
+        .unwrap(),
+        SetLstWeightIxArgs {
+            target_weight_bps: 10_000 - OTHER_LSTS_TARGET_WEIGHT_BPS + 1,
+        },
+    )
+    .unwrap();
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &manager], last_blockhash);
+
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_custom_err(err, TargetWeightError::TotalTargetWeightOutOfBound);
+
+    verify_weight_account(&mut banks_client, lst_mint, STARTING_TARGET_WEIGHT_BPS).await;
+}
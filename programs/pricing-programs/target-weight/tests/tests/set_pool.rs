@@ -0,0 +1,96 @@
+use s_controller_lib::{Pool, PoolId};
+use s_controller_test_utils::{MockPoolState, DEFAULT_POOL_STATE};
+use sanctum_solana_test_utils::{assert_program_error, IntoAccount};
+use solana_program::{program_error::ProgramError, pubkey::Pubkey};
+use solana_readonly_account::sdk::KeyedAccount;
+use solana_sdk::{signature::Keypair, signer::Signer, transaction::Transaction};
+use target_weight_interface::{set_pool_ix, ProgramState};
+use target_weight_lib::{account_resolvers::SetPoolFreeArgs, program::STATE_ID};
+
+use crate::common::*;
+
+#[tokio::test]
+async fn set_pool_keyed_pool() {
+    let manager = Keypair::new();
+    let lp_token_mint = Pubkey::new_unique();
+    let pool = Pool::find(PoolId::Keyed(lp_token_mint));
+
+    let mut program_test = normal_program_test(
+        ProgramState {
+            manager: manager.pubkey(),
+            ..DEFAULT_PROGRAM_STATE
+        },
+        &[],
+    );
+    program_test.add_account(
+        pool.pdas.pool_state,
+        MockPoolState({
+            let mut pool_state = DEFAULT_POOL_STATE;
+            pool_state.lp_token_mint = lp_token_mint;
+            pool_state
+        })
+        .into_account(),
+    );
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let state_acc = banks_client.get_target_weight_program_state_acc().await;
+    let ix = set_pool_ix(
+        SetPoolFreeArgs {
+            pool_state: pool.pdas.pool_state,
+            state_acc: KeyedAccount {
+                pubkey: STATE_ID,
+                account: state_acc,
+            },
+        }
+        .resolve()
+        .unwrap(),
+    )
+    .unwrap();
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &manager], last_blockhash);
+
+    banks_client.process_transaction(tx).await.unwrap();
+
+    let state = banks_client.get_target_weight_program_state().await;
+    assert_eq!(state.pool_state, pool.pdas.pool_state);
+    assert_eq!(state.lst_state_list, pool.pdas.lst_state_list);
+}
+
+#[tokio::test]
+async fn set_pool_fail_not_s_controller_pool() {
+    let manager = Keypair::new();
+    let fake_pool_state = Pubkey::new_unique();
+
+    let mut program_test = normal_program_test(
+        ProgramState {
+            manager: manager.pubkey(),
+            ..DEFAULT_PROGRAM_STATE
+        },
+        &[],
+    );
+    let mut fake_pool_state_acc = MockPoolState(DEFAULT_POOL_STATE).into_account();
+    fake_pool_state_acc.owner = Pubkey::new_unique();
+    program_test.add_account(fake_pool_state, fake_pool_state_acc);
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let state_acc = banks_client.get_target_weight_program_state_acc().await;
+    let ix = set_pool_ix(
+        SetPoolFreeArgs {
+            pool_state: fake_pool_state,
+            state_acc: KeyedAccount {
+                pubkey: STATE_ID,
+                account: state_acc,
+            },
+        }
+        .resolve()
+        .unwrap(),
+    )
+    .unwrap();
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &manager], last_blockhash);
+
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_program_error(err, ProgramError::IllegalOwner);
+}
@@ -1,5 +1,6 @@
 use s_controller_interface::{
-    AddLiquidityAccounts, EndRebalanceAccounts, RemoveLiquidityAccounts, SyncSolValueAccounts,
+    AddLiquidityAccounts, AddLiquidityExactOutAccounts, EndRebalanceAccounts,
+    RemoveLiquidityAccounts, RemoveLiquidityExactOutAccounts, SyncSolValueAccounts,
 };
 use solana_program::account_info::AccountInfo;
 
@@ -36,3 +37,15 @@ impl<'me, 'info> GetLstMintAccountInfo<'me, 'info> for RemoveLiquidityAccounts<'
         self.lst_mint
     }
 }
+
+impl<'me, 'info> GetLstMintAccountInfo<'me, 'info> for AddLiquidityExactOutAccounts<'me, 'info> {
+    fn get_lst_mint_account_info(&self) -> &'me AccountInfo<'info> {
+        self.lst_mint
+    }
+}
+
+impl<'me, 'info> GetLstMintAccountInfo<'me, 'info> for RemoveLiquidityExactOutAccounts<'me, 'info> {
+    fn get_lst_mint_account_info(&self) -> &'me AccountInfo<'info> {
+        self.lst_mint
+    }
+}
@@ -1,7 +1,8 @@
 use s_controller_interface::{
-    AddLiquidityAccounts, EndRebalanceAccounts, RemoveLiquidityAccounts,
-    SetSolValueCalculatorAccounts, StartRebalanceAccounts, SwapExactInAccounts,
-    SwapExactOutAccounts, SyncSolValueAccounts,
+    AddLiquidityAccounts, AddLiquidityExactOutAccounts, EndRebalanceAccounts,
+    RemoveLiquidityAccounts, RemoveLiquidityExactOutAccounts, SetSolValueCalculatorAccounts,
+    StartRebalanceAccounts, SwapExactInAccounts, SwapExactOutAccounts, SyncSolValueAccounts,
+    WithdrawProtocolFeesSwapAccounts,
 };
 use solana_program::account_info::AccountInfo;
 
@@ -49,6 +50,22 @@ impl<'me, 'info> GetLstStateListAccountInfo<'me, 'info> for RemoveLiquidityAccou
     }
 }
 
+impl<'me, 'info> GetLstStateListAccountInfo<'me, 'info>
+    for AddLiquidityExactOutAccounts<'me, 'info>
+{
+    fn get_lst_state_list_account_info(&self) -> &'me AccountInfo<'info> {
+        self.lst_state_list
+    }
+}
+
+impl<'me, 'info> GetLstStateListAccountInfo<'me, 'info>
+    for RemoveLiquidityExactOutAccounts<'me, 'info>
+{
+    fn get_lst_state_list_account_info(&self) -> &'me AccountInfo<'info> {
+        self.lst_state_list
+    }
+}
+
 impl<'me, 'info> GetLstStateListAccountInfo<'me, 'info> for SwapExactInAccounts<'me, 'info> {
     fn get_lst_state_list_account_info(&self) -> &'me AccountInfo<'info> {
         self.lst_state_list
@@ -69,6 +86,14 @@ impl<'me, 'info> GetLstStateListAccountInfo<'me, 'info>
     }
 }
 
+impl<'me, 'info> GetLstStateListAccountInfo<'me, 'info>
+    for WithdrawProtocolFeesSwapAccounts<'me, 'info>
+{
+    fn get_lst_state_list_account_info(&self) -> &'me AccountInfo<'info> {
+        self.lst_state_list
+    }
+}
+
 // impls for src_dst wrapper newtypes
 
 impl<'me, 'info, A: GetLstStateListAccountInfo<'me, 'info>> GetLstStateListAccountInfo<'me, 'info>
@@ -1,6 +1,7 @@
 use s_controller_interface::{
-    AddLiquidityAccounts, EndRebalanceAccounts, RemoveLiquidityAccounts,
-    SetSolValueCalculatorAccounts, SyncSolValueAccounts,
+    AddLiquidityAccounts, AddLiquidityExactOutAccounts, EndRebalanceAccounts,
+    RemoveLiquidityAccounts, RemoveLiquidityExactOutAccounts, SetSolValueCalculatorAccounts,
+    SyncSolValueAccounts,
 };
 use solana_program::account_info::AccountInfo;
 
@@ -42,6 +43,22 @@ impl<'me, 'info> GetPoolReservesAccountInfo<'me, 'info> for RemoveLiquidityAccou
     }
 }
 
+impl<'me, 'info> GetPoolReservesAccountInfo<'me, 'info>
+    for AddLiquidityExactOutAccounts<'me, 'info>
+{
+    fn get_pool_reserves_account_info(&self) -> &'me AccountInfo<'info> {
+        self.pool_reserves
+    }
+}
+
+impl<'me, 'info> GetPoolReservesAccountInfo<'me, 'info>
+    for RemoveLiquidityExactOutAccounts<'me, 'info>
+{
+    fn get_pool_reserves_account_info(&self) -> &'me AccountInfo<'info> {
+        self.pool_reserves
+    }
+}
+
 impl<'me, 'info> GetPoolReservesAccountInfo<'me, 'info>
     for SetSolValueCalculatorAccounts<'me, 'info>
 {
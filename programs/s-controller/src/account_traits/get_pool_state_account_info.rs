@@ -1,7 +1,8 @@
 use s_controller_interface::{
-    AddLiquidityAccounts, EndRebalanceAccounts, RemoveLiquidityAccounts,
-    SetSolValueCalculatorAccounts, StartRebalanceAccounts, SwapExactInAccounts,
-    SwapExactOutAccounts, SyncSolValueAccounts,
+    AddLiquidityAccounts, AddLiquidityExactOutAccounts, EndRebalanceAccounts,
+    RemoveLiquidityAccounts, RemoveLiquidityExactOutAccounts, SetSolValueCalculatorAccounts,
+    StartRebalanceAccounts, SwapExactInAccounts, SwapExactOutAccounts, SyncSolValueAccounts,
+    WithdrawProtocolFeesSwapAccounts,
 };
 use solana_program::account_info::AccountInfo;
 
@@ -49,6 +50,20 @@ impl<'me, 'info> GetPoolStateAccountInfo<'me, 'info> for RemoveLiquidityAccounts
     }
 }
 
+impl<'me, 'info> GetPoolStateAccountInfo<'me, 'info> for AddLiquidityExactOutAccounts<'me, 'info> {
+    fn get_pool_state_account_info(&self) -> &'me AccountInfo<'info> {
+        self.pool_state
+    }
+}
+
+impl<'me, 'info> GetPoolStateAccountInfo<'me, 'info>
+    for RemoveLiquidityExactOutAccounts<'me, 'info>
+{
+    fn get_pool_state_account_info(&self) -> &'me AccountInfo<'info> {
+        self.pool_state
+    }
+}
+
 impl<'me, 'info> GetPoolStateAccountInfo<'me, 'info> for SwapExactInAccounts<'me, 'info> {
     fn get_pool_state_account_info(&self) -> &'me AccountInfo<'info> {
         self.pool_state
@@ -67,6 +82,14 @@ impl<'me, 'info> GetPoolStateAccountInfo<'me, 'info> for SetSolValueCalculatorAc
     }
 }
 
+impl<'me, 'info> GetPoolStateAccountInfo<'me, 'info>
+    for WithdrawProtocolFeesSwapAccounts<'me, 'info>
+{
+    fn get_pool_state_account_info(&self) -> &'me AccountInfo<'info> {
+        self.pool_state
+    }
+}
+
 // impls for src_dst wrapper newtypes
 
 impl<'me, 'info, A: GetPoolStateAccountInfo<'me, 'info>> GetPoolStateAccountInfo<'me, 'info>
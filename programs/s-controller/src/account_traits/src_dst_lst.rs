@@ -1,4 +1,7 @@
-use s_controller_interface::{StartRebalanceAccounts, SwapExactInAccounts, SwapExactOutAccounts};
+use s_controller_interface::{
+    StartRebalanceAccounts, SwapExactInAccounts, SwapExactOutAccounts,
+    WithdrawProtocolFeesSwapAccounts,
+};
 use solana_program::account_info::AccountInfo;
 
 #[derive(Clone, Copy, Debug)]
@@ -61,6 +64,18 @@ impl<'me, 'info> GetSrcDstLstMintAccountInfo<'me, 'info> for SwapExactOutAccount
     }
 }
 
+impl<'me, 'info> GetSrcDstLstMintAccountInfo<'me, 'info>
+    for WithdrawProtocolFeesSwapAccounts<'me, 'info>
+{
+    fn get_src_lst_mint(&self) -> &'me AccountInfo<'info> {
+        self.src_lst_mint
+    }
+
+    fn get_dst_lst_mint(&self) -> &'me AccountInfo<'info> {
+        self.dst_lst_mint
+    }
+}
+
 pub trait GetSrcDstLstPoolReservesAccountInfo<'me, 'info> {
     fn get_src_lst_pool_reserves(&self) -> &'me AccountInfo<'info>;
     fn get_dst_lst_pool_reserves(&self) -> &'me AccountInfo<'info>;
@@ -121,3 +136,15 @@ impl<'me, 'info> GetSrcDstLstPoolReservesAccountInfo<'me, 'info>
         self.dst_pool_reserves
     }
 }
+
+impl<'me, 'info> GetSrcDstLstPoolReservesAccountInfo<'me, 'info>
+    for WithdrawProtocolFeesSwapAccounts<'me, 'info>
+{
+    fn get_src_lst_pool_reserves(&self) -> &'me AccountInfo<'info> {
+        self.src_pool_reserves
+    }
+
+    fn get_dst_lst_pool_reserves(&self) -> &'me AccountInfo<'info> {
+        self.dst_pool_reserves
+    }
+}
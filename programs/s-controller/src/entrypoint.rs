@@ -57,6 +57,40 @@ pub fn process_instruction(
         SControllerProgramIx::EndRebalance => process_end_rebalance(accounts),
         SControllerProgramIx::SetRebalanceAuthority => process_set_rebalance_authority(accounts),
         SControllerProgramIx::Initialize => process_initialize(accounts),
+        SControllerProgramIx::InitializePool => process_initialize_pool(accounts),
+        SControllerProgramIx::SwapExactInRoute(args) => process_swap_exact_in_route(accounts, args),
+        SControllerProgramIx::AddLiquidityMulti(args) => {
+            process_add_liquidity_multi(accounts, args)
+        }
+        SControllerProgramIx::RemoveLiquidityProRata(args) => {
+            process_remove_liquidity_pro_rata(accounts, args)
+        }
+        SControllerProgramIx::AddLiquidityExactOut(args) => {
+            process_add_liquidity_exact_out(accounts, args)
+        }
+        SControllerProgramIx::RemoveLiquidityExactOut(args) => {
+            process_remove_liquidity_exact_out(accounts, args)
+        }
+        SControllerProgramIx::SetTimelockDelay(args) => process_set_timelock_delay(accounts, args),
+        SControllerProgramIx::QueuePendingChange(args) => {
+            process_queue_pending_change(accounts, args)
+        }
+        SControllerProgramIx::ExecutePendingChange(args) => {
+            process_execute_pending_change(accounts, args)
+        }
+        SControllerProgramIx::CancelPendingChange(args) => {
+            process_cancel_pending_change(accounts, args)
+        }
+        SControllerProgramIx::SetLstCap(args) => process_set_lst_cap(accounts, args),
+        SControllerProgramIx::WithdrawProtocolFeesMulti(args) => {
+            process_withdraw_protocol_fees_multi(accounts, args)
+        }
+        SControllerProgramIx::WithdrawProtocolFeesSwap(args) => {
+            process_withdraw_protocol_fees_swap(accounts, args)
+        }
+        SControllerProgramIx::SetRebalanceLossLimit(args) => {
+            process_set_rebalance_loss_limit(accounts, args)
+        }
     };
     if let Err(e) = res.as_ref() {
         e.print::<SControllerError>();
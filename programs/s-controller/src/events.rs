@@ -0,0 +1,11 @@
+//! Event emission. See [`s_controller_lib::SControllerEvent`] for the log format.
+
+use borsh::BorshSerialize;
+use s_controller_lib::SControllerEvent;
+use solana_program::{account_info::AccountInfo, log::sol_log_data, program_error::ProgramError};
+
+pub fn emit_event(pool_state: &AccountInfo, event: &SControllerEvent) -> Result<(), ProgramError> {
+    let data = event.try_to_vec()?;
+    sol_log_data(&[pool_state.key.as_ref(), &data]);
+    Ok(())
+}
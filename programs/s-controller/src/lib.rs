@@ -1,6 +1,7 @@
 pub mod account_traits;
 pub mod cpi;
 pub mod entrypoint;
+pub mod events;
 pub mod list_account;
 pub mod processor;
 pub mod verify;
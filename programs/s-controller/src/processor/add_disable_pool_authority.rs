@@ -4,9 +4,8 @@ use s_controller_interface::{
     SControllerError,
 };
 use s_controller_lib::{
-    program::{DISABLE_POOL_AUTHORITY_LIST_BUMP, DISABLE_POOL_AUTHORITY_LIST_SEED},
-    try_disable_pool_authority_list, try_disable_pool_authority_list_mut,
-    AddDisablePoolAuthorityFreeArgs,
+    try_disable_pool_authority_list, try_disable_pool_authority_list_mut, try_pool_state,
+    AddDisablePoolAuthorityFreeArgs, Pool, SControllerEvent,
 };
 use sanctum_misc_utils::{
     load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
@@ -16,20 +15,34 @@ use solana_program::{
     pubkey::Pubkey,
 };
 
-use crate::list_account::{extend_list_pda, ExtendListPdaAccounts};
+use crate::{
+    events::emit_event,
+    list_account::{extend_list_pda, ExtendListPdaAccounts},
+    verify::verify_not_timelocked,
+};
 
 pub fn process_add_disable_pool_authority(accounts: &[AccountInfo]) -> ProgramResult {
-    let checked = verify_add_disable_pool_authority(accounts)?;
+    let (checked, pool) = verify_add_disable_pool_authority(accounts)?;
+
+    {
+        let pool_state_data = checked.pool_state.try_borrow_data()?;
+        verify_not_timelocked(try_pool_state(&pool_state_data)?)?;
+    }
 
+    add_disable_pool_authority_unchecked(checked, pool)
+}
+
+/// Does not check that the pool is not timelocked, for use by `ExecutePendingChange`
+pub fn add_disable_pool_authority_unchecked(
+    checked: AddDisablePoolAuthorityAccounts,
+    pool: Pool,
+) -> ProgramResult {
     extend_list_pda::<Pubkey>(
         ExtendListPdaAccounts {
             list_pda: checked.disable_pool_authority_list,
             payer: checked.payer,
         },
-        &[&[
-            DISABLE_POOL_AUTHORITY_LIST_SEED,
-            &[DISABLE_POOL_AUTHORITY_LIST_BUMP],
-        ]],
+        &[&pool.disable_pool_authority_list_seeds()],
     )?;
 
     let mut disable_pool_authority_list_data =
@@ -41,7 +54,12 @@ pub fn process_add_disable_pool_authority(accounts: &[AccountInfo]) -> ProgramRe
 
     *new_entry = *checked.new_authority.key;
 
-    Ok(())
+    emit_event(
+        checked.pool_state,
+        &SControllerEvent::AddDisablePoolAuthority {
+            authority: *checked.new_authority.key,
+        },
+    )
 }
 
 fn verify_not_duplicate(
@@ -57,17 +75,18 @@ fn verify_not_duplicate(
     }
 }
 
-fn verify_add_disable_pool_authority<'me, 'info>(
+pub fn verify_add_disable_pool_authority<'me, 'info>(
     accounts: &'me [AccountInfo<'info>],
-) -> Result<AddDisablePoolAuthorityAccounts<'me, 'info>, ProgramError> {
+) -> Result<(AddDisablePoolAuthorityAccounts<'me, 'info>, Pool), ProgramError> {
     let actual: AddDisablePoolAuthorityAccounts = load_accounts(accounts)?;
+    let pool = Pool::try_from_pool_state(actual.pool_state)?;
 
     let free_args = AddDisablePoolAuthorityFreeArgs {
         payer: *actual.payer.key,
         new_authority: *actual.new_authority.key,
         pool_state_acc: actual.pool_state,
     };
-    let expected = free_args.resolve()?;
+    let expected = free_args.resolve_for_pool(pool.pdas)?;
 
     add_disable_pool_authority_verify_account_keys(actual, expected)
         .map_err(log_and_return_wrong_acc_err)?;
@@ -79,5 +98,5 @@ fn verify_add_disable_pool_authority<'me, 'info>(
         *actual.new_authority.key,
     )?;
 
-    Ok(actual)
+    Ok((actual, pool))
 }
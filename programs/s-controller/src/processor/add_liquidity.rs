@@ -3,11 +3,10 @@ use s_controller_interface::{
     AddLiquidityAccounts, AddLiquidityIxArgs, SControllerError, ADD_LIQUIDITY_IX_ACCOUNTS_LEN,
 };
 use s_controller_lib::{
-    calc_add_liquidity_protocol_fees, calc_lp_tokens_to_mint, index_to_usize,
-    program::{POOL_STATE_BUMP, POOL_STATE_SEED},
-    try_lst_state_list, try_pool_state, AddLiquidityFreeArgs, AddLiquidityIxAmts,
-    AddLiquidityIxFullArgs, CalcAddLiquidityArgs, CalcAddLiquidityProtocolFeesResult,
-    LpTokenRateArgs, PoolStateAccount,
+    calc_add_liquidity_protocol_fees, calc_lp_tokens_to_mint, index_to_usize, try_lst_state_list,
+    try_pool_state, AddLiquidityFreeArgs, AddLiquidityIxAmts, AddLiquidityIxFullArgs,
+    CalcAddLiquidityArgs, CalcAddLiquidityProtocolFeesResult, LpTokenRateArgs, Pool,
+    PoolStateAccount, SControllerEvent,
 };
 use sanctum_misc_utils::{
     load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
@@ -22,9 +21,10 @@ use solana_program::{
 
 use crate::{
     cpi::{PricingProgramIxArgs, PricingProgramPriceLpCpi, SolValueCalculatorCpi},
+    events::emit_event,
     verify::{
-        verify_lp_cpis, verify_lst_input_not_disabled, verify_not_rebalancing_and_not_disabled,
-        VerifyLpCpiAccounts,
+        verify_lp_cpis, verify_lst_input_not_disabled, verify_lst_within_cap,
+        verify_not_rebalancing_and_not_disabled, VerifyLpCpiAccounts,
     },
 };
 
@@ -43,6 +43,7 @@ pub fn process_add_liquidity(accounts: &[AccountInfo], args: AddLiquidityIxArgs)
         },
         lst_cpi,
         pricing_cpi,
+        pool,
     ) = verify_add_liquidity(accounts, args)?;
 
     let sync_sol_value_accounts = SyncSolValueUncheckedAccounts::from(accounts);
@@ -118,7 +119,7 @@ pub fn process_add_liquidity(accounts: &[AccountInfo], args: AddLiquidityIxArgs)
             token_program: accounts.lp_token_program,
         },
         lp_tokens_to_mint,
-        &[&[POOL_STATE_SEED, &[POOL_STATE_BUMP]]],
+        &[&pool.pool_state_seeds()],
     )?;
     sync_sol_value_unchecked(sync_sol_value_accounts, lst_cpi, lst_index)?;
 
@@ -126,8 +127,19 @@ pub fn process_add_liquidity(accounts: &[AccountInfo], args: AddLiquidityIxArgs)
     if end_total_sol_value < start_total_sol_value {
         return Err(SControllerError::PoolWouldLoseSolValue.into());
     }
-
-    Ok(())
+    verify_lst_within_cap(accounts.pool_state, accounts.lst_state_list, lst_index)?;
+
+    emit_event(
+        accounts.pool_state,
+        &SControllerEvent::AddLiquidity {
+            lst_mint: *accounts.lst_mint.key,
+            lst_amount: to_reserves_lst_amount,
+            protocol_fee_amount: to_protocol_fees_lst_amount,
+            sol_value: lst_amount_sol_value,
+            sol_value_after_fees: lst_amount_sol_value_after_fees,
+            lp_tokens_minted: lp_tokens_to_mint,
+        },
+    )
 }
 
 fn verify_add_liquidity<'a, 'info>(
@@ -144,6 +156,7 @@ fn verify_add_liquidity<'a, 'info>(
         AddLiquidityIxFullArgs,
         SolValueCalculatorCpi<'a, 'info>,
         PricingProgramPriceLpCpi<'a, 'info>,
+        Pool,
     ),
     ProgramError,
 > {
@@ -154,6 +167,7 @@ fn verify_add_liquidity<'a, 'info>(
     let lst_index = index_to_usize(lst_index)?;
 
     let actual: AddLiquidityAccounts = load_accounts(accounts)?;
+    let pool = Pool::try_from_pool_state(actual.pool_state)?;
 
     let free_args = AddLiquidityFreeArgs {
         lst_index,
@@ -164,7 +178,7 @@ fn verify_add_liquidity<'a, 'info>(
         lst_state_list: actual.lst_state_list,
         lst_mint: actual.lst_mint,
     };
-    let expected = free_args.resolve()?;
+    let expected = free_args.resolve_for_pool(pool.pdas)?;
 
     add_liquidity_verify_account_keys(actual, expected).map_err(log_and_return_wrong_acc_err)?;
     add_liquidity_verify_account_privileges(actual).map_err(log_and_return_acc_privilege_err)?;
@@ -201,5 +215,6 @@ fn verify_add_liquidity<'a, 'info>(
         },
         lst_cpi,
         pricing_cpi,
+        pool,
     ))
 }
@@ -0,0 +1,272 @@
+use s_controller_interface::{
+    add_liquidity_exact_out_verify_account_keys, add_liquidity_exact_out_verify_account_privileges,
+    AddLiquidityExactOutAccounts, AddLiquidityExactOutIxArgs, SControllerError,
+    ADD_LIQUIDITY_EXACT_OUT_IX_ACCOUNTS_LEN,
+};
+use s_controller_lib::{
+    calc_add_liquidity_protocol_fees, calc_exact_out_sol_value_before_fees,
+    calc_lp_tokens_to_mint_sol_value, index_to_usize, try_lst_state_list, try_pool_state,
+    AddLiquidityExactOutIxAmts, AddLiquidityExactOutIxFullArgs, AddLiquidityFreeArgs,
+    CalcAddLiquidityArgs, CalcAddLiquidityProtocolFeesResult, ExactOutPricingArgs, LpTokenRateArgs,
+    Pool, PoolStateAccount, SControllerEvent,
+};
+use sanctum_misc_utils::{
+    load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
+};
+use sanctum_token_lib::{
+    mint_supply, mint_to_invoke_signed, transfer_checked_decimal_agnostic_invoke, MintToAccounts,
+    TransferCheckedAccounts,
+};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+};
+
+use crate::{
+    cpi::{PricingProgramIxArgs, PricingProgramPriceLpCpi, SolValueCalculatorCpi},
+    events::emit_event,
+    verify::{
+        verify_lp_cpis, verify_lst_input_not_disabled, verify_lst_within_cap,
+        verify_not_rebalancing_and_not_disabled, VerifyLpCpiAccounts,
+    },
+};
+
+use super::{sync_sol_value_unchecked, SyncSolValueUncheckedAccounts};
+
+#[derive(Clone, Copy, Debug)]
+struct PricedLstAmount {
+    lst_amount: u64,
+    lst_amount_sol_value: u64,
+    lst_amount_sol_value_after_fees: u64,
+}
+
+pub fn process_add_liquidity_exact_out(
+    accounts: &[AccountInfo],
+    args: AddLiquidityExactOutIxArgs,
+) -> ProgramResult {
+    let (
+        accounts,
+        AddLiquidityExactOutIxFullArgs {
+            lst_index,
+            amts:
+                AddLiquidityExactOutIxAmts {
+                    lp_token_amount,
+                    max_lst_in,
+                },
+        },
+        lst_cpi,
+        pricing_cpi,
+        pool,
+    ) = verify_add_liquidity_exact_out(accounts, args)?;
+
+    let sync_sol_value_accounts = SyncSolValueUncheckedAccounts::from(accounts);
+
+    sync_sol_value_unchecked(sync_sol_value_accounts, lst_cpi, lst_index)?;
+
+    let start_total_sol_value = accounts.pool_state.total_sol_value()?;
+
+    let lp_token_supply = mint_supply(accounts.lp_token_mint)?;
+    let target_sol_value_after_fees = calc_lp_tokens_to_mint_sol_value(
+        LpTokenRateArgs {
+            lp_token_supply,
+            pool_total_sol_value: start_total_sol_value,
+        },
+        lp_token_amount,
+    )?;
+    if target_sol_value_after_fees == 0 {
+        return Err(SControllerError::ZeroValue.into());
+    }
+
+    let mut priced =
+        price_lst_amount_for_sol_value(lst_cpi, pricing_cpi, target_sol_value_after_fees)?;
+    if priced.lst_amount_sol_value_after_fees < target_sol_value_after_fees {
+        let sol_value_before_fees = calc_exact_out_sol_value_before_fees(
+            ExactOutPricingArgs {
+                sol_value: priced.lst_amount_sol_value,
+                sol_value_after_fees: priced.lst_amount_sol_value_after_fees,
+            },
+            target_sol_value_after_fees,
+        )?;
+        priced = price_lst_amount_for_sol_value(lst_cpi, pricing_cpi, sol_value_before_fees)?;
+    }
+    let PricedLstAmount {
+        lst_amount,
+        lst_amount_sol_value,
+        lst_amount_sol_value_after_fees,
+    } = priced;
+    // Will dilute existing LPs if unchecked
+    if lst_amount_sol_value_after_fees > lst_amount_sol_value
+        || lst_amount_sol_value_after_fees < target_sol_value_after_fees
+    {
+        return Err(SControllerError::PoolWouldLoseSolValue.into());
+    }
+
+    if lst_amount > max_lst_in {
+        return Err(SControllerError::SlippageToleranceExceeded.into());
+    }
+
+    let CalcAddLiquidityProtocolFeesResult {
+        to_reserves_lst_amount,
+        to_protocol_fees_lst_amount,
+    } = calc_add_liquidity_protocol_fees(CalcAddLiquidityArgs {
+        lst_amount,
+        lst_amount_sol_value,
+        lst_amount_sol_value_after_fees,
+        lp_protocol_fee_bps: accounts.pool_state.lp_protocol_fee_bps()?,
+    })?;
+
+    if to_reserves_lst_amount == 0 {
+        return Err(SControllerError::ZeroValue.into());
+    }
+
+    transfer_checked_decimal_agnostic_invoke(
+        TransferCheckedAccounts {
+            from: accounts.src_lst_acc,
+            to: accounts.pool_reserves,
+            token_program: accounts.lst_token_program,
+            authority: accounts.signer,
+            mint: accounts.lst_mint,
+        },
+        to_reserves_lst_amount,
+    )?;
+    transfer_checked_decimal_agnostic_invoke(
+        TransferCheckedAccounts {
+            from: accounts.src_lst_acc,
+            to: accounts.protocol_fee_accumulator,
+            token_program: accounts.lst_token_program,
+            authority: accounts.signer,
+            mint: accounts.lst_mint,
+        },
+        to_protocol_fees_lst_amount,
+    )?;
+    mint_to_invoke_signed(
+        MintToAccounts {
+            mint: accounts.lp_token_mint,
+            mint_to: accounts.dst_lp_acc,
+            mint_authority: accounts.pool_state,
+            token_program: accounts.lp_token_program,
+        },
+        lp_token_amount,
+        &[&pool.pool_state_seeds()],
+    )?;
+    sync_sol_value_unchecked(sync_sol_value_accounts, lst_cpi, lst_index)?;
+
+    let end_total_sol_value = accounts.pool_state.total_sol_value()?;
+    if end_total_sol_value < start_total_sol_value {
+        return Err(SControllerError::PoolWouldLoseSolValue.into());
+    }
+    verify_lst_within_cap(accounts.pool_state, accounts.lst_state_list, lst_index)?;
+
+    emit_event(
+        accounts.pool_state,
+        &SControllerEvent::AddLiquidity {
+            lst_mint: *accounts.lst_mint.key,
+            lst_amount: to_reserves_lst_amount,
+            protocol_fee_amount: to_protocol_fees_lst_amount,
+            sol_value: lst_amount_sol_value,
+            sol_value_after_fees: lst_amount_sol_value_after_fees,
+            lp_tokens_minted: lp_token_amount,
+        },
+    )
+}
+
+/// Prices the min amount of LST worth at least `sol_value`
+fn price_lst_amount_for_sol_value(
+    lst_cpi: SolValueCalculatorCpi,
+    pricing_cpi: PricingProgramPriceLpCpi,
+    sol_value: u64,
+) -> Result<PricedLstAmount, ProgramError> {
+    let lst_amount = lst_cpi.invoke_sol_to_lst(sol_value)?.get_max();
+    if lst_amount == 0 {
+        return Err(SControllerError::ZeroValue.into());
+    }
+    let lst_amount_sol_value = lst_cpi.invoke_lst_to_sol(lst_amount)?.get_min();
+    let lst_amount_sol_value_after_fees =
+        pricing_cpi.invoke_price_lp_tokens_to_mint(PricingProgramIxArgs {
+            amount: lst_amount,
+            sol_value: lst_amount_sol_value,
+        })?;
+    Ok(PricedLstAmount {
+        lst_amount,
+        lst_amount_sol_value,
+        lst_amount_sol_value_after_fees,
+    })
+}
+
+fn verify_add_liquidity_exact_out<'a, 'info>(
+    accounts: &'a [AccountInfo<'info>],
+    AddLiquidityExactOutIxArgs {
+        lst_value_calc_accs,
+        lst_index,
+        lp_token_amount,
+        max_lst_in,
+    }: AddLiquidityExactOutIxArgs,
+) -> Result<
+    (
+        AddLiquidityExactOutAccounts<'a, 'info>,
+        AddLiquidityExactOutIxFullArgs,
+        SolValueCalculatorCpi<'a, 'info>,
+        PricingProgramPriceLpCpi<'a, 'info>,
+        Pool,
+    ),
+    ProgramError,
+> {
+    if lp_token_amount == 0 {
+        return Err(SControllerError::ZeroValue.into());
+    }
+
+    let lst_index = index_to_usize(lst_index)?;
+
+    let actual: AddLiquidityExactOutAccounts = load_accounts(accounts)?;
+    let pool = Pool::try_from_pool_state(actual.pool_state)?;
+
+    let free_args = AddLiquidityFreeArgs {
+        lst_index,
+        signer: *actual.signer.key,
+        src_lst_acc: *actual.src_lst_acc.key,
+        dst_lp_acc: *actual.dst_lp_acc.key,
+        pool_state: actual.pool_state,
+        lst_state_list: actual.lst_state_list,
+        lst_mint: actual.lst_mint,
+    };
+    let expected = free_args.resolve_exact_out_for_pool(pool.pdas)?;
+
+    add_liquidity_exact_out_verify_account_keys(actual, expected)
+        .map_err(log_and_return_wrong_acc_err)?;
+    add_liquidity_exact_out_verify_account_privileges(actual)
+        .map_err(log_and_return_acc_privilege_err)?;
+
+    let pool_state_bytes = actual.pool_state.try_borrow_data()?;
+    let pool_state = try_pool_state(&pool_state_bytes)?;
+    verify_not_rebalancing_and_not_disabled(pool_state)?;
+
+    let lst_state_list_bytes = actual.lst_state_list.try_borrow_data()?;
+    let lst_state_list = try_lst_state_list(&lst_state_list_bytes)?;
+    // lst_index checked above
+    let lst_state = lst_state_list[lst_index];
+    verify_lst_input_not_disabled(&lst_state)?;
+
+    let accounts_suffix_slice = accounts
+        .get(ADD_LIQUIDITY_EXACT_OUT_IX_ACCOUNTS_LEN..)
+        .ok_or(ProgramError::NotEnoughAccountKeys)?;
+
+    let (lst_cpi, pricing_cpi) = verify_lp_cpis(
+        VerifyLpCpiAccounts::from(actual),
+        accounts_suffix_slice,
+        lst_value_calc_accs,
+        lst_index,
+    )?;
+
+    Ok((
+        actual,
+        AddLiquidityExactOutIxFullArgs {
+            lst_index,
+            amts: AddLiquidityExactOutIxAmts {
+                lp_token_amount,
+                max_lst_in,
+            },
+        },
+        lst_cpi,
+        pricing_cpi,
+        pool,
+    ))
+}
@@ -0,0 +1,263 @@
+use s_controller_interface::{
+    add_liquidity_multi_verify_account_keys, add_liquidity_multi_verify_account_privileges,
+    AddLiquidityMultiAccounts, AddLiquidityMultiIxArgs, SControllerError,
+    ADD_LIQUIDITY_MULTI_IX_ACCOUNTS_LEN,
+};
+use s_controller_lib::{
+    calc_add_liquidity_protocol_fees, calc_lp_tokens_to_mint, index_to_usize, try_lst_state_list,
+    try_pool_state, AddLiquidityMultiFreeArgs, CalcAddLiquidityArgs,
+    CalcAddLiquidityProtocolFeesResult, LiquidityLstAmounts, LpTokenRateArgs, Pool,
+    PoolStateAccount, SControllerEvent,
+};
+use sanctum_misc_utils::{
+    load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
+};
+use sanctum_token_lib::{
+    mint_supply, mint_to_invoke_signed, transfer_checked_decimal_agnostic_invoke, MintToAccounts,
+    TransferCheckedAccounts,
+};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+};
+
+use crate::{
+    cpi::PricingProgramIxArgs,
+    events::emit_event,
+    verify::{
+        verify_lst_input_not_disabled, verify_lst_within_cap,
+        verify_not_rebalancing_and_not_disabled,
+    },
+};
+
+use super::{
+    sync_sol_value_unchecked, verify_multi_liquidity_lsts, MultiLiquidityLst,
+    VerifyMultiLiquidityLstsAccounts,
+};
+
+pub fn process_add_liquidity_multi(
+    accounts: &[AccountInfo],
+    args: AddLiquidityMultiIxArgs,
+) -> ProgramResult {
+    let (accounts, lsts, lst_amounts, min_lp_out, pool) =
+        verify_add_liquidity_multi(accounts, args)?;
+
+    for lst in lsts.iter() {
+        sync_sol_value_unchecked(
+            lst.sync_sol_value_accounts(accounts.pool_state, accounts.lst_state_list),
+            lst.cpi,
+            lst.lst_index,
+        )?;
+    }
+
+    let start_total_sol_value = accounts.pool_state.total_sol_value()?;
+    let lp_protocol_fee_bps = accounts.pool_state.lp_protocol_fee_bps()?;
+
+    // LP tokens are minted once for the sum of every LST's SOL value after fees,
+    // but each LST is still priced individually
+    let mut total_sol_value_after_fees: u64 = 0;
+    let mut lst_transfer_amounts = Vec::with_capacity(lsts.len());
+    for (lst, lst_amount) in lsts.iter().zip(lst_amounts) {
+        let lst_amount_sol_value = lst.cpi.invoke_lst_to_sol(lst_amount)?.get_min();
+        let lst_amount_sol_value_after_fees =
+            lst.pricing_cpi
+                .invoke_price_lp_tokens_to_mint(PricingProgramIxArgs {
+                    amount: lst_amount,
+                    sol_value: lst_amount_sol_value,
+                })?;
+        // Will dilute existing LPs if unchecked
+        if lst_amount_sol_value_after_fees > lst_amount_sol_value {
+            return Err(SControllerError::PoolWouldLoseSolValue.into());
+        }
+
+        let fees_result = calc_add_liquidity_protocol_fees(CalcAddLiquidityArgs {
+            lst_amount,
+            lst_amount_sol_value,
+            lst_amount_sol_value_after_fees,
+            lp_protocol_fee_bps,
+        })?;
+        if fees_result.to_reserves_lst_amount == 0 {
+            return Err(SControllerError::ZeroValue.into());
+        }
+
+        total_sol_value_after_fees = total_sol_value_after_fees
+            .checked_add(lst_amount_sol_value_after_fees)
+            .ok_or(SControllerError::MathError)?;
+        lst_transfer_amounts.push(fees_result);
+    }
+
+    let lp_token_supply = mint_supply(accounts.lp_token_mint)?;
+    let lp_tokens_to_mint = calc_lp_tokens_to_mint(
+        LpTokenRateArgs {
+            lp_token_supply,
+            pool_total_sol_value: start_total_sol_value,
+        },
+        total_sol_value_after_fees,
+    )?;
+
+    if lp_tokens_to_mint == 0 {
+        return Err(SControllerError::ZeroValue.into());
+    }
+
+    if lp_tokens_to_mint < min_lp_out {
+        return Err(SControllerError::SlippageToleranceExceeded.into());
+    }
+
+    let mut event_lsts = Vec::with_capacity(lsts.len());
+    for (
+        lst,
+        CalcAddLiquidityProtocolFeesResult {
+            to_reserves_lst_amount,
+            to_protocol_fees_lst_amount,
+        },
+    ) in lsts.iter().zip(lst_transfer_amounts)
+    {
+        transfer_checked_decimal_agnostic_invoke(
+            TransferCheckedAccounts {
+                from: lst.lst_acc,
+                to: lst.pool_reserves,
+                token_program: lst.lst_token_program,
+                authority: accounts.signer,
+                mint: lst.lst_mint,
+            },
+            to_reserves_lst_amount,
+        )?;
+        transfer_checked_decimal_agnostic_invoke(
+            TransferCheckedAccounts {
+                from: lst.lst_acc,
+                to: lst.protocol_fee_accumulator,
+                token_program: lst.lst_token_program,
+                authority: accounts.signer,
+                mint: lst.lst_mint,
+            },
+            to_protocol_fees_lst_amount,
+        )?;
+        event_lsts.push(LiquidityLstAmounts {
+            lst_mint: *lst.lst_mint.key,
+            lst_amount: to_reserves_lst_amount,
+            protocol_fee_amount: to_protocol_fees_lst_amount,
+        });
+    }
+    mint_to_invoke_signed(
+        MintToAccounts {
+            mint: accounts.lp_token_mint,
+            mint_to: accounts.dst_lp_acc,
+            mint_authority: accounts.pool_state,
+            token_program: accounts.lp_token_program,
+        },
+        lp_tokens_to_mint,
+        &[&pool.pool_state_seeds()],
+    )?;
+
+    for lst in lsts.iter() {
+        sync_sol_value_unchecked(
+            lst.sync_sol_value_accounts(accounts.pool_state, accounts.lst_state_list),
+            lst.cpi,
+            lst.lst_index,
+        )?;
+    }
+
+    let end_total_sol_value = accounts.pool_state.total_sol_value()?;
+    if end_total_sol_value < start_total_sol_value {
+        return Err(SControllerError::PoolWouldLoseSolValue.into());
+    }
+    for lst in lsts.iter() {
+        verify_lst_within_cap(accounts.pool_state, accounts.lst_state_list, lst.lst_index)?;
+    }
+
+    emit_event(
+        accounts.pool_state,
+        &SControllerEvent::AddLiquidityMulti {
+            lsts: event_lsts,
+            lp_tokens_minted: lp_tokens_to_mint,
+        },
+    )
+}
+
+fn verify_add_liquidity_multi<'a, 'info>(
+    accounts: &'a [AccountInfo<'info>],
+    AddLiquidityMultiIxArgs {
+        lst_value_calc_accs,
+        pricing_accs,
+        lst_indexes,
+        lst_amounts,
+        min_lp_out,
+    }: AddLiquidityMultiIxArgs,
+) -> Result<
+    (
+        AddLiquidityMultiAccounts<'a, 'info>,
+        Vec<MultiLiquidityLst<'a, 'info>>,
+        Vec<u64>,
+        u64,
+        Pool,
+    ),
+    ProgramError,
+> {
+    let n_lsts = lst_indexes.len();
+    if n_lsts == 0
+        || lst_amounts.len() != n_lsts
+        || lst_value_calc_accs.len() != n_lsts
+        || pricing_accs.len() != n_lsts
+    {
+        return Err(SControllerError::InvalidLiquidityLsts.into());
+    }
+    if lst_amounts.iter().any(|amt| *amt == 0) {
+        return Err(SControllerError::ZeroValue.into());
+    }
+    let lst_indexes = lst_indexes
+        .into_iter()
+        .map(index_to_usize)
+        .collect::<Result<Vec<_>, _>>()?;
+    for (i, lst_index) in lst_indexes.iter().enumerate() {
+        if lst_indexes[..i].contains(lst_index) {
+            return Err(SControllerError::InvalidLiquidityLsts.into());
+        }
+    }
+
+    let actual: AddLiquidityMultiAccounts = load_accounts(accounts)?;
+    let pool = Pool::try_from_pool_state(actual.pool_state)?;
+
+    let free_args = AddLiquidityMultiFreeArgs {
+        signer: *actual.signer.key,
+        dst_lp_acc: *actual.dst_lp_acc.key,
+        pool_state: actual.pool_state,
+    };
+    let expected = free_args.resolve_for_pool(pool.pdas)?;
+
+    add_liquidity_multi_verify_account_keys(actual, expected)
+        .map_err(log_and_return_wrong_acc_err)?;
+    add_liquidity_multi_verify_account_privileges(actual)
+        .map_err(log_and_return_acc_privilege_err)?;
+
+    {
+        let pool_state_bytes = actual.pool_state.try_borrow_data()?;
+        let pool_state = try_pool_state(&pool_state_bytes)?;
+        verify_not_rebalancing_and_not_disabled(pool_state)?;
+
+        let lst_state_list_bytes = actual.lst_state_list.try_borrow_data()?;
+        let lst_state_list = try_lst_state_list(&lst_state_list_bytes)?;
+        for lst_index in lst_indexes.iter() {
+            let lst_state = lst_state_list
+                .get(*lst_index)
+                .ok_or(SControllerError::InvalidLstIndex)?;
+            verify_lst_input_not_disabled(lst_state)?;
+        }
+    }
+
+    let accounts_suffix_slice = accounts
+        .get(ADD_LIQUIDITY_MULTI_IX_ACCOUNTS_LEN..)
+        .ok_or(ProgramError::NotEnoughAccountKeys)?;
+
+    let lsts = verify_multi_liquidity_lsts(
+        VerifyMultiLiquidityLstsAccounts {
+            pool_state: actual.pool_state,
+            lst_state_list: actual.lst_state_list,
+        },
+        pool.pdas.into(),
+        accounts_suffix_slice,
+        lst_indexes,
+        lst_value_calc_accs,
+        pricing_accs,
+    )?;
+
+    Ok((actual, lsts, lst_amounts, min_lp_out, pool))
+}
@@ -3,8 +3,8 @@ use s_controller_interface::{
     SControllerError,
 };
 use s_controller_lib::{
-    program::{LST_STATE_LIST_BUMP, LST_STATE_LIST_SEED},
     try_lst_state_list, try_lst_state_list_mut, try_pool_state, AddLstFreeArgs, LstStateBumps,
+    Pool, SControllerEvent,
 };
 use sanctum_associated_token_lib::{create_ata_invoke, CreateAtaAccounts};
 use sanctum_misc_utils::{
@@ -17,19 +17,34 @@ use solana_program::{
 };
 
 use crate::{
+    events::emit_event,
     list_account::{extend_list_pda, ExtendListPdaAccounts},
-    verify::{verify_not_rebalancing_and_not_disabled, verify_sol_value_calculator_is_program},
+    verify::{
+        verify_not_rebalancing_and_not_disabled, verify_not_timelocked,
+        verify_sol_value_calculator_is_program,
+    },
 };
 
 pub fn process_add_lst(accounts: &[AccountInfo]) -> ProgramResult {
-    let (
-        accounts,
-        LstStateBumps {
-            protocol_fee_accumulator: protocol_fee_accumulator_bump,
-            pool_reserves: pool_reserves_bump,
-        },
-    ) = verify_add_lst(accounts)?;
+    let (accounts, bumps, pool) = verify_add_lst(accounts)?;
+
+    {
+        let pool_state_data = accounts.pool_state.try_borrow_data()?;
+        verify_not_timelocked(try_pool_state(&pool_state_data)?)?;
+    }
 
+    add_lst_unchecked(accounts, bumps, pool)
+}
+
+/// Does not check that the pool is not timelocked, for use by `ExecutePendingChange`
+pub fn add_lst_unchecked(
+    accounts: AddLstAccounts,
+    LstStateBumps {
+        protocol_fee_accumulator: protocol_fee_accumulator_bump,
+        pool_reserves: pool_reserves_bump,
+    }: LstStateBumps,
+    pool: Pool,
+) -> ProgramResult {
     create_ata_if_not_exist(CreateAtaAccounts {
         ata_to_create: accounts.pool_reserves,
         wallet: accounts.pool_state,
@@ -55,7 +70,7 @@ pub fn process_add_lst(accounts: &[AccountInfo]) -> ProgramResult {
             list_pda: accounts.lst_state_list,
             payer: accounts.payer,
         },
-        &[&[LST_STATE_LIST_SEED, &[LST_STATE_LIST_BUMP]]],
+        &[&pool.lst_state_list_seeds()],
     )?;
 
     let mut lst_state_list_data = accounts.lst_state_list.try_borrow_mut_data()?;
@@ -71,10 +86,17 @@ pub fn process_add_lst(accounts: &[AccountInfo]) -> ProgramResult {
         mint: *accounts.lst_mint.key,
         sol_value_calculator: *accounts.sol_value_calculator.key,
         is_input_disabled: 0,
-        padding: [0u8; 5],
+        padding: [0u8; 3],
+        max_share_bps: 0,
     };
 
-    Ok(())
+    emit_event(
+        accounts.pool_state,
+        &SControllerEvent::AddLst {
+            lst_mint: *accounts.lst_mint.key,
+            sol_value_calculator: *accounts.sol_value_calculator.key,
+        },
+    )
 }
 
 fn create_ata_if_not_exist(accounts: CreateAtaAccounts) -> Result<(), ProgramError> {
@@ -100,10 +122,11 @@ fn verify_not_duplicate(
     }
 }
 
-fn verify_add_lst<'a, 'info>(
+pub fn verify_add_lst<'a, 'info>(
     accounts: &'a [AccountInfo<'info>],
-) -> Result<(AddLstAccounts<'a, 'info>, LstStateBumps), ProgramError> {
+) -> Result<(AddLstAccounts<'a, 'info>, LstStateBumps, Pool), ProgramError> {
     let actual: AddLstAccounts = load_accounts(accounts)?;
+    let pool = Pool::try_from_pool_state(actual.pool_state)?;
 
     let free_args = AddLstFreeArgs {
         payer: *actual.payer.key,
@@ -111,7 +134,8 @@ fn verify_add_lst<'a, 'info>(
         pool_state: actual.pool_state,
         lst_mint: actual.lst_mint,
     };
-    let (expected, bumps) = free_args.resolve()?;
+    // also rejects the pool's own LP token, whose SOL value would be circular
+    let (expected, bumps) = free_args.resolve_for_pool(pool.pdas)?;
 
     add_lst_verify_account_keys(actual, expected).map_err(log_and_return_wrong_acc_err)?;
     add_lst_verify_account_privileges(actual).map_err(log_and_return_acc_privilege_err)?;
@@ -125,5 +149,5 @@ fn verify_add_lst<'a, 'info>(
     verify_not_duplicate(actual.lst_state_list, *actual.lst_mint.key)?;
     verify_not_rebalancing_and_not_disabled(pool_state)?;
 
-    Ok((actual, bumps))
+    Ok((actual, bumps, pool))
 }
@@ -0,0 +1,85 @@
+use s_controller_interface::{
+    cancel_pending_change_verify_account_keys, cancel_pending_change_verify_account_privileges,
+    CancelPendingChangeAccounts, CancelPendingChangeIxArgs, PendingChange, SControllerError,
+};
+use s_controller_lib::{
+    index_to_usize, try_pending_change_list, try_pool_state, CancelPendingChangeFreeArgs, Pool,
+    SControllerEvent,
+};
+use sanctum_misc_utils::{
+    load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
+};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+};
+
+use crate::{
+    events::emit_event,
+    list_account::{remove_from_list_pda, RemoveFromListPdaAccounts},
+    verify::verify_admin_or_disable_pool_authority,
+};
+
+pub fn process_cancel_pending_change(
+    accounts: &[AccountInfo],
+    args: CancelPendingChangeIxArgs,
+) -> ProgramResult {
+    let (checked, index, pending_change) = verify_cancel_pending_change(accounts, args)?;
+
+    remove_from_list_pda::<PendingChange>(
+        RemoveFromListPdaAccounts {
+            list_pda: checked.pending_change_list,
+            refund_rent_to: checked.refund_rent_to,
+        },
+        index,
+    )?;
+
+    emit_event(
+        checked.pool_state,
+        &SControllerEvent::CancelPendingChange { pending_change },
+    )
+}
+
+fn verify_cancel_pending_change<'me, 'info>(
+    accounts: &'me [AccountInfo<'info>],
+    CancelPendingChangeIxArgs {
+        pending_change_index,
+    }: CancelPendingChangeIxArgs,
+) -> Result<
+    (
+        CancelPendingChangeAccounts<'me, 'info>,
+        usize,
+        PendingChange,
+    ),
+    ProgramError,
+> {
+    let actual: CancelPendingChangeAccounts = load_accounts(accounts)?;
+    let pool = Pool::try_from_pool_state(actual.pool_state)?;
+    let index = index_to_usize(pending_change_index)?;
+
+    let free_args = CancelPendingChangeFreeArgs {
+        signer: *actual.signer.key,
+        refund_rent_to: *actual.refund_rent_to.key,
+    };
+    let expected = free_args.resolve_for_pool(pool.pdas);
+
+    cancel_pending_change_verify_account_keys(actual, expected)
+        .map_err(log_and_return_wrong_acc_err)?;
+    cancel_pending_change_verify_account_privileges(actual)
+        .map_err(log_and_return_acc_privilege_err)?;
+
+    let pool_state_bytes = actual.pool_state.try_borrow_data()?;
+    let pool_state = try_pool_state(&pool_state_bytes)?;
+    verify_admin_or_disable_pool_authority(
+        *actual.signer.key,
+        pool_state,
+        actual.disable_pool_authority_list,
+    )?;
+
+    let pending_change_list_data = actual.pending_change_list.try_borrow_data()?;
+    let list = try_pending_change_list(&pending_change_list_data)?;
+    let pending_change = *list
+        .get(index)
+        .ok_or(SControllerError::InvalidPendingChange)?;
+
+    Ok((actual, index, pending_change))
+}
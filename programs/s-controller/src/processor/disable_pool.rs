@@ -1,7 +1,9 @@
 use s_controller_interface::{
     disable_pool_verify_account_keys, disable_pool_verify_account_privileges, DisablePoolAccounts,
 };
-use s_controller_lib::{try_pool_state, try_pool_state_mut, DisablePoolFreeArgs, U8BoolMut};
+use s_controller_lib::{
+    try_pool_state, try_pool_state_mut, DisablePoolFreeArgs, Pool, SControllerEvent, U8BoolMut,
+};
 use sanctum_misc_utils::{
     load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
 };
@@ -9,8 +11,9 @@ use solana_program::{
     account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
 };
 
-use crate::verify::{
-    verify_admin_or_disable_pool_authority, verify_not_rebalancing_and_not_disabled,
+use crate::{
+    events::emit_event,
+    verify::{verify_admin_or_disable_pool_authority, verify_not_rebalancing_and_not_disabled},
 };
 
 pub fn process_disable_pool(accounts: &[AccountInfo]) -> ProgramResult {
@@ -20,23 +23,30 @@ pub fn process_disable_pool(accounts: &[AccountInfo]) -> ProgramResult {
         disable_pool_authority_list: _,
     } = verify_disable_pool(accounts)?;
 
-    let mut pool_state_bytes = pool_state.try_borrow_mut_data()?;
-    let pool_state = try_pool_state_mut(&mut pool_state_bytes)?;
+    // braces to limit scope of pool_state_bytes borrow
+    {
+        let mut pool_state_bytes = pool_state.try_borrow_mut_data()?;
+        let pool_state = try_pool_state_mut(&mut pool_state_bytes)?;
 
-    U8BoolMut(&mut pool_state.is_disabled).set_true();
+        U8BoolMut(&mut pool_state.is_disabled).set_true();
+    }
 
-    Ok(())
+    emit_event(
+        pool_state,
+        &SControllerEvent::SetPoolDisabled { is_disabled: true },
+    )
 }
 
 fn verify_disable_pool<'me, 'info>(
     accounts: &'me [AccountInfo<'info>],
 ) -> Result<DisablePoolAccounts<'me, 'info>, ProgramError> {
     let actual: DisablePoolAccounts = load_accounts(accounts)?;
+    let pool = Pool::try_from_pool_state(actual.pool_state)?;
 
     let free_args = DisablePoolFreeArgs {
         signer: *actual.signer.key,
     };
-    let expected = free_args.resolve();
+    let expected = free_args.resolve_for_pool(pool.pdas);
 
     disable_pool_verify_account_keys(actual, expected).map_err(log_and_return_wrong_acc_err)?;
     disable_pool_verify_account_privileges(actual).map_err(log_and_return_acc_privilege_err)?;
@@ -3,8 +3,8 @@ use s_controller_interface::{
     EnableLstInputAccounts, EnableLstInputIxArgs,
 };
 use s_controller_lib::{
-    index_to_usize, try_lst_state_list_mut, try_pool_state, DisableEnableLstInputFreeArgs,
-    U8BoolMut,
+    index_to_usize, try_lst_state_list_mut, try_pool_state, DisableEnableLstInputFreeArgs, Pool,
+    SControllerEvent, U8BoolMut,
 };
 use sanctum_misc_utils::{
     load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
@@ -13,7 +13,7 @@ use solana_program::{
     account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
 };
 
-use crate::verify::verify_not_rebalancing_and_not_disabled;
+use crate::{events::emit_event, verify::verify_not_rebalancing_and_not_disabled};
 
 pub fn process_enable_lst_input(
     accounts: &[AccountInfo],
@@ -27,7 +27,13 @@ pub fn process_enable_lst_input(
     // lst_index checked in verify
     U8BoolMut(&mut lst_state_list[lst_index].is_input_disabled).set_false();
 
-    Ok(())
+    emit_event(
+        accounts.pool_state,
+        &SControllerEvent::SetLstInputDisabled {
+            lst_mint: *accounts.lst_mint.key,
+            is_input_disabled: false,
+        },
+    )
 }
 
 fn verify_enable_lst_input<'me, 'info>(
@@ -37,13 +43,14 @@ fn verify_enable_lst_input<'me, 'info>(
     let lst_index = index_to_usize(index)?;
 
     let actual: EnableLstInputAccounts = load_accounts(accounts)?;
+    let pool = Pool::try_from_pool_state(actual.pool_state)?;
 
     let free_args = DisableEnableLstInputFreeArgs {
         lst_index,
         pool_state: actual.pool_state,
         lst_state_list: actual.lst_state_list,
     };
-    let expected = free_args.resolve_enable()?;
+    let expected = free_args.resolve_enable_for_pool(pool.pdas)?;
 
     enable_lst_input_verify_account_keys(actual, expected).map_err(log_and_return_wrong_acc_err)?;
     enable_lst_input_verify_account_privileges(actual).map_err(log_and_return_acc_privilege_err)?;
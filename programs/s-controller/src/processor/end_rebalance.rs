@@ -4,19 +4,22 @@ use s_controller_interface::{
     END_REBALANCE_IX_ACCOUNTS_LEN,
 };
 use s_controller_lib::{
-    try_pool_state, try_pool_state_mut, try_rebalance_record, EndRebalanceFreeArgs,
-    PoolStateAccount, U8Bool, U8BoolMut,
+    record_rebalance_loss, try_pool_state, try_pool_state_mut, try_rebalance_loss_state_mut,
+    try_rebalance_record, EndRebalanceFreeArgs, Pool, PoolStateAccount, SControllerEvent, U8Bool,
+    U8BoolMut,
 };
 use sanctum_misc_utils::{
     load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
 };
 use sanctum_system_program_lib::{close_account, CloseAccountAccounts};
 use solana_program::{
-    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult,
+    program_error::ProgramError, sysvar::Sysvar,
 };
 
 use crate::{
     cpi::SolValueCalculatorCpi,
+    events::emit_event,
     verify::{verify_lst_sol_val_calc_cpi, VerifyLstSolValCalcCpiAccounts},
 };
 
@@ -30,13 +33,14 @@ pub fn process_end_rebalance(accounts: &[AccountInfo]) -> ProgramResult {
         let pool_state = try_pool_state_mut(&mut pool_state_data)?;
         U8BoolMut(&mut pool_state.is_rebalancing).set_false();
     }
-    let old_total_sol_value = {
+    let (old_total_sol_value, min_ending_sol_value) = {
         let rebalance_record_data = accounts.rebalance_record.try_borrow_data()?;
         let RebalanceRecord {
             old_total_sol_value,
+            min_ending_sol_value,
             ..
         } = try_rebalance_record(&rebalance_record_data)?;
-        *old_total_sol_value
+        (*old_total_sol_value, *min_ending_sol_value)
     };
 
     sync_sol_value_unchecked(
@@ -45,14 +49,38 @@ pub fn process_end_rebalance(accounts: &[AccountInfo]) -> ProgramResult {
         dst_lst_index,
     )?;
 
-    if accounts.pool_state.total_sol_value()? < old_total_sol_value {
+    let new_total_sol_value = accounts.pool_state.total_sol_value()?;
+    if new_total_sol_value < min_ending_sol_value {
         return Err(SControllerError::PoolWouldLoseSolValue.into());
     }
+    // StartRebalance only allows a nonzero loss if RebalanceLossState is initialized,
+    // and only up to the max loss the limits allow
+    let loss = old_total_sol_value.saturating_sub(new_total_sol_value);
+    if !accounts.rebalance_loss_state.data_is_empty() {
+        // record even if loss = 0 so that the epoch's starting total SOL value
+        // is snapshotted on its first rebalance
+        let mut rebalance_loss_state_data = accounts.rebalance_loss_state.try_borrow_mut_data()?;
+        let rebalance_loss_state = try_rebalance_loss_state_mut(&mut rebalance_loss_state_data)?;
+        record_rebalance_loss(
+            rebalance_loss_state,
+            old_total_sol_value,
+            loss,
+            Clock::get()?.epoch,
+        )?;
+    }
 
     close_account(CloseAccountAccounts {
         refund_rent_to: accounts.pool_state,
         close: accounts.rebalance_record,
-    })
+    })?;
+
+    emit_event(
+        accounts.pool_state,
+        &SControllerEvent::EndRebalance {
+            old_total_sol_value,
+            new_total_sol_value,
+        },
+    )
 }
 
 fn verify_end_rebalance<'a, 'info>(
@@ -66,6 +94,7 @@ fn verify_end_rebalance<'a, 'info>(
     ProgramError,
 > {
     let actual: EndRebalanceAccounts = load_accounts(accounts)?;
+    let pool = Pool::try_from_pool_state(actual.pool_state)?;
 
     let free_args = EndRebalanceFreeArgs {
         pool_state: actual.pool_state,
@@ -73,7 +102,7 @@ fn verify_end_rebalance<'a, 'info>(
         rebalance_record: actual.rebalance_record,
         dst_lst_mint: actual.dst_lst_mint,
     };
-    let (expected, dst_lst_index) = free_args.resolve()?;
+    let (expected, dst_lst_index) = free_args.resolve_for_pool(pool.pdas)?;
 
     end_rebalance_verify_account_keys(actual, expected).map_err(log_and_return_wrong_acc_err)?;
     end_rebalance_verify_account_privileges(actual).map_err(log_and_return_acc_privilege_err)?;
@@ -0,0 +1,291 @@
+use s_controller_interface::{
+    execute_pending_change_verify_account_keys, execute_pending_change_verify_account_privileges,
+    ExecutePendingChangeAccounts, ExecutePendingChangeIxArgs, PendingChange,
+    RemoveDisablePoolAuthorityAccounts, RemoveDisablePoolAuthorityIxArgs, RemoveLstAccounts,
+    RemoveLstIxArgs, SControllerError, SetLstCapAccounts, SetLstCapIxArgs, SetProtocolFeeIxArgs,
+    SetSolValueCalculatorAccounts, SetSolValueCalculatorIxArgs, SetTimelockDelayIxArgs,
+    EXECUTE_PENDING_CHANGE_IX_ACCOUNTS_LEN,
+};
+use s_controller_lib::{
+    index_to_u32, index_to_usize, try_disable_pool_authority_list, try_find_element_in_list,
+    try_find_lst_mint_on_list, try_lst_state_list, try_pending_change_list, AdminChange,
+    ExecutePendingChangeFreeArgs, Pool, SControllerEvent,
+};
+use sanctum_misc_utils::{
+    load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
+};
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult,
+    program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
+};
+
+use crate::{
+    events::emit_event,
+    list_account::{remove_from_list_pda, RemoveFromListPdaAccounts},
+};
+
+use super::{
+    add_disable_pool_authority_unchecked, add_lst_unchecked,
+    remove_disable_pool_authority_unchecked, remove_lst_unchecked, set_admin_unchecked,
+    set_lst_cap_unchecked, set_pricing_program_unchecked, set_protocol_fee_beneficiary_unchecked,
+    set_protocol_fee_unchecked, set_rebalance_authority_unchecked,
+    set_sol_value_calculator_unchecked, set_timelock_delay_unchecked,
+    verify_add_disable_pool_authority, verify_add_lst, verify_remove_disable_pool_authority,
+    verify_remove_lst, verify_set_admin, verify_set_lst_cap, verify_set_pricing_program,
+    verify_set_protocol_fee, verify_set_protocol_fee_beneficiary, verify_set_rebalance_authority,
+    verify_set_sol_value_calculator, verify_set_timelock_delay,
+};
+
+/// The accounts suffix is the full accounts slice of the direct instruction
+/// that performs the pending change, which is verified with that instruction's
+/// verification procedure, including the admin signer check.
+pub fn process_execute_pending_change(
+    accounts: &[AccountInfo],
+    args: ExecutePendingChangeIxArgs,
+) -> ProgramResult {
+    let (checked, index, pending_change) = verify_execute_pending_change(accounts, args)?;
+    let direct_accounts = accounts
+        .get(EXECUTE_PENDING_CHANGE_IX_ACCOUNTS_LEN..)
+        .ok_or(ProgramError::NotEnoughAccountKeys)?;
+
+    match AdminChange::try_from(&pending_change)? {
+        AdminChange::SetAdmin { new_admin } => {
+            let direct = verify_set_admin(direct_accounts)?;
+            verify_matches_pending_change(
+                &checked,
+                direct.pool_state,
+                *direct.new_admin.key == new_admin,
+            )?;
+            set_admin_unchecked(direct)?;
+        }
+        AdminChange::SetPricingProgram {
+            new_pricing_program,
+        } => {
+            let direct = verify_set_pricing_program(direct_accounts)?;
+            verify_matches_pending_change(
+                &checked,
+                direct.pool_state,
+                *direct.new_pricing_program.key == new_pricing_program,
+            )?;
+            set_pricing_program_unchecked(direct)?;
+        }
+        AdminChange::SetSolValueCalculator {
+            lst_mint,
+            new_sol_value_calculator,
+        } => {
+            let lst_index = {
+                let direct: SetSolValueCalculatorAccounts = load_accounts(direct_accounts)?;
+                find_lst_index(direct.lst_state_list, lst_mint)?
+            };
+            let (direct, lst_index, cpi) = verify_set_sol_value_calculator(
+                direct_accounts,
+                SetSolValueCalculatorIxArgs { lst_index },
+            )?;
+            verify_matches_pending_change(
+                &checked,
+                direct.pool_state,
+                *cpi.program.key == new_sol_value_calculator,
+            )?;
+            set_sol_value_calculator_unchecked(direct, lst_index, cpi)?;
+        }
+        AdminChange::SetProtocolFee {
+            new_trading_protocol_fee_bps,
+            new_lp_protocol_fee_bps,
+        } => {
+            let (direct, args) = verify_set_protocol_fee(
+                direct_accounts,
+                SetProtocolFeeIxArgs {
+                    new_trading_protocol_fee_bps,
+                    new_lp_protocol_fee_bps,
+                },
+            )?;
+            verify_matches_pending_change(&checked, direct.pool_state, true)?;
+            set_protocol_fee_unchecked(direct, args)?;
+        }
+        AdminChange::RemoveLst { lst_mint } => {
+            let lst_index = {
+                let direct: RemoveLstAccounts = load_accounts(direct_accounts)?;
+                find_lst_index(direct.lst_state_list, lst_mint)?
+            };
+            let (direct, lst_index, pool) =
+                verify_remove_lst(direct_accounts, RemoveLstIxArgs { lst_index })?;
+            verify_matches_pending_change(&checked, direct.pool_state, true)?;
+            remove_lst_unchecked(direct, lst_index, pool)?;
+        }
+        AdminChange::SetTimelockDelay {
+            new_timelock_delay_hours,
+        } => {
+            let direct = verify_set_timelock_delay(direct_accounts)?;
+            verify_matches_pending_change(&checked, direct.pool_state, true)?;
+            set_timelock_delay_unchecked(
+                direct,
+                SetTimelockDelayIxArgs {
+                    timelock_delay_hours: new_timelock_delay_hours,
+                },
+            )?;
+        }
+        AdminChange::AddLst {
+            lst_mint,
+            sol_value_calculator,
+        } => {
+            let (direct, bumps, pool) = verify_add_lst(direct_accounts)?;
+            verify_matches_pending_change(
+                &checked,
+                direct.pool_state,
+                *direct.lst_mint.key == lst_mint
+                    && *direct.sol_value_calculator.key == sol_value_calculator,
+            )?;
+            add_lst_unchecked(direct, bumps, pool)?;
+        }
+        AdminChange::SetRebalanceAuthority {
+            new_rebalance_authority,
+        } => {
+            let direct = verify_set_rebalance_authority(direct_accounts)?;
+            verify_matches_pending_change(
+                &checked,
+                direct.pool_state,
+                *direct.new_rebalance_authority.key == new_rebalance_authority,
+            )?;
+            set_rebalance_authority_unchecked(direct)?;
+        }
+        AdminChange::SetProtocolFeeBeneficiary { new_beneficiary } => {
+            let direct = verify_set_protocol_fee_beneficiary(direct_accounts)?;
+            verify_matches_pending_change(
+                &checked,
+                direct.pool_state,
+                *direct.new_beneficiary.key == new_beneficiary,
+            )?;
+            set_protocol_fee_beneficiary_unchecked(direct)?;
+        }
+        AdminChange::AddDisablePoolAuthority { new_authority } => {
+            let (direct, pool) = verify_add_disable_pool_authority(direct_accounts)?;
+            verify_matches_pending_change(
+                &checked,
+                direct.pool_state,
+                *direct.new_authority.key == new_authority,
+            )?;
+            add_disable_pool_authority_unchecked(direct, pool)?;
+        }
+        AdminChange::RemoveDisablePoolAuthority { authority } => {
+            let index = {
+                let direct: RemoveDisablePoolAuthorityAccounts = load_accounts(direct_accounts)?;
+                find_disable_pool_authority_index(direct.disable_pool_authority_list, authority)?
+            };
+            let (direct, index) = verify_remove_disable_pool_authority(
+                direct_accounts,
+                RemoveDisablePoolAuthorityIxArgs { index },
+            )?;
+            verify_matches_pending_change(
+                &checked,
+                direct.pool_state,
+                *direct.authority.key == authority,
+            )?;
+            remove_disable_pool_authority_unchecked(direct, index)?;
+        }
+        AdminChange::SetLstCap {
+            lst_mint,
+            max_share_bps,
+        } => {
+            let index = {
+                let direct: SetLstCapAccounts = load_accounts(direct_accounts)?;
+                find_lst_index(direct.lst_state_list, lst_mint)?
+            };
+            let (direct, lst_index, max_share_bps) = verify_set_lst_cap(
+                direct_accounts,
+                SetLstCapIxArgs {
+                    index,
+                    max_share_bps,
+                },
+            )?;
+            verify_matches_pending_change(&checked, direct.pool_state, true)?;
+            set_lst_cap_unchecked(direct, lst_index, max_share_bps)?;
+        }
+    }
+
+    // Gotta put direct account lamport manipuation last after CPIs
+    // because CPIs' lamport balance checks are broken:
+    // https://github.com/solana-labs/solana/issues/9711
+    remove_from_list_pda::<PendingChange>(
+        RemoveFromListPdaAccounts {
+            list_pda: checked.pending_change_list,
+            refund_rent_to: checked.refund_rent_to,
+        },
+        index,
+    )?;
+
+    emit_event(
+        checked.pool_state,
+        &SControllerEvent::ExecutePendingChange { pending_change },
+    )
+}
+
+fn verify_execute_pending_change<'me, 'info>(
+    accounts: &'me [AccountInfo<'info>],
+    ExecutePendingChangeIxArgs {
+        pending_change_index,
+    }: ExecutePendingChangeIxArgs,
+) -> Result<
+    (
+        ExecutePendingChangeAccounts<'me, 'info>,
+        usize,
+        PendingChange,
+    ),
+    ProgramError,
+> {
+    let actual: ExecutePendingChangeAccounts = load_accounts(accounts)?;
+    let pool = Pool::try_from_pool_state(actual.pool_state)?;
+    let index = index_to_usize(pending_change_index)?;
+
+    let free_args = ExecutePendingChangeFreeArgs {
+        refund_rent_to: *actual.refund_rent_to.key,
+    };
+    let expected = free_args.resolve_for_pool(pool.pdas);
+
+    execute_pending_change_verify_account_keys(actual, expected)
+        .map_err(log_and_return_wrong_acc_err)?;
+    execute_pending_change_verify_account_privileges(actual)
+        .map_err(log_and_return_acc_privilege_err)?;
+
+    let pending_change_list_data = actual.pending_change_list.try_borrow_data()?;
+    let list = try_pending_change_list(&pending_change_list_data)?;
+    let pending_change = *list
+        .get(index)
+        .ok_or(SControllerError::InvalidPendingChange)?;
+
+    if Clock::get()?.unix_timestamp < pending_change.executable_at {
+        return Err(SControllerError::TimelockNotElapsed.into());
+    }
+
+    Ok((actual, index, pending_change))
+}
+
+/// `args_match` is whether the direct instruction's accounts and args
+/// not already enforced by its verification procedure match the pending change
+fn verify_matches_pending_change(
+    checked: &ExecutePendingChangeAccounts,
+    direct_pool_state: &AccountInfo,
+    args_match: bool,
+) -> Result<(), SControllerError> {
+    if *direct_pool_state.key != *checked.pool_state.key || !args_match {
+        return Err(SControllerError::PendingChangeMismatch);
+    }
+    Ok(())
+}
+
+fn find_lst_index(lst_state_list: &AccountInfo, lst_mint: Pubkey) -> Result<u32, ProgramError> {
+    let lst_state_list_data = lst_state_list.try_borrow_data()?;
+    let list = try_lst_state_list(&lst_state_list_data)?;
+    let (lst_index, _) = try_find_lst_mint_on_list(lst_mint, list)?;
+    Ok(index_to_u32(lst_index)?)
+}
+
+fn find_disable_pool_authority_index(
+    disable_pool_authority_list: &AccountInfo,
+    authority: Pubkey,
+) -> Result<u32, ProgramError> {
+    let disable_pool_authority_list_data = disable_pool_authority_list.try_borrow_data()?;
+    let list = try_disable_pool_authority_list(&disable_pool_authority_list_data)?;
+    let (index, _) = try_find_element_in_list(authority, list)
+        .ok_or(SControllerError::InvalidDisablePoolAuthority)?;
+    Ok(index_to_u32(index)?)
+}
@@ -1,10 +1,11 @@
 use s_controller_interface::{
     initialize_verify_account_keys, initialize_verify_account_privileges, InitializeAccounts,
-    PoolState, SControllerError,
+    PoolState,
 };
 use s_controller_lib::{
-    try_pool_state_mut, InitializeFreeArgs, CURRENT_PROGRAM_VERS, DEFAULT_LP_PROTOCOL_FEE_BPS,
-    DEFAULT_PRICING_PROGRAM, DEFAULT_TRADING_PROTOCOL_FEE_BPS, POOL_STATE_SIZE,
+    try_pool_state_mut, InitializeFreeArgs, SControllerEvent, CURRENT_PROGRAM_VERS,
+    DEFAULT_LP_PROTOCOL_FEE_BPS, DEFAULT_PRICING_PROGRAM, DEFAULT_TRADING_PROTOCOL_FEE_BPS,
+    POOL_STATE_SIZE,
 };
 use sanctum_misc_utils::{
     load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
@@ -15,12 +16,12 @@ use sanctum_system_program_lib::{
 use sanctum_token_lib::{set_authority_invoke, SetAuthorityAccounts, SetAuthorityArgs};
 use solana_program::{
     account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
-    program_pack::Pack,
 };
-use spl_token::{native_mint, state::Mint};
 use spl_token_2022::instruction::AuthorityType;
 use system_program_interface::CreateAccountAccounts;
 
+use crate::{events::emit_event, verify::verify_lp_token_mint};
+
 pub fn process_initialize(accounts: &[AccountInfo]) -> ProgramResult {
     let accounts = verify_initialize(accounts)?;
 
@@ -50,7 +51,7 @@ pub fn process_initialize(accounts: &[AccountInfo]) -> ProgramResult {
             version: CURRENT_PROGRAM_VERS,
             is_disabled: 0,
             is_rebalancing: 0,
-            padding: [0],
+            timelock_delay_hours: 0,
             admin: *accounts.authority.key,
             rebalance_authority: *accounts.authority.key,
             protocol_fee_beneficiary: *accounts.authority.key,
@@ -78,6 +79,13 @@ pub fn process_initialize(accounts: &[AccountInfo]) -> ProgramResult {
             authority_type: AuthorityType::FreezeAccount,
             new_authority: Some(*accounts.pool_state.key),
         },
+    )?;
+
+    emit_event(
+        accounts.pool_state,
+        &SControllerEvent::Initialize {
+            lp_token_mint: *accounts.lp_token_mint.key,
+        },
     )
 }
 
@@ -99,17 +107,3 @@ fn verify_initialize<'a, 'info>(
 
     Ok(actual)
 }
-
-fn verify_lp_token_mint(lp_token_mint: &AccountInfo<'_>) -> Result<(), ProgramError> {
-    if *lp_token_mint.owner != spl_token::ID {
-        return Err(SControllerError::IncorrectLpMintInitialization.into());
-    }
-    let mint = Mint::unpack(&lp_token_mint.try_borrow_data()?)?;
-    if mint.supply != 0 {
-        return Err(SControllerError::IncorrectLpMintInitialization.into());
-    }
-    if mint.decimals != native_mint::DECIMALS {
-        return Err(SControllerError::IncorrectLpMintInitialization.into());
-    }
-    Ok(())
-}
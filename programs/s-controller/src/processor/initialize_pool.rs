@@ -0,0 +1,111 @@
+use s_controller_interface::{
+    initialize_pool_verify_account_keys, initialize_pool_verify_account_privileges,
+    InitializePoolAccounts, PoolState,
+};
+use s_controller_lib::{
+    try_pool_state_mut, InitializePoolFreeArgs, SControllerEvent, CURRENT_PROGRAM_VERS,
+    DEFAULT_LP_PROTOCOL_FEE_BPS, DEFAULT_PRICING_PROGRAM, DEFAULT_TRADING_PROTOCOL_FEE_BPS,
+    POOL_STATE_PDA_SEED, POOL_STATE_SIZE,
+};
+use sanctum_misc_utils::{
+    load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
+};
+use sanctum_system_program_lib::{
+    init_rent_exempt_account_invoke_signed, InitRentExemptAccountArgs,
+};
+use sanctum_token_lib::{set_authority_invoke, SetAuthorityAccounts, SetAuthorityArgs};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+};
+use spl_token_2022::instruction::AuthorityType;
+use system_program_interface::CreateAccountAccounts;
+
+use crate::{events::emit_event, verify::verify_lp_token_mint};
+
+pub fn process_initialize_pool(accounts: &[AccountInfo]) -> ProgramResult {
+    let (accounts, pool_state_bump) = verify_initialize_pool(accounts)?;
+
+    init_rent_exempt_account_invoke_signed(
+        CreateAccountAccounts {
+            from: accounts.payer,
+            to: accounts.pool_state,
+        },
+        InitRentExemptAccountArgs {
+            space: POOL_STATE_SIZE,
+            owner: s_controller_lib::program::ID,
+        },
+        &[&[
+            POOL_STATE_PDA_SEED,
+            accounts.lp_token_mint.key.as_ref(),
+            &[pool_state_bump],
+        ]],
+    )?;
+
+    // need to drop borrow of pool_state before mint CPIs
+    {
+        let mut pool_state_data = accounts.pool_state.try_borrow_mut_data()?;
+        let pool_state = try_pool_state_mut(&mut pool_state_data)?;
+        *pool_state = PoolState {
+            total_sol_value: 0,
+            trading_protocol_fee_bps: DEFAULT_TRADING_PROTOCOL_FEE_BPS,
+            lp_protocol_fee_bps: DEFAULT_LP_PROTOCOL_FEE_BPS,
+            version: CURRENT_PROGRAM_VERS,
+            is_disabled: 0,
+            is_rebalancing: 0,
+            timelock_delay_hours: 0,
+            admin: *accounts.authority.key,
+            rebalance_authority: *accounts.authority.key,
+            protocol_fee_beneficiary: *accounts.authority.key,
+            pricing_program: DEFAULT_PRICING_PROGRAM,
+            lp_token_mint: *accounts.lp_token_mint.key,
+        };
+    }
+
+    let set_authority_accounts = SetAuthorityAccounts {
+        token_program: accounts.lp_token_program,
+        to_change: accounts.lp_token_mint,
+        current_authority: accounts.authority,
+    };
+
+    set_authority_invoke(
+        set_authority_accounts,
+        SetAuthorityArgs {
+            authority_type: AuthorityType::MintTokens,
+            new_authority: Some(*accounts.pool_state.key),
+        },
+    )?;
+    set_authority_invoke(
+        set_authority_accounts,
+        SetAuthorityArgs {
+            authority_type: AuthorityType::FreezeAccount,
+            new_authority: Some(*accounts.pool_state.key),
+        },
+    )?;
+
+    emit_event(
+        accounts.pool_state,
+        &SControllerEvent::Initialize {
+            lp_token_mint: *accounts.lp_token_mint.key,
+        },
+    )
+}
+
+fn verify_initialize_pool<'a, 'info>(
+    accounts: &'a [AccountInfo<'info>],
+) -> Result<(InitializePoolAccounts<'a, 'info>, u8), ProgramError> {
+    let actual: InitializePoolAccounts = load_accounts(accounts)?;
+
+    let free_args = InitializePoolFreeArgs {
+        payer: *actual.payer.key,
+        lp_token_mint: *actual.lp_token_mint.key,
+        default_pool_state: actual.default_pool_state,
+    };
+    let (expected, pool_state_bump) = free_args.resolve()?;
+
+    initialize_pool_verify_account_keys(actual, expected).map_err(log_and_return_wrong_acc_err)?;
+    initialize_pool_verify_account_privileges(actual).map_err(log_and_return_acc_privilege_err)?;
+
+    verify_lp_token_mint(actual.lp_token_mint)?;
+
+    Ok((actual, pool_state_bump))
+}
@@ -0,0 +1,183 @@
+use s_controller_lib::{
+    MultiLiquidityLstFreeArgs, MultiLiquidityLstKeys, SwapLiquidityPdas,
+    MULTI_LIQUIDITY_LST_ACCOUNTS_LEN,
+};
+use sanctum_misc_utils::log_and_return_wrong_acc_err;
+use solana_program::{account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey};
+
+use crate::{
+    cpi::{PricingProgramPriceLpCpi, SolValueCalculatorCpi},
+    verify::{
+        verify_lst_sol_val_calc_cpi, verify_pricing_lp_cpi, VerifyLstSolValCalcCpiAccounts,
+        VerifyPricingLpCpiAccounts,
+    },
+};
+
+use super::SyncSolValueUncheckedAccounts;
+
+/// A single LST of an AddLiquidityMulti or RemoveLiquidityProRata instruction
+pub struct MultiLiquidityLst<'a, 'info> {
+    pub lst_index: usize,
+    pub lst_mint: &'a AccountInfo<'info>,
+    pub lst_token_program: &'a AccountInfo<'info>,
+    pub lst_acc: &'a AccountInfo<'info>,
+    pub pool_reserves: &'a AccountInfo<'info>,
+    pub protocol_fee_accumulator: &'a AccountInfo<'info>,
+    pub cpi: SolValueCalculatorCpi<'a, 'info>,
+    pub pricing_cpi: PricingProgramPriceLpCpi<'a, 'info>,
+}
+
+impl<'a, 'info> MultiLiquidityLst<'a, 'info> {
+    pub fn sync_sol_value_accounts(
+        &self,
+        pool_state: &'a AccountInfo<'info>,
+        lst_state_list: &'a AccountInfo<'info>,
+    ) -> SyncSolValueUncheckedAccounts<'a, 'info> {
+        SyncSolValueUncheckedAccounts {
+            pool_reserves: self.pool_reserves,
+            pool_state,
+            lst_state_list,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct VerifyMultiLiquidityLstsAccounts<'me, 'info> {
+    pub pool_state: &'me AccountInfo<'info>,
+    pub lst_state_list: &'me AccountInfo<'info>,
+}
+
+/// Verifies the per-LST accounts and the SOL value calculator and pricing program
+/// CPI accounts that come after the instruction's fixed accounts.
+///
+/// `accounts_suffix_slice` should start right after the instruction's fixed accounts.
+/// Caller must check that `lst_indexes`, `lst_value_calc_accs` and `pricing_accs`
+/// have the same length.
+pub fn verify_multi_liquidity_lsts<'a, 'info>(
+    VerifyMultiLiquidityLstsAccounts {
+        pool_state,
+        lst_state_list,
+    }: VerifyMultiLiquidityLstsAccounts<'a, 'info>,
+    pdas: SwapLiquidityPdas,
+    accounts_suffix_slice: &'a [AccountInfo<'info>],
+    lst_indexes: Vec<usize>,
+    lst_value_calc_accs: Vec<u8>,
+    pricing_accs: Vec<u8>,
+) -> Result<Vec<MultiLiquidityLst<'a, 'info>>, ProgramError> {
+    let n_lsts = lst_indexes.len();
+    let lst_accounts_end = n_lsts
+        .checked_mul(MULTI_LIQUIDITY_LST_ACCOUNTS_LEN)
+        .ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let lst_accounts = accounts_suffix_slice
+        .get(..lst_accounts_end)
+        .ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let cpi_accounts_suffix_slice = accounts_suffix_slice
+        .get(lst_accounts_end..)
+        .ok_or(ProgramError::NotEnoughAccountKeys)?;
+    // all SOL value calculator accounts come before all pricing program accounts
+    let calc_accounts_end: usize = lst_value_calc_accs.iter().map(|n| usize::from(*n)).sum();
+    let mut calc_accounts_suffix_slice = cpi_accounts_suffix_slice
+        .get(..calc_accounts_end)
+        .ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let mut pricing_accounts_suffix_slice = cpi_accounts_suffix_slice
+        .get(calc_accounts_end..)
+        .ok_or(ProgramError::NotEnoughAccountKeys)?;
+
+    let mut res = Vec::with_capacity(n_lsts);
+    for (((lst_index, lst_accounts), lst_value_calc_accs), lst_pricing_accs) in lst_indexes
+        .into_iter()
+        .zip(lst_accounts.chunks_exact(MULTI_LIQUIDITY_LST_ACCOUNTS_LEN))
+        .zip(lst_value_calc_accs)
+        .zip(pricing_accs)
+    {
+        // chunks_exact() guarantees MULTI_LIQUIDITY_LST_ACCOUNTS_LEN accounts
+        let lst_mint = &lst_accounts[0];
+        let lst_token_program = &lst_accounts[1];
+        let lst_acc = &lst_accounts[2];
+        let pool_reserves = &lst_accounts[3];
+        let protocol_fee_accumulator = &lst_accounts[4];
+        let expected = MultiLiquidityLstFreeArgs {
+            lst_index,
+            lst_acc: *lst_acc.key,
+            lst_mint,
+            lst_state_list,
+        }
+        .resolve_with_pdas(pdas)?;
+        verify_multi_liquidity_lst_keys(
+            [
+                lst_mint,
+                lst_token_program,
+                lst_acc,
+                pool_reserves,
+                protocol_fee_accumulator,
+            ],
+            expected,
+        )?;
+
+        let calc_accs: usize = lst_value_calc_accs.into();
+        let cpi = verify_lst_sol_val_calc_cpi(
+            VerifyLstSolValCalcCpiAccounts {
+                lst_state_list,
+                lst_mint,
+            },
+            calc_accounts_suffix_slice
+                .get(..calc_accs)
+                .ok_or(ProgramError::NotEnoughAccountKeys)?,
+            lst_index,
+        )?;
+        calc_accounts_suffix_slice = calc_accounts_suffix_slice
+            .get(calc_accs..)
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+
+        let lst_pricing_accs: usize = lst_pricing_accs.into();
+        let pricing_cpi = verify_pricing_lp_cpi(
+            VerifyPricingLpCpiAccounts {
+                pool_state,
+                lst_mint,
+            },
+            pricing_accounts_suffix_slice
+                .get(..lst_pricing_accs)
+                .ok_or(ProgramError::NotEnoughAccountKeys)?,
+        )?;
+        pricing_accounts_suffix_slice = pricing_accounts_suffix_slice
+            .get(lst_pricing_accs..)
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+
+        res.push(MultiLiquidityLst {
+            lst_index,
+            lst_mint,
+            lst_token_program,
+            lst_acc,
+            pool_reserves,
+            protocol_fee_accumulator,
+            cpi,
+            pricing_cpi,
+        });
+    }
+    Ok(res)
+}
+
+fn verify_multi_liquidity_lst_keys(
+    actual: [&AccountInfo; MULTI_LIQUIDITY_LST_ACCOUNTS_LEN],
+    MultiLiquidityLstKeys {
+        lst_mint,
+        lst_token_program,
+        lst_acc,
+        pool_reserves,
+        protocol_fee_accumulator,
+    }: MultiLiquidityLstKeys,
+) -> Result<(), ProgramError> {
+    let expected: [Pubkey; MULTI_LIQUIDITY_LST_ACCOUNTS_LEN] = [
+        lst_mint,
+        lst_token_program,
+        lst_acc,
+        pool_reserves,
+        protocol_fee_accumulator,
+    ];
+    for (actual, expected) in actual.iter().zip(expected) {
+        if *actual.key != expected {
+            return Err(log_and_return_wrong_acc_err((*actual.key, expected)));
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,106 @@
+use s_controller_interface::{
+    queue_pending_change_verify_account_keys, queue_pending_change_verify_account_privileges,
+    PendingChange, QueuePendingChangeAccounts, QueuePendingChangeIxArgs, SControllerError,
+};
+use s_controller_lib::{
+    pending_change_executable_at, try_pending_change_list_mut, try_pool_state, AdminChange, Pool,
+    QueuePendingChangeFreeArgs, SControllerEvent,
+};
+use sanctum_misc_utils::{
+    load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
+};
+use sanctum_token_ratio::BPS_DENOMINATOR;
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult,
+    program_error::ProgramError, sysvar::Sysvar,
+};
+
+use crate::{
+    events::emit_event,
+    list_account::{extend_list_pda, ExtendListPdaAccounts},
+};
+
+pub fn process_queue_pending_change(
+    accounts: &[AccountInfo],
+    args: QueuePendingChangeIxArgs,
+) -> ProgramResult {
+    let (checked, pool, new_pending_change) = verify_queue_pending_change(accounts, &args)?;
+
+    extend_list_pda::<PendingChange>(
+        ExtendListPdaAccounts {
+            list_pda: checked.pending_change_list,
+            payer: checked.payer,
+        },
+        &[&pool.pending_change_list_seeds()],
+    )?;
+
+    let mut pending_change_list_data = checked.pending_change_list.try_borrow_mut_data()?;
+    let list = try_pending_change_list_mut(&mut pending_change_list_data)?;
+    let new_entry = list
+        .last_mut()
+        .ok_or(SControllerError::InvalidPendingChangeListData)?;
+
+    *new_entry = new_pending_change;
+
+    emit_event(
+        checked.pool_state,
+        &SControllerEvent::QueuePendingChange {
+            pending_change: new_pending_change,
+        },
+    )
+}
+
+fn verify_queue_pending_change<'me, 'info>(
+    accounts: &'me [AccountInfo<'info>],
+    args: &QueuePendingChangeIxArgs,
+) -> Result<(QueuePendingChangeAccounts<'me, 'info>, Pool, PendingChange), ProgramError> {
+    let actual: QueuePendingChangeAccounts = load_accounts(accounts)?;
+    let pool = Pool::try_from_pool_state(actual.pool_state)?;
+
+    let free_args = QueuePendingChangeFreeArgs {
+        payer: *actual.payer.key,
+        pool_state: actual.pool_state,
+    };
+    let expected = free_args.resolve_for_pool(pool.pdas)?;
+
+    queue_pending_change_verify_account_keys(actual, expected)
+        .map_err(log_and_return_wrong_acc_err)?;
+    queue_pending_change_verify_account_privileges(actual)
+        .map_err(log_and_return_acc_privilege_err)?;
+
+    let pool_state_bytes = actual.pool_state.try_borrow_data()?;
+    let pool_state = try_pool_state(&pool_state_bytes)?;
+    if pool_state.timelock_delay_hours == 0 {
+        return Err(SControllerError::PoolNotTimelocked.into());
+    }
+
+    let change = AdminChange::try_from(args)?;
+    match change {
+        AdminChange::SetProtocolFee {
+            new_trading_protocol_fee_bps,
+            new_lp_protocol_fee_bps,
+        } => {
+            for fee_bps in [new_lp_protocol_fee_bps, new_trading_protocol_fee_bps]
+                .into_iter()
+                .flatten()
+            {
+                if fee_bps > BPS_DENOMINATOR {
+                    return Err(SControllerError::FeeTooHigh.into());
+                }
+            }
+        }
+        AdminChange::SetLstCap { max_share_bps, .. } => {
+            if max_share_bps > BPS_DENOMINATOR {
+                return Err(SControllerError::LstCapTooHigh.into());
+            }
+        }
+        _ => (),
+    }
+
+    let executable_at = pending_change_executable_at(
+        Clock::get()?.unix_timestamp,
+        pool_state.timelock_delay_hours,
+    )?;
+
+    Ok((actual, pool, change.to_pending_change(executable_at)))
+}
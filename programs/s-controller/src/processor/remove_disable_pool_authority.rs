@@ -3,7 +3,9 @@ use s_controller_interface::{
     remove_disable_pool_authority_verify_account_privileges, RemoveDisablePoolAuthorityAccounts,
     RemoveDisablePoolAuthorityIxArgs, SControllerError,
 };
-use s_controller_lib::{index_to_usize, try_pool_state, RemoveDisablePoolAuthorityFreeArgs};
+use s_controller_lib::{
+    index_to_usize, try_pool_state, Pool, RemoveDisablePoolAuthorityFreeArgs, SControllerEvent,
+};
 use sanctum_misc_utils::{
     load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
 };
@@ -13,8 +15,9 @@ use solana_program::{
 };
 
 use crate::{
+    events::emit_event,
     list_account::{remove_from_list_pda, RemoveFromListPdaAccounts},
-    verify::verify_admin_or_disable_pool_authority,
+    verify::{verify_admin_or_disable_pool_authority, verify_not_timelocked},
 };
 
 pub fn process_remove_disable_pool_authority(
@@ -23,20 +26,46 @@ pub fn process_remove_disable_pool_authority(
 ) -> ProgramResult {
     let (checked_accounts, index) = verify_remove_disable_pool_authority(accounts, args)?;
 
+    // a disable pool authority may still remove itself in timelock mode,
+    // only the admin's ability to remove others is timelocked
+    {
+        let pool_state_data = checked_accounts.pool_state.try_borrow_data()?;
+        let pool_state = try_pool_state(&pool_state_data)?;
+        if *checked_accounts.signer.key == pool_state.admin {
+            verify_not_timelocked(pool_state)?;
+        }
+    }
+
+    remove_disable_pool_authority_unchecked(checked_accounts, index)
+}
+
+/// Does not check that the pool is not timelocked, for use by `ExecutePendingChange`
+pub fn remove_disable_pool_authority_unchecked(
+    checked_accounts: RemoveDisablePoolAuthorityAccounts,
+    index: usize,
+) -> ProgramResult {
     remove_from_list_pda::<Pubkey>(
         RemoveFromListPdaAccounts {
             list_pda: checked_accounts.disable_pool_authority_list,
             refund_rent_to: checked_accounts.refund_rent_to,
         },
         index,
+    )?;
+
+    emit_event(
+        checked_accounts.pool_state,
+        &SControllerEvent::RemoveDisablePoolAuthority {
+            authority: *checked_accounts.authority.key,
+        },
     )
 }
 
-fn verify_remove_disable_pool_authority<'me, 'info>(
+pub fn verify_remove_disable_pool_authority<'me, 'info>(
     accounts: &'me [AccountInfo<'info>],
     RemoveDisablePoolAuthorityIxArgs { index }: RemoveDisablePoolAuthorityIxArgs,
 ) -> Result<(RemoveDisablePoolAuthorityAccounts<'me, 'info>, usize), ProgramError> {
     let actual: RemoveDisablePoolAuthorityAccounts = load_accounts(accounts)?;
+    let pool = Pool::try_from_pool_state(actual.pool_state)?;
     let index = index_to_usize(index)?;
 
     let free_args = RemoveDisablePoolAuthorityFreeArgs {
@@ -46,7 +75,7 @@ fn verify_remove_disable_pool_authority<'me, 'info>(
         pool_state_acc: actual.pool_state,
         disable_pool_authority_list: actual.disable_pool_authority_list,
     };
-    let expected = free_args.resolve()?;
+    let expected = free_args.resolve_for_pool(pool.pdas)?;
 
     remove_disable_pool_authority_verify_account_keys(actual, expected)
         .map_err(log_and_return_wrong_acc_err)?;
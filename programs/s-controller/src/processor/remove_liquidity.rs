@@ -4,10 +4,9 @@ use s_controller_interface::{
     REMOVE_LIQUIDITY_IX_ACCOUNTS_LEN,
 };
 use s_controller_lib::{
-    calc_lp_tokens_sol_value, calc_remove_liquidity_protocol_fees, index_to_usize,
-    program::{POOL_STATE_BUMP, POOL_STATE_SEED},
-    try_pool_state, CalcRemoveLiquidityProtocolFeesArgs, LpTokenRateArgs, PoolStateAccount,
-    RemoveLiquidityFreeArgs, RemoveLiquidityIxAmts, RemoveLiquidityIxFullArgs,
+    calc_lp_tokens_sol_value, calc_remove_liquidity_protocol_fees, index_to_usize, try_pool_state,
+    CalcRemoveLiquidityProtocolFeesArgs, LpTokenRateArgs, Pool, PoolStateAccount,
+    RemoveLiquidityFreeArgs, RemoveLiquidityIxAmts, RemoveLiquidityIxFullArgs, SControllerEvent,
 };
 use sanctum_misc_utils::{
     load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
@@ -22,6 +21,7 @@ use solana_program::{
 
 use crate::{
     cpi::{PricingProgramIxArgs, PricingProgramPriceLpCpi, SolValueCalculatorCpi},
+    events::emit_event,
     verify::{verify_lp_cpis, verify_not_rebalancing_and_not_disabled, VerifyLpCpiAccounts},
 };
 
@@ -43,6 +43,7 @@ pub fn process_remove_liquidity(
         },
         lst_cpi,
         pricing_cpi,
+        pool,
     ) = verify_remove_liquidity(accounts, args)?;
 
     let sync_sol_value_accounts = SyncSolValueUncheckedAccounts::from(accounts);
@@ -104,7 +105,7 @@ pub fn process_remove_liquidity(
             mint: accounts.lst_mint,
         },
         to_user_lst_amount,
-        &[&[POOL_STATE_SEED, &[POOL_STATE_BUMP]]],
+        &[&pool.pool_state_seeds()],
     )?;
 
     transfer_checked_decimal_agnostic_invoke_signed(
@@ -116,10 +117,22 @@ pub fn process_remove_liquidity(
             mint: accounts.lst_mint,
         },
         to_protocol_fees_lst_amount,
-        &[&[POOL_STATE_SEED, &[POOL_STATE_BUMP]]],
+        &[&pool.pool_state_seeds()],
     )?;
 
-    sync_sol_value_unchecked(sync_sol_value_accounts, lst_cpi, lst_index)
+    sync_sol_value_unchecked(sync_sol_value_accounts, lst_cpi, lst_index)?;
+
+    emit_event(
+        accounts.pool_state,
+        &SControllerEvent::RemoveLiquidity {
+            lst_mint: *accounts.lst_mint.key,
+            lp_tokens_burnt: lp_token_amount,
+            lst_amount: to_user_lst_amount,
+            protocol_fee_amount: to_protocol_fees_lst_amount,
+            sol_value: lp_tokens_sol_value,
+            sol_value_after_fees: lp_tokens_sol_value_after_fees,
+        },
+    )
 }
 
 fn verify_remove_liquidity<'a, 'info>(
@@ -136,6 +149,7 @@ fn verify_remove_liquidity<'a, 'info>(
         RemoveLiquidityIxFullArgs,
         SolValueCalculatorCpi<'a, 'info>,
         PricingProgramPriceLpCpi<'a, 'info>,
+        Pool,
     ),
     ProgramError,
 > {
@@ -146,6 +160,7 @@ fn verify_remove_liquidity<'a, 'info>(
     let lst_index = index_to_usize(lst_index)?;
 
     let actual: RemoveLiquidityAccounts = load_accounts(accounts)?;
+    let pool = Pool::try_from_pool_state(actual.pool_state)?;
 
     let free_args = RemoveLiquidityFreeArgs {
         lst_index,
@@ -156,7 +171,7 @@ fn verify_remove_liquidity<'a, 'info>(
         lst_state_list: actual.lst_state_list,
         lst_mint: actual.lst_mint,
     };
-    let expected = free_args.resolve()?;
+    let expected = free_args.resolve_for_pool(pool.pdas)?;
 
     remove_liquidity_verify_account_keys(actual, expected).map_err(log_and_return_wrong_acc_err)?;
     remove_liquidity_verify_account_privileges(actual).map_err(log_and_return_acc_privilege_err)?;
@@ -187,5 +202,6 @@ fn verify_remove_liquidity<'a, 'info>(
         },
         lst_cpi,
         pricing_cpi,
+        pool,
     ))
 }
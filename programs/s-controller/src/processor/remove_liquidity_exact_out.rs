@@ -0,0 +1,253 @@
+use s_controller_interface::{
+    remove_liquidity_exact_out_verify_account_keys,
+    remove_liquidity_exact_out_verify_account_privileges, RemoveLiquidityExactOutAccounts,
+    RemoveLiquidityExactOutIxArgs, SControllerError, REMOVE_LIQUIDITY_EXACT_OUT_IX_ACCOUNTS_LEN,
+};
+use s_controller_lib::{
+    calc_exact_out_sol_value_before_fees, calc_lp_tokens_for_sol_value, calc_lp_tokens_sol_value,
+    calc_remove_liquidity_protocol_fees, index_to_usize, try_pool_state,
+    CalcRemoveLiquidityProtocolFeesArgs, ExactOutPricingArgs, LpTokenRateArgs, Pool,
+    PoolStateAccount, RemoveLiquidityExactOutIxAmts, RemoveLiquidityExactOutIxFullArgs,
+    RemoveLiquidityFreeArgs, SControllerEvent,
+};
+use sanctum_misc_utils::{
+    load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
+};
+use sanctum_token_lib::{
+    burn_invoke, mint_supply, transfer_checked_decimal_agnostic_invoke_signed, BurnAccounts,
+    TransferCheckedAccounts,
+};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+};
+
+use crate::{
+    cpi::{PricingProgramIxArgs, PricingProgramPriceLpCpi, SolValueCalculatorCpi},
+    events::emit_event,
+    verify::{verify_lp_cpis, verify_not_rebalancing_and_not_disabled, VerifyLpCpiAccounts},
+};
+
+use super::{sync_sol_value_unchecked, SyncSolValueUncheckedAccounts};
+
+#[derive(Clone, Copy, Debug)]
+struct PricedLpTokenAmount {
+    lp_token_amount: u64,
+    lp_tokens_sol_value: u64,
+    lp_tokens_sol_value_after_fees: u64,
+}
+
+pub fn process_remove_liquidity_exact_out(
+    accounts: &[AccountInfo],
+    args: RemoveLiquidityExactOutIxArgs,
+) -> ProgramResult {
+    let (
+        accounts,
+        RemoveLiquidityExactOutIxFullArgs {
+            lst_index,
+            amts:
+                RemoveLiquidityExactOutIxAmts {
+                    lst_amount,
+                    max_lp_in,
+                },
+        },
+        lst_cpi,
+        pricing_cpi,
+        pool,
+    ) = verify_remove_liquidity_exact_out(accounts, args)?;
+
+    let sync_sol_value_accounts = SyncSolValueUncheckedAccounts::from(accounts);
+    sync_sol_value_unchecked(sync_sol_value_accounts, lst_cpi, lst_index)?;
+
+    let lp_token_rate_args = LpTokenRateArgs {
+        lp_token_supply: mint_supply(accounts.lp_token_mint)?,
+        pool_total_sol_value: accounts.pool_state.total_sol_value()?,
+    };
+
+    let target_sol_value_after_fees = lst_cpi.invoke_lst_to_sol(lst_amount)?.get_max();
+    if target_sol_value_after_fees == 0 {
+        return Err(SControllerError::ZeroValue.into());
+    }
+
+    let mut priced = price_lp_tokens_for_sol_value(
+        pricing_cpi,
+        lp_token_rate_args,
+        target_sol_value_after_fees,
+    )?;
+    if priced.lp_tokens_sol_value_after_fees < target_sol_value_after_fees {
+        let sol_value_before_fees = calc_exact_out_sol_value_before_fees(
+            ExactOutPricingArgs {
+                sol_value: priced.lp_tokens_sol_value,
+                sol_value_after_fees: priced.lp_tokens_sol_value_after_fees,
+            },
+            target_sol_value_after_fees,
+        )?;
+        priced =
+            price_lp_tokens_for_sol_value(pricing_cpi, lp_token_rate_args, sol_value_before_fees)?;
+    }
+    let PricedLpTokenAmount {
+        lp_token_amount,
+        lp_tokens_sol_value,
+        lp_tokens_sol_value_after_fees,
+    } = priced;
+    if lp_tokens_sol_value_after_fees > lp_tokens_sol_value
+        || lp_tokens_sol_value_after_fees < target_sol_value_after_fees
+    {
+        return Err(SControllerError::PoolWouldLoseSolValue.into());
+    }
+
+    if lp_token_amount > max_lp_in {
+        return Err(SControllerError::SlippageToleranceExceeded.into());
+    }
+
+    let to_protocol_fees_lst_amount =
+        calc_remove_liquidity_protocol_fees(CalcRemoveLiquidityProtocolFeesArgs {
+            lp_tokens_sol_value,
+            lp_tokens_sol_value_after_fees,
+            to_user_lst_amount: lst_amount,
+            lp_protocol_fee_bps: accounts.pool_state.lp_protocol_fee_bps()?,
+        })?;
+
+    burn_invoke(
+        BurnAccounts {
+            mint: accounts.lp_token_mint,
+            burn_from: accounts.src_lp_acc,
+            burn_from_authority: accounts.signer,
+            token_program: accounts.lp_token_program,
+        },
+        lp_token_amount,
+    )?;
+
+    transfer_checked_decimal_agnostic_invoke_signed(
+        TransferCheckedAccounts {
+            to: accounts.dst_lst_acc,
+            token_program: accounts.lst_token_program,
+            from: accounts.pool_reserves,
+            authority: accounts.pool_state,
+            mint: accounts.lst_mint,
+        },
+        lst_amount,
+        &[&pool.pool_state_seeds()],
+    )?;
+
+    transfer_checked_decimal_agnostic_invoke_signed(
+        TransferCheckedAccounts {
+            to: accounts.protocol_fee_accumulator,
+            token_program: accounts.lst_token_program,
+            from: accounts.pool_reserves,
+            authority: accounts.pool_state,
+            mint: accounts.lst_mint,
+        },
+        to_protocol_fees_lst_amount,
+        &[&pool.pool_state_seeds()],
+    )?;
+
+    sync_sol_value_unchecked(sync_sol_value_accounts, lst_cpi, lst_index)?;
+
+    emit_event(
+        accounts.pool_state,
+        &SControllerEvent::RemoveLiquidity {
+            lst_mint: *accounts.lst_mint.key,
+            lp_tokens_burnt: lp_token_amount,
+            lst_amount,
+            protocol_fee_amount: to_protocol_fees_lst_amount,
+            sol_value: lp_tokens_sol_value,
+            sol_value_after_fees: lp_tokens_sol_value_after_fees,
+        },
+    )
+}
+
+/// Prices the min amount of LP tokens worth at least `sol_value`
+fn price_lp_tokens_for_sol_value(
+    pricing_cpi: PricingProgramPriceLpCpi,
+    lp_token_rate_args: LpTokenRateArgs,
+    sol_value: u64,
+) -> Result<PricedLpTokenAmount, ProgramError> {
+    let lp_token_amount = calc_lp_tokens_for_sol_value(lp_token_rate_args, sol_value)?;
+    if lp_token_amount == 0 {
+        return Err(SControllerError::ZeroValue.into());
+    }
+    let lp_tokens_sol_value = calc_lp_tokens_sol_value(lp_token_rate_args, lp_token_amount)?;
+    let lp_tokens_sol_value_after_fees =
+        pricing_cpi.invoke_price_lp_tokens_to_redeem(PricingProgramIxArgs {
+            amount: lp_token_amount,
+            sol_value: lp_tokens_sol_value,
+        })?;
+    Ok(PricedLpTokenAmount {
+        lp_token_amount,
+        lp_tokens_sol_value,
+        lp_tokens_sol_value_after_fees,
+    })
+}
+
+fn verify_remove_liquidity_exact_out<'a, 'info>(
+    accounts: &'a [AccountInfo<'info>],
+    RemoveLiquidityExactOutIxArgs {
+        lst_value_calc_accs,
+        lst_index,
+        lst_amount,
+        max_lp_in,
+    }: RemoveLiquidityExactOutIxArgs,
+) -> Result<
+    (
+        RemoveLiquidityExactOutAccounts<'a, 'info>,
+        RemoveLiquidityExactOutIxFullArgs,
+        SolValueCalculatorCpi<'a, 'info>,
+        PricingProgramPriceLpCpi<'a, 'info>,
+        Pool,
+    ),
+    ProgramError,
+> {
+    if lst_amount == 0 {
+        return Err(SControllerError::ZeroValue.into());
+    }
+
+    let lst_index = index_to_usize(lst_index)?;
+
+    let actual: RemoveLiquidityExactOutAccounts = load_accounts(accounts)?;
+    let pool = Pool::try_from_pool_state(actual.pool_state)?;
+
+    let free_args = RemoveLiquidityFreeArgs {
+        lst_index,
+        signer: *actual.signer.key,
+        dst_lst_acc: *actual.dst_lst_acc.key,
+        src_lp_acc: *actual.src_lp_acc.key,
+        pool_state: actual.pool_state,
+        lst_state_list: actual.lst_state_list,
+        lst_mint: actual.lst_mint,
+    };
+    let expected = free_args.resolve_exact_out_for_pool(pool.pdas)?;
+
+    remove_liquidity_exact_out_verify_account_keys(actual, expected)
+        .map_err(log_and_return_wrong_acc_err)?;
+    remove_liquidity_exact_out_verify_account_privileges(actual)
+        .map_err(log_and_return_acc_privilege_err)?;
+
+    let pool_state_bytes = actual.pool_state.try_borrow_data()?;
+    let pool_state = try_pool_state(&pool_state_bytes)?;
+    verify_not_rebalancing_and_not_disabled(pool_state)?;
+
+    let accounts_suffix_slice = accounts
+        .get(REMOVE_LIQUIDITY_EXACT_OUT_IX_ACCOUNTS_LEN..)
+        .ok_or(ProgramError::NotEnoughAccountKeys)?;
+
+    let (lst_cpi, pricing_cpi) = verify_lp_cpis(
+        VerifyLpCpiAccounts::from(actual),
+        accounts_suffix_slice,
+        lst_value_calc_accs,
+        lst_index,
+    )?;
+
+    Ok((
+        actual,
+        RemoveLiquidityExactOutIxFullArgs {
+            lst_index,
+            amts: RemoveLiquidityExactOutIxAmts {
+                lst_amount,
+                max_lp_in,
+            },
+        },
+        lst_cpi,
+        pricing_cpi,
+        pool,
+    ))
+}
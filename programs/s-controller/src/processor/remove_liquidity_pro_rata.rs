@@ -0,0 +1,280 @@
+use s_controller_interface::{
+    remove_liquidity_pro_rata_verify_account_keys,
+    remove_liquidity_pro_rata_verify_account_privileges, RemoveLiquidityProRataAccounts,
+    RemoveLiquidityProRataIxArgs, SControllerError, REMOVE_LIQUIDITY_PRO_RATA_IX_ACCOUNTS_LEN,
+};
+use s_controller_lib::{
+    calc_lp_tokens_sol_value, calc_pro_rata_share, calc_remove_liquidity_protocol_fees,
+    try_lst_state_list, try_pool_state, CalcRemoveLiquidityProtocolFeesArgs, LiquidityLstAmounts,
+    LpTokenRateArgs, Pool, PoolStateAccount, ProRataShareArgs, RemoveLiquidityProRataFreeArgs,
+    SControllerEvent,
+};
+use sanctum_misc_utils::{
+    load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
+};
+use sanctum_token_lib::{
+    burn_invoke, mint_supply, transfer_checked_decimal_agnostic_invoke_signed, BurnAccounts,
+    TransferCheckedAccounts,
+};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+};
+
+use crate::{
+    cpi::PricingProgramIxArgs, events::emit_event, verify::verify_not_rebalancing_and_not_disabled,
+};
+
+use super::{
+    sync_sol_value_unchecked, verify_multi_liquidity_lsts, MultiLiquidityLst,
+    VerifyMultiLiquidityLstsAccounts,
+};
+
+#[derive(Clone, Copy, Debug, Default)]
+struct ProRataLstOut {
+    to_user_lst_amount: u64,
+    to_protocol_fees_lst_amount: u64,
+}
+
+pub fn process_remove_liquidity_pro_rata(
+    accounts: &[AccountInfo],
+    args: RemoveLiquidityProRataIxArgs,
+) -> ProgramResult {
+    let (accounts, lsts, lp_token_amount, min_lst_outs, pool) =
+        verify_remove_liquidity_pro_rata(accounts, args)?;
+
+    for lst in lsts.iter() {
+        sync_sol_value_unchecked(
+            lst.sync_sol_value_accounts(accounts.pool_state, accounts.lst_state_list),
+            lst.cpi,
+            lst.lst_index,
+        )?;
+    }
+
+    let pool_total_sol_value = accounts.pool_state.total_sol_value()?;
+    let lp_protocol_fee_bps = accounts.pool_state.lp_protocol_fee_bps()?;
+    let lp_token_supply = mint_supply(accounts.lp_token_mint)?;
+    let lp_tokens_sol_value = calc_lp_tokens_sol_value(
+        LpTokenRateArgs {
+            lp_token_supply,
+            pool_total_sol_value,
+        },
+        lp_token_amount,
+    )?;
+
+    let lst_sol_values: Vec<u64> = {
+        let lst_state_list_bytes = accounts.lst_state_list.try_borrow_data()?;
+        let lst_state_list = try_lst_state_list(&lst_state_list_bytes)?;
+        // every lst_index on the list checked in verify
+        lsts.iter()
+            .map(|lst| lst_state_list[lst.lst_index].sol_value)
+            .collect()
+    };
+
+    // The LP tokens and their SOL value are split across every LST
+    // in proportion to the LST's share of the pool's SOL value.
+    // Each LST's share is priced individually.
+    let mut lst_outs = Vec::with_capacity(lsts.len());
+    for ((lst, lst_sol_value), min_lst_out) in lsts.iter().zip(lst_sol_values).zip(min_lst_outs) {
+        let share_args = ProRataShareArgs {
+            lst_sol_value,
+            pool_total_sol_value,
+        };
+        let share_sol_value = calc_pro_rata_share(share_args, lp_tokens_sol_value)?;
+        let lst_out = if share_sol_value == 0 {
+            ProRataLstOut::default()
+        } else {
+            calc_pro_rata_lst_out(
+                lst,
+                PricingProgramIxArgs {
+                    amount: calc_pro_rata_share(share_args, lp_token_amount)?,
+                    sol_value: share_sol_value,
+                },
+                lp_protocol_fee_bps,
+            )?
+        };
+        if lst_out.to_user_lst_amount < min_lst_out {
+            return Err(SControllerError::SlippageToleranceExceeded.into());
+        }
+        lst_outs.push(lst_out);
+    }
+
+    if lst_outs.iter().all(|out| out.to_user_lst_amount == 0) {
+        return Err(SControllerError::ZeroValue.into());
+    }
+
+    burn_invoke(
+        BurnAccounts {
+            mint: accounts.lp_token_mint,
+            burn_from: accounts.src_lp_acc,
+            burn_from_authority: accounts.signer,
+            token_program: accounts.lp_token_program,
+        },
+        lp_token_amount,
+    )?;
+
+    let mut event_lsts = Vec::with_capacity(lsts.len());
+    for (
+        lst,
+        ProRataLstOut {
+            to_user_lst_amount,
+            to_protocol_fees_lst_amount,
+        },
+    ) in lsts.iter().zip(lst_outs)
+    {
+        event_lsts.push(LiquidityLstAmounts {
+            lst_mint: *lst.lst_mint.key,
+            lst_amount: to_user_lst_amount,
+            protocol_fee_amount: to_protocol_fees_lst_amount,
+        });
+        if to_user_lst_amount == 0 {
+            continue;
+        }
+        transfer_checked_decimal_agnostic_invoke_signed(
+            TransferCheckedAccounts {
+                to: lst.lst_acc,
+                token_program: lst.lst_token_program,
+                from: lst.pool_reserves,
+                authority: accounts.pool_state,
+                mint: lst.lst_mint,
+            },
+            to_user_lst_amount,
+            &[&pool.pool_state_seeds()],
+        )?;
+        transfer_checked_decimal_agnostic_invoke_signed(
+            TransferCheckedAccounts {
+                to: lst.protocol_fee_accumulator,
+                token_program: lst.lst_token_program,
+                from: lst.pool_reserves,
+                authority: accounts.pool_state,
+                mint: lst.lst_mint,
+            },
+            to_protocol_fees_lst_amount,
+            &[&pool.pool_state_seeds()],
+        )?;
+    }
+
+    for lst in lsts.iter() {
+        sync_sol_value_unchecked(
+            lst.sync_sol_value_accounts(accounts.pool_state, accounts.lst_state_list),
+            lst.cpi,
+            lst.lst_index,
+        )?;
+    }
+
+    emit_event(
+        accounts.pool_state,
+        &SControllerEvent::RemoveLiquidityProRata {
+            lsts: event_lsts,
+            lp_tokens_burnt: lp_token_amount,
+        },
+    )
+}
+
+/// Prices and converts a single LST's share of the LP tokens being redeemed.
+///
+/// Returns zero amounts if the share is too small to redeem any LST,
+/// in which case the share's SOL value stays in the pool
+fn calc_pro_rata_lst_out(
+    lst: &MultiLiquidityLst,
+    share_args: PricingProgramIxArgs,
+    lp_protocol_fee_bps: u16,
+) -> Result<ProRataLstOut, ProgramError> {
+    let lp_tokens_sol_value = share_args.sol_value;
+    let lp_tokens_sol_value_after_fees = lst
+        .pricing_cpi
+        .invoke_price_lp_tokens_to_redeem(share_args)?;
+    if lp_tokens_sol_value_after_fees > lp_tokens_sol_value {
+        return Err(SControllerError::PoolWouldLoseSolValue.into());
+    }
+    let to_user_lst_amount = lst
+        .cpi
+        .invoke_sol_to_lst(lp_tokens_sol_value_after_fees)?
+        .get_min();
+    if to_user_lst_amount == 0 {
+        return Ok(ProRataLstOut::default());
+    }
+    let to_protocol_fees_lst_amount =
+        calc_remove_liquidity_protocol_fees(CalcRemoveLiquidityProtocolFeesArgs {
+            lp_tokens_sol_value,
+            lp_tokens_sol_value_after_fees,
+            to_user_lst_amount,
+            lp_protocol_fee_bps,
+        })?;
+    Ok(ProRataLstOut {
+        to_user_lst_amount,
+        to_protocol_fees_lst_amount,
+    })
+}
+
+fn verify_remove_liquidity_pro_rata<'a, 'info>(
+    accounts: &'a [AccountInfo<'info>],
+    RemoveLiquidityProRataIxArgs {
+        lst_value_calc_accs,
+        pricing_accs,
+        lp_token_amount,
+        min_lst_outs,
+    }: RemoveLiquidityProRataIxArgs,
+) -> Result<
+    (
+        RemoveLiquidityProRataAccounts<'a, 'info>,
+        Vec<MultiLiquidityLst<'a, 'info>>,
+        u64,
+        Vec<u64>,
+        Pool,
+    ),
+    ProgramError,
+> {
+    if lp_token_amount == 0 {
+        return Err(SControllerError::ZeroValue.into());
+    }
+
+    let actual: RemoveLiquidityProRataAccounts = load_accounts(accounts)?;
+    let pool = Pool::try_from_pool_state(actual.pool_state)?;
+
+    let free_args = RemoveLiquidityProRataFreeArgs {
+        signer: *actual.signer.key,
+        src_lp_acc: *actual.src_lp_acc.key,
+        pool_state: actual.pool_state,
+    };
+    let expected = free_args.resolve_for_pool(pool.pdas)?;
+
+    remove_liquidity_pro_rata_verify_account_keys(actual, expected)
+        .map_err(log_and_return_wrong_acc_err)?;
+    remove_liquidity_pro_rata_verify_account_privileges(actual)
+        .map_err(log_and_return_acc_privilege_err)?;
+
+    // LP tokens are redeemed across every LST in the pool, in list order
+    let n_lsts = {
+        let pool_state_bytes = actual.pool_state.try_borrow_data()?;
+        let pool_state = try_pool_state(&pool_state_bytes)?;
+        verify_not_rebalancing_and_not_disabled(pool_state)?;
+
+        let lst_state_list_bytes = actual.lst_state_list.try_borrow_data()?;
+        try_lst_state_list(&lst_state_list_bytes)?.len()
+    };
+    if n_lsts == 0
+        || min_lst_outs.len() != n_lsts
+        || lst_value_calc_accs.len() != n_lsts
+        || pricing_accs.len() != n_lsts
+    {
+        return Err(SControllerError::InvalidLiquidityLsts.into());
+    }
+
+    let accounts_suffix_slice = accounts
+        .get(REMOVE_LIQUIDITY_PRO_RATA_IX_ACCOUNTS_LEN..)
+        .ok_or(ProgramError::NotEnoughAccountKeys)?;
+
+    let lsts = verify_multi_liquidity_lsts(
+        VerifyMultiLiquidityLstsAccounts {
+            pool_state: actual.pool_state,
+            lst_state_list: actual.lst_state_list,
+        },
+        pool.pdas.into(),
+        accounts_suffix_slice,
+        (0..n_lsts).collect(),
+        lst_value_calc_accs,
+        pricing_accs,
+    )?;
+
+    Ok((actual, lsts, lp_token_amount, min_lst_outs, pool))
+}
@@ -3,9 +3,7 @@ use s_controller_interface::{
     RemoveLstAccounts, RemoveLstIxArgs, SControllerError,
 };
 use s_controller_lib::{
-    index_to_usize,
-    program::{POOL_STATE_BUMP, POOL_STATE_SEED, PROTOCOL_FEE_BUMP, PROTOCOL_FEE_SEED},
-    try_lst_state_list, try_pool_state, RemoveLstFreeArgs,
+    index_to_usize, try_lst_state_list, try_pool_state, Pool, RemoveLstFreeArgs, SControllerEvent,
 };
 use sanctum_misc_utils::{
     load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
@@ -18,12 +16,28 @@ use solana_program::{
 };
 
 use crate::{
+    events::emit_event,
     list_account::{remove_from_list_pda, RemoveFromListPdaAccounts},
-    verify::verify_not_rebalancing_and_not_disabled,
+    verify::{verify_not_rebalancing_and_not_disabled, verify_not_timelocked},
 };
 
 pub fn process_remove_lst(accounts: &[AccountInfo], args: RemoveLstIxArgs) -> ProgramResult {
-    let (accounts, lst_index) = verify_remove_lst(accounts, args)?;
+    let (accounts, lst_index, pool) = verify_remove_lst(accounts, args)?;
+
+    {
+        let pool_state_bytes = accounts.pool_state.try_borrow_data()?;
+        verify_not_timelocked(try_pool_state(&pool_state_bytes)?)?;
+    }
+
+    remove_lst_unchecked(accounts, lst_index, pool)
+}
+
+/// Does not check that the pool is not timelocked, for use by `ExecutePendingChange`
+pub fn remove_lst_unchecked(
+    accounts: RemoveLstAccounts,
+    lst_index: usize,
+    pool: Pool,
+) -> ProgramResult {
     close_token_account_invoke_signed(
         CloseTokenAccountAccounts {
             account_to_close: accounts.protocol_fee_accumulator,
@@ -31,7 +45,7 @@ pub fn process_remove_lst(accounts: &[AccountInfo], args: RemoveLstIxArgs) -> Pr
             token_program: accounts.lst_token_program,
             refund_rent_to: accounts.refund_rent_to,
         },
-        &[&[PROTOCOL_FEE_SEED, &[PROTOCOL_FEE_BUMP]]],
+        &[&pool.protocol_fee_seeds()],
     )?;
 
     close_token_account_invoke_signed(
@@ -41,7 +55,7 @@ pub fn process_remove_lst(accounts: &[AccountInfo], args: RemoveLstIxArgs) -> Pr
             token_program: accounts.lst_token_program,
             refund_rent_to: accounts.refund_rent_to,
         },
-        &[&[POOL_STATE_SEED, &[POOL_STATE_BUMP]]],
+        &[&pool.pool_state_seeds()],
     )?;
     // Gotta put direct account lamport manipuation last after token program CPIs
     // because CPIs' lamport balance checks are broken:
@@ -52,16 +66,24 @@ pub fn process_remove_lst(accounts: &[AccountInfo], args: RemoveLstIxArgs) -> Pr
             refund_rent_to: accounts.refund_rent_to,
         },
         lst_index,
+    )?;
+
+    emit_event(
+        accounts.pool_state,
+        &SControllerEvent::RemoveLst {
+            lst_mint: *accounts.lst_mint.key,
+        },
     )
 }
 
-fn verify_remove_lst<'a, 'info>(
+pub fn verify_remove_lst<'a, 'info>(
     accounts: &'a [AccountInfo<'info>],
     RemoveLstIxArgs { lst_index }: RemoveLstIxArgs,
-) -> Result<(RemoveLstAccounts<'a, 'info>, usize), ProgramError> {
+) -> Result<(RemoveLstAccounts<'a, 'info>, usize, Pool), ProgramError> {
     let lst_index = index_to_usize(lst_index)?;
 
     let actual: RemoveLstAccounts = load_accounts(accounts)?;
+    let pool = Pool::try_from_pool_state(actual.pool_state)?;
 
     let free_args = RemoveLstFreeArgs {
         lst_index,
@@ -70,7 +92,7 @@ fn verify_remove_lst<'a, 'info>(
         lst_state_list: actual.lst_state_list,
         lst_mint: actual.lst_mint,
     };
-    let expected = free_args.resolve()?;
+    let expected = free_args.resolve_for_pool(pool.pdas)?;
 
     remove_lst_verify_account_keys(actual, expected).map_err(log_and_return_wrong_acc_err)?;
     remove_lst_verify_account_privileges(actual).map_err(log_and_return_acc_privilege_err)?;
@@ -90,5 +112,5 @@ fn verify_remove_lst<'a, 'info>(
         return Err(SControllerError::LstStillHasValue.into());
     }
 
-    Ok((actual, lst_index))
+    Ok((actual, lst_index, pool))
 }
@@ -1,7 +1,9 @@
 use s_controller_interface::{
     set_admin_verify_account_keys, set_admin_verify_account_privileges, SetAdminAccounts,
 };
-use s_controller_lib::{try_pool_state_mut, SetAdminFreeArgs};
+use s_controller_lib::{
+    try_pool_state, try_pool_state_mut, Pool, SControllerEvent, SetAdminFreeArgs,
+};
 use sanctum_misc_utils::{
     load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
 };
@@ -9,26 +11,44 @@ use solana_program::{
     account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
 };
 
+use crate::{events::emit_event, verify::verify_not_timelocked};
+
 pub fn process_set_admin(accounts: &[AccountInfo]) -> ProgramResult {
     let checked = verify_set_admin(accounts)?;
 
+    {
+        let pool_state_data = checked.pool_state.try_borrow_data()?;
+        verify_not_timelocked(try_pool_state(&pool_state_data)?)?;
+    }
+
+    set_admin_unchecked(checked)
+}
+
+/// Does not check that the pool is not timelocked, for use by `ExecutePendingChange`
+pub fn set_admin_unchecked(checked: SetAdminAccounts) -> ProgramResult {
     let mut pool_state_data = checked.pool_state.try_borrow_mut_data()?;
     let pool_state = try_pool_state_mut(&mut pool_state_data)?;
     pool_state.admin = *checked.new_admin.key;
 
-    Ok(())
+    emit_event(
+        checked.pool_state,
+        &SControllerEvent::SetAdmin {
+            admin: *checked.new_admin.key,
+        },
+    )
 }
 
-fn verify_set_admin<'a, 'info>(
+pub fn verify_set_admin<'a, 'info>(
     accounts: &'a [AccountInfo<'info>],
 ) -> Result<SetAdminAccounts<'a, 'info>, ProgramError> {
     let actual: SetAdminAccounts = load_accounts(accounts)?;
+    let pool = Pool::try_from_pool_state(actual.pool_state)?;
 
     let expected = SetAdminFreeArgs {
         new_admin: *actual.new_admin.key,
         pool_state: actual.pool_state,
     }
-    .resolve()?;
+    .resolve_for_pool(pool.pdas)?;
 
     set_admin_verify_account_keys(actual, expected).map_err(log_and_return_wrong_acc_err)?;
     set_admin_verify_account_privileges(actual).map_err(log_and_return_acc_privilege_err)?;
@@ -0,0 +1,86 @@
+use s_controller_interface::{
+    set_lst_cap_verify_account_keys, set_lst_cap_verify_account_privileges, SControllerError,
+    SetLstCapAccounts, SetLstCapIxArgs,
+};
+use s_controller_lib::{
+    index_to_usize, try_lst_state_list_mut, try_pool_state, Pool, SControllerEvent,
+    SetLstCapFreeArgs,
+};
+use sanctum_misc_utils::{
+    load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
+};
+use sanctum_token_ratio::BPS_DENOMINATOR;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+};
+
+use crate::{
+    events::emit_event,
+    verify::{verify_not_rebalancing_and_not_disabled, verify_not_timelocked},
+};
+
+pub fn process_set_lst_cap(accounts: &[AccountInfo], args: SetLstCapIxArgs) -> ProgramResult {
+    let (accounts, lst_index, max_share_bps) = verify_set_lst_cap(accounts, args)?;
+
+    {
+        let pool_state_data = accounts.pool_state.try_borrow_data()?;
+        verify_not_timelocked(try_pool_state(&pool_state_data)?)?;
+    }
+
+    set_lst_cap_unchecked(accounts, lst_index, max_share_bps)
+}
+
+/// Does not check that the pool is not timelocked, for use by `ExecutePendingChange`.
+/// `lst_index` must have been checked by [`verify_set_lst_cap`]
+pub fn set_lst_cap_unchecked(
+    accounts: SetLstCapAccounts,
+    lst_index: usize,
+    max_share_bps: u16,
+) -> ProgramResult {
+    let mut lst_state_list_data = accounts.lst_state_list.try_borrow_mut_data()?;
+    let lst_state_list = try_lst_state_list_mut(&mut lst_state_list_data)?;
+
+    // lst_index checked in verify
+    lst_state_list[lst_index].max_share_bps = max_share_bps;
+
+    emit_event(
+        accounts.pool_state,
+        &SControllerEvent::SetLstCap {
+            lst_mint: *accounts.lst_mint.key,
+            max_share_bps,
+        },
+    )
+}
+
+pub fn verify_set_lst_cap<'me, 'info>(
+    accounts: &'me [AccountInfo<'info>],
+    SetLstCapIxArgs {
+        index,
+        max_share_bps,
+    }: SetLstCapIxArgs,
+) -> Result<(SetLstCapAccounts<'me, 'info>, usize, u16), ProgramError> {
+    let lst_index = index_to_usize(index)?;
+
+    let actual: SetLstCapAccounts = load_accounts(accounts)?;
+    let pool = Pool::try_from_pool_state(actual.pool_state)?;
+
+    let free_args = SetLstCapFreeArgs {
+        lst_index,
+        pool_state: actual.pool_state,
+        lst_state_list: actual.lst_state_list,
+    };
+    let expected = free_args.resolve_for_pool(pool.pdas)?;
+
+    set_lst_cap_verify_account_keys(actual, expected).map_err(log_and_return_wrong_acc_err)?;
+    set_lst_cap_verify_account_privileges(actual).map_err(log_and_return_acc_privilege_err)?;
+
+    let pool_state_bytes = actual.pool_state.try_borrow_data()?;
+    let pool_state = try_pool_state(&pool_state_bytes)?;
+    verify_not_rebalancing_and_not_disabled(pool_state)?;
+
+    if max_share_bps > BPS_DENOMINATOR {
+        return Err(SControllerError::LstCapTooHigh.into());
+    }
+
+    Ok((actual, lst_index, max_share_bps))
+}
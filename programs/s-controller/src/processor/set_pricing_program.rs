@@ -2,7 +2,9 @@ use s_controller_interface::{
     set_pricing_program_verify_account_keys, set_pricing_program_verify_account_privileges,
     SetPricingProgramAccounts,
 };
-use s_controller_lib::{try_pool_state, try_pool_state_mut, SetPricingProgramFreeArgs};
+use s_controller_lib::{
+    try_pool_state, try_pool_state_mut, Pool, SControllerEvent, SetPricingProgramFreeArgs,
+};
 use sanctum_misc_utils::{
     load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
 };
@@ -10,33 +12,60 @@ use solana_program::{
     account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
 };
 
-use crate::verify::{verify_not_rebalancing_and_not_disabled, verify_pricing_program_is_program};
+use crate::{
+    events::emit_event,
+    verify::{
+        verify_not_rebalancing_and_not_disabled, verify_not_timelocked,
+        verify_pricing_program_is_program,
+    },
+};
 
 pub fn process_set_pricing_program(accounts: &[AccountInfo]) -> ProgramResult {
-    let SetPricingProgramAccounts {
+    let checked = verify_set_pricing_program(accounts)?;
+
+    {
+        let pool_state_bytes = checked.pool_state.try_borrow_data()?;
+        verify_not_timelocked(try_pool_state(&pool_state_bytes)?)?;
+    }
+
+    set_pricing_program_unchecked(checked)
+}
+
+/// Does not check that the pool is not timelocked, for use by `ExecutePendingChange`
+pub fn set_pricing_program_unchecked(
+    SetPricingProgramAccounts {
         admin: _,
         new_pricing_program,
         pool_state,
-    } = verify_set_pricing_program(accounts)?;
+    }: SetPricingProgramAccounts,
+) -> ProgramResult {
+    // braces to limit scope of pool_state_bytes borrow
+    {
+        let mut pool_state_bytes = pool_state.try_borrow_mut_data()?;
+        let pool_state = try_pool_state_mut(&mut pool_state_bytes)?;
 
-    let mut pool_state_bytes = pool_state.try_borrow_mut_data()?;
-    let pool_state = try_pool_state_mut(&mut pool_state_bytes)?;
+        pool_state.pricing_program = *new_pricing_program.key;
+    }
 
-    pool_state.pricing_program = *new_pricing_program.key;
-
-    Ok(())
+    emit_event(
+        pool_state,
+        &SControllerEvent::SetPricingProgram {
+            pricing_program: *new_pricing_program.key,
+        },
+    )
 }
 
-fn verify_set_pricing_program<'me, 'info>(
+pub fn verify_set_pricing_program<'me, 'info>(
     accounts: &'me [AccountInfo<'info>],
 ) -> Result<SetPricingProgramAccounts<'me, 'info>, ProgramError> {
     let actual: SetPricingProgramAccounts = load_accounts(accounts)?;
+    let pool = Pool::try_from_pool_state(actual.pool_state)?;
 
     let free_args = SetPricingProgramFreeArgs {
         new_pricing_program: *actual.new_pricing_program.key,
         pool_state_acc: actual.pool_state,
     };
-    let expected = free_args.resolve()?;
+    let expected = free_args.resolve_for_pool(pool.pdas)?;
 
     set_pricing_program_verify_account_keys(actual, expected)
         .map_err(log_and_return_wrong_acc_err)?;
@@ -2,7 +2,9 @@ use s_controller_interface::{
     set_protocol_fee_verify_account_keys, set_protocol_fee_verify_account_privileges,
     SControllerError, SetProtocolFeeAccounts, SetProtocolFeeIxArgs,
 };
-use s_controller_lib::{try_pool_state, try_pool_state_mut, SetProtocolFeeFreeArgs};
+use s_controller_lib::{
+    try_pool_state, try_pool_state_mut, Pool, SControllerEvent, SetProtocolFeeFreeArgs,
+};
 use sanctum_misc_utils::{
     load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
 };
@@ -11,20 +13,33 @@ use solana_program::{
     account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
 };
 
-use crate::verify::verify_not_rebalancing_and_not_disabled;
+use crate::{
+    events::emit_event,
+    verify::{verify_not_rebalancing_and_not_disabled, verify_not_timelocked},
+};
 
 pub fn process_set_protocol_fee(
     accounts: &[AccountInfo],
     args: SetProtocolFeeIxArgs,
 ) -> ProgramResult {
-    let (
-        accounts,
-        SetProtocolFeeIxArgs {
-            new_trading_protocol_fee_bps,
-            new_lp_protocol_fee_bps,
-        },
-    ) = verify_set_protocol_fee(accounts, args)?;
+    let (accounts, args) = verify_set_protocol_fee(accounts, args)?;
+
+    {
+        let pool_state_bytes = accounts.pool_state.try_borrow_data()?;
+        verify_not_timelocked(try_pool_state(&pool_state_bytes)?)?;
+    }
 
+    set_protocol_fee_unchecked(accounts, args)
+}
+
+/// Does not check that the pool is not timelocked, for use by `ExecutePendingChange`
+pub fn set_protocol_fee_unchecked(
+    accounts: SetProtocolFeeAccounts,
+    SetProtocolFeeIxArgs {
+        new_trading_protocol_fee_bps,
+        new_lp_protocol_fee_bps,
+    }: SetProtocolFeeIxArgs,
+) -> ProgramResult {
     let mut pool_state_bytes = accounts.pool_state.try_borrow_mut_data()?;
     let pool_state = try_pool_state_mut(&mut pool_state_bytes)?;
 
@@ -35,10 +50,16 @@ pub fn process_set_protocol_fee(
         pool_state.lp_protocol_fee_bps = new_lp_protocol_fee_bps;
     }
 
-    Ok(())
+    emit_event(
+        accounts.pool_state,
+        &SControllerEvent::SetProtocolFee {
+            trading_protocol_fee_bps: pool_state.trading_protocol_fee_bps,
+            lp_protocol_fee_bps: pool_state.lp_protocol_fee_bps,
+        },
+    )
 }
 
-fn verify_set_protocol_fee<'a, 'info>(
+pub fn verify_set_protocol_fee<'a, 'info>(
     accounts: &'a [AccountInfo<'info>],
     SetProtocolFeeIxArgs {
         new_trading_protocol_fee_bps,
@@ -46,11 +67,12 @@ fn verify_set_protocol_fee<'a, 'info>(
     }: SetProtocolFeeIxArgs,
 ) -> Result<(SetProtocolFeeAccounts<'a, 'info>, SetProtocolFeeIxArgs), ProgramError> {
     let actual: SetProtocolFeeAccounts = load_accounts(accounts)?;
+    let pool = Pool::try_from_pool_state(actual.pool_state)?;
 
     let free_args = SetProtocolFeeFreeArgs {
         pool_state: actual.pool_state,
     };
-    let expected = free_args.resolve()?;
+    let expected = free_args.resolve_for_pool(pool.pdas)?;
 
     set_protocol_fee_verify_account_keys(actual, expected).map_err(log_and_return_wrong_acc_err)?;
     set_protocol_fee_verify_account_privileges(actual).map_err(log_and_return_acc_privilege_err)?;
@@ -2,7 +2,9 @@ use s_controller_interface::{
     set_protocol_fee_beneficiary_verify_account_keys,
     set_protocol_fee_beneficiary_verify_account_privileges, SetProtocolFeeBeneficiaryAccounts,
 };
-use s_controller_lib::{try_pool_state, try_pool_state_mut, SetProtocolFeeBeneficiaryFreeArgs};
+use s_controller_lib::{
+    try_pool_state, try_pool_state_mut, Pool, SControllerEvent, SetProtocolFeeBeneficiaryFreeArgs,
+};
 use sanctum_misc_utils::{
     load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
 };
@@ -10,28 +12,49 @@ use solana_program::{
     account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
 };
 
-use crate::verify::verify_not_rebalancing_and_not_disabled;
+use crate::{
+    events::emit_event,
+    verify::{verify_not_rebalancing_and_not_disabled, verify_not_timelocked},
+};
 
 pub fn process_set_protocol_fee_beneficiary(accounts: &[AccountInfo]) -> ProgramResult {
     let checked = verify_set_protocol_fee_beneficiary(accounts)?;
 
+    {
+        let pool_state_data = checked.pool_state.try_borrow_data()?;
+        verify_not_timelocked(try_pool_state(&pool_state_data)?)?;
+    }
+
+    set_protocol_fee_beneficiary_unchecked(checked)
+}
+
+/// Does not check that the pool is not timelocked, for use by `ExecutePendingChange`
+pub fn set_protocol_fee_beneficiary_unchecked(
+    checked: SetProtocolFeeBeneficiaryAccounts,
+) -> ProgramResult {
     let mut pool_state_data = checked.pool_state.try_borrow_mut_data()?;
     let pool_state = try_pool_state_mut(&mut pool_state_data)?;
     pool_state.protocol_fee_beneficiary = *checked.new_beneficiary.key;
 
-    Ok(())
+    emit_event(
+        checked.pool_state,
+        &SControllerEvent::SetProtocolFeeBeneficiary {
+            protocol_fee_beneficiary: *checked.new_beneficiary.key,
+        },
+    )
 }
 
-fn verify_set_protocol_fee_beneficiary<'a, 'info>(
+pub fn verify_set_protocol_fee_beneficiary<'a, 'info>(
     accounts: &'a [AccountInfo<'info>],
 ) -> Result<SetProtocolFeeBeneficiaryAccounts<'a, 'info>, ProgramError> {
     let actual: SetProtocolFeeBeneficiaryAccounts = load_accounts(accounts)?;
+    let pool = Pool::try_from_pool_state(actual.pool_state)?;
 
     let expected = SetProtocolFeeBeneficiaryFreeArgs {
         new_beneficiary: *actual.new_beneficiary.key,
         pool_state: actual.pool_state,
     }
-    .resolve()?;
+    .resolve_for_pool(pool.pdas)?;
 
     set_protocol_fee_beneficiary_verify_account_keys(actual, expected)
         .map_err(log_and_return_wrong_acc_err)?;
@@ -2,7 +2,9 @@ use s_controller_interface::{
     set_rebalance_authority_verify_account_keys, set_rebalance_authority_verify_account_privileges,
     SControllerError, SetRebalanceAuthorityAccounts,
 };
-use s_controller_lib::{try_pool_state, try_pool_state_mut, SetRebalanceAuthorityFreeArgs};
+use s_controller_lib::{
+    try_pool_state, try_pool_state_mut, Pool, SControllerEvent, SetRebalanceAuthorityFreeArgs,
+};
 use sanctum_misc_utils::{
     load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
 };
@@ -10,29 +12,53 @@ use solana_program::{
     account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
 };
 
-use crate::verify::verify_not_rebalancing_and_not_disabled;
+use crate::{
+    events::emit_event,
+    verify::{verify_not_rebalancing_and_not_disabled, verify_not_timelocked},
+};
 
 pub fn process_set_rebalance_authority(accounts: &[AccountInfo]) -> ProgramResult {
     let accounts = verify_set_rebalance_authority(accounts)?;
 
+    // the current rebalance authority may still replace itself in timelock mode,
+    // only the admin's ability to appoint a new one is timelocked
+    {
+        let pool_state_data = accounts.pool_state.try_borrow_data()?;
+        let pool_state = try_pool_state(&pool_state_data)?;
+        if *accounts.signer.key == pool_state.admin {
+            verify_not_timelocked(pool_state)?;
+        }
+    }
+
+    set_rebalance_authority_unchecked(accounts)
+}
+
+/// Does not check that the pool is not timelocked, for use by `ExecutePendingChange`
+pub fn set_rebalance_authority_unchecked(accounts: SetRebalanceAuthorityAccounts) -> ProgramResult {
     let mut pool_state_bytes = accounts.pool_state.try_borrow_mut_data()?;
     let pool_state = try_pool_state_mut(&mut pool_state_bytes)?;
 
     pool_state.rebalance_authority = *accounts.new_rebalance_authority.key;
 
-    Ok(())
+    emit_event(
+        accounts.pool_state,
+        &SControllerEvent::SetRebalanceAuthority {
+            rebalance_authority: *accounts.new_rebalance_authority.key,
+        },
+    )
 }
 
-fn verify_set_rebalance_authority<'a, 'info>(
+pub fn verify_set_rebalance_authority<'a, 'info>(
     accounts: &'a [AccountInfo<'info>],
 ) -> Result<SetRebalanceAuthorityAccounts<'a, 'info>, ProgramError> {
     let actual: SetRebalanceAuthorityAccounts = load_accounts(accounts)?;
+    let pool = Pool::try_from_pool_state(actual.pool_state)?;
 
     let expected = SetRebalanceAuthorityFreeArgs {
         signer: *actual.signer.key,
         new_rebalance_authority: *actual.new_rebalance_authority.key,
     }
-    .resolve();
+    .resolve_for_pool(pool.pdas);
 
     set_rebalance_authority_verify_account_keys(actual, expected)
         .map_err(log_and_return_wrong_acc_err)?;
@@ -0,0 +1,123 @@
+use s_controller_interface::{
+    set_rebalance_loss_limit_verify_account_keys,
+    set_rebalance_loss_limit_verify_account_privileges, RebalanceLossState, SControllerError,
+    SetRebalanceLossLimitAccounts, SetRebalanceLossLimitIxArgs,
+};
+use s_controller_lib::{
+    try_pool_state, try_rebalance_loss_state, try_rebalance_loss_state_mut, Pool, SControllerEvent,
+    SetRebalanceLossLimitFreeArgs, REBALANCE_LOSS_STATE_SIZE,
+};
+use sanctum_misc_utils::{
+    load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
+};
+use sanctum_system_program_lib::{
+    init_rent_exempt_account_invoke_signed, InitRentExemptAccountArgs,
+};
+use sanctum_token_ratio::BPS_DENOMINATOR;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+};
+use system_program_interface::CreateAccountAccounts;
+
+use crate::{events::emit_event, verify::verify_not_rebalancing_and_not_disabled};
+
+/// Creates the pool's RebalanceLossState PDA if it does not exist yet.
+///
+/// While the pool is in timelock mode, the limits can only be lowered directly.
+/// Raising them requires disabling timelock mode through the pending change list first.
+pub fn process_set_rebalance_loss_limit(
+    accounts: &[AccountInfo],
+    args: SetRebalanceLossLimitIxArgs,
+) -> ProgramResult {
+    let (checked, pool, args) = verify_set_rebalance_loss_limit(accounts, args)?;
+    let SetRebalanceLossLimitIxArgs {
+        max_loss_bps_per_rebalance,
+        max_loss_bps_per_epoch,
+    } = args;
+
+    if checked.rebalance_loss_state.data_is_empty() {
+        init_rent_exempt_account_invoke_signed(
+            CreateAccountAccounts {
+                from: checked.payer,
+                to: checked.rebalance_loss_state,
+            },
+            InitRentExemptAccountArgs {
+                space: REBALANCE_LOSS_STATE_SIZE,
+                owner: s_controller_lib::program::ID,
+            },
+            &[&pool.rebalance_loss_state_seeds()],
+        )?;
+    }
+
+    let mut rebalance_loss_state_data = checked.rebalance_loss_state.try_borrow_mut_data()?;
+    let rebalance_loss_state = try_rebalance_loss_state_mut(&mut rebalance_loss_state_data)?;
+    rebalance_loss_state.max_loss_bps_per_rebalance = max_loss_bps_per_rebalance;
+    rebalance_loss_state.max_loss_bps_per_epoch = max_loss_bps_per_epoch;
+
+    emit_event(
+        checked.pool_state,
+        &SControllerEvent::SetRebalanceLossLimit {
+            max_loss_bps_per_rebalance,
+            max_loss_bps_per_epoch,
+        },
+    )
+}
+
+fn verify_set_rebalance_loss_limit<'me, 'info>(
+    accounts: &'me [AccountInfo<'info>],
+    args: SetRebalanceLossLimitIxArgs,
+) -> Result<
+    (
+        SetRebalanceLossLimitAccounts<'me, 'info>,
+        Pool,
+        SetRebalanceLossLimitIxArgs,
+    ),
+    ProgramError,
+> {
+    let actual: SetRebalanceLossLimitAccounts = load_accounts(accounts)?;
+    let pool = Pool::try_from_pool_state(actual.pool_state)?;
+
+    let free_args = SetRebalanceLossLimitFreeArgs {
+        payer: *actual.payer.key,
+        pool_state: actual.pool_state,
+    };
+    let expected = free_args.resolve_for_pool(pool.pdas)?;
+
+    set_rebalance_loss_limit_verify_account_keys(actual, expected)
+        .map_err(log_and_return_wrong_acc_err)?;
+    set_rebalance_loss_limit_verify_account_privileges(actual)
+        .map_err(log_and_return_acc_privilege_err)?;
+
+    let pool_state_bytes = actual.pool_state.try_borrow_data()?;
+    let pool_state = try_pool_state(&pool_state_bytes)?;
+    verify_not_rebalancing_and_not_disabled(pool_state)?;
+
+    let SetRebalanceLossLimitIxArgs {
+        max_loss_bps_per_rebalance,
+        max_loss_bps_per_epoch,
+    } = args;
+    if max_loss_bps_per_rebalance > BPS_DENOMINATOR || max_loss_bps_per_epoch > BPS_DENOMINATOR {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    if pool_state.timelock_delay_hours != 0 {
+        let (curr_per_rebalance, curr_per_epoch) = if actual.rebalance_loss_state.data_is_empty() {
+            (0, 0)
+        } else {
+            let data = actual.rebalance_loss_state.try_borrow_data()?;
+            let RebalanceLossState {
+                max_loss_bps_per_rebalance,
+                max_loss_bps_per_epoch,
+                ..
+            } = try_rebalance_loss_state(&data)?;
+            (*max_loss_bps_per_rebalance, *max_loss_bps_per_epoch)
+        };
+        if max_loss_bps_per_rebalance > curr_per_rebalance
+            || max_loss_bps_per_epoch > curr_per_epoch
+        {
+            return Err(SControllerError::PoolTimelocked.into());
+        }
+    }
+
+    Ok((actual, pool, args))
+}
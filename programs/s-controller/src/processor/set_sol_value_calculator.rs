@@ -4,7 +4,8 @@ use s_controller_interface::{
     SetSolValueCalculatorIxArgs, SET_SOL_VALUE_CALCULATOR_IX_ACCOUNTS_LEN,
 };
 use s_controller_lib::{
-    index_to_usize, try_lst_state_list_mut, try_pool_state, SetSolValueCalculatorFreeArgs,
+    index_to_usize, try_lst_state_list_mut, try_pool_state, Pool, SControllerEvent,
+    SetSolValueCalculatorFreeArgs,
 };
 use sanctum_misc_utils::{
     load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
@@ -15,7 +16,11 @@ use solana_program::{
 
 use crate::{
     cpi::SolValueCalculatorCpi,
-    verify::{verify_not_rebalancing_and_not_disabled, verify_sol_value_calculator_is_program},
+    events::emit_event,
+    verify::{
+        verify_not_rebalancing_and_not_disabled, verify_not_timelocked,
+        verify_sol_value_calculator_is_program,
+    },
 };
 
 use super::{sync_sol_value_unchecked, SyncSolValueUncheckedAccounts};
@@ -26,6 +31,20 @@ pub fn process_set_sol_value_calculator(
 ) -> ProgramResult {
     let (accounts, lst_index, cpi) = verify_set_sol_value_calculator(accounts, args)?;
 
+    {
+        let pool_state_bytes = accounts.pool_state.try_borrow_data()?;
+        verify_not_timelocked(try_pool_state(&pool_state_bytes)?)?;
+    }
+
+    set_sol_value_calculator_unchecked(accounts, lst_index, cpi)
+}
+
+/// Does not check that the pool is not timelocked, for use by `ExecutePendingChange`
+pub fn set_sol_value_calculator_unchecked<'a, 'info>(
+    accounts: SetSolValueCalculatorAccounts<'a, 'info>,
+    lst_index: usize,
+    cpi: SolValueCalculatorCpi<'a, 'info>,
+) -> ProgramResult {
     {
         let mut lst_state_list_data = accounts.lst_state_list.try_borrow_mut_data()?;
         let lst_state_list = try_lst_state_list_mut(&mut lst_state_list_data)?;
@@ -37,10 +56,18 @@ pub fn process_set_sol_value_calculator(
         SyncSolValueUncheckedAccounts::from(accounts),
         cpi,
         lst_index,
+    )?;
+
+    emit_event(
+        accounts.pool_state,
+        &SControllerEvent::SetSolValueCalculator {
+            lst_mint: *accounts.lst_mint.key,
+            sol_value_calculator: *cpi.program.key,
+        },
     )
 }
 
-fn verify_set_sol_value_calculator<'a, 'info>(
+pub fn verify_set_sol_value_calculator<'a, 'info>(
     accounts: &'a [AccountInfo<'info>],
     SetSolValueCalculatorIxArgs { lst_index }: SetSolValueCalculatorIxArgs,
 ) -> Result<
@@ -53,6 +80,7 @@ fn verify_set_sol_value_calculator<'a, 'info>(
 > {
     let lst_index = index_to_usize(lst_index)?;
     let actual: SetSolValueCalculatorAccounts = load_accounts(accounts)?;
+    let pool = Pool::try_from_pool_state(actual.pool_state)?;
 
     let free_args = SetSolValueCalculatorFreeArgs {
         lst_index,
@@ -60,7 +88,7 @@ fn verify_set_sol_value_calculator<'a, 'info>(
         lst_state_list: actual.lst_state_list,
         lst_mint: actual.lst_mint,
     };
-    let expected = free_args.resolve()?;
+    let expected = free_args.resolve_for_pool(pool.pdas)?;
 
     set_sol_value_calculator_verify_account_keys(actual, expected)
         .map_err(log_and_return_wrong_acc_err)?;
@@ -0,0 +1,70 @@
+use s_controller_interface::{
+    set_timelock_delay_verify_account_keys, set_timelock_delay_verify_account_privileges,
+    SetTimelockDelayAccounts, SetTimelockDelayIxArgs,
+};
+use s_controller_lib::{
+    try_pool_state, try_pool_state_mut, Pool, SControllerEvent, SetTimelockDelayFreeArgs,
+};
+use sanctum_misc_utils::{
+    load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
+};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+};
+
+use crate::{events::emit_event, verify::verify_not_timelocked};
+
+/// Can only be used directly to enable timelock mode.
+/// Once enabled, changing the delay or disabling timelock mode
+/// must go through the pending change list.
+pub fn process_set_timelock_delay(
+    accounts: &[AccountInfo],
+    args: SetTimelockDelayIxArgs,
+) -> ProgramResult {
+    let checked = verify_set_timelock_delay(accounts)?;
+
+    {
+        let pool_state_data = checked.pool_state.try_borrow_data()?;
+        verify_not_timelocked(try_pool_state(&pool_state_data)?)?;
+    }
+
+    set_timelock_delay_unchecked(checked, args)
+}
+
+/// Does not check that the pool is not timelocked, for use by `ExecutePendingChange`
+pub fn set_timelock_delay_unchecked(
+    checked: SetTimelockDelayAccounts,
+    SetTimelockDelayIxArgs {
+        timelock_delay_hours,
+    }: SetTimelockDelayIxArgs,
+) -> ProgramResult {
+    let mut pool_state_data = checked.pool_state.try_borrow_mut_data()?;
+    let pool_state = try_pool_state_mut(&mut pool_state_data)?;
+    pool_state.timelock_delay_hours = timelock_delay_hours;
+
+    emit_event(
+        checked.pool_state,
+        &SControllerEvent::SetTimelockDelay {
+            timelock_delay_hours,
+        },
+    )
+}
+
+pub fn verify_set_timelock_delay<'a, 'info>(
+    accounts: &'a [AccountInfo<'info>],
+) -> Result<SetTimelockDelayAccounts<'a, 'info>, ProgramError> {
+    let actual: SetTimelockDelayAccounts = load_accounts(accounts)?;
+    let pool = Pool::try_from_pool_state(actual.pool_state)?;
+
+    let expected = SetTimelockDelayFreeArgs {
+        pool_state: actual.pool_state,
+    }
+    .resolve_for_pool(pool.pdas)?;
+
+    set_timelock_delay_verify_account_keys(actual, expected)
+        .map_err(log_and_return_wrong_acc_err)?;
+    set_timelock_delay_verify_account_privileges(actual)
+        .map_err(log_and_return_acc_privilege_err)?;
+
+    Ok(actual)
+}
@@ -4,10 +4,9 @@ use s_controller_interface::{
     START_REBALANCE_IX_ACCOUNTS_LEN,
 };
 use s_controller_lib::{
-    index_to_usize,
-    program::{POOL_STATE_BUMP, POOL_STATE_SEED, REBALANCE_RECORD_BUMP, REBALANCE_RECORD_SEED},
-    try_lst_state_list, try_pool_state, try_pool_state_mut, try_rebalance_record_mut,
-    PoolStateAccount, SrcDstLstIndexes, StartRebalanceFreeArgs, U8BoolMut, REBALANCE_RECORD_SIZE,
+    index_to_usize, max_rebalance_loss, try_lst_state_list, try_pool_state, try_pool_state_mut,
+    try_rebalance_loss_state, try_rebalance_record_mut, Pool, PoolStateAccount, SControllerEvent,
+    SrcDstLstIndexes, StartRebalanceFreeArgs, U8BoolMut, REBALANCE_RECORD_SIZE,
 };
 use sanctum_misc_utils::{
     load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
@@ -18,11 +17,15 @@ use sanctum_token_lib::{
 };
 use solana_program::{
     account_info::AccountInfo,
+    clock::Clock,
     entrypoint::ProgramResult,
     instruction::Instruction,
     program_error::ProgramError,
     pubkey::Pubkey,
-    sysvar::instructions::{load_current_index_checked, load_instruction_at_checked},
+    sysvar::{
+        instructions::{load_current_index_checked, load_instruction_at_checked},
+        Sysvar,
+    },
 };
 use system_program_interface::{
     allocate_invoke_signed, assign_invoke_signed, AllocateAccounts, AllocateIxArgs, AssignAccounts,
@@ -32,6 +35,7 @@ use system_program_interface::{
 use crate::{
     account_traits::{DstLstPoolReservesOf, SrcLstPoolReservesOf},
     cpi::SrcDstLstSolValueCalculatorCpis,
+    events::emit_event,
     verify::{
         verify_lst_input_not_disabled, verify_not_rebalancing_and_not_disabled,
         verify_src_dst_lst_sol_val_calc_cpis, VerifySrcDstLstSolValCalcCpiAccounts,
@@ -54,6 +58,7 @@ pub fn process_start_rebalance(
             src_lst_index,
             dst_lst_index,
         },
+        pool,
     ) = verify_start_rebalance(accounts, &args)?;
 
     let src_sync_sol_value_accounts =
@@ -66,6 +71,11 @@ pub fn process_start_rebalance(
     )?;
 
     let old_total_sol_value = accounts.pool_state.total_sol_value()?;
+    let min_ending_sol_value = bound_min_ending_sol_value(
+        accounts.rebalance_loss_state,
+        old_total_sol_value,
+        args.min_ending_sol_value,
+    )?;
 
     transfer_checked_decimal_agnostic_invoke_signed(
         TransferCheckedAccounts {
@@ -76,7 +86,7 @@ pub fn process_start_rebalance(
             mint: accounts.src_lst_mint,
         },
         args.amount,
-        &[&[POOL_STATE_SEED, &[POOL_STATE_BUMP]]],
+        &[&pool.pool_state_seeds()],
     )?;
 
     sync_sol_value_unchecked(src_sync_sol_value_accounts, src_lst_cpi, src_lst_index)?;
@@ -88,7 +98,7 @@ pub fn process_start_rebalance(
         AllocateIxArgs {
             space: space_to_u64(REBALANCE_RECORD_SIZE)?,
         },
-        &[&[REBALANCE_RECORD_SEED, &[REBALANCE_RECORD_BUMP]]],
+        &[&pool.rebalance_record_seeds()],
     )?;
     assign_invoke_signed(
         AssignAccounts {
@@ -97,7 +107,7 @@ pub fn process_start_rebalance(
         AssignIxArgs {
             owner: s_controller_lib::program::ID,
         },
-        &[&[REBALANCE_RECORD_SEED, &[REBALANCE_RECORD_BUMP]]],
+        &[&pool.rebalance_record_seeds()],
     )?;
     transfer_direct_increment(
         TransferAccounts {
@@ -111,12 +121,20 @@ pub fn process_start_rebalance(
     let rebalance_record = try_rebalance_record_mut(&mut rebalance_record_data)?;
     rebalance_record.dst_lst_index = args.dst_lst_index;
     rebalance_record.old_total_sol_value = old_total_sol_value;
+    rebalance_record.min_ending_sol_value = min_ending_sol_value;
 
     let mut pool_state_data = accounts.pool_state.try_borrow_mut_data()?;
     let pool_state = try_pool_state_mut(&mut pool_state_data)?;
     U8BoolMut(&mut pool_state.is_rebalancing).set_true();
 
-    Ok(())
+    emit_event(
+        accounts.pool_state,
+        &SControllerEvent::StartRebalance {
+            src_lst_mint: *accounts.src_lst_mint.key,
+            dst_lst_mint: *accounts.dst_lst_mint.key,
+            amount: args.amount,
+        },
+    )
 }
 
 fn verify_start_rebalance<'a, 'info>(
@@ -128,12 +146,14 @@ fn verify_start_rebalance<'a, 'info>(
         amount: _,
         min_starting_src_lst,
         max_starting_dst_lst,
+        min_ending_sol_value: _,
     }: &StartRebalanceIxArgs,
 ) -> Result<
     (
         StartRebalanceAccounts<'a, 'info>,
         SrcDstLstSolValueCalculatorCpis<'a, 'info>,
         SrcDstLstIndexes,
+        Pool,
     ),
     ProgramError,
 > {
@@ -141,6 +161,7 @@ fn verify_start_rebalance<'a, 'info>(
     let dst_lst_index = index_to_usize(*dst_lst_index)?;
 
     let actual: StartRebalanceAccounts = load_accounts(accounts)?;
+    let pool = Pool::try_from_pool_state(actual.pool_state)?;
 
     let free_args = StartRebalanceFreeArgs {
         withdraw_to: *actual.withdraw_to.key,
@@ -151,7 +172,7 @@ fn verify_start_rebalance<'a, 'info>(
         src_lst_mint: actual.src_lst_mint,
         dst_lst_mint: actual.dst_lst_mint,
     };
-    let expected = free_args.resolve()?;
+    let expected = free_args.resolve_for_pool(pool.pdas)?;
 
     start_rebalance_verify_account_keys(actual, expected).map_err(log_and_return_wrong_acc_err)?;
     start_rebalance_verify_account_privileges(actual).map_err(log_and_return_acc_privilege_err)?;
@@ -188,13 +209,42 @@ fn verify_start_rebalance<'a, 'info>(
         src_dst_lst_indexes,
     )?;
 
-    verify_has_succeeding_end_rebalance_ix(actual.instructions, *actual.dst_lst_mint.key)?;
+    verify_has_succeeding_end_rebalance_ix(
+        actual.instructions,
+        *actual.pool_state.key,
+        *actual.dst_lst_mint.key,
+    )?;
+
+    Ok((actual, src_dst_lst_cpis, src_dst_lst_indexes, pool))
+}
 
-    Ok((actual, src_dst_lst_cpis, src_dst_lst_indexes))
+/// Raises `min_ending_sol_value` to the lowest total SOL value
+/// the pool's rebalance loss limits allow the rebalance to end with.
+///
+/// Pools that have not opted into rebalance loss limits with SetRebalanceLossLimit
+/// have no RebalanceLossState and keep the original rule that a rebalance
+/// may not decrease the pool's total SOL value.
+fn bound_min_ending_sol_value(
+    rebalance_loss_state_acc: &AccountInfo,
+    old_total_sol_value: u64,
+    min_ending_sol_value: u64,
+) -> Result<u64, ProgramError> {
+    let rebalance_loss_state_data = rebalance_loss_state_acc.try_borrow_data()?;
+    if rebalance_loss_state_data.is_empty() {
+        return Ok(min_ending_sol_value.max(old_total_sol_value));
+    }
+    let max_loss = max_rebalance_loss(
+        try_rebalance_loss_state(&rebalance_loss_state_data)?,
+        old_total_sol_value,
+        Clock::get()?.epoch,
+    );
+    // max_loss <= old_total_sol_value
+    Ok(min_ending_sol_value.max(old_total_sol_value - max_loss))
 }
 
 fn verify_has_succeeding_end_rebalance_ix(
     instructions_sysvar: &AccountInfo,
+    pool_state: Pubkey,
     dst_lst_mint: Pubkey,
 ) -> Result<(), ProgramError> {
     let mut next_ix_idx: usize = load_current_index_checked(instructions_sysvar)?.into();
@@ -204,16 +254,18 @@ fn verify_has_succeeding_end_rebalance_ix(
             .ok_or(SControllerError::MathError)?;
         let next_ix = load_instruction_at_checked(next_ix_idx, instructions_sysvar)
             .map_err(|_| SControllerError::NoSucceedingEndRebalance)?;
-        if is_end_rebalance_ix(&next_ix, dst_lst_mint) {
+        if is_end_rebalance_ix(&next_ix, pool_state, dst_lst_mint) {
             break;
         }
     }
     Ok(())
 }
 
+const END_REBALANCE_IX_POOL_STATE_INDEX: usize = 1;
+
 const END_REBALANCE_IX_DST_LST_MINT_INDEX: usize = 4;
 
-fn is_end_rebalance_ix(ix: &Instruction, dst_lst_mint: Pubkey) -> bool {
+fn is_end_rebalance_ix(ix: &Instruction, pool_state: Pubkey, dst_lst_mint: Pubkey) -> bool {
     let discm = match ix.data.first() {
         Some(d) => d,
         None => return false,
@@ -224,6 +276,13 @@ fn is_end_rebalance_ix(ix: &Instruction, dst_lst_mint: Pubkey) -> bool {
     if ix.program_id != s_controller_lib::program::ID {
         return false;
     }
+    let pool_state_account = match ix.accounts.get(END_REBALANCE_IX_POOL_STATE_INDEX) {
+        Some(a) => a,
+        None => return false,
+    };
+    if pool_state_account.pubkey != pool_state {
+        return false;
+    }
     let dst_lst_mint_account = match ix.accounts.get(END_REBALANCE_IX_DST_LST_MINT_INDEX) {
         Some(a) => a,
         None => return false,
@@ -0,0 +1,392 @@
+use s_controller_interface::{
+    swap_exact_in_route_verify_account_keys, swap_exact_in_route_verify_account_privileges,
+    SControllerError, SwapExactInRouteAccounts, SwapExactInRouteIxArgs,
+    SWAP_EXACT_IN_ROUTE_IX_ACCOUNTS_LEN,
+};
+use s_controller_lib::{
+    calc_swap_protocol_fees, index_to_usize, try_lst_state_list, try_pool_state,
+    CalcSwapProtocolFeesArgs, Pool, PoolStateAccount, SControllerEvent, SwapExactInAmounts,
+    SwapExactInRouteFreeArgs, SwapRouteLstFreeArgs, SwapRouteLstKeys, MAX_SWAP_ROUTE_LSTS,
+    SWAP_ROUTE_LST_ACCOUNTS_LEN,
+};
+use sanctum_misc_utils::{
+    load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
+};
+use sanctum_token_lib::{
+    token_account_balance, transfer_checked_decimal_agnostic_invoke,
+    transfer_checked_decimal_agnostic_invoke_signed, TransferCheckedAccounts,
+};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::{
+    account_traits::SrcDstLstMintAccountInfos,
+    cpi::{PricingProgramIxArgs, PricingProgramPriceSwapCpi, SolValueCalculatorCpi},
+    events::emit_event,
+    processor::sync_sol_value_unchecked,
+    verify::{
+        verify_lst_input_not_disabled, verify_lst_sol_val_calc_cpi, verify_lst_within_cap,
+        verify_not_rebalancing_and_not_disabled, verify_pricing_swap_cpi,
+        VerifyLstSolValCalcCpiAccounts, VerifyPricingSwapCpiAccounts,
+    },
+};
+
+use super::SyncSolValueUncheckedAccounts;
+
+/// A single LST on the swap route
+struct RouteLst<'a, 'info> {
+    lst_index: usize,
+    lst_mint: &'a AccountInfo<'info>,
+    lst_token_program: &'a AccountInfo<'info>,
+    pool_reserves: &'a AccountInfo<'info>,
+    protocol_fee_accumulator: &'a AccountInfo<'info>,
+    cpi: SolValueCalculatorCpi<'a, 'info>,
+}
+
+pub fn process_swap_exact_in_route(
+    accounts: &[AccountInfo],
+    args: SwapExactInRouteIxArgs,
+) -> ProgramResult {
+    let (
+        accounts,
+        SwapExactInAmounts {
+            min_amount_out,
+            amount,
+        },
+        route,
+        pricing_cpis,
+        pool,
+    ) = verify_swap_exact_in_route(accounts, args)?;
+
+    // each touched LstState is synced exactly once before and once after the swap,
+    // regardless of how many hops it's part of
+    for lst in route.iter() {
+        sync_sol_value_unchecked(
+            route_lst_sync_accounts(accounts, lst),
+            lst.cpi,
+            lst.lst_index,
+        )?;
+    }
+
+    let start_total_sol_value = accounts.pool_state.total_sol_value()?;
+    let trading_protocol_fee_bps = accounts.pool_state.trading_protocol_fee_bps()?;
+
+    // protocol fees of each hop, denominated in the hop's dst LST
+    let mut hop_protocol_fees = Vec::with_capacity(pricing_cpis.len());
+    let mut hop_events = Vec::with_capacity(pricing_cpis.len());
+    let mut hop_amount_in = amount;
+    for (hop, pricing_cpi) in pricing_cpis.into_iter().enumerate() {
+        let src = &route[hop];
+        let dst = &route[hop + 1];
+
+        let in_sol_value = src.cpi.invoke_lst_to_sol(hop_amount_in)?.get_min();
+        if in_sol_value == 0 {
+            return Err(SControllerError::ZeroValue.into());
+        }
+        let out_sol_value = pricing_cpi.invoke_price_exact_in(PricingProgramIxArgs {
+            amount: hop_amount_in,
+            sol_value: in_sol_value,
+        })?;
+        let dst_lst_out = dst.cpi.invoke_sol_to_lst(out_sol_value)?.get_min();
+        if dst_lst_out == 0 {
+            return Err(SControllerError::ZeroValue.into());
+        }
+
+        let to_protocol_fees_lst_amount = calc_swap_protocol_fees(CalcSwapProtocolFeesArgs {
+            in_sol_value,
+            out_sol_value,
+            dst_lst_out,
+            trading_protocol_fee_bps,
+        })?;
+        let total_dst_lst_out = dst_lst_out
+            .checked_add(to_protocol_fees_lst_amount)
+            .ok_or(SControllerError::MathError)?;
+        if total_dst_lst_out > token_account_balance(dst.pool_reserves)? {
+            return Err(SControllerError::NotEnoughLiquidity.into());
+        }
+
+        hop_protocol_fees.push(to_protocol_fees_lst_amount);
+        hop_events.push(SControllerEvent::Swap {
+            src_lst_mint: *src.lst_mint.key,
+            dst_lst_mint: *dst.lst_mint.key,
+            src_lst_amount: hop_amount_in,
+            dst_lst_amount: dst_lst_out,
+            in_sol_value,
+            out_sol_value,
+            protocol_fee_amount: to_protocol_fees_lst_amount,
+        });
+        hop_amount_in = dst_lst_out;
+    }
+
+    // hop_amount_in is now the last hop's dst_lst_out
+    let final_amount_out = hop_amount_in;
+    if final_amount_out < min_amount_out {
+        return Err(SControllerError::SlippageToleranceExceeded.into());
+    }
+
+    let src = &route[0];
+    transfer_checked_decimal_agnostic_invoke(
+        TransferCheckedAccounts {
+            from: accounts.src_lst_acc,
+            to: src.pool_reserves,
+            token_program: src.lst_token_program,
+            authority: accounts.signer,
+            mint: src.lst_mint,
+        },
+        amount,
+    )?;
+    // Intermediate LSTs' dst_lst_out is immediately used as the next hop's input,
+    // so it never leaves the pool's reserves. Only the protocol fees are transferred out.
+    for (dst, to_protocol_fees_lst_amount) in route.iter().skip(1).zip(hop_protocol_fees) {
+        transfer_checked_decimal_agnostic_invoke_signed(
+            TransferCheckedAccounts {
+                from: dst.pool_reserves,
+                to: dst.protocol_fee_accumulator,
+                token_program: dst.lst_token_program,
+                authority: accounts.pool_state,
+                mint: dst.lst_mint,
+            },
+            to_protocol_fees_lst_amount,
+            &[&pool.pool_state_seeds()],
+        )?;
+    }
+    // route.len() >= 2 checked in verify
+    let dst = &route[route.len() - 1];
+    transfer_checked_decimal_agnostic_invoke_signed(
+        TransferCheckedAccounts {
+            from: dst.pool_reserves,
+            to: accounts.dst_lst_acc,
+            token_program: dst.lst_token_program,
+            authority: accounts.pool_state,
+            mint: dst.lst_mint,
+        },
+        final_amount_out,
+        &[&pool.pool_state_seeds()],
+    )?;
+
+    for lst in route.iter() {
+        sync_sol_value_unchecked(
+            route_lst_sync_accounts(accounts, lst),
+            lst.cpi,
+            lst.lst_index,
+        )?;
+    }
+
+    let end_total_sol_value = accounts.pool_state.total_sol_value()?;
+    if end_total_sol_value < start_total_sol_value {
+        return Err(SControllerError::PoolWouldLoseSolValue.into());
+    }
+    // only the route's src LST receives a net inflow from the user
+    verify_lst_within_cap(accounts.pool_state, accounts.lst_state_list, src.lst_index)?;
+
+    for event in hop_events.iter() {
+        emit_event(accounts.pool_state, event)?;
+    }
+
+    Ok(())
+}
+
+fn route_lst_sync_accounts<'a, 'info>(
+    accounts: SwapExactInRouteAccounts<'a, 'info>,
+    lst: &RouteLst<'a, 'info>,
+) -> SyncSolValueUncheckedAccounts<'a, 'info> {
+    SyncSolValueUncheckedAccounts {
+        pool_reserves: lst.pool_reserves,
+        pool_state: accounts.pool_state,
+        lst_state_list: accounts.lst_state_list,
+    }
+}
+
+fn verify_swap_exact_in_route<'a, 'info>(
+    accounts: &'a [AccountInfo<'info>],
+    SwapExactInRouteIxArgs {
+        lst_value_calc_accs,
+        pricing_accs,
+        lst_indexes,
+        min_amount_out,
+        amount,
+    }: SwapExactInRouteIxArgs,
+) -> Result<
+    (
+        SwapExactInRouteAccounts<'a, 'info>,
+        SwapExactInAmounts,
+        Vec<RouteLst<'a, 'info>>,
+        Vec<PricingProgramPriceSwapCpi<'a, 'info>>,
+        Pool,
+    ),
+    ProgramError,
+> {
+    if amount == 0 {
+        return Err(SControllerError::ZeroValue.into());
+    }
+
+    let n_lsts = lst_indexes.len();
+    if n_lsts < 2
+        || n_lsts > MAX_SWAP_ROUTE_LSTS
+        || lst_value_calc_accs.len() != n_lsts
+        || pricing_accs.len() != n_lsts - 1
+    {
+        return Err(SControllerError::InvalidSwapRoute.into());
+    }
+    let lst_indexes = lst_indexes
+        .into_iter()
+        .map(index_to_usize)
+        .collect::<Result<Vec<_>, _>>()?;
+    for (i, lst_index) in lst_indexes.iter().enumerate() {
+        if lst_indexes[..i].contains(lst_index) {
+            return Err(SControllerError::SwapSameLst.into());
+        }
+    }
+
+    let actual: SwapExactInRouteAccounts = load_accounts(accounts)?;
+    let pool = Pool::try_from_pool_state(actual.pool_state)?;
+
+    let free_args = SwapExactInRouteFreeArgs {
+        signer: *actual.signer.key,
+        src_lst_acc: *actual.src_lst_acc.key,
+        dst_lst_acc: *actual.dst_lst_acc.key,
+    };
+    let expected = free_args.resolve_for_pool(pool.pdas);
+
+    swap_exact_in_route_verify_account_keys(actual, expected)
+        .map_err(log_and_return_wrong_acc_err)?;
+    swap_exact_in_route_verify_account_privileges(actual)
+        .map_err(log_and_return_acc_privilege_err)?;
+
+    {
+        let pool_state_bytes = actual.pool_state.try_borrow_data()?;
+        let pool_state = try_pool_state(&pool_state_bytes)?;
+        verify_not_rebalancing_and_not_disabled(pool_state)?;
+
+        let lst_state_list_bytes = actual.lst_state_list.try_borrow_data()?;
+        let lst_state_list = try_lst_state_list(&lst_state_list_bytes)?;
+        // every LST except the last is used as an input to a hop
+        for lst_index in lst_indexes[..n_lsts - 1].iter() {
+            let lst_state = lst_state_list
+                .get(*lst_index)
+                .ok_or(SControllerError::InvalidLstIndex)?;
+            verify_lst_input_not_disabled(lst_state)?;
+        }
+    }
+
+    let accounts_suffix_slice = accounts
+        .get(SWAP_EXACT_IN_ROUTE_IX_ACCOUNTS_LEN..)
+        .ok_or(ProgramError::NotEnoughAccountKeys)?;
+    // no overflow, n_lsts <= MAX_SWAP_ROUTE_LSTS
+    let route_lst_accounts_end = n_lsts * SWAP_ROUTE_LST_ACCOUNTS_LEN;
+    let route_lst_accounts = accounts_suffix_slice
+        .get(..route_lst_accounts_end)
+        .ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let mut cpi_accounts_suffix_slice = accounts_suffix_slice
+        .get(route_lst_accounts_end..)
+        .ok_or(ProgramError::NotEnoughAccountKeys)?;
+
+    let mut route = Vec::with_capacity(n_lsts);
+    for ((lst_index, lst_accounts), lst_value_calc_accs) in lst_indexes
+        .into_iter()
+        .zip(route_lst_accounts.chunks_exact(SWAP_ROUTE_LST_ACCOUNTS_LEN))
+        .zip(lst_value_calc_accs)
+    {
+        // chunks_exact() guarantees SWAP_ROUTE_LST_ACCOUNTS_LEN accounts
+        let lst_mint = &lst_accounts[0];
+        let lst_token_program = &lst_accounts[1];
+        let pool_reserves = &lst_accounts[2];
+        let protocol_fee_accumulator = &lst_accounts[3];
+        let expected = SwapRouteLstFreeArgs {
+            lst_index,
+            lst_mint,
+            lst_state_list: actual.lst_state_list,
+        }
+        .resolve_with_pdas(pool.pdas.into())?;
+        verify_route_lst_keys(
+            [
+                lst_mint,
+                lst_token_program,
+                pool_reserves,
+                protocol_fee_accumulator,
+            ],
+            expected,
+        )?;
+
+        let calc_accs: usize = lst_value_calc_accs.into();
+        let cpi = verify_lst_sol_val_calc_cpi(
+            VerifyLstSolValCalcCpiAccounts {
+                lst_state_list: actual.lst_state_list,
+                lst_mint,
+            },
+            cpi_accounts_suffix_slice
+                .get(..calc_accs)
+                .ok_or(ProgramError::NotEnoughAccountKeys)?,
+            lst_index,
+        )?;
+        cpi_accounts_suffix_slice = cpi_accounts_suffix_slice
+            .get(calc_accs..)
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+
+        route.push(RouteLst {
+            lst_index,
+            lst_mint,
+            lst_token_program,
+            pool_reserves,
+            protocol_fee_accumulator,
+            cpi,
+        });
+    }
+
+    let mut pricing_cpis = Vec::with_capacity(n_lsts - 1);
+    for (hop, hop_pricing_accs) in pricing_accs.into_iter().enumerate() {
+        let hop_pricing_accs: usize = hop_pricing_accs.into();
+        let pricing_cpi = verify_pricing_swap_cpi(
+            VerifyPricingSwapCpiAccounts {
+                pool_state: actual.pool_state,
+                src_dst_lst_mints: SrcDstLstMintAccountInfos {
+                    src_lst_mint: route[hop].lst_mint,
+                    dst_lst_mint: route[hop + 1].lst_mint,
+                },
+            },
+            cpi_accounts_suffix_slice
+                .get(..hop_pricing_accs)
+                .ok_or(ProgramError::NotEnoughAccountKeys)?,
+        )?;
+        cpi_accounts_suffix_slice = cpi_accounts_suffix_slice
+            .get(hop_pricing_accs..)
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        pricing_cpis.push(pricing_cpi);
+    }
+
+    Ok((
+        actual,
+        SwapExactInAmounts {
+            min_amount_out,
+            amount,
+        },
+        route,
+        pricing_cpis,
+        pool,
+    ))
+}
+
+fn verify_route_lst_keys(
+    actual: [&AccountInfo; SWAP_ROUTE_LST_ACCOUNTS_LEN],
+    SwapRouteLstKeys {
+        lst_mint,
+        lst_token_program,
+        pool_reserves,
+        protocol_fee_accumulator,
+    }: SwapRouteLstKeys,
+) -> Result<(), ProgramError> {
+    let expected: [Pubkey; SWAP_ROUTE_LST_ACCOUNTS_LEN] = [
+        lst_mint,
+        lst_token_program,
+        pool_reserves,
+        protocol_fee_accumulator,
+    ];
+    for (actual, expected) in actual.iter().zip(expected) {
+        if *actual.key != expected {
+            return Err(log_and_return_wrong_acc_err((*actual.key, expected)));
+        }
+    }
+    Ok(())
+}
@@ -3,10 +3,9 @@ use s_controller_interface::{
     SwapExactOutAccounts, SwapExactOutIxArgs, SWAP_EXACT_OUT_IX_ACCOUNTS_LEN,
 };
 use s_controller_lib::{
-    calc_swap_protocol_fees, index_to_usize,
-    program::{POOL_STATE_BUMP, POOL_STATE_SEED},
-    try_lst_state_list, try_pool_state, CalcSwapProtocolFeesArgs, PoolStateAccount,
-    SrcDstLstIndexes, SrcDstLstValueCalcAccs, SwapExactOutAmounts, SwapFreeArgs,
+    calc_swap_protocol_fees, index_to_usize, try_lst_state_list, try_pool_state,
+    CalcSwapProtocolFeesArgs, Pool, PoolStateAccount, SControllerEvent, SrcDstLstIndexes,
+    SrcDstLstValueCalcAccs, SwapExactOutAmounts, SwapFreeArgs,
 };
 use sanctum_misc_utils::{
     load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
@@ -22,9 +21,11 @@ use solana_program::{
 use crate::{
     account_traits::{DstLstPoolReservesOf, SrcLstPoolReservesOf},
     cpi::{PricingProgramIxArgs, PricingProgramPriceSwapCpi, SrcDstLstSolValueCalculatorCpis},
+    events::emit_event,
     verify::{
-        verify_lst_input_not_disabled, verify_not_rebalancing_and_not_disabled, verify_swap_cpis,
-        verify_swap_not_same_lst, VerifySwapCpiAccounts,
+        verify_lst_input_not_disabled, verify_lst_within_cap,
+        verify_not_rebalancing_and_not_disabled, verify_swap_cpis, verify_swap_not_same_lst,
+        VerifySwapCpiAccounts,
     },
 };
 
@@ -46,6 +47,7 @@ pub fn process_swap_exact_out(accounts: &[AccountInfo], args: SwapExactOutIxArgs
             dst_lst: dst_lst_cpi,
         },
         pricing_cpi,
+        pool,
     ) = verify_swap_exact_out(accounts, args)?;
 
     let src_sync_sol_value_accounts =
@@ -108,7 +110,7 @@ pub fn process_swap_exact_out(accounts: &[AccountInfo], args: SwapExactOutIxArgs
             mint: accounts.dst_lst_mint,
         },
         to_protocol_fees_lst_amount,
-        &[&[POOL_STATE_SEED, &[POOL_STATE_BUMP]]],
+        &[&pool.pool_state_seeds()],
     )?;
     transfer_checked_decimal_agnostic_invoke_signed(
         TransferCheckedAccounts {
@@ -119,7 +121,7 @@ pub fn process_swap_exact_out(accounts: &[AccountInfo], args: SwapExactOutIxArgs
             mint: accounts.dst_lst_mint,
         },
         amount,
-        &[&[POOL_STATE_SEED, &[POOL_STATE_BUMP]]],
+        &[&pool.pool_state_seeds()],
     )?;
 
     sync_sol_value_unchecked(src_sync_sol_value_accounts, src_lst_cpi, src_lst_index)?;
@@ -129,8 +131,20 @@ pub fn process_swap_exact_out(accounts: &[AccountInfo], args: SwapExactOutIxArgs
     if end_total_sol_value < start_total_sol_value {
         return Err(SControllerError::PoolWouldLoseSolValue.into());
     }
-
-    Ok(())
+    verify_lst_within_cap(accounts.pool_state, accounts.lst_state_list, src_lst_index)?;
+
+    emit_event(
+        accounts.pool_state,
+        &SControllerEvent::Swap {
+            src_lst_mint: *accounts.src_lst_mint.key,
+            dst_lst_mint: *accounts.dst_lst_mint.key,
+            src_lst_amount: src_lst_in,
+            dst_lst_amount: amount,
+            in_sol_value,
+            out_sol_value,
+            protocol_fee_amount: to_protocol_fees_lst_amount,
+        },
+    )
 }
 
 fn verify_swap_exact_out<'a, 'info>(
@@ -150,6 +164,7 @@ fn verify_swap_exact_out<'a, 'info>(
         SrcDstLstIndexes,
         SrcDstLstSolValueCalculatorCpis<'a, 'info>,
         PricingProgramPriceSwapCpi<'a, 'info>,
+        Pool,
     ),
     ProgramError,
 > {
@@ -161,6 +176,7 @@ fn verify_swap_exact_out<'a, 'info>(
     let dst_lst_index = index_to_usize(dst_lst_index)?;
 
     let actual: SwapExactOutAccounts = load_accounts(accounts)?;
+    let pool = Pool::try_from_pool_state(actual.pool_state)?;
 
     let free_args = SwapFreeArgs {
         signer: *actual.signer.key,
@@ -172,7 +188,7 @@ fn verify_swap_exact_out<'a, 'info>(
         dst_lst_mint: actual.dst_lst_mint,
         lst_state_list: actual.lst_state_list,
     };
-    let expected = free_args.resolve_exact_out()?;
+    let expected = free_args.resolve_exact_out_for_pool(pool.pdas)?;
 
     swap_exact_out_verify_account_keys(actual, expected).map_err(log_and_return_wrong_acc_err)?;
     swap_exact_out_verify_account_privileges(actual).map_err(log_and_return_acc_privilege_err)?;
@@ -216,5 +232,6 @@ fn verify_swap_exact_out<'a, 'info>(
         src_dst_lst_indexes,
         src_dst_cpis,
         pricing_cpi,
+        pool,
     ))
 }
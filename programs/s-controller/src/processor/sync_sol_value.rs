@@ -3,8 +3,8 @@ use s_controller_interface::{
     SyncSolValueAccounts, SyncSolValueIxArgs, SYNC_SOL_VALUE_IX_ACCOUNTS_LEN,
 };
 use s_controller_lib::{
-    index_to_usize, sync_sol_value_with_retval, try_lst_state_list_mut, try_pool_state,
-    try_pool_state_mut, SyncSolValueFreeArgs,
+    index_to_usize, sync_sol_value_with_retval, try_lst_state_list, try_lst_state_list_mut,
+    try_pool_state, try_pool_state_mut, Pool, SControllerEvent, SyncSolValueFreeArgs,
 };
 use sanctum_misc_utils::{
     load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
@@ -19,6 +19,7 @@ use crate::{
         GetLstStateListAccountInfo, GetPoolReservesAccountInfo, GetPoolStateAccountInfo,
     },
     cpi::SolValueCalculatorCpi,
+    events::emit_event,
     verify::{
         verify_lst_sol_val_calc_cpi, verify_not_rebalancing_and_not_disabled,
         VerifyLstSolValCalcCpiAccounts,
@@ -28,13 +29,29 @@ use crate::{
 pub fn process_sync_sol_value(accounts: &[AccountInfo], args: SyncSolValueIxArgs) -> ProgramResult {
     let (accounts, cpi) = verify_sync_sol_value(accounts, &args)?;
     let lst_index: usize = args.lst_index.try_into().unwrap(); // lst_index checked in verify
+    let old_sol_value = lst_sol_value(accounts.lst_state_list, lst_index)?;
     sync_sol_value_unchecked(
         SyncSolValueUncheckedAccounts::from(accounts),
         cpi,
         lst_index,
+    )?;
+    let new_sol_value = lst_sol_value(accounts.lst_state_list, lst_index)?;
+    emit_event(
+        accounts.pool_state,
+        &SControllerEvent::SyncSolValue {
+            lst_mint: *accounts.lst_mint.key,
+            old_sol_value,
+            new_sol_value,
+        },
     )
 }
 
+fn lst_sol_value(lst_state_list: &AccountInfo, lst_index: usize) -> Result<u64, ProgramError> {
+    let lst_state_list_bytes = lst_state_list.try_borrow_data()?;
+    let lst_state_list = try_lst_state_list(&lst_state_list_bytes)?;
+    Ok(lst_state_list[lst_index].sol_value)
+}
+
 #[derive(Clone, Copy, Debug)]
 pub struct SyncSolValueUncheckedAccounts<'me, 'info> {
     pub pool_reserves: &'me AccountInfo<'info>,
@@ -117,7 +134,8 @@ fn verify_sync_sol_value_base_accounts<'a, 'info>(
         lst_state_list: actual.lst_state_list,
         lst_mint: actual.lst_mint,
     };
-    let expected = free_args.resolve()?;
+    let pool = Pool::try_from_pool_state(actual.pool_state)?;
+    let expected = free_args.resolve_for_pool(pool.pdas)?;
 
     sync_sol_value_verify_account_keys(actual, expected).map_err(log_and_return_wrong_acc_err)?;
     sync_sol_value_verify_account_privileges(actual).map_err(log_and_return_acc_privilege_err)?;
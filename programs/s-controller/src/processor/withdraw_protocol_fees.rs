@@ -2,10 +2,7 @@ use s_controller_interface::{
     withdraw_protocol_fees_verify_account_keys, withdraw_protocol_fees_verify_account_privileges,
     SControllerError, WithdrawProtocolFeesAccounts, WithdrawProtocolFeesIxArgs,
 };
-use s_controller_lib::{
-    program::{PROTOCOL_FEE_BUMP, PROTOCOL_FEE_SEED},
-    try_pool_state, WithdrawProtocolFeesFreeArgs,
-};
+use s_controller_lib::{try_pool_state, Pool, SControllerEvent, WithdrawProtocolFeesFreeArgs};
 use sanctum_misc_utils::{
     load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
 };
@@ -16,13 +13,13 @@ use solana_program::{
     account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
 };
 
-use crate::verify::verify_not_rebalancing_and_not_disabled;
+use crate::{events::emit_event, verify::verify_not_rebalancing_and_not_disabled};
 
 pub fn process_withdraw_protocol_fees(
     accounts: &[AccountInfo],
     args: WithdrawProtocolFeesIxArgs,
 ) -> ProgramResult {
-    let accounts = verify_withdraw_protocol_fees(accounts)?;
+    let (accounts, pool) = verify_withdraw_protocol_fees(accounts)?;
 
     if args.amount > token_account_balance(accounts.protocol_fee_accumulator)? {
         return Err(SControllerError::NotEnoughFees.into());
@@ -37,22 +34,30 @@ pub fn process_withdraw_protocol_fees(
             mint: accounts.lst_mint,
         },
         args.amount,
-        &[&[PROTOCOL_FEE_SEED, &[PROTOCOL_FEE_BUMP]]],
+        &[&pool.protocol_fee_seeds()],
     )?;
 
-    Ok(())
+    emit_event(
+        accounts.pool_state,
+        &SControllerEvent::WithdrawProtocolFees {
+            lst_mint: *accounts.lst_mint.key,
+            amount: args.amount,
+            withdraw_to: *accounts.withdraw_to.key,
+        },
+    )
 }
 
 fn verify_withdraw_protocol_fees<'a, 'info>(
     accounts: &'a [AccountInfo<'info>],
-) -> Result<WithdrawProtocolFeesAccounts<'a, 'info>, ProgramError> {
+) -> Result<(WithdrawProtocolFeesAccounts<'a, 'info>, Pool), ProgramError> {
     let actual: WithdrawProtocolFeesAccounts = load_accounts(accounts)?;
+    let pool = Pool::try_from_pool_state(actual.pool_state)?;
 
     let free_args = WithdrawProtocolFeesFreeArgs {
         pool_state: actual.pool_state,
         withdraw_to: actual.withdraw_to,
     };
-    let expected = free_args.resolve()?;
+    let expected = free_args.resolve_for_pool(pool.pdas)?;
 
     withdraw_protocol_fees_verify_account_keys(actual, expected)
         .map_err(log_and_return_wrong_acc_err)?;
@@ -64,5 +69,5 @@ fn verify_withdraw_protocol_fees<'a, 'info>(
 
     verify_not_rebalancing_and_not_disabled(pool_state)?;
 
-    Ok(actual)
+    Ok((actual, pool))
 }
@@ -0,0 +1,167 @@
+use s_controller_interface::{
+    withdraw_protocol_fees_multi_verify_account_keys,
+    withdraw_protocol_fees_multi_verify_account_privileges, SControllerError,
+    WithdrawProtocolFeesMultiAccounts, WithdrawProtocolFeesMultiIxArgs,
+    WITHDRAW_PROTOCOL_FEES_MULTI_IX_ACCOUNTS_LEN,
+};
+use s_controller_lib::{
+    try_pool_state, Pool, SControllerEvent, WithdrawProtocolFeesMultiFreeArgs,
+    WithdrawProtocolFeesMultiLstFreeArgs, WithdrawProtocolFeesMultiLstKeys,
+    WITHDRAW_PROTOCOL_FEES_MULTI_LST_ACCOUNTS_LEN,
+};
+use sanctum_misc_utils::{
+    load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
+};
+use sanctum_token_lib::{
+    token_account_balance, transfer_checked_decimal_agnostic_invoke_signed, TransferCheckedAccounts,
+};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::{events::emit_event, verify::verify_not_rebalancing_and_not_disabled};
+
+/// A single LST of a WithdrawProtocolFeesMulti instruction
+pub struct WithdrawProtocolFeesMultiLst<'a, 'info> {
+    pub lst_mint: &'a AccountInfo<'info>,
+    pub token_program: &'a AccountInfo<'info>,
+    pub protocol_fee_accumulator: &'a AccountInfo<'info>,
+    pub withdraw_to: &'a AccountInfo<'info>,
+}
+
+pub fn process_withdraw_protocol_fees_multi(
+    accounts: &[AccountInfo],
+    args: WithdrawProtocolFeesMultiIxArgs,
+) -> ProgramResult {
+    let (accounts, lsts, pool) = verify_withdraw_protocol_fees_multi(accounts, &args)?;
+
+    for (lst, amount) in lsts.iter().zip(args.amounts) {
+        // balance read right before each transfer
+        // so that duplicate LSTs cannot overdraw the accumulator
+        if amount > token_account_balance(lst.protocol_fee_accumulator)? {
+            return Err(SControllerError::NotEnoughFees.into());
+        }
+
+        transfer_checked_decimal_agnostic_invoke_signed(
+            TransferCheckedAccounts {
+                from: lst.protocol_fee_accumulator,
+                to: lst.withdraw_to,
+                token_program: lst.token_program,
+                authority: accounts.protocol_fee_accumulator_auth,
+                mint: lst.lst_mint,
+            },
+            amount,
+            &[&pool.protocol_fee_seeds()],
+        )?;
+
+        emit_event(
+            accounts.pool_state,
+            &SControllerEvent::WithdrawProtocolFees {
+                lst_mint: *lst.lst_mint.key,
+                amount,
+                withdraw_to: *lst.withdraw_to.key,
+            },
+        )?;
+    }
+
+    Ok(())
+}
+
+fn verify_withdraw_protocol_fees_multi<'a, 'info>(
+    accounts: &'a [AccountInfo<'info>],
+    WithdrawProtocolFeesMultiIxArgs { amounts }: &WithdrawProtocolFeesMultiIxArgs,
+) -> Result<
+    (
+        WithdrawProtocolFeesMultiAccounts<'a, 'info>,
+        Vec<WithdrawProtocolFeesMultiLst<'a, 'info>>,
+        Pool,
+    ),
+    ProgramError,
+> {
+    if amounts.is_empty() {
+        return Err(SControllerError::InvalidProtocolFeeWithdrawals.into());
+    }
+
+    let actual: WithdrawProtocolFeesMultiAccounts = load_accounts(accounts)?;
+    let pool = Pool::try_from_pool_state(actual.pool_state)?;
+
+    let free_args = WithdrawProtocolFeesMultiFreeArgs {
+        pool_state: actual.pool_state,
+    };
+    let expected = free_args.resolve_for_pool(pool.pdas)?;
+
+    withdraw_protocol_fees_multi_verify_account_keys(actual, expected)
+        .map_err(log_and_return_wrong_acc_err)?;
+    withdraw_protocol_fees_multi_verify_account_privileges(actual)
+        .map_err(log_and_return_acc_privilege_err)?;
+
+    {
+        let pool_state_bytes = actual.pool_state.try_borrow_data()?;
+        let pool_state = try_pool_state(&pool_state_bytes)?;
+        verify_not_rebalancing_and_not_disabled(pool_state)?;
+    }
+
+    let lst_accounts_len = amounts
+        .len()
+        .checked_mul(WITHDRAW_PROTOCOL_FEES_MULTI_LST_ACCOUNTS_LEN)
+        .ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let lst_accounts = accounts
+        .get(WITHDRAW_PROTOCOL_FEES_MULTI_IX_ACCOUNTS_LEN..)
+        .and_then(|suffix| suffix.get(..lst_accounts_len))
+        .ok_or(ProgramError::NotEnoughAccountKeys)?;
+
+    let mut lsts = Vec::with_capacity(amounts.len());
+    for lst_accounts in lst_accounts.chunks_exact(WITHDRAW_PROTOCOL_FEES_MULTI_LST_ACCOUNTS_LEN) {
+        // chunks_exact() guarantees WITHDRAW_PROTOCOL_FEES_MULTI_LST_ACCOUNTS_LEN accounts
+        let lst_mint = &lst_accounts[0];
+        let token_program = &lst_accounts[1];
+        let protocol_fee_accumulator = &lst_accounts[2];
+        let withdraw_to = &lst_accounts[3];
+        let expected = WithdrawProtocolFeesMultiLstFreeArgs {
+            lst_mint,
+            withdraw_to: *withdraw_to.key,
+        }
+        .resolve_for_pool(pool.pdas);
+        verify_withdraw_protocol_fees_multi_lst_keys(
+            [
+                lst_mint,
+                token_program,
+                protocol_fee_accumulator,
+                withdraw_to,
+            ],
+            expected,
+        )?;
+        lsts.push(WithdrawProtocolFeesMultiLst {
+            lst_mint,
+            token_program,
+            protocol_fee_accumulator,
+            withdraw_to,
+        });
+    }
+
+    Ok((actual, lsts, pool))
+}
+
+fn verify_withdraw_protocol_fees_multi_lst_keys(
+    actual: [&AccountInfo; WITHDRAW_PROTOCOL_FEES_MULTI_LST_ACCOUNTS_LEN],
+    WithdrawProtocolFeesMultiLstKeys {
+        lst_mint,
+        token_program,
+        protocol_fee_accumulator,
+        withdraw_to,
+    }: WithdrawProtocolFeesMultiLstKeys,
+) -> Result<(), ProgramError> {
+    let expected: [Pubkey; WITHDRAW_PROTOCOL_FEES_MULTI_LST_ACCOUNTS_LEN] = [
+        lst_mint,
+        token_program,
+        protocol_fee_accumulator,
+        withdraw_to,
+    ];
+    for (actual, expected) in actual.iter().zip(expected) {
+        if *actual.key != expected {
+            return Err(log_and_return_wrong_acc_err((*actual.key, expected)));
+        }
+    }
+    Ok(())
+}
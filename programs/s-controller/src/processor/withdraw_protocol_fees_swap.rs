@@ -0,0 +1,214 @@
+use s_controller_interface::{
+    withdraw_protocol_fees_swap_verify_account_keys,
+    withdraw_protocol_fees_swap_verify_account_privileges, SControllerError,
+    WithdrawProtocolFeesSwapAccounts, WithdrawProtocolFeesSwapIxArgs,
+    WITHDRAW_PROTOCOL_FEES_SWAP_IX_ACCOUNTS_LEN,
+};
+use s_controller_lib::{
+    index_to_usize, try_lst_state_list, try_pool_state, Pool, PoolStateAccount, SControllerEvent,
+    SrcDstLstIndexes, SwapExactInAmounts, WithdrawProtocolFeesSwapFreeArgs,
+};
+use sanctum_misc_utils::{
+    load_accounts, log_and_return_acc_privilege_err, log_and_return_wrong_acc_err,
+};
+use sanctum_token_lib::{
+    token_account_balance, transfer_checked_decimal_agnostic_invoke_signed, TransferCheckedAccounts,
+};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+};
+
+use crate::{
+    account_traits::{DstLstPoolReservesOf, SrcLstPoolReservesOf},
+    cpi::SrcDstLstSolValueCalculatorCpis,
+    events::emit_event,
+    verify::{
+        verify_lst_input_not_disabled, verify_lst_within_cap,
+        verify_not_rebalancing_and_not_disabled, verify_src_dst_lst_sol_val_calc_cpis,
+        verify_swap_not_same_lst, VerifySrcDstLstSolValCalcCpiAccounts,
+    },
+};
+
+use super::{sync_sol_value_unchecked, SyncSolValueUncheckedAccounts};
+
+/// Withdraws `amount` src LST protocol fees into the pool
+/// in exchange for dst LST of equal SOL value, with no trading fees charged
+pub fn process_withdraw_protocol_fees_swap(
+    accounts: &[AccountInfo],
+    args: WithdrawProtocolFeesSwapIxArgs,
+) -> ProgramResult {
+    let (
+        accounts,
+        SwapExactInAmounts {
+            min_amount_out,
+            amount,
+        },
+        SrcDstLstIndexes {
+            src_lst_index,
+            dst_lst_index,
+        },
+        SrcDstLstSolValueCalculatorCpis {
+            src_lst: src_lst_cpi,
+            dst_lst: dst_lst_cpi,
+        },
+        pool,
+    ) = verify_withdraw_protocol_fees_swap(accounts, args)?;
+
+    let src_sync_sol_value_accounts =
+        SyncSolValueUncheckedAccounts::from(SrcLstPoolReservesOf(accounts));
+    let dst_sync_sol_value_accounts =
+        SyncSolValueUncheckedAccounts::from(DstLstPoolReservesOf(accounts));
+    sync_sol_value_unchecked(src_sync_sol_value_accounts, src_lst_cpi, src_lst_index)?;
+    sync_sol_value_unchecked(dst_sync_sol_value_accounts, dst_lst_cpi, dst_lst_index)?;
+
+    let start_total_sol_value = accounts.pool_state.total_sol_value()?;
+
+    let sol_value = src_lst_cpi.invoke_lst_to_sol(amount)?.get_min();
+    if sol_value == 0 {
+        return Err(SControllerError::ZeroValue.into());
+    }
+    let dst_lst_out = dst_lst_cpi.invoke_sol_to_lst(sol_value)?.get_min();
+
+    if dst_lst_out < min_amount_out {
+        return Err(SControllerError::SlippageToleranceExceeded.into());
+    }
+    if dst_lst_out == 0 {
+        return Err(SControllerError::ZeroValue.into());
+    }
+    if dst_lst_out > token_account_balance(accounts.dst_pool_reserves)? {
+        return Err(SControllerError::NotEnoughLiquidity.into());
+    }
+
+    transfer_checked_decimal_agnostic_invoke_signed(
+        TransferCheckedAccounts {
+            from: accounts.protocol_fee_accumulator,
+            to: accounts.src_pool_reserves,
+            token_program: accounts.src_lst_token_program,
+            authority: accounts.protocol_fee_accumulator_auth,
+            mint: accounts.src_lst_mint,
+        },
+        amount,
+        &[&pool.protocol_fee_seeds()],
+    )?;
+    transfer_checked_decimal_agnostic_invoke_signed(
+        TransferCheckedAccounts {
+            from: accounts.dst_pool_reserves,
+            to: accounts.withdraw_to,
+            token_program: accounts.dst_lst_token_program,
+            authority: accounts.pool_state,
+            mint: accounts.dst_lst_mint,
+        },
+        dst_lst_out,
+        &[&pool.pool_state_seeds()],
+    )?;
+
+    sync_sol_value_unchecked(src_sync_sol_value_accounts, src_lst_cpi, src_lst_index)?;
+    sync_sol_value_unchecked(dst_sync_sol_value_accounts, dst_lst_cpi, dst_lst_index)?;
+
+    let end_total_sol_value = accounts.pool_state.total_sol_value()?;
+    if end_total_sol_value < start_total_sol_value {
+        return Err(SControllerError::PoolWouldLoseSolValue.into());
+    }
+    verify_lst_within_cap(accounts.pool_state, accounts.lst_state_list, src_lst_index)?;
+
+    emit_event(
+        accounts.pool_state,
+        &SControllerEvent::WithdrawProtocolFeesSwap {
+            src_lst_mint: *accounts.src_lst_mint.key,
+            dst_lst_mint: *accounts.dst_lst_mint.key,
+            src_lst_amount: amount,
+            dst_lst_amount: dst_lst_out,
+            sol_value,
+            withdraw_to: *accounts.withdraw_to.key,
+        },
+    )
+}
+
+fn verify_withdraw_protocol_fees_swap<'a, 'info>(
+    accounts: &'a [AccountInfo<'info>],
+    WithdrawProtocolFeesSwapIxArgs {
+        src_lst_calc_accs,
+        src_lst_index,
+        dst_lst_index,
+        min_amount_out,
+        amount,
+    }: WithdrawProtocolFeesSwapIxArgs,
+) -> Result<
+    (
+        WithdrawProtocolFeesSwapAccounts<'a, 'info>,
+        SwapExactInAmounts,
+        SrcDstLstIndexes,
+        SrcDstLstSolValueCalculatorCpis<'a, 'info>,
+        Pool,
+    ),
+    ProgramError,
+> {
+    if amount == 0 {
+        return Err(SControllerError::ZeroValue.into());
+    }
+
+    let src_lst_index = index_to_usize(src_lst_index)?;
+    let dst_lst_index = index_to_usize(dst_lst_index)?;
+
+    let actual: WithdrawProtocolFeesSwapAccounts = load_accounts(accounts)?;
+    let pool = Pool::try_from_pool_state(actual.pool_state)?;
+
+    let free_args = WithdrawProtocolFeesSwapFreeArgs {
+        withdraw_to: *actual.withdraw_to.key,
+        src_lst_index,
+        dst_lst_index,
+        lst_state_list: actual.lst_state_list,
+        pool_state: actual.pool_state,
+        src_lst_mint: actual.src_lst_mint,
+        dst_lst_mint: actual.dst_lst_mint,
+    };
+    let expected = free_args.resolve_for_pool(pool.pdas)?;
+
+    withdraw_protocol_fees_swap_verify_account_keys(actual, expected)
+        .map_err(log_and_return_wrong_acc_err)?;
+    withdraw_protocol_fees_swap_verify_account_privileges(actual)
+        .map_err(log_and_return_acc_privilege_err)?;
+
+    verify_swap_not_same_lst(actual.src_lst_mint, actual.dst_lst_mint)?;
+
+    if amount > token_account_balance(actual.protocol_fee_accumulator)? {
+        return Err(SControllerError::NotEnoughFees.into());
+    }
+
+    {
+        let pool_state_bytes = actual.pool_state.try_borrow_data()?;
+        let pool_state = try_pool_state(&pool_state_bytes)?;
+        verify_not_rebalancing_and_not_disabled(pool_state)?;
+
+        let lst_state_list_bytes = actual.lst_state_list.try_borrow_data()?;
+        let lst_state_list = try_lst_state_list(&lst_state_list_bytes)?;
+        let src_lst_state = lst_state_list[src_lst_index]; // src_lst_index checked above
+        verify_lst_input_not_disabled(&src_lst_state)?;
+    }
+
+    let src_dst_lst_indexes = SrcDstLstIndexes {
+        src_lst_index,
+        dst_lst_index,
+    };
+
+    let accounts_suffix_slice = accounts
+        .get(WITHDRAW_PROTOCOL_FEES_SWAP_IX_ACCOUNTS_LEN..)
+        .ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let src_dst_lst_cpis = verify_src_dst_lst_sol_val_calc_cpis(
+        VerifySrcDstLstSolValCalcCpiAccounts::from(actual),
+        accounts_suffix_slice,
+        src_lst_calc_accs,
+        src_dst_lst_indexes,
+    )?;
+
+    Ok((
+        actual,
+        SwapExactInAmounts {
+            min_amount_out,
+            amount,
+        },
+        src_dst_lst_indexes,
+        src_dst_lst_cpis,
+        pool,
+    ))
+}
@@ -2,10 +2,13 @@
 
 use s_controller_interface::{LstState, PoolState, SControllerError};
 use s_controller_lib::{
-    try_disable_pool_authority_list, try_find_element_in_list, SrcDstLstIndexes,
-    SrcDstLstValueCalcAccs, U8Bool,
+    is_lst_over_cap, try_disable_pool_authority_list, try_find_element_in_list, try_lst_state_list,
+    try_pool_state, SrcDstLstIndexes, SrcDstLstValueCalcAccs, U8Bool,
 };
-use solana_program::{account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey};
+use solana_program::{
+    account_info::AccountInfo, program_error::ProgramError, program_pack::Pack, pubkey::Pubkey,
+};
+use spl_token::{native_mint, state::Mint};
 
 use crate::{
     account_traits::{
@@ -30,6 +33,15 @@ pub const fn verify_not_rebalancing_and_not_disabled(
     Ok(())
 }
 
+/// Sensitive admin changes must go through the pending change list
+/// while the pool is in timelock mode
+pub const fn verify_not_timelocked(pool_state: &PoolState) -> Result<(), SControllerError> {
+    if pool_state.timelock_delay_hours != 0 {
+        return Err(SControllerError::PoolTimelocked);
+    }
+    Ok(())
+}
+
 pub const fn verify_lst_input_not_disabled(lst_state: &LstState) -> Result<(), SControllerError> {
     if U8Bool(lst_state.is_input_disabled).is_true() {
         return Err(SControllerError::LstInputDisabled);
@@ -37,6 +49,26 @@ pub const fn verify_lst_input_not_disabled(lst_state: &LstState) -> Result<(), S
     Ok(())
 }
 
+/// Verifies that the LST at `lst_index` does not exceed its max share of the pool's total SOL value.
+/// Should be called after the LST's SOL value has been synced following the transfer into its reserves.
+pub fn verify_lst_within_cap(
+    pool_state_acc: &AccountInfo,
+    lst_state_list_acc: &AccountInfo,
+    lst_index: usize,
+) -> Result<(), ProgramError> {
+    let pool_state_bytes = pool_state_acc.try_borrow_data()?;
+    let pool_state = try_pool_state(&pool_state_bytes)?;
+    let lst_state_list_bytes = lst_state_list_acc.try_borrow_data()?;
+    let lst_state_list = try_lst_state_list(&lst_state_list_bytes)?;
+    let lst_state = lst_state_list
+        .get(lst_index)
+        .ok_or(SControllerError::InvalidLstIndex)?;
+    if is_lst_over_cap(pool_state, lst_state) {
+        return Err(SControllerError::LstCapExceeded.into());
+    }
+    Ok(())
+}
+
 pub fn verify_admin_or_disable_pool_authority(
     signer: Pubkey,
     pool_state: &PoolState,
@@ -52,6 +84,21 @@ pub fn verify_admin_or_disable_pool_authority(
     Ok(())
 }
 
+/// Verifies that a new LP token mint is a Tokenkeg mint with 0 supply and 9 decimals
+pub fn verify_lp_token_mint(lp_token_mint: &AccountInfo<'_>) -> Result<(), ProgramError> {
+    if *lp_token_mint.owner != spl_token::ID {
+        return Err(SControllerError::IncorrectLpMintInitialization.into());
+    }
+    let mint = Mint::unpack(&lp_token_mint.try_borrow_data()?)?;
+    if mint.supply != 0 {
+        return Err(SControllerError::IncorrectLpMintInitialization.into());
+    }
+    if mint.decimals != native_mint::DECIMALS {
+        return Err(SControllerError::IncorrectLpMintInitialization.into());
+    }
+    Ok(())
+}
+
 #[derive(Clone, Copy, Debug)]
 pub struct VerifyLstSolValCalcCpiAccounts<'me, 'info> {
     pub lst_state_list: &'me AccountInfo<'info>,
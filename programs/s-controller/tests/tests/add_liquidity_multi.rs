@@ -0,0 +1,266 @@
+use marinade_calculator_lib::marinade_sol_val_calc_account_metas;
+use marinade_keys::msol;
+use s_controller_interface::SControllerError;
+use s_controller_lib::{
+    add_liquidity_multi_ix_by_mints_full, add_liquidity_multi_ix_full,
+    program::{LST_STATE_LIST_ID, POOL_STATE_ID},
+    try_find_lst_mint_on_list, try_lst_state_list, try_pool_state,
+    AddLiquidityMultiByMintsFreeArgs, AddLiquidityMultiFreeArgs, AddLiquidityMultiIxAmts,
+    AddLiquidityMultiIxFullArgs, LstSolValueCalcAccounts, MultiLiquidityLst,
+    MultiLiquidityLstFreeArgs,
+};
+use s_controller_test_utils::{
+    jito_marinade_no_fee_program_test, GenAndAddTokenAccountProgramTest,
+    JitoMarinadeProgramTestArgs, LstStateListBanksClient, PoolStateBanksClient,
+};
+use sanctum_solana_test_utils::{
+    assert_custom_err, token::MockTokenAccountArgs, ExtendedBanksClient,
+};
+use sanctum_token_lib::{token_account_balance, MintWithTokenProgram};
+use solana_program::{clock::Clock, instruction::AccountMeta, pubkey::Pubkey};
+use solana_program_test::ProgramTestContext;
+use solana_readonly_account::sdk::KeyedAccount;
+use solana_sdk::{signature::Keypair, signer::Signer, transaction::Transaction};
+use spl_calculator_lib::SplLstSolCommonFreeArgsConst;
+use test_utils::{jito_stake_pool, jitosol, JITO_STAKE_POOL_LAST_UPDATE_EPOCH};
+
+use crate::common::SControllerProgramTest;
+
+const JITOSOL_TO_ADD: u64 = 1_000_000_000;
+const MSOL_TO_ADD: u64 = 1_000_000_000;
+
+#[tokio::test]
+async fn basic_add_liquidity_multi_no_fee() {
+    let liquidity_provider = Keypair::new();
+    let lp_token_mint = Pubkey::new_unique();
+
+    let mut program_test = jito_marinade_no_fee_program_test(JitoMarinadeProgramTestArgs {
+        jitosol_sol_value: 0,
+        msol_sol_value: 0,
+        jitosol_reserves: 0,
+        msol_reserves: 0,
+        jitosol_protocol_fee_accumulator: 0,
+        msol_protocol_fee_accumulator: 0,
+        lp_token_mint,
+        lp_token_supply: 0,
+    })
+    .add_s_program();
+    let liquidity_provider_jitosol_acc_addr =
+        program_test.gen_and_add_token_account(MockTokenAccountArgs {
+            mint: jitosol::ID,
+            authority: liquidity_provider.pubkey(),
+            amount: JITOSOL_TO_ADD,
+        });
+    let liquidity_provider_msol_acc_addr =
+        program_test.gen_and_add_token_account(MockTokenAccountArgs {
+            mint: msol::ID,
+            authority: liquidity_provider.pubkey(),
+            amount: MSOL_TO_ADD,
+        });
+    let liquidity_provider_lp_token_acc_addr =
+        program_test.gen_and_add_token_account(MockTokenAccountArgs {
+            mint: lp_token_mint,
+            authority: liquidity_provider.pubkey(),
+            amount: 0,
+        });
+    let ctx = program_test.start_with_context().await;
+    ctx.set_sysvar(&Clock {
+        epoch: JITO_STAKE_POOL_LAST_UPDATE_EPOCH,
+        ..Default::default()
+    });
+    let ProgramTestContext {
+        mut banks_client,
+        last_blockhash,
+        payer,
+        ..
+    } = ctx;
+
+    let jito_stake_pool_acc = banks_client
+        .get_account_unwrapped(jito_stake_pool::ID)
+        .await;
+    let jito_sol_val_calc_accounts = SplLstSolCommonFreeArgsConst {
+        spl_stake_pool: KeyedAccount {
+            pubkey: jito_stake_pool::ID,
+            account: jito_stake_pool_acc,
+        },
+    }
+    .resolve_spl_to_account_metas()
+    .unwrap();
+    let marinade_sol_val_calc_accounts = marinade_sol_val_calc_account_metas();
+    let pool_state_account = banks_client.get_pool_state_acc().await;
+    let lst_state_list_account = banks_client.get_lst_state_list_acc().await;
+
+    let lsts = [
+        MultiLiquidityLst {
+            lst_mint: MintWithTokenProgram {
+                pubkey: jitosol::ID,
+                token_program: spl_token::ID,
+            },
+            lst_acc: liquidity_provider_jitosol_acc_addr,
+        },
+        MultiLiquidityLst {
+            lst_mint: MintWithTokenProgram {
+                pubkey: msol::ID,
+                token_program: spl_token::ID,
+            },
+            lst_acc: liquidity_provider_msol_acc_addr,
+        },
+    ];
+    let ix = add_liquidity_multi_ix_by_mints_full(
+        AddLiquidityMultiByMintsFreeArgs {
+            signer: liquidity_provider.pubkey(),
+            dst_lp_acc: liquidity_provider_lp_token_acc_addr,
+            pool_state: pool_state_account,
+            lst_state_list: lst_state_list_account,
+            lsts: &lsts,
+        },
+        AddLiquidityMultiIxAmts {
+            lst_amounts: &[JITOSOL_TO_ADD, MSOL_TO_ADD],
+            min_lp_out: 0,
+        },
+        &[&jito_sol_val_calc_accounts, &marinade_sol_val_calc_accounts],
+        &[
+            &[AccountMeta::new_readonly(jitosol::ID, false)],
+            &[AccountMeta::new_readonly(msol::ID, false)],
+        ],
+    )
+    .unwrap();
+    // fixed accounts, then [mint, token program, lst acc, reserves, protocol fee accumulator] per LST
+    let jitosol_pool_reserves = ix.accounts[9].pubkey;
+    let msol_pool_reserves = ix.accounts[14].pubkey;
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &liquidity_provider], last_blockhash);
+
+    banks_client.process_transaction(tx).await.unwrap();
+
+    for src in [
+        liquidity_provider_jitosol_acc_addr,
+        liquidity_provider_msol_acc_addr,
+    ] {
+        let src_account = banks_client.get_account_unwrapped(src).await;
+        assert_eq!(token_account_balance(src_account).unwrap(), 0);
+    }
+    let jitosol_pool_reserves_account = banks_client
+        .get_account_unwrapped(jitosol_pool_reserves)
+        .await;
+    assert_eq!(
+        token_account_balance(jitosol_pool_reserves_account).unwrap(),
+        JITOSOL_TO_ADD
+    );
+    let msol_pool_reserves_account = banks_client.get_account_unwrapped(msol_pool_reserves).await;
+    assert_eq!(
+        token_account_balance(msol_pool_reserves_account).unwrap(),
+        MSOL_TO_ADD
+    );
+
+    let lp_token_account = banks_client
+        .get_account_unwrapped(liquidity_provider_lp_token_acc_addr)
+        .await;
+    let lp_tokens_minted = token_account_balance(lp_token_account).unwrap();
+    // both LSTs are worth more than SOL
+    assert!(lp_tokens_minted > JITOSOL_TO_ADD + MSOL_TO_ADD);
+
+    // empty pool: LP tokens minted 1:1 with SOL value added
+    let pool_state_account = banks_client.get_pool_state_acc().await;
+    let pool_state = try_pool_state(&pool_state_account.data).unwrap();
+    assert_eq!(lp_tokens_minted, pool_state.total_sol_value);
+}
+
+#[tokio::test]
+async fn fail_add_liquidity_multi_duplicate_lst() {
+    let liquidity_provider = Keypair::new();
+    let lp_token_mint = Pubkey::new_unique();
+
+    let mut program_test = jito_marinade_no_fee_program_test(JitoMarinadeProgramTestArgs {
+        jitosol_sol_value: 0,
+        msol_sol_value: 0,
+        jitosol_reserves: 0,
+        msol_reserves: 0,
+        jitosol_protocol_fee_accumulator: 0,
+        msol_protocol_fee_accumulator: 0,
+        lp_token_mint,
+        lp_token_supply: 0,
+    })
+    .add_s_program();
+    let liquidity_provider_msol_acc_addr =
+        program_test.gen_and_add_token_account(MockTokenAccountArgs {
+            mint: msol::ID,
+            authority: liquidity_provider.pubkey(),
+            amount: MSOL_TO_ADD,
+        });
+    let liquidity_provider_lp_token_acc_addr =
+        program_test.gen_and_add_token_account(MockTokenAccountArgs {
+            mint: lp_token_mint,
+            authority: liquidity_provider.pubkey(),
+            amount: 0,
+        });
+    let ProgramTestContext {
+        mut banks_client,
+        last_blockhash,
+        payer,
+        ..
+    } = program_test.start_with_context().await;
+
+    let pool_state_account = banks_client.get_pool_state_acc().await;
+    let lst_state_list_account = banks_client.get_lst_state_list_acc().await;
+    let msol_index = try_find_lst_mint_on_list(
+        msol::ID,
+        try_lst_state_list(&lst_state_list_account.data).unwrap(),
+    )
+    .unwrap()
+    .0;
+
+    let keys = AddLiquidityMultiFreeArgs {
+        signer: liquidity_provider.pubkey(),
+        dst_lp_acc: liquidity_provider_lp_token_acc_addr,
+        pool_state: KeyedAccount {
+            pubkey: POOL_STATE_ID,
+            account: pool_state_account,
+        },
+    }
+    .resolve()
+    .unwrap();
+    let msol_keys = MultiLiquidityLstFreeArgs {
+        lst_index: msol_index,
+        lst_acc: liquidity_provider_msol_acc_addr,
+        lst_mint: MintWithTokenProgram {
+            pubkey: msol::ID,
+            token_program: spl_token::ID,
+        },
+        lst_state_list: KeyedAccount {
+            pubkey: LST_STATE_LIST_ID,
+            account: lst_state_list_account,
+        },
+    }
+    .resolve()
+    .unwrap();
+    let marinade_sol_val_calc_accounts = marinade_sol_val_calc_account_metas();
+    let marinade_calc = LstSolValueCalcAccounts {
+        calculator_program_id: marinade_calculator_lib::program::ID,
+        calculator_accounts: &marinade_sol_val_calc_accounts,
+    };
+    let msol_pricing_accounts = [AccountMeta::new_readonly(msol::ID, false)];
+
+    let ix = add_liquidity_multi_ix_full(
+        keys,
+        AddLiquidityMultiIxFullArgs {
+            lst_indexes: &[msol_index, msol_index],
+            amts: AddLiquidityMultiIxAmts {
+                lst_amounts: &[MSOL_TO_ADD / 2, MSOL_TO_ADD / 2],
+                min_lp_out: 0,
+            },
+        },
+        &[msol_keys, msol_keys],
+        &[marinade_calc, marinade_calc],
+        &[&msol_pricing_accounts, &msol_pricing_accounts],
+        no_fee_pricing_program::ID,
+    )
+    .unwrap();
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &liquidity_provider], last_blockhash);
+
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_custom_err(err, SControllerError::InvalidLiquidityLsts);
+}
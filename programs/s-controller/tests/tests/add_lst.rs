@@ -1,13 +1,13 @@
 use marinade_keys::msol;
-use s_controller_interface::{add_lst_ix, AddLstKeys, LstState, SControllerError};
+use s_controller_interface::{add_lst_ix, AddLstKeys, LstState, PoolState, SControllerError};
 use s_controller_lib::{
     find_pool_reserves_address, find_protocol_fee_accumulator_address,
     program::{LST_STATE_LIST_ID, POOL_STATE_ID, PROTOCOL_FEE_ID},
     try_find_lst_mint_on_list, try_lst_state_list, AddLstFreeArgs, FindLstPdaAtaKeys,
 };
 use s_controller_test_utils::{
-    AddMarinadeProgramTest, AddSplProgramTest, LstStateListBanksClient, PoolStateBanksClient,
-    PoolStateProgramTest, DEFAULT_POOL_STATE,
+    AddMarinadeProgramTest, AddSplProgramTest, LpTokenProgramTest, LstStateListBanksClient,
+    PoolStateBanksClient, PoolStateProgramTest, DEFAULT_POOL_STATE,
 };
 use sanctum_solana_test_utils::{
     assert_custom_err, assert_program_error, test_fixtures_dir,
@@ -30,6 +30,12 @@ use test_utils::jitosol;
 use crate::common::*;
 
 fn jito_marinade_add_lst_program_test() -> (ProgramTest, Keypair) {
+    jito_marinade_add_lst_program_test_with_pool_state(DEFAULT_POOL_STATE)
+}
+
+fn jito_marinade_add_lst_program_test_with_pool_state(
+    pool_state: PoolState,
+) -> (ProgramTest, Keypair) {
     let mock_auth_kp =
         read_keypair_file(test_fixtures_dir().join("s-controller-test-initial-authority-key.json"))
             .unwrap();
@@ -46,7 +52,7 @@ fn jito_marinade_add_lst_program_test() -> (ProgramTest, Keypair) {
         .add_marinade_progs()
         .add_jito_stake_pool()
         .add_marinade_stake_pool()
-        .add_pool_state(DEFAULT_POOL_STATE);
+        .add_pool_state(pool_state);
 
     (program_test, mock_auth_kp)
 }
@@ -377,3 +383,59 @@ async fn fail_add_non_exec_sol_val_calc() {
     let err = banks_client.process_transaction(tx).await.unwrap_err();
     assert_custom_err(err, SControllerError::FaultySolValueCalculator);
 }
+
+#[tokio::test]
+async fn fail_add_pool_lp_token() {
+    let lp_token_mint = Pubkey::new_unique();
+    let (program_test, mock_auth_kp) =
+        jito_marinade_add_lst_program_test_with_pool_state(PoolState {
+            lp_token_mint,
+            ..DEFAULT_POOL_STATE
+        });
+    let program_test = program_test.add_mock_lp_mint(lp_token_mint, 0);
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let pool_state_account = banks_client.get_pool_state_acc().await;
+    let lp_token_mint_acc = banks_client.get_account_unwrapped(lp_token_mint).await;
+    let err = AddLstFreeArgs {
+        payer: payer.pubkey(),
+        sol_value_calculator: spl_calculator_lib::program::ID,
+        pool_state: KeyedAccount {
+            pubkey: POOL_STATE_ID,
+            account: pool_state_account,
+        },
+        lst_mint: KeyedAccount {
+            pubkey: lp_token_mint,
+            account: lp_token_mint_acc,
+        },
+    }
+    .resolve()
+    .unwrap_err();
+    assert_eq!(err, SControllerError::LpTokenAsLst);
+
+    // bypass the resolver to check that the program rejects it too
+    let find_pda_keys = FindLstPdaAtaKeys {
+        lst_mint: lp_token_mint,
+        token_program: spl_token::ID,
+    };
+    let ix = add_lst_ix(AddLstKeys {
+        admin: mock_auth_kp.pubkey(),
+        payer: payer.pubkey(),
+        lst_mint: lp_token_mint,
+        pool_reserves: find_pool_reserves_address(find_pda_keys).0,
+        protocol_fee_accumulator: find_protocol_fee_accumulator_address(find_pda_keys).0,
+        protocol_fee_accumulator_auth: PROTOCOL_FEE_ID,
+        sol_value_calculator: spl_calculator_lib::program::ID,
+        pool_state: POOL_STATE_ID,
+        lst_state_list: LST_STATE_LIST_ID,
+        associated_token_program: spl_associated_token_account::ID,
+        system_program: system_program::ID,
+        lst_token_program: spl_token::ID,
+    })
+    .unwrap();
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &mock_auth_kp], last_blockhash);
+
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_custom_err(err, SControllerError::LpTokenAsLst);
+}
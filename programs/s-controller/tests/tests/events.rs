@@ -0,0 +1,141 @@
+use s_controller_events::{decode_events, DecodedEvent, SControllerEvent};
+use s_controller_lib::{
+    program::POOL_STATE_ID, set_lst_cap_ix_by_mint_full, sync_sol_value_ix_by_mint_full,
+    SetLstCapByMintFreeArgs, SyncSolValueByMintFreeArgs,
+};
+use s_controller_test_utils::{
+    jito_marinade_no_fee_program_test, JitoMarinadeProgramTestArgs, LstStateListBanksClient,
+    PoolStateBanksClient,
+};
+use sanctum_solana_test_utils::{test_fixtures_dir, ExtendedBanksClient};
+use solana_program::{clock::Clock, pubkey::Pubkey};
+use solana_program_test::{BanksClient, ProgramTestContext};
+use solana_readonly_account::sdk::KeyedAccount;
+use solana_sdk::{signature::read_keypair_file, signer::Signer, transaction::Transaction};
+use spl_calculator_lib::SplLstSolCommonFreeArgsConst;
+use test_utils::{jito_stake_pool, jitosol, JITO_STAKE_POOL_LAST_UPDATE_EPOCH};
+
+use crate::common::*;
+
+async fn process_tx_decode_events(
+    banks_client: &mut BanksClient,
+    tx: Transaction,
+) -> Vec<DecodedEvent> {
+    let res = banks_client
+        .process_transaction_with_metadata(tx)
+        .await
+        .unwrap();
+    res.result.unwrap();
+    decode_events(
+        &s_controller_lib::program::ID,
+        &res.metadata.unwrap().log_messages,
+    )
+    .unwrap()
+}
+
+#[tokio::test]
+async fn sync_sol_value_emits_event() {
+    const JITOSOL_STARTING_SOL_VALUE: u64 = 1_000_000_000;
+    const EXPECTED_NEW_JITOSOL_SOL_VALUE: u64 = 1_072_326_756;
+
+    let program_test = jito_marinade_no_fee_program_test(JitoMarinadeProgramTestArgs {
+        jitosol_sol_value: JITOSOL_STARTING_SOL_VALUE,
+        msol_sol_value: 1_000_000_000,
+        jitosol_reserves: 1_000_000_000,
+        msol_reserves: 1_000_000_000,
+        jitosol_protocol_fee_accumulator: 0,
+        msol_protocol_fee_accumulator: 0,
+        lp_token_mint: Pubkey::new_unique(),
+        lp_token_supply: 0,
+    })
+    .add_s_program();
+    let ctx = program_test.start_with_context().await;
+    ctx.set_sysvar(&Clock {
+        epoch: JITO_STAKE_POOL_LAST_UPDATE_EPOCH,
+        ..Default::default()
+    });
+    let ProgramTestContext {
+        mut banks_client,
+        last_blockhash,
+        payer,
+        ..
+    } = ctx;
+
+    let jitosol_mint_acc = banks_client.get_account_unwrapped(jitosol::ID).await;
+    let jito_stake_pool_acc = banks_client
+        .get_account_unwrapped(jito_stake_pool::ID)
+        .await;
+    let ix = sync_sol_value_ix_by_mint_full(
+        SyncSolValueByMintFreeArgs {
+            lst_state_list: banks_client.get_lst_state_list_acc().await,
+            lst_mint: KeyedAccount {
+                pubkey: jitosol::ID,
+                account: jitosol_mint_acc,
+            },
+        },
+        &SplLstSolCommonFreeArgsConst {
+            spl_stake_pool: KeyedAccount {
+                pubkey: jito_stake_pool::ID,
+                account: jito_stake_pool_acc,
+            },
+        }
+        .resolve_spl_to_account_metas()
+        .unwrap(),
+    )
+    .unwrap();
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer], last_blockhash);
+
+    let events = process_tx_decode_events(&mut banks_client, tx).await;
+    assert_eq!(
+        events,
+        vec![DecodedEvent {
+            pool_state: POOL_STATE_ID,
+            event: SControllerEvent::SyncSolValue {
+                lst_mint: jitosol::ID,
+                old_sol_value: JITOSOL_STARTING_SOL_VALUE,
+                new_sol_value: EXPECTED_NEW_JITOSOL_SOL_VALUE,
+            },
+        }]
+    );
+}
+
+#[tokio::test]
+async fn admin_ix_emits_event() {
+    const MAX_SHARE_BPS: u16 = 5_000;
+
+    let mock_auth_kp =
+        read_keypair_file(test_fixtures_dir().join("s-controller-test-initial-authority-key.json"))
+            .unwrap();
+    let program_test = jito_marinade_no_fee_program_test(
+        JitoMarinadeProgramTestArgs::default().with_lp_token_mint(Pubkey::new_unique()),
+    )
+    .add_s_program();
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let ix = set_lst_cap_ix_by_mint_full(
+        &SetLstCapByMintFreeArgs {
+            lst_mint: jitosol::ID,
+            pool_state: banks_client.get_pool_state_acc().await,
+            lst_state_list: banks_client.get_lst_state_list_acc().await,
+        },
+        MAX_SHARE_BPS,
+    )
+    .unwrap();
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &mock_auth_kp], last_blockhash);
+
+    let events = process_tx_decode_events(&mut banks_client, tx).await;
+    assert_eq!(
+        events,
+        vec![DecodedEvent {
+            pool_state: POOL_STATE_ID,
+            event: SControllerEvent::SetLstCap {
+                lst_mint: jitosol::ID,
+                max_share_bps: MAX_SHARE_BPS,
+            },
+        }]
+    );
+}
@@ -67,12 +67,12 @@ async fn initialize_basic() {
             version: CURRENT_PROGRAM_VERS,
             is_disabled: 0,
             is_rebalancing: 0,
+            timelock_delay_hours: 0,
             admin: initial_authority::ID,
             rebalance_authority: initial_authority::ID,
             protocol_fee_beneficiary: initial_authority::ID,
             pricing_program: DEFAULT_PRICING_PROGRAM,
             lp_token_mint: lp_token_mint_addr,
-            padding: [0; 1] // dont care
         }
     );
 
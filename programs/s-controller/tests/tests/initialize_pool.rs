@@ -0,0 +1,135 @@
+use s_controller_interface::{initialize_pool_ix, PoolState};
+use s_controller_lib::{
+    find_pool_state_address_for_pool, initial_authority, program::POOL_STATE_ID, try_pool_state,
+    InitializePoolFreeArgs, PoolId, CURRENT_PROGRAM_VERS, DEFAULT_LP_PROTOCOL_FEE_BPS,
+    DEFAULT_PRICING_PROGRAM, DEFAULT_TRADING_PROTOCOL_FEE_BPS,
+};
+use s_controller_test_utils::{
+    LpTokenProgramTest, MockLpMintToInitArgs, PoolStateBanksClient, PoolStateProgramTest,
+    DEFAULT_POOL_STATE,
+};
+use sanctum_solana_test_utils::{assert_program_error, test_fixtures_dir, ExtendedBanksClient};
+use solana_program::{
+    program_error::ProgramError, program_option::COption, program_pack::Pack, pubkey::Pubkey,
+};
+use solana_program_test::ProgramTest;
+use solana_readonly_account::sdk::KeyedAccount;
+use solana_sdk::{signature::read_keypair_file, signer::Signer, transaction::Transaction};
+use spl_token::{native_mint, state::Mint};
+
+use crate::common::*;
+
+/// Returns (program_test, lp_token_mint_addr)
+fn setup(lp_mint_intial_auth: Pubkey) -> (ProgramTest, Pubkey) {
+    let lp_token_mint_addr = Pubkey::new_unique();
+    let program_test = ProgramTest::default()
+        .add_s_program()
+        .add_pool_state(DEFAULT_POOL_STATE)
+        .add_mock_lp_mint_to_init(MockLpMintToInitArgs {
+            initial_authority: lp_mint_intial_auth,
+            addr: lp_token_mint_addr,
+        });
+    (program_test, lp_token_mint_addr)
+}
+
+#[tokio::test]
+async fn initialize_pool_basic() {
+    let mock_auth_kp =
+        read_keypair_file(test_fixtures_dir().join("s-controller-test-initial-authority-key.json"))
+            .unwrap();
+
+    let (program_test, lp_token_mint_addr) = setup(mock_auth_kp.pubkey());
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let (keys, _bump) = InitializePoolFreeArgs {
+        payer: payer.pubkey(),
+        lp_token_mint: lp_token_mint_addr,
+        default_pool_state: KeyedAccount {
+            pubkey: POOL_STATE_ID,
+            account: banks_client.get_pool_state_acc().await,
+        },
+    }
+    .resolve()
+    .unwrap();
+    let ix = initialize_pool_ix(keys).unwrap();
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &mock_auth_kp], last_blockhash);
+
+    banks_client.process_transaction(tx).await.unwrap();
+
+    let (expected_pool_state_addr, _bump) = find_pool_state_address_for_pool(
+        s_controller_lib::program::ID,
+        PoolId::Keyed(lp_token_mint_addr),
+    );
+    assert_eq!(keys.pool_state, expected_pool_state_addr);
+
+    let pool_state_acc = banks_client
+        .get_account_unwrapped(expected_pool_state_addr)
+        .await;
+    let pool_state = try_pool_state(&pool_state_acc.data).unwrap();
+    assert_eq!(
+        *pool_state,
+        PoolState {
+            total_sol_value: 0,
+            trading_protocol_fee_bps: DEFAULT_TRADING_PROTOCOL_FEE_BPS,
+            lp_protocol_fee_bps: DEFAULT_LP_PROTOCOL_FEE_BPS,
+            version: CURRENT_PROGRAM_VERS,
+            is_disabled: 0,
+            is_rebalancing: 0,
+            timelock_delay_hours: 0,
+            admin: initial_authority::ID,
+            rebalance_authority: initial_authority::ID,
+            protocol_fee_beneficiary: initial_authority::ID,
+            pricing_program: DEFAULT_PRICING_PROGRAM,
+            lp_token_mint: lp_token_mint_addr,
+        }
+    );
+
+    let lp_token_mint_acc = banks_client.get_account_unwrapped(lp_token_mint_addr).await;
+    let lp_token_mint = Mint::unpack(&lp_token_mint_acc.data).unwrap();
+    assert_eq!(
+        lp_token_mint,
+        Mint {
+            mint_authority: COption::Some(expected_pool_state_addr),
+            supply: 0,
+            decimals: native_mint::DECIMALS,
+            is_initialized: true,
+            freeze_authority: COption::Some(expected_pool_state_addr),
+        }
+    );
+
+    // default pool must be untouched
+    let default_pool_state_acc = banks_client.get_pool_state_acc().await;
+    let default_pool_state = try_pool_state(&default_pool_state_acc.data).unwrap();
+    assert_eq!(*default_pool_state, DEFAULT_POOL_STATE);
+}
+
+#[tokio::test]
+async fn fail_initialize_pool_unauthorized() {
+    let mock_auth_kp =
+        read_keypair_file(test_fixtures_dir().join("s-controller-test-initial-authority-key.json"))
+            .unwrap();
+
+    let (program_test, lp_token_mint_addr) = setup(mock_auth_kp.pubkey());
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let (keys, _bump) = InitializePoolFreeArgs {
+        payer: payer.pubkey(),
+        lp_token_mint: lp_token_mint_addr,
+        default_pool_state: KeyedAccount {
+            pubkey: POOL_STATE_ID,
+            account: banks_client.get_pool_state_acc().await,
+        },
+    }
+    .resolve()
+    .unwrap();
+    let mut ix = initialize_pool_ix(keys).unwrap();
+    ix.accounts[1].pubkey = payer.pubkey();
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer], last_blockhash);
+
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_program_error(err, ProgramError::InvalidArgument);
+}
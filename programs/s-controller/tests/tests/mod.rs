@@ -1,21 +1,33 @@
 mod add_disable_pool_authority;
 mod add_liquidity;
+mod add_liquidity_exact_out;
+mod add_liquidity_multi;
 mod add_lst;
 mod disable_pool;
 mod enable_disable_lst_input;
 mod enable_pool;
+mod events;
 mod initialize;
+mod initialize_pool;
 mod rebalance;
 mod remove_disable_pool_authority;
 mod remove_liquidity;
+mod remove_liquidity_exact_out;
+mod remove_liquidity_pro_rata;
 mod remove_lst;
 mod set_admin;
+mod set_lst_cap;
 mod set_pricing_program;
 mod set_protocol_fee;
 mod set_protocol_fee_beneficiary;
 mod set_rebalance_authority;
+mod set_rebalance_loss_limit;
 mod set_sol_value_calculator;
 mod swap_exact_in;
+mod swap_exact_in_route;
 mod swap_exact_out;
 mod sync_sol_value;
+mod timelock;
 mod withdraw_protocol_fees;
+mod withdraw_protocol_fees_multi;
+mod withdraw_protocol_fees_swap;
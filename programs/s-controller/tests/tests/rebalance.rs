@@ -1,16 +1,18 @@
 use marinade_calculator_lib::marinade_sol_val_calc_account_metas;
 use marinade_keys::msol;
-use s_controller_interface::SControllerError;
+use s_controller_interface::{RebalanceLossState, SControllerError};
 use s_controller_lib::{
     end_rebalance_ix_full,
     program::{LST_STATE_LIST_ID, POOL_STATE_ID, REBALANCE_RECORD_ID},
-    start_rebalance_ix_full, try_lst_state_list, try_pool_state,
-    EndRebalanceFromStartRebalanceKeys, SrcDstLstIndexes, SrcDstLstSolValueCalcAccounts,
+    rebalance_ixs, start_rebalance_ix_by_mints_full, start_rebalance_ix_full, try_lst_state_list,
+    try_pool_state, try_rebalance_loss_state, EndRebalanceFromStartRebalanceKeys, SrcDstLstIndexes,
+    SrcDstLstSolValueCalcAccountSuffixes, SrcDstLstSolValueCalcAccounts,
     StartRebalanceByMintsFreeArgs, StartRebalanceIxFullArgs, StartRebalanceIxLstAmts, U8Bool,
 };
 use s_controller_test_utils::{
     jito_marinade_no_fee_program_test, GenAndAddTokenAccountProgramTest,
     JitoMarinadeProgramTestArgs, LstStateListBanksClient, PoolStateBanksClient,
+    RebalanceLossStateBanksClient, RebalanceLossStateProgramTest,
 };
 use sanctum_solana_test_utils::{
     assert_custom_err, assert_program_error, test_fixtures_dir, token::MockTokenAccountArgs,
@@ -22,7 +24,7 @@ use sanctum_token_lib::{
 use solana_program::{
     clock::Clock, instruction::Instruction, program_error::ProgramError, pubkey::Pubkey,
 };
-use solana_program_test::ProgramTestContext;
+use solana_program_test::{BanksClient, BanksClientError, ProgramTestContext};
 use solana_readonly_account::sdk::KeyedAccount;
 use solana_sdk::{
     account::Account,
@@ -46,6 +48,7 @@ struct CreateRebalanceDonateIxsArgs {
     pub msol_donate_amt: u64,
     pub min_starting_src_lst: u64,
     pub max_starting_dst_lst: u64,
+    pub min_ending_sol_value: u64,
 }
 
 fn create_rebalance_donate_ixs(
@@ -60,6 +63,7 @@ fn create_rebalance_donate_ixs(
         msol_donate_amt,
         min_starting_src_lst,
         max_starting_dst_lst,
+        min_ending_sol_value,
     }: CreateRebalanceDonateIxsArgs,
 ) -> [Instruction; 3] {
     let jito_sol_val_calc_accounts = SplLstSolCommonFreeArgsConst {
@@ -111,6 +115,7 @@ fn create_rebalance_donate_ixs(
                 amount: jitosol_withdraw_amt,
                 min_starting_src_lst,
                 max_starting_dst_lst,
+                min_ending_sol_value,
             },
         },
         SrcDstLstSolValueCalcAccounts {
@@ -208,6 +213,7 @@ async fn rebalance_basic() {
         msol_donate_amt: MSOL_DONATE_AMT,
         min_starting_src_lst: 0,
         max_starting_dst_lst: u64::MAX,
+        min_ending_sol_value: 0,
     });
 
     let mut tx = Transaction::new_with_payer(&ixs, Some(&payer.pubkey()));
@@ -234,6 +240,131 @@ async fn rebalance_basic() {
     assert!(rebalance_record.is_none());
 }
 
+#[tokio::test]
+async fn rebalance_ixs_sandwich() {
+    const JITOSOL_START_SOL_VALUE: u64 = 1_000_000_000;
+    const MSOL_START_SOL_VALUE: u64 = 1_000_000_000;
+    const JITOSOL_WITHDRAW_AMT: u64 = 500_000_000;
+    const MSOL_DONATE_AMT: u64 = 500_000_000;
+
+    let mock_auth_kp =
+        read_keypair_file(test_fixtures_dir().join("s-controller-test-initial-authority-key.json"))
+            .unwrap();
+
+    let mut program_test = jito_marinade_no_fee_program_test(JitoMarinadeProgramTestArgs {
+        jitosol_sol_value: JITOSOL_START_SOL_VALUE,
+        msol_sol_value: MSOL_START_SOL_VALUE,
+        jitosol_reserves: JITOSOL_START_SOL_VALUE,
+        msol_reserves: MSOL_START_SOL_VALUE,
+        jitosol_protocol_fee_accumulator: 0,
+        msol_protocol_fee_accumulator: 0,
+        lp_token_mint: Pubkey::new_unique(),
+        lp_token_supply: 0,
+    })
+    .add_s_program();
+
+    let withdraw_jitosol_to_addr = program_test.gen_and_add_token_account(MockTokenAccountArgs {
+        mint: jitosol::ID,
+        authority: mock_auth_kp.pubkey(),
+        amount: 0,
+    });
+    let donate_msol_from_addr = program_test.gen_and_add_token_account(MockTokenAccountArgs {
+        mint: msol::ID,
+        authority: mock_auth_kp.pubkey(),
+        amount: MSOL_DONATE_AMT,
+    });
+
+    let ctx = program_test.start_with_context().await;
+    ctx.set_sysvar(&Clock {
+        epoch: JITO_STAKE_POOL_LAST_UPDATE_EPOCH,
+        ..Default::default()
+    });
+    let ProgramTestContext {
+        mut banks_client,
+        last_blockhash,
+        payer,
+        ..
+    } = ctx;
+
+    let lst_state_list_acc = banks_client.get_lst_state_list_acc().await;
+    let pool_state_acc = banks_client.get_pool_state_acc().await;
+    let jito_stake_pool_acc = banks_client
+        .get_account_unwrapped(jito_stake_pool::ID)
+        .await;
+    let jito_sol_val_calc_accounts = SplLstSolCommonFreeArgsConst {
+        spl_stake_pool: KeyedAccount {
+            pubkey: jito_stake_pool::ID,
+            account: jito_stake_pool_acc,
+        },
+    }
+    .resolve_spl_to_account_metas()
+    .unwrap();
+    let marinade_sol_val_calc_accounts = marinade_sol_val_calc_account_metas();
+
+    let start_rebalance_ix = start_rebalance_ix_by_mints_full(
+        StartRebalanceByMintsFreeArgs {
+            withdraw_to: withdraw_jitosol_to_addr,
+            lst_state_list: KeyedAccount {
+                pubkey: LST_STATE_LIST_ID,
+                account: lst_state_list_acc,
+            },
+            pool_state: KeyedAccount {
+                pubkey: POOL_STATE_ID,
+                account: pool_state_acc,
+            },
+            src_lst_mint: MintWithTokenProgram {
+                pubkey: jitosol::ID,
+                token_program: spl_token::ID,
+            },
+            dst_lst_mint: MintWithTokenProgram {
+                pubkey: msol::ID,
+                token_program: spl_token::ID,
+            },
+        },
+        StartRebalanceIxLstAmts {
+            amount: JITOSOL_WITHDRAW_AMT,
+            min_starting_src_lst: 0,
+            max_starting_dst_lst: u64::MAX,
+            min_ending_sol_value: 0,
+        },
+        SrcDstLstSolValueCalcAccountSuffixes {
+            src_lst_calculator_accounts: &jito_sol_val_calc_accounts,
+            dst_lst_calculator_accounts: &marinade_sol_val_calc_accounts,
+        },
+    )
+    .unwrap();
+    let msol_pool_reserves = start_rebalance_ix.accounts[7].pubkey;
+    let donate_msol_ix = transfer_checked_ix(
+        TransferCheckedKeys {
+            token_program: spl_token::ID,
+            from: donate_msol_from_addr,
+            to: msol_pool_reserves,
+            authority: mock_auth_kp.pubkey(),
+            mint: msol::ID,
+        },
+        TransferCheckedArgs {
+            amount: MSOL_DONATE_AMT,
+            decimals: 9,
+        },
+    )
+    .unwrap();
+    let ixs = rebalance_ixs(start_rebalance_ix, [donate_msol_ix]).unwrap();
+    assert_eq!(ixs.len(), 3);
+
+    let mut tx = Transaction::new_with_payer(&ixs, Some(&payer.pubkey()));
+    tx.sign(&[&payer, &mock_auth_kp], last_blockhash);
+
+    banks_client.process_transaction(tx).await.unwrap();
+
+    let pool_state_acc = banks_client.get_pool_state_acc().await;
+    let pool_state = try_pool_state(&pool_state_acc.data).unwrap();
+    assert!(U8Bool(pool_state.is_rebalancing).is_false());
+    assert!(pool_state.total_sol_value >= JITOSOL_START_SOL_VALUE + MSOL_START_SOL_VALUE);
+
+    let rebalance_record = banks_client.get_account(REBALANCE_RECORD_ID).await.unwrap();
+    assert!(rebalance_record.is_none());
+}
+
 #[tokio::test]
 async fn rebalance_fail_no_end() {
     let mock_auth_kp =
@@ -324,6 +455,7 @@ async fn rebalance_fail_no_end() {
                 amount: 500_000_000,
                 min_starting_src_lst: 0,
                 max_starting_dst_lst: u64::MAX,
+                min_ending_sol_value: 0,
             },
         },
         SrcDstLstSolValueCalcAccounts {
@@ -402,6 +534,7 @@ async fn rebalance_fail_unauthorized() {
         msol_donate_amt: 500_000_000,
         min_starting_src_lst: 0,
         max_starting_dst_lst: u64::MAX,
+        min_ending_sol_value: 0,
     });
     // change rebalance authority
     ixs[0].accounts[0].pubkey = unauthorized.pubkey();
@@ -477,6 +610,7 @@ async fn rebalance_fail_not_enough_sol_value_returned() {
         msol_donate_amt: TINY_MSOL_DONATE_AMT,
         min_starting_src_lst: 0,
         max_starting_dst_lst: u64::MAX,
+        min_ending_sol_value: 0,
     });
 
     let mut tx = Transaction::new_with_payer(&ixs, Some(&payer.pubkey()));
@@ -544,6 +678,7 @@ async fn rebalance_fail_wrong_end_rebalance_dst_lst_mint() {
         msol_donate_amt: 500_000_000,
         min_starting_src_lst: 0,
         max_starting_dst_lst: u64::MAX,
+        min_ending_sol_value: 0,
     });
     // change dst_lst_mint of end rebalance ix
     ixs[2].accounts[4].pubkey = jitosol::ID;
@@ -623,6 +758,7 @@ async fn rebalance_fail_slippage_tolerance_exceeded() {
             msol_donate_amt: MSOL_DONATE_AMT,
             min_starting_src_lst,
             max_starting_dst_lst,
+            min_ending_sol_value: 0,
         });
 
         let mut tx = Transaction::new_with_payer(&ixs, Some(&payer.pubkey()));
@@ -632,3 +768,325 @@ async fn rebalance_fail_slippage_tolerance_exceeded() {
         assert_custom_err(err, SControllerError::SlippageToleranceExceeded);
     }
 }
+
+const LOSSY_REBALANCE_JITOSOL_START_SOL_VALUE: u64 = 1_000_000_000;
+const LOSSY_REBALANCE_MSOL_START_SOL_VALUE: u64 = 1_000_000_000;
+
+/// Withdraws half the pool's jitoSOL and donates back a tiny amount of mSOL,
+/// losing roughly a quarter of the pool's SOL value
+async fn process_lossy_rebalance(
+    rebalance_loss_state: Option<RebalanceLossState>,
+    min_ending_sol_value: u64,
+) -> (BanksClient, Result<(), BanksClientError>) {
+    const JITOSOL_WITHDRAW_AMT: u64 = 500_000_000;
+    const TINY_MSOL_DONATE_AMT: u64 = 100_000;
+
+    let mock_auth_kp =
+        read_keypair_file(test_fixtures_dir().join("s-controller-test-initial-authority-key.json"))
+            .unwrap();
+
+    let mut program_test = jito_marinade_no_fee_program_test(JitoMarinadeProgramTestArgs {
+        jitosol_sol_value: LOSSY_REBALANCE_JITOSOL_START_SOL_VALUE,
+        msol_sol_value: LOSSY_REBALANCE_MSOL_START_SOL_VALUE,
+        jitosol_reserves: LOSSY_REBALANCE_JITOSOL_START_SOL_VALUE,
+        msol_reserves: LOSSY_REBALANCE_MSOL_START_SOL_VALUE,
+        jitosol_protocol_fee_accumulator: 0,
+        msol_protocol_fee_accumulator: 0,
+        lp_token_mint: Pubkey::new_unique(),
+        lp_token_supply: 0,
+    })
+    .add_s_program();
+    if let Some(rebalance_loss_state) = rebalance_loss_state {
+        program_test = program_test.add_rebalance_loss_state(rebalance_loss_state);
+    }
+
+    let withdraw_jitosol_to_addr = program_test.gen_and_add_token_account(MockTokenAccountArgs {
+        mint: jitosol::ID,
+        authority: mock_auth_kp.pubkey(),
+        amount: 0,
+    });
+    let donate_msol_from_addr = program_test.gen_and_add_token_account(MockTokenAccountArgs {
+        mint: msol::ID,
+        authority: mock_auth_kp.pubkey(),
+        amount: TINY_MSOL_DONATE_AMT,
+    });
+
+    let ctx = program_test.start_with_context().await;
+    ctx.set_sysvar(&Clock {
+        epoch: JITO_STAKE_POOL_LAST_UPDATE_EPOCH,
+        ..Default::default()
+    });
+    let ProgramTestContext {
+        mut banks_client,
+        last_blockhash,
+        payer,
+        ..
+    } = ctx;
+
+    let lst_state_list_acc = banks_client.get_lst_state_list_acc().await;
+    let pool_state_acc = banks_client.get_pool_state_acc().await;
+    let jito_stake_pool_acc = banks_client
+        .get_account_unwrapped(jito_stake_pool::ID)
+        .await;
+
+    let ixs = create_rebalance_donate_ixs(CreateRebalanceDonateIxsArgs {
+        jito_stake_pool_acc,
+        pool_state_acc,
+        lst_state_list_acc,
+        withdraw_jitosol_to_addr,
+        donate_msol_from_addr,
+        donate_msol_authority: mock_auth_kp.pubkey(),
+        jitosol_withdraw_amt: JITOSOL_WITHDRAW_AMT,
+        msol_donate_amt: TINY_MSOL_DONATE_AMT,
+        min_starting_src_lst: 0,
+        max_starting_dst_lst: u64::MAX,
+        min_ending_sol_value,
+    });
+
+    let mut tx = Transaction::new_with_payer(&ixs, Some(&payer.pubkey()));
+    tx.sign(&[&payer, &mock_auth_kp], last_blockhash);
+
+    let res = banks_client.process_transaction(tx).await;
+    (banks_client, res)
+}
+
+async fn get_rebalance_loss_state(banks_client: &mut BanksClient) -> RebalanceLossState {
+    let acc = banks_client.get_rebalance_loss_state_acc().await;
+    *try_rebalance_loss_state(&acc.data).unwrap()
+}
+
+#[tokio::test]
+async fn rebalance_loss_within_limit() {
+    let (mut banks_client, res) = process_lossy_rebalance(
+        Some(RebalanceLossState {
+            max_loss_bps_per_rebalance: 5_000,
+            max_loss_bps_per_epoch: 5_000,
+            padding: [0u8; 4],
+            epoch: 0,
+            epoch_loss: 0,
+            epoch_start_total_sol_value: 0,
+        }),
+        0,
+    )
+    .await;
+    res.unwrap();
+
+    let pool_state_acc = banks_client.get_pool_state_acc().await;
+    let pool_state = try_pool_state(&pool_state_acc.data).unwrap();
+    assert!(U8Bool(pool_state.is_rebalancing).is_false());
+
+    let RebalanceLossState {
+        epoch, epoch_loss, ..
+    } = get_rebalance_loss_state(&mut banks_client).await;
+    assert_eq!(epoch, JITO_STAKE_POOL_LAST_UPDATE_EPOCH);
+    assert!(epoch_loss > 0);
+    assert!(
+        epoch_loss
+            <= (LOSSY_REBALANCE_JITOSOL_START_SOL_VALUE + LOSSY_REBALANCE_MSOL_START_SOL_VALUE) / 2
+    );
+}
+
+#[tokio::test]
+async fn rebalance_fail_loss_without_rebalance_loss_state() {
+    // pools that have not opted into loss limits keep the original no loss rule
+    let (_banks_client, res) = process_lossy_rebalance(None, 0).await;
+    assert_custom_err(res.unwrap_err(), SControllerError::PoolWouldLoseSolValue);
+}
+
+#[tokio::test]
+async fn rebalance_fail_loss_exceeds_per_rebalance_limit() {
+    let (_banks_client, res) = process_lossy_rebalance(
+        Some(RebalanceLossState {
+            max_loss_bps_per_rebalance: 100,
+            max_loss_bps_per_epoch: 5_000,
+            padding: [0u8; 4],
+            epoch: 0,
+            epoch_loss: 0,
+            epoch_start_total_sol_value: 0,
+        }),
+        0,
+    )
+    .await;
+    assert_custom_err(res.unwrap_err(), SControllerError::PoolWouldLoseSolValue);
+}
+
+#[tokio::test]
+async fn rebalance_fail_loss_exceeds_epoch_limit() {
+    let (_banks_client, res) = process_lossy_rebalance(
+        Some(RebalanceLossState {
+            max_loss_bps_per_rebalance: 5_000,
+            max_loss_bps_per_epoch: 5_000,
+            padding: [0u8; 4],
+            epoch: JITO_STAKE_POOL_LAST_UPDATE_EPOCH,
+            epoch_loss: 900_000_000,
+            epoch_start_total_sol_value: LOSSY_REBALANCE_JITOSOL_START_SOL_VALUE
+                + LOSSY_REBALANCE_MSOL_START_SOL_VALUE,
+        }),
+        0,
+    )
+    .await;
+    assert_custom_err(res.unwrap_err(), SControllerError::PoolWouldLoseSolValue);
+}
+
+#[tokio::test]
+async fn rebalance_loss_epoch_limit_resets_on_new_epoch() {
+    const PREV_EPOCH_LOSS: u64 = 900_000_000;
+    const PREV_EPOCH_START_TOTAL_SOL_VALUE: u64 = 10_000_000_000;
+
+    let (mut banks_client, res) = process_lossy_rebalance(
+        Some(RebalanceLossState {
+            max_loss_bps_per_rebalance: 5_000,
+            max_loss_bps_per_epoch: 5_000,
+            padding: [0u8; 4],
+            epoch: JITO_STAKE_POOL_LAST_UPDATE_EPOCH - 1,
+            epoch_loss: PREV_EPOCH_LOSS,
+            epoch_start_total_sol_value: PREV_EPOCH_START_TOTAL_SOL_VALUE,
+        }),
+        0,
+    )
+    .await;
+    res.unwrap();
+
+    let RebalanceLossState {
+        epoch,
+        epoch_loss,
+        epoch_start_total_sol_value,
+        ..
+    } = get_rebalance_loss_state(&mut banks_client).await;
+    assert_eq!(epoch, JITO_STAKE_POOL_LAST_UPDATE_EPOCH);
+    assert!(epoch_loss > 0);
+    assert!(epoch_loss < PREV_EPOCH_LOSS);
+    assert!(epoch_start_total_sol_value < PREV_EPOCH_START_TOTAL_SOL_VALUE);
+    assert!(epoch_start_total_sol_value > epoch_loss);
+}
+
+#[tokio::test]
+async fn rebalance_loss_multiple_rebalances_in_epoch() {
+    const JITOSOL_START_SOL_VALUE: u64 = 10_000_000_000;
+    const MSOL_START_SOL_VALUE: u64 = 1_000_000_000;
+    const TINY_MSOL_DONATE_AMT: u64 = 100_000;
+    // Each withdrawal loses ~1.07x its amount in SOL value.
+    // The epoch limit is 50% of the epoch's starting total SOL value of ~12 SOL:
+    // - the first 2 rebalances lose ~5.4 SOL in total, which is within the limit.
+    //   The second would have exceeded it if the limit were recomputed from
+    //   the ~8.7 SOL total SOL value remaining after the first.
+    // - the third would bring the total loss to ~6.4 SOL, exceeding the limit
+    const JITOSOL_WITHDRAW_AMTS: [u64; 3] = [3_000_000_000, 2_000_000_000, 1_000_000_000];
+
+    let mock_auth_kp =
+        read_keypair_file(test_fixtures_dir().join("s-controller-test-initial-authority-key.json"))
+            .unwrap();
+
+    let mut program_test = jito_marinade_no_fee_program_test(JitoMarinadeProgramTestArgs {
+        jitosol_sol_value: JITOSOL_START_SOL_VALUE,
+        msol_sol_value: MSOL_START_SOL_VALUE,
+        jitosol_reserves: JITOSOL_START_SOL_VALUE,
+        msol_reserves: MSOL_START_SOL_VALUE,
+        jitosol_protocol_fee_accumulator: 0,
+        msol_protocol_fee_accumulator: 0,
+        lp_token_mint: Pubkey::new_unique(),
+        lp_token_supply: 0,
+    })
+    .add_s_program()
+    .add_rebalance_loss_state(RebalanceLossState {
+        max_loss_bps_per_rebalance: 5_000,
+        max_loss_bps_per_epoch: 5_000,
+        padding: [0u8; 4],
+        epoch: 0,
+        epoch_loss: 0,
+        epoch_start_total_sol_value: 0,
+    });
+
+    let withdraw_jitosol_to_addr = program_test.gen_and_add_token_account(MockTokenAccountArgs {
+        mint: jitosol::ID,
+        authority: mock_auth_kp.pubkey(),
+        amount: 0,
+    });
+    let donate_msol_from_addr = program_test.gen_and_add_token_account(MockTokenAccountArgs {
+        mint: msol::ID,
+        authority: mock_auth_kp.pubkey(),
+        amount: TINY_MSOL_DONATE_AMT * JITOSOL_WITHDRAW_AMTS.len() as u64,
+    });
+
+    let ctx = program_test.start_with_context().await;
+    ctx.set_sysvar(&Clock {
+        epoch: JITO_STAKE_POOL_LAST_UPDATE_EPOCH,
+        ..Default::default()
+    });
+    let ProgramTestContext {
+        mut banks_client,
+        last_blockhash,
+        payer,
+        ..
+    } = ctx;
+
+    let mut epoch_start_total_sol_value = None;
+    let mut prev_epoch_loss = 0;
+    for (i, jitosol_withdraw_amt) in JITOSOL_WITHDRAW_AMTS.into_iter().enumerate() {
+        let lst_state_list_acc = banks_client.get_lst_state_list_acc().await;
+        let pool_state_acc = banks_client.get_pool_state_acc().await;
+        let old_total_sol_value = try_pool_state(&pool_state_acc.data)
+            .unwrap()
+            .total_sol_value;
+        let jito_stake_pool_acc = banks_client
+            .get_account_unwrapped(jito_stake_pool::ID)
+            .await;
+
+        let ixs = create_rebalance_donate_ixs(CreateRebalanceDonateIxsArgs {
+            jito_stake_pool_acc,
+            pool_state_acc,
+            lst_state_list_acc,
+            withdraw_jitosol_to_addr,
+            donate_msol_from_addr,
+            donate_msol_authority: mock_auth_kp.pubkey(),
+            jitosol_withdraw_amt,
+            msol_donate_amt: TINY_MSOL_DONATE_AMT,
+            min_starting_src_lst: 0,
+            max_starting_dst_lst: u64::MAX,
+            min_ending_sol_value: 0,
+        });
+        let mut tx = Transaction::new_with_payer(&ixs, Some(&payer.pubkey()));
+        tx.sign(&[&payer, &mock_auth_kp], last_blockhash);
+        let res = banks_client.process_transaction(tx).await;
+
+        if i == JITOSOL_WITHDRAW_AMTS.len() - 1 {
+            assert_custom_err(res.unwrap_err(), SControllerError::PoolWouldLoseSolValue);
+            break;
+        }
+        res.unwrap();
+
+        let RebalanceLossState {
+            epoch,
+            epoch_loss,
+            epoch_start_total_sol_value: snapshot,
+            ..
+        } = get_rebalance_loss_state(&mut banks_client).await;
+        assert_eq!(epoch, JITO_STAKE_POOL_LAST_UPDATE_EPOCH);
+        assert!(epoch_loss > prev_epoch_loss);
+        assert!(epoch_loss <= snapshot / 2);
+        if let Some(expected) = epoch_start_total_sol_value {
+            assert_eq!(snapshot, expected);
+            // more than the epoch limit allows if computed from the fallen total SOL value
+            assert!(epoch_loss > old_total_sol_value / 2);
+        }
+        epoch_start_total_sol_value = Some(snapshot);
+        prev_epoch_loss = epoch_loss;
+    }
+}
+
+#[tokio::test]
+async fn rebalance_fail_min_ending_sol_value_above_limit() {
+    let (_banks_client, res) = process_lossy_rebalance(
+        Some(RebalanceLossState {
+            max_loss_bps_per_rebalance: 5_000,
+            max_loss_bps_per_epoch: 5_000,
+            padding: [0u8; 4],
+            epoch: 0,
+            epoch_loss: 0,
+            epoch_start_total_sol_value: 0,
+        }),
+        LOSSY_REBALANCE_JITOSOL_START_SOL_VALUE + LOSSY_REBALANCE_MSOL_START_SOL_VALUE,
+    )
+    .await;
+    assert_custom_err(res.unwrap_err(), SControllerError::PoolWouldLoseSolValue);
+}
@@ -0,0 +1,207 @@
+use s_controller_interface::SControllerError;
+use s_controller_lib::{
+    remove_liquidity_exact_out_ix_by_mint_full, AddRemoveLiquidityAccountSuffixes,
+    RemoveLiquidityByMintFreeArgs, RemoveLiquidityExactOutIxAmts,
+};
+use s_controller_test_utils::{
+    jito_marinade_no_fee_program_test, GenAndAddTokenAccountProgramTest,
+    JitoMarinadeProgramTestArgs, LstStateListBanksClient, PoolStateBanksClient,
+};
+use sanctum_solana_test_utils::{
+    assert_custom_err, token::MockTokenAccountArgs, ExtendedBanksClient,
+};
+use sanctum_token_lib::{token_account_balance, MintWithTokenProgram};
+use solana_program::{clock::Clock, instruction::AccountMeta, pubkey::Pubkey};
+use solana_program_test::{BanksClient, ProgramTestContext};
+use solana_readonly_account::sdk::KeyedAccount;
+use solana_sdk::{
+    hash::Hash, instruction::Instruction, signature::Keypair, signer::Signer,
+    transaction::Transaction,
+};
+use spl_calculator_lib::SplLstSolCommonFreeArgsConst;
+use test_utils::{jito_stake_pool, jitosol, JITO_STAKE_POOL_LAST_UPDATE_EPOCH};
+
+use crate::common::SControllerProgramTest;
+
+const JITOSOL_STARTING_POOL_RESERVES: u64 = 10_000_000_000;
+const LP_TOKEN_SUPPLY: u64 = 10_000_000_000;
+const LIQUIDITY_PROVIDER_LP_TOKEN_BALANCE: u64 = LP_TOKEN_SUPPLY;
+const JITOSOL_TO_RECEIVE: u64 = 1_000_000_000;
+
+struct ExactOutTestSetup {
+    banks_client: BanksClient,
+    payer: Keypair,
+    last_blockhash: Hash,
+    liquidity_provider: Keypair,
+    liquidity_provider_jitosol_acc_addr: Pubkey,
+    liquidity_provider_lp_token_acc_addr: Pubkey,
+}
+
+async fn setup() -> ExactOutTestSetup {
+    let liquidity_provider = Keypair::new();
+    let lp_token_mint = Pubkey::new_unique();
+
+    let mut program_test = jito_marinade_no_fee_program_test(JitoMarinadeProgramTestArgs {
+        jitosol_reserves: JITOSOL_STARTING_POOL_RESERVES,
+        jitosol_sol_value: JITOSOL_STARTING_POOL_RESERVES, // updated on sync
+        msol_reserves: 0,
+        msol_sol_value: 0,
+        jitosol_protocol_fee_accumulator: 0,
+        msol_protocol_fee_accumulator: 0,
+        lp_token_mint,
+        lp_token_supply: LP_TOKEN_SUPPLY,
+    })
+    .add_s_program();
+    let liquidity_provider_jitosol_acc_addr =
+        program_test.gen_and_add_token_account(MockTokenAccountArgs {
+            mint: jitosol::ID,
+            authority: liquidity_provider.pubkey(),
+            amount: 0,
+        });
+    let liquidity_provider_lp_token_acc_addr =
+        program_test.gen_and_add_token_account(MockTokenAccountArgs {
+            mint: lp_token_mint,
+            authority: liquidity_provider.pubkey(),
+            amount: LIQUIDITY_PROVIDER_LP_TOKEN_BALANCE,
+        });
+
+    let ctx = program_test.start_with_context().await;
+    ctx.set_sysvar(&Clock {
+        epoch: JITO_STAKE_POOL_LAST_UPDATE_EPOCH,
+        ..Default::default()
+    });
+    let ProgramTestContext {
+        banks_client,
+        last_blockhash,
+        payer,
+        ..
+    } = ctx;
+
+    ExactOutTestSetup {
+        banks_client,
+        payer,
+        last_blockhash,
+        liquidity_provider,
+        liquidity_provider_jitosol_acc_addr,
+        liquidity_provider_lp_token_acc_addr,
+    }
+}
+
+async fn remove_liquidity_exact_out_ix(
+    banks_client: &mut BanksClient,
+    liquidity_provider: Pubkey,
+    src_lp_acc: Pubkey,
+    dst_lst_acc: Pubkey,
+    max_lp_in: u64,
+) -> Instruction {
+    let jito_stake_pool_acc = banks_client
+        .get_account_unwrapped(jito_stake_pool::ID)
+        .await;
+    let jito_sol_val_calc_accounts = SplLstSolCommonFreeArgsConst {
+        spl_stake_pool: KeyedAccount {
+            pubkey: jito_stake_pool::ID,
+            account: jito_stake_pool_acc,
+        },
+    }
+    .resolve_spl_to_account_metas()
+    .unwrap();
+    let pool_state_account = banks_client.get_pool_state_acc().await;
+    let lst_state_list_account = banks_client.get_lst_state_list_acc().await;
+
+    remove_liquidity_exact_out_ix_by_mint_full(
+        RemoveLiquidityByMintFreeArgs {
+            signer: liquidity_provider,
+            src_lp_acc,
+            dst_lst_acc,
+            pool_state: pool_state_account,
+            lst_state_list: &lst_state_list_account,
+            lst_mint: MintWithTokenProgram {
+                pubkey: jitosol::ID,
+                token_program: spl_token::ID,
+            },
+        },
+        RemoveLiquidityExactOutIxAmts {
+            lst_amount: JITOSOL_TO_RECEIVE,
+            max_lp_in,
+        },
+        AddRemoveLiquidityAccountSuffixes {
+            lst_calculator_accounts: &jito_sol_val_calc_accounts,
+            pricing_program_price_lp_accounts: &[AccountMeta::new_readonly(jitosol::ID, false)],
+        },
+    )
+    .unwrap()
+}
+
+#[tokio::test]
+async fn basic_remove_liquidity_exact_out_no_fee() {
+    // rounding up across lst_to_sol and LP token amount calculation
+    const TOLERANCE: u64 = 10;
+
+    let ExactOutTestSetup {
+        mut banks_client,
+        payer,
+        last_blockhash,
+        liquidity_provider,
+        liquidity_provider_jitosol_acc_addr,
+        liquidity_provider_lp_token_acc_addr,
+    } = setup().await;
+
+    let ix = remove_liquidity_exact_out_ix(
+        &mut banks_client,
+        liquidity_provider.pubkey(),
+        liquidity_provider_lp_token_acc_addr,
+        liquidity_provider_jitosol_acc_addr,
+        LIQUIDITY_PROVIDER_LP_TOKEN_BALANCE,
+    )
+    .await;
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &liquidity_provider], last_blockhash);
+
+    banks_client.process_transaction(tx).await.unwrap();
+
+    let jitosol_account = banks_client
+        .get_account_unwrapped(liquidity_provider_jitosol_acc_addr)
+        .await;
+    assert_eq!(
+        token_account_balance(jitosol_account).unwrap(),
+        JITOSOL_TO_RECEIVE
+    );
+
+    let lp_token_account = banks_client
+        .get_account_unwrapped(liquidity_provider_lp_token_acc_addr)
+        .await;
+    let lp_tokens_burnt =
+        LIQUIDITY_PROVIDER_LP_TOKEN_BALANCE - token_account_balance(lp_token_account).unwrap();
+    // pool is 1 jitoSOL : 1 LP token, so ~10% of LP token supply should be burnt
+    let expected = LP_TOKEN_SUPPLY / 10;
+    assert!(lp_tokens_burnt >= expected);
+    assert!(lp_tokens_burnt - expected <= TOLERANCE);
+}
+
+#[tokio::test]
+async fn fail_remove_liquidity_exact_out_slippage() {
+    let ExactOutTestSetup {
+        mut banks_client,
+        payer,
+        last_blockhash,
+        liquidity_provider,
+        liquidity_provider_jitosol_acc_addr,
+        liquidity_provider_lp_token_acc_addr,
+    } = setup().await;
+
+    let ix = remove_liquidity_exact_out_ix(
+        &mut banks_client,
+        liquidity_provider.pubkey(),
+        liquidity_provider_lp_token_acc_addr,
+        liquidity_provider_jitosol_acc_addr,
+        1,
+    )
+    .await;
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &liquidity_provider], last_blockhash);
+
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_custom_err(err, SControllerError::SlippageToleranceExceeded);
+}
@@ -0,0 +1,247 @@
+use marinade_calculator_lib::marinade_sol_val_calc_account_metas;
+use marinade_keys::msol;
+use s_controller_interface::SControllerError;
+use s_controller_lib::{
+    remove_liquidity_pro_rata_ix_by_mints_full, MultiLiquidityLst,
+    RemoveLiquidityProRataByMintsFreeArgs, RemoveLiquidityProRataIxAmts,
+};
+use s_controller_test_utils::{
+    jito_marinade_no_fee_program_test, GenAndAddTokenAccountProgramTest,
+    JitoMarinadeProgramTestArgs, LstStateListBanksClient, PoolStateBanksClient,
+};
+use sanctum_solana_test_utils::{
+    assert_custom_err, token::MockTokenAccountArgs, ExtendedBanksClient,
+};
+use sanctum_token_lib::{token_account_balance, MintWithTokenProgram};
+use solana_program::{clock::Clock, instruction::AccountMeta, pubkey::Pubkey};
+use solana_program_test::{BanksClient, ProgramTestContext};
+use solana_readonly_account::sdk::KeyedAccount;
+use solana_sdk::{
+    hash::Hash, instruction::Instruction, signature::Keypair, signer::Signer,
+    transaction::Transaction,
+};
+use spl_calculator_lib::SplLstSolCommonFreeArgsConst;
+use test_utils::{jito_stake_pool, jitosol, JITO_STAKE_POOL_LAST_UPDATE_EPOCH};
+
+use crate::common::SControllerProgramTest;
+
+const JITOSOL_STARTING_POOL_RESERVES: u64 = 10_000_000_000;
+const MSOL_STARTING_POOL_RESERVES: u64 = 10_000_000_000;
+const LP_TOKEN_SUPPLY: u64 = 20_000_000_000;
+/// 10% of LP token supply
+const LP_TOKENS_TO_REMOVE: u64 = 2_000_000_000;
+
+struct ProRataTestSetup {
+    banks_client: BanksClient,
+    payer: Keypair,
+    last_blockhash: Hash,
+    liquidity_provider: Keypair,
+    liquidity_provider_jitosol_acc_addr: Pubkey,
+    liquidity_provider_msol_acc_addr: Pubkey,
+    liquidity_provider_lp_token_acc_addr: Pubkey,
+}
+
+async fn setup() -> ProRataTestSetup {
+    let liquidity_provider = Keypair::new();
+    let lp_token_mint = Pubkey::new_unique();
+
+    let mut program_test = jito_marinade_no_fee_program_test(JitoMarinadeProgramTestArgs {
+        jitosol_reserves: JITOSOL_STARTING_POOL_RESERVES,
+        msol_reserves: MSOL_STARTING_POOL_RESERVES,
+        jitosol_sol_value: JITOSOL_STARTING_POOL_RESERVES, // updated on sync
+        msol_sol_value: MSOL_STARTING_POOL_RESERVES,       // updated on sync
+        jitosol_protocol_fee_accumulator: 0,
+        msol_protocol_fee_accumulator: 0,
+        lp_token_mint,
+        lp_token_supply: LP_TOKEN_SUPPLY,
+    })
+    .add_s_program();
+    let liquidity_provider_jitosol_acc_addr =
+        program_test.gen_and_add_token_account(MockTokenAccountArgs {
+            mint: jitosol::ID,
+            authority: liquidity_provider.pubkey(),
+            amount: 0,
+        });
+    let liquidity_provider_msol_acc_addr =
+        program_test.gen_and_add_token_account(MockTokenAccountArgs {
+            mint: msol::ID,
+            authority: liquidity_provider.pubkey(),
+            amount: 0,
+        });
+    let liquidity_provider_lp_token_acc_addr =
+        program_test.gen_and_add_token_account(MockTokenAccountArgs {
+            mint: lp_token_mint,
+            authority: liquidity_provider.pubkey(),
+            amount: LP_TOKENS_TO_REMOVE,
+        });
+
+    let ctx = program_test.start_with_context().await;
+    ctx.set_sysvar(&Clock {
+        epoch: JITO_STAKE_POOL_LAST_UPDATE_EPOCH,
+        ..Default::default()
+    });
+    let ProgramTestContext {
+        banks_client,
+        last_blockhash,
+        payer,
+        ..
+    } = ctx;
+
+    ProRataTestSetup {
+        banks_client,
+        payer,
+        last_blockhash,
+        liquidity_provider,
+        liquidity_provider_jitosol_acc_addr,
+        liquidity_provider_msol_acc_addr,
+        liquidity_provider_lp_token_acc_addr,
+    }
+}
+
+async fn remove_liquidity_pro_rata_ix(
+    banks_client: &mut BanksClient,
+    liquidity_provider: Pubkey,
+    src_lp_acc: Pubkey,
+    lst_accs: [Pubkey; 2],
+    min_lst_outs: &[u64],
+) -> Instruction {
+    let jito_stake_pool_acc = banks_client
+        .get_account_unwrapped(jito_stake_pool::ID)
+        .await;
+    let jito_sol_val_calc_accounts = SplLstSolCommonFreeArgsConst {
+        spl_stake_pool: KeyedAccount {
+            pubkey: jito_stake_pool::ID,
+            account: jito_stake_pool_acc,
+        },
+    }
+    .resolve_spl_to_account_metas()
+    .unwrap();
+    let marinade_sol_val_calc_accounts = marinade_sol_val_calc_account_metas();
+    let pool_state_account = banks_client.get_pool_state_acc().await;
+    let lst_state_list_account = banks_client.get_lst_state_list_acc().await;
+
+    let [jitosol_acc, msol_acc] = lst_accs;
+    // in lst_state_list order
+    let lsts = [
+        MultiLiquidityLst {
+            lst_mint: MintWithTokenProgram {
+                pubkey: jitosol::ID,
+                token_program: spl_token::ID,
+            },
+            lst_acc: jitosol_acc,
+        },
+        MultiLiquidityLst {
+            lst_mint: MintWithTokenProgram {
+                pubkey: msol::ID,
+                token_program: spl_token::ID,
+            },
+            lst_acc: msol_acc,
+        },
+    ];
+    remove_liquidity_pro_rata_ix_by_mints_full(
+        RemoveLiquidityProRataByMintsFreeArgs {
+            signer: liquidity_provider,
+            src_lp_acc,
+            pool_state: pool_state_account,
+            lst_state_list: lst_state_list_account,
+            lsts: &lsts,
+        },
+        RemoveLiquidityProRataIxAmts {
+            lp_token_amount: LP_TOKENS_TO_REMOVE,
+            min_lst_outs,
+        },
+        &[&jito_sol_val_calc_accounts, &marinade_sol_val_calc_accounts],
+        &[
+            &[AccountMeta::new_readonly(jitosol::ID, false)],
+            &[AccountMeta::new_readonly(msol::ID, false)],
+        ],
+    )
+    .unwrap()
+}
+
+#[tokio::test]
+async fn basic_remove_liquidity_pro_rata_no_fee() {
+    // rounding down across share calculation and sol_to_lst
+    const TOLERANCE: u64 = 10;
+
+    let ProRataTestSetup {
+        mut banks_client,
+        payer,
+        last_blockhash,
+        liquidity_provider,
+        liquidity_provider_jitosol_acc_addr,
+        liquidity_provider_msol_acc_addr,
+        liquidity_provider_lp_token_acc_addr,
+    } = setup().await;
+
+    let ix = remove_liquidity_pro_rata_ix(
+        &mut banks_client,
+        liquidity_provider.pubkey(),
+        liquidity_provider_lp_token_acc_addr,
+        [
+            liquidity_provider_jitosol_acc_addr,
+            liquidity_provider_msol_acc_addr,
+        ],
+        &[0, 0],
+    )
+    .await;
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &liquidity_provider], last_blockhash);
+
+    banks_client.process_transaction(tx).await.unwrap();
+
+    let lp_token_account = banks_client
+        .get_account_unwrapped(liquidity_provider_lp_token_acc_addr)
+        .await;
+    assert_eq!(token_account_balance(lp_token_account).unwrap(), 0);
+
+    // 10% of LP token supply should redeem ~10% of each LST's reserves
+    for (lst_acc, starting_pool_reserves) in [
+        (
+            liquidity_provider_jitosol_acc_addr,
+            JITOSOL_STARTING_POOL_RESERVES,
+        ),
+        (
+            liquidity_provider_msol_acc_addr,
+            MSOL_STARTING_POOL_RESERVES,
+        ),
+    ] {
+        let lst_account = banks_client.get_account_unwrapped(lst_acc).await;
+        let lst_received = token_account_balance(lst_account).unwrap();
+        let expected = starting_pool_reserves / 10;
+        assert!(lst_received <= expected);
+        assert!(expected - lst_received <= TOLERANCE);
+    }
+}
+
+#[tokio::test]
+async fn fail_remove_liquidity_pro_rata_slippage() {
+    let ProRataTestSetup {
+        mut banks_client,
+        payer,
+        last_blockhash,
+        liquidity_provider,
+        liquidity_provider_jitosol_acc_addr,
+        liquidity_provider_msol_acc_addr,
+        liquidity_provider_lp_token_acc_addr,
+    } = setup().await;
+
+    let ix = remove_liquidity_pro_rata_ix(
+        &mut banks_client,
+        liquidity_provider.pubkey(),
+        liquidity_provider_lp_token_acc_addr,
+        [
+            liquidity_provider_jitosol_acc_addr,
+            liquidity_provider_msol_acc_addr,
+        ],
+        &[u64::MAX, 0],
+    )
+    .await;
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &liquidity_provider], last_blockhash);
+
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_custom_err(err, SControllerError::SlippageToleranceExceeded);
+}
@@ -0,0 +1,217 @@
+use marinade_calculator_lib::marinade_sol_val_calc_account_metas;
+use marinade_keys::msol;
+use s_controller_interface::SControllerError;
+use s_controller_lib::{
+    set_lst_cap_ix_by_mint_full, swap_exact_in_ix_by_mint_full, SetLstCapByMintFreeArgs,
+    SrcDstLstSolValueCalcAccountSuffixes, SwapByMintsFreeArgs, SwapExactInAmounts,
+};
+use s_controller_test_utils::{
+    jito_marinade_no_fee_program_test, GenAndAddTokenAccountProgramTest,
+    JitoMarinadeProgramTestArgs, LstStateListBanksClient, PoolStateBanksClient,
+};
+use sanctum_solana_test_utils::{
+    assert_custom_err, test_fixtures_dir, token::MockTokenAccountArgs, ExtendedBanksClient,
+};
+use sanctum_token_lib::MintWithTokenProgram;
+use solana_program::{clock::Clock, instruction::AccountMeta, pubkey::Pubkey};
+use solana_program_test::ProgramTestContext;
+use solana_readonly_account::sdk::KeyedAccount;
+use solana_sdk::{
+    signature::{read_keypair_file, Keypair},
+    signer::Signer,
+    transaction::Transaction,
+};
+use spl_calculator_lib::SplLstSolCommonFreeArgsConst;
+use test_utils::{jito_stake_pool, jitosol, JITO_STAKE_POOL_LAST_UPDATE_EPOCH};
+
+use crate::common::*;
+
+#[tokio::test]
+async fn basic_set_lst_cap() {
+    const MAX_SHARE_BPS: u16 = 5_000;
+
+    let mock_auth_kp =
+        read_keypair_file(test_fixtures_dir().join("s-controller-test-initial-authority-key.json"))
+            .unwrap();
+    let program_test = jito_marinade_no_fee_program_test(
+        JitoMarinadeProgramTestArgs::default().with_lp_token_mint(Pubkey::new_unique()),
+    )
+    .add_s_program();
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    assert_eq!(
+        banks_client.get_lst_state(jitosol::ID).await.max_share_bps,
+        0
+    );
+
+    let ix = set_lst_cap_ix_by_mint_full(
+        &SetLstCapByMintFreeArgs {
+            lst_mint: jitosol::ID,
+            pool_state: banks_client.get_pool_state_acc().await,
+            lst_state_list: banks_client.get_lst_state_list_acc().await,
+        },
+        MAX_SHARE_BPS,
+    )
+    .unwrap();
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &mock_auth_kp], last_blockhash);
+
+    banks_client.process_transaction(tx).await.unwrap();
+
+    assert_eq!(
+        banks_client.get_lst_state(jitosol::ID).await.max_share_bps,
+        MAX_SHARE_BPS
+    );
+    assert_eq!(banks_client.get_lst_state(msol::ID).await.max_share_bps, 0);
+}
+
+#[tokio::test]
+async fn fail_set_lst_cap_over_100_pct() {
+    let mock_auth_kp =
+        read_keypair_file(test_fixtures_dir().join("s-controller-test-initial-authority-key.json"))
+            .unwrap();
+    let program_test = jito_marinade_no_fee_program_test(
+        JitoMarinadeProgramTestArgs::default().with_lp_token_mint(Pubkey::new_unique()),
+    )
+    .add_s_program();
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let ix = set_lst_cap_ix_by_mint_full(
+        &SetLstCapByMintFreeArgs {
+            lst_mint: jitosol::ID,
+            pool_state: banks_client.get_pool_state_acc().await,
+            lst_state_list: banks_client.get_lst_state_list_acc().await,
+        },
+        10_001,
+    )
+    .unwrap();
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &mock_auth_kp], last_blockhash);
+
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_custom_err(err, SControllerError::LstCapTooHigh);
+}
+
+#[tokio::test]
+async fn fail_swap_exact_in_over_src_lst_cap() {
+    const JITOSOL_STARTING_POOL_RESERVES: u64 = 10_000_000_000;
+    const MSOL_STARTING_POOL_RESERVES: u64 = 10_000_000_000;
+    const MSOL_TO_SWAP_IN: u64 = 1_000_000_000;
+    // mSOL starts at ~51% of the pool, swapping 1 mSOL in pushes it to ~56%
+    const MSOL_MAX_SHARE_BPS: u16 = 5_300;
+
+    let mock_auth_kp =
+        read_keypair_file(test_fixtures_dir().join("s-controller-test-initial-authority-key.json"))
+            .unwrap();
+    let swapper = Keypair::new();
+
+    let mut program_test = jito_marinade_no_fee_program_test(JitoMarinadeProgramTestArgs {
+        jitosol_reserves: JITOSOL_STARTING_POOL_RESERVES,
+        msol_reserves: MSOL_STARTING_POOL_RESERVES,
+        jitosol_sol_value: JITOSOL_STARTING_POOL_RESERVES, // updated on sync
+        msol_sol_value: MSOL_STARTING_POOL_RESERVES,       // updated on sync
+        // dont cares
+        jitosol_protocol_fee_accumulator: 0,
+        msol_protocol_fee_accumulator: 0,
+        lp_token_mint: Pubkey::new_unique(),
+        lp_token_supply: 0,
+    })
+    .add_s_program();
+
+    let swapper_jitosol_acc_addr = program_test.gen_and_add_token_account(MockTokenAccountArgs {
+        mint: jitosol::ID,
+        authority: swapper.pubkey(),
+        amount: 0,
+    });
+    let swapper_msol_acc_addr = program_test.gen_and_add_token_account(MockTokenAccountArgs {
+        mint: msol::ID,
+        authority: swapper.pubkey(),
+        amount: MSOL_TO_SWAP_IN,
+    });
+
+    let ctx = program_test.start_with_context().await;
+    ctx.set_sysvar(&Clock {
+        epoch: JITO_STAKE_POOL_LAST_UPDATE_EPOCH,
+        ..Default::default()
+    });
+    let ProgramTestContext {
+        mut banks_client,
+        last_blockhash,
+        payer,
+        ..
+    } = ctx;
+
+    let ix = set_lst_cap_ix_by_mint_full(
+        &SetLstCapByMintFreeArgs {
+            lst_mint: msol::ID,
+            pool_state: banks_client.get_pool_state_acc().await,
+            lst_state_list: banks_client.get_lst_state_list_acc().await,
+        },
+        MSOL_MAX_SHARE_BPS,
+    )
+    .unwrap();
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &mock_auth_kp], last_blockhash);
+    banks_client.process_transaction(tx).await.unwrap();
+
+    let lst_state_list_account = banks_client.get_lst_state_list_acc().await;
+    let jito_stake_pool_acc = banks_client
+        .get_account_unwrapped(jito_stake_pool::ID)
+        .await;
+    let jito_sol_val_calc_accounts = SplLstSolCommonFreeArgsConst {
+        spl_stake_pool: KeyedAccount {
+            pubkey: jito_stake_pool::ID,
+            account: jito_stake_pool_acc,
+        },
+    }
+    .resolve_spl_to_account_metas()
+    .unwrap();
+    let marinade_sol_val_calc_accounts = marinade_sol_val_calc_account_metas();
+
+    let ix = swap_exact_in_ix_by_mint_full(
+        SwapByMintsFreeArgs {
+            signer: swapper.pubkey(),
+            src_lst_acc: swapper_msol_acc_addr,
+            dst_lst_acc: swapper_jitosol_acc_addr,
+            src_lst_mint: MintWithTokenProgram {
+                pubkey: msol::ID,
+                token_program: spl_token::ID,
+            },
+            dst_lst_mint: MintWithTokenProgram {
+                pubkey: jitosol::ID,
+                token_program: spl_token::ID,
+            },
+            lst_state_list: lst_state_list_account,
+        },
+        SwapExactInAmounts {
+            min_amount_out: 0,
+            amount: MSOL_TO_SWAP_IN,
+        },
+        SrcDstLstSolValueCalcAccountSuffixes {
+            dst_lst_calculator_accounts: &jito_sol_val_calc_accounts,
+            src_lst_calculator_accounts: &marinade_sol_val_calc_accounts,
+        },
+        &[
+            AccountMeta {
+                pubkey: msol::ID,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: jitosol::ID,
+                is_signer: false,
+                is_writable: false,
+            },
+        ],
+        no_fee_pricing_program::ID,
+    )
+    .unwrap();
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &swapper], last_blockhash);
+
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_custom_err(err, SControllerError::LstCapExceeded);
+}
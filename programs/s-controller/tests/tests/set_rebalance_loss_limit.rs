@@ -0,0 +1,226 @@
+use s_controller_interface::{
+    set_rebalance_loss_limit_ix, PoolState, RebalanceLossState, SControllerError,
+    SetRebalanceLossLimitIxArgs, SetRebalanceLossLimitKeys,
+};
+use s_controller_lib::{
+    program::{POOL_STATE_ID, REBALANCE_LOSS_STATE_ID},
+    try_rebalance_loss_state, SetRebalanceLossLimitFreeArgs,
+};
+use s_controller_test_utils::{
+    MockPoolState, PoolStateProgramTest, RebalanceLossStateBanksClient,
+    RebalanceLossStateProgramTest, DEFAULT_POOL_STATE,
+};
+use sanctum_solana_test_utils::{
+    assert_custom_err, assert_program_error, test_fixtures_dir, IntoAccount,
+};
+use solana_program::{instruction::Instruction, program_error::ProgramError, pubkey::Pubkey};
+use solana_program_test::{BanksClient, ProgramTest};
+use solana_readonly_account::sdk::KeyedAccount;
+use solana_sdk::{
+    signature::{read_keypair_file, Keypair},
+    signer::Signer,
+    system_program,
+    transaction::Transaction,
+};
+
+use crate::common::SControllerProgramTest;
+
+const TIMELOCKED_POOL_STATE: PoolState = PoolState {
+    timelock_delay_hours: 24,
+    ..DEFAULT_POOL_STATE
+};
+
+const EXISTING_REBALANCE_LOSS_STATE: RebalanceLossState = RebalanceLossState {
+    max_loss_bps_per_rebalance: 10,
+    max_loss_bps_per_epoch: 50,
+    padding: [0u8; 4],
+    epoch: 3,
+    epoch_loss: 1_000,
+    epoch_start_total_sol_value: 1_000_000,
+};
+
+fn set_rebalance_loss_limit_ix_for(
+    payer: Pubkey,
+    pool_state: PoolState,
+    max_loss_bps_per_rebalance: u16,
+    max_loss_bps_per_epoch: u16,
+) -> Instruction {
+    set_rebalance_loss_limit_ix(
+        SetRebalanceLossLimitFreeArgs {
+            payer,
+            pool_state: KeyedAccount {
+                pubkey: POOL_STATE_ID,
+                account: MockPoolState(pool_state).into_account(),
+            },
+        }
+        .resolve()
+        .unwrap(),
+        SetRebalanceLossLimitIxArgs {
+            max_loss_bps_per_rebalance,
+            max_loss_bps_per_epoch,
+        },
+    )
+    .unwrap()
+}
+
+async fn get_rebalance_loss_state(banks_client: &mut BanksClient) -> RebalanceLossState {
+    let acc = banks_client.get_rebalance_loss_state_acc().await;
+    *try_rebalance_loss_state(&acc.data).unwrap()
+}
+
+#[tokio::test]
+async fn set_rebalance_loss_limit_init() {
+    let mock_auth_kp =
+        read_keypair_file(test_fixtures_dir().join("s-controller-test-initial-authority-key.json"))
+            .unwrap();
+
+    let program_test = ProgramTest::default()
+        .add_s_program()
+        .add_pool_state(DEFAULT_POOL_STATE);
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let ix = set_rebalance_loss_limit_ix_for(payer.pubkey(), DEFAULT_POOL_STATE, 5, 20);
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &mock_auth_kp], last_blockhash);
+    banks_client.process_transaction(tx).await.unwrap();
+
+    let acc = banks_client.get_rebalance_loss_state_acc().await;
+    assert_eq!(acc.owner, s_controller_lib::program::ID);
+    let RebalanceLossState {
+        max_loss_bps_per_rebalance,
+        max_loss_bps_per_epoch,
+        epoch,
+        epoch_loss,
+        ..
+    } = *try_rebalance_loss_state(&acc.data).unwrap();
+    assert_eq!(max_loss_bps_per_rebalance, 5);
+    assert_eq!(max_loss_bps_per_epoch, 20);
+    assert_eq!(epoch, 0);
+    assert_eq!(epoch_loss, 0);
+}
+
+#[tokio::test]
+async fn set_rebalance_loss_limit_update_preserves_epoch_loss() {
+    let mock_auth_kp =
+        read_keypair_file(test_fixtures_dir().join("s-controller-test-initial-authority-key.json"))
+            .unwrap();
+
+    let program_test = ProgramTest::default()
+        .add_s_program()
+        .add_pool_state(DEFAULT_POOL_STATE)
+        .add_rebalance_loss_state(EXISTING_REBALANCE_LOSS_STATE);
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let ix = set_rebalance_loss_limit_ix_for(payer.pubkey(), DEFAULT_POOL_STATE, 100, 200);
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &mock_auth_kp], last_blockhash);
+    banks_client.process_transaction(tx).await.unwrap();
+
+    assert_eq!(
+        get_rebalance_loss_state(&mut banks_client).await,
+        RebalanceLossState {
+            max_loss_bps_per_rebalance: 100,
+            max_loss_bps_per_epoch: 200,
+            ..EXISTING_REBALANCE_LOSS_STATE
+        }
+    );
+}
+
+#[tokio::test]
+async fn set_rebalance_loss_limit_timelocked_lower() {
+    let mock_auth_kp =
+        read_keypair_file(test_fixtures_dir().join("s-controller-test-initial-authority-key.json"))
+            .unwrap();
+
+    let program_test = ProgramTest::default()
+        .add_s_program()
+        .add_pool_state(TIMELOCKED_POOL_STATE)
+        .add_rebalance_loss_state(EXISTING_REBALANCE_LOSS_STATE);
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let ix = set_rebalance_loss_limit_ix_for(payer.pubkey(), TIMELOCKED_POOL_STATE, 0, 50);
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &mock_auth_kp], last_blockhash);
+    banks_client.process_transaction(tx).await.unwrap();
+
+    assert_eq!(
+        get_rebalance_loss_state(&mut banks_client).await,
+        RebalanceLossState {
+            max_loss_bps_per_rebalance: 0,
+            ..EXISTING_REBALANCE_LOSS_STATE
+        }
+    );
+}
+
+#[tokio::test]
+async fn set_rebalance_loss_limit_fail_timelocked_raise() {
+    let mock_auth_kp =
+        read_keypair_file(test_fixtures_dir().join("s-controller-test-initial-authority-key.json"))
+            .unwrap();
+
+    let program_test = ProgramTest::default()
+        .add_s_program()
+        .add_pool_state(TIMELOCKED_POOL_STATE)
+        .add_rebalance_loss_state(EXISTING_REBALANCE_LOSS_STATE);
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let ix = set_rebalance_loss_limit_ix_for(payer.pubkey(), TIMELOCKED_POOL_STATE, 10, 51);
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &mock_auth_kp], last_blockhash);
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+
+    assert_custom_err(err, SControllerError::PoolTimelocked);
+    assert_eq!(
+        get_rebalance_loss_state(&mut banks_client).await,
+        EXISTING_REBALANCE_LOSS_STATE
+    );
+}
+
+#[tokio::test]
+async fn set_rebalance_loss_limit_fail_bps_too_large() {
+    let mock_auth_kp =
+        read_keypair_file(test_fixtures_dir().join("s-controller-test-initial-authority-key.json"))
+            .unwrap();
+
+    let program_test = ProgramTest::default()
+        .add_s_program()
+        .add_pool_state(DEFAULT_POOL_STATE);
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let ix = set_rebalance_loss_limit_ix_for(payer.pubkey(), DEFAULT_POOL_STATE, 5, 10_001);
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &mock_auth_kp], last_blockhash);
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+
+    assert_program_error(err, ProgramError::InvalidArgument);
+}
+
+#[tokio::test]
+async fn set_rebalance_loss_limit_fail_unauthorized() {
+    let program_test = ProgramTest::default()
+        .add_s_program()
+        .add_pool_state(DEFAULT_POOL_STATE);
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let rando_kp = Keypair::new();
+    let ix = set_rebalance_loss_limit_ix(
+        SetRebalanceLossLimitKeys {
+            payer: payer.pubkey(),
+            admin: rando_kp.pubkey(),
+            pool_state: POOL_STATE_ID,
+            rebalance_loss_state: REBALANCE_LOSS_STATE_ID,
+            system_program: system_program::ID,
+        },
+        SetRebalanceLossLimitIxArgs {
+            max_loss_bps_per_rebalance: 5,
+            max_loss_bps_per_epoch: 20,
+        },
+    )
+    .unwrap();
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &rando_kp], last_blockhash);
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+
+    // InvalidArgument thrown by mismatch keys in *_verify_account_keys()
+    assert_program_error(err, ProgramError::InvalidArgument);
+}
@@ -0,0 +1,342 @@
+use marinade_calculator_lib::marinade_sol_val_calc_account_metas;
+use marinade_keys::msol;
+use s_controller_interface::SControllerError;
+use s_controller_lib::{
+    swap_exact_in_route_ix_by_mints_full, swap_exact_in_route_ix_full, try_find_lst_mint_on_list,
+    try_lst_state_list, try_pool_state, LstSolValueCalcAccounts, SwapExactInAmounts,
+    SwapExactInRouteFreeArgs, SwapExactInRouteIxFullArgs, SwapRouteByMintsFreeArgs,
+    SwapRouteLstFreeArgs,
+};
+use s_controller_test_utils::{
+    jito_marinade_no_fee_program_test, GenAndAddTokenAccountProgramTest,
+    JitoMarinadeProgramTestArgs, LstStateListBanksClient, PoolStateBanksClient,
+};
+use sanctum_solana_test_utils::{
+    assert_custom_err, token::MockTokenAccountArgs, ExtendedBanksClient,
+};
+use sanctum_token_lib::{token_account_balance, MintWithTokenProgram};
+use solana_program::{clock::Clock, instruction::AccountMeta, pubkey::Pubkey};
+use solana_program_test::{BanksClient, ProgramTestContext};
+use solana_readonly_account::sdk::KeyedAccount;
+use solana_sdk::{
+    account::Account, hash::Hash, signature::Keypair, signer::Signer, transaction::Transaction,
+};
+use spl_calculator_lib::SplLstSolCommonFreeArgsConst;
+use test_utils::{jito_stake_pool, jitosol, JITO_STAKE_POOL_LAST_UPDATE_EPOCH};
+
+use crate::common::SControllerProgramTest;
+
+const JITOSOL_STARTING_POOL_RESERVES: u64 = 10_000_000_000;
+const MSOL_STARTING_POOL_RESERVES: u64 = 10_000_000_000;
+const MSOL_TO_SWAP_IN: u64 = 1_000_000_000;
+
+struct RouteTestSetup {
+    banks_client: BanksClient,
+    payer: Keypair,
+    last_blockhash: Hash,
+    swapper: Keypair,
+    swapper_msol_acc_addr: Pubkey,
+    swapper_jitosol_acc_addr: Pubkey,
+    lst_state_list_account: Account,
+    jito_sol_val_calc_accounts: Vec<AccountMeta>,
+}
+
+async fn setup() -> RouteTestSetup {
+    let swapper = Keypair::new();
+
+    let mut program_test = jito_marinade_no_fee_program_test(JitoMarinadeProgramTestArgs {
+        jitosol_reserves: JITOSOL_STARTING_POOL_RESERVES,
+        msol_reserves: MSOL_STARTING_POOL_RESERVES,
+        jitosol_sol_value: JITOSOL_STARTING_POOL_RESERVES, // updated on sync
+        msol_sol_value: MSOL_STARTING_POOL_RESERVES,       // updated on sync
+        // dont cares
+        jitosol_protocol_fee_accumulator: 0,
+        msol_protocol_fee_accumulator: 0,
+        lp_token_mint: Pubkey::new_unique(),
+        lp_token_supply: 0,
+    })
+    .add_s_program();
+
+    let swapper_jitosol_acc_addr = program_test.gen_and_add_token_account(MockTokenAccountArgs {
+        mint: jitosol::ID,
+        authority: swapper.pubkey(),
+        amount: 0,
+    });
+    let swapper_msol_acc_addr = program_test.gen_and_add_token_account(MockTokenAccountArgs {
+        mint: msol::ID,
+        authority: swapper.pubkey(),
+        amount: MSOL_TO_SWAP_IN,
+    });
+
+    let ctx = program_test.start_with_context().await;
+    ctx.set_sysvar(&Clock {
+        epoch: JITO_STAKE_POOL_LAST_UPDATE_EPOCH,
+        ..Default::default()
+    });
+    let ProgramTestContext {
+        mut banks_client,
+        last_blockhash,
+        payer,
+        ..
+    } = ctx;
+
+    let lst_state_list_account = banks_client.get_lst_state_list_acc().await;
+    let jito_stake_pool_acc = banks_client
+        .get_account_unwrapped(jito_stake_pool::ID)
+        .await;
+    let jito_sol_val_calc_accounts = SplLstSolCommonFreeArgsConst {
+        spl_stake_pool: KeyedAccount {
+            pubkey: jito_stake_pool::ID,
+            account: jito_stake_pool_acc,
+        },
+    }
+    .resolve_spl_to_account_metas()
+    .unwrap();
+
+    RouteTestSetup {
+        banks_client,
+        payer,
+        last_blockhash,
+        swapper,
+        swapper_msol_acc_addr,
+        swapper_jitosol_acc_addr,
+        lst_state_list_account,
+        jito_sol_val_calc_accounts,
+    }
+}
+
+fn no_fee_pricing_accounts(input_lst_mint: Pubkey, output_lst_mint: Pubkey) -> [AccountMeta; 2] {
+    [
+        AccountMeta::new_readonly(input_lst_mint, false),
+        AccountMeta::new_readonly(output_lst_mint, false),
+    ]
+}
+
+#[tokio::test]
+async fn basic_swap_exact_in_route_no_fee() {
+    let RouteTestSetup {
+        mut banks_client,
+        payer,
+        last_blockhash,
+        swapper,
+        swapper_msol_acc_addr,
+        swapper_jitosol_acc_addr,
+        lst_state_list_account,
+        jito_sol_val_calc_accounts,
+    } = setup().await;
+
+    let pool_state_account = banks_client.get_pool_state_acc().await;
+    // hasnt synced yet, should be MSOL_POOL_RESERVES + JITOSOL_POOL_RESERVES
+    let start_pool_total_sol_value = try_pool_state(&pool_state_account.data)
+        .unwrap()
+        .total_sol_value;
+
+    let marinade_sol_val_calc_accounts = marinade_sol_val_calc_account_metas();
+    let lst_mints = [
+        MintWithTokenProgram {
+            pubkey: msol::ID,
+            token_program: spl_token::ID,
+        },
+        MintWithTokenProgram {
+            pubkey: jitosol::ID,
+            token_program: spl_token::ID,
+        },
+    ];
+    let ix = swap_exact_in_route_ix_by_mints_full(
+        SwapRouteByMintsFreeArgs {
+            signer: swapper.pubkey(),
+            src_lst_acc: swapper_msol_acc_addr,
+            dst_lst_acc: swapper_jitosol_acc_addr,
+            lst_mints: &lst_mints,
+            lst_state_list: lst_state_list_account,
+        },
+        SwapExactInAmounts {
+            // mSOL worth more than jitoSOL
+            min_amount_out: MSOL_TO_SWAP_IN,
+            amount: MSOL_TO_SWAP_IN,
+        },
+        &[&marinade_sol_val_calc_accounts, &jito_sol_val_calc_accounts],
+        &[&no_fee_pricing_accounts(msol::ID, jitosol::ID)],
+        no_fee_pricing_program::ID,
+    )
+    .unwrap();
+    // fixed accounts, then [mint, token program, reserves, protocol fee accumulator] per LST
+    let msol_pool_reserves = ix.accounts[7].pubkey;
+    let jitosol_pool_reserves = ix.accounts[11].pubkey;
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &swapper], last_blockhash);
+
+    banks_client.process_transaction(tx).await.unwrap();
+
+    let msol_account = banks_client
+        .get_account_unwrapped(swapper_msol_acc_addr)
+        .await;
+    assert_eq!(token_account_balance(msol_account).unwrap(), 0);
+
+    let jitosol_account = banks_client
+        .get_account_unwrapped(swapper_jitosol_acc_addr)
+        .await;
+    let jitosol_received = token_account_balance(jitosol_account).unwrap();
+    // mSOL worth more than jitoSOL
+    assert!(jitosol_received > MSOL_TO_SWAP_IN);
+
+    let msol_pool_reserves_account = banks_client.get_account_unwrapped(msol_pool_reserves).await;
+    assert_eq!(
+        token_account_balance(msol_pool_reserves_account).unwrap(),
+        MSOL_STARTING_POOL_RESERVES + MSOL_TO_SWAP_IN
+    );
+
+    let jitosol_pool_reserves_account = banks_client
+        .get_account_unwrapped(jitosol_pool_reserves)
+        .await;
+    assert_eq!(
+        token_account_balance(jitosol_pool_reserves_account).unwrap(),
+        JITOSOL_STARTING_POOL_RESERVES - jitosol_received
+    );
+
+    let pool_state_account = banks_client.get_pool_state_acc().await;
+    let end_pool_total_sol_value = try_pool_state(&pool_state_account.data)
+        .unwrap()
+        .total_sol_value;
+    assert!(start_pool_total_sol_value < end_pool_total_sol_value);
+}
+
+#[tokio::test]
+async fn fail_swap_exact_in_route_slippage() {
+    let RouteTestSetup {
+        mut banks_client,
+        payer,
+        last_blockhash,
+        swapper,
+        swapper_msol_acc_addr,
+        swapper_jitosol_acc_addr,
+        lst_state_list_account,
+        jito_sol_val_calc_accounts,
+    } = setup().await;
+
+    let marinade_sol_val_calc_accounts = marinade_sol_val_calc_account_metas();
+    let lst_mints = [
+        MintWithTokenProgram {
+            pubkey: msol::ID,
+            token_program: spl_token::ID,
+        },
+        MintWithTokenProgram {
+            pubkey: jitosol::ID,
+            token_program: spl_token::ID,
+        },
+    ];
+    let ix = swap_exact_in_route_ix_by_mints_full(
+        SwapRouteByMintsFreeArgs {
+            signer: swapper.pubkey(),
+            src_lst_acc: swapper_msol_acc_addr,
+            dst_lst_acc: swapper_jitosol_acc_addr,
+            lst_mints: &lst_mints,
+            lst_state_list: lst_state_list_account,
+        },
+        SwapExactInAmounts {
+            min_amount_out: u64::MAX,
+            amount: MSOL_TO_SWAP_IN,
+        },
+        &[&marinade_sol_val_calc_accounts, &jito_sol_val_calc_accounts],
+        &[&no_fee_pricing_accounts(msol::ID, jitosol::ID)],
+        no_fee_pricing_program::ID,
+    )
+    .unwrap();
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &swapper], last_blockhash);
+
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_custom_err(err, SControllerError::SlippageToleranceExceeded);
+}
+
+#[tokio::test]
+async fn fail_swap_exact_in_route_cycle() {
+    let RouteTestSetup {
+        mut banks_client,
+        payer,
+        last_blockhash,
+        swapper,
+        swapper_msol_acc_addr,
+        lst_state_list_account,
+        jito_sol_val_calc_accounts,
+        ..
+    } = setup().await;
+
+    let (msol_index, jitosol_index) = {
+        let lst_state_list = try_lst_state_list(&lst_state_list_account.data).unwrap();
+        (
+            try_find_lst_mint_on_list(msol::ID, lst_state_list)
+                .unwrap()
+                .0,
+            try_find_lst_mint_on_list(jitosol::ID, lst_state_list)
+                .unwrap()
+                .0,
+        )
+    };
+    let msol_keys = SwapRouteLstFreeArgs {
+        lst_index: msol_index,
+        lst_mint: MintWithTokenProgram {
+            pubkey: msol::ID,
+            token_program: spl_token::ID,
+        },
+        lst_state_list: KeyedAccount {
+            pubkey: s_controller_lib::program::LST_STATE_LIST_ID,
+            account: lst_state_list_account.clone(),
+        },
+    }
+    .resolve()
+    .unwrap();
+    let jitosol_keys = SwapRouteLstFreeArgs {
+        lst_index: jitosol_index,
+        lst_mint: MintWithTokenProgram {
+            pubkey: jitosol::ID,
+            token_program: spl_token::ID,
+        },
+        lst_state_list: KeyedAccount {
+            pubkey: s_controller_lib::program::LST_STATE_LIST_ID,
+            account: lst_state_list_account,
+        },
+    }
+    .resolve()
+    .unwrap();
+
+    let marinade_sol_val_calc_accounts = marinade_sol_val_calc_account_metas();
+    let marinade_calc = LstSolValueCalcAccounts {
+        calculator_program_id: marinade_calculator_lib::program::ID,
+        calculator_accounts: &marinade_sol_val_calc_accounts,
+    };
+    let jito_calc = LstSolValueCalcAccounts {
+        calculator_program_id: spl_calculator_lib::program::ID,
+        calculator_accounts: &jito_sol_val_calc_accounts,
+    };
+    // mSOL -> jitoSOL -> mSOL
+    let ix = swap_exact_in_route_ix_full(
+        SwapExactInRouteFreeArgs {
+            signer: swapper.pubkey(),
+            src_lst_acc: swapper_msol_acc_addr,
+            dst_lst_acc: swapper_msol_acc_addr,
+        }
+        .resolve(),
+        SwapExactInRouteIxFullArgs {
+            lst_indexes: &[msol_index, jitosol_index, msol_index],
+            min_amount_out: 0,
+            amount: MSOL_TO_SWAP_IN,
+        },
+        &[msol_keys, jitosol_keys, msol_keys],
+        &[marinade_calc, jito_calc, marinade_calc],
+        &[
+            &no_fee_pricing_accounts(msol::ID, jitosol::ID),
+            &no_fee_pricing_accounts(jitosol::ID, msol::ID),
+        ],
+        no_fee_pricing_program::ID,
+    )
+    .unwrap();
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &swapper], last_blockhash);
+
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_custom_err(err, SControllerError::SwapSameLst);
+}
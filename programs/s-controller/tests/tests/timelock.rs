@@ -0,0 +1,452 @@
+use s_controller_interface::{
+    add_lst_ix, cancel_pending_change_ix, queue_pending_change_ix, set_admin_ix,
+    set_rebalance_authority_ix, CancelPendingChangeIxArgs, PoolState, SControllerError,
+};
+use s_controller_lib::{
+    execute_pending_change_ix_full,
+    program::{LST_STATE_LIST_ID, PENDING_CHANGE_LIST_ID, POOL_STATE_ID},
+    try_pending_change_list, AddLstFreeArgs, AdminChange, CancelPendingChangeFreeArgs,
+    ExecutePendingChangeFreeArgs, KnownAuthoritySetRebalanceAuthorityFreeArgs,
+    QueuePendingChangeFreeArgs, SetAdminFreeArgs, SECONDS_PER_HOUR,
+};
+use s_controller_test_utils::{
+    assert_admin, assert_rebalance_authority, AddSplProgramTest,
+    DisablePoolAuthorityListProgramTest, PoolStateBanksClient, PoolStateProgramTest,
+    DEFAULT_POOL_STATE,
+};
+use sanctum_solana_test_utils::{assert_custom_err, test_fixtures_dir, ExtendedBanksClient};
+use solana_program::{clock::Clock, pubkey::Pubkey};
+use solana_program_test::{BanksClient, ProgramTest, ProgramTestContext};
+use solana_readonly_account::sdk::KeyedAccount;
+use solana_sdk::{
+    hash::Hash,
+    instruction::Instruction,
+    signature::{read_keypair_file, Keypair, Signer},
+    transaction::Transaction,
+};
+use test_utils::jitosol;
+
+use crate::common::*;
+
+const TIMELOCK_DELAY_HOURS: u8 = 24;
+
+const START_UNIX_TIMESTAMP: i64 = 1_700_000_000;
+
+const TIMELOCKED_POOL_STATE: PoolState = PoolState {
+    timelock_delay_hours: TIMELOCK_DELAY_HOURS,
+    ..DEFAULT_POOL_STATE
+};
+
+async fn start_with_clock(program_test: ProgramTest) -> (BanksClient, Keypair, Hash) {
+    let ctx = program_test.start_with_context().await;
+    ctx.set_sysvar(&Clock {
+        unix_timestamp: START_UNIX_TIMESTAMP,
+        ..Default::default()
+    });
+    let ProgramTestContext {
+        banks_client,
+        last_blockhash,
+        payer,
+        ..
+    } = ctx;
+    (banks_client, payer, last_blockhash)
+}
+
+async fn set_admin_ix_for(banks_client: &mut BanksClient, new_admin: Pubkey) -> Instruction {
+    set_admin_ix(
+        SetAdminFreeArgs {
+            new_admin,
+            pool_state: KeyedAccount {
+                pubkey: POOL_STATE_ID,
+                account: banks_client.get_pool_state_acc().await,
+            },
+        }
+        .resolve()
+        .unwrap(),
+    )
+    .unwrap()
+}
+
+async fn set_rebalance_authority_ix_for(
+    banks_client: &mut BanksClient,
+    new_rebalance_authority: Pubkey,
+) -> Instruction {
+    set_rebalance_authority_ix(
+        KnownAuthoritySetRebalanceAuthorityFreeArgs {
+            new_rebalance_authority,
+            pool_state: KeyedAccount {
+                pubkey: POOL_STATE_ID,
+                account: banks_client.get_pool_state_acc().await,
+            },
+        }
+        .resolve_pool_admin_with_pool_state_id()
+        .unwrap(),
+    )
+    .unwrap()
+}
+
+async fn queue_change_ix(
+    banks_client: &mut BanksClient,
+    payer: Pubkey,
+    change: AdminChange,
+) -> Instruction {
+    queue_pending_change_ix(
+        QueuePendingChangeFreeArgs {
+            payer,
+            pool_state: KeyedAccount {
+                pubkey: POOL_STATE_ID,
+                account: banks_client.get_pool_state_acc().await,
+            },
+        }
+        .resolve()
+        .unwrap(),
+        change.into(),
+    )
+    .unwrap()
+}
+
+async fn pending_change_list_len(banks_client: &mut BanksClient) -> usize {
+    match banks_client
+        .get_account(PENDING_CHANGE_LIST_ID)
+        .await
+        .unwrap()
+    {
+        None => 0,
+        Some(acc) => try_pending_change_list(&acc.data).unwrap().len(),
+    }
+}
+
+#[tokio::test]
+async fn queue_and_execute_set_admin() {
+    let mock_auth_kp =
+        read_keypair_file(test_fixtures_dir().join("s-controller-test-initial-authority-key.json"))
+            .unwrap();
+    let new_admin_kp = Keypair::new();
+
+    let program_test = ProgramTest::default()
+        .add_s_program()
+        .add_pool_state(TIMELOCKED_POOL_STATE);
+
+    let ctx = program_test.start_with_context().await;
+    ctx.set_sysvar(&Clock {
+        unix_timestamp: START_UNIX_TIMESTAMP,
+        ..Default::default()
+    });
+
+    let mut banks_client = ctx.banks_client.clone();
+    let payer = &ctx.payer;
+    let last_blockhash = ctx.last_blockhash;
+
+    let ix = queue_change_ix(
+        &mut banks_client,
+        payer.pubkey(),
+        AdminChange::SetAdmin {
+            new_admin: new_admin_kp.pubkey(),
+        },
+    )
+    .await;
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[payer, &mock_auth_kp], last_blockhash);
+    banks_client.process_transaction(tx).await.unwrap();
+
+    let pending_change_list_acc = banks_client
+        .get_account_unwrapped(PENDING_CHANGE_LIST_ID)
+        .await;
+    let pending_change = try_pending_change_list(&pending_change_list_acc.data).unwrap()[0];
+    assert_eq!(
+        pending_change.executable_at,
+        START_UNIX_TIMESTAMP + i64::from(TIMELOCK_DELAY_HOURS) * SECONDS_PER_HOUR
+    );
+    assert_eq!(
+        AdminChange::try_from(&pending_change).unwrap(),
+        AdminChange::SetAdmin {
+            new_admin: new_admin_kp.pubkey(),
+        }
+    );
+
+    ctx.set_sysvar(&Clock {
+        unix_timestamp: pending_change.executable_at,
+        ..Default::default()
+    });
+
+    let direct_ix = set_admin_ix_for(&mut banks_client, new_admin_kp.pubkey()).await;
+    let ix = execute_pending_change_ix_full(
+        ExecutePendingChangeFreeArgs {
+            refund_rent_to: payer.pubkey(),
+        }
+        .resolve(),
+        0,
+        direct_ix,
+    )
+    .unwrap();
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[payer, &mock_auth_kp], last_blockhash);
+    banks_client.process_transaction(tx).await.unwrap();
+
+    assert_admin(&mut banks_client, new_admin_kp.pubkey()).await;
+    assert_eq!(pending_change_list_len(&mut banks_client).await, 0);
+}
+
+#[tokio::test]
+async fn reject_execute_before_delay_elapsed() {
+    let mock_auth_kp =
+        read_keypair_file(test_fixtures_dir().join("s-controller-test-initial-authority-key.json"))
+            .unwrap();
+    let new_admin_kp = Keypair::new();
+
+    let program_test = ProgramTest::default()
+        .add_s_program()
+        .add_pool_state(TIMELOCKED_POOL_STATE);
+
+    let (mut banks_client, payer, last_blockhash) = start_with_clock(program_test).await;
+
+    let ix = queue_change_ix(
+        &mut banks_client,
+        payer.pubkey(),
+        AdminChange::SetAdmin {
+            new_admin: new_admin_kp.pubkey(),
+        },
+    )
+    .await;
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &mock_auth_kp], last_blockhash);
+    banks_client.process_transaction(tx).await.unwrap();
+
+    let direct_ix = set_admin_ix_for(&mut banks_client, new_admin_kp.pubkey()).await;
+    let ix = execute_pending_change_ix_full(
+        ExecutePendingChangeFreeArgs {
+            refund_rent_to: payer.pubkey(),
+        }
+        .resolve(),
+        0,
+        direct_ix,
+    )
+    .unwrap();
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &mock_auth_kp], last_blockhash);
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+
+    assert_custom_err(err, SControllerError::TimelockNotElapsed);
+    assert_admin(&mut banks_client, DEFAULT_POOL_STATE.admin).await;
+    assert_eq!(pending_change_list_len(&mut banks_client).await, 1);
+}
+
+#[tokio::test]
+async fn reject_direct_change_when_timelocked() {
+    let mock_auth_kp =
+        read_keypair_file(test_fixtures_dir().join("s-controller-test-initial-authority-key.json"))
+            .unwrap();
+    let new_admin_kp = Keypair::new();
+
+    let program_test = ProgramTest::default()
+        .add_s_program()
+        .add_pool_state(TIMELOCKED_POOL_STATE);
+
+    let (mut banks_client, payer, last_blockhash) = start_with_clock(program_test).await;
+
+    let ix = set_admin_ix_for(&mut banks_client, new_admin_kp.pubkey()).await;
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &mock_auth_kp], last_blockhash);
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+
+    assert_custom_err(err, SControllerError::PoolTimelocked);
+    assert_admin(&mut banks_client, DEFAULT_POOL_STATE.admin).await;
+}
+
+#[tokio::test]
+async fn reject_queue_when_not_timelocked() {
+    let mock_auth_kp =
+        read_keypair_file(test_fixtures_dir().join("s-controller-test-initial-authority-key.json"))
+            .unwrap();
+
+    let program_test = ProgramTest::default()
+        .add_s_program()
+        .add_pool_state(DEFAULT_POOL_STATE);
+
+    let (mut banks_client, payer, last_blockhash) = start_with_clock(program_test).await;
+
+    let ix = queue_change_ix(
+        &mut banks_client,
+        payer.pubkey(),
+        AdminChange::SetAdmin {
+            new_admin: Pubkey::new_unique(),
+        },
+    )
+    .await;
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &mock_auth_kp], last_blockhash);
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+
+    assert_custom_err(err, SControllerError::PoolNotTimelocked);
+}
+
+#[tokio::test]
+async fn cancel_pending_change_by_disable_pool_authority() {
+    let mock_auth_kp =
+        read_keypair_file(test_fixtures_dir().join("s-controller-test-initial-authority-key.json"))
+            .unwrap();
+    let disable_pool_authority_kp = Keypair::new();
+    let rando_kp = Keypair::new();
+
+    let program_test = ProgramTest::default()
+        .add_s_program()
+        .add_pool_state(TIMELOCKED_POOL_STATE)
+        .add_disable_pool_authority_list(&[disable_pool_authority_kp.pubkey()]);
+
+    let (mut banks_client, payer, last_blockhash) = start_with_clock(program_test).await;
+
+    let ix = queue_change_ix(
+        &mut banks_client,
+        payer.pubkey(),
+        AdminChange::SetProtocolFee {
+            new_trading_protocol_fee_bps: Some(10_000),
+            new_lp_protocol_fee_bps: None,
+        },
+    )
+    .await;
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &mock_auth_kp], last_blockhash);
+    banks_client.process_transaction(tx).await.unwrap();
+    assert_eq!(pending_change_list_len(&mut banks_client).await, 1);
+
+    let cancel_ix = |signer: Pubkey| {
+        cancel_pending_change_ix(
+            CancelPendingChangeFreeArgs {
+                signer,
+                refund_rent_to: payer.pubkey(),
+            }
+            .resolve(),
+            CancelPendingChangeIxArgs {
+                pending_change_index: 0,
+            },
+        )
+        .unwrap()
+    };
+
+    // Unauthorized signer
+    let mut tx =
+        Transaction::new_with_payer(&[cancel_ix(rando_kp.pubkey())], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &rando_kp], last_blockhash);
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_custom_err(err, SControllerError::InvalidDisablePoolAuthority);
+    assert_eq!(pending_change_list_len(&mut banks_client).await, 1);
+
+    // Disable pool authority
+    let mut tx = Transaction::new_with_payer(
+        &[cancel_ix(disable_pool_authority_kp.pubkey())],
+        Some(&payer.pubkey()),
+    );
+    tx.sign(&[&payer, &disable_pool_authority_kp], last_blockhash);
+    banks_client.process_transaction(tx).await.unwrap();
+    assert_eq!(pending_change_list_len(&mut banks_client).await, 0);
+}
+
+#[tokio::test]
+async fn reject_direct_add_lst_when_timelocked() {
+    let mock_auth_kp =
+        read_keypair_file(test_fixtures_dir().join("s-controller-test-initial-authority-key.json"))
+            .unwrap();
+
+    let program_test = ProgramTest::default()
+        .add_s_program()
+        .add_spl_progs()
+        .add_jito_stake_pool()
+        .add_pool_state(TIMELOCKED_POOL_STATE);
+
+    let (mut banks_client, payer, last_blockhash) = start_with_clock(program_test).await;
+
+    let (keys, _bumps) = AddLstFreeArgs {
+        payer: payer.pubkey(),
+        sol_value_calculator: spl_calculator_lib::program::ID,
+        pool_state: KeyedAccount {
+            pubkey: POOL_STATE_ID,
+            account: banks_client.get_pool_state_acc().await,
+        },
+        lst_mint: KeyedAccount {
+            pubkey: jitosol::ID,
+            account: banks_client.get_account_unwrapped(jitosol::ID).await,
+        },
+    }
+    .resolve()
+    .unwrap();
+    let ix = add_lst_ix(keys).unwrap();
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer, &mock_auth_kp], last_blockhash);
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+
+    assert_custom_err(err, SControllerError::PoolTimelocked);
+    assert!(banks_client
+        .get_account(LST_STATE_LIST_ID)
+        .await
+        .unwrap()
+        .is_none());
+}
+
+#[tokio::test]
+async fn queue_and_execute_set_rebalance_authority() {
+    let mock_auth_kp =
+        read_keypair_file(test_fixtures_dir().join("s-controller-test-initial-authority-key.json"))
+            .unwrap();
+    let new_rebalance_authority = Pubkey::new_unique();
+
+    let program_test = ProgramTest::default()
+        .add_s_program()
+        .add_pool_state(TIMELOCKED_POOL_STATE);
+
+    let ctx = program_test.start_with_context().await;
+    ctx.set_sysvar(&Clock {
+        unix_timestamp: START_UNIX_TIMESTAMP,
+        ..Default::default()
+    });
+
+    let mut banks_client = ctx.banks_client.clone();
+    let payer = &ctx.payer;
+    let last_blockhash = ctx.last_blockhash;
+
+    // admin cannot set it directly
+    let ix = set_rebalance_authority_ix_for(&mut banks_client, new_rebalance_authority).await;
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[payer, &mock_auth_kp], last_blockhash);
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_custom_err(err, SControllerError::PoolTimelocked);
+
+    let ix = queue_change_ix(
+        &mut banks_client,
+        payer.pubkey(),
+        AdminChange::SetRebalanceAuthority {
+            new_rebalance_authority,
+        },
+    )
+    .await;
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[payer, &mock_auth_kp], last_blockhash);
+    banks_client.process_transaction(tx).await.unwrap();
+
+    let pending_change_list_acc = banks_client
+        .get_account_unwrapped(PENDING_CHANGE_LIST_ID)
+        .await;
+    let pending_change = try_pending_change_list(&pending_change_list_acc.data).unwrap()[0];
+
+    ctx.set_sysvar(&Clock {
+        unix_timestamp: pending_change.executable_at,
+        ..Default::default()
+    });
+
+    let direct_ix =
+        set_rebalance_authority_ix_for(&mut banks_client, new_rebalance_authority).await;
+    let ix = execute_pending_change_ix_full(
+        ExecutePendingChangeFreeArgs {
+            refund_rent_to: payer.pubkey(),
+        }
+        .resolve(),
+        0,
+        direct_ix,
+    )
+    .unwrap();
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[payer, &mock_auth_kp], last_blockhash);
+    banks_client.process_transaction(tx).await.unwrap();
+
+    assert_rebalance_authority(&mut banks_client, new_rebalance_authority).await;
+    assert_eq!(pending_change_list_len(&mut banks_client).await, 0);
+}
@@ -0,0 +1,174 @@
+use marinade_keys::msol;
+use s_controller_interface::SControllerError;
+use s_controller_lib::{
+    find_protocol_fee_accumulator_address, program::POOL_STATE_ID,
+    withdraw_protocol_fees_multi_ix_full, FindLstPdaAtaKeys, WithdrawProtocolFeesMultiFreeArgs,
+    WithdrawProtocolFeesMultiLstFreeArgs,
+};
+use s_controller_test_utils::{
+    jito_marinade_no_fee_program_test, JitoMarinadeProgramTestArgs, PoolStateBanksClient,
+};
+use sanctum_solana_test_utils::{
+    assert_custom_err, test_fixtures_dir,
+    token::{tokenkeg::TokenkegProgramTest, MockTokenAccountArgs},
+    ExtendedBanksClient,
+};
+use sanctum_token_lib::{token_account_balance, MintWithTokenProgram};
+use solana_program::pubkey::Pubkey;
+use solana_program_test::*;
+use solana_readonly_account::sdk::KeyedAccount;
+use solana_sdk::{
+    account::Account,
+    hash::Hash,
+    signature::{read_keypair_file, Keypair, Signer},
+    transaction::Transaction,
+};
+use test_utils::jitosol;
+
+use crate::common::*;
+
+const JITOSOL_ACCUMULATED_FEES: u64 = 2_000_000_000;
+const MSOL_ACCUMULATED_FEES: u64 = 10_000_000_000;
+
+struct WithdrawMultiFixture {
+    banks_client: BanksClient,
+    payer: Keypair,
+    last_blockhash: Hash,
+    beneficiary: Keypair,
+    /// (mint, beneficiary token account)
+    lsts: [(Pubkey, Pubkey); 2],
+}
+
+async fn setup() -> WithdrawMultiFixture {
+    let beneficiary =
+        read_keypair_file(test_fixtures_dir().join("s-controller-test-initial-authority-key.json"))
+            .unwrap();
+    let lsts = [
+        (jitosol::ID, Pubkey::new_unique()),
+        (msol::ID, Pubkey::new_unique()),
+    ];
+
+    let mut program_test = jito_marinade_no_fee_program_test(JitoMarinadeProgramTestArgs {
+        jitosol_sol_value: 0,
+        msol_sol_value: 0,
+        jitosol_reserves: 0,
+        msol_reserves: 0,
+        jitosol_protocol_fee_accumulator: JITOSOL_ACCUMULATED_FEES,
+        msol_protocol_fee_accumulator: MSOL_ACCUMULATED_FEES,
+        lp_token_mint: Pubkey::new_unique(),
+        lp_token_supply: 0,
+    });
+    for (mint, withdraw_to) in lsts {
+        program_test = program_test.add_tokenkeg_account_from_args(
+            withdraw_to,
+            MockTokenAccountArgs {
+                mint,
+                authority: beneficiary.pubkey(),
+                amount: 0,
+            },
+        );
+    }
+    let (banks_client, payer, last_blockhash) = program_test.add_s_program().start().await;
+
+    WithdrawMultiFixture {
+        banks_client,
+        payer,
+        last_blockhash,
+        beneficiary,
+        lsts,
+    }
+}
+
+fn withdraw_multi_tx(
+    fixture: &WithdrawMultiFixture,
+    pool_state_acc: Account,
+    amounts: &[u64],
+) -> Transaction {
+    let keys = WithdrawProtocolFeesMultiFreeArgs {
+        pool_state: KeyedAccount {
+            pubkey: POOL_STATE_ID,
+            account: pool_state_acc,
+        },
+    }
+    .resolve()
+    .unwrap();
+    let lst_keys: Vec<_> = fixture
+        .lsts
+        .iter()
+        .map(|(mint, withdraw_to)| {
+            WithdrawProtocolFeesMultiLstFreeArgs {
+                lst_mint: MintWithTokenProgram {
+                    pubkey: *mint,
+                    token_program: spl_token::ID,
+                },
+                withdraw_to: *withdraw_to,
+            }
+            .resolve()
+        })
+        .collect();
+    let ix = withdraw_protocol_fees_multi_ix_full(keys, &lst_keys, amounts).unwrap();
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&fixture.payer.pubkey()));
+    tx.sign(
+        &[&fixture.payer, &fixture.beneficiary],
+        fixture.last_blockhash,
+    );
+    tx
+}
+
+#[tokio::test]
+async fn basic_withdraw_protocol_fees_multi() {
+    const JITOSOL_FEES_TO_WITHDRAW: u64 = JITOSOL_ACCUMULATED_FEES;
+    const MSOL_FEES_TO_WITHDRAW: u64 = 1_000_000_000;
+
+    let mut fixture = setup().await;
+    let pool_state_acc = fixture.banks_client.get_pool_state_acc().await;
+    let tx = withdraw_multi_tx(
+        &fixture,
+        pool_state_acc,
+        &[JITOSOL_FEES_TO_WITHDRAW, MSOL_FEES_TO_WITHDRAW],
+    );
+    fixture.banks_client.process_transaction(tx).await.unwrap();
+
+    for ((mint, withdraw_to), (withdrawn, accumulated)) in fixture.lsts.into_iter().zip([
+        (JITOSOL_FEES_TO_WITHDRAW, JITOSOL_ACCUMULATED_FEES),
+        (MSOL_FEES_TO_WITHDRAW, MSOL_ACCUMULATED_FEES),
+    ]) {
+        let withdraw_to_acc = fixture
+            .banks_client
+            .get_account_unwrapped(withdraw_to)
+            .await;
+        assert_eq!(token_account_balance(withdraw_to_acc).unwrap(), withdrawn);
+
+        let (protocol_fee_accumulator, _bump) =
+            find_protocol_fee_accumulator_address(FindLstPdaAtaKeys {
+                lst_mint: mint,
+                token_program: spl_token::ID,
+            });
+        let protocol_fee_accumulator_acc = fixture
+            .banks_client
+            .get_account_unwrapped(protocol_fee_accumulator)
+            .await;
+        assert_eq!(
+            token_account_balance(protocol_fee_accumulator_acc).unwrap(),
+            accumulated - withdrawn
+        );
+    }
+}
+
+#[tokio::test]
+async fn withdraw_protocol_fees_multi_not_enough_fees() {
+    let mut fixture = setup().await;
+    let pool_state_acc = fixture.banks_client.get_pool_state_acc().await;
+    let tx = withdraw_multi_tx(
+        &fixture,
+        pool_state_acc,
+        &[JITOSOL_ACCUMULATED_FEES, MSOL_ACCUMULATED_FEES + 1],
+    );
+    let err = fixture
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+    assert_custom_err(err, SControllerError::NotEnoughFees);
+}
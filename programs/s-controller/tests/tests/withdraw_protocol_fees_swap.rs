@@ -0,0 +1,243 @@
+use marinade_calculator_lib::marinade_sol_val_calc_account_metas;
+use marinade_keys::msol;
+use s_controller_interface::SControllerError;
+use s_controller_lib::{
+    program::{LST_STATE_LIST_ID, POOL_STATE_ID},
+    withdraw_protocol_fees_swap_ix_by_mints_full, SrcDstLstSolValueCalcAccountSuffixes,
+    SwapExactInAmounts, WithdrawProtocolFeesSwapByMintsFreeArgs,
+};
+use s_controller_test_utils::{
+    jito_marinade_no_fee_program_test, GenAndAddTokenAccountProgramTest,
+    JitoMarinadeProgramTestArgs, LstStateListBanksClient, PoolStateBanksClient,
+};
+use sanctum_solana_test_utils::{
+    assert_custom_err, test_fixtures_dir, token::MockTokenAccountArgs, ExtendedBanksClient,
+};
+use sanctum_token_lib::{token_account_balance, MintWithTokenProgram};
+use solana_program::{clock::Clock, pubkey::Pubkey};
+use solana_program_test::{BanksClient, ProgramTestContext};
+use solana_readonly_account::sdk::KeyedAccount;
+use solana_sdk::{
+    hash::Hash,
+    instruction::Instruction,
+    signature::{read_keypair_file, Keypair, Signer},
+    transaction::Transaction,
+};
+use spl_calculator_lib::SplLstSolCommonFreeArgsConst;
+use test_utils::{jito_stake_pool, jitosol, JITO_STAKE_POOL_LAST_UPDATE_EPOCH};
+
+use crate::common::SControllerProgramTest;
+
+const JITOSOL_STARTING_POOL_RESERVES: u64 = 10_000_000_000;
+const MSOL_STARTING_POOL_RESERVES: u64 = 10_000_000_000;
+const MSOL_ACCUMULATED_FEES: u64 = 2_000_000_000;
+
+struct WithdrawSwapFixture {
+    banks_client: BanksClient,
+    payer: Keypair,
+    last_blockhash: Hash,
+    beneficiary: Keypair,
+    withdraw_to: Pubkey,
+}
+
+async fn setup() -> WithdrawSwapFixture {
+    let beneficiary =
+        read_keypair_file(test_fixtures_dir().join("s-controller-test-initial-authority-key.json"))
+            .unwrap();
+
+    let mut program_test = jito_marinade_no_fee_program_test(JitoMarinadeProgramTestArgs {
+        jitosol_reserves: JITOSOL_STARTING_POOL_RESERVES,
+        msol_reserves: MSOL_STARTING_POOL_RESERVES,
+        jitosol_sol_value: JITOSOL_STARTING_POOL_RESERVES, // updated on sync
+        msol_sol_value: MSOL_STARTING_POOL_RESERVES,       // updated on sync
+        jitosol_protocol_fee_accumulator: 0,
+        msol_protocol_fee_accumulator: MSOL_ACCUMULATED_FEES,
+        // dont cares
+        lp_token_mint: Pubkey::new_unique(),
+        lp_token_supply: 0,
+    })
+    .add_s_program();
+    let withdraw_to = program_test.gen_and_add_token_account(MockTokenAccountArgs {
+        mint: jitosol::ID,
+        authority: beneficiary.pubkey(),
+        amount: 0,
+    });
+
+    let ctx = program_test.start_with_context().await;
+    ctx.set_sysvar(&Clock {
+        epoch: JITO_STAKE_POOL_LAST_UPDATE_EPOCH,
+        ..Default::default()
+    });
+    let ProgramTestContext {
+        banks_client,
+        last_blockhash,
+        payer,
+        ..
+    } = ctx;
+
+    WithdrawSwapFixture {
+        banks_client,
+        payer,
+        last_blockhash,
+        beneficiary,
+        withdraw_to,
+    }
+}
+
+/// Withdraws mSOL protocol fees as jitoSOL
+async fn withdraw_msol_fees_as_jitosol_ix(
+    fixture: &mut WithdrawSwapFixture,
+    amounts: SwapExactInAmounts,
+) -> Instruction {
+    let pool_state_acc = fixture.banks_client.get_pool_state_acc().await;
+    let lst_state_list_acc = fixture.banks_client.get_lst_state_list_acc().await;
+    let jito_stake_pool_acc = fixture
+        .banks_client
+        .get_account_unwrapped(jito_stake_pool::ID)
+        .await;
+    let jito_sol_val_calc_accounts = SplLstSolCommonFreeArgsConst {
+        spl_stake_pool: KeyedAccount {
+            pubkey: jito_stake_pool::ID,
+            account: jito_stake_pool_acc,
+        },
+    }
+    .resolve_spl_to_account_metas()
+    .unwrap();
+    let marinade_sol_val_calc_accounts = marinade_sol_val_calc_account_metas();
+
+    withdraw_protocol_fees_swap_ix_by_mints_full(
+        WithdrawProtocolFeesSwapByMintsFreeArgs {
+            withdraw_to: fixture.withdraw_to,
+            lst_state_list: KeyedAccount {
+                pubkey: LST_STATE_LIST_ID,
+                account: lst_state_list_acc,
+            },
+            pool_state: KeyedAccount {
+                pubkey: POOL_STATE_ID,
+                account: pool_state_acc,
+            },
+            src_lst_mint: MintWithTokenProgram {
+                pubkey: msol::ID,
+                token_program: spl_token::ID,
+            },
+            dst_lst_mint: MintWithTokenProgram {
+                pubkey: jitosol::ID,
+                token_program: spl_token::ID,
+            },
+        },
+        amounts,
+        SrcDstLstSolValueCalcAccountSuffixes {
+            src_lst_calculator_accounts: &marinade_sol_val_calc_accounts,
+            dst_lst_calculator_accounts: &jito_sol_val_calc_accounts,
+        },
+    )
+    .unwrap()
+}
+
+#[tokio::test]
+async fn basic_withdraw_protocol_fees_swap() {
+    const MSOL_FEES_TO_WITHDRAW: u64 = 1_000_000_000;
+
+    let mut fixture = setup().await;
+    let ix = withdraw_msol_fees_as_jitosol_ix(
+        &mut fixture,
+        SwapExactInAmounts {
+            // mSOL worth more than jitoSOL
+            min_amount_out: MSOL_FEES_TO_WITHDRAW,
+            amount: MSOL_FEES_TO_WITHDRAW,
+        },
+    )
+    .await;
+    let msol_protocol_fee_accumulator = ix.accounts[3].pubkey;
+    let msol_pool_reserves = ix.accounts[10].pubkey;
+    let jitosol_pool_reserves = ix.accounts[11].pubkey;
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&fixture.payer.pubkey()));
+    tx.sign(
+        &[&fixture.payer, &fixture.beneficiary],
+        fixture.last_blockhash,
+    );
+    fixture.banks_client.process_transaction(tx).await.unwrap();
+
+    let withdraw_to_acc = fixture
+        .banks_client
+        .get_account_unwrapped(fixture.withdraw_to)
+        .await;
+    let jitosol_received = token_account_balance(withdraw_to_acc).unwrap();
+    // mSOL worth more than jitoSOL
+    assert!(jitosol_received > MSOL_FEES_TO_WITHDRAW);
+
+    let accumulator_acc = fixture
+        .banks_client
+        .get_account_unwrapped(msol_protocol_fee_accumulator)
+        .await;
+    assert_eq!(
+        token_account_balance(accumulator_acc).unwrap(),
+        MSOL_ACCUMULATED_FEES - MSOL_FEES_TO_WITHDRAW
+    );
+
+    let msol_pool_reserves_acc = fixture
+        .banks_client
+        .get_account_unwrapped(msol_pool_reserves)
+        .await;
+    assert_eq!(
+        token_account_balance(msol_pool_reserves_acc).unwrap(),
+        MSOL_STARTING_POOL_RESERVES + MSOL_FEES_TO_WITHDRAW
+    );
+    let jitosol_pool_reserves_acc = fixture
+        .banks_client
+        .get_account_unwrapped(jitosol_pool_reserves)
+        .await;
+    assert_eq!(
+        token_account_balance(jitosol_pool_reserves_acc).unwrap(),
+        JITOSOL_STARTING_POOL_RESERVES - jitosol_received
+    );
+}
+
+#[tokio::test]
+async fn withdraw_protocol_fees_swap_slippage() {
+    let mut fixture = setup().await;
+    let ix = withdraw_msol_fees_as_jitosol_ix(
+        &mut fixture,
+        SwapExactInAmounts {
+            min_amount_out: u64::MAX,
+            amount: MSOL_ACCUMULATED_FEES,
+        },
+    )
+    .await;
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&fixture.payer.pubkey()));
+    tx.sign(
+        &[&fixture.payer, &fixture.beneficiary],
+        fixture.last_blockhash,
+    );
+    let err = fixture
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+    assert_custom_err(err, SControllerError::SlippageToleranceExceeded);
+}
+
+#[tokio::test]
+async fn withdraw_protocol_fees_swap_not_enough_fees() {
+    let mut fixture = setup().await;
+    let ix = withdraw_msol_fees_as_jitosol_ix(
+        &mut fixture,
+        SwapExactInAmounts {
+            min_amount_out: 0,
+            amount: MSOL_ACCUMULATED_FEES + 1,
+        },
+    )
+    .await;
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&fixture.payer.pubkey()));
+    tx.sign(
+        &[&fixture.payer, &fixture.beneficiary],
+        fixture.last_blockhash,
+    );
+    let err = fixture
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+    assert_custom_err(err, SControllerError::NotEnoughFees);
+}
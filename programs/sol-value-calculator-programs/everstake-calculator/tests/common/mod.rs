@@ -0,0 +1,28 @@
+use everstake_calculator_lib::EverstakeSolValCalc;
+use generic_pool_calculator_lib::GenericPoolSolValCalc;
+use generic_pool_calculator_test_utils::{
+    GenericPoolCalculatorProgramTest, MockCalculatorStateAccountArgs,
+};
+use sanctum_solana_test_utils::ExtendedProgramTest;
+use solana_program::pubkey::Pubkey;
+use solana_program_test::{processor, ProgramTest};
+use test_utils::EVERSTAKE_PROG_LAST_UPDATED_SLOT;
+
+pub fn everstake_normal_program_test() -> ProgramTest {
+    let mut program_test = ProgramTest::default();
+    // name must match <name>.so filename
+    program_test.add_program(
+        "everstake_calculator",
+        everstake_calculator_lib::program::ID,
+        processor!(everstake_calculator::entrypoint::process_instruction),
+    );
+    program_test
+        .add_mock_calculator_state(MockCalculatorStateAccountArgs {
+            manager: Pubkey::default(),
+            last_upgrade_slot: EVERSTAKE_PROG_LAST_UPDATED_SLOT,
+            owner: EverstakeSolValCalc::ID,
+        })
+        .add_test_fixtures_account("everstake-stake-pool.json")
+        .add_test_fixtures_account("everstake-prog.json")
+        .add_test_fixtures_account("everstake-prog-data.json")
+}
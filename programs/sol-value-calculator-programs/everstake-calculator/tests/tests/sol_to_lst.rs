@@ -0,0 +1,65 @@
+use everstake_calculator_lib::{
+    deserialize_everstake_stake_pool_checked, everstake_sol_to_lst_ix, EverstakeSolValCalc,
+    EverstakeStakePoolCalc, EVERSTAKE_LST_SOL_COMMON_INTERMEDIATE_KEYS,
+};
+use generic_pool_calculator_interface::{SolToLstIxArgs, SolToLstKeys};
+use sanctum_solana_test_utils::KeyedUiAccount;
+use sanctum_token_ratio::{U64ValueRange, U64_VALUE_RANGE_BORSH_SER_LEN};
+use sol_value_calculator_lib::SolValueCalculator;
+use solana_program::clock::Clock;
+use solana_program_test::ProgramTestContext;
+use test_utils::{BorshReturnDataBanksClient, EVERSTAKE_STAKE_POOL_LAST_UPDATE_EPOCH};
+
+use crate::common::everstake_normal_program_test;
+
+#[tokio::test]
+async fn basic() {
+    const LAMPORTS_AMOUNT: u64 = 1_053_727_689;
+    const LST_AMOUNT: u64 = 1_000_000_000;
+
+    let eversol_stake_pool =
+        KeyedUiAccount::from_test_fixtures_file("everstake-stake-pool.json").to_keyed_account();
+    let calc: EverstakeStakePoolCalc = deserialize_everstake_stake_pool_checked(eversol_stake_pool)
+        .unwrap()
+        .into();
+    let expected_lst_range = calc.calc_sol_to_lst(LAMPORTS_AMOUNT).unwrap();
+    // LAMPORTS_AMOUNT is the lst_to_sol result for LST_AMOUNT
+    assert!(expected_lst_range.get_min() <= LST_AMOUNT);
+    assert!(expected_lst_range.get_max() >= LST_AMOUNT);
+
+    let program_test = everstake_normal_program_test();
+
+    let ctx = program_test.start_with_context().await;
+    ctx.set_sysvar(&Clock {
+        epoch: EVERSTAKE_STAKE_POOL_LAST_UPDATE_EPOCH,
+        ..Default::default()
+    });
+
+    let ProgramTestContext {
+        mut banks_client,
+        last_blockhash,
+        payer,
+        ..
+    } = ctx;
+
+    let accounts: SolToLstKeys = EVERSTAKE_LST_SOL_COMMON_INTERMEDIATE_KEYS
+        .resolve::<EverstakeSolValCalc>()
+        .into();
+
+    let ix = everstake_sol_to_lst_ix(
+        accounts,
+        SolToLstIxArgs {
+            amount: LAMPORTS_AMOUNT,
+        },
+    )
+    .unwrap();
+
+    banks_client
+        .exec_verify_borsh_return_data::<U64ValueRange, U64_VALUE_RANGE_BORSH_SER_LEN>(
+            &payer,
+            last_blockhash,
+            ix,
+            expected_lst_range,
+        )
+        .await;
+}
@@ -0,0 +1,8 @@
+use generic_pool_calculator_onchain::processor::{process_init_unchecked, verify_init};
+use s_pool_calculator_lib::{initial_manager, SPoolSolValCalc};
+use solana_program::{account_info::AccountInfo, program_error::ProgramError};
+
+pub fn process_init(accounts: &[AccountInfo]) -> Result<(), ProgramError> {
+    let checked = verify_init::<SPoolSolValCalc>(accounts)?;
+    process_init_unchecked::<SPoolSolValCalc>(checked, initial_manager::ID)
+}
@@ -0,0 +1,40 @@
+use generic_pool_calculator_interface::{lst_to_sol_verify_account_keys, LstToSolAccounts};
+use generic_pool_calculator_lib::utils::{
+    verify_no_stake_pool_prog_upgrade, VerifyNoStakePoolProgUpgradeArgs,
+};
+use s_pool_calculator_interface::SPoolCalculatorError;
+use s_pool_calculator_lib::{SPoolLpCalc, SPoolLstSolCommonFreeArgs, SPoolSolValCalc};
+use sanctum_misc_utils::{load_accounts, log_and_return_wrong_acc_err};
+use sanctum_token_lib::mint_supply;
+use solana_program::{account_info::AccountInfo, program_error::ProgramError};
+
+/// Assumes:
+/// - LstToSolAccounts/Keys and SolToLstAccounts/Keys are identical
+pub fn verify_lst_sol_common(accounts: &[AccountInfo<'_>]) -> Result<SPoolLpCalc, ProgramError> {
+    let actual: LstToSolAccounts = load_accounts(accounts)?;
+
+    let root_keys = SPoolLstSolCommonFreeArgs {
+        pool_state: actual.pool_state,
+        s_controller_prog: actual.pool_program,
+    };
+    let (intermediate, pool_state) = root_keys.resolve()?;
+    let expected = intermediate.resolve::<SPoolSolValCalc>()?.into();
+
+    lst_to_sol_verify_account_keys(actual, expected).map_err(log_and_return_wrong_acc_err)?;
+    // accounts should all be read-only, no need to verify_account_privileges.
+    // However, a S pool syncing the SOL value of its own LP token passes its pool state as writable,
+    // and its total_sol_value is in the middle of being updated, so refuse to value it.
+    if actual.pool_state.is_writable {
+        return Err(SPoolCalculatorError::PoolStateWritable.into());
+    }
+
+    verify_no_stake_pool_prog_upgrade(VerifyNoStakePoolProgUpgradeArgs {
+        stake_pool_prog_data: actual.pool_program_data,
+        calculator_state: actual.state,
+    })?;
+
+    let calc = SPoolLpCalc::from_pool_state(&pool_state, mint_supply(actual.lst_mint)?);
+    calc.verify_pool_not_rebalancing()?;
+
+    Ok(calc)
+}
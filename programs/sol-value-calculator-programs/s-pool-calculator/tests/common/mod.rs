@@ -0,0 +1,51 @@
+use generic_pool_calculator_lib::GenericPoolSolValCalc;
+use generic_pool_calculator_test_utils::{
+    GenericPoolCalculatorProgramTest, MockCalculatorStateAccountArgs,
+};
+use s_controller_interface::PoolState;
+use s_controller_test_utils::{LpTokenProgramTest, PoolStateProgramTest, DEFAULT_POOL_STATE};
+use s_pool_calculator_lib::SPoolSolValCalc;
+use sanctum_solana_test_utils::ExtendedProgramTest;
+use solana_program::pubkey::Pubkey;
+use solana_program_test::{processor, ProgramTest};
+use test_utils::S_CONTROLLER_PROG_LAST_UPDATED_SLOT;
+
+pub struct SPoolProgramTestArgs {
+    pub total_sol_value: u64,
+    pub lp_token_mint: Pubkey,
+    pub lp_token_supply: u64,
+    pub is_rebalancing: bool,
+}
+
+/// Adds the default S pool's PoolState and LP token mint
+pub fn s_pool_normal_program_test(
+    SPoolProgramTestArgs {
+        total_sol_value,
+        lp_token_mint,
+        lp_token_supply,
+        is_rebalancing,
+    }: SPoolProgramTestArgs,
+) -> ProgramTest {
+    let mut program_test = ProgramTest::default();
+    // name must match <name>.so filename
+    program_test.add_program(
+        "s_pool_calculator",
+        s_pool_calculator_lib::program::ID,
+        processor!(s_pool_calculator::entrypoint::process_instruction),
+    );
+    program_test
+        .add_mock_calculator_state(MockCalculatorStateAccountArgs {
+            manager: Pubkey::default(),
+            last_upgrade_slot: S_CONTROLLER_PROG_LAST_UPDATED_SLOT,
+            owner: SPoolSolValCalc::ID,
+        })
+        .add_pool_state(PoolState {
+            total_sol_value,
+            lp_token_mint,
+            is_rebalancing: is_rebalancing.into(),
+            ..DEFAULT_POOL_STATE
+        })
+        .add_mock_lp_mint(lp_token_mint, lp_token_supply)
+        .add_test_fixtures_account("s-controller-prog.json")
+        .add_test_fixtures_account("s-controller-prog-data.json")
+}
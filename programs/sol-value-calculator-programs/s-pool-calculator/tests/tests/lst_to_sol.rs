@@ -0,0 +1,211 @@
+use generic_pool_calculator_interface::{GenericPoolCalculatorError, LstToSolIxArgs, LstToSolKeys};
+use s_controller_interface::PoolState;
+use s_controller_lib::program::POOL_STATE_ID;
+use s_controller_test_utils::{MockPoolState, DEFAULT_POOL_STATE};
+use s_pool_calculator_interface::SPoolCalculatorError;
+use s_pool_calculator_lib::{
+    s_pool_lst_sol_common_intermediate_keys, s_pool_lst_to_sol_ix, SPoolSolValCalc,
+};
+use sanctum_solana_test_utils::{
+    assert_custom_err, assert_program_error, ExtendedProgramTest, IntoAccount,
+};
+use sanctum_token_ratio::{U64ValueRange, U64_VALUE_RANGE_BORSH_SER_LEN};
+use solana_program::{program_error::ProgramError, pubkey::Pubkey};
+use solana_sdk::{signer::Signer, transaction::Transaction};
+use test_utils::BorshReturnDataBanksClient;
+
+use crate::common::{s_pool_normal_program_test, SPoolProgramTestArgs};
+
+const TOTAL_SOL_VALUE: u64 = 1_100_000_000_000;
+const LP_TOKEN_SUPPLY: u64 = 1_000_000_000_000;
+
+#[tokio::test]
+async fn basic() {
+    const LP_TOKEN_AMOUNT: u64 = 1_000_000_000;
+    const EXPECTED_LAMPORTS_RANGE: U64ValueRange = U64ValueRange::single(1_100_000_000);
+
+    let lp_token_mint = Pubkey::new_unique();
+    let program_test = s_pool_normal_program_test(SPoolProgramTestArgs {
+        total_sol_value: TOTAL_SOL_VALUE,
+        lp_token_mint,
+        lp_token_supply: LP_TOKEN_SUPPLY,
+        is_rebalancing: false,
+    });
+
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let accounts: LstToSolKeys =
+        s_pool_lst_sol_common_intermediate_keys(POOL_STATE_ID, lp_token_mint)
+            .resolve::<SPoolSolValCalc>()
+            .into();
+
+    let ix = s_pool_lst_to_sol_ix(
+        accounts,
+        LstToSolIxArgs {
+            amount: LP_TOKEN_AMOUNT,
+        },
+    )
+    .unwrap();
+
+    banks_client
+        .exec_verify_borsh_return_data::<U64ValueRange, U64_VALUE_RANGE_BORSH_SER_LEN>(
+            &payer,
+            last_blockhash,
+            ix,
+            EXPECTED_LAMPORTS_RANGE,
+        )
+        .await;
+}
+
+#[tokio::test]
+async fn fail_wrong_lp_token_mint() {
+    const LP_TOKEN_AMOUNT: u64 = 1_000_000_000;
+
+    let lp_token_mint = Pubkey::new_unique();
+    let program_test = s_pool_normal_program_test(SPoolProgramTestArgs {
+        total_sol_value: TOTAL_SOL_VALUE,
+        lp_token_mint,
+        lp_token_supply: LP_TOKEN_SUPPLY,
+        is_rebalancing: false,
+    });
+
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let accounts: LstToSolKeys =
+        s_pool_lst_sol_common_intermediate_keys(POOL_STATE_ID, Pubkey::new_unique())
+            .resolve::<SPoolSolValCalc>()
+            .into();
+
+    let ix = s_pool_lst_to_sol_ix(
+        accounts,
+        LstToSolIxArgs {
+            amount: LP_TOKEN_AMOUNT,
+        },
+    )
+    .unwrap();
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer], last_blockhash);
+
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_program_error(err, ProgramError::InvalidArgument);
+}
+
+#[tokio::test]
+async fn fail_pool_rebalancing() {
+    const LP_TOKEN_AMOUNT: u64 = 1_000_000_000;
+
+    let lp_token_mint = Pubkey::new_unique();
+    let program_test = s_pool_normal_program_test(SPoolProgramTestArgs {
+        total_sol_value: TOTAL_SOL_VALUE,
+        lp_token_mint,
+        lp_token_supply: LP_TOKEN_SUPPLY,
+        is_rebalancing: true,
+    });
+
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let accounts: LstToSolKeys =
+        s_pool_lst_sol_common_intermediate_keys(POOL_STATE_ID, lp_token_mint)
+            .resolve::<SPoolSolValCalc>()
+            .into();
+
+    let ix = s_pool_lst_to_sol_ix(
+        accounts,
+        LstToSolIxArgs {
+            amount: LP_TOKEN_AMOUNT,
+        },
+    )
+    .unwrap();
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer], last_blockhash);
+
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_custom_err(err, SPoolCalculatorError::PoolRebalancing);
+}
+
+#[tokio::test]
+async fn fail_pool_state_not_pda() {
+    const LP_TOKEN_AMOUNT: u64 = 1_000_000_000;
+
+    let lp_token_mint = Pubkey::new_unique();
+    let fake_pool_state_addr = Pubkey::new_unique();
+    let program_test = s_pool_normal_program_test(SPoolProgramTestArgs {
+        total_sol_value: TOTAL_SOL_VALUE,
+        lp_token_mint,
+        lp_token_supply: LP_TOKEN_SUPPLY,
+        is_rebalancing: false,
+    })
+    // S controller owned account of PoolState size that is not a pool state PDA
+    .add_account_chained(
+        fake_pool_state_addr,
+        MockPoolState(PoolState {
+            total_sol_value: TOTAL_SOL_VALUE,
+            lp_token_mint,
+            ..DEFAULT_POOL_STATE
+        })
+        .into_account(),
+    );
+
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let accounts: LstToSolKeys =
+        s_pool_lst_sol_common_intermediate_keys(fake_pool_state_addr, lp_token_mint)
+            .resolve::<SPoolSolValCalc>()
+            .into();
+
+    let ix = s_pool_lst_to_sol_ix(
+        accounts,
+        LstToSolIxArgs {
+            amount: LP_TOKEN_AMOUNT,
+        },
+    )
+    .unwrap();
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer], last_blockhash);
+
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_custom_err(err, GenericPoolCalculatorError::WrongPoolAccountType);
+}
+
+#[tokio::test]
+async fn fail_pool_state_writable() {
+    const LP_TOKEN_AMOUNT: u64 = 1_000_000_000;
+
+    let lp_token_mint = Pubkey::new_unique();
+    let program_test = s_pool_normal_program_test(SPoolProgramTestArgs {
+        total_sol_value: TOTAL_SOL_VALUE,
+        lp_token_mint,
+        lp_token_supply: LP_TOKEN_SUPPLY,
+        is_rebalancing: false,
+    });
+
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let accounts: LstToSolKeys =
+        s_pool_lst_sol_common_intermediate_keys(POOL_STATE_ID, lp_token_mint)
+            .resolve::<SPoolSolValCalc>()
+            .into();
+
+    let mut ix = s_pool_lst_to_sol_ix(
+        accounts,
+        LstToSolIxArgs {
+            amount: LP_TOKEN_AMOUNT,
+        },
+    )
+    .unwrap();
+    // the S pool invoking the calculator to value its own LP token has its pool state writable
+    ix.accounts
+        .iter_mut()
+        .find(|meta| meta.pubkey == POOL_STATE_ID)
+        .unwrap()
+        .is_writable = true;
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer], last_blockhash);
+
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_custom_err(err, SPoolCalculatorError::PoolStateWritable);
+}
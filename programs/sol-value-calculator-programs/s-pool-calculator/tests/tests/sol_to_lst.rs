@@ -0,0 +1,49 @@
+use generic_pool_calculator_interface::{SolToLstIxArgs, SolToLstKeys};
+use s_controller_lib::program::POOL_STATE_ID;
+use s_pool_calculator_lib::{
+    s_pool_lst_sol_common_intermediate_keys, s_pool_sol_to_lst_ix, SPoolSolValCalc,
+};
+use sanctum_token_ratio::{U64ValueRange, U64_VALUE_RANGE_BORSH_SER_LEN};
+use solana_program::pubkey::Pubkey;
+use test_utils::BorshReturnDataBanksClient;
+
+use crate::common::{s_pool_normal_program_test, SPoolProgramTestArgs};
+
+#[tokio::test]
+async fn basic() {
+    const LAMPORTS_AMOUNT: u64 = 1_100_000_000;
+    const EXPECTED_LST_RANGE: U64ValueRange =
+        U64ValueRange::from_min_max_unchecked(1_000_000_000, 1_000_000_001);
+
+    let lp_token_mint = Pubkey::new_unique();
+    let program_test = s_pool_normal_program_test(SPoolProgramTestArgs {
+        total_sol_value: 1_100_000_000_000,
+        lp_token_mint,
+        lp_token_supply: 1_000_000_000_000,
+        is_rebalancing: false,
+    });
+
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let accounts: SolToLstKeys =
+        s_pool_lst_sol_common_intermediate_keys(POOL_STATE_ID, lp_token_mint)
+            .resolve::<SPoolSolValCalc>()
+            .into();
+
+    let ix = s_pool_sol_to_lst_ix(
+        accounts,
+        SolToLstIxArgs {
+            amount: LAMPORTS_AMOUNT,
+        },
+    )
+    .unwrap();
+
+    banks_client
+        .exec_verify_borsh_return_data::<U64ValueRange, U64_VALUE_RANGE_BORSH_SER_LEN>(
+            &payer,
+            last_blockhash,
+            ix,
+            EXPECTED_LST_RANGE,
+        )
+        .await;
+}
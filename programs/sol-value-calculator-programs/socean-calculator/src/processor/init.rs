@@ -0,0 +1,8 @@
+use generic_pool_calculator_onchain::processor::{process_init_unchecked, verify_init};
+use socean_calculator_lib::{initial_manager, SoceanSolValCalc};
+use solana_program::{account_info::AccountInfo, program_error::ProgramError};
+
+pub fn process_init(accounts: &[AccountInfo]) -> Result<(), ProgramError> {
+    let checked = verify_init::<SoceanSolValCalc>(accounts)?;
+    process_init_unchecked::<SoceanSolValCalc>(checked, initial_manager::ID)
+}
@@ -0,0 +1,10 @@
+use generic_pool_calculator_onchain::processor::{
+    process_set_manager_unchecked, verify_set_manager,
+};
+use socean_calculator_lib::SoceanSolValCalc;
+use solana_program::{account_info::AccountInfo, program_error::ProgramError};
+
+pub fn process_set_manager(accounts: &[AccountInfo]) -> Result<(), ProgramError> {
+    let checked = verify_set_manager::<SoceanSolValCalc>(accounts)?;
+    process_set_manager_unchecked(checked)
+}
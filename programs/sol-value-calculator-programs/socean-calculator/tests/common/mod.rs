@@ -0,0 +1,28 @@
+use generic_pool_calculator_lib::GenericPoolSolValCalc;
+use generic_pool_calculator_test_utils::{
+    GenericPoolCalculatorProgramTest, MockCalculatorStateAccountArgs,
+};
+use sanctum_solana_test_utils::ExtendedProgramTest;
+use socean_calculator_lib::SoceanSolValCalc;
+use solana_program::pubkey::Pubkey;
+use solana_program_test::{processor, ProgramTest};
+use test_utils::SOCEAN_PROG_LAST_UPDATED_SLOT;
+
+pub fn socean_normal_program_test() -> ProgramTest {
+    let mut program_test = ProgramTest::default();
+    // name must match <name>.so filename
+    program_test.add_program(
+        "socean_calculator",
+        socean_calculator_lib::program::ID,
+        processor!(socean_calculator::entrypoint::process_instruction),
+    );
+    program_test
+        .add_mock_calculator_state(MockCalculatorStateAccountArgs {
+            manager: Pubkey::default(),
+            last_upgrade_slot: SOCEAN_PROG_LAST_UPDATED_SLOT,
+            owner: SoceanSolValCalc::ID,
+        })
+        .add_test_fixtures_account("socean-stake-pool.json")
+        .add_test_fixtures_account("socean-prog.json")
+        .add_test_fixtures_account("socean-prog-data.json")
+}
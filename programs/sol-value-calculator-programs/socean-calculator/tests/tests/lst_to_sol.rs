@@ -0,0 +1,81 @@
+use generic_pool_calculator_interface::{LstToSolIxArgs, LstToSolKeys};
+use sanctum_solana_test_utils::assert_custom_err;
+use sanctum_token_ratio::{U64ValueRange, U64_VALUE_RANGE_BORSH_SER_LEN};
+use socean_calculator_interface::SoceanCalculatorError;
+use socean_calculator_lib::{
+    socean_lst_to_sol_ix, SoceanSolValCalc, SOCEAN_LST_SOL_COMMON_INTERMEDIATE_KEYS,
+};
+use solana_program::clock::Clock;
+use solana_program_test::ProgramTestContext;
+use solana_sdk::{signer::Signer, transaction::Transaction};
+use test_utils::{BorshReturnDataBanksClient, SOCEAN_STAKE_POOL_LAST_UPDATE_EPOCH};
+
+use crate::common::socean_normal_program_test;
+
+#[tokio::test]
+async fn basic() {
+    const LST_AMOUNT: u64 = 1_000_000_000;
+    const EXPECTED_LAMPORTS_RANGE: U64ValueRange = U64ValueRange::single(1_246_657_455);
+
+    let program_test = socean_normal_program_test();
+
+    let ctx = program_test.start_with_context().await;
+    ctx.set_sysvar(&Clock {
+        epoch: SOCEAN_STAKE_POOL_LAST_UPDATE_EPOCH,
+        ..Default::default()
+    });
+
+    let ProgramTestContext {
+        mut banks_client,
+        last_blockhash,
+        payer,
+        ..
+    } = ctx;
+
+    let accounts: LstToSolKeys = SOCEAN_LST_SOL_COMMON_INTERMEDIATE_KEYS
+        .resolve::<SoceanSolValCalc>()
+        .into();
+
+    let ix = socean_lst_to_sol_ix(accounts, LstToSolIxArgs { amount: LST_AMOUNT }).unwrap();
+
+    banks_client
+        .exec_verify_borsh_return_data::<U64ValueRange, U64_VALUE_RANGE_BORSH_SER_LEN>(
+            &payer,
+            last_blockhash,
+            ix,
+            EXPECTED_LAMPORTS_RANGE,
+        )
+        .await;
+}
+
+#[tokio::test]
+async fn fail_pool_not_updated() {
+    const LST_AMOUNT: u64 = 1_000_000_000;
+
+    let program_test = socean_normal_program_test();
+
+    let ctx = program_test.start_with_context().await;
+    ctx.set_sysvar(&Clock {
+        epoch: SOCEAN_STAKE_POOL_LAST_UPDATE_EPOCH + 1,
+        ..Default::default()
+    });
+
+    let ProgramTestContext {
+        mut banks_client,
+        last_blockhash,
+        payer,
+        ..
+    } = ctx;
+
+    let accounts: LstToSolKeys = SOCEAN_LST_SOL_COMMON_INTERMEDIATE_KEYS
+        .resolve::<SoceanSolValCalc>()
+        .into();
+
+    let ix = socean_lst_to_sol_ix(accounts, LstToSolIxArgs { amount: LST_AMOUNT }).unwrap();
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer], last_blockhash);
+
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_custom_err(err, SoceanCalculatorError::PoolNotUpdated);
+}
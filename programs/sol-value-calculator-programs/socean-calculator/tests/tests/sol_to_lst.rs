@@ -0,0 +1,53 @@
+use generic_pool_calculator_interface::{SolToLstIxArgs, SolToLstKeys};
+use sanctum_token_ratio::{U64ValueRange, U64_VALUE_RANGE_BORSH_SER_LEN};
+use socean_calculator_lib::{
+    socean_sol_to_lst_ix, SoceanSolValCalc, SOCEAN_LST_SOL_COMMON_INTERMEDIATE_KEYS,
+};
+use solana_program::clock::Clock;
+use solana_program_test::ProgramTestContext;
+use test_utils::{BorshReturnDataBanksClient, SOCEAN_STAKE_POOL_LAST_UPDATE_EPOCH};
+
+use crate::common::socean_normal_program_test;
+
+#[tokio::test]
+async fn basic() {
+    const LAMPORTS_AMOUNT: u64 = 1_246_657_455;
+    const EXPECTED_LST_RANGE: U64ValueRange =
+        U64ValueRange::from_min_max_unchecked(999_999_998, 1_000_000_003);
+
+    let program_test = socean_normal_program_test();
+
+    let ctx = program_test.start_with_context().await;
+    ctx.set_sysvar(&Clock {
+        epoch: SOCEAN_STAKE_POOL_LAST_UPDATE_EPOCH,
+        ..Default::default()
+    });
+
+    let ProgramTestContext {
+        mut banks_client,
+        last_blockhash,
+        payer,
+        ..
+    } = ctx;
+
+    let accounts: SolToLstKeys = SOCEAN_LST_SOL_COMMON_INTERMEDIATE_KEYS
+        .resolve::<SoceanSolValCalc>()
+        .into();
+
+    let ix = socean_sol_to_lst_ix(
+        accounts,
+        SolToLstIxArgs {
+            amount: LAMPORTS_AMOUNT,
+        },
+    )
+    .unwrap();
+
+    banks_client
+        .exec_verify_borsh_return_data::<U64ValueRange, U64_VALUE_RANGE_BORSH_SER_LEN>(
+            &payer,
+            last_blockhash,
+            ix,
+            EXPECTED_LST_RANGE,
+        )
+        .await;
+}
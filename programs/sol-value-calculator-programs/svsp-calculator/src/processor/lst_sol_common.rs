@@ -0,0 +1,50 @@
+use generic_pool_calculator_interface::{
+    lst_to_sol_verify_account_keys, LstToSolAccounts, LST_TO_SOL_IX_ACCOUNTS_LEN,
+};
+use generic_pool_calculator_lib::utils::{
+    verify_no_stake_pool_prog_upgrade, VerifyNoStakePoolProgUpgradeArgs,
+};
+use sanctum_misc_utils::{load_accounts, log_and_return_wrong_acc_err};
+use sanctum_token_lib::mint_supply;
+use solana_program::{account_info::AccountInfo, program_error::ProgramError};
+use svsp_calculator_lib::{
+    find_svsp_pool_stake, read_svsp_pool_stake, SvspLstSolCommonFreeArgs, SvspSolValCalc,
+    SvspStakeCalc,
+};
+
+/// Assumes:
+/// - LstToSolAccounts/Keys and SolToLstAccounts/Keys are identical
+/// - the SVSP pool stake account immediately follows them
+pub fn verify_lst_sol_common(accounts: &[AccountInfo<'_>]) -> Result<SvspStakeCalc, ProgramError> {
+    let actual: LstToSolAccounts = load_accounts(accounts)?;
+    let pool_stake = accounts
+        .get(LST_TO_SOL_IX_ACCOUNTS_LEN)
+        .ok_or(ProgramError::NotEnoughAccountKeys)?;
+
+    let root_keys = SvspLstSolCommonFreeArgs {
+        svsp_pool: actual.pool_state,
+        svsp_prog: actual.pool_program,
+    };
+    let intermediate = root_keys.resolve()?;
+    let expected = intermediate.resolve::<SvspSolValCalc>()?.into();
+
+    lst_to_sol_verify_account_keys(actual, expected).map_err(log_and_return_wrong_acc_err)?;
+    let (expected_pool_stake, _bump) = find_svsp_pool_stake(actual.pool_state.key);
+    if *pool_stake.key != expected_pool_stake {
+        return Err(log_and_return_wrong_acc_err((
+            *pool_stake.key,
+            expected_pool_stake,
+        )));
+    }
+    // accounts should all be read-only, no need to verify_account_privileges
+
+    verify_no_stake_pool_prog_upgrade(VerifyNoStakePoolProgUpgradeArgs {
+        stake_pool_prog_data: actual.pool_program_data,
+        calculator_state: actual.state,
+    })?;
+
+    Ok(SvspStakeCalc {
+        pool_stake: read_svsp_pool_stake(pool_stake)?,
+        lst_supply: mint_supply(actual.lst_mint)?,
+    })
+}
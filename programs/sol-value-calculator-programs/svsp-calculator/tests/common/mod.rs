@@ -0,0 +1,30 @@
+use generic_pool_calculator_lib::GenericPoolSolValCalc;
+use generic_pool_calculator_test_utils::{
+    GenericPoolCalculatorProgramTest, MockCalculatorStateAccountArgs,
+};
+use sanctum_solana_test_utils::ExtendedProgramTest;
+use solana_program::pubkey::Pubkey;
+use solana_program_test::{processor, ProgramTest};
+use svsp_calculator_lib::SvspSolValCalc;
+use test_utils::SVSP_PROG_LAST_UPDATED_SLOT;
+
+pub fn svsp_normal_program_test() -> ProgramTest {
+    let mut program_test = ProgramTest::default();
+    // name must match <name>.so filename
+    program_test.add_program(
+        "svsp_calculator",
+        svsp_calculator_lib::program::ID,
+        processor!(svsp_calculator::entrypoint::process_instruction),
+    );
+    program_test
+        .add_mock_calculator_state(MockCalculatorStateAccountArgs {
+            manager: Pubkey::default(),
+            last_upgrade_slot: SVSP_PROG_LAST_UPDATED_SLOT,
+            owner: SvspSolValCalc::ID,
+        })
+        .add_test_fixtures_account("svsp-pool.json")
+        .add_test_fixtures_account("svsp-mint.json")
+        .add_test_fixtures_account("svsp-stake.json")
+        .add_test_fixtures_account("svsp-prog.json")
+        .add_test_fixtures_account("svsp-prog-data.json")
+}
@@ -0,0 +1,4 @@
+//! Put tests in one module so that cargo doesnt compile them as separate crates
+
+mod common;
+mod tests;
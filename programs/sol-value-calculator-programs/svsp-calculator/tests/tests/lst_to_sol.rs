@@ -0,0 +1,85 @@
+use generic_pool_calculator_interface::{LstToSolIxArgs, LstToSolKeys};
+use sanctum_solana_test_utils::assert_program_error;
+use sanctum_token_ratio::{U64ValueRange, U64_VALUE_RANGE_BORSH_SER_LEN};
+use solana_program::{instruction::AccountMeta, program_error::ProgramError};
+use solana_sdk::{signer::Signer, transaction::Transaction};
+use svsp_calculator_lib::{
+    find_svsp_pool_stake, svsp_lst_sol_common_intermediate_keys, svsp_lst_to_sol_ix, SvspSolValCalc,
+};
+use test_utils::{svsp_pool, BorshReturnDataBanksClient};
+
+use crate::common::svsp_normal_program_test;
+
+#[tokio::test]
+async fn basic() {
+    const LST_AMOUNT: u64 = 1_000_000_000;
+    const EXPECTED_LAMPORTS_RANGE: U64ValueRange = U64ValueRange::single(1_051_347_829);
+
+    let program_test = svsp_normal_program_test();
+
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let accounts: LstToSolKeys = svsp_lst_sol_common_intermediate_keys(svsp_pool::ID)
+        .resolve::<SvspSolValCalc>()
+        .into();
+
+    let mut ix = svsp_lst_to_sol_ix(accounts, LstToSolIxArgs { amount: LST_AMOUNT }).unwrap();
+    ix.accounts.push(AccountMeta::new_readonly(
+        find_svsp_pool_stake(&svsp_pool::ID).0,
+        false,
+    ));
+
+    banks_client
+        .exec_verify_borsh_return_data::<U64ValueRange, U64_VALUE_RANGE_BORSH_SER_LEN>(
+            &payer,
+            last_blockhash,
+            ix,
+            EXPECTED_LAMPORTS_RANGE,
+        )
+        .await;
+}
+
+#[tokio::test]
+async fn fail_missing_pool_stake() {
+    const LST_AMOUNT: u64 = 1_000_000_000;
+
+    let program_test = svsp_normal_program_test();
+
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let accounts: LstToSolKeys = svsp_lst_sol_common_intermediate_keys(svsp_pool::ID)
+        .resolve::<SvspSolValCalc>()
+        .into();
+
+    let ix = svsp_lst_to_sol_ix(accounts, LstToSolIxArgs { amount: LST_AMOUNT }).unwrap();
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer], last_blockhash);
+
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_program_error(err, ProgramError::NotEnoughAccountKeys);
+}
+
+#[tokio::test]
+async fn fail_wrong_pool_stake() {
+    const LST_AMOUNT: u64 = 1_000_000_000;
+
+    let program_test = svsp_normal_program_test();
+
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let accounts: LstToSolKeys = svsp_lst_sol_common_intermediate_keys(svsp_pool::ID)
+        .resolve::<SvspSolValCalc>()
+        .into();
+
+    let mut ix = svsp_lst_to_sol_ix(accounts, LstToSolIxArgs { amount: LST_AMOUNT }).unwrap();
+    // the pool account is not the pool stake account
+    ix.accounts
+        .push(AccountMeta::new_readonly(svsp_pool::ID, false));
+
+    let mut tx = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    tx.sign(&[&payer], last_blockhash);
+
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_program_error(err, ProgramError::InvalidArgument);
+}
@@ -0,0 +1,52 @@
+use generic_pool_calculator_interface::{SolToLstIxArgs, SolToLstKeys};
+use sanctum_token_ratio::{U64ValueRange, U64_VALUE_RANGE_BORSH_SER_LEN};
+use solana_program::instruction::AccountMeta;
+use svsp_calculator_lib::{
+    find_svsp_pool_stake, svsp_lst_sol_common_intermediate_keys, svsp_sol_to_lst_ix, SvspSolValCalc,
+};
+use test_utils::{svsp_pool, BorshReturnDataBanksClient};
+
+use crate::common::svsp_normal_program_test;
+
+#[tokio::test]
+async fn basic() {
+    const LAMPORTS_AMOUNT: u64 = 1_051_347_829;
+    // The fixture pool has 1_051_347_829_114 lamports of NEV
+    // (1_052_347_829_114 delegated - SVSP_MINIMUM_POOL_BALANCE) backing 1_000_000_000_000 LST,
+    // so lst_to_sol(1_000_000_000) = floor(1_000_000_000 * 1.051347829114) = LAMPORTS_AMOUNT.
+    //
+    // FloorDiv::reverse() returns [floor(y * supply / nev), ceil((y + 1) * supply / nev)]
+    // = [floor(999_999_999.89), ceil(1_000_000_000.84)], a superset of the exact preimage
+    // {1_000_000_000}, same as the other FloorDiv-based calculators e.g. lido and sanctum-spl.
+    const EXPECTED_LST_RANGE: U64ValueRange =
+        U64ValueRange::from_min_max_unchecked(999_999_999, 1_000_000_001);
+
+    let program_test = svsp_normal_program_test();
+
+    let (mut banks_client, payer, last_blockhash) = program_test.start().await;
+
+    let accounts: SolToLstKeys = svsp_lst_sol_common_intermediate_keys(svsp_pool::ID)
+        .resolve::<SvspSolValCalc>()
+        .into();
+
+    let mut ix = svsp_sol_to_lst_ix(
+        accounts,
+        SolToLstIxArgs {
+            amount: LAMPORTS_AMOUNT,
+        },
+    )
+    .unwrap();
+    ix.accounts.push(AccountMeta::new_readonly(
+        find_svsp_pool_stake(&svsp_pool::ID).0,
+        false,
+    ));
+
+    banks_client
+        .exec_verify_borsh_return_data::<U64ValueRange, U64_VALUE_RANGE_BORSH_SER_LEN>(
+            &payer,
+            last_blockhash,
+            ix,
+            EXPECTED_LST_RANGE,
+        )
+        .await;
+}
@@ -14,6 +14,18 @@ pub const LIDO_PROG_LAST_UPDATED_SLOT: u64 = 165_468_732;
 
 pub const LIDO_STATE_LAST_UPDATE_EPOCH: u64 = 543;
 
+pub const SVSP_PROG_LAST_UPDATED_SLOT: u64 = 254_719_330;
+
+pub const SOCEAN_PROG_LAST_UPDATED_SLOT: u64 = 176_289_214;
+
+pub const SOCEAN_STAKE_POOL_LAST_UPDATE_EPOCH: u64 = 545;
+
+pub const EVERSTAKE_PROG_LAST_UPDATED_SLOT: u64 = 183_012_471;
+
+pub const EVERSTAKE_STAKE_POOL_LAST_UPDATE_EPOCH: u64 = 545;
+
+pub const S_CONTROLLER_PROG_LAST_UPDATED_SLOT: u64 = 257_893_011;
+
 pub mod jito_stake_pool {
     sanctum_macros::declare_program_keys!("Jito4APyf642JPZPx3hGc6WWJ8zPKtRbRs4P815Awbb", []);
 }
@@ -21,3 +33,8 @@ pub mod jito_stake_pool {
 pub mod jitosol {
     sanctum_macros::declare_program_keys!("J1toso1uCk3RLmjorhTtrVwY9HJ7X8V9yYac6Y7kGCPn", []);
 }
+
+/// Mock SVSP pool in test-fixtures/svsp-pool.json
+pub mod svsp_pool {
+    sanctum_macros::declare_program_keys!("FG5xQMkifX3buK33JyzhzFRB2ST7NqxTqsMvF4SKSrSf", []);
+}